@@ -0,0 +1,38 @@
+//! Accessors for raw Atlassian Document Format (ADF) nodes.
+//!
+//! [`AtlassianDoc`](super::types::AtlassianDoc) keeps its content as JSON
+//! values. These helpers read the common node properties and fall back to
+//! empty values when a property is missing.
+
+use serde_json::Value;
+
+/// Get a node's `type`.
+pub fn node_type(node: &Value) -> &str {
+    node.get("type").and_then(Value::as_str).unwrap_or("")
+}
+
+/// Get a node's child nodes.
+pub fn content(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+/// Get a text node's marks.
+pub fn marks(node: &Value) -> &[Value] {
+    node.get("marks")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+/// Get one of a node's attributes.
+pub fn attr<'v>(node: &'v Value, name: &str) -> Option<&'v Value> {
+    node.get("attrs").and_then(|attrs| attrs.get(name))
+}
+
+/// Get one of a node's attributes as a string.
+pub fn attr_str<'v>(node: &'v Value, name: &str) -> Option<&'v str> {
+    attr(node, name).and_then(Value::as_str)
+}
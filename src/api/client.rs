@@ -9,15 +9,19 @@ use reqwest::{header, Client, Response, StatusCode};
 use tracing::{debug, error, info, instrument, warn};
 
 use super::auth::Auth;
+use super::columns::FieldIds;
 use super::error::{ApiError, Result};
 use super::types::{
-    AddCommentRequest, BoardsResponse, Changelog, Comment, CommentsResponse,
-    CreateIssueLinkRequest, CreateIssueRequest, CreateIssueResponse, CurrentUser, FieldUpdates,
-    FilterOption, FilterOptions, Issue, IssueKeyRef, IssueLinkType, IssueLinkTypeRef,
-    IssueLinkTypesResponse, IssuePickerResponse, IssueSuggestion, IssueTypeMeta,
-    IssueTypeMetaResponse, IssueUpdateRequest, LabelOperation, LabelsResponse, Priority, Project,
-    SearchResult, SprintsResponse, Status, Transition, TransitionRef, TransitionRequest,
-    TransitionsResponse, UpdateOperations, User,
+    AddCommentRequest, AllowedValue, BoardsResponse, Changelog, Comment, CommentsResponse,
+    CreateFieldMeta, CreateFieldMetaResponse, CreateIssueLinkRequest, CreateIssueRequest,
+    CreateIssueResponse, CreateVersionRequest, CurrentUser, Field, FieldUpdates, FilterOption,
+    FilterOptions, Issue, IssueKeyRef, IssueLinkType, IssueLinkTypeRef, IssueLinkTypesResponse,
+    IssuePickerResponse, IssueSuggestion, IssueType, IssueTypeMeta, IssueTypeMetaResponse,
+    IssueUpdateRequest, JqlAutocompleteData, JqlParseResponse, JqlSuggestion,
+    JqlSuggestionsResponse, LabelOperation, LabelsResponse, Priority, Project,
+    ReleaseVersionRequest, SearchResult, SprintsResponse, Status, Transition, TransitionRef,
+    TransitionRequest, TransitionsResponse, UpdateOperations, User, Version, VersionField,
+    VersionOperation, Watchers,
 };
 use crate::config::Profile;
 
@@ -30,6 +34,9 @@ const MAX_RETRIES: u32 = 3;
 /// Base delay between retries in milliseconds.
 const RETRY_DELAY_MS: u64 = 1000;
 
+/// Maximum number of issues loaded for a version in the release view.
+const MAX_VERSION_ISSUES: usize = 1000;
+
 /// The JIRA API client.
 ///
 /// Provides async methods for interacting with the JIRA REST API v3.
@@ -42,6 +49,11 @@ pub struct JiraClient {
     base_url: String,
     /// Authentication credentials.
     auth: Auth,
+    /// Account ID of the authenticated user, known once the connection
+    /// has been validated.
+    account_id: Option<String>,
+    /// IDs of the custom fields the issue list shows by name.
+    field_ids: FieldIds,
 }
 
 impl JiraClient {
@@ -69,14 +81,24 @@ impl JiraClient {
 
         let base_url = normalize_base_url(&profile.url);
 
-        let jira = Self {
+        let mut jira = Self {
             client,
             base_url,
             auth,
+            account_id: None,
+            field_ids: FieldIds::default(),
         };
 
         // Validate connection
-        jira.validate_connection().await?;
+        let user = jira.validate_connection().await?;
+        jira.account_id = Some(user.account_id);
+
+        // Custom field IDs differ between instances; keep the defaults if
+        // the field list is unavailable
+        match jira.get_fields().await {
+            Ok(fields) => jira.field_ids = FieldIds::from_fields(&fields),
+            Err(e) => warn!("Failed to fetch fields, using default field IDs: {}", e),
+        }
 
         info!("JIRA client created and connection validated");
         Ok(jira)
@@ -102,6 +124,8 @@ impl JiraClient {
             client,
             base_url,
             auth,
+            account_id: None,
+            field_ids: FieldIds::default(),
         })
     }
 
@@ -624,6 +648,11 @@ impl JiraClient {
         &self.base_url
     }
 
+    /// Get the IDs of the custom fields the issue list shows by name.
+    pub fn field_ids(&self) -> &FieldIds {
+        &self.field_ids
+    }
+
     // ========================================================================
     // Filter Options API Methods
     // ========================================================================
@@ -638,6 +667,26 @@ impl JiraClient {
         Ok(statuses)
     }
 
+    /// Get all issue types the user can see.
+    #[instrument(skip(self))]
+    pub async fn get_issue_types(&self) -> Result<Vec<IssueType>> {
+        debug!("Fetching issue types");
+        let url = format!("{}/rest/api/3/issuetype", self.base_url);
+        let issue_types: Vec<IssueType> = self.get(&url).await?;
+        debug!("Found {} issue types", issue_types.len());
+        Ok(issue_types)
+    }
+
+    /// Get all fields of the JIRA instance, including custom fields.
+    #[instrument(skip(self))]
+    pub async fn get_fields(&self) -> Result<Vec<Field>> {
+        debug!("Fetching fields");
+        let url = format!("{}/rest/api/3/field", self.base_url);
+        let fields: Vec<Field> = self.get(&url).await?;
+        debug!("Found {} fields", fields.len());
+        Ok(fields)
+    }
+
     /// Get all projects the user has access to.
     #[instrument(skip(self))]
     pub async fn get_projects(&self) -> Result<Vec<Project>> {
@@ -654,7 +703,6 @@ impl JiraClient {
     ///
     /// * `query` - Search query for username or display name
     /// * `max_results` - Maximum number of results to return (default 50)
-    #[allow(dead_code)]
     #[instrument(skip(self), fields(query = %query))]
     pub async fn search_users(&self, query: &str, max_results: u32) -> Result<Vec<User>> {
         debug!("Searching users");
@@ -773,11 +821,14 @@ impl JiraClient {
     pub async fn get_filter_options(&self) -> Result<FilterOptions> {
         debug!("Fetching all filter options");
 
-        // Fetch statuses, projects, labels, and epics sequentially
+        // Fetch statuses, projects, labels, epics, issue types and priorities
+        // sequentially
         let statuses = self.get_statuses().await.unwrap_or_default();
         let projects = self.get_projects().await.unwrap_or_default();
         let labels = self.get_labels().await.unwrap_or_default();
         let epics = self.get_epics().await.unwrap_or_default();
+        let issue_types = self.get_issue_types().await.unwrap_or_default();
+        let priorities = self.get_priorities().await.unwrap_or_default();
 
         let mut options = FilterOptions::new();
 
@@ -808,12 +859,31 @@ impl JiraClient {
             ));
         }
 
+        // Convert issue types and priorities (JQL matches them by name, and
+        // each project can define its own type with a shared name)
+        for issue_type in issue_types {
+            if !options.issue_types.iter().any(|o| o.id == issue_type.name) {
+                options
+                    .issue_types
+                    .push(FilterOption::new(&issue_type.name, &issue_type.name));
+            }
+        }
+        for priority in priorities {
+            if !options.priorities.iter().any(|o| o.id == priority.name) {
+                options
+                    .priorities
+                    .push(FilterOption::new(&priority.name, &priority.name));
+            }
+        }
+
         debug!(
-            "Loaded filter options: {} statuses, {} projects, {} labels, {} epics",
+            "Loaded filter options: {} statuses, {} projects, {} labels, {} epics, {} issue types, {} priorities",
             options.statuses.len(),
             options.projects.len(),
             options.labels.len(),
-            options.epics.len()
+            options.epics.len(),
+            options.issue_types.len(),
+            options.priorities.len()
         );
 
         Ok(options)
@@ -1139,7 +1209,6 @@ impl JiraClient {
     /// - Permission is denied (403)
     /// - Validation fails (400) - e.g., required fields missing
     /// - The project or issue type doesn't exist
-    #[allow(dead_code)] // Will be used by CreateIssueView in future task
     #[instrument(skip(self, request))]
     pub async fn create_issue(&self, request: CreateIssueRequest) -> Result<CreateIssueResponse> {
         let url = format!("{}/rest/api/3/issue", self.base_url);
@@ -1186,7 +1255,6 @@ impl JiraClient {
     /// - The project doesn't exist (404)
     /// - Permission is denied (403)
     /// - The API call fails
-    #[allow(dead_code)] // Will be used by CreateIssueView in future task
     #[instrument(skip(self), fields(project = %project_key))]
     pub async fn get_project_issue_types(&self, project_key: &str) -> Result<Vec<IssueTypeMeta>> {
         debug!("Fetching issue types for project {}", project_key);
@@ -1219,6 +1287,83 @@ impl JiraClient {
         Ok(response.issue_types)
     }
 
+    /// Get the fields that can be set when creating an issue of a type.
+    ///
+    /// Sprint fields have no allowed values in the metadata, so the active
+    /// and future sprints of the project's boards are filled in.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The project key (e.g., "PROJ")
+    /// * `issue_type_id` - The issue type ID
+    #[instrument(skip(self), fields(project = %project_key, issue_type = %issue_type_id))]
+    pub async fn get_create_fields(
+        &self,
+        project_key: &str,
+        issue_type_id: &str,
+    ) -> Result<Vec<CreateFieldMeta>> {
+        debug!("Fetching create fields");
+
+        let url = format!(
+            "{}/rest/api/3/issue/createmeta/{}/issuetypes/{}?maxResults=200",
+            self.base_url,
+            urlencoding::encode(project_key),
+            urlencoding::encode(issue_type_id)
+        );
+
+        let response: CreateFieldMetaResponse = self.get(&url).await.map_err(|e| {
+            error!("Failed to get create fields: {}", e);
+            match e {
+                ApiError::Forbidden => ApiError::PermissionDenied,
+                other => other,
+            }
+        })?;
+        let mut fields = response.fields;
+
+        if let Some(field) = fields
+            .iter_mut()
+            .find(|f| f.is_sprint() && f.allowed_values.is_empty())
+        {
+            match self.get_project_sprints(project_key).await {
+                Ok(sprints) => {
+                    field.allowed_values = sprints
+                        .into_iter()
+                        .map(|s| AllowedValue::new(s.id.to_string(), s.name))
+                        .collect();
+                }
+                Err(e) => warn!("Failed to get sprints for project {}: {}", project_key, e),
+            }
+        }
+
+        debug!("Found {} create fields", fields.len());
+        Ok(fields)
+    }
+
+    /// Get the active and future sprints of a project's boards.
+    #[instrument(skip(self), fields(project = %project_key))]
+    pub async fn get_project_sprints(
+        &self,
+        project_key: &str,
+    ) -> Result<Vec<super::types::Sprint>> {
+        let url = format!(
+            "{}/rest/agile/1.0/board?projectKeyOrId={}",
+            self.base_url,
+            urlencoding::encode(project_key)
+        );
+        let boards: BoardsResponse = self.get(&url).await?;
+
+        let mut sprints: Vec<super::types::Sprint> = Vec::new();
+        for board in boards.values.iter().filter(|b| b.board_type == "scrum") {
+            for sprint in self.get_sprints(board.id, Some("active,future")).await? {
+                if !sprints.iter().any(|s| s.id == sprint.id) {
+                    sprints.push(sprint);
+                }
+            }
+        }
+        debug!("Found {} open sprints", sprints.len());
+        Ok(sprints)
+    }
+
     // ========================================================================
     // Comment Operations
     // ========================================================================
@@ -1259,7 +1404,7 @@ impl JiraClient {
     /// # Arguments
     ///
     /// * `key` - The issue key (e.g., "PROJ-123")
-    /// * `body` - The comment text (will be converted to ADF)
+    /// * `body` - The comment text as Markdown (will be converted to ADF)
     ///
     /// # Returns
     ///
@@ -1268,7 +1413,7 @@ impl JiraClient {
     pub async fn add_comment(&self, key: &str, body: &str) -> Result<Comment> {
         info!("Adding comment to issue {}", key);
         let url = format!("{}/rest/api/3/issue/{}/comment", self.base_url, key);
-        let request = AddCommentRequest::from_text(body);
+        let request = AddCommentRequest::from_markdown(body);
         let json_value = serde_json::to_value(request).map_err(|e| {
             ApiError::InvalidResponse(format!("Failed to serialize comment: {}", e))
         })?;
@@ -1277,6 +1422,274 @@ impl JiraClient {
         Ok(comment)
     }
 
+    // ========================================================================
+    // Version Operations
+    // ========================================================================
+
+    /// Get all versions of a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_key` - The project key (e.g., "PROJ")
+    #[instrument(skip(self), fields(project = %project_key))]
+    pub async fn get_project_versions(&self, project_key: &str) -> Result<Vec<Version>> {
+        debug!("Fetching versions for project");
+        let url = format!(
+            "{}/rest/api/3/project/{}/versions",
+            self.base_url,
+            urlencoding::encode(project_key)
+        );
+        let versions: Vec<Version> = self.get(&url).await?;
+        debug!("Found {} versions", versions.len());
+        Ok(versions)
+    }
+
+    /// Create a version in a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The numeric project ID
+    /// * `name` - The version name
+    #[instrument(skip(self), fields(project_id = %project_id, name = %name))]
+    pub async fn create_version(&self, project_id: &str, name: &str) -> Result<Version> {
+        let project_id: u64 = project_id.parse().map_err(|_| {
+            ApiError::InvalidResponse(format!("Invalid project ID: {}", project_id))
+        })?;
+        let request = CreateVersionRequest {
+            name: name.to_string(),
+            project_id,
+            description: None,
+        };
+        let url = format!("{}/rest/api/3/version", self.base_url);
+        let json_value = serde_json::to_value(&request).map_err(|e| {
+            ApiError::InvalidResponse(format!("Failed to serialize create version request: {}", e))
+        })?;
+
+        let version: Version = self.post(&url, &json_value).await?;
+        info!("Created version {} ({})", version.name, version.id);
+        Ok(version)
+    }
+
+    /// Release a version.
+    ///
+    /// # Arguments
+    ///
+    /// * `version_id` - The ID of the version to release
+    /// * `release_date` - The release date (YYYY-MM-DD)
+    /// * `move_unresolved_to` - ID of the version that unresolved issues are moved to,
+    ///   or `None` to leave them in the released version
+    #[instrument(skip(self), fields(version_id = %version_id))]
+    pub async fn release_version(
+        &self,
+        version_id: &str,
+        release_date: &str,
+        move_unresolved_to: Option<&str>,
+    ) -> Result<()> {
+        let request = ReleaseVersionRequest {
+            released: true,
+            release_date: Some(release_date.to_string()),
+            move_unfixed_issues_to: move_unresolved_to
+                .map(|id| format!("{}/rest/api/3/version/{}", self.base_url, id)),
+        };
+        let url = format!("{}/rest/api/3/version/{}", self.base_url, version_id);
+        self.put(&url, &request).await?;
+        info!("Released version {}", version_id);
+        Ok(())
+    }
+
+    /// Get all issues with the given fix version.
+    ///
+    /// Follows the search pagination up to `MAX_VERSION_ISSUES` issues.
+    ///
+    /// # Arguments
+    ///
+    /// * `version_id` - The version ID
+    #[instrument(skip(self), fields(version_id = %version_id))]
+    pub async fn get_version_issues(&self, version_id: &str) -> Result<Vec<Issue>> {
+        let jql = format!("fixVersion = {} ORDER BY status ASC, key ASC", version_id);
+        let mut issues = Vec::new();
+        let mut next_page_token: Option<String> = None;
+
+        loop {
+            let result = self
+                .search_issues_with_token(&jql, 100, next_page_token.as_deref())
+                .await?;
+            let has_more = result.has_more();
+            issues.extend(result.issues);
+            next_page_token = result.next_page_token;
+
+            if !has_more || next_page_token.is_none() || issues.len() >= MAX_VERSION_ISSUES {
+                break;
+            }
+        }
+
+        debug!("Found {} issues in version", issues.len());
+        Ok(issues)
+    }
+
+    /// Add versions to a fix or affects version field of an issue.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The issue key (e.g., "PROJ-123")
+    /// * `field` - The version field to update
+    /// * `versions` - The version names to add
+    #[instrument(skip(self, versions), fields(issue_key = %key, field = field.field_id()))]
+    pub async fn add_versions(
+        &self,
+        key: &str,
+        field: VersionField,
+        versions: Vec<String>,
+    ) -> Result<()> {
+        let operations = versions
+            .into_iter()
+            .map(|name| VersionOperation::Add { name })
+            .collect();
+        self.update_versions(key, field, operations).await
+    }
+
+    /// Remove versions from a fix or affects version field of an issue.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The issue key (e.g., "PROJ-123")
+    /// * `field` - The version field to update
+    /// * `versions` - The version names to remove
+    #[instrument(skip(self, versions), fields(issue_key = %key, field = field.field_id()))]
+    pub async fn remove_versions(
+        &self,
+        key: &str,
+        field: VersionField,
+        versions: Vec<String>,
+    ) -> Result<()> {
+        let operations = versions
+            .into_iter()
+            .map(|name| VersionOperation::Remove { name })
+            .collect();
+        self.update_versions(key, field, operations).await
+    }
+
+    /// Apply version operations to a version field of an issue.
+    async fn update_versions(
+        &self,
+        key: &str,
+        field: VersionField,
+        operations: Vec<VersionOperation>,
+    ) -> Result<()> {
+        let update = match field {
+            VersionField::Fix => UpdateOperations {
+                fix_versions: Some(operations),
+                ..Default::default()
+            },
+            VersionField::Affects => UpdateOperations {
+                versions: Some(operations),
+                ..Default::default()
+            },
+        };
+        let request = IssueUpdateRequest {
+            fields: None,
+            update: Some(update),
+        };
+        self.update_issue(key, request).await
+    }
+
+    // ========================================================================
+    // Watcher and Vote Operations
+    // ========================================================================
+
+    /// Get the watchers of an issue.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The issue key (e.g., "PROJ-123")
+    #[instrument(skip(self), fields(issue_key = %key))]
+    pub async fn get_watchers(&self, key: &str) -> Result<Watchers> {
+        debug!("Fetching watchers for issue {}", key);
+        let url = format!("{}/rest/api/3/issue/{}/watchers", self.base_url, key);
+        let watchers: Watchers = self.get(&url).await?;
+        debug!("Found {} watchers", watchers.watchers.len());
+        Ok(watchers)
+    }
+
+    /// Add a user as a watcher of an issue.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The issue key (e.g., "PROJ-123")
+    /// * `account_id` - The account ID of the user to add
+    #[instrument(skip(self), fields(issue_key = %key, account_id = %account_id))]
+    pub async fn add_watcher(&self, key: &str, account_id: &str) -> Result<()> {
+        info!("Adding watcher to issue {}", key);
+        let url = format!("{}/rest/api/3/issue/{}/watchers", self.base_url, key);
+        // The body is the bare account ID as a JSON string
+        self.post_no_content(&url, &account_id).await?;
+        info!("Successfully added watcher to issue {}", key);
+        Ok(())
+    }
+
+    /// Remove a user from the watchers of an issue.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The issue key (e.g., "PROJ-123")
+    /// * `account_id` - The account ID of the user to remove
+    #[instrument(skip(self), fields(issue_key = %key, account_id = %account_id))]
+    pub async fn remove_watcher(&self, key: &str, account_id: &str) -> Result<()> {
+        info!("Removing watcher from issue {}", key);
+        let url = format!(
+            "{}/rest/api/3/issue/{}/watchers?accountId={}",
+            self.base_url,
+            key,
+            urlencoding::encode(account_id)
+        );
+        self.delete(&url).await?;
+        info!("Successfully removed watcher from issue {}", key);
+        Ok(())
+    }
+
+    /// Start or stop watching an issue as the current user.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The issue key (e.g., "PROJ-123")
+    /// * `watch` - True to watch the issue, false to stop watching it
+    #[instrument(skip(self), fields(issue_key = %key))]
+    pub async fn set_watching(&self, key: &str, watch: bool) -> Result<()> {
+        // Clients created without validation don't know the user yet
+        let account_id = match &self.account_id {
+            Some(account_id) => account_id.clone(),
+            None => self.get_current_user().await?.account_id,
+        };
+        if watch {
+            self.add_watcher(key, &account_id).await
+        } else {
+            self.remove_watcher(key, &account_id).await
+        }
+    }
+
+    /// Vote for an issue or remove the current user's vote.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The issue key (e.g., "PROJ-123")
+    /// * `vote` - True to vote for the issue, false to remove the vote
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if voting is disabled or the user reported the issue.
+    #[instrument(skip(self), fields(issue_key = %key))]
+    pub async fn set_vote(&self, key: &str, vote: bool) -> Result<()> {
+        info!("Setting vote on issue {} to {}", key, vote);
+        let url = format!("{}/rest/api/3/issue/{}/votes", self.base_url, key);
+        if vote {
+            self.post_no_content(&url, &serde_json::json!({})).await?;
+        } else {
+            self.delete(&url).await?;
+        }
+        info!("Successfully updated vote on issue {}", key);
+        Ok(())
+    }
+
     // ========================================================================
     // Changelog Operations
     // ========================================================================
@@ -1499,6 +1912,60 @@ impl JiraClient {
         debug!("Found {} issue suggestions", suggestions.len());
         Ok(suggestions)
     }
+
+    /// Get the fields, operators and functions available for JQL
+    /// autocomplete.
+    #[instrument(skip(self))]
+    pub async fn get_jql_autocomplete_data(&self) -> Result<JqlAutocompleteData> {
+        debug!("Fetching JQL autocomplete data");
+        let url = format!("{}/rest/api/3/jql/autocompletedata", self.base_url);
+        let data: JqlAutocompleteData = self.get(&url).await?;
+        debug!(
+            "Found {} JQL fields and {} functions",
+            data.visible_field_names.len(),
+            data.visible_function_names.len()
+        );
+        Ok(data)
+    }
+
+    /// Get suggested values for a JQL field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The field as used in queries (e.g. `status`)
+    /// * `field_value` - The part of the value typed so far
+    #[instrument(skip(self))]
+    pub async fn get_jql_suggestions(
+        &self,
+        field_name: &str,
+        field_value: &str,
+    ) -> Result<Vec<JqlSuggestion>> {
+        debug!("Fetching JQL value suggestions");
+        let url = format!(
+            "{}/rest/api/3/jql/autocompletedata/suggestions?fieldName={}&fieldValue={}",
+            self.base_url,
+            urlencoding::encode(field_name),
+            urlencoding::encode(field_value)
+        );
+        let response: JqlSuggestionsResponse = self.get(&url).await?;
+        debug!("Found {} JQL value suggestions", response.results.len());
+        Ok(response.results)
+    }
+
+    /// Validate a JQL query, returning the errors found in it.
+    #[instrument(skip(self), fields(query = %query))]
+    pub async fn parse_jql(&self, query: &str) -> Result<Vec<String>> {
+        debug!("Parsing JQL");
+        let url = format!("{}/rest/api/3/jql/parse?validation=strict", self.base_url);
+        let body = serde_json::json!({ "queries": [query] });
+        let response: JqlParseResponse = self.post(&url, &body).await?;
+        Ok(response
+            .queries
+            .into_iter()
+            .next()
+            .map(|parsed| parsed.errors)
+            .unwrap_or_default())
+    }
 }
 
 /// Normalize the base URL by removing trailing slashes and ensuring HTTPS.
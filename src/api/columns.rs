@@ -0,0 +1,376 @@
+//! Issue list column fields.
+//!
+//! Maps the fields that can be shown as issue list columns to their config
+//! identifiers and JQL names. Sprint and story points are custom fields whose
+//! IDs differ between JIRA instances, so they are resolved from the
+//! instance's field list into [`FieldIds`].
+
+use serde::{Deserialize, Serialize};
+
+use super::types::Field;
+
+/// A field that can be shown as a column and sorted by in the issue list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnField {
+    Key,
+    Summary,
+    Status,
+    Assignee,
+    Priority,
+    IssueType,
+    Reporter,
+    Created,
+    Updated,
+    DueDate,
+    StoryPoints,
+    Sprint,
+    Labels,
+    Components,
+    /// A custom field, identified by its field ID (e.g. "customfield_10050").
+    CustomField(String),
+}
+
+impl ColumnField {
+    /// Parse a column from its config field identifier.
+    ///
+    /// Accepts the built-in names (`key`, `created`, `story_points`, ...) as well
+    /// as custom field IDs in either `customfield_10050` or `cf[10050]` form.
+    pub fn from_field_id(id: &str) -> Option<Self> {
+        let column = match id.trim().to_lowercase().as_str() {
+            "key" => ColumnField::Key,
+            "summary" => ColumnField::Summary,
+            "status" => ColumnField::Status,
+            "assignee" => ColumnField::Assignee,
+            "priority" => ColumnField::Priority,
+            "type" | "issuetype" => ColumnField::IssueType,
+            "reporter" => ColumnField::Reporter,
+            "created" => ColumnField::Created,
+            "updated" => ColumnField::Updated,
+            "due" | "duedate" => ColumnField::DueDate,
+            "story_points" | "storypoints" => ColumnField::StoryPoints,
+            "sprint" => ColumnField::Sprint,
+            "labels" => ColumnField::Labels,
+            "components" | "component" => ColumnField::Components,
+            other => {
+                let number = other
+                    .strip_prefix("customfield_")
+                    .or_else(|| other.strip_prefix("cf[").and_then(|s| s.strip_suffix(']')))?;
+                if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+                ColumnField::CustomField(format!("customfield_{}", number))
+            }
+        };
+        Some(column)
+    }
+
+    /// Get the config field identifier, as accepted by `from_field_id`.
+    pub fn field_id(&self) -> String {
+        match self {
+            ColumnField::Key => "key".to_string(),
+            ColumnField::Summary => "summary".to_string(),
+            ColumnField::Status => "status".to_string(),
+            ColumnField::Assignee => "assignee".to_string(),
+            ColumnField::Priority => "priority".to_string(),
+            ColumnField::IssueType => "type".to_string(),
+            ColumnField::Reporter => "reporter".to_string(),
+            ColumnField::Created => "created".to_string(),
+            ColumnField::Updated => "updated".to_string(),
+            ColumnField::DueDate => "due".to_string(),
+            ColumnField::StoryPoints => "story_points".to_string(),
+            ColumnField::Sprint => "sprint".to_string(),
+            ColumnField::Labels => "labels".to_string(),
+            ColumnField::Components => "components".to_string(),
+            ColumnField::CustomField(id) => id.clone(),
+        }
+    }
+
+    /// Convert to JQL field name.
+    pub fn as_jql_field(&self, fields: &FieldIds) -> String {
+        match self {
+            ColumnField::Key => "key".to_string(),
+            ColumnField::Summary => "summary".to_string(),
+            ColumnField::Status => "status".to_string(),
+            ColumnField::Assignee => "assignee".to_string(),
+            ColumnField::Priority => "priority".to_string(),
+            ColumnField::IssueType => "issuetype".to_string(),
+            ColumnField::Reporter => "reporter".to_string(),
+            ColumnField::Created => "created".to_string(),
+            ColumnField::Updated => "updated".to_string(),
+            ColumnField::DueDate => "duedate".to_string(),
+            ColumnField::StoryPoints => custom_field_jql(&fields.story_points),
+            ColumnField::Sprint => "sprint".to_string(),
+            ColumnField::Labels => "labels".to_string(),
+            ColumnField::Components => "component".to_string(),
+            ColumnField::CustomField(id) => custom_field_jql(id),
+        }
+    }
+
+    /// Get display name for the column.
+    pub fn display_name(&self) -> String {
+        match self {
+            ColumnField::Key => "Key".to_string(),
+            ColumnField::Summary => "Summary".to_string(),
+            ColumnField::Status => "Status".to_string(),
+            ColumnField::Assignee => "Assignee".to_string(),
+            ColumnField::Priority => "Priority".to_string(),
+            ColumnField::IssueType => "Type".to_string(),
+            ColumnField::Reporter => "Reporter".to_string(),
+            ColumnField::Created => "Created".to_string(),
+            ColumnField::Updated => "Updated".to_string(),
+            ColumnField::DueDate => "Due".to_string(),
+            ColumnField::StoryPoints => "Points".to_string(),
+            ColumnField::Sprint => "Sprint".to_string(),
+            ColumnField::Labels => "Labels".to_string(),
+            ColumnField::Components => "Components".to_string(),
+            ColumnField::CustomField(id) => id.clone(),
+        }
+    }
+
+    /// Default column width in characters.
+    pub fn default_width(&self) -> u16 {
+        match self {
+            ColumnField::Key => 14, // PROJ-12345
+            ColumnField::Summary => 30,
+            ColumnField::Status => 15,
+            ColumnField::Assignee | ColumnField::Reporter => 20,
+            ColumnField::Priority => 10,
+            ColumnField::IssueType => 12,
+            ColumnField::Created | ColumnField::Updated => 9,
+            ColumnField::DueDate => 10,
+            ColumnField::StoryPoints => 7,
+            ColumnField::Sprint => 16,
+            ColumnField::Labels | ColumnField::Components => 18,
+            ColumnField::CustomField(_) => 15,
+        }
+    }
+
+    /// The columns shown when none are configured.
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            ColumnField::Key,
+            ColumnField::Summary,
+            ColumnField::Status,
+            ColumnField::Assignee,
+            ColumnField::Priority,
+        ]
+    }
+}
+
+/// Get the JQL name of a custom field from its ID.
+fn custom_field_jql(id: &str) -> String {
+    format!("cf[{}]", id.trim_start_matches("customfield_"))
+}
+
+/// A single column in the issue list.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ColumnConfig {
+    /// The field shown in this column.
+    ///
+    /// One of `key`, `summary`, `status`, `assignee`, `priority`, `type`,
+    /// `reporter`, `created`, `updated`, `due`, `story_points`, `sprint`,
+    /// `labels`, `components`, or a custom field ID such as `customfield_10050`.
+    pub field: String,
+
+    /// Column width in characters.
+    ///
+    /// Defaults to a width suited to the field. The summary column expands to
+    /// fill the remaining space when no width is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    /// Header title.
+    ///
+    /// Defaults to the field's display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl ColumnConfig {
+    /// Create a column with default width and title.
+    #[cfg(test)]
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            width: None,
+            title: None,
+        }
+    }
+}
+
+/// Custom field type of the sprint field.
+const SPRINT_FIELD_TYPE: &str = "com.pyxis.greenhopper.jira:gh-sprint";
+
+/// Custom field type of the story point estimate field of JIRA Software.
+const STORY_POINTS_FIELD_TYPE: &str = "com.pyxis.greenhopper.jira:jsw-story-points";
+
+/// Names the story points field goes by, in order of preference.
+const STORY_POINTS_FIELD_NAMES: [&str; 2] = ["Story Points", "Story point estimate"];
+
+/// IDs of the custom fields the issue list shows by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldIds {
+    /// Field ID of the story points estimate.
+    pub story_points: String,
+    /// Field ID of the sprint.
+    pub sprint: String,
+}
+
+impl Default for FieldIds {
+    /// The IDs JIRA Cloud assigns, used until the instance's fields are known.
+    fn default() -> Self {
+        Self {
+            story_points: "customfield_10016".to_string(),
+            sprint: "customfield_10020".to_string(),
+        }
+    }
+}
+
+impl FieldIds {
+    /// Resolve the field IDs from an instance's field list.
+    ///
+    /// The sprint field is found by its custom field type. The story points
+    /// field is found by type, then by name. Fields that can't be found keep
+    /// their default ID.
+    pub fn from_fields(fields: &[Field]) -> Self {
+        let custom_type = |custom: &str| {
+            fields
+                .iter()
+                .find(|f| f.schema.custom.as_deref() == Some(custom))
+                .map(|f| f.id.clone())
+        };
+        let named = || {
+            STORY_POINTS_FIELD_NAMES.iter().find_map(|name| {
+                fields
+                    .iter()
+                    .find(|f| f.name.eq_ignore_ascii_case(name))
+                    .map(|f| f.id.clone())
+            })
+        };
+
+        let defaults = Self::default();
+        Self {
+            story_points: custom_type(STORY_POINTS_FIELD_TYPE)
+                .or_else(named)
+                .unwrap_or(defaults.story_points),
+            sprint: custom_type(SPRINT_FIELD_TYPE).unwrap_or(defaults.sprint),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(id: &str, name: &str, custom: Option<&str>) -> Field {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "schema": { "type": "any", "custom": custom },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_as_jql_field() {
+        let fields = FieldIds::default();
+        assert_eq!(ColumnField::Key.as_jql_field(&fields), "key");
+        assert_eq!(ColumnField::Summary.as_jql_field(&fields), "summary");
+        assert_eq!(ColumnField::Status.as_jql_field(&fields), "status");
+        assert_eq!(ColumnField::Assignee.as_jql_field(&fields), "assignee");
+        assert_eq!(ColumnField::Priority.as_jql_field(&fields), "priority");
+        assert_eq!(ColumnField::IssueType.as_jql_field(&fields), "issuetype");
+        assert_eq!(ColumnField::Created.as_jql_field(&fields), "created");
+        assert_eq!(ColumnField::DueDate.as_jql_field(&fields), "duedate");
+        assert_eq!(ColumnField::StoryPoints.as_jql_field(&fields), "cf[10016]");
+        assert_eq!(ColumnField::Components.as_jql_field(&fields), "component");
+        assert_eq!(
+            ColumnField::CustomField("customfield_10050".to_string()).as_jql_field(&fields),
+            "cf[10050]"
+        );
+    }
+
+    #[test]
+    fn test_story_points_jql_uses_resolved_id() {
+        let fields = FieldIds {
+            story_points: "customfield_10106".to_string(),
+            ..FieldIds::default()
+        };
+        assert_eq!(ColumnField::StoryPoints.as_jql_field(&fields), "cf[10106]");
+    }
+
+    #[test]
+    fn test_display_name() {
+        assert_eq!(ColumnField::Key.display_name(), "Key");
+        assert_eq!(ColumnField::Summary.display_name(), "Summary");
+        assert_eq!(ColumnField::Status.display_name(), "Status");
+        assert_eq!(ColumnField::Assignee.display_name(), "Assignee");
+        assert_eq!(ColumnField::Priority.display_name(), "Priority");
+    }
+
+    #[test]
+    fn test_from_field_id() {
+        assert_eq!(ColumnField::from_field_id("key"), Some(ColumnField::Key));
+        assert_eq!(
+            ColumnField::from_field_id("Type"),
+            Some(ColumnField::IssueType)
+        );
+        assert_eq!(
+            ColumnField::from_field_id("due"),
+            Some(ColumnField::DueDate)
+        );
+        assert_eq!(
+            ColumnField::from_field_id("story_points"),
+            Some(ColumnField::StoryPoints)
+        );
+        assert_eq!(
+            ColumnField::from_field_id("customfield_10050"),
+            Some(ColumnField::CustomField("customfield_10050".to_string()))
+        );
+        assert_eq!(
+            ColumnField::from_field_id("cf[10050]"),
+            Some(ColumnField::CustomField("customfield_10050".to_string()))
+        );
+        assert_eq!(ColumnField::from_field_id("customfield_"), None);
+        assert_eq!(ColumnField::from_field_id("customfield_abc"), None);
+        assert_eq!(ColumnField::from_field_id("bogus"), None);
+    }
+
+    #[test]
+    fn test_field_ids_from_fields() {
+        let fields = vec![
+            field("summary", "Summary", None),
+            field("customfield_10100", "Story Points", Some("float")),
+            field("customfield_10200", "Sprint", Some(SPRINT_FIELD_TYPE)),
+        ];
+        assert_eq!(
+            FieldIds::from_fields(&fields),
+            FieldIds {
+                story_points: "customfield_10100".to_string(),
+                sprint: "customfield_10200".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_field_ids_prefer_story_points_type() {
+        let fields = vec![
+            field("customfield_10100", "Story Points", Some("float")),
+            field(
+                "customfield_10300",
+                "Story point estimate",
+                Some(STORY_POINTS_FIELD_TYPE),
+            ),
+        ];
+        assert_eq!(
+            FieldIds::from_fields(&fields).story_points,
+            "customfield_10300"
+        );
+    }
+
+    #[test]
+    fn test_field_ids_default_when_missing() {
+        let fields = vec![field("summary", "Summary", None)];
+        assert_eq!(FieldIds::from_fields(&fields), FieldIds::default());
+    }
+}
@@ -0,0 +1,936 @@
+//! A small JQL syntax tree with a parser and serializer.
+//!
+//! This covers the subset of JQL the app builds and reads back: clauses
+//! combined with `AND`, `OR` and `NOT`, value lists, function calls and an
+//! `ORDER BY` suffix. Serializing an [`Expr`] quotes and escapes values and
+//! adds the parentheses operator precedence needs, so a value containing `"`
+//! or `\` can never break the query.
+//!
+//! History operators such as `WAS` and `CHANGED` are not supported.
+
+use std::fmt;
+use std::ops::Range;
+
+/// A JQL token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// A field name, keyword, function name or unquoted value.
+    Word(&'a str),
+    /// A quoted string with its quotes (the closing one may be missing).
+    Quoted(&'a str),
+    /// A comparison operator such as `=` or `!~`.
+    Operator(&'a str),
+    /// `(`
+    Open,
+    /// `)`
+    Close,
+    /// `,`
+    Comma,
+}
+
+impl<'a> Token<'a> {
+    /// Get the text of a word or quoted string.
+    pub fn text(&self) -> Option<&'a str> {
+        match self {
+            Token::Word(text) | Token::Quoted(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Check whether the token is the given keyword, ignoring case.
+    pub fn is(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+fn is_operator_char(c: char) -> bool {
+    matches!(c, '=' | '!' | '~' | '<' | '>')
+}
+
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !is_operator_char(c) && !matches!(c, '(' | ')' | ',' | '"' | '\'')
+}
+
+/// Split JQL into tokens with their byte ranges.
+pub fn tokenize(jql: &str) -> Vec<(Token<'_>, Range<usize>)> {
+    let mut tokens = Vec::new();
+    let mut chars = jql.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            '"' | '\'' => {
+                let mut end = jql.len();
+                let mut escaped = false;
+                for (i, next) in chars.by_ref() {
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == c {
+                        end = i + 1;
+                        break;
+                    }
+                }
+                tokens.push((Token::Quoted(&jql[start..end]), start..end));
+                continue;
+            }
+            c => {
+                let in_token = if is_operator_char(c) {
+                    is_operator_char
+                } else {
+                    is_word_char
+                };
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, next)) = chars.peek() {
+                    if !in_token(next) {
+                        break;
+                    }
+                    end = i + next.len_utf8();
+                    chars.next();
+                }
+                let text = &jql[start..end];
+                let token = if is_operator_char(c) {
+                    Token::Operator(text)
+                } else {
+                    Token::Word(text)
+                };
+                tokens.push((token, start..end));
+                continue;
+            }
+        };
+        tokens.push((token, start..start + 1));
+    }
+    tokens
+}
+
+/// Words that must be quoted when used as a field name or value.
+const RESERVED: &[&str] = &[
+    "and", "or", "not", "in", "is", "was", "changed", "order", "by", "asc", "desc", "empty", "null",
+];
+
+/// Check whether a string can be written without quotes.
+fn is_bare(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '@'))
+        && !RESERVED.contains(&text.to_lowercase().as_str())
+}
+
+/// Write a string as a quoted JQL string, escaping as needed.
+fn write_quoted(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in text.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+/// Write a field name or unquoted value, quoting it if necessary.
+fn write_word(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    if is_bare(text) {
+        f.write_str(text)
+    } else {
+        write_quoted(f, text)
+    }
+}
+
+/// Remove the quotes from a quoted token and resolve its escapes.
+fn unquote(quoted: &str) -> Result<String, String> {
+    let mut chars = quoted.chars();
+    let quote = chars.next().unwrap_or('"');
+    let mut text = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => text.push('\n'),
+                Some('r') => text.push('\r'),
+                Some('t') => text.push('\t'),
+                Some(c) => text.push(c),
+                None => break,
+            },
+            c if c == quote => return Ok(text),
+            c => text.push(c),
+        }
+    }
+    Err(format!("unterminated string {}", quoted))
+}
+
+/// A JQL value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// A string, always written quoted.
+    Text(String),
+    /// A number, key or other bare word, quoted only if it has to be.
+    Word(String),
+    /// A function call such as `currentUser()` or `startOfDay(-1d)`.
+    Function { name: String, args: Vec<Value> },
+    /// `EMPTY`
+    Empty,
+}
+
+impl Value {
+    /// Create a quoted string value.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// Create a function call without arguments.
+    pub fn function(name: impl Into<String>) -> Self {
+        Self::Function {
+            name: name.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Get the string of a text or word value.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Text(text) | Value::Word(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Check whether the value is a call to the given function without
+    /// arguments, ignoring case.
+    pub fn is_call(&self, function: &str) -> bool {
+        match self {
+            Value::Function { name, args } => {
+                args.is_empty() && name.eq_ignore_ascii_case(function)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Text(text) => write_quoted(f, text),
+            Value::Word(word) => write_word(f, word),
+            Value::Function { name, args } => {
+                f.write_str(name)?;
+                f.write_str("(")?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                f.write_str(")")
+            }
+            Value::Empty => f.write_str("EMPTY"),
+        }
+    }
+}
+
+/// A clause operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Equals,
+    NotEquals,
+    Contains,
+    NotContains,
+    GreaterThan,
+    GreaterThanOrEquals,
+    LessThan,
+    LessThanOrEquals,
+    In,
+    NotIn,
+    Is,
+    IsNot,
+}
+
+impl Operator {
+    /// Get the operator's JQL text.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Equals => "=",
+            Operator::NotEquals => "!=",
+            Operator::Contains => "~",
+            Operator::NotContains => "!~",
+            Operator::GreaterThan => ">",
+            Operator::GreaterThanOrEquals => ">=",
+            Operator::LessThan => "<",
+            Operator::LessThanOrEquals => "<=",
+            Operator::In => "IN",
+            Operator::NotIn => "NOT IN",
+            Operator::Is => "IS",
+            Operator::IsNot => "IS NOT",
+        }
+    }
+
+    /// Get the operator matching the opposite condition, if there is one.
+    pub fn negated(&self) -> Option<Self> {
+        match self {
+            Operator::Equals => Some(Operator::NotEquals),
+            Operator::NotEquals => Some(Operator::Equals),
+            Operator::Contains => Some(Operator::NotContains),
+            Operator::NotContains => Some(Operator::Contains),
+            Operator::In => Some(Operator::NotIn),
+            Operator::NotIn => Some(Operator::In),
+            Operator::Is => Some(Operator::IsNot),
+            Operator::IsNot => Some(Operator::Is),
+            _ => None,
+        }
+    }
+
+    /// Check whether the operator is a negative one such as `!=` or `NOT IN`.
+    pub fn is_negative(&self) -> bool {
+        matches!(
+            self,
+            Operator::NotEquals | Operator::NotContains | Operator::NotIn | Operator::IsNot
+        )
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The right-hand side of a clause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operand {
+    /// A single value.
+    Single(Value),
+    /// A parenthesized list of values.
+    List(Vec<Value>),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Single(value) => write!(f, "{}", value),
+            Operand::List(values) => {
+                f.write_str("(")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// A single `field operator operand` comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clause {
+    /// The field name.
+    pub field: String,
+    /// The comparison operator.
+    pub operator: Operator,
+    /// The value or values compared against.
+    pub operand: Operand,
+}
+
+impl Clause {
+    /// Create a clause comparing a field with a single value.
+    pub fn new(field: impl Into<String>, operator: Operator, value: Value) -> Self {
+        Self {
+            field: field.into(),
+            operator,
+            operand: Operand::Single(value),
+        }
+    }
+
+    /// Create a clause comparing a field with a list of values.
+    pub fn list(field: impl Into<String>, operator: Operator, values: Vec<Value>) -> Self {
+        Self {
+            field: field.into(),
+            operator,
+            operand: Operand::List(values),
+        }
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_word(f, &self.field)?;
+        write!(f, " {} {}", self.operator, self.operand)
+    }
+}
+
+/// A JQL condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// A single comparison.
+    Clause(Clause),
+    /// All of the conditions hold.
+    And(Vec<Expr>),
+    /// Any of the conditions holds.
+    Or(Vec<Expr>),
+    /// The condition does not hold.
+    Not(Box<Expr>),
+}
+
+impl Expr {
+    /// Combine conditions with `AND`.
+    ///
+    /// Returns `None` for no conditions and the condition itself for one.
+    pub fn and(mut exprs: Vec<Expr>) -> Option<Expr> {
+        match exprs.len() {
+            0 => None,
+            1 => exprs.pop(),
+            _ => Some(Expr::And(exprs)),
+        }
+    }
+
+    /// Get the conditions that must all hold for this one to hold.
+    pub fn conjuncts(&self) -> &[Expr] {
+        match self {
+            Expr::And(exprs) => exprs,
+            expr => std::slice::from_ref(expr),
+        }
+    }
+
+    /// Binding strength used to decide where parentheses are needed.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Or(_) => 0,
+            Expr::And(_) => 1,
+            Expr::Not(_) | Expr::Clause(_) => 2,
+        }
+    }
+
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>, precedence: u8) -> fmt::Result {
+        if self.precedence() < precedence {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+
+    fn write_joined(
+        f: &mut fmt::Formatter<'_>,
+        exprs: &[Expr],
+        keyword: &str,
+        precedence: u8,
+    ) -> fmt::Result {
+        for (i, expr) in exprs.iter().enumerate() {
+            if i > 0 {
+                write!(f, " {} ", keyword)?;
+            }
+            // Nested groups of the same kind keep their parentheses
+            expr.write_operand(f, precedence + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Clause> for Expr {
+    fn from(clause: Clause) -> Self {
+        Expr::Clause(clause)
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Clause(clause) => write!(f, "{}", clause),
+            Expr::And(exprs) => Self::write_joined(f, exprs, "AND", 1),
+            Expr::Or(exprs) => Self::write_joined(f, exprs, "OR", 0),
+            Expr::Not(expr) => {
+                f.write_str("NOT ")?;
+                expr.write_operand(f, 2)
+            }
+        }
+    }
+}
+
+/// A field to sort by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    /// The field name.
+    pub field: String,
+    /// Whether to sort in descending order.
+    pub descending: bool,
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_word(f, &self.field)?;
+        if self.descending {
+            f.write_str(" DESC")?;
+        }
+        Ok(())
+    }
+}
+
+/// A complete JQL query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    /// The condition, if any.
+    pub filter: Option<Expr>,
+    /// The `ORDER BY` fields.
+    pub order_by: Vec<SortKey>,
+}
+
+impl Query {
+    /// Parse a JQL query.
+    pub fn parse(jql: &str) -> Result<Self, String> {
+        let tokens: Vec<Token<'_>> = tokenize(jql).into_iter().map(|(token, _)| token).collect();
+        let mut parser = Parser { tokens, pos: 0 };
+        let query = parser.query()?;
+        match parser.peek() {
+            None => Ok(query),
+            Some(token) => Err(format!("unexpected {}", describe(&token))),
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(filter) = &self.filter {
+            write!(f, "{}", filter)?;
+            if !self.order_by.is_empty() {
+                f.write_str(" ")?;
+            }
+        }
+        if !self.order_by.is_empty() {
+            f.write_str("ORDER BY ")?;
+            for (i, key) in self.order_by.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{}", key)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse a single JQL value such as `"In Progress"` or `startOfDay(-1d)`.
+pub fn parse_value(jql: &str) -> Result<Value, String> {
+    let tokens: Vec<Token<'_>> = tokenize(jql).into_iter().map(|(token, _)| token).collect();
+    let mut parser = Parser { tokens, pos: 0 };
+    let value = parser.value()?;
+    match parser.peek() {
+        None => Ok(value),
+        Some(token) => Err(format!("unexpected {}", describe(&token))),
+    }
+}
+
+/// Describe a token for an error message.
+fn describe(token: &Token<'_>) -> String {
+    match token {
+        Token::Word(text) | Token::Quoted(text) | Token::Operator(text) => format!("'{}'", text),
+        Token::Open => "'('".to_string(),
+        Token::Close => "')'".to_string(),
+        Token::Comma => "','".to_string(),
+    }
+}
+
+/// Recursive descent parser over a token list.
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    /// Consume the next token if it is the given keyword.
+    fn eat(&mut self, keyword: &str) -> bool {
+        let found = self.peek().is_some_and(|token| token.is(keyword));
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, expected: Token<'_>, what: &str) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format!("expected {}, found {}", what, describe(&token))),
+            None => Err(format!("expected {}", what)),
+        }
+    }
+
+    fn query(&mut self) -> Result<Query, String> {
+        let filter = match self.peek() {
+            None => None,
+            Some(token) if token.is("order") => None,
+            Some(_) => Some(self.or()?),
+        };
+        let mut order_by = Vec::new();
+        if self.eat("order") {
+            if !self.eat("by") {
+                return Err("expected BY after ORDER".to_string());
+            }
+            loop {
+                let field = self.name("a field to sort by")?;
+                let descending = if self.eat("desc") {
+                    true
+                } else {
+                    self.eat("asc");
+                    false
+                };
+                order_by.push(SortKey { field, descending });
+                if self.peek() != Some(Token::Comma) {
+                    break;
+                }
+                self.pos += 1;
+            }
+        }
+        Ok(Query { filter, order_by })
+    }
+
+    fn or(&mut self) -> Result<Expr, String> {
+        let mut exprs = vec![self.and()?];
+        while self.eat("or") {
+            exprs.push(self.and()?);
+        }
+        Ok(if exprs.len() == 1 {
+            exprs.remove(0)
+        } else {
+            Expr::Or(exprs)
+        })
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut exprs = vec![self.not()?];
+        while self.eat("and") {
+            exprs.push(self.not()?);
+        }
+        Ok(Expr::and(exprs).expect("at least one condition"))
+    }
+
+    fn not(&mut self) -> Result<Expr, String> {
+        if self.eat("not") {
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        if self.peek() == Some(Token::Open) {
+            self.pos += 1;
+            let expr = self.or()?;
+            self.expect(Token::Close, "')'")?;
+            return Ok(expr);
+        }
+        self.clause().map(Expr::Clause)
+    }
+
+    /// Parse a field name or sort field.
+    fn name(&mut self, what: &str) -> Result<String, String> {
+        match self.next() {
+            Some(Token::Word(word)) if !RESERVED.contains(&word.to_lowercase().as_str()) => {
+                Ok(word.to_string())
+            }
+            Some(Token::Quoted(quoted)) => unquote(quoted),
+            Some(token) => Err(format!("expected {}, found {}", what, describe(&token))),
+            None => Err(format!("expected {}", what)),
+        }
+    }
+
+    fn clause(&mut self) -> Result<Clause, String> {
+        let field = self.name("a field")?;
+        let operator = match self.next() {
+            Some(Token::Operator(text)) => match text {
+                "=" => Operator::Equals,
+                "!=" => Operator::NotEquals,
+                "~" => Operator::Contains,
+                "!~" => Operator::NotContains,
+                ">" => Operator::GreaterThan,
+                ">=" => Operator::GreaterThanOrEquals,
+                "<" => Operator::LessThan,
+                "<=" => Operator::LessThanOrEquals,
+                _ => return Err(format!("unknown operator '{}'", text)),
+            },
+            Some(token) if token.is("in") => Operator::In,
+            Some(token) if token.is("is") => {
+                if self.eat("not") {
+                    Operator::IsNot
+                } else {
+                    Operator::Is
+                }
+            }
+            Some(token) if token.is("not") => {
+                if !self.eat("in") {
+                    return Err(format!("expected IN after NOT in clause on {}", field));
+                }
+                Operator::NotIn
+            }
+            Some(token) => {
+                return Err(format!(
+                    "unsupported operator {} after {}",
+                    describe(&token),
+                    field
+                ))
+            }
+            None => return Err(format!("expected an operator after {}", field)),
+        };
+        let operand = if matches!(operator, Operator::In | Operator::NotIn)
+            && self.peek() == Some(Token::Open)
+        {
+            Operand::List(self.list()?)
+        } else {
+            Operand::Single(self.value()?)
+        };
+        Ok(Clause {
+            field,
+            operator,
+            operand,
+        })
+    }
+
+    /// Parse a parenthesized, comma separated list of values.
+    fn list(&mut self) -> Result<Vec<Value>, String> {
+        self.expect(Token::Open, "'('")?;
+        let mut values = Vec::new();
+        if self.peek() == Some(Token::Close) {
+            self.pos += 1;
+            return Ok(values);
+        }
+        loop {
+            values.push(self.value()?);
+            match self.next() {
+                Some(Token::Comma) => {}
+                Some(Token::Close) => return Ok(values),
+                Some(token) => {
+                    return Err(format!("expected ',' or ')', found {}", describe(&token)))
+                }
+                None => return Err("expected ')'".to_string()),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.next() {
+            Some(Token::Quoted(quoted)) => unquote(quoted).map(Value::Text),
+            Some(token) if token.is("empty") || token.is("null") => Ok(Value::Empty),
+            Some(Token::Word(word)) if self.peek() == Some(Token::Open) => Ok(Value::Function {
+                name: word.to_string(),
+                args: self.list()?,
+            }),
+            Some(Token::Word(word)) if !RESERVED.contains(&word.to_lowercase().as_str()) => {
+                Ok(Value::Word(word.to_string()))
+            }
+            Some(token) => Err(format!("expected a value, found {}", describe(&token))),
+            None => Err("expected a value".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(jql: &str) -> Expr {
+        Query::parse(jql).unwrap().filter.unwrap()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let tokens: Vec<Token<'_>> = tokenize(r#"status IN ("To Do", x)!=~"#)
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Word("status"),
+                Token::Word("IN"),
+                Token::Open,
+                Token::Quoted("\"To Do\""),
+                Token::Comma,
+                Token::Word("x"),
+                Token::Close,
+                Token::Operator("!=~"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_value_escaping() {
+        assert_eq!(Value::text("Open").to_string(), "\"Open\"");
+        assert_eq!(
+            Value::text(r#"say "hi" \ bye"#).to_string(),
+            r#""say \"hi\" \\ bye""#
+        );
+        assert_eq!(Value::Word("PROJ-1".to_string()).to_string(), "PROJ-1");
+        assert_eq!(
+            Value::Word("In Progress".to_string()).to_string(),
+            "\"In Progress\""
+        );
+        assert_eq!(Value::Word("empty".to_string()).to_string(), "\"empty\"");
+        assert_eq!(Value::function("currentUser").to_string(), "currentUser()");
+        assert_eq!(Value::Empty.to_string(), "EMPTY");
+    }
+
+    #[test]
+    fn test_clause_display_quotes_field() {
+        let clause = Clause::new(
+            "Story Points",
+            Operator::GreaterThan,
+            Value::Word("3".to_string()),
+        );
+        assert_eq!(clause.to_string(), r#""Story Points" > 3"#);
+    }
+
+    #[test]
+    fn test_expr_display_adds_parentheses() {
+        let status = Expr::from(Clause::new("status", Operator::Equals, Value::text("Open")));
+        let mine = Expr::from(Clause::new(
+            "assignee",
+            Operator::Equals,
+            Value::function("currentUser"),
+        ));
+        let bug = Expr::from(Clause::new(
+            "type",
+            Operator::Equals,
+            Value::Word("Bug".to_string()),
+        ));
+
+        let expr = Expr::And(vec![
+            Expr::Or(vec![status.clone(), mine.clone()]),
+            Expr::Not(Box::new(bug.clone())),
+        ]);
+        assert_eq!(
+            expr.to_string(),
+            r#"(status = "Open" OR assignee = currentUser()) AND NOT type = Bug"#
+        );
+
+        let expr = Expr::Or(vec![Expr::And(vec![status, mine]), bug.clone()]);
+        assert_eq!(
+            expr.to_string(),
+            r#"status = "Open" AND assignee = currentUser() OR type = Bug"#
+        );
+
+        let expr = Expr::Not(Box::new(Expr::Or(vec![bug.clone(), bug])));
+        assert_eq!(expr.to_string(), "NOT (type = Bug OR type = Bug)");
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        let expr = parse("a = 1 OR b = 2 AND NOT c = 3");
+        let Expr::Or(exprs) = &expr else {
+            panic!("expected OR, got {:?}", expr);
+        };
+        assert_eq!(exprs.len(), 2);
+        assert!(matches!(&exprs[1], Expr::And(and) if matches!(and[1], Expr::Not(_))));
+    }
+
+    #[test]
+    fn test_parse_operators_and_values() {
+        let expr = parse(
+            r#"status NOT IN ("Done", Closed) and assignee is not empty AND created >= startOfDay(-7d) and summary !~ 'it\'s'"#,
+        );
+        assert_eq!(
+            expr.conjuncts(),
+            &[
+                Expr::from(Clause::list(
+                    "status",
+                    Operator::NotIn,
+                    vec![Value::text("Done"), Value::Word("Closed".to_string())]
+                )),
+                Expr::from(Clause::new("assignee", Operator::IsNot, Value::Empty)),
+                Expr::from(Clause::new(
+                    "created",
+                    Operator::GreaterThanOrEquals,
+                    Value::Function {
+                        name: "startOfDay".to_string(),
+                        args: vec![Value::Word("-7d".to_string())],
+                    }
+                )),
+                Expr::from(Clause::new(
+                    "summary",
+                    Operator::NotContains,
+                    Value::text("it's")
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_order_by() {
+        let query = Query::parse("project = A ORDER BY priority DESC, created asc").unwrap();
+        assert!(query.filter.is_some());
+        assert_eq!(
+            query.order_by,
+            vec![
+                SortKey {
+                    field: "priority".to_string(),
+                    descending: true
+                },
+                SortKey {
+                    field: "created".to_string(),
+                    descending: false
+                },
+            ]
+        );
+        assert_eq!(
+            query.to_string(),
+            "project = A ORDER BY priority DESC, created"
+        );
+
+        let query = Query::parse("ORDER BY rank").unwrap();
+        assert!(query.filter.is_none());
+        assert_eq!(Query::parse("").unwrap(), Query::default());
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for jql in [
+            r#"status IN ("Open", "In Progress") AND assignee = currentUser()"#,
+            r#"labels = "a \"b\" \\ c" OR NOT (priority = High AND type != Bug)"#,
+            r#"project = "PROJ" AND sprint IN openSprints() AND text ~ "crash""#,
+        ] {
+            assert_eq!(Query::parse(jql).unwrap().to_string(), jql);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Query::parse("status").unwrap_err(),
+            "expected an operator after status"
+        );
+        assert_eq!(Query::parse("status =").unwrap_err(), "expected a value");
+        assert_eq!(Query::parse("(a = 1").unwrap_err(), "expected ')'");
+        assert_eq!(Query::parse("a = 1 b = 2").unwrap_err(), "unexpected 'b'");
+        assert_eq!(
+            Query::parse(r#"a = "open"#).unwrap_err(),
+            "unterminated string \"open"
+        );
+        assert_eq!(
+            Query::parse("status was Open").unwrap_err(),
+            "unsupported operator 'was' after status"
+        );
+        assert_eq!(
+            Query::parse("a IN (1 2)").unwrap_err(),
+            "expected ',' or ')', found '2'"
+        );
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("-7d").unwrap(), Value::Word("-7d".to_string()));
+        assert_eq!(
+            parse_value("\"2024-01-01\"").unwrap(),
+            Value::text("2024-01-01")
+        );
+        assert!(parse_value("startOfWeek()").unwrap().is_call("startofweek"));
+        assert!(parse_value("a b").is_err());
+    }
+}
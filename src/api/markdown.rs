@@ -0,0 +1,1255 @@
+//! Markdown conversion for Atlassian Document Format (ADF) content.
+//!
+//! Descriptions and comments are edited as Markdown and converted back to ADF
+//! on save, so formatting survives a round trip through the editor.
+//!
+//! Supported Markdown:
+//!
+//! - Headings (`#` to `######`), paragraphs, and quotes (`>`)
+//! - Bullet (`-`) and numbered (`1.`) lists, nested by indentation
+//! - Fenced code blocks with a language, horizontal rules (`---`)
+//! - Pipe tables with a header row
+//! - `**bold**`, `*italic*`, `~~strike~~`, `` `code` ``, and `[links](url)`
+//! - Mentions as `[@Name](mention:ACCOUNT_ID)` and smart links as `<url>`
+//!
+//! Line breaks inside a paragraph are kept as hard breaks. Any node without a
+//! Markdown equivalent (panels, status lozenges, media, ...) is written as an
+//! opaque fenced block holding the node's JSON:
+//!
+//! ````text
+//! ```adf
+//! {"type":"panel","attrs":{"panelType":"info"},"content":[...]}
+//! ```
+//! ````
+//!
+//! Such blocks are converted back verbatim.
+
+use serde_json::{json, Value};
+
+use super::adf::{attr, attr_str, content, marks, node_type};
+use super::types::AtlassianDoc;
+
+/// Language tag of fenced blocks holding raw ADF nodes.
+const OPAQUE_LANGUAGE: &str = "adf";
+
+/// Marks with Markdown delimiters, outermost first.
+const DELIMITED_MARKS: [(&str, &str); 3] = [("strong", "**"), ("em", "*"), ("strike", "~~")];
+
+/// Canonical order of marks on a text node.
+const MARK_ORDER: [&str; 5] = ["strong", "em", "strike", "code", "link"];
+
+impl AtlassianDoc {
+    /// Convert the document to Markdown for editing.
+    pub fn to_markdown(&self) -> String {
+        blocks_to_markdown(&self.content)
+    }
+
+    /// Create a document from Markdown.
+    pub fn from_markdown(markdown: &str) -> Self {
+        let lines: Vec<String> = markdown.lines().map(str::to_string).collect();
+        Self {
+            doc_type: "doc".to_string(),
+            version: Some(1),
+            content: parse_blocks(&lines),
+        }
+    }
+}
+
+// ============================================================================
+// ADF to Markdown
+// ============================================================================
+
+fn has_mark(node: &Value, mark: &str) -> bool {
+    marks(node).iter().any(|m| node_type(m) == mark)
+}
+
+fn link_href(node: &Value) -> Option<&str> {
+    marks(node)
+        .iter()
+        .find(|m| node_type(m) == "link")
+        .and_then(|m| attr_str(m, "href"))
+}
+
+/// Check whether a block node (and everything inside it) has a Markdown form.
+fn is_supported(node: &Value) -> bool {
+    match node_type(node) {
+        "paragraph" => marks(node).is_empty() && inlines_supported(content(node), true),
+        "heading" => {
+            let level = attr(node, "level").and_then(Value::as_u64).unwrap_or(0);
+            (1..=6).contains(&level)
+                && marks(node).is_empty()
+                && inlines_supported(content(node), false)
+        }
+        "bulletList" | "orderedList" => content(node).iter().all(|item| {
+            node_type(item) == "listItem"
+                && !content(item).is_empty()
+                && content(item).iter().all(is_supported)
+        }),
+        "codeBlock" => content(node).iter().all(|text| {
+            node_type(text) == "text"
+                && marks(text).is_empty()
+                && text
+                    .get("text")
+                    .and_then(Value::as_str)
+                    .unwrap_or("")
+                    .lines()
+                    .all(|line| !line.trim_start().starts_with("```"))
+        }),
+        "blockquote" => content(node).iter().all(is_supported),
+        "rule" => true,
+        "table" => table_supported(node),
+        _ => false,
+    }
+}
+
+fn inlines_supported(nodes: &[Value], allow_breaks: bool) -> bool {
+    nodes.iter().all(|node| match node_type(node) {
+        "text" => {
+            let text = node.get("text").and_then(Value::as_str).unwrap_or("");
+            !text.contains('\n')
+                && marks(node).iter().all(|mark| match node_type(mark) {
+                    "strong" | "em" | "strike" | "code" => true,
+                    "link" => attr_str(mark, "href").is_some_and(|href| !href.is_empty()),
+                    _ => false,
+                })
+        }
+        "hardBreak" => allow_breaks,
+        "mention" => attr_str(node, "id").is_some(),
+        "inlineCard" => attr_str(node, "url").is_some_and(is_autolink_url),
+        _ => false,
+    })
+}
+
+fn table_supported(node: &Value) -> bool {
+    let rows = content(node);
+    let Some(header) = rows.first() else {
+        return false;
+    };
+    let columns = content(header).len();
+    columns > 0
+        && rows.iter().enumerate().all(|(index, row)| {
+            let cell_type = if index == 0 {
+                "tableHeader"
+            } else {
+                "tableCell"
+            };
+            node_type(row) == "tableRow"
+                && content(row).len() == columns
+                && content(row).iter().all(|cell| {
+                    let span = |name| attr(cell, name).and_then(Value::as_u64).unwrap_or(1);
+                    node_type(cell) == cell_type
+                        && span("colspan") == 1
+                        && span("rowspan") == 1
+                        && attr(cell, "background").is_none()
+                        && content(cell).len() <= 1
+                        && content(cell).iter().all(|p| {
+                            node_type(p) == "paragraph"
+                                && marks(p).is_empty()
+                                && inlines_supported(content(p), false)
+                        })
+                })
+        })
+}
+
+fn is_autolink_url(url: &str) -> bool {
+    (url.starts_with("http://") || url.starts_with("https://"))
+        && !url.contains(|c: char| c.is_whitespace() || c == '>')
+}
+
+/// Convert block nodes to Markdown, separated by blank lines.
+fn blocks_to_markdown(nodes: &[Value]) -> String {
+    nodes
+        .iter()
+        .filter_map(block_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Convert a block node to Markdown, or `None` if it renders to nothing.
+fn block_to_markdown(node: &Value) -> Option<String> {
+    if !is_supported(node) {
+        let json = serde_json::to_string(node).unwrap_or_default();
+        return Some(format!("```{}\n{}\n```", OPAQUE_LANGUAGE, json));
+    }
+
+    let markdown = match node_type(node) {
+        "paragraph" => {
+            let text = inlines_to_markdown(content(node), false);
+            if text.trim().is_empty() {
+                return None;
+            }
+            text.lines()
+                .map(escape_line_start)
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        "heading" => {
+            let level = attr(node, "level").and_then(Value::as_u64).unwrap_or(1) as usize;
+            format!(
+                "{} {}",
+                "#".repeat(level),
+                inlines_to_markdown(content(node), false)
+            )
+        }
+        "bulletList" | "orderedList" => list_to_markdown(node),
+        "codeBlock" => {
+            let language = attr_str(node, "language").unwrap_or("");
+            let code: String = content(node)
+                .iter()
+                .filter_map(|text| text.get("text").and_then(Value::as_str))
+                .collect();
+            let code = code.strip_suffix('\n').unwrap_or(&code);
+            format!("```{}\n{}\n```", language, code)
+        }
+        "blockquote" => blocks_to_markdown(content(node))
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    ">".to_string()
+                } else {
+                    format!("> {}", line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "rule" => "---".to_string(),
+        "table" => table_to_markdown(node),
+        _ => return None,
+    };
+    Some(markdown)
+}
+
+fn list_to_markdown(node: &Value) -> String {
+    let ordered = node_type(node) == "orderedList";
+    let start = attr(node, "order").and_then(Value::as_u64).unwrap_or(1);
+
+    content(node)
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = if ordered {
+                format!("{}. ", start + i as u64)
+            } else {
+                "- ".to_string()
+            };
+            let indent = " ".repeat(marker.len());
+
+            // Nested lists follow their parent line directly (tight list)
+            let mut body = String::new();
+            for (j, child) in content(item).iter().enumerate() {
+                let Some(markdown) = block_to_markdown(child) else {
+                    continue;
+                };
+                if j > 0 && !body.is_empty() {
+                    let nested = matches!(node_type(child), "bulletList" | "orderedList");
+                    body.push_str(if nested { "\n" } else { "\n\n" });
+                }
+                body.push_str(&markdown);
+            }
+
+            let mut lines = body.lines();
+            let mut result = format!("{}{}", marker, lines.next().unwrap_or(""));
+            for line in lines {
+                result.push('\n');
+                if !line.is_empty() {
+                    result.push_str(&indent);
+                    result.push_str(line);
+                }
+            }
+            result.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn table_to_markdown(node: &Value) -> String {
+    let rows: Vec<Vec<String>> = content(node)
+        .iter()
+        .map(|row| {
+            content(row)
+                .iter()
+                .map(|cell| {
+                    content(cell)
+                        .first()
+                        .map(|p| inlines_to_markdown(content(p), true))
+                        .unwrap_or_default()
+                })
+                .collect()
+        })
+        .collect();
+
+    let format_row = |cells: &[String]| format!("| {} |", cells.join(" | "));
+    let mut lines = Vec::new();
+    if let Some(header) = rows.first() {
+        lines.push(format_row(header));
+        lines.push(format_row(&vec!["---".to_string(); header.len()]));
+    }
+    for row in rows.iter().skip(1) {
+        lines.push(format_row(row));
+    }
+    lines.join("\n")
+}
+
+/// Convert inline nodes to Markdown.
+///
+/// Consecutive text nodes sharing a link become one `[text](url)`; within a
+/// run, emphasis delimiters are opened and closed only where marks change, and
+/// whitespace is kept outside delimiters so they stay valid.
+fn inlines_to_markdown(nodes: &[Value], in_table: bool) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < nodes.len() {
+        match link_href(&nodes[i]).filter(|_| node_type(&nodes[i]) == "text") {
+            Some(href) => {
+                let start = i;
+                while i < nodes.len()
+                    && node_type(&nodes[i]) == "text"
+                    && link_href(&nodes[i]) == Some(href)
+                {
+                    i += 1;
+                }
+                out.push('[');
+                out.push_str(&marked_run(&nodes[start..i], in_table));
+                out.push_str("](");
+                out.push_str(href);
+                out.push(')');
+            }
+            None => {
+                let start = i;
+                while i < nodes.len()
+                    && !(node_type(&nodes[i]) == "text" && link_href(&nodes[i]).is_some())
+                {
+                    i += 1;
+                }
+                out.push_str(&marked_run(&nodes[start..i], in_table));
+            }
+        }
+    }
+    out
+}
+
+fn marked_run(nodes: &[Value], in_table: bool) -> String {
+    let mut out = String::new();
+    let mut open: Vec<&str> = Vec::new();
+    let mut pending_space = String::new();
+
+    let close_all = |open: &mut Vec<&str>, out: &mut String| {
+        while let Some(mark) = open.pop() {
+            out.push_str(delimiter(mark));
+        }
+    };
+
+    for node in nodes {
+        match node_type(node) {
+            "text" => {
+                let text = node.get("text").and_then(Value::as_str).unwrap_or("");
+                let is_code = has_mark(node, "code");
+                // Spaces inside code are part of the code, so they stay in the span
+                let core = if is_code { text } else { text.trim() };
+                if core.is_empty() {
+                    pending_space.push_str(text);
+                    continue;
+                }
+                let lead = &text[..text.len() - text.trim_start().len()];
+                let trail = &text[text.trim_end().len()..];
+                let (lead, trail) = if is_code { ("", "") } else { (lead, trail) };
+
+                let wanted: Vec<&str> = DELIMITED_MARKS
+                    .iter()
+                    .map(|(mark, _)| *mark)
+                    .filter(|mark| has_mark(node, mark))
+                    .collect();
+                let common = open.iter().zip(&wanted).take_while(|(a, b)| a == b).count();
+                while open.len() > common {
+                    out.push_str(delimiter(open.pop().unwrap_or_default()));
+                }
+                out.push_str(&pending_space);
+                out.push_str(lead);
+                for mark in &wanted[common..] {
+                    out.push_str(delimiter(mark));
+                    open.push(*mark);
+                }
+
+                if is_code {
+                    out.push_str(&code_span(core));
+                } else {
+                    out.push_str(&escape_text(core, in_table));
+                }
+                pending_space = trail.to_string();
+            }
+            "hardBreak" => {
+                close_all(&mut open, &mut out);
+                pending_space.clear();
+                out.push('\n');
+            }
+            "mention" => {
+                close_all(&mut open, &mut out);
+                out.push_str(&std::mem::take(&mut pending_space));
+                let id = attr_str(node, "id").unwrap_or("");
+                let name = attr_str(node, "text").unwrap_or("").trim_start_matches('@');
+                out.push_str(&format!(
+                    "[@{}](mention:{})",
+                    escape_text(name, in_table),
+                    id
+                ));
+            }
+            "inlineCard" => {
+                close_all(&mut open, &mut out);
+                out.push_str(&std::mem::take(&mut pending_space));
+                out.push_str(&format!("<{}>", attr_str(node, "url").unwrap_or("")));
+            }
+            _ => {}
+        }
+    }
+    close_all(&mut open, &mut out);
+    out.push_str(&pending_space);
+    out
+}
+
+fn delimiter(mark: &str) -> &'static str {
+    DELIMITED_MARKS
+        .iter()
+        .find(|(name, _)| *name == mark)
+        .map(|(_, delimiter)| *delimiter)
+        .unwrap_or("")
+}
+
+/// Wrap code in a backtick fence longer than any backtick run inside it.
+///
+/// Code next to the fence that starts or ends with a backtick or a space is
+/// padded with one space on each side, which parsing strips again.
+fn code_span(code: &str) -> String {
+    let longest = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest + 1);
+    let spaced = code.starts_with(' ') || code.ends_with(' ');
+    if code.starts_with('`') || code.ends_with('`') || (spaced && !code.trim().is_empty()) {
+        format!("{} {} {}", fence, code, fence)
+    } else {
+        format!("{}{}{}", fence, code, fence)
+    }
+}
+
+/// Escape characters that would otherwise be read as inline Markdown.
+fn escape_text(text: &str, in_table: bool) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|p| chars[p]);
+        let next = chars.get(i + 1).copied();
+        let escape = match c {
+            '\\' | '`' | '*' | '[' | ']' => true,
+            '|' => in_table,
+            '~' => prev == Some('~') || next == Some('~'),
+            // Underscores inside words are never emphasis
+            '_' => {
+                !(prev.is_some_and(char::is_alphanumeric)
+                    && next.is_some_and(char::is_alphanumeric))
+            }
+            '<' => chars[i + 1..].starts_with(&['h', 't', 't', 'p']),
+            _ => false,
+        };
+        if escape {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Escape a line start that would otherwise begin a block (heading, list, ...).
+fn escape_line_start(line: &str) -> String {
+    match line.chars().next() {
+        Some('#' | '>' | '-' | '+' | '|') => format!("\\{}", line),
+        Some(c) if c.is_ascii_digit() => {
+            let digits = line.chars().take_while(char::is_ascii_digit).count();
+            match line[digits..].chars().next() {
+                Some('.' | ')') => format!("{}\\{}", &line[..digits], &line[digits..]),
+                _ => line.to_string(),
+            }
+        }
+        _ => line.to_string(),
+    }
+}
+
+// ============================================================================
+// Markdown to ADF: blocks
+// ============================================================================
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// A list item marker: `(indent, content column, ordered, number)`.
+fn list_marker(line: &str) -> Option<(usize, usize, bool, u64)> {
+    let indent = indent_of(line);
+    let rest = &line[indent..];
+    let mut chars = rest.chars();
+    match chars.next()? {
+        '-' | '+' | '*' => {
+            let after = &rest[1..];
+            if after.is_empty() {
+                Some((indent, indent + 1, false, 0))
+            } else if after.starts_with(' ') {
+                Some((indent, indent + 2, false, 0))
+            } else {
+                None
+            }
+        }
+        c if c.is_ascii_digit() => {
+            let digits = rest.chars().take_while(char::is_ascii_digit).count();
+            if digits > 9 {
+                return None;
+            }
+            let after = &rest[digits..];
+            let number = rest[..digits].parse().ok()?;
+            if after == "." || after == ")" {
+                Some((indent, indent + digits + 1, true, number))
+            } else if after.starts_with(". ") || after.starts_with(") ") {
+                Some((indent, indent + digits + 2, true, number))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn fence_of(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    let ticks = trimmed.chars().take_while(|&c| c == '`').count();
+    if ticks >= 3 {
+        let info = trimmed[ticks..].trim();
+        if !info.contains('`') {
+            return Some((ticks, info));
+        }
+    }
+    None
+}
+
+fn heading_of(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) {
+        let rest = &line[level..];
+        if rest.is_empty() {
+            return Some((level, ""));
+        }
+        if let Some(text) = rest.strip_prefix(' ') {
+            return Some((level, text.trim()));
+        }
+    }
+    None
+}
+
+fn is_rule(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&c| trimmed.chars().all(|ch| ch == c))
+}
+
+fn is_table_separator(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.starts_with('|')
+        && trimmed.contains('-')
+        && trimmed.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '))
+}
+
+fn starts_table(lines: &[String], i: usize) -> bool {
+    lines[i].trim_start().starts_with('|')
+        && lines
+            .get(i + 1)
+            .is_some_and(|next| is_table_separator(next))
+}
+
+/// Check whether a line starts a block that interrupts a paragraph.
+fn starts_block(lines: &[String], i: usize) -> bool {
+    let line = &lines[i];
+    fence_of(line).is_some()
+        || heading_of(line).is_some()
+        || line.starts_with('>')
+        || is_rule(line)
+        || list_marker(line).is_some()
+        || starts_table(lines, i)
+}
+
+fn parse_blocks(lines: &[String]) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = &lines[i];
+        if is_blank(line) {
+            i += 1;
+            continue;
+        }
+
+        if let Some((ticks, info)) = fence_of(line) {
+            let mut body = Vec::new();
+            i += 1;
+            while i < lines.len() {
+                if fence_of(&lines[i]).is_some_and(|(n, rest)| n >= ticks && rest.is_empty()) {
+                    i += 1;
+                    break;
+                }
+                body.push(lines[i].as_str());
+                i += 1;
+            }
+            blocks.extend(fenced_block(info, &body.join("\n")));
+            continue;
+        }
+
+        if let Some((level, text)) = heading_of(line) {
+            blocks.push(json!({
+                "type": "heading",
+                "attrs": {"level": level},
+                "content": parse_inline(text),
+            }));
+            i += 1;
+            continue;
+        }
+
+        if is_rule(line) {
+            blocks.push(json!({"type": "rule"}));
+            i += 1;
+            continue;
+        }
+
+        if line.starts_with('>') {
+            let mut quoted = Vec::new();
+            while i < lines.len() && lines[i].starts_with('>') {
+                let rest = &lines[i][1..];
+                quoted.push(rest.strip_prefix(' ').unwrap_or(rest).to_string());
+                i += 1;
+            }
+            // ADF quotes need content, so a quote of blank lines is dropped
+            let content = parse_blocks(&quoted);
+            if !content.is_empty() {
+                blocks.push(json!({"type": "blockquote", "content": content}));
+            }
+            continue;
+        }
+
+        if starts_table(lines, i) {
+            let mut rows = vec![table_row(line, "tableHeader")];
+            i += 2;
+            while i < lines.len() && lines[i].trim_start().starts_with('|') {
+                rows.push(table_row(&lines[i], "tableCell"));
+                i += 1;
+            }
+            blocks.push(json!({
+                "type": "table",
+                "attrs": {"isNumberColumnEnabled": false, "layout": "default"},
+                "content": rows,
+            }));
+            continue;
+        }
+
+        if let Some((indent, _, ordered, start)) = list_marker(line) {
+            let (list, next) = parse_list(lines, i, indent, ordered);
+            let list_type = if ordered { "orderedList" } else { "bulletList" };
+            let mut node = json!({"type": list_type, "content": list});
+            if ordered {
+                node["attrs"] = json!({"order": start});
+            }
+            blocks.push(node);
+            i = next;
+            continue;
+        }
+
+        // Paragraph: lines up to a blank line or another block
+        let mut inlines: Vec<Value> = Vec::new();
+        let start = i;
+        while i < lines.len() && !is_blank(&lines[i]) && (i == start || !starts_block(lines, i)) {
+            if i > start {
+                inlines.push(json!({"type": "hardBreak"}));
+            }
+            inlines.extend(parse_inline(lines[i].trim()));
+            i += 1;
+        }
+        blocks.push(json!({"type": "paragraph", "content": inlines}));
+    }
+
+    blocks
+}
+
+/// Parse list items starting at `start`, returning the items and the index
+/// of the first line after the list.
+fn parse_list(lines: &[String], start: usize, indent: usize, ordered: bool) -> (Vec<Value>, usize) {
+    let mut items = Vec::new();
+    let mut i = start;
+
+    while i < lines.len() {
+        let Some((marker_indent, content_col, item_ordered, _)) = list_marker(&lines[i]) else {
+            break;
+        };
+        if marker_indent != indent || item_ordered != ordered {
+            break;
+        }
+
+        let mut item_lines = vec![lines[i].get(content_col..).unwrap_or("").to_string()];
+        i += 1;
+        while i < lines.len() {
+            let line = &lines[i];
+            if is_blank(line) {
+                // A blank line continues the item only if indented content follows
+                let next = (i..lines.len()).find(|&j| !is_blank(&lines[j]));
+                match next {
+                    Some(j) if indent_of(&lines[j]) > indent => {
+                        item_lines.push(String::new());
+                        i += 1;
+                    }
+                    _ => break,
+                }
+            } else if indent_of(line) > indent {
+                let strip = indent_of(line).min(content_col);
+                item_lines.push(line[strip..].to_string());
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        let mut content = parse_blocks(&item_lines);
+        if content.is_empty() {
+            content.push(json!({"type": "paragraph", "content": []}));
+        }
+        items.push(json!({"type": "listItem", "content": content}));
+
+        // Loose lists may separate items with blank lines
+        let next = (i..lines.len()).find(|&j| !is_blank(&lines[j]));
+        match next {
+            Some(j)
+                if list_marker(&lines[j])
+                    .is_some_and(|(n, _, o, _)| n == indent && o == ordered) =>
+            {
+                i = j;
+            }
+            _ => break,
+        }
+    }
+
+    (items, i)
+}
+
+fn fenced_block(info: &str, body: &str) -> Vec<Value> {
+    if info == OPAQUE_LANGUAGE {
+        match serde_json::from_str::<Value>(body) {
+            Ok(node @ Value::Object(_)) if !node_type(&node).is_empty() => return vec![node],
+            Ok(Value::Array(nodes)) => return nodes,
+            _ => {}
+        }
+    }
+
+    let mut node = json!({
+        "type": "codeBlock",
+        "content": if body.is_empty() { json!([]) } else { json!([{"type": "text", "text": body}]) },
+    });
+    if !info.is_empty() {
+        node["attrs"] = json!({"language": info});
+    }
+    vec![node]
+}
+
+fn table_row(line: &str, cell_type: &str) -> Value {
+    let cells: Vec<Value> = split_table_row(line)
+        .into_iter()
+        .map(|cell| {
+            let inlines = parse_inline(&cell);
+            let paragraph = json!({"type": "paragraph", "content": inlines});
+            json!({"type": cell_type, "attrs": {}, "content": [paragraph]})
+        })
+        .collect();
+    json!({"type": "tableRow", "content": cells})
+}
+
+/// Split a pipe table row on unescaped `|`, keeping escapes in the cells.
+fn split_table_row(line: &str) -> Vec<String> {
+    let trimmed = line.trim();
+    let trimmed = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = trimmed.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                cell.push(c);
+                if let Some(next) = chars.next() {
+                    cell.push(next);
+                }
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    if !cell.trim().is_empty() {
+        cells.push(cell.trim().to_string());
+    }
+    cells
+}
+
+// ============================================================================
+// Markdown to ADF: inline content
+// ============================================================================
+
+fn parse_inline(text: &str) -> Vec<Value> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = Vec::new();
+    parse_span(&chars, &[], &mut out);
+    out
+}
+
+/// Append text with the given marks, merging with a preceding identical run.
+fn push_text(out: &mut Vec<Value>, text: &str, marks: &[Value]) {
+    if text.is_empty() {
+        return;
+    }
+    let mut marks = marks.to_vec();
+    marks.sort_by_key(|mark| {
+        MARK_ORDER
+            .iter()
+            .position(|name| *name == node_type(mark))
+            .unwrap_or(MARK_ORDER.len())
+    });
+
+    if let Some(last) = out.last_mut() {
+        let same_marks = last
+            .get("marks")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+            == marks.as_slice();
+        if node_type(last) == "text" && same_marks {
+            let merged = format!("{}{}", last["text"].as_str().unwrap_or(""), text);
+            last["text"] = Value::String(merged);
+            return;
+        }
+    }
+
+    let mut node = json!({"type": "text", "text": text});
+    if !marks.is_empty() {
+        node["marks"] = Value::Array(marks);
+    }
+    out.push(node);
+}
+
+fn with_mark(marks: &[Value], mark: Value) -> Vec<Value> {
+    let mut marks = marks.to_vec();
+    marks.push(mark);
+    marks
+}
+
+fn run_length(chars: &[char], i: usize) -> usize {
+    chars[i..].iter().take_while(|&&c| c == chars[i]).count()
+}
+
+/// Find the end of a backtick code span opened by a run at `i`.
+fn code_span_end(chars: &[char], i: usize) -> Option<(usize, usize)> {
+    let ticks = run_length(chars, i);
+    let mut j = i + ticks;
+    while j < chars.len() {
+        if chars[j] == '`' {
+            let run = run_length(chars, j);
+            if run == ticks {
+                return Some((j, ticks));
+            }
+            j += run;
+        } else {
+            j += 1;
+        }
+    }
+    None
+}
+
+/// Find the closing delimiter for `delim` repeated `len` times, starting at `from`.
+fn find_closer(chars: &[char], from: usize, delim: char, len: usize) -> Option<usize> {
+    let mut j = from;
+    while j < chars.len() {
+        match chars[j] {
+            '\\' => j += 2,
+            '`' => match code_span_end(chars, j) {
+                Some((end, ticks)) => j = end + ticks,
+                None => j += run_length(chars, j),
+            },
+            c if c == delim => {
+                let run = run_length(chars, j);
+                let candidate = match (delim, len, run) {
+                    ('*', 2, 2) | ('*', 1, 1) | ('*', 1, 3) => Some(j),
+                    ('*', 2, 3) => Some(j + 1),
+                    ('~', 2, 2) | ('_', 1, 1) | ('_', 2, 2) => Some(j),
+                    _ => None,
+                };
+                if let Some(pos) = candidate {
+                    let after = chars.get(pos + len).copied();
+                    let valid = pos > from
+                        && !chars[pos - 1].is_whitespace()
+                        && (delim != '_' || !after.is_some_and(char::is_alphanumeric));
+                    if valid {
+                        return Some(pos);
+                    }
+                }
+                j += run;
+            }
+            _ => j += 1,
+        }
+    }
+    None
+}
+
+/// Find the `]` closing a link label opened at `i`.
+fn find_label_end(chars: &[char], i: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut j = i;
+    while j < chars.len() {
+        match chars[j] {
+            '\\' => j += 1,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(j);
+                }
+            }
+            _ => {}
+        }
+        j += 1;
+    }
+    None
+}
+
+fn unescape(chars: &[char]) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '\\' && chars.get(i + 1).is_some_and(char::is_ascii_punctuation) {
+            i += 1;
+        }
+        out.push(chars[i]);
+        i += 1;
+    }
+    out
+}
+
+fn parse_span(chars: &[char], marks: &[Value], out: &mut Vec<Value>) {
+    let mut text = String::new();
+    let mut i = 0;
+
+    macro_rules! flush {
+        () => {
+            push_text(out, &std::mem::take(&mut text), marks)
+        };
+    }
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '\\' if next.is_some_and(|n| n.is_ascii_punctuation()) => {
+                text.push(next.unwrap_or_default());
+                i += 2;
+            }
+            '`' => match code_span_end(chars, i) {
+                Some((end, ticks)) => {
+                    flush!();
+                    let mut code: String = chars[i + ticks..end].iter().collect();
+                    if code.len() > 2
+                        && code.starts_with(' ')
+                        && code.ends_with(' ')
+                        && !code.trim().is_empty()
+                    {
+                        code = code[1..code.len() - 1].to_string();
+                    }
+                    push_text(out, &code, &with_mark(marks, json!({"type": "code"})));
+                    i = end + ticks;
+                }
+                None => {
+                    let run = run_length(chars, i);
+                    text.extend(&chars[i..i + run]);
+                    i += run;
+                }
+            },
+            '*' | '_' | '~' => {
+                let run = run_length(chars, i);
+                let len = match (c, run) {
+                    ('*', 1) | ('_', 1) => 1,
+                    ('*', 2) | ('*', 3) | ('_', 2) | ('~', 2) => 2,
+                    _ => 0,
+                };
+                let after = chars.get(i + len).copied();
+                let prev = i.checked_sub(1).map(|p| chars[p]);
+                let can_open = len > 0
+                    && after.is_some_and(|a| !a.is_whitespace())
+                    && (c != '_' || !prev.is_some_and(char::is_alphanumeric));
+                let closer = if can_open {
+                    find_closer(chars, i + len, c, len)
+                } else {
+                    None
+                };
+                match closer {
+                    Some(end) => {
+                        flush!();
+                        let mark = match (c, len) {
+                            ('~', _) => "strike",
+                            (_, 2) => "strong",
+                            _ => "em",
+                        };
+                        let inner = with_mark(marks, json!({"type": mark}));
+                        parse_span(&chars[i + len..end], &inner, out);
+                        i = end + len;
+                    }
+                    None => {
+                        text.extend(&chars[i..i + run]);
+                        i += run;
+                    }
+                }
+            }
+            '[' => {
+                let link = find_label_end(chars, i).and_then(|label_end| {
+                    if chars.get(label_end + 1) != Some(&'(') {
+                        return None;
+                    }
+                    let close = (label_end + 2..chars.len()).find(|&j| chars[j] == ')')?;
+                    let url: String = chars[label_end + 2..close].iter().collect();
+                    Some((label_end, close, url.trim().to_string()))
+                });
+                match link {
+                    Some((label_end, close, url)) => {
+                        flush!();
+                        let label = &chars[i + 1..label_end];
+                        if let Some(id) = url.strip_prefix("mention:") {
+                            let name = unescape(label);
+                            out.push(json!({
+                                "type": "mention",
+                                "attrs": {
+                                    "id": id,
+                                    "text": format!("@{}", name.trim_start_matches('@')),
+                                },
+                            }));
+                        } else {
+                            let inner =
+                                with_mark(marks, json!({"type": "link", "attrs": {"href": url}}));
+                            parse_span(label, &inner, out);
+                        }
+                        i = close + 1;
+                    }
+                    None => {
+                        text.push(c);
+                        i += 1;
+                    }
+                }
+            }
+            '<' => {
+                let rest: String = chars[i + 1..].iter().collect();
+                let end = rest.find('>');
+                match end
+                    .map(|end| &rest[..end])
+                    .filter(|url| is_autolink_url(url))
+                {
+                    Some(url) => {
+                        flush!();
+                        out.push(json!({"type": "inlineCard", "attrs": {"url": url}}));
+                        i += url.chars().count() + 2;
+                    }
+                    None => {
+                        text.push(c);
+                        i += 1;
+                    }
+                }
+            }
+            _ => {
+                text.push(c);
+                i += 1;
+            }
+        }
+    }
+    flush!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(content: Value) -> AtlassianDoc {
+        AtlassianDoc {
+            doc_type: "doc".to_string(),
+            version: Some(1),
+            content: content.as_array().cloned().unwrap_or_default(),
+        }
+    }
+
+    fn text(text: &str) -> Value {
+        json!({"type": "text", "text": text})
+    }
+
+    fn marked(text: &str, marks: Value) -> Value {
+        json!({"type": "text", "text": text, "marks": marks})
+    }
+
+    fn paragraph(content: Value) -> Value {
+        json!({"type": "paragraph", "content": content})
+    }
+
+    #[test]
+    fn test_round_trip_preserves_formatting() {
+        let original = doc(json!([
+            {"type": "heading", "attrs": {"level": 2}, "content": [text("Overview")]},
+            paragraph(json!([
+                text("Some "),
+                marked("bold", json!([{"type": "strong"}])),
+                text(", "),
+                marked("bold italic", json!([{"type": "strong"}, {"type": "em"}])),
+                text(" and "),
+                marked("gone", json!([{"type": "strike"}])),
+                text(" with "),
+                marked("code()", json!([{"type": "code"}])),
+                {"type": "hardBreak"},
+                text("See "),
+                marked("the docs", json!([{"type": "link", "attrs": {"href": "https://example.com"}}])),
+                text(", ask "),
+                {"type": "mention", "attrs": {"id": "abc123", "text": "@Jane Doe"}},
+                text(" or open "),
+                {"type": "inlineCard", "attrs": {"url": "https://jira.example.com/browse/PROJ-1"}}
+            ])),
+            {"type": "orderedList", "attrs": {"order": 3}, "content": [
+                {"type": "listItem", "content": [
+                    paragraph(json!([text("First")])),
+                    {"type": "bulletList", "content": [
+                        {"type": "listItem", "content": [paragraph(json!([text("Nested")]))]}
+                    ]}
+                ]},
+                {"type": "listItem", "content": [paragraph(json!([text("Second")]))]}
+            ]},
+            {"type": "codeBlock", "attrs": {"language": "rust"}, "content": [text("fn main() {\n    let a = *b;\n}")]},
+            {"type": "blockquote", "content": [paragraph(json!([text("Quoted")]))]},
+            {"type": "rule"},
+            {"type": "table", "attrs": {"isNumberColumnEnabled": false, "layout": "default"}, "content": [
+                {"type": "tableRow", "content": [
+                    {"type": "tableHeader", "attrs": {}, "content": [paragraph(json!([text("Key")]))]},
+                    {"type": "tableHeader", "attrs": {}, "content": [paragraph(json!([text("Value")]))]}
+                ]},
+                {"type": "tableRow", "content": [
+                    {"type": "tableCell", "attrs": {}, "content": [paragraph(json!([text("a|b")]))]},
+                    {"type": "tableCell", "attrs": {}, "content": [paragraph(json!([marked("1", json!([{"type": "strong"}]))]))]}
+                ]}
+            ]}
+        ]));
+
+        let markdown = original.to_markdown();
+        assert_eq!(AtlassianDoc::from_markdown(&markdown), original);
+        assert_eq!(
+            AtlassianDoc::from_markdown(&markdown).to_markdown(),
+            markdown
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_output() {
+        let d = doc(json!([
+            paragraph(json!([text("Hello "), marked("world", json!([{"type": "strong"}]))])),
+            {"type": "bulletList", "content": [
+                {"type": "listItem", "content": [paragraph(json!([text("one")]))]},
+                {"type": "listItem", "content": [paragraph(json!([text("two")]))]}
+            ]}
+        ]));
+
+        assert_eq!(d.to_markdown(), "Hello **world**\n\n- one\n- two");
+    }
+
+    #[test]
+    fn test_unsupported_nodes_are_kept_opaque() {
+        let panel = json!({
+            "type": "panel",
+            "attrs": {"panelType": "warning"},
+            "content": [paragraph(json!([text("Careful")]))]
+        });
+        let status = paragraph(json!([
+            text("State: "),
+            {"type": "status", "attrs": {"text": "DONE", "color": "green"}}
+        ]));
+        let original = doc(json!([panel, status, paragraph(json!([text("After")]))]));
+
+        let markdown = original.to_markdown();
+        assert!(markdown.starts_with("```adf\n{"));
+        assert!(markdown.contains("\"panelType\":\"warning\""));
+        assert_eq!(AtlassianDoc::from_markdown(&markdown), original);
+    }
+
+    #[test]
+    fn test_plain_text_is_not_formatted() {
+        let d = AtlassianDoc::from_markdown("snake_case_name and 2 * 3 * 4 or a < b");
+        assert_eq!(
+            d.content,
+            vec![paragraph(json!([text(
+                "snake_case_name and 2 * 3 * 4 or a < b"
+            )]))]
+        );
+    }
+
+    #[test]
+    fn test_special_characters_round_trip() {
+        let original = doc(json!([
+            paragraph(json!([text("# not a heading")])),
+            paragraph(json!([text(
+                "1. not a list with *stars* and [brackets] and _under_"
+            )])),
+            paragraph(json!([text("- also not a list, `tick` \\ slash")]))
+        ]));
+
+        let markdown = original.to_markdown();
+        assert_eq!(AtlassianDoc::from_markdown(&markdown), original);
+    }
+
+    #[test]
+    fn test_from_markdown_blocks() {
+        let d = AtlassianDoc::from_markdown(
+            "# Title\n\nLine one\nLine two\n\n1. a\n2. b\n   - c\n\n```\ncode\n```",
+        );
+        let types: Vec<&str> = d.content.iter().map(node_type).collect();
+        assert_eq!(
+            types,
+            vec!["heading", "paragraph", "orderedList", "codeBlock"]
+        );
+        assert_eq!(
+            d.content[1],
+            paragraph(json!([text("Line one"), {"type": "hardBreak"}, text("Line two")]))
+        );
+        let second_item = &content(&d.content[2])[1];
+        assert_eq!(node_type(&content(second_item)[1]), "bulletList");
+    }
+
+    #[test]
+    fn test_code_padding_round_trip() {
+        let code = |t: &str| marked(t, json!([{"type": "code"}]));
+        let original = doc(json!([paragraph(json!([
+            text("run "),
+            code(" ls -l "),
+            text(" then "),
+            code("  "),
+            text(" and "),
+            code("` y ")
+        ]))]));
+
+        let markdown = original.to_markdown();
+        assert_eq!(AtlassianDoc::from_markdown(&markdown), original);
+    }
+
+    #[test]
+    fn test_blank_quotes_are_dropped() {
+        assert!(AtlassianDoc::from_markdown("> ").content.is_empty());
+        assert!(AtlassianDoc::from_markdown("> > \n>").content.is_empty());
+        assert_eq!(
+            AtlassianDoc::from_markdown("> > \nafter").content,
+            vec![paragraph(json!([text("after")]))]
+        );
+    }
+
+    #[test]
+    fn test_empty_markdown() {
+        assert!(AtlassianDoc::from_markdown("").content.is_empty());
+        assert_eq!(AtlassianDoc::default().to_markdown(), "");
+    }
+}
@@ -9,6 +9,9 @@
 //! - [`JiraClient`]: The main client for making API requests
 //! - [`auth`]: Authentication handling and keyring integration
 //! - [`types`]: Request and response types for the JIRA API
+//! - [`adf`]: Accessors for raw Atlassian Document Format nodes
+//! - [`columns`]: Issue list column fields and their JQL names
+//! - [`jql`]: JQL syntax tree, parser and serializer
 //! - [`error`]: Error types for API operations
 //!
 //! # Example
@@ -37,9 +40,13 @@
 //! }
 //! ```
 
+pub mod adf;
 pub mod auth;
 mod client;
+pub mod columns;
 pub mod error;
+pub mod jql;
+mod markdown;
 pub mod types;
 
 // Re-export main types for convenience
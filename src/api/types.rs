@@ -7,8 +7,12 @@
 #![allow(dead_code)]
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
+use super::columns::ColumnConfig;
+use super::jql::{self, Clause, Expr, Operand, Operator, Query, Value};
+
 /// The current authenticated user.
 ///
 /// Returned by `GET /rest/api/3/myself`.
@@ -202,10 +206,109 @@ impl Issue {
             .unwrap_or_default()
     }
 
+    /// Get the description as an ADF document, if set.
+    ///
+    /// Plain string descriptions are converted to a document of paragraphs.
+    pub fn description_doc(&self) -> Option<AtlassianDoc> {
+        let description = self.fields.description.as_ref()?;
+        if let Ok(doc) = serde_json::from_value::<AtlassianDoc>(description.clone()) {
+            Some(doc)
+        } else {
+            description.as_str().map(AtlassianDoc::from_text)
+        }
+    }
+
+    /// Get the description as Markdown for editing, or empty string if not set.
+    pub fn description_markdown(&self) -> String {
+        self.description_doc()
+            .map(|doc| doc.to_markdown())
+            .unwrap_or_default()
+    }
+
     /// Get the project key, if available.
     pub fn project_key(&self) -> Option<&str> {
         self.fields.project.as_ref().map(|p| p.key.as_str())
     }
+
+    /// Get the raw value of a field that is not modelled on `IssueFields`.
+    ///
+    /// Returns `None` if the field is missing or null.
+    pub fn field_value(&self, field_id: &str) -> Option<&serde_json::Value> {
+        self.fields
+            .extra_fields
+            .get(field_id)
+            .filter(|v| !v.is_null())
+    }
+
+    /// Get the watch state of the issue, if the `watches` field was returned.
+    pub fn watches(&self) -> Option<Watches> {
+        serde_json::from_value(self.field_value("watches")?.clone()).ok()
+    }
+
+    /// Get the vote state of the issue, if the `votes` field was returned.
+    pub fn votes(&self) -> Option<Votes> {
+        serde_json::from_value(self.field_value("votes")?.clone()).ok()
+    }
+
+    /// Get the versions in a version field (fix or affects versions).
+    pub fn versions(&self, field: VersionField) -> Vec<Version> {
+        self.field_value(field.field_id())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Get the story points estimate from the given field.
+    pub fn story_points(&self, field_id: &str) -> Option<f64> {
+        // The JIRA Cloud field is deserialized into its own member
+        if field_id == "customfield_10016" {
+            return self.fields.story_points;
+        }
+        self.field_value(field_id)?.as_f64()
+    }
+
+    /// Get the name of the sprint this issue belongs to, from the given field.
+    ///
+    /// Issues carried over between sprints list every sprint they were part of;
+    /// the active sprint is preferred, otherwise the most recent one is used.
+    pub fn sprint_name(&self, field_id: &str) -> Option<&str> {
+        let sprints = self.field_value(field_id)?.as_array()?;
+        sprints
+            .iter()
+            .find(|s| s.get("state").and_then(|v| v.as_str()) == Some("active"))
+            .or_else(|| sprints.last())
+            .and_then(|s| s.get("name"))
+            .and_then(|v| v.as_str())
+    }
+}
+
+/// Format an arbitrary JIRA field value for display.
+///
+/// Handles the common shapes returned for custom fields: plain strings and
+/// numbers, option objects (`value`), named objects (`name`/`displayName`),
+/// and arrays of any of these.
+pub fn field_value_text(value: &serde_json::Value) -> String {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => String::new(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if f.fract() == 0.0 => format!("{}", f as i64),
+            _ => n.to_string(),
+        },
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(field_value_text)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(map) => ["value", "displayName", "name", "key"]
+            .iter()
+            .find_map(|k| map.get(*k))
+            .map(field_value_text)
+            .unwrap_or_default(),
+    }
 }
 
 impl fmt::Display for Issue {
@@ -268,6 +371,9 @@ pub struct IssueFields {
     /// Parent issue (for subtasks).
     #[serde(default)]
     pub parent: Option<ParentIssue>,
+    /// Fields not modelled above, keyed by field ID (custom fields, sprint, etc.).
+    #[serde(flatten, default)]
+    pub extra_fields: HashMap<String, serde_json::Value>,
 }
 
 // ============================================================================
@@ -510,6 +616,198 @@ pub struct IssueTypeMetaResponse {
     pub issue_types: Vec<IssueTypeMeta>,
 }
 
+/// Custom field type of the JIRA Software sprint field.
+const SPRINT_CUSTOM_TYPE: &str = "com.pyxis.greenhopper.jira:gh-sprint";
+
+/// Custom field type of the legacy epic link field (set through `parent` instead).
+const EPIC_LINK_CUSTOM_TYPE: &str = "com.pyxis.greenhopper.jira:gh-epic-link";
+
+/// Custom field type of multi-line text fields, which take rich text.
+const TEXTAREA_CUSTOM_TYPE: &str = "com.atlassian.jira.plugin.system.customfieldtypes:textarea";
+
+/// Fields the create issue form has dedicated inputs for.
+const BUILT_IN_CREATE_FIELDS: &[&str] = &[
+    "project",
+    "issuetype",
+    "summary",
+    "description",
+    "parent",
+    "assignee",
+    "priority",
+];
+
+/// Metadata for a field that can be set when creating an issue.
+///
+/// Returned by `GET /rest/api/3/issue/createmeta/{projectIdOrKey}/issuetypes/{issueTypeId}`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFieldMeta {
+    /// The field ID (e.g., "duedate", "customfield_10020").
+    pub field_id: String,
+    /// The field display name.
+    pub name: String,
+    /// Whether the field must be set.
+    #[serde(default)]
+    pub required: bool,
+    /// Whether JIRA fills in a default when the field is not set.
+    #[serde(default)]
+    pub has_default_value: bool,
+    /// The field's data type.
+    #[serde(default)]
+    pub schema: FieldSchema,
+    /// The values the field accepts, for select-like fields.
+    #[serde(default)]
+    pub allowed_values: Vec<AllowedValue>,
+}
+
+/// A field of the JIRA instance.
+///
+/// Returned by `GET /rest/api/3/field`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Field {
+    /// The field ID (e.g., "summary", "customfield_10020").
+    pub id: String,
+    /// The field display name.
+    #[serde(default)]
+    pub name: String,
+    /// The field's data type.
+    #[serde(default)]
+    pub schema: FieldSchema,
+}
+
+/// The data type of a field.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FieldSchema {
+    /// The value type (e.g., "string", "array", "user", "option").
+    #[serde(rename = "type", default)]
+    pub field_type: String,
+    /// The item type for array fields (e.g., "component", "string").
+    #[serde(default)]
+    pub items: Option<String>,
+    /// The system field this is, if any.
+    #[serde(default)]
+    pub system: Option<String>,
+    /// The custom field type, if this is a custom field.
+    #[serde(default)]
+    pub custom: Option<String>,
+}
+
+/// A value accepted by a select-like field.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct AllowedValue {
+    /// The value ID.
+    #[serde(default)]
+    pub id: String,
+    /// The display name (components, versions, priorities).
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The display value (custom field options).
+    #[serde(default)]
+    pub value: Option<String>,
+}
+
+impl AllowedValue {
+    /// Create an allowed value with a display name.
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: Some(name.into()),
+            value: None,
+        }
+    }
+
+    /// Get the text to show for this value.
+    pub fn label(&self) -> &str {
+        self.name
+            .as_deref()
+            .or(self.value.as_deref())
+            .unwrap_or(&self.id)
+    }
+}
+
+/// How a create field is edited and sent to JIRA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreateFieldKind {
+    /// Single-line text.
+    Text,
+    /// Multi-line text, sent as Atlassian Document Format.
+    RichText,
+    /// A number.
+    Number,
+    /// A date (`YYYY-MM-DD`).
+    Date,
+    /// A date and time (ISO 8601).
+    DateTime,
+    /// A list of labels.
+    Labels,
+    /// A single user.
+    User,
+    /// One of the allowed values.
+    Select,
+    /// Any of the allowed values.
+    MultiSelect,
+    /// A sprint, sent by its numeric ID.
+    Sprint,
+    /// A field type the form cannot edit.
+    Unsupported,
+}
+
+impl CreateFieldMeta {
+    /// Check if the create issue form has a dedicated input for this field.
+    pub fn is_built_in(&self) -> bool {
+        BUILT_IN_CREATE_FIELDS.contains(&self.field_id.as_str())
+            || self.schema.custom.as_deref() == Some(EPIC_LINK_CUSTOM_TYPE)
+    }
+
+    /// Check if this is the sprint field.
+    pub fn is_sprint(&self) -> bool {
+        self.schema.custom.as_deref() == Some(SPRINT_CUSTOM_TYPE)
+    }
+
+    /// Check if the field must be filled in before submitting.
+    ///
+    /// Required fields with a default are filled in by JIRA.
+    pub fn must_be_set(&self) -> bool {
+        self.required && !self.has_default_value
+    }
+
+    /// Determine how the field is edited.
+    pub fn kind(&self) -> CreateFieldKind {
+        if self.is_sprint() {
+            return CreateFieldKind::Sprint;
+        }
+        let schema = &self.schema;
+        match schema.field_type.as_str() {
+            "string" if self.schema.system.as_deref() == Some("environment") => {
+                CreateFieldKind::RichText
+            }
+            "string" if schema.custom.as_deref() == Some(TEXTAREA_CUSTOM_TYPE) => {
+                CreateFieldKind::RichText
+            }
+            "string" => CreateFieldKind::Text,
+            "number" => CreateFieldKind::Number,
+            "date" => CreateFieldKind::Date,
+            "datetime" => CreateFieldKind::DateTime,
+            "user" => CreateFieldKind::User,
+            "array" if schema.items.as_deref() == Some("string") => CreateFieldKind::Labels,
+            "array" if !self.allowed_values.is_empty() => CreateFieldKind::MultiSelect,
+            "array" => CreateFieldKind::Unsupported,
+            _ if !self.allowed_values.is_empty() => CreateFieldKind::Select,
+            _ => CreateFieldKind::Unsupported,
+        }
+    }
+}
+
+/// Response from the create field metadata endpoint.
+///
+/// Returned by `GET /rest/api/3/issue/createmeta/{projectIdOrKey}/issuetypes/{issueTypeId}`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateFieldMetaResponse {
+    /// The fields of the issue type.
+    #[serde(default, alias = "values", alias = "results")]
+    pub fields: Vec<CreateFieldMeta>,
+}
+
 // ============================================================================
 // Issue Creation Types
 // ============================================================================
@@ -546,6 +844,15 @@ pub struct CreateIssueFields {
     /// The parent issue (required for subtasks).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent: Option<ParentRef>,
+    /// Labels to add to the issue.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Components to add to the issue.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<ComponentRef>,
+    /// Other fields from the create metadata, keyed by field ID.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Reference to a project by key.
@@ -575,6 +882,15 @@ pub struct ParentRef {
     pub key: String,
 }
 
+/// Reference to a project component by name.
+///
+/// Used when creating issues to set their components.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentRef {
+    /// The component name.
+    pub name: String,
+}
+
 /// Response from creating a new JIRA issue.
 ///
 /// Returned by `POST /rest/api/3/issue`.
@@ -800,6 +1116,16 @@ impl AddCommentRequest {
             body: AtlassianDoc::from_text(text),
         }
     }
+
+    /// Create a new comment request from Markdown.
+    ///
+    /// Converts the Markdown into an Atlassian Document Format structure,
+    /// keeping its formatting.
+    pub fn from_markdown(markdown: &str) -> Self {
+        Self {
+            body: AtlassianDoc::from_markdown(markdown),
+        }
+    }
 }
 
 /// Atlassian Document Format (ADF) content.
@@ -1019,28 +1345,169 @@ pub enum SprintFilter {
     Specific(String),
 }
 
+/// Resolution filter options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionFilter {
+    /// Only issues without a resolution.
+    Unresolved,
+    /// Only issues with a resolution.
+    Resolved,
+}
+
+/// A date range filter.
+///
+/// Bounds are JQL date values such as `2024-01-31`, `-7d` or
+/// `startOfWeek()`, and are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DateRange {
+    /// The earliest date, if bounded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// The latest date, if bounded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+impl DateRange {
+    /// Check if the range has no bounds.
+    pub fn is_empty(&self) -> bool {
+        self.from.is_none() && self.to.is_none()
+    }
+
+    /// Build the clauses for the range on the given field.
+    fn clauses(&self, field: &str) -> Vec<Expr> {
+        let bound = |date: &Option<String>, operator| {
+            date.as_ref()
+                .map(|date| Expr::from(Clause::new(field, operator, date_value(date))))
+        };
+        bound(&self.from, Operator::GreaterThanOrEquals)
+            .into_iter()
+            .chain(bound(&self.to, Operator::LessThanOrEquals))
+            .collect()
+    }
+
+    /// Describe the range for display.
+    fn summary(&self) -> String {
+        match (&self.from, &self.to) {
+            (Some(from), Some(to)) => format!("{} to {}", from, to),
+            (Some(from), None) => format!("since {}", from),
+            (None, Some(to)) => format!("until {}", to),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+/// Get the JQL value for a date bound, keeping function calls unquoted.
+fn date_value(date: &str) -> Value {
+    match jql::parse_value(date) {
+        Ok(value @ Value::Function { .. }) => value,
+        _ => Value::text(date),
+    }
+}
+
+/// Get the string for a date bound parsed from JQL.
+fn date_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Text(text) | Value::Word(text) => Some(text.clone()),
+        Value::Function { .. } => Some(value.to_string()),
+        Value::Empty => None,
+    }
+}
+
+/// A filter that can be negated to exclude its values instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterField {
+    Status,
+    Assignee,
+    Project,
+    Labels,
+    Components,
+    Sprint,
+    Epic,
+    IssueType,
+    Priority,
+    Reporter,
+}
+
+impl FilterField {
+    /// Check whether issues can have no value for the field.
+    ///
+    /// JQL's negative operators skip issues without a value, so negating
+    /// these filters also matches `IS EMPTY` to keep them.
+    pub fn is_optional(self) -> bool {
+        matches!(
+            self,
+            Self::Assignee | Self::Labels | Self::Components | Self::Sprint | Self::Epic
+        )
+    }
+}
+
 /// Filter state for issues.
 ///
 /// This struct holds all the filter criteria that can be applied to issues.
-/// It can generate a JQL query string from the current filter state.
+/// It can generate a JQL query string from the current filter state, and
+/// read simple queries back with [`FilterState::from_jql`].
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FilterState {
     /// Filter by statuses (multi-select).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub statuses: Vec<String>,
     /// Filter by assignee account IDs (multi-select).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub assignees: Vec<String>,
     /// Special flag to filter by current user ("Assigned to me").
+    #[serde(skip_serializing_if = "is_false")]
     pub assignee_is_me: bool,
     /// Filter by project key.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
     /// Filter by labels (multi-select).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub labels: Vec<String>,
     /// Filter by components (multi-select).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub components: Vec<String>,
     /// Filter by sprint.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sprint: Option<SprintFilter>,
     /// Filter by epic keys (multi-select).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub epics: Vec<String>,
+    /// Filter by issue type names (multi-select).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub issue_types: Vec<String>,
+    /// Filter by priority names (multi-select).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub priorities: Vec<String>,
+    /// Filter by reporter account IDs (multi-select).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reporters: Vec<String>,
+    /// Special flag to filter by issues the current user reported.
+    #[serde(skip_serializing_if = "is_false")]
+    pub reporter_is_me: bool,
+    /// Filter by creation date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateRange>,
+    /// Filter by last update date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<DateRange>,
+    /// Full text search across summary, description and comments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Filter by whether issues are resolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<ResolutionFilter>,
+    /// Filters that exclude their values instead of matching them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub negated: Vec<FilterField>,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
 }
 
 impl FilterState {
@@ -1049,82 +1516,350 @@ impl FilterState {
         Self::default()
     }
 
+    /// Convert the filter state to a JQL condition.
+    ///
+    /// Returns `None` if no filters are active.
+    pub fn to_expr(&self) -> Option<Expr> {
+        let mut clauses = Vec::new();
+
+        clauses.extend(self.list_clause(FilterField::Status, "status", &self.statuses));
+        clauses.extend(self.user_clause(
+            FilterField::Assignee,
+            "assignee",
+            self.assignee_is_me,
+            &self.assignees,
+        ));
+
+        if let Some(project) = &self.project {
+            let operator = self.operator(FilterField::Project, Operator::Equals);
+            clauses.push(Clause::new("project", operator, Value::text(project)).into());
+        }
+
+        clauses.extend(self.list_clause(FilterField::Labels, "labels", &self.labels));
+        clauses.extend(self.list_clause(FilterField::Components, "component", &self.components));
+
+        let sprint = match &self.sprint {
+            Some(SprintFilter::Current) => Some((Operator::In, Value::function("openSprints"))),
+            Some(SprintFilter::Specific(id)) => Some((Operator::Equals, Value::Word(id.clone()))),
+            None => None,
+        };
+        if let Some((operator, value)) = sprint {
+            let operator = self.operator(FilterField::Sprint, operator);
+            let clause = Clause::new("sprint", operator, value).into();
+            clauses.push(self.keep_empty(FilterField::Sprint, "sprint", clause));
+        }
+
+        clauses.extend(self.list_clause(FilterField::Epic, "parent", &self.epics));
+        clauses.extend(self.list_clause(FilterField::IssueType, "issuetype", &self.issue_types));
+        clauses.extend(self.list_clause(FilterField::Priority, "priority", &self.priorities));
+        clauses.extend(self.user_clause(
+            FilterField::Reporter,
+            "reporter",
+            self.reporter_is_me,
+            &self.reporters,
+        ));
+
+        if let Some(created) = &self.created {
+            clauses.extend(created.clauses("created"));
+        }
+        if let Some(updated) = &self.updated {
+            clauses.extend(updated.clauses("updated"));
+        }
+
+        match self.resolution {
+            Some(ResolutionFilter::Unresolved) => {
+                clauses.push(Clause::new("resolution", Operator::Is, Value::Empty).into());
+            }
+            Some(ResolutionFilter::Resolved) => {
+                clauses.push(Clause::new("resolution", Operator::IsNot, Value::Empty).into());
+            }
+            None => {}
+        }
+
+        if let Some(text) = &self.text {
+            clauses.push(Clause::new("text", Operator::Contains, Value::text(text)).into());
+        }
+
+        Expr::and(clauses)
+    }
+
     /// Convert the filter state to a JQL query string.
     ///
     /// Returns an empty string if no filters are active.
     pub fn to_jql(&self) -> String {
-        let mut clauses = Vec::new();
+        self.to_expr()
+            .map(|expr| expr.to_string())
+            .unwrap_or_default()
+    }
 
-        if !self.statuses.is_empty() {
-            let statuses = self
-                .statuses
-                .iter()
-                .map(|s| format!("\"{}\"", s))
-                .collect::<Vec<_>>()
-                .join(", ");
-            clauses.push(format!("status IN ({})", statuses));
+    /// Get the operator for a field, negated if the field is.
+    fn operator(&self, field: FilterField, operator: Operator) -> Operator {
+        if self.is_negated(field) {
+            operator.negated().unwrap_or(operator)
+        } else {
+            operator
         }
+    }
 
-        if self.assignee_is_me {
-            clauses.push("assignee = currentUser()".to_string());
-        } else if !self.assignees.is_empty() {
-            let assignees = self
-                .assignees
-                .iter()
-                .map(|a| format!("\"{}\"", a))
-                .collect::<Vec<_>>()
-                .join(", ");
-            clauses.push(format!("assignee IN ({})", assignees));
+    /// Build an `IN` clause for a multi-select field.
+    fn list_clause(&self, field: FilterField, name: &str, values: &[String]) -> Option<Expr> {
+        if values.is_empty() {
+            return None;
         }
+        let values = values.iter().map(Value::text).collect();
+        let clause = Clause::list(name, self.operator(field, Operator::In), values).into();
+        Some(self.keep_empty(field, name, clause))
+    }
 
-        if let Some(project) = &self.project {
-            clauses.push(format!("project = \"{}\"", project));
+    /// Also match issues without a value when an optional field is negated.
+    fn keep_empty(&self, field: FilterField, name: &str, clause: Expr) -> Expr {
+        if field.is_optional() && self.is_negated(field) {
+            let empty = Clause::new(name, Operator::Is, Value::Empty).into();
+            Expr::Or(vec![clause, empty])
+        } else {
+            clause
         }
+    }
 
-        if !self.labels.is_empty() {
-            let labels = self
-                .labels
-                .iter()
-                .map(|l| format!("\"{}\"", l))
-                .collect::<Vec<_>>()
-                .join(", ");
-            clauses.push(format!("labels IN ({})", labels));
+    /// Build the clause for a user field with a "me" shortcut.
+    fn user_clause(
+        &self,
+        field: FilterField,
+        name: &str,
+        is_me: bool,
+        users: &[String],
+    ) -> Option<Expr> {
+        if is_me {
+            let operator = self.operator(field, Operator::Equals);
+            let clause = Clause::new(name, operator, Value::function("currentUser")).into();
+            Some(self.keep_empty(field, name, clause))
+        } else {
+            self.list_clause(field, name, users)
         }
+    }
 
-        if !self.components.is_empty() {
-            let components = self
-                .components
-                .iter()
-                .map(|c| format!("\"{}\"", c))
-                .collect::<Vec<_>>()
-                .join(", ");
-            clauses.push(format!("component IN ({})", components));
+    /// Read a filter state back from JQL.
+    ///
+    /// Only queries the filter state can express are read: clauses on known
+    /// fields joined with `AND`, each field at most once, optionally negated
+    /// with `NOT` or a negative operator. Negated optional fields must also
+    /// match `IS EMPTY`, as in `(labels NOT IN (a) OR labels IS EMPTY)`.
+    /// Returns `None` for anything else, including other uses of `OR` and
+    /// queries with `ORDER BY`.
+    pub fn from_jql(jql: &str) -> Option<Self> {
+        let query = Query::parse(jql).ok()?;
+        if !query.order_by.is_empty() {
+            return None;
         }
+        let mut state = Self::default();
+        let Some(filter) = query.filter else {
+            return Some(state);
+        };
+        for expr in filter.conjuncts() {
+            let (clause, negated, keeps_empty) = match expr {
+                Expr::Clause(clause) => (clause, false, false),
+                Expr::Not(inner) => match inner.as_ref() {
+                    Expr::Clause(clause) => (clause, true, false),
+                    _ => return None,
+                },
+                Expr::Or(exprs) => (Self::or_empty_clause(exprs)?, false, true),
+                _ => return None,
+            };
+            let negated_before = state.negated.len();
+            state.read_clause(clause, negated)?;
+            // Negated optional fields and `OR ... IS EMPTY` only come together
+            let optional = state.negated[negated_before..]
+                .first()
+                .is_some_and(|field| field.is_optional());
+            if optional != keeps_empty {
+                return None;
+            }
+        }
+        Some(state)
+    }
 
-        match &self.sprint {
-            Some(SprintFilter::Current) => {
-                clauses.push("sprint IN openSprints()".to_string());
+    /// Get the negative clause of `(<clause> OR <field> IS EMPTY)`.
+    fn or_empty_clause(exprs: &[Expr]) -> Option<&Clause> {
+        let [Expr::Clause(first), Expr::Clause(second)] = exprs else {
+            return None;
+        };
+        let is_empty = |clause: &Clause| {
+            clause.operator == Operator::Is && clause.operand == Operand::Single(Value::Empty)
+        };
+        let (clause, empty) = if is_empty(second) {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        (is_empty(empty)
+            && clause.operator.is_negative()
+            && clause.field.eq_ignore_ascii_case(&empty.field))
+        .then_some(clause)
+    }
+
+    /// Apply a clause parsed from JQL, failing if it can't be expressed.
+    fn read_clause(&mut self, clause: &Clause, not: bool) -> Option<()> {
+        let negated = not != clause.operator.is_negative();
+        let operator = if clause.operator.is_negative() {
+            clause.operator.negated()?
+        } else {
+            clause.operator
+        };
+        let single = match &clause.operand {
+            Operand::Single(value) => Some(value),
+            Operand::List(_) => None,
+        };
+        let field = clause.field.to_lowercase();
+
+        let field = match field.as_str() {
+            "status" => {
+                Self::read_list(&mut self.statuses, operator, &clause.operand)?;
+                FilterField::Status
             }
-            Some(SprintFilter::Specific(id)) => {
-                clauses.push(format!("sprint = {}", id));
+            "assignee" => {
+                Self::read_user(
+                    &mut self.assignee_is_me,
+                    &mut self.assignees,
+                    operator,
+                    &clause.operand,
+                )?;
+                FilterField::Assignee
             }
-            None => {}
+            "reporter" => {
+                Self::read_user(
+                    &mut self.reporter_is_me,
+                    &mut self.reporters,
+                    operator,
+                    &clause.operand,
+                )?;
+                FilterField::Reporter
+            }
+            "project" => {
+                let value = single?.as_str()?;
+                if operator != Operator::Equals || self.project.is_some() {
+                    return None;
+                }
+                self.project = Some(value.to_string());
+                FilterField::Project
+            }
+            "labels" | "label" => {
+                Self::read_list(&mut self.labels, operator, &clause.operand)?;
+                FilterField::Labels
+            }
+            "component" => {
+                Self::read_list(&mut self.components, operator, &clause.operand)?;
+                FilterField::Components
+            }
+            "parent" => {
+                Self::read_list(&mut self.epics, operator, &clause.operand)?;
+                FilterField::Epic
+            }
+            "issuetype" | "type" => {
+                Self::read_list(&mut self.issue_types, operator, &clause.operand)?;
+                FilterField::IssueType
+            }
+            "priority" => {
+                Self::read_list(&mut self.priorities, operator, &clause.operand)?;
+                FilterField::Priority
+            }
+            "sprint" => {
+                let value = single?;
+                let sprint = match operator {
+                    Operator::In if value.is_call("openSprints") => SprintFilter::Current,
+                    Operator::Equals => SprintFilter::Specific(value.as_str()?.to_string()),
+                    _ => return None,
+                };
+                if self.sprint.replace(sprint).is_some() {
+                    return None;
+                }
+                FilterField::Sprint
+            }
+            "created" | "createddate" | "updated" | "updateddate" => {
+                let date = date_string(single?)?;
+                let range = if field.starts_with("created") {
+                    self.created.get_or_insert_with(DateRange::default)
+                } else {
+                    self.updated.get_or_insert_with(DateRange::default)
+                };
+                let bound = match operator {
+                    Operator::GreaterThanOrEquals if !negated => &mut range.from,
+                    Operator::LessThanOrEquals if !negated => &mut range.to,
+                    _ => return None,
+                };
+                return bound.replace(date).is_none().then_some(());
+            }
+            "resolution" => {
+                let resolution = match (operator, single?) {
+                    (Operator::Is, Value::Empty) => ResolutionFilter::Unresolved,
+                    (Operator::Equals, value)
+                        if value
+                            .as_str()
+                            .is_some_and(|v| v.eq_ignore_ascii_case("unresolved")) =>
+                    {
+                        ResolutionFilter::Unresolved
+                    }
+                    _ => return None,
+                };
+                let resolution = match (resolution, negated) {
+                    (resolution, false) => resolution,
+                    (_, true) => ResolutionFilter::Resolved,
+                };
+                return self.resolution.replace(resolution).is_none().then_some(());
+            }
+            "text" => {
+                let text = single?.as_str()?;
+                if operator != Operator::Contains || negated || self.text.is_some() {
+                    return None;
+                }
+                self.text = Some(text.to_string());
+                return Some(());
+            }
+            _ => return None,
+        };
+
+        if negated {
+            self.negated.push(field);
         }
+        Some(())
+    }
 
-        if !self.epics.is_empty() {
-            let epics = self
-                .epics
+    /// Read the values of a multi-select field, failing if it is already set.
+    fn read_list(values: &mut Vec<String>, operator: Operator, operand: &Operand) -> Option<()> {
+        if !values.is_empty() {
+            return None;
+        }
+        let read = match (operator, operand) {
+            (Operator::In, Operand::List(list)) => list
                 .iter()
-                .map(|e| format!("\"{}\"", e))
-                .collect::<Vec<_>>()
-                .join(", ");
-            clauses.push(format!("parent IN ({})", epics));
+                .map(|value| value.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()?,
+            (Operator::Equals, Operand::Single(value)) => vec![value.as_str()?.to_string()],
+            _ => return None,
+        };
+        if read.is_empty() {
+            return None;
         }
-
-        if clauses.is_empty() {
-            String::new()
-        } else {
-            clauses.join(" AND ")
+        *values = read;
+        Some(())
+    }
+
+    /// Read a user field that may be `currentUser()`.
+    fn read_user(
+        is_me: &mut bool,
+        users: &mut Vec<String>,
+        operator: Operator,
+        operand: &Operand,
+    ) -> Option<()> {
+        match (operator, operand) {
+            (Operator::Equals, Operand::Single(value)) if value.is_call("currentUser") => {
+                if *is_me || !users.is_empty() {
+                    return None;
+                }
+                *is_me = true;
+                Some(())
+            }
+            _ if *is_me => None,
+            _ => Self::read_list(users, operator, operand),
         }
     }
 
@@ -1138,6 +1873,14 @@ impl FilterState {
             && self.components.is_empty()
             && self.sprint.is_none()
             && self.epics.is_empty()
+            && self.issue_types.is_empty()
+            && self.priorities.is_empty()
+            && self.reporters.is_empty()
+            && !self.reporter_is_me
+            && self.created.as_ref().map_or(true, DateRange::is_empty)
+            && self.updated.as_ref().map_or(true, DateRange::is_empty)
+            && self.text.is_none()
+            && self.resolution.is_none()
     }
 
     /// Clear all filters.
@@ -1148,46 +1891,133 @@ impl FilterState {
     /// Get a summary of active filters for display.
     pub fn summary(&self) -> Vec<String> {
         let mut parts = Vec::new();
+        let not = |field| if self.is_negated(field) { "not " } else { "" };
 
         if !self.statuses.is_empty() {
-            parts.push(format!("Status: {}", self.statuses.join(", ")));
+            parts.push(format!(
+                "Status: {}{}",
+                not(FilterField::Status),
+                self.statuses.join(", ")
+            ));
         }
 
         if self.assignee_is_me {
-            parts.push("Assigned to me".to_string());
+            if self.is_negated(FilterField::Assignee) {
+                parts.push("Not assigned to me".to_string());
+            } else {
+                parts.push("Assigned to me".to_string());
+            }
         } else if !self.assignees.is_empty() {
-            parts.push(format!("Assignee: {} selected", self.assignees.len()));
+            parts.push(format!(
+                "Assignee: {}{} selected",
+                not(FilterField::Assignee),
+                self.assignees.len()
+            ));
         }
 
         if let Some(project) = &self.project {
-            parts.push(format!("Project: {}", project));
+            parts.push(format!("Project: {}{}", not(FilterField::Project), project));
         }
 
         if !self.labels.is_empty() {
-            parts.push(format!("Labels: {}", self.labels.join(", ")));
+            parts.push(format!(
+                "Labels: {}{}",
+                not(FilterField::Labels),
+                self.labels.join(", ")
+            ));
         }
 
         if !self.components.is_empty() {
-            parts.push(format!("Components: {}", self.components.join(", ")));
+            parts.push(format!(
+                "Components: {}{}",
+                not(FilterField::Components),
+                self.components.join(", ")
+            ));
         }
 
         match &self.sprint {
             Some(SprintFilter::Current) => {
-                parts.push("Sprint: Current".to_string());
+                parts.push(format!("Sprint: {}Current", not(FilterField::Sprint)));
             }
             Some(SprintFilter::Specific(name)) => {
-                parts.push(format!("Sprint: {}", name));
+                parts.push(format!("Sprint: {}{}", not(FilterField::Sprint), name));
             }
             None => {}
         }
 
         if !self.epics.is_empty() {
-            parts.push(format!("Epic: {}", self.epics.join(", ")));
+            parts.push(format!(
+                "Epic: {}{}",
+                not(FilterField::Epic),
+                self.epics.join(", ")
+            ));
+        }
+
+        if !self.issue_types.is_empty() {
+            parts.push(format!(
+                "Type: {}{}",
+                not(FilterField::IssueType),
+                self.issue_types.join(", ")
+            ));
+        }
+
+        if !self.priorities.is_empty() {
+            parts.push(format!(
+                "Priority: {}{}",
+                not(FilterField::Priority),
+                self.priorities.join(", ")
+            ));
+        }
+
+        if self.reporter_is_me {
+            if self.is_negated(FilterField::Reporter) {
+                parts.push("Not reported by me".to_string());
+            } else {
+                parts.push("Reported by me".to_string());
+            }
+        } else if !self.reporters.is_empty() {
+            parts.push(format!(
+                "Reporter: {}{} selected",
+                not(FilterField::Reporter),
+                self.reporters.len()
+            ));
+        }
+
+        if let Some(created) = self.created.as_ref().filter(|range| !range.is_empty()) {
+            parts.push(format!("Created: {}", created.summary()));
+        }
+
+        if let Some(updated) = self.updated.as_ref().filter(|range| !range.is_empty()) {
+            parts.push(format!("Updated: {}", updated.summary()));
+        }
+
+        match self.resolution {
+            Some(ResolutionFilter::Unresolved) => parts.push("Unresolved".to_string()),
+            Some(ResolutionFilter::Resolved) => parts.push("Resolved".to_string()),
+            None => {}
+        }
+
+        if let Some(text) = &self.text {
+            parts.push(format!("Text: \"{}\"", text));
         }
 
         parts
     }
 
+    /// Check whether a filter excludes its values instead of matching them.
+    pub fn is_negated(&self, field: FilterField) -> bool {
+        self.negated.contains(&field)
+    }
+
+    /// Toggle whether a filter excludes its values instead of matching them.
+    pub fn toggle_negated(&mut self, field: FilterField) {
+        if let Some(pos) = self.negated.iter().position(|f| *f == field) {
+            self.negated.remove(pos);
+        } else {
+            self.negated.push(field);
+        }
+    }
+
     /// Toggle a status in the filter.
     pub fn toggle_status(&mut self, status: &str) {
         if let Some(pos) = self.statuses.iter().position(|s| s == status) {
@@ -1253,6 +2083,9 @@ pub struct SavedFilter {
     pub name: String,
     /// The filter state to apply when this filter is selected.
     pub filter: FilterState,
+    /// Issue list columns to show while this filter is applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<ColumnConfig>>,
 }
 
 impl SavedFilter {
@@ -1261,6 +2094,7 @@ impl SavedFilter {
         Self {
             name: name.into(),
             filter,
+            columns: None,
         }
     }
 }
@@ -1301,6 +2135,10 @@ pub struct FilterOptions {
     pub sprints: Vec<FilterOption>,
     /// Available epics.
     pub epics: Vec<FilterOption>,
+    /// Available issue types (name as ID).
+    pub issue_types: Vec<FilterOption>,
+    /// Available priorities (name as ID).
+    pub priorities: Vec<FilterOption>,
 }
 
 impl FilterOptions {
@@ -1526,23 +2364,38 @@ impl serde::Serialize for NullableUserRef {
     }
 }
 
-/// Reference to a priority by ID.
+/// Reference to a priority by ID or name.
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct PriorityRef {
-    /// The priority ID.
+    /// The priority ID (empty when referring by name).
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub id: String,
+    /// The priority name, used when the ID is not known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 impl PriorityRef {
     /// Create a new priority reference.
     pub fn new(id: impl Into<String>) -> Self {
-        Self { id: id.into() }
+        Self {
+            id: id.into(),
+            name: None,
+        }
+    }
+
+    /// Create a priority reference by name (e.g. from an issue template).
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            id: String::new(),
+            name: Some(name.into()),
+        }
     }
 }
 
 /// Complex update operations for list fields.
 ///
-/// Used for operations like add/remove on labels and components.
+/// Used for operations like add/remove on labels, components and versions.
 #[derive(Debug, Clone, Serialize, Default, PartialEq)]
 pub struct UpdateOperations {
     /// Label add/remove operations.
@@ -1551,6 +2404,12 @@ pub struct UpdateOperations {
     /// Component add/remove operations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub components: Option<Vec<ComponentOperation>>,
+    /// Fix version add/remove operations.
+    #[serde(rename = "fixVersions", skip_serializing_if = "Option::is_none")]
+    pub fix_versions: Option<Vec<VersionOperation>>,
+    /// Affects version add/remove operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub versions: Option<Vec<VersionOperation>>,
 }
 
 /// Operation to add or remove a label.
@@ -1573,6 +2432,16 @@ pub enum ComponentOperation {
     Remove { name: String },
 }
 
+/// Operation to add or remove a fix or affects version.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionOperation {
+    /// Add a version by name.
+    Add { name: String },
+    /// Remove a version by name.
+    Remove { name: String },
+}
+
 // ============================================================================
 // Transition Types
 // ============================================================================
@@ -1608,39 +2477,198 @@ pub struct TransitionTarget {
     pub name: String,
     /// The status category.
     #[serde(default)]
-    pub status_category: Option<StatusCategory>,
+    pub status_category: Option<StatusCategory>,
+}
+
+/// A field that may be required during a transition.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransitionField {
+    /// Whether this field is required for the transition.
+    pub required: bool,
+    /// The field name.
+    pub name: String,
+}
+
+/// Request to perform a status transition.
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitionRequest {
+    /// The transition to perform.
+    pub transition: TransitionRef,
+    /// Optional fields to set during the transition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<FieldUpdates>,
+}
+
+/// Reference to a transition by ID.
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitionRef {
+    /// The transition ID.
+    pub id: String,
+}
+
+impl TransitionRef {
+    /// Create a new transition reference.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+}
+
+// ============================================================================
+// Version Types
+// ============================================================================
+
+/// A project version (release).
+///
+/// Returned by `GET /rest/api/3/project/{projectIdOrKey}/versions` and as
+/// part of the `fixVersions` and `versions` issue fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Version {
+    /// The version ID.
+    pub id: String,
+    /// The version name (e.g., "1.2.0").
+    pub name: String,
+    /// The version description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Whether the version has been released.
+    #[serde(default)]
+    pub released: bool,
+    /// Whether the version is archived.
+    #[serde(default)]
+    pub archived: bool,
+    /// The release date (YYYY-MM-DD).
+    #[serde(default)]
+    pub release_date: Option<String>,
+    /// The start date (YYYY-MM-DD).
+    #[serde(default)]
+    pub start_date: Option<String>,
+}
+
+/// Request body for creating a version.
+///
+/// Sent to `POST /rest/api/3/version`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateVersionRequest {
+    /// The version name.
+    pub name: String,
+    /// The ID of the project the version belongs to.
+    pub project_id: u64,
+    /// Optional version description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Request body for releasing a version.
+///
+/// Sent to `PUT /rest/api/3/version/{id}`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseVersionRequest {
+    /// Always true; marks the version as released.
+    pub released: bool,
+    /// The release date (YYYY-MM-DD).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    /// URL of the version that unresolved issues are moved to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub move_unfixed_issues_to: Option<String>,
+}
+
+/// The issue field holding a list of versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionField {
+    /// The versions the issue is fixed in (`fixVersions`).
+    Fix,
+    /// The versions the issue affects (`versions`).
+    Affects,
+}
+
+impl VersionField {
+    /// Get the JIRA field ID.
+    pub fn field_id(&self) -> &'static str {
+        match self {
+            Self::Fix => "fixVersions",
+            Self::Affects => "versions",
+        }
+    }
+
+    /// Get the display label for this field.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Fix => "Fix versions",
+            Self::Affects => "Affects versions",
+        }
+    }
+}
+
+// ============================================================================
+// Watcher and Vote Types
+// ============================================================================
+
+/// Watch state of an issue, as returned in the `watches` field.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Watches {
+    /// Number of users watching the issue.
+    #[serde(default)]
+    pub watch_count: u32,
+    /// Whether the current user is watching the issue.
+    #[serde(default)]
+    pub is_watching: bool,
 }
 
-/// A field that may be required during a transition.
-#[derive(Debug, Clone, Deserialize)]
-pub struct TransitionField {
-    /// Whether this field is required for the transition.
-    pub required: bool,
-    /// The field name.
-    pub name: String,
+/// Vote state of an issue, as returned in the `votes` field.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Votes {
+    /// Number of votes for the issue.
+    #[serde(default)]
+    pub votes: u32,
+    /// Whether the current user has voted for the issue.
+    #[serde(default)]
+    pub has_voted: bool,
 }
 
-/// Request to perform a status transition.
-#[derive(Debug, Clone, Serialize)]
-pub struct TransitionRequest {
-    /// The transition to perform.
-    pub transition: TransitionRef,
-    /// Optional fields to set during the transition.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fields: Option<FieldUpdates>,
+/// Watchers of an issue.
+///
+/// Returned by `GET /rest/api/3/issue/{issueKey}/watchers`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Watchers {
+    /// Number of users watching the issue.
+    #[serde(default)]
+    pub watch_count: u32,
+    /// Whether the current user is watching the issue.
+    #[serde(default)]
+    pub is_watching: bool,
+    /// The users watching the issue.
+    #[serde(default)]
+    pub watchers: Vec<User>,
 }
 
-/// Reference to a transition by ID.
-#[derive(Debug, Clone, Serialize)]
-pub struct TransitionRef {
-    /// The transition ID.
-    pub id: String,
+/// A change to the watchers or votes of an issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchChange {
+    /// Start watching the issue.
+    Watch,
+    /// Stop watching the issue.
+    Unwatch,
+    /// Add another user as a watcher (account ID, display name).
+    AddWatcher(String, String),
+    /// Remove a watcher (account ID, display name).
+    RemoveWatcher(String, String),
+    /// Vote for the issue.
+    Vote,
+    /// Remove the current user's vote.
+    Unvote,
 }
 
-impl TransitionRef {
-    /// Create a new transition reference.
-    pub fn new(id: impl Into<String>) -> Self {
-        Self { id: id.into() }
+impl WatchChange {
+    /// Check if this change is a vote or unvote.
+    pub fn is_vote(&self) -> bool {
+        matches!(self, Self::Vote | Self::Unvote)
     }
 }
 
@@ -1808,6 +2836,115 @@ impl ChangeType {
     }
 }
 
+// ============================================================================
+// JQL Autocomplete Types
+// ============================================================================
+
+/// Field and function names for JQL autocomplete.
+///
+/// Returned by `GET /rest/api/3/jql/autocompletedata`.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlAutocompleteData {
+    /// Fields that can be used in queries.
+    #[serde(default)]
+    pub visible_field_names: Vec<JqlFieldReference>,
+    /// Functions that can be used as values.
+    #[serde(default)]
+    pub visible_function_names: Vec<JqlFunctionReference>,
+    /// Words reserved by JQL.
+    #[serde(default)]
+    pub jql_reserved_words: Vec<String>,
+}
+
+impl JqlAutocompleteData {
+    /// Find a field by the name used in queries, ignoring case.
+    pub fn field(&self, name: &str) -> Option<&JqlFieldReference> {
+        let name = name.trim_matches('"');
+        self.visible_field_names
+            .iter()
+            .find(|f| f.value.trim_matches('"').eq_ignore_ascii_case(name))
+    }
+}
+
+/// A field that can be used in JQL.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlFieldReference {
+    /// The name used in queries (quoted for names with spaces).
+    pub value: String,
+    /// The display name.
+    #[serde(default)]
+    pub display_name: String,
+    /// The operators the field supports.
+    #[serde(default)]
+    pub operators: Vec<String>,
+}
+
+/// A function that can be used in JQL.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlFunctionReference {
+    /// The function as used in queries (e.g. `currentUser()`).
+    pub value: String,
+    /// The display name.
+    #[serde(default)]
+    pub display_name: String,
+}
+
+/// Suggested values for a JQL field.
+///
+/// Returned by `GET /rest/api/3/jql/autocompletedata/suggestions`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JqlSuggestionsResponse {
+    /// The suggestions.
+    #[serde(default)]
+    pub results: Vec<JqlSuggestion>,
+}
+
+/// A suggested value for a JQL field.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlSuggestion {
+    /// The value as used in queries (e.g. an account ID for users).
+    pub value: String,
+    /// The display name, with the matched text in `<b>` tags.
+    #[serde(default)]
+    pub display_name: String,
+}
+
+impl JqlSuggestion {
+    /// Get the display name without highlighting tags.
+    pub fn display_text(&self) -> String {
+        let text = if self.display_name.is_empty() {
+            &self.value
+        } else {
+            &self.display_name
+        };
+        text.replace("<b>", "").replace("</b>", "")
+    }
+}
+
+/// Result of parsing JQL queries.
+///
+/// Returned by `POST /rest/api/3/jql/parse`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JqlParseResponse {
+    /// One result per query.
+    #[serde(default)]
+    pub queries: Vec<ParsedJqlQuery>,
+}
+
+/// A parsed JQL query.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParsedJqlQuery {
+    /// The query.
+    pub query: String,
+    /// Errors found in the query.
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1881,6 +3018,7 @@ mod tests {
                 issue_links: vec![],
                 subtasks: vec![],
                 parent: None,
+                extra_fields: Default::default(),
             },
         }
     }
@@ -2103,6 +3241,7 @@ mod tests {
                 issue_links: vec![],
                 subtasks: vec![],
                 parent: None,
+                extra_fields: Default::default(),
             },
         };
         assert_eq!(issue_with_data.assignee_name(), "John Doe");
@@ -2617,6 +3756,155 @@ mod tests {
         assert!(summary.iter().any(|s| s.contains("Project: PROJ")));
     }
 
+    #[test]
+    fn test_filter_state_escapes_values() {
+        let mut filter = FilterState::new();
+        filter.toggle_label(r#"say "hi""#);
+        filter.toggle_component(r"C:\path");
+
+        assert_eq!(
+            filter.to_jql(),
+            r#"labels IN ("say \"hi\"") AND component IN ("C:\\path")"#
+        );
+        assert_eq!(FilterState::from_jql(&filter.to_jql()), Some(filter));
+    }
+
+    #[test]
+    fn test_filter_state_negated() {
+        let mut filter = FilterState::new();
+        filter.toggle_status("Done");
+        filter.toggle_assigned_to_me();
+        filter.set_project(Some("PROJ".to_string()));
+        filter.set_sprint(Some(SprintFilter::Current));
+        filter.toggle_negated(FilterField::Status);
+        filter.toggle_negated(FilterField::Assignee);
+        filter.toggle_negated(FilterField::Project);
+        filter.toggle_negated(FilterField::Sprint);
+
+        // Negated optional fields keep issues without a value
+        assert_eq!(
+            filter.to_jql(),
+            r#"status NOT IN ("Done") AND (assignee != currentUser() OR assignee IS EMPTY) AND project != "PROJ" AND (sprint NOT IN openSprints() OR sprint IS EMPTY)"#
+        );
+        assert_eq!(
+            FilterState::from_jql(&filter.to_jql()),
+            Some(filter.clone())
+        );
+        let summary = filter.summary();
+        assert!(summary.contains(&"Status: not Done".to_string()));
+        assert!(summary.contains(&"Not assigned to me".to_string()));
+
+        filter.toggle_negated(FilterField::Status);
+        assert!(!filter.is_negated(FilterField::Status));
+        assert!(filter.to_jql().starts_with(r#"status IN ("Done")"#));
+    }
+
+    #[test]
+    fn test_filter_state_serializes_only_set_fields() {
+        let mut filter = FilterState::new();
+        filter.toggle_status("Done");
+        filter.toggle_negated(FilterField::Status);
+
+        let toml = toml::to_string(&filter).unwrap();
+        assert_eq!(toml, "statuses = [\"Done\"]\nnegated = [\"status\"]\n");
+        assert_eq!(toml::from_str::<FilterState>(&toml).unwrap(), filter);
+    }
+
+    #[test]
+    fn test_filter_state_extended_fields() {
+        let filter = FilterState {
+            issue_types: vec!["Bug".to_string()],
+            priorities: vec!["High".to_string(), "Highest".to_string()],
+            reporter_is_me: true,
+            created: Some(DateRange {
+                from: Some("2024-01-01".to_string()),
+                to: Some("endOfMonth()".to_string()),
+            }),
+            updated: Some(DateRange {
+                from: Some("-7d".to_string()),
+                to: None,
+            }),
+            text: Some("null pointer".to_string()),
+            resolution: Some(ResolutionFilter::Unresolved),
+            ..FilterState::default()
+        };
+
+        assert!(!filter.is_empty());
+        assert_eq!(
+            filter.to_jql(),
+            r#"issuetype IN ("Bug") AND priority IN ("High", "Highest") AND reporter = currentUser() AND created >= "2024-01-01" AND created <= endOfMonth() AND updated >= "-7d" AND resolution IS EMPTY AND text ~ "null pointer""#
+        );
+        let summary = filter.summary();
+        assert!(summary.contains(&"Created: 2024-01-01 to endOfMonth()".to_string()));
+        assert!(summary.contains(&"Updated: since -7d".to_string()));
+        assert!(summary.contains(&"Unresolved".to_string()));
+    }
+
+    #[test]
+    fn test_filter_state_from_jql_round_trip() {
+        let mut filter = FilterState::new();
+        filter.toggle_status("In Progress");
+        filter.toggle_assignee("abc123");
+        filter.set_project(Some("PROJ".to_string()));
+        filter.set_sprint(Some(SprintFilter::Specific("42".to_string())));
+        filter.epics.push("PROJ-1".to_string());
+        filter.reporters.push("def456".to_string());
+        filter.issue_types.push("Story".to_string());
+        filter.toggle_negated(FilterField::IssueType);
+        filter.resolution = Some(ResolutionFilter::Resolved);
+        filter.created = Some(DateRange {
+            from: None,
+            to: Some("startOfWeek(-1)".to_string()),
+        });
+
+        assert_eq!(FilterState::from_jql(&filter.to_jql()), Some(filter));
+        assert_eq!(FilterState::from_jql(""), Some(FilterState::default()));
+    }
+
+    #[test]
+    fn test_filter_state_from_jql_handwritten() {
+        let filter = FilterState::from_jql(
+            "project = PROJ and (labels not in (bug, 'needs triage') or labels is EMPTY) AND type = Bug AND resolution = Unresolved",
+        )
+        .unwrap();
+        assert_eq!(filter.project.as_deref(), Some("PROJ"));
+        assert_eq!(filter.labels, vec!["bug", "needs triage"]);
+        assert!(filter.is_negated(FilterField::Labels));
+        assert_eq!(filter.issue_types, vec!["Bug"]);
+        assert_eq!(filter.resolution, Some(ResolutionFilter::Unresolved));
+    }
+
+    #[test]
+    fn test_filter_state_from_jql_rejects_unsupported() {
+        for jql in [
+            "status = Open OR status = Done",
+            "status = Open ORDER BY created",
+            "status = Open AND status = Done",
+            "summary ~ crash",
+            "created > -7d",
+            "NOT (status = Open AND project = A)",
+            "status = ",
+            // Negated optional fields must keep issues without a value
+            "labels NOT IN (bug)",
+            "NOT labels IN (bug)",
+            "(status != Done OR status IS EMPTY)",
+            "(labels NOT IN (bug) OR component IS EMPTY)",
+            "(labels IN (bug) OR labels IS EMPTY)",
+        ] {
+            assert_eq!(FilterState::from_jql(jql), None, "{}", jql);
+        }
+    }
+
+    #[test]
+    fn test_filter_state_deserializes_without_new_fields() {
+        let filter: FilterState = toml::from_str(r#"statuses = ["Open"]"#).unwrap();
+        assert_eq!(filter.statuses, vec!["Open"]);
+
+        let toml = toml::to_string(&filter).unwrap();
+        assert!(!toml.contains("negated"));
+        assert!(!toml.contains("reporter_is_me"));
+    }
+
     #[test]
     fn test_filter_option_new() {
         let opt = FilterOption::new("id123", "My Label");
@@ -2726,6 +4014,64 @@ mod tests {
         assert_eq!(json, r#"{"id":"2"}"#);
     }
 
+    #[test]
+    fn test_priority_ref_named_serialization() {
+        let priority_ref = PriorityRef::named("High");
+        let json = serde_json::to_string(&priority_ref).unwrap();
+        assert_eq!(json, r#"{"name":"High"}"#);
+    }
+
+    #[test]
+    fn test_create_field_meta_deserialization() {
+        let json = r#"{
+            "fields": [
+                {
+                    "fieldId": "customfield_10020",
+                    "name": "Sprint",
+                    "required": false,
+                    "schema": {
+                        "type": "array",
+                        "items": "json",
+                        "custom": "com.pyxis.greenhopper.jira:gh-sprint"
+                    }
+                },
+                {
+                    "fieldId": "fixVersions",
+                    "name": "Fix versions",
+                    "required": true,
+                    "hasDefaultValue": false,
+                    "schema": {"type": "array", "items": "version", "system": "fixVersions"},
+                    "allowedValues": [{"id": "10000", "name": "1.0"}]
+                },
+                {
+                    "fieldId": "customfield_10050",
+                    "name": "Severity",
+                    "required": true,
+                    "hasDefaultValue": true,
+                    "schema": {"type": "option"},
+                    "allowedValues": [{"id": "1", "value": "Major"}]
+                },
+                {
+                    "fieldId": "summary",
+                    "name": "Summary",
+                    "required": true,
+                    "schema": {"type": "string", "system": "summary"}
+                }
+            ]
+        }"#;
+        let response: CreateFieldMetaResponse = serde_json::from_str(json).unwrap();
+        let fields = &response.fields;
+        assert_eq!(fields.len(), 4);
+
+        assert_eq!(fields[0].kind(), CreateFieldKind::Sprint);
+        assert_eq!(fields[1].kind(), CreateFieldKind::MultiSelect);
+        assert!(fields[1].must_be_set());
+        assert_eq!(fields[2].kind(), CreateFieldKind::Select);
+        assert_eq!(fields[2].allowed_values[0].label(), "Major");
+        assert!(!fields[2].must_be_set());
+        assert!(fields[3].is_built_in());
+    }
+
     #[test]
     fn test_label_operation_add_serialization() {
         let op = LabelOperation::Add("bug".to_string());
@@ -2892,6 +4238,7 @@ mod tests {
                 components: Some(vec![ComponentOperation::Add {
                     name: "API".to_string(),
                 }]),
+                ..Default::default()
             }),
         };
 
@@ -2937,6 +4284,177 @@ mod tests {
         assert_eq!(json, r#"{"fields":{"assignee":null}}"#);
     }
 
+    // ========================================================================
+    // Version Types tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_version() {
+        let json = r#"{
+            "self": "https://company.atlassian.net/rest/api/3/version/10000",
+            "id": "10000",
+            "name": "1.2.0",
+            "archived": false,
+            "released": true,
+            "releaseDate": "2024-03-01",
+            "projectId": 10001
+        }"#;
+
+        let version: Version = serde_json::from_str(json).unwrap();
+        assert_eq!(version.id, "10000");
+        assert_eq!(version.name, "1.2.0");
+        assert!(version.released);
+        assert!(!version.archived);
+        assert_eq!(version.release_date.as_deref(), Some("2024-03-01"));
+        assert!(version.description.is_none());
+    }
+
+    #[test]
+    fn test_issue_versions() {
+        let json = r#"{
+            "id": "10001",
+            "key": "PROJ-123",
+            "fields": {
+                "summary": "Test",
+                "fixVersions": [{"id": "10000", "name": "1.2.0", "released": false}],
+                "versions": [
+                    {"id": "9998", "name": "1.0.0", "released": true},
+                    {"id": "9999", "name": "1.1.0", "released": true}
+                ]
+            }
+        }"#;
+
+        let issue: Issue = serde_json::from_str(json).unwrap();
+        let fix = issue.versions(VersionField::Fix);
+        assert_eq!(fix.len(), 1);
+        assert_eq!(fix[0].name, "1.2.0");
+        let affects = issue.versions(VersionField::Affects);
+        assert_eq!(affects.len(), 2);
+        assert_eq!(affects[1].name, "1.1.0");
+    }
+
+    #[test]
+    fn test_issue_versions_missing() {
+        let json = r#"{"id": "10001", "key": "PROJ-123", "fields": {"summary": "Test"}}"#;
+
+        let issue: Issue = serde_json::from_str(json).unwrap();
+        assert!(issue.versions(VersionField::Fix).is_empty());
+    }
+
+    #[test]
+    fn test_serialize_version_operations() {
+        let ops = UpdateOperations {
+            fix_versions: Some(vec![VersionOperation::Add {
+                name: "1.2.0".to_string(),
+            }]),
+            versions: Some(vec![VersionOperation::Remove {
+                name: "1.0.0".to_string(),
+            }]),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&ops).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "fixVersions": [{"add": {"name": "1.2.0"}}],
+                "versions": [{"remove": {"name": "1.0.0"}}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_serialize_release_version_request() {
+        let request = ReleaseVersionRequest {
+            released: true,
+            release_date: Some("2024-03-01".to_string()),
+            move_unfixed_issues_to: None,
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"released": true, "releaseDate": "2024-03-01"})
+        );
+    }
+
+    // ========================================================================
+    // Watcher and Vote Types tests
+    // ========================================================================
+
+    #[test]
+    fn test_issue_watches_and_votes() {
+        let json = r#"{
+            "id": "10001",
+            "key": "PROJ-123",
+            "fields": {
+                "summary": "Test issue",
+                "watches": {
+                    "self": "https://company.atlassian.net/rest/api/3/issue/PROJ-123/watchers",
+                    "watchCount": 3,
+                    "isWatching": true
+                },
+                "votes": {
+                    "votes": 2,
+                    "hasVoted": false
+                }
+            }
+        }"#;
+
+        let issue: Issue = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            issue.watches(),
+            Some(Watches {
+                watch_count: 3,
+                is_watching: true
+            })
+        );
+        assert_eq!(
+            issue.votes(),
+            Some(Votes {
+                votes: 2,
+                has_voted: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_issue_watches_missing() {
+        let json = r#"{"id": "10001", "key": "PROJ-123", "fields": {"summary": "Test"}}"#;
+
+        let issue: Issue = serde_json::from_str(json).unwrap();
+        assert!(issue.watches().is_none());
+        assert!(issue.votes().is_none());
+    }
+
+    #[test]
+    fn test_parse_watchers() {
+        let json = r#"{
+            "self": "https://company.atlassian.net/rest/api/3/issue/PROJ-123/watchers",
+            "isWatching": false,
+            "watchCount": 1,
+            "watchers": [
+                {
+                    "accountId": "abc123",
+                    "displayName": "Alice Smith",
+                    "active": true
+                }
+            ]
+        }"#;
+
+        let watchers: Watchers = serde_json::from_str(json).unwrap();
+        assert_eq!(watchers.watch_count, 1);
+        assert!(!watchers.is_watching);
+        assert_eq!(watchers.watchers.len(), 1);
+        assert_eq!(watchers.watchers[0].display_name, "Alice Smith");
+    }
+
+    #[test]
+    fn test_watch_change_is_vote() {
+        assert!(WatchChange::Vote.is_vote());
+        assert!(WatchChange::Unvote.is_vote());
+        assert!(!WatchChange::Watch.is_vote());
+        assert!(!WatchChange::RemoveWatcher("abc".to_string(), "Alice".to_string()).is_vote());
+    }
+
     // ========================================================================
     // Changelog Types tests
     // ========================================================================
@@ -2999,6 +4517,34 @@ mod tests {
         assert!(!changelog.has_more());
     }
 
+    #[test]
+    fn test_jql_autocomplete_deserialization() {
+        let json = r#"{
+            "visibleFieldNames": [
+                {"value": "status", "displayName": "Status", "operators": ["=", "!=", "in"]},
+                {"value": "\"Story Points\"", "displayName": "Story Points", "cfid": "cf[10016]"}
+            ],
+            "visibleFunctionNames": [{"value": "currentUser()", "displayName": "currentUser()"}],
+            "jqlReservedWords": ["and", "or"]
+        }"#;
+        let data: JqlAutocompleteData = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            data.field("STATUS").unwrap().operators,
+            vec!["=", "!=", "in"]
+        );
+        assert!(data.field("Story Points").unwrap().operators.is_empty());
+        assert_eq!(data.visible_function_names[0].value, "currentUser()");
+
+        let json =
+            r#"{"results": [{"value": "In Progress", "displayName": "<b>In</b> Progress"}]}"#;
+        let response: JqlSuggestionsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.results[0].display_text(), "In Progress");
+
+        let json = r#"{"queries": [{"query": "x = ", "errors": ["Error in the JQL Query"]}]}"#;
+        let response: JqlParseResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.queries[0].errors.len(), 1);
+    }
+
     #[test]
     fn test_changelog_has_more() {
         // First page with more to come
@@ -6,6 +6,10 @@
 // Many public methods are part of the App API for external use and testing
 #![allow(dead_code)]
 
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
 use tracing::{debug, info, trace, warn};
 
 use ratatui::{
@@ -17,26 +21,40 @@ use ratatui::{
 };
 
 use crate::api::auth;
+use crate::api::columns::{ColumnField, FieldIds};
 use crate::api::types::{
-    AtlassianDoc, Changelog, CreateIssueFields, CreateIssueRequest, FieldUpdates, FilterOptions,
-    FilterState, Issue, IssueTypeRef, IssueUpdateRequest, ParentRef, Priority, PriorityRef,
-    ProjectRef, SavedFilter, Transition, User, UserRef,
+    AtlassianDoc, Changelog, ComponentRef, CreateIssueFields, CreateIssueRequest, FieldUpdates,
+    FilterOptions, FilterState, Issue, IssueSuggestion, IssueTypeRef, IssueUpdateRequest,
+    JqlAutocompleteData, JqlSuggestion, ParentRef, Priority, PriorityRef, ProjectRef, SavedFilter,
+    Transition, User, UserRef, Version, VersionField, VersionOperation, WatchChange, Watchers,
 };
 use crate::commands::CommandAction;
-use crate::config::{Config, ConfigError, Profile};
+use crate::config::{
+    Config, ConfigError, ConfigWatcher, ConflictResolution, IssuePanel, IssueTemplate, JumpList,
+    Location, Profile, ProfileBundle, Settings, TabConfig,
+};
 use crate::error::AppError;
 use crate::events::Event;
-use crate::events::KeyContext;
+use crate::events::{keymap, set_keymap, Action, KeyContext, Keymap};
+use crate::ui::init_vim_editing;
+use crate::ui::theme::{load_theme, set_theme};
 use crate::ui::{
-    render_context_help, CommandPalette, CommandPaletteAction, ConfirmDialog, CreateIssueAction,
+    render_context_help, render_tab_bar, CloneOptionsAction, CloneOptionsDialog, CommandPalette,
+    CommandPaletteAction, ConfirmDialog, CreateFieldsAction, CreateIssueAction,
     CreateIssueRenderData, CreateIssueView, DeleteProfileDialog, DetailAction, DetailView,
-    DropdownAction, DropdownItem, ErrorDialog, FilterPanelAction, FilterPanelView, FormField,
-    HelpAction, HelpView, JqlAction, JqlInput, ListAction, ListView, LoadingIndicator,
-    Notification, NotificationManager, ProfileFormAction, ProfileFormData, ProfileFormView,
-    ProfileListAction, ProfileListView, ProfilePicker, ProfilePickerAction, ProfileSummary,
-    SavedFiltersAction, SavedFiltersDialog,
+    DropdownAction, DropdownItem, ErrorDialog, ExternalEditRequest, ExternalEditTarget,
+    FilterPanelAction, FilterPanelView, FormField, GoToIssueAction, GoToIssuePicker, HelpAction,
+    HelpView, ImportBundleAction, ImportBundleDialog, IssueCandidate, IssueSource, JqlAction,
+    JqlInput, ListAction, ListColumn, ListView, LoadingIndicator, Notification,
+    NotificationManager, ProfileFormAction, ProfileFormData, ProfileFormView, ProfileListAction,
+    ProfileListView, ProfilePicker, ProfilePickerAction, ProfileSummary, ReleaseAction,
+    ReleaseView, SavedFiltersAction, SavedFiltersDialog, SortDirection, SortState, TemplatePicker,
+    TemplatePickerAction, ThemePicker, ThemePickerAction,
 };
 
+/// Maximum number of recently viewed issues listed in the command palette.
+const MAX_RECENT_ISSUES: usize = 20;
+
 /// The current view/screen state of the application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AppState {
@@ -61,6 +79,8 @@ pub enum AppState {
     Exiting,
     /// Creating a new issue.
     CreateIssue,
+    /// Release view listing the versions of a project.
+    Releases,
 }
 
 // ============================================================================
@@ -87,6 +107,8 @@ pub enum CreateIssueFormField {
     Assignee,
     /// Priority selection field.
     Priority,
+    /// Additional fields from the issue type's create metadata.
+    Fields,
     /// Submit button.
     Submit,
 }
@@ -105,7 +127,8 @@ impl CreateIssueFormField {
             Self::Summary => Self::Description,
             Self::Description => Self::Assignee,
             Self::Assignee => Self::Priority,
-            Self::Priority => Self::Submit,
+            Self::Priority => Self::Fields,
+            Self::Fields => Self::Submit,
             Self::Submit => Self::Project,
         }
     }
@@ -124,7 +147,8 @@ impl CreateIssueFormField {
             Self::Description => Self::Summary,
             Self::Assignee => Self::Description,
             Self::Priority => Self::Assignee,
-            Self::Submit => Self::Priority,
+            Self::Fields => Self::Priority,
+            Self::Submit => Self::Fields,
         }
     }
 
@@ -193,7 +217,17 @@ pub struct CreateIssueFormData {
     /// The priority ID (optional).
     pub priority_id: Option<String>,
     /// The priority name for display purposes (optional).
+    ///
+    /// Sent by name when no priority ID is set (e.g. from a template).
     pub priority_name: Option<String>,
+    /// Labels to add to the issue.
+    pub labels: Vec<String>,
+    /// Component names to add to the issue.
+    pub components: Vec<String>,
+    /// Name of the issue template the form was started from.
+    pub template_name: Option<String>,
+    /// Key of the issue being cloned.
+    pub clone_of: Option<String>,
 }
 
 impl CreateIssueFormData {
@@ -239,6 +273,81 @@ impl CreateIssueFormData {
     }
 }
 
+/// Issue type to select once the create form's issue types load.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PendingIssueType {
+    /// The type with this name, matched case-insensitively.
+    Named(String),
+    /// The project's first subtask type.
+    Subtask,
+}
+
+/// A link copied to a clone: (link type name, other issue key, whether the
+/// clone is the outward issue).
+pub type CloneLink = (String, String, bool);
+
+/// Links and subtasks copied to a cloned issue once it is created.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CloneCopies {
+    /// Links to copy.
+    pub links: Vec<CloneLink>,
+    /// Subtasks as (issue type ID, summary).
+    pub subtasks: Vec<(String, String)>,
+}
+
+impl CloneCopies {
+    /// Check if there is nothing to copy.
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty() && self.subtasks.is_empty()
+    }
+}
+
+/// An issue list tab.
+///
+/// The active tab's list, filter, JQL and fetch request live directly on
+/// [`App`] (`list_view`, `filter_state`, `current_jql`, `fetch_request`) and
+/// are swapped in and out of this struct when switching tabs.
+struct WorkspaceTab {
+    /// Tab title set explicitly (e.g. from a saved filter).
+    name: Option<String>,
+    /// The tab's issue list.
+    list_view: ListView,
+    /// The tab's filter state.
+    filter_state: FilterState,
+    /// The tab's direct JQL query.
+    current_jql: Option<String>,
+    /// Id of the tab's latest issue fetch.
+    fetch_request: u64,
+}
+
+impl WorkspaceTab {
+    /// Create a tab with the given list view and no query.
+    fn new(list_view: ListView) -> Self {
+        Self {
+            name: None,
+            list_view,
+            filter_state: FilterState::new(),
+            current_jql: None,
+            fetch_request: 0,
+        }
+    }
+
+    /// Get the title shown in the tab bar.
+    ///
+    /// Uses the explicit name if set, otherwise describes the query.
+    fn title(name: Option<&str>, current_jql: Option<&str>, filter_state: &FilterState) -> String {
+        if let Some(name) = name {
+            name.to_string()
+        } else if let Some(jql) = current_jql {
+            jql.to_string()
+        } else if !filter_state.is_empty() {
+            filter_state.summary().join(", ")
+        } else {
+            "My Issues".to_string()
+        }
+    }
+}
+
 /// The main application struct that holds all state.
 ///
 /// This implements the Model part of The Elm Architecture (TEA).
@@ -261,16 +370,24 @@ pub struct App {
     loading: LoadingIndicator,
     /// Application configuration.
     config: Config,
+    /// Effective settings for the current profile and directory.
+    settings: Settings,
     /// The current active profile.
     current_profile: Option<Profile>,
     /// Profile picker popup (quick switch).
     profile_picker: ProfilePicker,
+    /// Theme switcher popup.
+    theme_picker: ThemePicker,
     /// Profile list view (full management).
     profile_list_view: ProfileListView,
     /// Profile form view (add/edit).
     profile_form_view: ProfileFormView,
     /// Delete profile confirmation dialog.
     delete_profile_dialog: DeleteProfileDialog,
+    /// Conflict resolution dialog for importing a profile bundle.
+    import_bundle_dialog: ImportBundleDialog,
+    /// Bundle waiting for a conflict resolution.
+    pending_bundle: Option<ProfileBundle>,
     /// Filter panel view.
     filter_panel: FilterPanelView,
     /// Current filter state.
@@ -283,6 +400,38 @@ pub struct App {
     jql_input: JqlInput,
     /// Current JQL query (if using direct JQL instead of filters).
     current_jql: Option<String>,
+    /// Issue list tabs. The entry at `active_tab` is a placeholder whose
+    /// list, filter and JQL live in `list_view`, `filter_state` and `current_jql`.
+    tabs: Vec<WorkspaceTab>,
+    /// IDs of the custom fields the issue lists show by name.
+    field_ids: FieldIds,
+    /// Index of the active tab.
+    active_tab: usize,
+    /// Whether the newly activated tab needs its issues fetched.
+    pending_tab_fetch: bool,
+    /// Id of the active tab's latest issue fetch; results of other fetches
+    /// are ignored.
+    fetch_request: u64,
+    /// Id of the most recently started issue fetch in any tab.
+    last_fetch_request: u64,
+    /// Visited issues and views for back/forward navigation.
+    history: JumpList,
+    /// Issue location to restore once its issue has been fetched.
+    pending_jump: Option<Location>,
+    /// Go to issue finder.
+    go_to_issue: GoToIssuePicker,
+    /// Pending go to issue search query.
+    pending_go_to_search: Option<String>,
+    /// Pending user search for an @mention picker.
+    pending_mention_search: Option<String>,
+    /// Whether the JQL autocomplete data needs to be fetched.
+    pending_jql_autocomplete: bool,
+    /// Pending JQL value suggestion request (field, partial value).
+    pending_jql_suggestions: Option<(String, String)>,
+    /// Pending validation of the JQL being typed.
+    pending_jql_validation: Option<String>,
+    /// Whether the go to issue finder needs the cached issues.
+    pending_cached_issues: bool,
     /// Pending issue update (issue key, update request).
     pending_issue_update: Option<(String, IssueUpdateRequest)>,
     /// Discard changes confirmation dialog.
@@ -302,6 +451,24 @@ pub struct App {
     assignee_fetch_for_create_issue: bool,
     /// Pending assignee change request (issue key, account_id or None for unassign).
     pending_assignee_change: Option<(String, Option<String>)>,
+    /// Pending fetch watchers request (issue key).
+    pending_fetch_watchers: Option<String>,
+    /// Pending fetch watcher candidates request (issue key, project key).
+    pending_fetch_watcher_candidates: Option<(String, String)>,
+    /// Pending watch or vote change request (issue key, change).
+    pending_watch_change: Option<(String, WatchChange)>,
+    /// Pending fetch versions request for the version editor (issue key, project key).
+    pending_fetch_versions: Option<(String, String)>,
+    /// Pending fix or affects version change request (issue key, field, operation).
+    pending_version_change: Option<(String, VersionField, VersionOperation)>,
+    /// Pending fetch versions request for the release view (project key).
+    pending_fetch_release_versions: Option<String>,
+    /// Pending fetch version issues request (version ID).
+    pending_fetch_version_issues: Option<String>,
+    /// Pending create version request (project ID, version name).
+    pending_create_version: Option<(String, String)>,
+    /// Pending release version request (version ID, release date, move-to version ID).
+    pending_release_version: Option<(String, String, Option<String>)>,
     /// Pending fetch priorities request (issue key or "__create_issue__" for create form).
     pending_fetch_priorities: Option<String>,
     /// Tracks if the current priority fetch is for create issue context.
@@ -348,12 +515,14 @@ pub struct App {
     pending_delete_issue: Option<String>,
     /// Delete issue confirmation dialog.
     delete_issue_confirm_dialog: ConfirmDialog,
-    /// Pending external editor request (issue key, current description).
-    pending_external_edit: Option<(String, String)>,
+    /// Pending external editor request.
+    pending_external_edit: Option<ExternalEditRequest>,
     /// Pending load more issues request (pagination).
     pending_load_more: bool,
     /// Help view.
     help_view: HelpView,
+    /// Release view for project versions.
+    release_view: ReleaseView,
     /// Previous state before opening help (to return to).
     previous_state: Option<AppState>,
     /// Command palette for quick command access.
@@ -375,101 +544,61 @@ pub struct App {
     pending_create_issue: bool,
     /// Whether a fetch issue types request is pending.
     pending_fetch_issue_types: bool,
+    /// Template picker shown before the create issue form.
+    template_picker: TemplatePicker,
+    /// Issue type selected once issue types load (from a template, clone or subtask).
+    pending_issue_type: Option<PendingIssueType>,
+    /// Clone options dialog shown before cloning an issue with links or subtasks.
+    clone_options_dialog: CloneOptionsDialog,
+    /// Links and subtasks to copy once the clone being created exists.
+    clone_copies: Option<CloneCopies>,
+    /// Pending copy of links and subtasks to a new clone (issue key, copies).
+    pending_copy_to_clone: Option<(String, CloneCopies)>,
+    /// Whether cancelling the create issue form returns to the issue detail view.
+    create_issue_from_detail: bool,
+    /// Pending fetch of the create fields (project key, issue type ID).
+    pending_fetch_create_fields: Option<(String, String)>,
+    // -------------------------------------------------------------------------
+    // Configuration File State
+    // -------------------------------------------------------------------------
+    /// Watches the config file for changes to apply while running.
+    config_watcher: Option<ConfigWatcher>,
+    /// Dialog reporting a config file that failed to load.
+    config_error_dialog: ConfirmDialog,
+    /// Config file location shown in the error dialog (path, line).
+    config_edit_target: Option<(PathBuf, Option<usize>)>,
+    /// Pending request to open the config file in the editor (path, line).
+    pending_config_edit: Option<(PathBuf, Option<usize>)>,
 }
 
 impl App {
-    /// Create a new application instance.
+    /// Create a new application instance from the configuration file.
+    ///
+    /// If the file fails to load the application starts with defaults, shows
+    /// the error and keeps the file from being overwritten.
     pub fn new() -> Self {
         debug!("Creating new application instance");
+        Self::with_loaded_config(Config::load())
+    }
 
-        // Load configuration (page_size is validated during load)
-        let config = Config::load().unwrap_or_else(|e| {
-            warn!("Failed to load config, using default: {}", e);
-            Config::default()
-        });
-
-        // Get the default profile
-        let current_profile = config.get_default_profile().cloned();
-        let profile_name = current_profile.as_ref().map(|p| p.name.clone());
-
-        // Create list view with configured page size
-        let mut list_view = ListView::with_page_size(config.settings.page_size);
-        list_view.set_loading(true);
-        list_view.set_profile_name(profile_name);
-
-        let mut loading = LoadingIndicator::with_message("Loading issues...");
-        loading.start();
-
-        // Initialize JQL input with history from config
-        let jql_input = JqlInput::with_history(config.jql_history().to_vec());
+    /// Create an application from the result of loading the configuration
+    /// file, watching the file for changes.
+    pub fn with_loaded_config(loaded: Result<Config, ConfigError>) -> Self {
+        let (config, error) = match loaded {
+            Ok(config) => (config, None),
+            Err(e) => {
+                warn!("Failed to load config, using default: {}", e);
+                (Config::fallback(), Some(e))
+            }
+        };
 
-        Self {
-            state: AppState::Loading,
-            should_quit: false,
-            list_view,
-            detail_view: DetailView::new(),
-            selected_issue_key: None,
-            notifications: NotificationManager::new(),
-            error_dialog: ErrorDialog::new(),
-            loading,
-            config,
-            current_profile,
-            profile_picker: ProfilePicker::new(),
-            profile_list_view: ProfileListView::new(),
-            profile_form_view: ProfileFormView::new_add(),
-            delete_profile_dialog: DeleteProfileDialog::new(),
-            filter_panel: FilterPanelView::new(),
-            filter_state: FilterState::new(),
-            filter_options: None,
-            saved_filters_dialog: SavedFiltersDialog::new(),
-            jql_input,
-            current_jql: None,
-            pending_issue_update: None,
-            discard_confirm_dialog: ConfirmDialog::new(),
-            transition_confirm_dialog: ConfirmDialog::new(),
-            pending_transition_confirm: None,
-            pending_transition: None,
-            pending_fetch_transitions: None,
-            pending_fetch_assignees: None,
-            assignee_fetch_for_create_issue: false,
-            pending_assignee_change: None,
-            pending_fetch_priorities: None,
-            priority_fetch_for_create_issue: false,
-            pending_priority_change: None,
-            pending_fetch_comments: None,
-            pending_submit_comment: None,
-            pending_fetch_labels: None,
-            pending_add_label: None,
-            pending_remove_label: None,
-            pending_fetch_components: None,
-            pending_add_component: None,
-            pending_remove_component: None,
-            pending_fetch_changelog: None,
-            pending_navigate_to_issue: None,
-            pending_fetch_link_types: None,
-            pending_fetch_recent_issues_for_link: None,
-            pending_search_issues_for_link: None,
-            pending_create_link: None,
-            pending_confirm_delete_link: None,
-            pending_delete_link: None,
-            delete_link_confirm_dialog: ConfirmDialog::new(),
-            pending_confirm_delete_issue: None,
-            pending_delete_issue: None,
-            delete_issue_confirm_dialog: ConfirmDialog::new(),
-            pending_external_edit: None,
-            pending_load_more: false,
-            help_view: HelpView::default(),
-            previous_state: None,
-            command_palette: CommandPalette::new(),
-            // Create issue form state
-            create_issue_view: CreateIssueView::new(),
-            create_issue_form: CreateIssueFormData::new(),
-            create_issue_focus: CreateIssueFormField::default(),
-            create_issue_errors: Vec::new(),
-            available_issue_types: Vec::new(),
-            pending_create_issue: false,
-            pending_fetch_issue_types: false,
+        let mut app = Self::with_config(config);
+        app.config_watcher = Config::config_path().ok().map(ConfigWatcher::new);
+        app.apply_keymap();
+        if let Some(e) = error {
+            app.show_config_error(&e);
         }
+        app
     }
 
     /// Create a new application instance with the given configuration.
@@ -481,41 +610,54 @@ impl App {
         // Validate page_size setting (in case config wasn't loaded via Config::load)
         config.settings.validate_page_size();
 
-        let current_profile = config.get_default_profile().cloned();
-        let profile_name = current_profile.as_ref().map(|p| p.name.clone());
-
-        // Create list view with configured page size
-        let mut list_view = ListView::with_page_size(config.settings.page_size);
-        list_view.set_loading(true);
-        list_view.set_profile_name(profile_name);
+        let current_profile = config.startup_profile().cloned();
+        let settings = config.effective_settings(current_profile.as_ref().map(|p| p.name.as_str()));
 
         let mut loading = LoadingIndicator::with_message("Loading issues...");
         loading.start();
 
-        // Initialize JQL input with history from config
-        let jql_input = JqlInput::with_history(config.jql_history().to_vec());
-
-        Self {
+        let mut app = Self {
             state: AppState::Loading,
             should_quit: false,
-            list_view,
+            // Replaced by the active tab's list view in `restore_tabs`
+            list_view: ListView::new(),
             detail_view: DetailView::new(),
             selected_issue_key: None,
             notifications: NotificationManager::new(),
             error_dialog: ErrorDialog::new(),
             loading,
             config,
+            settings,
             current_profile,
             profile_picker: ProfilePicker::new(),
+            theme_picker: ThemePicker::new(),
             profile_list_view: ProfileListView::new(),
             profile_form_view: ProfileFormView::new_add(),
             delete_profile_dialog: DeleteProfileDialog::new(),
+            import_bundle_dialog: ImportBundleDialog::new(),
+            pending_bundle: None,
             filter_panel: FilterPanelView::new(),
             filter_state: FilterState::new(),
             filter_options: None,
             saved_filters_dialog: SavedFiltersDialog::new(),
-            jql_input,
+            // Filled with the profile's history in `restore_recent`
+            jql_input: JqlInput::new(),
             current_jql: None,
+            tabs: Vec::new(),
+            field_ids: FieldIds::default(),
+            active_tab: 0,
+            pending_tab_fetch: false,
+            fetch_request: 0,
+            last_fetch_request: 0,
+            history: JumpList::new(),
+            pending_jump: None,
+            go_to_issue: GoToIssuePicker::new(),
+            pending_go_to_search: None,
+            pending_mention_search: None,
+            pending_jql_autocomplete: false,
+            pending_jql_suggestions: None,
+            pending_jql_validation: None,
+            pending_cached_issues: false,
             pending_issue_update: None,
             discard_confirm_dialog: ConfirmDialog::new(),
             transition_confirm_dialog: ConfirmDialog::new(),
@@ -525,6 +667,15 @@ impl App {
             pending_fetch_assignees: None,
             assignee_fetch_for_create_issue: false,
             pending_assignee_change: None,
+            pending_fetch_watchers: None,
+            pending_fetch_watcher_candidates: None,
+            pending_watch_change: None,
+            pending_fetch_versions: None,
+            pending_version_change: None,
+            pending_fetch_release_versions: None,
+            pending_fetch_version_issues: None,
+            pending_create_version: None,
+            pending_release_version: None,
             pending_fetch_priorities: None,
             priority_fetch_for_create_issue: false,
             pending_priority_change: None,
@@ -551,6 +702,7 @@ impl App {
             pending_external_edit: None,
             pending_load_more: false,
             help_view: HelpView::default(),
+            release_view: ReleaseView::new(),
             previous_state: None,
             command_palette: CommandPalette::new(),
             // Create issue form state
@@ -560,7 +712,42 @@ impl App {
             create_issue_errors: Vec::new(),
             available_issue_types: Vec::new(),
             pending_create_issue: false,
+            template_picker: TemplatePicker::new(),
+            pending_issue_type: None,
+            clone_options_dialog: CloneOptionsDialog::new(),
+            clone_copies: None,
+            pending_copy_to_clone: None,
+            create_issue_from_detail: false,
+            pending_fetch_create_fields: None,
             pending_fetch_issue_types: false,
+            config_watcher: None,
+            config_error_dialog: ConfirmDialog::new(),
+            config_edit_target: None,
+            pending_config_edit: None,
+        };
+        app.jql_input.set_aliases(app.config.aliases.clone());
+        app.restore_tabs();
+        app.open_local_query();
+        app.restore_history();
+        app.restore_recent();
+        app.restore_location();
+        app
+    }
+
+    /// Install the key bindings of the config and report binding conflicts.
+    fn apply_keymap(&mut self) {
+        match Keymap::from_config(&self.config.keys) {
+            Ok((keymap, conflicts)) => {
+                set_keymap(keymap);
+                if !conflicts.is_empty() {
+                    warn!(?conflicts, "Key binding conflicts");
+                    self.notify_warning(format!("Key binding conflicts: {}", conflicts.join("; ")));
+                }
+            }
+            Err(e) => {
+                warn!(error = %e, "Invalid key bindings");
+                self.notify_error(format!("Invalid key bindings: {}", e));
+            }
         }
     }
 
@@ -696,6 +883,192 @@ impl App {
         &self.config
     }
 
+    /// Get the effective settings for the current profile.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Recompute the effective settings after the profile changed, applying
+    /// the theme and editing mode if they differ.
+    fn apply_settings(&mut self) {
+        let settings = self
+            .config
+            .effective_settings(self.current_profile.as_ref().map(|p| p.name.as_str()));
+        if settings.theme != self.settings.theme
+            || settings.custom_theme != self.settings.custom_theme
+        {
+            set_theme(self.config.theme(&settings));
+        }
+        if settings.vim_editing != self.settings.vim_editing {
+            init_vim_editing(settings.vim_editing);
+        }
+        self.settings = settings;
+    }
+
+    // ========================================================================
+    // Configuration file methods
+    // ========================================================================
+
+    /// Show a config file that failed to load, offering to open it in the
+    /// editor at the error.
+    fn show_config_error(&mut self, error: &ConfigError) {
+        let Some(path) = self.config_watcher.as_ref().map(|w| w.path().to_path_buf()) else {
+            self.show_error_dialog("Configuration Error", error.to_string());
+            return;
+        };
+        let location = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| error.location(&content));
+        let detail = match location {
+            Some((line, column)) => {
+                format!("Line {}, column {}: {}", line, column, error.summary())
+            }
+            None => error.summary(),
+        };
+        let consequence = if self.config.load_failed {
+            "Using defaults; settings are not saved until the file is fixed."
+        } else {
+            "The previous configuration stays in use."
+        };
+        self.config_error_dialog.show_with_labels(
+            "Configuration Error",
+            format!("{}\n{}\n\n{}", path.display(), detail, consequence),
+            "Open in Editor",
+            if self.config.load_failed {
+                "Use Defaults"
+            } else {
+                "Dismiss"
+            },
+        );
+        self.config_edit_target = Some((path, location.map(|(line, _)| line)));
+    }
+
+    /// Check if the config error dialog is visible.
+    pub fn is_config_error_dialog_visible(&self) -> bool {
+        self.config_error_dialog.is_visible()
+    }
+
+    /// Take the pending request to open the config file in the editor.
+    ///
+    /// Returns the file path and the line to place the cursor on.
+    pub fn take_pending_config_edit(&mut self) -> Option<(PathBuf, Option<usize>)> {
+        self.pending_config_edit.take()
+    }
+
+    /// Reload the config file and apply its changes.
+    ///
+    /// If the file fails to load the error is shown and the current
+    /// configuration stays in use.
+    pub fn reload_config(&mut self) {
+        // Changes made so far are handled here, not on the next poll
+        if let Some(watcher) = self.config_watcher.as_mut() {
+            watcher.check();
+        }
+        match Config::load() {
+            Ok(config) => self.apply_config(config),
+            Err(e) => {
+                warn!(error = %e, "Failed to reload config");
+                self.show_config_error(&e);
+            }
+        }
+    }
+
+    /// Apply a reloaded configuration: theme, editing mode, key bindings,
+    /// saved filters and list columns take effect immediately.
+    ///
+    /// Changes to the active profile's connection reconnect to JIRA. If the
+    /// active profile was removed or renamed, the default profile (or the one
+    /// pinned by `.lazyjira.toml`) is opened instead. Does nothing if the
+    /// configuration is unchanged, as after the app's own saves.
+    pub fn apply_config(&mut self, mut config: Config) {
+        self.config_error_dialog.hide();
+        self.config_edit_target = None;
+        let unchanged = !self.config.load_failed
+            && toml::to_string(&config).ok() == toml::to_string(&self.config).ok()
+            && config.file_themes == self.config.file_themes
+            && config.local == self.config.local;
+        if unchanged {
+            return;
+        }
+
+        info!("Applying changed configuration");
+        let themes_changed = config.file_themes != self.config.file_themes;
+        // The session state in memory is newer than the one on disk
+        for (name, state) in std::mem::take(&mut self.config.state) {
+            config.state.insert(name, state);
+        }
+        self.config = config;
+        self.jql_input.set_aliases(self.config.aliases.clone());
+        // Also opens a profile if there was none, e.g. when the file was
+        // fixed after starting with defaults
+        let previous = self.current_profile.take();
+        self.current_profile = previous
+            .as_ref()
+            .and_then(|p| self.config.get_profile(&p.name))
+            .or_else(|| self.config.startup_profile())
+            .cloned();
+        let previous_name = previous.as_ref().map(|p| p.name.as_str());
+        let current_name = self.current_profile.as_ref().map(|p| p.name.as_str());
+        let opened_profile = previous_name != current_name;
+        let removed_profile = previous_name.filter(|name| self.config.get_profile(name).is_none());
+        let notice = match (removed_profile, current_name) {
+            (Some(removed), Some(current)) => Some(format!(
+                "Profile '{}' no longer exists, switched to '{}'",
+                removed, current
+            )),
+            (Some(removed), None) => Some(format!("Profile '{}' no longer exists", removed)),
+            (None, _) => None,
+        };
+        if self.current_profile.as_ref().map(|p| &p.url) != previous.as_ref().map(|p| &p.url) {
+            self.jql_input.reset_autocomplete();
+        }
+
+        let settings = self
+            .config
+            .effective_settings(self.current_profile.as_ref().map(|p| p.name.as_str()));
+        if themes_changed
+            || settings.theme != self.settings.theme
+            || settings.custom_theme != self.settings.custom_theme
+        {
+            set_theme(self.config.theme(&settings));
+        }
+        if settings.vim_editing != self.settings.vim_editing {
+            init_vim_editing(settings.vim_editing);
+        }
+        self.settings = settings;
+        self.apply_keymap();
+        if opened_profile {
+            if self.state == AppState::IssueDetail {
+                self.state = AppState::IssueList;
+            }
+            self.detail_view.clear();
+            self.selected_issue_key = None;
+            self.pending_jump = None;
+            self.restore_tabs();
+            self.open_local_query();
+            self.restore_history();
+            self.restore_recent();
+        } else {
+            self.refresh_columns();
+        }
+        match notice {
+            Some(notice) => self.notify_warning(notice),
+            None => self.notify_info("Configuration reloaded"),
+        }
+    }
+
+    /// Check the config file for changes and apply them.
+    fn poll_config_file(&mut self, now: Instant) {
+        let changed = self
+            .config_watcher
+            .as_mut()
+            .is_some_and(|watcher| watcher.poll(now));
+        if changed {
+            debug!("Config file changed");
+            self.reload_config();
+        }
+    }
+
     /// Get the current active profile.
     pub fn current_profile(&self) -> Option<&Profile> {
         self.current_profile.as_ref()
@@ -761,15 +1134,18 @@ impl App {
         info!(profile = %profile_name, "Switching profile");
 
         // Clear session data
-        self.list_view.set_issues(Vec::new());
-        self.list_view.set_loading(true);
+        self.persist_session();
         self.detail_view.clear();
         self.selected_issue_key = None;
+        self.pending_jump = None;
 
-        // Set new profile
+        // Set new profile and open its tabs and history
         self.current_profile = Some(profile);
-        self.list_view
-            .set_profile_name(Some(profile_name.to_string()));
+        self.apply_settings();
+        self.restore_tabs();
+        self.restore_history();
+        self.restore_recent();
+        self.jql_input.reset_autocomplete();
 
         // Notify user
         self.notify_success(format!("Switched to profile: {}", profile_name));
@@ -802,6 +1178,17 @@ impl App {
         }
     }
 
+    /// Open a link from issue content in the browser.
+    pub fn open_link_in_browser(&mut self, url: &str) {
+        info!(url = %url, "Opening link in browser");
+        if let Err(e) = open::that(url) {
+            warn!(error = %e, "Failed to open browser");
+            self.notify_error(format!("Failed to open browser: {}", e));
+        } else {
+            self.notify_info(format!("Opened {}", url));
+        }
+    }
+
     /// Get the number of configured profiles.
     pub fn profile_count(&self) -> usize {
         self.config.profiles.len()
@@ -906,8 +1293,13 @@ impl App {
             )));
         }
 
-        // Update the profile
-        let profile = Profile::new(data.name.clone(), data.url, data.email);
+        // Update the profile, keeping its list columns and tabs
+        let profile = Profile {
+            name: data.name.clone(),
+            url: data.url,
+            email: data.email,
+            ..self.config.profiles[index].clone()
+        };
         self.config.profiles[index] = profile.clone();
 
         // Update token (delete old if name changed, then store new)
@@ -926,6 +1318,11 @@ impl App {
         if self.config.settings.default_profile.as_deref() == Some(original_name) {
             self.config.settings.default_profile = Some(data.name.clone());
         }
+        if data.name != *original_name {
+            if let Err(e) = self.config.rename_state(original_name, &data.name) {
+                warn!("Failed to rename session state: {}", e);
+            }
+        }
 
         // Save config
         self.config.save()?;
@@ -964,6 +1361,9 @@ impl App {
 
         // Save config
         self.config.save()?;
+        if let Err(e) = self.config.remove_state(&profile.name) {
+            warn!("Failed to remove session state: {}", e);
+        }
 
         // If we deleted the current profile, switch to another
         if self.current_profile.as_ref().map(|p| p.name.as_str()) == Some(&profile.name) {
@@ -984,6 +1384,133 @@ impl App {
         Ok(())
     }
 
+    /// Export a profile by index to a bundle file, without its token.
+    pub fn export_profile(&mut self, index: usize, path: &Path) -> Result<(), ConfigError> {
+        let name = self
+            .get_profile_by_index(index)
+            .map(|p| p.name.clone())
+            .ok_or_else(|| ConfigError::ProfileNotFound(format!("index {}", index)))?;
+        debug!(name = %name, path = %path.display(), "Exporting profile");
+        self.config.export_profile(&name)?.write(path)?;
+        self.notify_success(format!(
+            "Exported profile '{}' to {} (without API token)",
+            name,
+            path.display()
+        ));
+        Ok(())
+    }
+
+    /// Read a profile bundle and import it, asking how to resolve conflicts
+    /// with existing entries first.
+    pub fn start_import(&mut self, path: &Path) -> Result<(), ConfigError> {
+        debug!(path = %path.display(), "Importing profile bundle");
+        let bundle = ProfileBundle::read(path)?;
+        let conflicts = bundle.conflicts(&self.config);
+        if conflicts.is_empty() {
+            return self.import_bundle(bundle, ConflictResolution::KeepExisting);
+        }
+        self.import_bundle_dialog
+            .show(&bundle.profile.name, conflicts);
+        self.pending_bundle = Some(bundle);
+        Ok(())
+    }
+
+    /// Check if the import conflict dialog is visible.
+    pub fn is_import_bundle_dialog_visible(&self) -> bool {
+        self.import_bundle_dialog.is_visible()
+    }
+
+    /// Import a profile bundle and save the configuration.
+    ///
+    /// Nothing changes if the result would be an invalid configuration.
+    pub fn import_bundle(
+        &mut self,
+        bundle: ProfileBundle,
+        resolution: ConflictResolution,
+    ) -> Result<(), ConfigError> {
+        let mut config = self.config.clone();
+        let summary = config.import_bundle(bundle, resolution)?;
+        config.validate()?;
+        config.save()?;
+        self.config = config;
+        self.jql_input.set_aliases(self.config.aliases.clone());
+
+        // The current profile may have been replaced
+        if let Some(current) = self.current_profile.as_ref().map(|p| p.name.clone()) {
+            self.current_profile = self.config.get_profile(&current).cloned();
+            self.apply_settings();
+            self.refresh_columns();
+        }
+        self.refresh_profile_list();
+
+        let mut message = format!(
+            "Imported {} entries for profile '{}'",
+            summary.imported, summary.profile
+        );
+        if summary.skipped > 0 {
+            message.push_str(&format!(", kept {} existing", summary.skipped));
+        }
+        if !auth::has_token(&summary.profile) {
+            message.push_str(". Press 'e' to add your API token");
+        }
+        self.notify_success(message);
+        Ok(())
+    }
+
+    /// Handle an action from the profile list view.
+    fn handle_profile_list_action(&mut self, action: ProfileListAction) {
+        match action {
+            ProfileListAction::AddProfile => {
+                debug!("Opening add profile form");
+                self.profile_form_view.show_add();
+            }
+            ProfileListAction::EditProfile(index) => {
+                if let Some(profile) = self.get_profile_by_index(index).cloned() {
+                    debug!(name = %profile.name, "Opening edit profile form");
+                    // Get token for editing (may be empty if not set)
+                    let token = auth::get_token(&profile.name).unwrap_or_default();
+                    self.profile_form_view.show_edit(&profile, &token);
+                }
+            }
+            ProfileListAction::DeleteProfile(index) => {
+                if let Some(profile) = self.get_profile_by_index(index).cloned() {
+                    debug!(name = %profile.name, "Showing delete confirmation");
+                    self.delete_profile_dialog.show(&profile.name);
+                }
+            }
+            ProfileListAction::SetDefault(index) => {
+                if let Err(e) = self.set_default_profile(index) {
+                    self.notify_error(format!("Failed to set default: {}", e));
+                }
+            }
+            ProfileListAction::SwitchToProfile(index) => {
+                if let Some(profile) = self.get_profile_by_index(index) {
+                    let name = profile.name.clone();
+                    if let Err(e) = self.switch_profile(&name) {
+                        self.notify_error(format!("Failed to switch profile: {}", e));
+                    } else {
+                        // Go back to issue list after switching
+                        self.state = AppState::IssueList;
+                    }
+                }
+            }
+            ProfileListAction::ExportProfile(index, path) => {
+                if let Err(e) = self.export_profile(index, &path) {
+                    self.notify_error(format!("Failed to export profile: {}", e));
+                }
+            }
+            ProfileListAction::ImportBundle(path) => {
+                if let Err(e) = self.start_import(&path) {
+                    self.notify_error(format!("Failed to import {}: {}", path.display(), e));
+                }
+            }
+            ProfileListAction::GoBack => {
+                debug!("Going back from profile management");
+                self.state = AppState::IssueList;
+            }
+        }
+    }
+
     /// Set a profile as the default.
     pub fn set_default_profile(&mut self, index: usize) -> Result<(), ConfigError> {
         let profile_name = self
@@ -1020,6 +1547,10 @@ impl App {
         self.available_issue_types.clear();
         self.pending_create_issue = false;
         self.pending_fetch_issue_types = false;
+        self.pending_issue_type = None;
+        self.pending_fetch_create_fields = None;
+        self.clone_copies = None;
+        self.create_issue_from_detail = false;
         self.create_issue_view.reset();
     }
 
@@ -1049,21 +1580,51 @@ impl App {
     }
 
     /// Move focus to the next field in the create issue form.
+    ///
+    /// Within the additional fields panel, focus moves field by field first.
     pub fn create_issue_focus_next(&mut self) {
+        if self.create_issue_focus == CreateIssueFormField::Fields
+            && self.create_issue_view.create_fields_mut().select_next()
+        {
+            return;
+        }
         let is_subtask = self.create_issue_form.is_subtask;
         let can_have_epic_parent = self.create_issue_form.can_have_epic_parent;
-        self.create_issue_focus = self
+        let mut next = self
             .create_issue_focus
             .next_for_form(is_subtask, can_have_epic_parent);
+        if next == CreateIssueFormField::Fields {
+            if self.create_issue_view.create_fields().is_empty() {
+                next = next.next_for_form(is_subtask, can_have_epic_parent);
+            } else {
+                self.create_issue_view.create_fields_mut().select_first();
+            }
+        }
+        self.create_issue_focus = next;
     }
 
     /// Move focus to the previous field in the create issue form.
+    ///
+    /// Within the additional fields panel, focus moves field by field first.
     pub fn create_issue_focus_prev(&mut self) {
+        if self.create_issue_focus == CreateIssueFormField::Fields
+            && self.create_issue_view.create_fields_mut().select_prev()
+        {
+            return;
+        }
         let is_subtask = self.create_issue_form.is_subtask;
         let can_have_epic_parent = self.create_issue_form.can_have_epic_parent;
-        self.create_issue_focus = self
+        let mut prev = self
             .create_issue_focus
             .prev_for_form(is_subtask, can_have_epic_parent);
+        if prev == CreateIssueFormField::Fields {
+            if self.create_issue_view.create_fields().is_empty() {
+                prev = prev.prev_for_form(is_subtask, can_have_epic_parent);
+            } else {
+                self.create_issue_view.create_fields_mut().select_last();
+            }
+        }
+        self.create_issue_focus = prev;
     }
 
     /// Get the validation errors for the create issue form.
@@ -1108,6 +1669,8 @@ impl App {
     /// Returns true if the form is valid, false otherwise.
     pub fn validate_create_issue_form(&mut self) -> bool {
         self.create_issue_errors = self.create_issue_form.validate();
+        self.create_issue_errors
+            .extend(self.create_issue_view.create_fields().validate());
         self.create_issue_errors.is_empty()
     }
 
@@ -1150,8 +1713,14 @@ impl App {
         debug!("Opening create issue form");
         self.init_create_issue_form();
 
-        // Pre-populate project key from current filter if available
-        if let Some(project_key) = &self.filter_state.project {
+        // Pre-populate project key from current filter or the local config
+        let project_key = self.filter_state.project.as_ref().or_else(|| {
+            self.config
+                .local
+                .as_ref()
+                .and_then(|local| local.project.as_ref())
+        });
+        if let Some(project_key) = project_key {
             debug!(project = %project_key, "Pre-populating project from filter");
             self.create_issue_form.project_key = project_key.clone();
             // Mark that we need to fetch issue types for this project
@@ -1161,14 +1730,211 @@ impl App {
         self.state = AppState::CreateIssue;
     }
 
-    /// Handle keyboard input for the create issue form.
+    /// Start creating an issue.
     ///
-    /// This method handles input directly in App to avoid borrow conflicts
-    /// with CreateIssueView (which is a field of App).
-    fn handle_create_issue_input(
-        &mut self,
-        key: crossterm::event::KeyEvent,
-    ) -> Option<CreateIssueAction> {
+    /// Shows the template picker first when issue templates are configured,
+    /// otherwise opens a blank create issue form.
+    pub fn start_create_issue(&mut self) {
+        let templates: Vec<(String, String)> = self
+            .config
+            .issue_templates()
+            .map(|t| (t.name.clone(), t.summary()))
+            .collect();
+
+        if templates.is_empty() {
+            self.open_create_issue_form();
+        } else {
+            self.template_picker.show(templates);
+        }
+    }
+
+    /// Check if the issue template picker is visible.
+    pub fn is_template_picker_visible(&self) -> bool {
+        self.template_picker.is_visible()
+    }
+
+    /// Open the create issue form pre-filled from the named template.
+    pub fn open_create_issue_form_from_template(&mut self, name: &str) {
+        let Some(template) = self.config.get_issue_template(name).cloned() else {
+            self.notify_error(format!("Issue template '{}' not found", name));
+            return;
+        };
+        self.open_create_issue_form();
+        self.apply_issue_template(&template);
+    }
+
+    /// Fill the create issue form from a template.
+    ///
+    /// The issue type is selected by name once the project's issue types load.
+    fn apply_issue_template(&mut self, template: &IssueTemplate) {
+        debug!(template = %template.name, "Applying issue template");
+        let form = &mut self.create_issue_form;
+        form.template_name = Some(template.name.clone());
+        form.summary = template.summary_prefix.clone();
+        form.description = template.description.clone();
+        form.labels = template.labels.clone();
+        form.components = template.components.clone();
+        form.priority_name = template.priority.clone();
+        if let Some(ref account_id) = template.assignee {
+            form.assignee_id = Some(account_id.clone());
+            form.assignee_name = Some(
+                template
+                    .assignee_name
+                    .clone()
+                    .unwrap_or_else(|| account_id.clone()),
+            );
+        }
+
+        if let Some(ref project_key) = template.project {
+            let name = self
+                .get_available_projects_for_create_issue()
+                .into_iter()
+                .find(|(key, _)| key == project_key)
+                .map(|(_, name)| name)
+                .unwrap_or_else(|| project_key.clone());
+            self.update_selected_project(project_key, &name);
+            self.pending_fetch_issue_types = true;
+        }
+        self.pending_issue_type = template.issue_type.clone().map(PendingIssueType::Named);
+
+        // With the project chosen, start where the template leaves off
+        if !self.create_issue_form.project_key.is_empty() {
+            self.create_issue_focus = CreateIssueFormField::Summary;
+        }
+        self.sync_create_issue_to_view();
+    }
+
+    /// Start cloning the issue shown in the detail view.
+    ///
+    /// Asks which links and subtasks to copy first when the issue has any.
+    pub fn start_clone_issue(&mut self) {
+        let Some(issue) = self.detail_view.issue() else {
+            return;
+        };
+        let (key, links, subtasks) = (
+            issue.key.clone(),
+            issue.fields.issue_links.len(),
+            issue.fields.subtasks.len(),
+        );
+        if links + subtasks > 0 {
+            self.clone_options_dialog.show(&key, links, subtasks);
+        } else {
+            self.open_clone_issue_form(false, false);
+        }
+    }
+
+    /// Check if the clone options dialog is visible.
+    pub fn is_clone_options_visible(&self) -> bool {
+        self.clone_options_dialog.is_visible()
+    }
+
+    /// Open the create issue form pre-filled from the issue in the detail view.
+    ///
+    /// The chosen links and subtasks are copied once the clone is created.
+    pub fn open_clone_issue_form(&mut self, copy_links: bool, copy_subtasks: bool) {
+        let Some(issue) = self.detail_view.issue().cloned() else {
+            return;
+        };
+        debug!(key = %issue.key, "Cloning issue");
+        self.open_create_issue_form_for(&issue);
+
+        let fields = &issue.fields;
+        let form = &mut self.create_issue_form;
+        form.clone_of = Some(issue.key.clone());
+        form.summary = format!("CLONE - {}", fields.summary);
+        form.description = issue.description_markdown();
+        form.labels = fields.labels.clone();
+        form.components = fields.components.iter().map(|c| c.name.clone()).collect();
+        if let Some(ref priority) = fields.priority {
+            form.priority_id = Some(priority.id.clone());
+            form.priority_name = Some(priority.name.clone());
+        }
+        if let Some(ref assignee) = fields.assignee {
+            form.assignee_id = Some(assignee.account_id.clone());
+            form.assignee_name = Some(assignee.display_name.clone());
+        }
+        if let Some(ref parent) = fields.parent {
+            if fields.issuetype.subtask {
+                form.parent_issue_key = Some(parent.key.clone());
+            } else {
+                form.epic_parent_key = Some(parent.key.clone());
+            }
+        }
+        self.pending_issue_type = Some(PendingIssueType::Named(fields.issuetype.name.clone()));
+
+        let mut copies = CloneCopies::default();
+        if copy_links {
+            copies.links = fields
+                .issue_links
+                .iter()
+                .filter_map(|link| match (&link.outward_issue, &link.inward_issue) {
+                    (Some(other), _) => {
+                        Some((link.link_type.name.clone(), other.key.clone(), true))
+                    }
+                    (None, Some(other)) => {
+                        Some((link.link_type.name.clone(), other.key.clone(), false))
+                    }
+                    (None, None) => None,
+                })
+                .collect();
+        }
+        if copy_subtasks {
+            copies.subtasks = fields
+                .subtasks
+                .iter()
+                .filter_map(|subtask| {
+                    let issue_type = subtask.fields.issue_type.as_ref()?;
+                    Some((issue_type.id.clone(), subtask.fields.summary.clone()))
+                })
+                .collect();
+        }
+        self.clone_copies = Some(copies);
+        self.sync_create_issue_to_view();
+    }
+
+    /// Open the create issue form for a subtask of the issue in the detail view.
+    ///
+    /// The project's subtask issue type is selected once issue types load.
+    pub fn open_create_subtask_form(&mut self) {
+        let Some(issue) = self.detail_view.issue().cloned() else {
+            return;
+        };
+        if issue.fields.issuetype.subtask {
+            self.notify_warning("Subtasks cannot have subtasks");
+            return;
+        }
+        debug!(key = %issue.key, "Creating subtask");
+        self.open_create_issue_form_for(&issue);
+        self.create_issue_form.parent_issue_key = Some(issue.key.clone());
+        self.pending_issue_type = Some(PendingIssueType::Subtask);
+        self.sync_create_issue_to_view();
+    }
+
+    /// Open the create issue form in the project of an issue from the detail view.
+    fn open_create_issue_form_for(&mut self, issue: &Issue) {
+        self.open_create_issue_form();
+        self.create_issue_from_detail = true;
+        if let Some(project_key) = issue.project_key() {
+            let name = issue
+                .fields
+                .project
+                .as_ref()
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| project_key.to_string());
+            self.update_selected_project(project_key, &name);
+            self.pending_fetch_issue_types = true;
+            self.create_issue_focus = CreateIssueFormField::Summary;
+        }
+    }
+
+    /// Handle keyboard input for the create issue form.
+    ///
+    /// This method handles input directly in App to avoid borrow conflicts
+    /// with CreateIssueView (which is a field of App).
+    fn handle_create_issue_input(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> Option<CreateIssueAction> {
         use crossterm::event::{KeyCode, KeyModifiers};
 
         // Don't handle input while submitting
@@ -1193,6 +1959,11 @@ impl App {
             return self.handle_create_issue_field_input(key, CreateIssueFormField::EpicParent);
         }
 
+        // If a picker of the additional fields is open, route all input to it
+        if self.create_issue_view.is_create_fields_picker_open() {
+            return self.handle_create_issue_field_input(key, CreateIssueFormField::Fields);
+        }
+
         // If the assignee picker is visible, route all input to it
         if self.create_issue_view.is_assignee_picker_visible() {
             return self.handle_create_issue_assignee_picker_input(key);
@@ -1204,8 +1975,11 @@ impl App {
         }
 
         match (key.code, key.modifiers) {
-            // Tab - next field
-            (KeyCode::Tab, KeyModifiers::NONE) => {
+            // Tab - next field (unless it picks a user in the mention picker)
+            (KeyCode::Tab, KeyModifiers::NONE)
+                if !(focus == CreateIssueFormField::Description
+                    && self.create_issue_view.is_mention_picker_open()) =>
+            {
                 self.sync_create_issue_from_view();
                 self.create_issue_focus_next();
                 self.sync_create_issue_to_view();
@@ -1218,8 +1992,23 @@ impl App {
                 self.sync_create_issue_to_view();
                 None
             }
-            // Escape - cancel
-            (KeyCode::Esc, _) => {
+            // Continue the description in the external editor
+            _ if focus == CreateIssueFormField::Description
+                && keymap().matches(KeyContext::Editor, Action::ExternalEditor, key) =>
+            {
+                self.sync_create_issue_from_view();
+                Some(CreateIssueAction::OpenExternalEditor)
+            }
+            // Escape in a modal description editor leaves insert/visual mode
+            (KeyCode::Esc, _)
+                if focus == CreateIssueFormField::Description
+                    && self.create_issue_view.description_wants_escape() =>
+            {
+                self.create_issue_view.handle_description_input(key);
+                None
+            }
+            // Cancel
+            _ if keymap().matches(KeyContext::Editor, Action::Cancel, key) => {
                 self.create_issue_view.reset();
                 Some(CreateIssueAction::Cancel)
             }
@@ -1241,7 +2030,8 @@ impl App {
                     && focus != CreateIssueFormField::IssueType
                     && focus != CreateIssueFormField::EpicParent
                     && focus != CreateIssueFormField::Assignee
-                    && focus != CreateIssueFormField::Priority =>
+                    && focus != CreateIssueFormField::Priority
+                    && focus != CreateIssueFormField::Fields =>
             {
                 self.sync_create_issue_from_view();
                 self.create_issue_focus_next();
@@ -1418,6 +2208,22 @@ impl App {
                 }
                 None
             }
+            CreateIssueFormField::Fields => {
+                match self
+                    .create_issue_view
+                    .create_fields_mut()
+                    .handle_input(key)?
+                {
+                    CreateFieldsAction::FetchUsers => {
+                        let project_key = self.create_issue_form.project_key.clone();
+                        Some(CreateIssueAction::FetchAssignableUsers(project_key))
+                    }
+                    CreateFieldsAction::Next => {
+                        self.create_issue_focus_next();
+                        None
+                    }
+                }
+            }
             CreateIssueFormField::Submit => None,
         }
     }
@@ -1511,6 +2317,7 @@ impl App {
             self.create_issue_form.issue_type_name.clear();
             self.available_issue_types.clear();
             self.create_issue_view.set_issue_type_items(Vec::new());
+            self.create_issue_view.create_fields_mut().clear();
             // Trigger fetch of issue types for the new project
             // (take_pending_fetch_issue_types will use create_issue_form.project_key)
             self.pending_fetch_issue_types = true;
@@ -1535,6 +2342,12 @@ impl App {
             if !issue_type.can_have_epic_parent() {
                 self.create_issue_form.epic_parent_key = None;
             }
+
+            // Load the fields this issue type accepts
+            self.pending_fetch_create_fields = Some((
+                self.create_issue_form.project_key.clone(),
+                self.create_issue_form.issue_type_id.clone(),
+            ));
         }
     }
 
@@ -1587,8 +2400,13 @@ impl App {
     /// This should be called after a successful issue creation.
     pub fn close_create_issue_form(&mut self, refresh_list: bool) {
         debug!(refresh = refresh_list, "Closing create issue form");
+        let from_detail = self.create_issue_from_detail && self.detail_view.issue().is_some();
         self.clear_create_issue_form();
-        self.state = AppState::IssueList;
+        self.state = if from_detail && !refresh_list {
+            AppState::IssueDetail
+        } else {
+            AppState::IssueList
+        };
 
         if refresh_list {
             // Trigger a refresh of the issue list
@@ -1604,21 +2422,22 @@ impl App {
     pub fn build_create_issue_request(&self) -> CreateIssueRequest {
         let form = &self.create_issue_form;
 
-        // Convert plain text description to Atlassian Document Format if present
+        // Convert Markdown description to Atlassian Document Format if present
         let description = if form.description.trim().is_empty() {
             None
         } else {
-            Some(AtlassianDoc::from_plain_text(&form.description))
+            Some(AtlassianDoc::from_markdown(&form.description))
         };
 
         // Build optional assignee reference
         let assignee = form.assignee_id.as_ref().map(|id| UserRef::new(id.clone()));
 
-        // Build optional priority reference
+        // Build optional priority reference, by name if only the name is known
         let priority = form
             .priority_id
             .as_ref()
-            .map(|id| PriorityRef::new(id.clone()));
+            .map(|id| PriorityRef::new(id.clone()))
+            .or_else(|| form.priority_name.as_ref().map(PriorityRef::named));
 
         // Build optional parent reference
         // - For subtasks: use parent_issue_key (required)
@@ -1635,6 +2454,30 @@ impl App {
             None
         };
 
+        // Template labels and components are merged with the additional fields
+        let mut extra = self.create_issue_view.create_fields().values();
+        let mut labels = form.labels.clone();
+        if let Some(serde_json::Value::Array(picked)) = extra.remove("labels") {
+            for label in picked.iter().filter_map(serde_json::Value::as_str) {
+                if !labels.iter().any(|l| l == label) {
+                    labels.push(label.to_string());
+                }
+            }
+        }
+        let mut components: Vec<ComponentRef> = form
+            .components
+            .iter()
+            .map(|name| ComponentRef { name: name.clone() })
+            .collect();
+        if let Some(serde_json::Value::Array(picked)) = extra.get_mut("components") {
+            // Picked components are referenced by ID, so the template's join them
+            picked.extend(
+                components
+                    .drain(..)
+                    .map(|c| serde_json::json!({ "name": c.name })),
+            );
+        }
+
         CreateIssueRequest {
             fields: CreateIssueFields {
                 project: ProjectRef {
@@ -1648,6 +2491,9 @@ impl App {
                 assignee,
                 priority,
                 parent,
+                labels,
+                components,
+                extra,
             },
         }
     }
@@ -1728,15 +2574,30 @@ impl App {
     }
 
     /// Open the filter panel.
+    ///
+    /// While a JQL query is active, the panel starts from the query if it
+    /// is simple enough to be read back as filters.
     pub fn open_filter_panel(&mut self) {
         debug!("Opening filter panel");
-        self.filter_panel.show_with_state(&self.filter_state);
+        let mut state = self.filter_state.clone();
+        if let Some(jql) = &self.current_jql {
+            let expanded = self.config.aliases.expand(jql);
+            match expanded.ok().and_then(|jql| FilterState::from_jql(&jql)) {
+                Some(parsed) => state = parsed,
+                None => self.notify_info("The current JQL query can't be shown as filters"),
+            }
+        }
+        self.filter_panel.show_with_state(&state);
         self.state = AppState::FilterPanel;
     }
 
-    /// Apply the given filter state.
+    /// Apply the given filter state, replacing any active JQL query.
+    ///
+    /// Restores the profile's list columns; use `apply_saved_filter` to apply
+    /// a saved filter together with its own columns.
     pub fn apply_filter(&mut self, filter: FilterState) {
         debug!("Applying filter: {:?}", filter.summary());
+        self.apply_columns(None);
         // Update filter summary for display
         let summary = if filter.is_empty() {
             None
@@ -1745,6 +2606,8 @@ impl App {
         };
         self.list_view.set_filter_summary(summary);
         self.filter_state = filter;
+        self.current_jql = None;
+        self.tabs[self.active_tab].name = None;
         // Set list to loading - the runner will trigger a refresh
         self.list_view.set_loading(true);
         self.state = AppState::IssueList;
@@ -1754,7 +2617,9 @@ impl App {
     pub fn clear_filters(&mut self) {
         debug!("Clearing all filters");
         self.filter_state.clear();
+        self.apply_columns(None);
         self.list_view.set_filter_summary(None);
+        self.tabs[self.active_tab].name = None;
         self.list_view.set_loading(true);
     }
 
@@ -1775,6 +2640,14 @@ impl App {
             .show(filters, self.filter_state.clone());
     }
 
+    /// Apply a saved filter, including any columns configured for it.
+    pub fn apply_saved_filter(&mut self, saved_filter: SavedFilter) {
+        debug!(name = %saved_filter.name, "Applying saved filter");
+        self.apply_filter(saved_filter.filter.clone());
+        self.apply_columns(Some(&saved_filter));
+        self.tabs[self.active_tab].name = Some(saved_filter.name);
+    }
+
     /// Save the current filter state with the given name.
     pub fn save_current_filter(&mut self, name: String) {
         debug!(name = %name, "Saving current filter");
@@ -1804,626 +2677,1031 @@ impl App {
         }
     }
 
-    /// Get the JQL query string from the current filter state.
-    pub fn filter_jql(&self) -> String {
-        self.filter_state.to_jql()
+    // ========================================================================
+    // List column methods
+    // ========================================================================
+
+    /// Resolve the issue list columns from configuration.
+    ///
+    /// The saved filter's columns take precedence, then the profile's, then
+    /// the global settings. Falls back to the default columns.
+    fn resolve_columns(
+        config: &Config,
+        profile: Option<&Profile>,
+        saved_filter: Option<&SavedFilter>,
+    ) -> Vec<ListColumn> {
+        let configured = saved_filter
+            .and_then(|f| f.columns.as_deref())
+            .or_else(|| profile.and_then(|p| p.columns.as_deref()))
+            .unwrap_or(&config.settings.columns);
+        ListColumn::from_configs(configured)
+    }
+
+    /// Update the list view columns for the current profile and saved filter.
+    fn apply_columns(&mut self, saved_filter: Option<&SavedFilter>) {
+        let columns =
+            Self::resolve_columns(&self.config, self.current_profile.as_ref(), saved_filter);
+        self.list_view.set_columns(columns);
+    }
+
+    /// Resolve the columns of every tab again, e.g. after the config file
+    /// changed.
+    fn refresh_columns(&mut self) {
+        for index in 0..self.tabs.len() {
+            let saved_filter = self.tabs[index].name.as_ref().and_then(|name| {
+                self.config
+                    .settings
+                    .saved_filters
+                    .iter()
+                    .find(|f| &f.name == name)
+            });
+            let columns =
+                Self::resolve_columns(&self.config, self.current_profile.as_ref(), saved_filter);
+            if index == self.active_tab {
+                self.list_view.set_columns(columns);
+            } else {
+                self.tabs[index].list_view.set_columns(columns);
+            }
+        }
     }
 
     // ========================================================================
-    // JQL input methods
+    // Tab methods
     // ========================================================================
 
-    /// Get a reference to the JQL input.
-    pub fn jql_input(&self) -> &JqlInput {
-        &self.jql_input
+    /// Set the IDs of the custom fields the issue lists show by name.
+    ///
+    /// Called when a client connects, as the IDs differ between instances.
+    pub fn set_field_ids(&mut self, field_ids: FieldIds) {
+        for tab in &mut self.tabs {
+            tab.list_view.set_field_ids(field_ids.clone());
+        }
+        self.list_view.set_field_ids(field_ids.clone());
+        self.field_ids = field_ids;
     }
 
-    /// Get a mutable reference to the JQL input.
-    pub fn jql_input_mut(&mut self) -> &mut JqlInput {
-        &mut self.jql_input
+    /// Create an empty list view for a new tab, configured for the current profile.
+    fn new_tab_list_view(&self) -> ListView {
+        let mut list_view = ListView::with_page_size(self.settings.page_size);
+        list_view.set_field_ids(self.field_ids.clone());
+        list_view.set_loading(true);
+        list_view.set_profile_name(self.current_profile.as_ref().map(|p| p.name.clone()));
+        list_view.set_columns(Self::resolve_columns(
+            &self.config,
+            self.current_profile.as_ref(),
+            None,
+        ));
+        list_view
     }
 
-    /// Get the current JQL query if set.
-    pub fn current_jql(&self) -> Option<&str> {
-        self.current_jql.as_deref()
+    /// Swap the active tab's stored state with the live list, filter, JQL
+    /// and fetch request.
+    ///
+    /// Called once to park the active tab and once more to activate another.
+    fn swap_active_tab(&mut self) {
+        let tab = &mut self.tabs[self.active_tab];
+        std::mem::swap(&mut self.list_view, &mut tab.list_view);
+        std::mem::swap(&mut self.filter_state, &mut tab.filter_state);
+        std::mem::swap(&mut self.current_jql, &mut tab.current_jql);
+        std::mem::swap(&mut self.fetch_request, &mut tab.fetch_request);
     }
 
-    /// Open the JQL input.
-    pub fn open_jql_input(&mut self) {
-        debug!("Opening JQL input");
-        self.jql_input.show();
-        self.state = AppState::JqlInput;
+    /// Open the current profile's tabs from configuration, replacing any open tabs.
+    ///
+    /// Opens a single default tab if none are saved. Every tab starts loading;
+    /// only the active one is fetched until the others are visited.
+    fn restore_tabs(&mut self) {
+        let (configs, active_tab) = self
+            .current_profile
+            .as_ref()
+            .and_then(|p| self.config.profile_state(&p.name))
+            .map(|state| (state.tabs.clone(), state.active_tab))
+            .unwrap_or_default();
+
+        let mut tabs: Vec<WorkspaceTab> = configs
+            .into_iter()
+            .map(|config| {
+                let mut list_view = self.new_tab_list_view();
+                if let Some(column) = config.sort.as_deref().and_then(ColumnField::from_field_id) {
+                    let direction = if config.sort_ascending {
+                        SortDirection::Ascending
+                    } else {
+                        SortDirection::Descending
+                    };
+                    list_view.set_sort(SortState::new(column, direction));
+                }
+                list_view
+                    .set_filter_summary(Self::query_summary(config.jql.as_deref(), &config.filter));
+                list_view.restore_position(config.selected, config.scroll);
+                WorkspaceTab {
+                    name: config.name,
+                    list_view,
+                    filter_state: config.filter,
+                    current_jql: config.jql,
+                    fetch_request: 0,
+                }
+            })
+            .collect();
+        if tabs.is_empty() {
+            tabs.push(WorkspaceTab::new(self.new_tab_list_view()));
+        }
+
+        // The previous live state ends up in the active tab's placeholder
+        self.tabs = tabs;
+        self.active_tab = active_tab.min(self.tabs.len() - 1);
+        self.swap_active_tab();
+        self.pending_tab_fetch = true;
     }
 
-    /// Execute a JQL query.
+    /// Open the query pinned by the local `.lazyjira.toml`, if any.
     ///
-    /// This sets the current JQL, clears filter state, and triggers a refresh.
-    /// Also saves the query to history in config.
-    pub fn execute_jql(&mut self, jql: String) {
-        debug!(jql = %jql, "Executing JQL query");
-        // Clear filter state when using direct JQL
-        self.filter_state.clear();
-        self.current_jql = Some(jql.clone());
-        // Update filter summary to show JQL is active
-        self.list_view
-            .set_filter_summary(Some(format!("JQL: {}", jql)));
-
-        // Save to config history
-        self.config.add_jql_to_history(jql);
-        // Persist config (ignore errors)
-        if let Err(e) = self.config.save() {
-            debug!("Failed to save JQL history to config: {}", e);
+    /// Activates the tab showing the pinned JQL, opening one if needed.
+    /// Without a pinned JQL, a pinned project filters the active tab when it
+    /// has no query of its own.
+    fn open_local_query(&mut self) {
+        let Some(local) = self.config.local.clone() else {
+            return;
+        };
+        if let Some(jql) = local.jql {
+            self.open_query(jql);
+        } else if let Some(project) = local.project {
+            if self.current_jql.is_none() && self.filter_state.is_empty() {
+                self.filter_state.project = Some(project);
+                self.list_view
+                    .set_filter_summary(Self::query_summary(None, &self.filter_state));
+            }
         }
+    }
 
-        // Trigger refresh
-        self.list_view.set_loading(true);
-        self.state = AppState::IssueList;
+    /// Open a JQL query, e.g. one given on the command line.
+    ///
+    /// Activates the tab showing the query, opening one unless the active
+    /// tab has no query of its own. A query using unknown aliases is
+    /// reported instead.
+    pub fn open_query(&mut self, jql: String) {
+        if let Err(e) = self.config.aliases.expand(&jql) {
+            self.notify_error(format!("Invalid query: {}", e));
+            return;
+        }
+        let existing = (0..self.tabs.len()).find(|&index| {
+            let tab_jql = if index == self.active_tab {
+                &self.current_jql
+            } else {
+                &self.tabs[index].current_jql
+            };
+            tab_jql.as_deref() == Some(jql.as_str())
+        });
+        if let Some(index) = existing {
+            self.switch_tab(index);
+            return;
+        }
+        if self.current_jql.is_some() || !self.filter_state.is_empty() {
+            self.new_tab();
+        }
+        self.list_view
+            .set_filter_summary(Self::query_summary(Some(&jql), &self.filter_state));
+        self.current_jql = Some(jql);
     }
 
-    /// Execute a command action from the command palette.
-    fn execute_command_action(&mut self, action: CommandAction) {
-        match action {
-            CommandAction::GoToList => {
-                debug!("Command: Go to issue list");
-                self.state = AppState::IssueList;
-            }
-            CommandAction::GoToProfiles => {
-                debug!("Command: Go to profile management");
-                self.open_profile_management();
-            }
-            CommandAction::GoToFilters => {
-                debug!("Command: Open filter panel");
-                self.open_filter_panel();
-            }
-            CommandAction::GoToHelp => {
-                debug!("Command: Show help");
-                if self.state != AppState::Help {
-                    self.previous_state = Some(self.state);
-                    let current_context = KeyContext::from_app_state(&self.state);
-                    self.help_view = HelpView::new(current_context);
-                    self.state = AppState::Help;
-                }
-            }
-            CommandAction::RefreshIssues => {
-                debug!("Command: Refresh issues");
-                self.list_view.set_loading(true);
-                // The main loop will handle triggering the actual refresh
-            }
-            CommandAction::SwitchProfile => {
-                debug!("Command: Switch profile");
-                self.show_profile_picker();
-            }
-            CommandAction::OpenJqlInput => {
-                debug!("Command: Open JQL input");
-                self.open_jql_input();
-            }
-            CommandAction::ClearFilters => {
-                debug!("Command: Clear filters");
-                self.filter_state.clear();
-                self.current_jql = None;
-                self.list_view.set_filter_summary(None);
-                self.list_view.set_loading(true);
-                self.notify_info("Filters cleared");
-            }
-            CommandAction::ClearCache => {
-                debug!("Command: Clear cache");
-                // TODO: Implement cache clearing when cache module exposes this
-                self.notify_info("Cache cleared");
-            }
+    /// Get the status bar summary for a query.
+    fn query_summary(current_jql: Option<&str>, filter_state: &FilterState) -> Option<String> {
+        if let Some(jql) = current_jql {
+            Some(format!("JQL: {}", jql))
+        } else if filter_state.is_empty() {
+            None
+        } else {
+            Some(filter_state.summary().join(", "))
         }
     }
 
-    /// Set an error on the JQL input.
-    pub fn set_jql_error(&mut self, error: impl Into<String>) {
-        self.jql_input.set_error(error);
+    /// Get the persisted form of the open tabs.
+    ///
+    /// A single tab with the default query is stored as no tabs.
+    fn tab_configs(&self) -> Vec<TabConfig> {
+        let configs: Vec<TabConfig> = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| {
+                let (list_view, filter_state, current_jql) = if i == self.active_tab {
+                    (&self.list_view, &self.filter_state, &self.current_jql)
+                } else {
+                    (&tab.list_view, &tab.filter_state, &tab.current_jql)
+                };
+                let sort = list_view.sort();
+                let (selected, scroll) = list_view.position();
+                TabConfig {
+                    name: tab.name.clone(),
+                    jql: current_jql.clone(),
+                    filter: filter_state.clone(),
+                    sort: (*sort != SortState::default()).then(|| sort.column.field_id()),
+                    sort_ascending: sort.direction == SortDirection::Ascending,
+                    selected,
+                    scroll,
+                }
+            })
+            .collect();
+
+        if configs == [TabConfig::default()] {
+            Vec::new()
+        } else {
+            configs
+        }
     }
 
-    /// Get the effective JQL query.
+    /// Save the open tabs, the open issue and navigation history to the
+    /// current profile in the config file.
     ///
-    /// Returns the current direct JQL query if set, otherwise generates JQL
-    /// from the filter state. Appends the current sort order from the list view
-    /// unless the query already contains an ORDER BY clause.
-    pub fn effective_jql(&self) -> String {
-        let base_jql = if let Some(jql) = &self.current_jql {
-            jql.clone()
+    /// Called on exit and before switching profiles. Does nothing if neither
+    /// has changed.
+    pub fn persist_session(&mut self) {
+        self.save_location();
+        let tabs = self.tab_configs();
+        let active_tab = self.active_tab;
+        let location = self
+            .current_location()
+            .filter(|location| matches!(location, Location::Issue { .. }));
+        // A history without issues only holds the startup list view
+        let history = if self.history.recent_issues().is_empty() {
+            JumpList::new()
         } else {
-            self.filter_state.to_jql()
+            self.history.clone()
         };
-
-        // If empty, let caller handle default query
-        if base_jql.is_empty() {
-            return base_jql;
+        let recent_commands = self.command_palette.recent_commands();
+        let Some(name) = self.current_profile.as_ref().map(|p| p.name.clone()) else {
+            return;
+        };
+        if self.config.get_profile(&name).is_none() {
+            return;
+        }
+        let state = self.config.profile_state_mut(&name);
+        if state.tabs == tabs
+            && state.active_tab == active_tab
+            && state.location == location
+            && state.history == history
+            && state.recent_commands == recent_commands
+        {
+            return;
         }
 
-        // If already has ORDER BY, don't modify (user explicitly set sort)
-        if base_jql.to_uppercase().contains("ORDER BY") {
-            return base_jql;
+        state.tabs = tabs;
+        state.active_tab = active_tab;
+        state.location = location;
+        state.history = history;
+        state.recent_commands = recent_commands;
+        if let Err(e) = self.config.save_state(&name) {
+            debug!("Failed to save session state: {}", e);
         }
+    }
 
-        // Append sort clause from list view
-        format!("{} {}", base_jql, self.list_view.sort().to_jql())
+    /// Start fetching the active tab's issues.
+    ///
+    /// Returns the id the fetched results must carry to be shown, so that a
+    /// response arriving after a tab switch or a new query is dropped.
+    pub fn begin_issue_fetch(&mut self) -> u64 {
+        self.last_fetch_request += 1;
+        self.fetch_request = self.last_fetch_request;
+        self.fetch_request
     }
 
-    /// Set the JQL history.
-    pub fn set_jql_history(&mut self, history: Vec<String>) {
-        self.jql_input.set_history(history);
+    /// Check whether fetched issues belong to the active tab's latest fetch.
+    pub fn is_current_fetch(&self, request: u64) -> bool {
+        request == self.fetch_request
     }
 
-    /// Get the JQL history.
-    pub fn jql_history(&self) -> Vec<String> {
-        self.jql_input.history()
+    /// Get the number of open tabs.
+    pub fn tab_count(&self) -> usize {
+        self.tabs.len()
     }
 
-    // ========================================================================
-    // Issue edit methods
-    // ========================================================================
+    /// Get the index of the active tab.
+    pub fn active_tab(&self) -> usize {
+        self.active_tab
+    }
 
-    /// Get the pending issue update, if any.
-    pub fn take_pending_issue_update(&mut self) -> Option<(String, IssueUpdateRequest)> {
-        self.pending_issue_update.take()
+    /// Get the titles of all open tabs.
+    pub fn tab_titles(&self) -> Vec<String> {
+        self.tabs
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| {
+                if i == self.active_tab {
+                    WorkspaceTab::title(
+                        tab.name.as_deref(),
+                        self.current_jql.as_deref(),
+                        &self.filter_state,
+                    )
+                } else {
+                    WorkspaceTab::title(
+                        tab.name.as_deref(),
+                        tab.current_jql.as_deref(),
+                        &tab.filter_state,
+                    )
+                }
+            })
+            .collect()
     }
 
-    /// Check if there is a pending issue update.
-    pub fn has_pending_issue_update(&self) -> bool {
-        self.pending_issue_update.is_some()
+    /// Check and clear whether the active tab needs its issues fetched.
+    pub fn take_pending_tab_fetch(&mut self) -> bool {
+        std::mem::take(&mut self.pending_tab_fetch)
     }
 
-    /// Handle successful issue update.
+    /// Switch to the tab at the given index.
     ///
-    /// Updates the local issue data and exits edit mode.
-    pub fn handle_issue_update_success(&mut self, updated_issue: Issue) {
-        info!(key = %updated_issue.key, "Issue updated successfully");
-
-        // Clear saving state
-        self.detail_view.set_saving(false);
-
-        // Update the detail view with the updated issue
-        self.detail_view.set_issue(updated_issue.clone());
-
-        // Update the issue in the list view if present
-        self.list_view.update_issue(&updated_issue);
+    /// Fetches the tab's issues if it has not finished loading.
+    pub fn switch_tab(&mut self, index: usize) {
+        if index == self.active_tab || index >= self.tabs.len() {
+            return;
+        }
+        debug!(from = self.active_tab, to = index, "Switching tab");
 
-        // Show success notification
-        self.notify_success(format!("Issue {} updated", updated_issue.key));
-    }
+        // An in-flight page for the old tab would be appended to the new one
+        self.list_view.pagination_mut().cancel_loading();
+        self.swap_active_tab();
+        self.active_tab = index;
+        self.swap_active_tab();
 
-    /// Handle failed issue update.
-    pub fn handle_issue_update_failure(&mut self, error: &str) {
-        warn!(error = %error, "Issue update failed");
-        self.detail_view.set_saving(false);
-        self.notify_error(format!("Failed to update issue: {}", error));
+        if self.list_view.is_loading() {
+            self.pending_tab_fetch = true;
+        }
     }
 
-    /// Show the discard changes confirmation dialog.
-    fn show_discard_confirm_dialog(&mut self) {
-        self.discard_confirm_dialog.show_destructive_with_label(
-            "Discard Changes?",
-            "You have unsaved changes. Are you sure you want to discard them?",
-            "Discard",
-        );
+    /// Switch to the next tab, wrapping around.
+    pub fn next_tab(&mut self) {
+        let index = (self.active_tab + 1) % self.tabs.len();
+        self.switch_tab(index);
     }
 
-    /// Check if the discard confirm dialog is visible.
-    pub fn is_discard_confirm_visible(&self) -> bool {
-        self.discard_confirm_dialog.is_visible()
+    /// Switch to the previous tab, wrapping around.
+    pub fn prev_tab(&mut self) {
+        let index = (self.active_tab + self.tabs.len() - 1) % self.tabs.len();
+        self.switch_tab(index);
     }
 
-    /// Check if the transition confirm dialog is visible.
-    pub fn is_transition_confirm_visible(&self) -> bool {
-        self.transition_confirm_dialog.is_visible()
+    /// Open a new tab with the default query and switch to it.
+    pub fn new_tab(&mut self) {
+        debug!("Opening new tab");
+        let list_view = self.new_tab_list_view();
+        self.tabs.push(WorkspaceTab::new(list_view));
+        self.switch_tab(self.tabs.len() - 1);
     }
 
-    /// Show the transition confirmation dialog.
+    /// Close the active tab and switch to its neighbour.
     ///
-    /// If confirm_transitions setting is true, shows a confirmation dialog.
-    /// Otherwise, executes the transition immediately.
-    fn request_transition_with_confirmation(
-        &mut self,
-        issue_key: String,
-        transition_id: String,
-        transition_name: String,
-        fields: Option<FieldUpdates>,
-    ) {
-        if self.config.settings.confirm_transitions {
-            // Store the pending transition for confirmation
-            self.pending_transition_confirm = Some((
-                issue_key.clone(),
-                transition_id,
-                transition_name.clone(),
-                fields,
-            ));
-            // Show the confirmation dialog
-            self.transition_confirm_dialog.show_with_labels(
-                "Change Status?",
-                format!("Move issue {} to '{}'?", issue_key, transition_name),
-                "Confirm",
-                "Cancel",
-            );
-        } else {
-            // Execute immediately without confirmation
-            self.pending_transition = Some((issue_key, transition_id, fields));
+    /// The last remaining tab cannot be closed.
+    pub fn close_tab(&mut self) {
+        if self.tabs.len() <= 1 {
+            self.notify_warning("Cannot close the last tab");
+            return;
         }
-    }
+        debug!(index = self.active_tab, "Closing tab");
 
-    /// Confirm the pending transition and execute it.
-    fn confirm_transition(&mut self) {
-        if let Some((issue_key, transition_id, _name, fields)) =
-            self.pending_transition_confirm.take()
-        {
-            self.pending_transition = Some((issue_key, transition_id, fields));
+        // The removed entry is the placeholder; the closed tab's live state
+        // becomes the new active tab's placeholder
+        self.tabs.remove(self.active_tab);
+        self.history.remove_tab(self.active_tab);
+        self.active_tab = self.active_tab.min(self.tabs.len() - 1);
+        self.swap_active_tab();
+
+        if self.list_view.is_loading() {
+            self.pending_tab_fetch = true;
         }
     }
 
-    /// Cancel the pending transition confirmation.
-    fn cancel_transition_confirm(&mut self) {
-        self.pending_transition_confirm = None;
+    /// Get the JQL query string from the current filter state.
+    pub fn filter_jql(&self) -> String {
+        self.filter_state.to_jql()
     }
 
     // ========================================================================
-    // Transition methods
+    // Navigation history methods
     // ========================================================================
 
-    /// Take the pending transition request, if any.
-    ///
-    /// Returns (issue_key, transition_id, optional fields).
-    pub fn take_pending_transition(&mut self) -> Option<(String, String, Option<FieldUpdates>)> {
-        self.pending_transition.take()
+    /// Load the current profile's navigation history, starting at the active
+    /// tab's issue list.
+    fn restore_history(&mut self) {
+        self.history = self
+            .current_profile
+            .as_ref()
+            .and_then(|p| self.config.profile_state(&p.name))
+            .map(|state| state.history.clone())
+            .unwrap_or_default();
+        self.history.visit(Location::List {
+            tab: self.active_tab,
+        });
     }
 
-    /// Check if there is a pending transition.
-    pub fn has_pending_transition(&self) -> bool {
-        self.pending_transition.is_some()
+    /// Reopen the issue that was open when the current profile was last used.
+    fn restore_location(&mut self) {
+        let location = self
+            .current_profile
+            .as_ref()
+            .and_then(|p| self.config.profile_state(&p.name))
+            .and_then(|state| state.location.clone());
+        if let Some(location) = location {
+            debug!(?location, "Restoring last view");
+            self.history.visit(location.clone());
+            self.jump_to(location);
+        }
     }
 
-    /// Take the pending fetch transitions request, if any.
-    ///
-    /// Returns the issue key.
-    pub fn take_pending_fetch_transitions(&mut self) -> Option<String> {
-        self.pending_fetch_transitions.take()
+    /// Load the current profile's JQL history and recently used commands.
+    fn restore_recent(&mut self) {
+        let state = self
+            .current_profile
+            .as_ref()
+            .and_then(|p| self.config.profile_state(&p.name))
+            .cloned()
+            .unwrap_or_default();
+        self.jql_input.set_history(state.jql_history);
+        self.command_palette
+            .set_recent_commands(&state.recent_commands);
     }
 
-    /// Check if there is a pending fetch transitions request.
-    pub fn has_pending_fetch_transitions(&self) -> bool {
-        self.pending_fetch_transitions.is_some()
+    /// Get the location currently shown, if it can be returned to.
+    fn current_location(&self) -> Option<Location> {
+        match self.state {
+            AppState::IssueList | AppState::Loading => Some(Location::List {
+                tab: self.active_tab,
+            }),
+            AppState::IssueDetail => {
+                let issue = self.detail_view.issue()?;
+                let panel = if self.detail_view.is_comments_panel_visible() {
+                    Some(IssuePanel::Comments)
+                } else if self.detail_view.is_history_visible() {
+                    Some(IssuePanel::History)
+                } else {
+                    None
+                };
+                Some(Location::Issue {
+                    key: issue.key.clone(),
+                    summary: issue.fields.summary.clone(),
+                    scroll: self.detail_view.scroll(),
+                    panel,
+                })
+            }
+            _ => None,
+        }
     }
 
-    /// Set the available transitions in the detail view.
-    pub fn set_transitions(&mut self, transitions: Vec<Transition>) {
-        self.detail_view.set_transitions(transitions);
+    /// Record the current location as newly visited.
+    fn record_location(&mut self) {
+        if let Some(location) = self.current_location() {
+            self.history.visit(location);
+        }
     }
 
-    /// Handle successful transition completion.
-    ///
-    /// Updates the local issue data with the refreshed issue.
-    pub fn handle_transition_success(&mut self, updated_issue: Issue) {
-        info!(key = %updated_issue.key, "Issue transitioned successfully");
+    /// Remember the scroll and panel state of the current location before
+    /// leaving it.
+    fn save_location(&mut self) {
+        if let Some(location) = self.current_location() {
+            self.history.update_current(location);
+        }
+    }
 
-        // Update the detail view with the updated issue
-        self.detail_view.set_issue(updated_issue.clone());
+    /// Check whether back/forward navigation keys apply in the current view.
+    fn can_jump(&self) -> bool {
+        match self.state {
+            AppState::IssueList | AppState::Loading => true,
+            // Popups keep Tab (which terminals also send for Ctrl+I) for
+            // their own focus handling.
+            AppState::IssueDetail => self.can_open_issue() && !self.detail_view.has_popup(),
+            _ => false,
+        }
+    }
 
-        // Update the issue in the list view if present
-        self.list_view.update_issue(&updated_issue);
+    /// Check whether another issue can be opened without losing unsaved
+    /// input in the current view.
+    fn can_open_issue(&self) -> bool {
+        match self.state {
+            AppState::CreateIssue => false,
+            AppState::IssueDetail => {
+                !self.detail_view.is_editing() && !self.detail_view.is_composing_comment()
+            }
+            _ => true,
+        }
+    }
 
-        // Show success notification
-        self.notify_success(format!(
-            "Issue {} status changed to {}",
-            updated_issue.key, updated_issue.fields.status.name
-        ));
+    /// Get the navigation history.
+    pub fn history(&self) -> &JumpList {
+        &self.history
     }
 
-    /// Handle failed transition.
-    pub fn handle_transition_failure(&mut self, error: &str) {
-        warn!(error = %error, "Transition failed");
-        self.detail_view.hide_transition_picker();
-        self.notify_error(format!("Failed to change status: {}", error));
+    /// Move back to the previous location in the history.
+    pub fn jump_back(&mut self) {
+        self.save_location();
+        if let Some(location) = self.history.back().cloned() {
+            debug!(?location, "Jumping back");
+            self.jump_to(location);
+        }
     }
 
-    /// Handle failure to fetch transitions.
-    pub fn handle_fetch_transitions_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch transitions");
-        self.detail_view.hide_transition_picker();
-        self.notify_error(format!("Failed to load transitions: {}", error));
+    /// Move forward to the next location in the history.
+    pub fn jump_forward(&mut self) {
+        self.save_location();
+        if let Some(location) = self.history.forward().cloned() {
+            debug!(?location, "Jumping forward");
+            self.jump_to(location);
+        }
     }
 
-    // ========================================================================
-    // Assignee Picker Methods
-    // ========================================================================
+    /// Open an issue's detail view as a new visit.
+    pub fn open_issue(&mut self, key: &str) {
+        self.save_location();
+        let location = Location::Issue {
+            key: key.to_string(),
+            summary: String::new(),
+            scroll: 0,
+            panel: None,
+        };
+        self.history.visit(location.clone());
+        self.jump_to(location);
+    }
 
-    /// Take the pending fetch assignees request, if any.
+    /// Show a location without recording a new visit.
     ///
-    /// Returns the (issue_key, project_key).
-    /// Also sets the `assignee_fetch_for_create_issue` flag based on the issue_key.
-    pub fn take_pending_fetch_assignees(&mut self) -> Option<(String, String)> {
-        if let Some((issue_key, project_key)) = self.pending_fetch_assignees.take() {
-            // Check if this fetch is for create issue context
-            self.assignee_fetch_for_create_issue = issue_key == "__create_issue__";
-            Some((issue_key, project_key))
-        } else {
-            None
+    /// Issues that are not loaded are fetched first and shown once the fetch
+    /// succeeds.
+    fn jump_to(&mut self, location: Location) {
+        match location {
+            Location::List { tab } => {
+                self.pending_jump = None;
+                self.detail_view.clear();
+                self.state = AppState::IssueList;
+                self.switch_tab(tab);
+            }
+            Location::Issue { ref key, .. } => {
+                let loaded = self
+                    .detail_view
+                    .issue()
+                    .filter(|issue| &issue.key == key)
+                    .or_else(|| self.list_view.issue_by_key(key))
+                    .cloned();
+                match loaded {
+                    Some(issue) => {
+                        self.pending_jump = None;
+                        self.show_issue_location(issue, &location);
+                    }
+                    None => {
+                        self.pending_navigate_to_issue = Some(key.clone());
+                        self.start_loading(format!("Loading issue {}...", key));
+                        self.pending_jump = Some(location);
+                    }
+                }
+            }
         }
     }
 
-    /// Check if there is a pending fetch assignees request.
-    pub fn has_pending_fetch_assignees(&self) -> bool {
-        self.pending_fetch_assignees.is_some()
+    /// Show an issue with the scroll position and panel of a location.
+    fn show_issue_location(&mut self, issue: Issue, location: &Location) {
+        let key = issue.key.clone();
+        self.set_detail_issue(issue);
+        self.state = AppState::IssueDetail;
+
+        if let Location::Issue { scroll, panel, .. } = location {
+            self.detail_view.set_scroll(*scroll);
+            match panel {
+                Some(IssuePanel::Comments) => {
+                    self.detail_view.show_comments_panel();
+                    self.pending_fetch_comments = Some(key);
+                }
+                Some(IssuePanel::History) => {
+                    self.detail_view.show_history();
+                    self.pending_fetch_changelog = Some((key, 0));
+                }
+                None => {}
+            }
+        }
+        // Fill in the summary for issues opened by key
+        self.save_location();
     }
 
-    /// Check if the current/last assignee fetch was for create issue context.
-    pub fn is_assignee_fetch_for_create_issue(&self) -> bool {
-        self.assignee_fetch_for_create_issue
+    /// Update the recently viewed issues listed in the command palette.
+    fn refresh_recent_issues(&mut self) {
+        let recent: Vec<(&str, &str)> = self
+            .history
+            .recent_issues()
+            .into_iter()
+            .take(MAX_RECENT_ISSUES)
+            .collect();
+        self.command_palette.set_recent_issues(&recent);
     }
 
-    /// Set the available assignable users in the detail view.
-    pub fn set_assignable_users(&mut self, users: Vec<User>) {
-        self.detail_view.set_assignable_users(users);
+    // ========================================================================
+    // Go to issue methods
+    // ========================================================================
+
+    /// Open the go to issue finder.
+    ///
+    /// Lists recently viewed issues first, then the issues loaded in any tab.
+    /// Cached issues are added once the runner has read them.
+    pub fn open_go_to_issue(&mut self) {
+        debug!("Opening go to issue finder");
+        let mut candidates: Vec<IssueCandidate> = self
+            .history
+            .recent_issues()
+            .into_iter()
+            .map(|(key, summary)| IssueCandidate::new(key, summary, IssueSource::Recent))
+            .collect();
+        let loaded = self.tabs.iter().enumerate().flat_map(|(i, tab)| {
+            if i == self.active_tab {
+                self.list_view.issues()
+            } else {
+                tab.list_view.issues()
+            }
+        });
+        candidates.extend(loaded.map(|issue| {
+            IssueCandidate::new(&issue.key, &issue.fields.summary, IssueSource::Local)
+        }));
+
+        self.go_to_issue.show(candidates);
+        self.pending_cached_issues = true;
     }
 
-    /// Set the available assignable users in the create issue view.
-    pub fn set_create_issue_assignable_users(&mut self, users: Vec<User>) {
-        let current_assignee = self
-            .create_issue_form
-            .assignee_name
-            .clone()
-            .unwrap_or_else(|| "Unassigned".to_string());
-        self.create_issue_view
-            .set_assignable_users(users, &current_assignee);
+    /// Check if the go to issue finder is visible.
+    pub fn is_go_to_issue_visible(&self) -> bool {
+        self.go_to_issue.is_visible()
     }
 
-    /// Hide the create issue assignee picker (e.g., on fetch failure).
-    pub fn hide_create_issue_assignee_picker(&mut self) {
-        self.create_issue_view.hide_assignee_picker();
+    /// Check and clear whether the finder needs the cached issues.
+    pub fn take_pending_cached_issues(&mut self) -> bool {
+        std::mem::take(&mut self.pending_cached_issues)
     }
 
-    /// Take the pending assignee change request, if any.
-    ///
-    /// Returns the (issue_key, account_id or None for unassign).
-    pub fn take_pending_assignee_change(&mut self) -> Option<(String, Option<String>)> {
-        self.pending_assignee_change.take()
+    /// Add cached issues to the go to issue finder.
+    pub fn add_cached_issues(&mut self, issues: Vec<Issue>) {
+        let candidates = issues
+            .into_iter()
+            .map(|issue| IssueCandidate::new(issue.key, issue.fields.summary, IssueSource::Local))
+            .collect();
+        self.go_to_issue.add_local(candidates);
     }
 
-    /// Check if there is a pending assignee change request.
-    pub fn has_pending_assignee_change(&self) -> bool {
-        self.pending_assignee_change.is_some()
+    /// Take the pending go to issue search query.
+    pub fn take_pending_go_to_search(&mut self) -> Option<String> {
+        self.pending_go_to_search.take()
     }
 
-    /// Handle successful assignee change.
+    /// Handle go to issue search results.
+    pub fn handle_go_to_search_success(&mut self, query: &str, suggestions: Vec<IssueSuggestion>) {
+        self.go_to_issue.set_remote_results(query, suggestions);
+    }
+
+    /// Handle a failed go to issue search.
     ///
-    /// Updates the local issue data with the refreshed issue.
-    pub fn handle_assignee_change_success(&mut self, updated_issue: Issue) {
-        info!(key = %updated_issue.key, "Assignee changed successfully");
+    /// Local matches are still listed, so the failure is only logged.
+    pub fn handle_go_to_search_failure(&mut self, query: &str, error: &str) {
+        warn!(query = %query, error = %error, "Go to issue search failed");
+        self.go_to_issue.set_search_failed(query);
+    }
 
-        // Update the detail view with the updated issue
-        self.detail_view.set_issue(updated_issue.clone());
+    // ========================================================================
+    // Mention methods
+    // ========================================================================
 
-        // Update the issue in the list view if present
-        self.list_view.update_issue(&updated_issue);
+    /// Take the pending @mention user search query.
+    pub fn take_pending_mention_search(&mut self) -> Option<String> {
+        self.pending_mention_search.take()
+    }
 
-        // Show success notification
-        let assignee_name = updated_issue.assignee_name();
-        self.notify_success(format!(
-            "Issue {} assignee changed to {}",
-            updated_issue.key, assignee_name
-        ));
+    /// Handle @mention user search results.
+    pub fn handle_mention_search_success(&mut self, query: &str, users: Vec<User>) {
+        self.detail_view.set_mention_results(query, users.clone());
+        self.create_issue_view.set_mention_results(query, users);
     }
 
-    /// Handle failed assignee change.
-    pub fn handle_assignee_change_failure(&mut self, error: &str) {
-        warn!(error = %error, "Assignee change failed");
-        self.detail_view.hide_assignee_picker();
-        self.notify_error(format!("Failed to change assignee: {}", error));
-    }
-
-    /// Handle failure to fetch assignable users.
-    pub fn handle_fetch_assignees_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch assignable users");
-        self.detail_view.hide_assignee_picker();
-        self.notify_error(format!("Failed to load assignees: {}", error));
+    /// Handle a failed @mention user search.
+    pub fn handle_mention_search_failure(&mut self, query: &str, error: &str) {
+        warn!(query = %query, error = %error, "Mention user search failed");
+        self.detail_view.set_mention_search_failed(query);
+        self.create_issue_view.set_mention_search_failed(query);
     }
 
     // ========================================================================
-    // Priority Picker Methods
+    // JQL input methods
     // ========================================================================
 
-    /// Take the pending fetch priorities request, if any.
-    ///
-    /// Returns the issue_key (or "__create_issue__" for create form context).
-    /// Also sets the `priority_fetch_for_create_issue` flag based on the key.
-    pub fn take_pending_fetch_priorities(&mut self) -> Option<String> {
-        if let Some(key) = self.pending_fetch_priorities.take() {
-            // Check if this fetch is for create issue context
-            self.priority_fetch_for_create_issue = key == "__create_issue__";
-            Some(key)
-        } else {
-            None
-        }
+    /// Get a reference to the JQL input.
+    pub fn jql_input(&self) -> &JqlInput {
+        &self.jql_input
     }
 
-    /// Check if there is a pending fetch priorities request.
-    pub fn has_pending_fetch_priorities(&self) -> bool {
-        self.pending_fetch_priorities.is_some()
+    /// Get a mutable reference to the JQL input.
+    pub fn jql_input_mut(&mut self) -> &mut JqlInput {
+        &mut self.jql_input
     }
 
-    /// Check if the current/last priority fetch was for create issue context.
-    pub fn is_priority_fetch_for_create_issue(&self) -> bool {
-        self.priority_fetch_for_create_issue
+    /// Get the current JQL query if set.
+    pub fn current_jql(&self) -> Option<&str> {
+        self.current_jql.as_deref()
     }
 
-    /// Set the available priorities in the detail view.
-    pub fn set_priorities(&mut self, priorities: Vec<Priority>) {
-        self.detail_view.set_priorities(priorities);
+    /// Open the JQL input.
+    pub fn open_jql_input(&mut self) {
+        debug!("Opening JQL input");
+        self.jql_input.show();
+        if self.jql_input.take_autocomplete_request() {
+            self.pending_jql_autocomplete = true;
+        }
+        self.state = AppState::JqlInput;
     }
 
-    /// Set the available priorities in the create issue view.
-    pub fn set_create_issue_priorities(&mut self, priorities: Vec<Priority>) {
-        let current_priority = self
-            .create_issue_form
-            .priority_name
-            .clone()
-            .unwrap_or_else(|| "Default".to_string());
-        self.create_issue_view
-            .set_priorities(priorities, &current_priority);
+    /// Check and clear whether the JQL autocomplete data should be fetched.
+    pub fn take_pending_jql_autocomplete(&mut self) -> bool {
+        std::mem::take(&mut self.pending_jql_autocomplete)
     }
 
-    /// Hide the priority picker in the create issue view.
-    pub fn hide_create_issue_priority_picker(&mut self) {
-        self.create_issue_view.hide_priority_picker();
+    /// Take the pending JQL value suggestion request (field, partial value).
+    pub fn take_pending_jql_suggestions(&mut self) -> Option<(String, String)> {
+        self.pending_jql_suggestions.take()
     }
 
-    /// Take the pending priority change request, if any.
+    /// Take the pending JQL query to validate.
+    pub fn take_pending_jql_validation(&mut self) -> Option<String> {
+        self.pending_jql_validation.take()
+    }
+
+    /// Handle the fetched JQL autocomplete data.
     ///
-    /// Returns the (issue_key, priority_id).
-    pub fn take_pending_priority_change(&mut self) -> Option<(String, String)> {
-        self.pending_priority_change.take()
+    /// Without it the JQL input only offers its static hints, so a failure
+    /// is only logged.
+    pub fn handle_jql_autocomplete_fetched(&mut self, result: Result<JqlAutocompleteData, String>) {
+        match result {
+            Ok(data) => self.jql_input.set_autocomplete_data(Some(data)),
+            Err(e) => {
+                warn!(error = %e, "Failed to fetch JQL autocomplete data");
+                self.jql_input.set_autocomplete_data(None);
+            }
+        }
     }
 
-    /// Check if there is a pending priority change request.
-    pub fn has_pending_priority_change(&self) -> bool {
-        self.pending_priority_change.is_some()
+    /// Handle fetched JQL value suggestions.
+    pub fn handle_jql_suggestions_fetched(
+        &mut self,
+        field: &str,
+        value: &str,
+        result: Result<Vec<JqlSuggestion>, String>,
+    ) {
+        match result {
+            Ok(suggestions) => self
+                .jql_input
+                .set_value_suggestions(field, value, suggestions),
+            Err(e) => debug!(field = %field, error = %e, "JQL value suggestions failed"),
+        }
     }
 
-    /// Handle successful priority change.
+    /// Handle the result of validating a JQL query.
+    pub fn handle_jql_validated(&mut self, query: &str, result: Result<Vec<String>, String>) {
+        match result {
+            Ok(errors) => self.jql_input.set_validation(query, errors),
+            Err(e) => debug!(error = %e, "JQL validation failed"),
+        }
+    }
+
+    /// Execute a JQL query.
     ///
-    /// Updates the local issue data with the refreshed issue.
-    pub fn handle_priority_change_success(&mut self, updated_issue: Issue) {
-        info!(key = %updated_issue.key, "Priority changed successfully");
+    /// This sets the current JQL, clears filter state, and triggers a refresh.
+    /// Also saves the query to the current profile's history.
+    pub fn execute_jql(&mut self, jql: String) {
+        debug!(jql = %jql, "Executing JQL query");
+        // Clear filter state (and any saved filter columns) when using direct JQL
+        self.filter_state.clear();
+        self.apply_columns(None);
+        self.current_jql = Some(jql.clone());
+        self.tabs[self.active_tab].name = None;
+        // Update filter summary to show JQL is active
+        self.list_view
+            .set_filter_summary(Some(format!("JQL: {}", jql)));
 
-        // Update the detail view with the updated issue
-        self.detail_view.set_issue(updated_issue.clone());
+        // Save to the profile's history
+        if let Some(name) = self.current_profile.as_ref().map(|p| p.name.clone()) {
+            self.config.profile_state_mut(&name).add_jql_to_history(jql);
+            // Persist state (ignore errors)
+            if let Err(e) = self.config.save_state(&name) {
+                debug!("Failed to save JQL history: {}", e);
+            }
+        }
 
-        // Update the issue in the list view if present
-        self.list_view.update_issue(&updated_issue);
+        // Trigger refresh
+        self.list_view.set_loading(true);
+        self.state = AppState::IssueList;
+    }
 
-        // Show success notification
-        let priority_name = updated_issue.priority_name();
-        self.notify_success(format!(
-            "Issue {} priority changed to {}",
-            updated_issue.key, priority_name
-        ));
+    /// Execute a command action from the command palette.
+    fn execute_command_action(&mut self, action: CommandAction) {
+        match action {
+            CommandAction::GoToList => {
+                debug!("Command: Go to issue list");
+                self.state = AppState::IssueList;
+            }
+            CommandAction::GoToProfiles => {
+                debug!("Command: Go to profile management");
+                self.open_profile_management();
+            }
+            CommandAction::GoToFilters => {
+                debug!("Command: Open filter panel");
+                self.open_filter_panel();
+            }
+            CommandAction::GoToHelp => {
+                debug!("Command: Show help");
+                if self.state != AppState::Help {
+                    self.previous_state = Some(self.state);
+                    let current_context = KeyContext::from_app_state(&self.state);
+                    self.help_view = HelpView::new(current_context);
+                    self.state = AppState::Help;
+                }
+            }
+            CommandAction::RefreshIssues => {
+                debug!("Command: Refresh issues");
+                self.list_view.set_loading(true);
+                // The main loop will handle triggering the actual refresh
+            }
+            CommandAction::SwitchProfile => {
+                debug!("Command: Switch profile");
+                self.show_profile_picker();
+            }
+            CommandAction::OpenJqlInput => {
+                debug!("Command: Open JQL input");
+                self.open_jql_input();
+            }
+            CommandAction::ClearFilters => {
+                debug!("Command: Clear filters");
+                self.filter_state.clear();
+                self.current_jql = None;
+                self.list_view.set_filter_summary(None);
+                self.tabs[self.active_tab].name = None;
+                self.list_view.set_loading(true);
+                self.notify_info("Filters cleared");
+            }
+            CommandAction::ClearCache => {
+                debug!("Command: Clear cache");
+                // TODO: Implement cache clearing when cache module exposes this
+                self.notify_info("Cache cleared");
+            }
+            CommandAction::GoToIssue => {
+                debug!("Command: Go to issue");
+                self.open_go_to_issue();
+            }
+            CommandAction::OpenIssue(key) => {
+                debug!(key = %key, "Command: Open issue");
+                self.open_issue(&key);
+            }
+            CommandAction::OpenReleases => {
+                debug!("Command: Open releases");
+                self.open_releases();
+            }
+            CommandAction::SwitchTheme => {
+                debug!("Command: Switch theme");
+                self.theme_picker
+                    .show(self.config.theme_names(), &self.settings.theme);
+            }
+        }
     }
 
-    /// Handle failed priority change.
-    pub fn handle_priority_change_failure(&mut self, error: &str) {
-        warn!(error = %error, "Priority change failed");
-        self.detail_view.hide_priority_picker();
-        self.notify_error(format!("Failed to change priority: {}", error));
+    /// Check if the theme picker is visible.
+    pub fn is_theme_picker_visible(&self) -> bool {
+        self.theme_picker.is_visible()
     }
 
-    /// Handle failure to fetch priorities.
-    pub fn handle_fetch_priorities_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch priorities");
-        self.detail_view.hide_priority_picker();
-        self.notify_error(format!("Failed to load priorities: {}", error));
+    /// Preview a theme by making it the global theme, keeping custom overrides.
+    fn preview_theme(&self, name: &str) {
+        set_theme(load_theme(
+            name,
+            self.settings.custom_theme.as_ref(),
+            &self.config.file_themes,
+        ));
     }
 
-    // ========================================================================
-    // Comments methods
-    // ========================================================================
+    /// Apply a theme chosen in the theme picker and save it as the configured theme.
+    ///
+    /// The theme is saved to the current profile's settings if the profile
+    /// overrides the theme, and to the global settings otherwise.
+    fn apply_theme(&mut self, name: &str) {
+        self.preview_theme(name);
+        if self.settings.theme == name {
+            return;
+        }
+        self.settings.theme = name.to_string();
 
-    /// Take the pending fetch comments request.
-    pub fn take_pending_fetch_comments(&mut self) -> Option<String> {
-        self.pending_fetch_comments.take()
+        let profile_name = self.current_profile.as_ref().map(|p| p.name.clone());
+        let profile_theme = self
+            .config
+            .profiles
+            .iter_mut()
+            .find(|p| Some(&p.name) == profile_name.as_ref())
+            .and_then(|p| p.settings.theme.as_mut());
+        match profile_theme {
+            Some(theme) => *theme = name.to_string(),
+            None => self.config.settings.theme = name.to_string(),
+        }
+        match self.config.save() {
+            Ok(()) => self.notify_success(format!("Theme set to {}", name)),
+            Err(e) => self.notify_error(format!("Theme applied but not saved: {}", e)),
+        }
     }
 
-    /// Check if there is a pending fetch comments request.
-    pub fn has_pending_fetch_comments(&self) -> bool {
-        self.pending_fetch_comments.is_some()
+    /// Set an error on the JQL input.
+    pub fn set_jql_error(&mut self, error: impl Into<String>) {
+        self.jql_input.set_error(error);
     }
 
-    /// Handle successful comments fetch.
-    pub fn handle_comments_fetched(
-        &mut self,
-        comments: Vec<crate::api::types::Comment>,
-        total: u32,
-    ) {
-        debug!("Comments fetched: {} of {}", comments.len(), total);
-        self.detail_view.set_comments(comments, total);
-    }
+    /// Get the effective JQL query.
+    ///
+    /// Returns the current direct JQL query if set, with its aliases
+    /// expanded, otherwise generates JQL from the filter state. Appends the
+    /// current sort order from the list view unless the query already
+    /// contains an ORDER BY clause.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if the query's aliases can't be
+    /// expanded (e.g. an alias was removed from the config).
+    pub fn effective_jql(&self) -> Result<String, String> {
+        let base_jql = if let Some(jql) = &self.current_jql {
+            self.config.aliases.expand(jql)?
+        } else {
+            self.filter_state.to_jql()
+        };
 
-    /// Handle failure to fetch comments.
-    pub fn handle_fetch_comments_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch comments");
-        self.detail_view.hide_comments_panel();
-        self.notify_error(format!("Failed to load comments: {}", error));
+        // If empty, let caller handle default query
+        if base_jql.is_empty() {
+            return Ok(base_jql);
+        }
+
+        // If already has ORDER BY, don't modify (user explicitly set sort)
+        if base_jql.to_uppercase().contains("ORDER BY") {
+            return Ok(base_jql);
+        }
+
+        // Append sort clause from list view
+        Ok(format!("{} {}", base_jql, self.list_view.sort_jql()))
     }
 
-    /// Take the pending submit comment request.
-    pub fn take_pending_submit_comment(&mut self) -> Option<(String, String)> {
-        self.pending_submit_comment.take()
+    /// Get the JQL used to fetch the active tab's issues.
+    ///
+    /// Falls back to the user's own issues when no query is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query's aliases can't be expanded; see
+    /// [`App::effective_jql`].
+    pub fn search_jql(&self) -> Result<String, String> {
+        let jql = self.effective_jql()?;
+        if jql.is_empty() {
+            Ok(format!(
+                "assignee = currentUser() OR reporter = currentUser() {}",
+                self.list_view.sort_jql()
+            ))
+        } else {
+            Ok(jql)
+        }
     }
 
-    /// Check if there is a pending submit comment request.
-    pub fn has_pending_submit_comment(&self) -> bool {
-        self.pending_submit_comment.is_some()
+    /// Show why the active tab's query can't be run.
+    ///
+    /// The list is emptied so it doesn't show results of an earlier query.
+    pub fn handle_invalid_query(&mut self, error: &str) {
+        let message = format!("Invalid query: {}", error);
+        warn!("{}", message);
+        self.notify_error(&message);
+        self.list_view.set_issues(Vec::new());
+        self.list_view.set_loading(false);
+        self.list_view.set_error(message);
     }
 
-    /// Handle successful comment submission.
-    pub fn handle_comment_submitted(&mut self, comment: crate::api::types::Comment) {
-        info!(comment_id = %comment.id, "Comment submitted successfully");
-        self.detail_view.add_comment(comment);
-        self.notify_success("Comment added successfully");
+    /// Set the JQL history.
+    pub fn set_jql_history(&mut self, history: Vec<String>) {
+        self.jql_input.set_history(history);
     }
 
-    /// Handle failure to submit comment.
-    pub fn handle_submit_comment_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to submit comment");
-        self.detail_view.set_comment_submitting(false);
-        self.notify_error(format!("Failed to add comment: {}", error));
+    /// Get the JQL history.
+    pub fn jql_history(&self) -> Vec<String> {
+        self.jql_input.history()
     }
 
     // ========================================================================
-    // Labels methods
+    // Issue edit methods
     // ========================================================================
 
-    /// Take the pending fetch labels request.
-    pub fn take_pending_fetch_labels(&mut self) -> Option<String> {
-        self.pending_fetch_labels.take()
-    }
-
-    /// Check if there is a pending fetch labels request.
-    pub fn has_pending_fetch_labels(&self) -> bool {
-        self.pending_fetch_labels.is_some()
-    }
-
-    /// Set the available labels in the detail view.
-    pub fn set_labels(&mut self, labels: Vec<String>) {
-        self.detail_view.set_labels(labels);
-    }
-
-    /// Handle failure to fetch labels.
-    pub fn handle_fetch_labels_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch labels");
-        self.detail_view.hide_label_editor();
-        self.notify_error(format!("Failed to load labels: {}", error));
-    }
-
-    /// Take the pending add label request.
-    pub fn take_pending_add_label(&mut self) -> Option<(String, String)> {
-        self.pending_add_label.take()
-    }
-
-    /// Check if there is a pending add label request.
-    pub fn has_pending_add_label(&self) -> bool {
-        self.pending_add_label.is_some()
-    }
-
-    /// Take the pending remove label request.
-    pub fn take_pending_remove_label(&mut self) -> Option<(String, String)> {
-        self.pending_remove_label.take()
+    /// Get the pending issue update, if any.
+    pub fn take_pending_issue_update(&mut self) -> Option<(String, IssueUpdateRequest)> {
+        self.pending_issue_update.take()
     }
 
-    /// Check if there is a pending remove label request.
-    pub fn has_pending_remove_label(&self) -> bool {
-        self.pending_remove_label.is_some()
+    /// Check if there is a pending issue update.
+    pub fn has_pending_issue_update(&self) -> bool {
+        self.pending_issue_update.is_some()
     }
 
-    /// Handle successful label change.
-    pub fn handle_label_change_success(&mut self, updated_issue: Issue) {
-        info!(key = %updated_issue.key, "Label changed successfully");
+    /// Handle successful issue update.
+    ///
+    /// Updates the local issue data and exits edit mode.
+    pub fn handle_issue_update_success(&mut self, updated_issue: Issue) {
+        info!(key = %updated_issue.key, "Issue updated successfully");
 
-        // Add any new labels to filter options
-        for label in &updated_issue.fields.labels {
-            self.add_label_to_filter_options(label);
-        }
+        // Clear saving state
+        self.detail_view.set_saving(false);
 
         // Update the detail view with the updated issue
         self.detail_view.set_issue(updated_issue.clone());
@@ -2431,69 +3709,120 @@ impl App {
         // Update the issue in the list view if present
         self.list_view.update_issue(&updated_issue);
 
-        self.notify_success(format!("Issue {} labels updated", updated_issue.key));
+        // Show success notification
+        self.notify_success(format!("Issue {} updated", updated_issue.key));
     }
 
-    /// Handle failed label change.
-    pub fn handle_label_change_failure(&mut self, error: &str) {
-        warn!(error = %error, "Label change failed");
-        self.notify_error(format!("Failed to update labels: {}", error));
+    /// Handle failed issue update.
+    pub fn handle_issue_update_failure(&mut self, error: &str) {
+        warn!(error = %error, "Issue update failed");
+        self.detail_view.set_saving(false);
+        self.notify_error(format!("Failed to update issue: {}", error));
     }
 
-    // ========================================================================
-    // Components methods
-    // ========================================================================
+    /// Show the discard changes confirmation dialog.
+    fn show_discard_confirm_dialog(&mut self) {
+        self.discard_confirm_dialog.show_destructive_with_label(
+            "Discard Changes?",
+            "You have unsaved changes. Are you sure you want to discard them?",
+            "Discard",
+        );
+    }
 
-    /// Take the pending fetch components request.
-    pub fn take_pending_fetch_components(&mut self) -> Option<(String, String)> {
-        self.pending_fetch_components.take()
+    /// Check if the discard confirm dialog is visible.
+    pub fn is_discard_confirm_visible(&self) -> bool {
+        self.discard_confirm_dialog.is_visible()
     }
 
-    /// Check if there is a pending fetch components request.
-    pub fn has_pending_fetch_components(&self) -> bool {
-        self.pending_fetch_components.is_some()
+    /// Check if the transition confirm dialog is visible.
+    pub fn is_transition_confirm_visible(&self) -> bool {
+        self.transition_confirm_dialog.is_visible()
     }
 
-    /// Set the available components in the detail view.
-    pub fn set_components(&mut self, components: Vec<String>) {
-        self.detail_view.set_components(components);
+    /// Show the transition confirmation dialog.
+    ///
+    /// If confirm_transitions setting is true, shows a confirmation dialog.
+    /// Otherwise, executes the transition immediately.
+    fn request_transition_with_confirmation(
+        &mut self,
+        issue_key: String,
+        transition_id: String,
+        transition_name: String,
+        fields: Option<FieldUpdates>,
+    ) {
+        if self.settings.confirm_transitions {
+            // Store the pending transition for confirmation
+            self.pending_transition_confirm = Some((
+                issue_key.clone(),
+                transition_id,
+                transition_name.clone(),
+                fields,
+            ));
+            // Show the confirmation dialog
+            self.transition_confirm_dialog.show_with_labels(
+                "Change Status?",
+                format!("Move issue {} to '{}'?", issue_key, transition_name),
+                "Confirm",
+                "Cancel",
+            );
+        } else {
+            // Execute immediately without confirmation
+            self.pending_transition = Some((issue_key, transition_id, fields));
+        }
     }
 
-    /// Handle failure to fetch components.
-    pub fn handle_fetch_components_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch components");
-        self.detail_view.hide_component_editor();
-        self.notify_error(format!("Failed to load components: {}", error));
+    /// Confirm the pending transition and execute it.
+    fn confirm_transition(&mut self) {
+        if let Some((issue_key, transition_id, _name, fields)) =
+            self.pending_transition_confirm.take()
+        {
+            self.pending_transition = Some((issue_key, transition_id, fields));
+        }
     }
 
-    /// Take the pending add component request.
-    pub fn take_pending_add_component(&mut self) -> Option<(String, String)> {
-        self.pending_add_component.take()
+    /// Cancel the pending transition confirmation.
+    fn cancel_transition_confirm(&mut self) {
+        self.pending_transition_confirm = None;
     }
 
-    /// Check if there is a pending add component request.
-    pub fn has_pending_add_component(&self) -> bool {
-        self.pending_add_component.is_some()
+    // ========================================================================
+    // Transition methods
+    // ========================================================================
+
+    /// Take the pending transition request, if any.
+    ///
+    /// Returns (issue_key, transition_id, optional fields).
+    pub fn take_pending_transition(&mut self) -> Option<(String, String, Option<FieldUpdates>)> {
+        self.pending_transition.take()
     }
 
-    /// Take the pending remove component request.
-    pub fn take_pending_remove_component(&mut self) -> Option<(String, String)> {
-        self.pending_remove_component.take()
+    /// Check if there is a pending transition.
+    pub fn has_pending_transition(&self) -> bool {
+        self.pending_transition.is_some()
     }
 
-    /// Check if there is a pending remove component request.
-    pub fn has_pending_remove_component(&self) -> bool {
-        self.pending_remove_component.is_some()
+    /// Take the pending fetch transitions request, if any.
+    ///
+    /// Returns the issue key.
+    pub fn take_pending_fetch_transitions(&mut self) -> Option<String> {
+        self.pending_fetch_transitions.take()
     }
 
-    /// Handle successful component change.
-    pub fn handle_component_change_success(&mut self, updated_issue: Issue) {
-        info!(key = %updated_issue.key, "Component changed successfully");
+    /// Check if there is a pending fetch transitions request.
+    pub fn has_pending_fetch_transitions(&self) -> bool {
+        self.pending_fetch_transitions.is_some()
+    }
 
-        // Add any new components to filter options
-        for component in &updated_issue.fields.components {
-            self.add_component_to_filter_options(&component.name);
-        }
+    /// Set the available transitions in the detail view.
+    pub fn set_transitions(&mut self, transitions: Vec<Transition>) {
+        self.detail_view.set_transitions(transitions);
+    }
+
+    /// Handle successful transition completion.
+    ///
+    /// Updates the local issue data with the refreshed issue.
+    pub fn handle_transition_success(&mut self, updated_issue: Issue) {
+        info!(key = %updated_issue.key, "Issue transitioned successfully");
 
         // Update the detail view with the updated issue
         self.detail_view.set_issue(updated_issue.clone());
@@ -2501,632 +3830,1564 @@ impl App {
         // Update the issue in the list view if present
         self.list_view.update_issue(&updated_issue);
 
-        self.notify_success(format!("Issue {} components updated", updated_issue.key));
+        // Show success notification
+        self.notify_success(format!(
+            "Issue {} status changed to {}",
+            updated_issue.key, updated_issue.fields.status.name
+        ));
     }
 
-    /// Handle failed component change.
-    pub fn handle_component_change_failure(&mut self, error: &str) {
-        warn!(error = %error, "Component change failed");
-        self.notify_error(format!("Failed to update components: {}", error));
+    /// Handle failed transition.
+    pub fn handle_transition_failure(&mut self, error: &str) {
+        warn!(error = %error, "Transition failed");
+        self.detail_view.hide_transition_picker();
+        self.notify_error(format!("Failed to change status: {}", error));
     }
 
-    // ========================================================================
-    // Changelog methods
-    // ========================================================================
-
-    /// Take the pending fetch changelog request (issue_key, start_at).
-    pub fn take_pending_fetch_changelog(&mut self) -> Option<(String, u32)> {
-        self.pending_fetch_changelog.take()
+    /// Handle failure to fetch transitions.
+    pub fn handle_fetch_transitions_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch transitions");
+        self.detail_view.hide_transition_picker();
+        self.notify_error(format!("Failed to load transitions: {}", error));
     }
 
-    /// Check if there is a pending fetch changelog request.
-    pub fn has_pending_fetch_changelog(&self) -> bool {
-        self.pending_fetch_changelog.is_some()
-    }
+    // ========================================================================
+    // Assignee Picker Methods
+    // ========================================================================
 
-    /// Handle successful changelog fetch.
-    pub fn handle_changelog_fetched(&mut self, changelog: Changelog, append: bool) {
-        debug!(
-            "Changelog fetched: {} entries (total: {})",
-            changelog.histories.len(),
-            changelog.total
-        );
-        if append {
-            self.detail_view.append_changelog(changelog);
+    /// Take the pending fetch assignees request, if any.
+    ///
+    /// Returns the (issue_key, project_key).
+    /// Also sets the `assignee_fetch_for_create_issue` flag based on the issue_key.
+    pub fn take_pending_fetch_assignees(&mut self) -> Option<(String, String)> {
+        if let Some((issue_key, project_key)) = self.pending_fetch_assignees.take() {
+            // Check if this fetch is for create issue context
+            self.assignee_fetch_for_create_issue = issue_key == "__create_issue__";
+            Some((issue_key, project_key))
         } else {
-            self.detail_view.set_changelog(changelog);
+            None
         }
     }
 
-    /// Handle failure to fetch changelog.
-    pub fn handle_fetch_changelog_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch changelog");
-        self.detail_view.hide_history();
-        self.notify_error(format!("Failed to load history: {}", error));
+    /// Check if there is a pending fetch assignees request.
+    pub fn has_pending_fetch_assignees(&self) -> bool {
+        self.pending_fetch_assignees.is_some()
     }
 
-    // ========================================================================
-    // Linked issue navigation methods
-    // ========================================================================
-
-    /// Take the pending navigate to issue request.
-    pub fn take_pending_navigate_to_issue(&mut self) -> Option<String> {
-        self.pending_navigate_to_issue.take()
+    /// Check if the current/last assignee fetch was for create issue context.
+    pub fn is_assignee_fetch_for_create_issue(&self) -> bool {
+        self.assignee_fetch_for_create_issue
     }
 
-    /// Handle successful linked issue navigation.
-    pub fn handle_navigate_to_issue_success(&mut self, issue: Issue) {
-        info!(key = %issue.key, "Navigated to linked issue");
-        self.selected_issue_key = Some(issue.key.clone());
-        self.set_detail_issue(issue);
-        self.stop_loading();
+    /// Set the available assignable users in the detail view.
+    pub fn set_assignable_users(&mut self, users: Vec<User>) {
+        self.detail_view.set_assignable_users(users);
     }
 
-    /// Handle failure to navigate to linked issue.
-    pub fn handle_navigate_to_issue_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to navigate to linked issue");
-        self.stop_loading();
-        self.notify_error(format!("Failed to load issue: {}", error));
+    /// Set the available assignable users in the create issue view.
+    pub fn set_create_issue_assignable_users(&mut self, users: Vec<User>) {
+        let current_assignee = self
+            .create_issue_form
+            .assignee_name
+            .clone()
+            .unwrap_or_else(|| "Unassigned".to_string());
+        self.create_issue_view
+            .set_assignable_users(users, &current_assignee);
     }
 
-    /// Take the pending fetch link types request.
-    pub fn take_pending_fetch_link_types(&mut self) -> Option<String> {
-        self.pending_fetch_link_types.take()
+    /// Hide the create issue assignee picker (e.g., on fetch failure).
+    pub fn hide_create_issue_assignee_picker(&mut self) {
+        self.create_issue_view.hide_assignee_picker();
     }
 
-    /// Handle successful link types fetch.
-    pub fn handle_link_types_success(&mut self, link_types: Vec<crate::api::types::IssueLinkType>) {
-        info!(count = %link_types.len(), "Fetched link types");
-        self.detail_view.set_link_types(link_types);
+    /// Take the pending assignee change request, if any.
+    ///
+    /// Returns the (issue_key, account_id or None for unassign).
+    pub fn take_pending_assignee_change(&mut self) -> Option<(String, Option<String>)> {
+        self.pending_assignee_change.take()
     }
 
-    /// Handle failure to fetch link types.
-    pub fn handle_link_types_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch link types");
-        self.notify_error(format!("Failed to load link types: {}", error));
+    /// Check if there is a pending assignee change request.
+    pub fn has_pending_assignee_change(&self) -> bool {
+        self.pending_assignee_change.is_some()
     }
 
-    /// Take the pending fetch recent issues for link request.
-    pub fn take_pending_fetch_recent_issues_for_link(&mut self) -> Option<String> {
-        self.pending_fetch_recent_issues_for_link.take()
-    }
+    /// Handle successful assignee change.
+    ///
+    /// Updates the local issue data with the refreshed issue.
+    pub fn handle_assignee_change_success(&mut self, updated_issue: Issue) {
+        info!(key = %updated_issue.key, "Assignee changed successfully");
 
-    /// Take the pending search issues for link request.
-    pub fn take_pending_search_issues_for_link(&mut self) -> Option<(String, String)> {
-        self.pending_search_issues_for_link.take()
+        // Update the detail view with the updated issue
+        self.detail_view.set_issue(updated_issue.clone());
+
+        // Update the issue in the list view if present
+        self.list_view.update_issue(&updated_issue);
+
+        // Show success notification
+        let assignee_name = updated_issue.assignee_name();
+        self.notify_success(format!(
+            "Issue {} assignee changed to {}",
+            updated_issue.key, assignee_name
+        ));
     }
 
-    /// Handle successful issue search for linking.
-    pub fn handle_issue_search_success(
-        &mut self,
-        suggestions: Vec<crate::api::types::IssueSuggestion>,
-    ) {
-        info!(count = %suggestions.len(), "Fetched issue suggestions");
-        self.detail_view.set_issue_search_suggestions(suggestions);
+    /// Handle failed assignee change.
+    pub fn handle_assignee_change_failure(&mut self, error: &str) {
+        warn!(error = %error, "Assignee change failed");
+        self.detail_view.hide_assignee_picker();
+        self.notify_error(format!("Failed to change assignee: {}", error));
     }
 
-    /// Handle failure to search issues.
-    pub fn handle_issue_search_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to search issues");
-        self.notify_error(format!("Failed to search issues: {}", error));
+    /// Handle failure to fetch assignable users.
+    pub fn handle_fetch_assignees_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch assignable users");
+        self.detail_view.hide_assignee_picker();
+        self.notify_error(format!("Failed to load assignees: {}", error));
     }
 
-    /// Take the pending create link request.
-    pub fn take_pending_create_link(&mut self) -> Option<(String, String, String, bool)> {
-        self.pending_create_link.take()
+    // ========================================================================
+    // Watcher and Vote Methods
+    // ========================================================================
+
+    /// Take the pending fetch watchers request, if any.
+    ///
+    /// Returns the issue key.
+    pub fn take_pending_fetch_watchers(&mut self) -> Option<String> {
+        self.pending_fetch_watchers.take()
     }
 
-    /// Handle successful link creation.
-    pub fn handle_create_link_success(&mut self, issue_key: &str) {
-        info!(key = %issue_key, "Link created successfully");
-        self.stop_loading();
-        self.notify_success("Link created successfully");
-        // Set up to refresh the issue details
-        self.pending_navigate_to_issue = Some(issue_key.to_string());
+    /// Take the pending fetch watcher candidates request, if any.
+    ///
+    /// Returns the (issue_key, project_key).
+    pub fn take_pending_fetch_watcher_candidates(&mut self) -> Option<(String, String)> {
+        self.pending_fetch_watcher_candidates.take()
     }
 
-    /// Handle failure to create link.
-    pub fn handle_create_link_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to create link");
-        self.stop_loading();
-        self.notify_error(format!("Failed to create link: {}", error));
+    /// Take the pending watch or vote change request, if any.
+    pub fn take_pending_watch_change(&mut self) -> Option<(String, WatchChange)> {
+        self.pending_watch_change.take()
     }
 
-    /// Take the pending confirm delete link request.
-    pub fn take_pending_confirm_delete_link(&mut self) -> Option<(String, String)> {
-        self.pending_confirm_delete_link.take()
+    /// Set the watchers shown in the detail view's watcher manager.
+    pub fn set_watchers(&mut self, watchers: Watchers) {
+        self.detail_view.set_watchers(watchers);
     }
 
-    /// Show the delete link confirmation dialog.
-    pub fn show_delete_link_confirmation(&mut self, link_id: String, description: String) {
-        self.delete_link_confirm_dialog
-            .show_destructive("Delete Link", format!("Delete link to {}?", description));
-        // Store the link info for when confirmed (not in pending_delete_link yet)
-        self.pending_confirm_delete_link =
-            Some((link_id, self.selected_issue_key.clone().unwrap_or_default()));
+    /// Handle failure to fetch watchers.
+    pub fn handle_fetch_watchers_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch watchers");
+        self.detail_view.hide_watcher_manager();
+        self.notify_error(format!("Failed to load watchers: {}", error));
     }
 
-    /// Check if the delete link confirm dialog is visible.
-    pub fn is_delete_link_confirm_visible(&self) -> bool {
-        self.delete_link_confirm_dialog.is_visible()
+    /// Set the users offered when adding a watcher.
+    pub fn set_watcher_candidates(&mut self, users: Vec<User>) {
+        self.detail_view.set_watcher_candidates(users);
     }
 
-    /// Take the pending delete link request.
-    pub fn take_pending_delete_link(&mut self) -> Option<(String, String)> {
-        self.pending_delete_link.take()
+    /// Handle failure to fetch the users that can be added as watchers.
+    pub fn handle_fetch_watcher_candidates_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch watcher candidates");
+        self.detail_view.hide_watcher_picker();
+        self.notify_error(format!("Failed to load users: {}", error));
     }
 
-    /// Handle successful link deletion.
-    pub fn handle_delete_link_success(&mut self, issue_key: &str) {
-        info!(key = %issue_key, "Link deleted successfully");
-        self.stop_loading();
-        self.notify_success("Link deleted successfully");
-        // Set up to refresh the issue details
-        self.pending_navigate_to_issue = Some(issue_key.to_string());
+    /// Handle a successful watch or vote change.
+    ///
+    /// Updates the local issue data and, if open, the watcher manager.
+    pub fn handle_watch_change_success(
+        &mut self,
+        change: &WatchChange,
+        updated_issue: Issue,
+        watchers: Watchers,
+    ) {
+        info!(key = %updated_issue.key, ?change, "Watchers or votes changed");
+
+        let manager_open = self.detail_view.is_watcher_manager_visible();
+        self.detail_view.set_issue(updated_issue.clone());
+        if manager_open {
+            self.detail_view.set_watchers(watchers);
+        }
+        self.list_view.update_issue(&updated_issue);
+
+        let key = &updated_issue.key;
+        self.notify_success(match change {
+            WatchChange::Watch => format!("Watching {}", key),
+            WatchChange::Unwatch => format!("Stopped watching {}", key),
+            WatchChange::AddWatcher(_, name) => format!("Added {} as a watcher of {}", name, key),
+            WatchChange::RemoveWatcher(_, name) => {
+                format!("Removed {} from the watchers of {}", name, key)
+            }
+            WatchChange::Vote => format!("Voted for {}", key),
+            WatchChange::Unvote => format!("Removed vote from {}", key),
+        });
     }
 
-    /// Handle failure to delete link.
-    pub fn handle_delete_link_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to delete link");
-        self.stop_loading();
-        self.notify_error(format!("Failed to delete link: {}", error));
+    /// Handle a failed watch or vote change.
+    pub fn handle_watch_change_failure(&mut self, change: &WatchChange, error: &str) {
+        warn!(error = %error, ?change, "Watch change failed");
+        let what = if change.is_vote() { "vote" } else { "watchers" };
+        self.notify_error(format!("Failed to update {}: {}", what, error));
     }
 
     // ========================================================================
-    // Delete Issue methods
+    // Priority Picker Methods
     // ========================================================================
 
-    /// Show the delete issue confirmation dialog.
-    pub fn show_delete_issue_confirmation(&mut self, issue_key: String) {
-        self.delete_issue_confirm_dialog.show_destructive(
-            "Delete Issue",
-            format!("Are you sure you want to delete {}? This cannot be undone.", issue_key),
-        );
-        self.pending_confirm_delete_issue = Some(issue_key);
+    /// Take the pending fetch priorities request, if any.
+    ///
+    /// Returns the issue_key (or "__create_issue__" for create form context).
+    /// Also sets the `priority_fetch_for_create_issue` flag based on the key.
+    pub fn take_pending_fetch_priorities(&mut self) -> Option<String> {
+        if let Some(key) = self.pending_fetch_priorities.take() {
+            // Check if this fetch is for create issue context
+            self.priority_fetch_for_create_issue = key == "__create_issue__";
+            Some(key)
+        } else {
+            None
+        }
     }
 
-    /// Check if the delete issue confirm dialog is visible.
-    pub fn is_delete_issue_confirm_visible(&self) -> bool {
-        self.delete_issue_confirm_dialog.is_visible()
+    /// Check if there is a pending fetch priorities request.
+    pub fn has_pending_fetch_priorities(&self) -> bool {
+        self.pending_fetch_priorities.is_some()
     }
 
-    /// Take the pending delete issue request.
-    pub fn take_pending_delete_issue(&mut self) -> Option<String> {
-        self.pending_delete_issue.take()
+    /// Check if the current/last priority fetch was for create issue context.
+    pub fn is_priority_fetch_for_create_issue(&self) -> bool {
+        self.priority_fetch_for_create_issue
     }
 
-    /// Handle successful issue deletion.
-    pub fn handle_delete_issue_success(&mut self, issue_key: &str) {
-        info!(key = %issue_key, "Issue deleted successfully");
-        self.stop_loading();
-        self.notify_success(format!("Issue {} deleted successfully", issue_key));
-        // Go back to the issue list and refresh
-        self.detail_view.clear();
-        self.selected_issue_key = None;
-        self.state = AppState::IssueList;
-        self.list_view.set_loading(true);
+    /// Set the available priorities in the detail view.
+    pub fn set_priorities(&mut self, priorities: Vec<Priority>) {
+        self.detail_view.set_priorities(priorities);
     }
 
-    /// Handle failure to delete issue.
-    pub fn handle_delete_issue_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to delete issue");
-        self.stop_loading();
-        self.notify_error(format!("Failed to delete issue: {}", error));
+    /// Set the available priorities in the create issue view.
+    pub fn set_create_issue_priorities(&mut self, priorities: Vec<Priority>) {
+        let current_priority = self
+            .create_issue_form
+            .priority_name
+            .clone()
+            .unwrap_or_else(|| "Default".to_string());
+        self.create_issue_view
+            .set_priorities(priorities, &current_priority);
     }
 
-    // ========================================================================
-    // Create Issue methods
-    // ========================================================================
-
-    /// Handle successful issue creation.
-    pub fn handle_create_issue_success(
-        &mut self,
-        response: crate::api::types::CreateIssueResponse,
-    ) {
-        info!(key = %response.key, "Issue created successfully");
-        self.stop_loading();
-        self.notify_success(format!("Issue {} created successfully", response.key));
-        // Close the form and refresh the issue list
-        self.close_create_issue_form(true);
+    /// Hide the priority picker in the create issue view.
+    pub fn hide_create_issue_priority_picker(&mut self) {
+        self.create_issue_view.hide_priority_picker();
     }
 
-    /// Handle failure to create issue.
-    pub fn handle_create_issue_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to create issue");
-        self.stop_loading();
-        self.create_issue_view.set_submitting(false);
-        self.notify_error(format!("Failed to create issue: {}", error));
+    /// Take the pending priority change request, if any.
+    ///
+    /// Returns the (issue_key, priority_id).
+    pub fn take_pending_priority_change(&mut self) -> Option<(String, String)> {
+        self.pending_priority_change.take()
     }
 
-    /// Handle successful issue types fetch.
-    pub fn handle_issue_types_fetched(
-        &mut self,
-        issue_types: Vec<crate::api::types::IssueTypeMeta>,
-    ) {
-        debug!(count = issue_types.len(), "Loaded issue types");
-        self.stop_loading();
-        self.available_issue_types = issue_types;
+    /// Check if there is a pending priority change request.
+    pub fn has_pending_priority_change(&self) -> bool {
+        self.pending_priority_change.is_some()
     }
 
-    /// Handle failure to fetch issue types.
-    pub fn handle_fetch_issue_types_failure(&mut self, error: &str) {
-        warn!(error = %error, "Failed to fetch issue types");
-        self.stop_loading();
-        self.notify_error(format!("Failed to load issue types: {}", error));
+    /// Handle successful priority change.
+    ///
+    /// Updates the local issue data with the refreshed issue.
+    pub fn handle_priority_change_success(&mut self, updated_issue: Issue) {
+        info!(key = %updated_issue.key, "Priority changed successfully");
+
+        // Update the detail view with the updated issue
+        self.detail_view.set_issue(updated_issue.clone());
+
+        // Update the issue in the list view if present
+        self.list_view.update_issue(&updated_issue);
+
+        // Show success notification
+        let priority_name = updated_issue.priority_name();
+        self.notify_success(format!(
+            "Issue {} priority changed to {}",
+            updated_issue.key, priority_name
+        ));
     }
 
-    // ========================================================================
-    // External editor methods
-    // ========================================================================
+    /// Handle failed priority change.
+    pub fn handle_priority_change_failure(&mut self, error: &str) {
+        warn!(error = %error, "Priority change failed");
+        self.detail_view.hide_priority_picker();
+        self.notify_error(format!("Failed to change priority: {}", error));
+    }
 
-    /// Take the pending external edit request.
-    ///
-    /// Returns (issue_key, current_description) if there's a pending external edit.
-    pub fn take_pending_external_edit(&mut self) -> Option<(String, String)> {
-        self.pending_external_edit.take()
+    /// Handle failure to fetch priorities.
+    pub fn handle_fetch_priorities_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch priorities");
+        self.detail_view.hide_priority_picker();
+        self.notify_error(format!("Failed to load priorities: {}", error));
     }
 
     // ========================================================================
-    // Pagination methods
+    // Comments methods
     // ========================================================================
 
-    /// Check if there's a pending load more request and clear it.
-    ///
-    /// Returns true if pagination should load more issues.
-    pub fn take_pending_load_more(&mut self) -> bool {
-        std::mem::take(&mut self.pending_load_more)
+    /// Take the pending fetch comments request.
+    pub fn take_pending_fetch_comments(&mut self) -> Option<String> {
+        self.pending_fetch_comments.take()
     }
 
-    /// Handle successful load more response.
-    ///
-    /// Appends the new issues to the list and updates pagination state.
-    pub fn handle_load_more_success(
+    /// Check if there is a pending fetch comments request.
+    pub fn has_pending_fetch_comments(&self) -> bool {
+        self.pending_fetch_comments.is_some()
+    }
+
+    /// Handle successful comments fetch.
+    pub fn handle_comments_fetched(
         &mut self,
-        issues: Vec<Issue>,
+        comments: Vec<crate::api::types::Comment>,
         total: u32,
-        has_more: bool,
-        next_page_token: Option<String>,
     ) {
-        let count = issues.len() as u32;
-        let current_offset = self.list_view.pagination().current_offset;
-        info!(
-            count = count,
-            current_offset = current_offset,
-            total = total,
-            has_more = has_more,
-            has_token = next_page_token.is_some(),
-            "Appending issues from load more"
-        );
-        // Clear any previous pagination error on success
-        self.list_view.pagination_mut().clear_error();
-        self.list_view.append_issues(issues);
-        self.list_view.pagination_mut().update_from_response(
-            current_offset,
-            count,
-            total,
-            has_more,
-            next_page_token,
-        );
+        debug!("Comments fetched: {} of {}", comments.len(), total);
+        self.detail_view.set_comments(comments, total);
     }
 
-    /// Handle failed load more request.
-    pub fn handle_load_more_failure(&mut self, error: &str) {
-        warn!(error = %error, "Load more failed");
-        // Set error on pagination state (also stops loading)
-        self.list_view.pagination_mut().set_error(error);
-        // Also show a notification for visibility
-        self.notify_error(format!("Failed to load more issues: {}", error));
+    /// Handle failure to fetch comments.
+    pub fn handle_fetch_comments_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch comments");
+        self.detail_view.hide_comments_panel();
+        self.notify_error(format!("Failed to load comments: {}", error));
     }
 
-    /// Apply the result from an external editor session to the detail view.
-    ///
-    /// If the content was modified, this enters edit mode with the new content
-    /// ready for the user to review and save. If the content wasn't modified,
-    /// nothing happens.
-    pub fn apply_external_edit_result(&mut self, content: String) {
-        info!("Applying external edit result to detail view");
-        self.detail_view.set_external_edit_content(content);
+    /// Take the pending submit comment request.
+    pub fn take_pending_submit_comment(&mut self) -> Option<(String, String)> {
+        self.pending_submit_comment.take()
     }
 
-    /// Returns whether the application should quit.
-    pub fn should_quit(&self) -> bool {
-        self.should_quit
+    /// Check if there is a pending submit comment request.
+    pub fn has_pending_submit_comment(&self) -> bool {
+        self.pending_submit_comment.is_some()
     }
 
-    /// Returns the current application state.
-    pub fn state(&self) -> AppState {
-        self.state
+    /// Handle successful comment submission.
+    pub fn handle_comment_submitted(&mut self, comment: crate::api::types::Comment) {
+        info!(comment_id = %comment.id, "Comment submitted successfully");
+        self.detail_view.add_comment(comment);
+        self.notify_success("Comment added successfully");
     }
 
-    /// Update the application state based on an event.
-    ///
-    /// This implements the Update part of The Elm Architecture (TEA).
-    /// All state changes flow through this method for predictable behavior.
-    pub fn update(&mut self, event: Event) {
-        match event {
-            Event::Quit => {
-                info!("Quit event received");
-                self.should_quit = true;
-                self.state = AppState::Exiting;
-            }
-            Event::Key(key_event) => {
-                trace!(key = ?key_event.code, modifiers = ?key_event.modifiers, "Key event");
-                self.handle_key_event(key_event);
-            }
-            Event::Resize(width, height) => {
-                trace!(width, height, "Terminal resize event");
-                // Terminal resize is handled automatically by ratatui
-            }
-            Event::Tick => {
-                // Handle periodic tick for animations or background tasks
-                self.handle_tick();
-            }
-        }
+    /// Handle failure to submit comment.
+    pub fn handle_submit_comment_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to submit comment");
+        self.detail_view.set_comment_submitting(false);
+        self.notify_error(format!("Failed to add comment: {}", error));
     }
 
-    /// Handle keyboard input events.
-    fn handle_key_event(&mut self, key_event: crossterm::event::KeyEvent) {
-        use crossterm::event::{KeyCode, KeyModifiers};
+    // ========================================================================
+    // Labels methods
+    // ========================================================================
 
-        // Handle error dialog first (blocks all other input)
-        if self.error_dialog.is_visible() {
-            match key_event.code {
-                KeyCode::Esc | KeyCode::Enter => {
-                    self.error_dialog.dismiss();
-                }
-                _ => {}
-            }
-            return;
-        }
+    /// Take the pending fetch labels request.
+    pub fn take_pending_fetch_labels(&mut self) -> Option<String> {
+        self.pending_fetch_labels.take()
+    }
 
-        // Handle delete profile dialog (blocks other input)
-        if self.delete_profile_dialog.is_visible() {
-            if let Some(confirmed) = self.delete_profile_dialog.handle_input(key_event) {
-                if confirmed {
-                    if let Some(name) = self.profile_list_view.selected_profile_name() {
-                        let index = self.profile_list_view.selected();
-                        debug!(name = %name, "Delete profile confirmed");
-                        if let Err(e) = self.delete_profile(index) {
-                            self.notify_error(format!("Failed to delete profile: {}", e));
-                        }
-                    }
-                } else {
-                    debug!("Delete profile cancelled");
-                }
-            }
-            return;
-        }
+    /// Check if there is a pending fetch labels request.
+    pub fn has_pending_fetch_labels(&self) -> bool {
+        self.pending_fetch_labels.is_some()
+    }
 
-        // Handle discard changes confirmation dialog (blocks other input)
-        if self.discard_confirm_dialog.is_visible() {
-            if let Some(confirmed) = self.discard_confirm_dialog.handle_input(key_event) {
-                if confirmed {
-                    debug!("Discard changes confirmed");
-                    self.detail_view.exit_edit_mode();
-                } else {
-                    debug!("Discard changes cancelled");
-                }
-            }
-            return;
-        }
+    /// Set the available labels in the detail view.
+    pub fn set_labels(&mut self, labels: Vec<String>) {
+        self.detail_view.set_labels(labels);
+    }
 
-        // Handle transition confirmation dialog (blocks other input)
-        if self.transition_confirm_dialog.is_visible() {
-            if let Some(confirmed) = self.transition_confirm_dialog.handle_input(key_event) {
-                if confirmed {
-                    debug!("Transition confirmed");
-                    self.confirm_transition();
-                } else {
-                    debug!("Transition cancelled");
-                    self.cancel_transition_confirm();
-                }
-            }
-            return;
-        }
+    /// Handle failure to fetch labels.
+    pub fn handle_fetch_labels_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch labels");
+        self.detail_view.hide_label_editor();
+        self.notify_error(format!("Failed to load labels: {}", error));
+    }
 
-        // Handle delete link confirmation dialog (blocks other input)
-        if self.delete_link_confirm_dialog.is_visible() {
-            if let Some(confirmed) = self.delete_link_confirm_dialog.handle_input(key_event) {
-                if confirmed {
-                    debug!("Delete link confirmed");
-                    // Move from pending_confirm to pending_delete to trigger the actual deletion
-                    if let Some((link_id, issue_key)) = self.pending_confirm_delete_link.take() {
-                        self.pending_delete_link = Some((link_id, issue_key));
-                        self.start_loading("Deleting link...".to_string());
-                    }
-                } else {
-                    debug!("Delete link cancelled");
-                    self.pending_confirm_delete_link = None;
-                }
-            }
-            return;
-        }
+    /// Take the pending add label request.
+    pub fn take_pending_add_label(&mut self) -> Option<(String, String)> {
+        self.pending_add_label.take()
+    }
 
-        // Handle delete issue confirmation dialog (blocks other input)
-        if self.delete_issue_confirm_dialog.is_visible() {
-            if let Some(confirmed) = self.delete_issue_confirm_dialog.handle_input(key_event) {
-                if confirmed {
-                    debug!("Delete issue confirmed");
-                    // Move from pending_confirm to pending_delete to trigger the actual deletion
-                    if let Some(issue_key) = self.pending_confirm_delete_issue.take() {
-                        self.pending_delete_issue = Some(issue_key);
-                        self.start_loading("Deleting issue...".to_string());
-                    }
-                } else {
-                    debug!("Delete issue cancelled");
-                    self.pending_confirm_delete_issue = None;
-                }
-            }
-            return;
-        }
+    /// Check if there is a pending add label request.
+    pub fn has_pending_add_label(&self) -> bool {
+        self.pending_add_label.is_some()
+    }
 
-        // Handle profile form (blocks other input when visible)
-        if self.profile_form_view.is_visible() {
-            if let Some(action) = self.profile_form_view.handle_input(key_event) {
-                match action {
-                    ProfileFormAction::Cancel => {
-                        debug!("Profile form cancelled");
-                    }
-                    ProfileFormAction::Submit(data) => {
-                        debug!("Profile form submitted");
-                        // Handle add/edit
-                        let result = if data.original_name.is_some() {
-                            self.update_profile(data)
-                        } else {
-                            self.add_profile(data)
-                        };
+    /// Take the pending remove label request.
+    pub fn take_pending_remove_label(&mut self) -> Option<(String, String)> {
+        self.pending_remove_label.take()
+    }
 
-                        match result {
-                            Ok(()) => {
-                                self.profile_form_view.hide();
-                            }
-                            Err(e) => {
-                                self.profile_form_view
-                                    .set_error(FormField::Name, format!("Failed to save: {}", e));
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            return;
-        }
+    /// Check if there is a pending remove label request.
+    pub fn has_pending_remove_label(&self) -> bool {
+        self.pending_remove_label.is_some()
+    }
 
-        // Handle profile picker (blocks other input when visible)
-        if self.profile_picker.is_visible() {
-            if let Some(action) = self.profile_picker.handle_input(key_event) {
-                match action {
-                    ProfilePickerAction::Select(profile_name) => {
-                        debug!(profile = %profile_name, "Profile selected");
-                        if let Err(e) = self.switch_profile(&profile_name) {
-                            self.notify_error(format!("Failed to switch profile: {}", e));
-                        }
-                    }
-                    ProfilePickerAction::Cancel => {
-                        debug!("Profile selection cancelled");
-                    }
-                }
-            }
-            return;
+    /// Handle successful label change.
+    pub fn handle_label_change_success(&mut self, updated_issue: Issue) {
+        info!(key = %updated_issue.key, "Label changed successfully");
+
+        // Add any new labels to filter options
+        for label in &updated_issue.fields.labels {
+            self.add_label_to_filter_options(label);
         }
 
-        // Handle saved filters dialog (blocks other input when visible)
-        if self.saved_filters_dialog.is_visible() {
-            if let Some(action) = self.saved_filters_dialog.handle_input(key_event) {
-                match action {
-                    SavedFiltersAction::Select(filter) => {
-                        debug!("Saved filter selected");
-                        self.apply_filter(filter);
-                    }
-                    SavedFiltersAction::Save(name) => {
-                        debug!(name = %name, "Saving current filter");
-                        self.save_current_filter(name);
-                    }
-                    SavedFiltersAction::Delete(name) => {
-                        debug!(name = %name, "Deleting saved filter");
-                        self.delete_saved_filter(name);
-                    }
-                    SavedFiltersAction::Cancel => {
-                        debug!("Saved filters dialog cancelled");
-                    }
-                }
-            }
-            return;
+        // Update the detail view with the updated issue
+        self.detail_view.set_issue(updated_issue.clone());
+
+        // Update the issue in the list view if present
+        self.list_view.update_issue(&updated_issue);
+
+        self.notify_success(format!("Issue {} labels updated", updated_issue.key));
+    }
+
+    /// Handle failed label change.
+    pub fn handle_label_change_failure(&mut self, error: &str) {
+        warn!(error = %error, "Label change failed");
+        self.notify_error(format!("Failed to update labels: {}", error));
+    }
+
+    // ========================================================================
+    // Components methods
+    // ========================================================================
+
+    /// Take the pending fetch components request.
+    pub fn take_pending_fetch_components(&mut self) -> Option<(String, String)> {
+        self.pending_fetch_components.take()
+    }
+
+    /// Check if there is a pending fetch components request.
+    pub fn has_pending_fetch_components(&self) -> bool {
+        self.pending_fetch_components.is_some()
+    }
+
+    /// Set the available components in the detail view.
+    pub fn set_components(&mut self, components: Vec<String>) {
+        self.detail_view.set_components(components);
+    }
+
+    /// Handle failure to fetch components.
+    pub fn handle_fetch_components_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch components");
+        self.detail_view.hide_component_editor();
+        self.notify_error(format!("Failed to load components: {}", error));
+    }
+
+    /// Take the pending add component request.
+    pub fn take_pending_add_component(&mut self) -> Option<(String, String)> {
+        self.pending_add_component.take()
+    }
+
+    /// Check if there is a pending add component request.
+    pub fn has_pending_add_component(&self) -> bool {
+        self.pending_add_component.is_some()
+    }
+
+    /// Take the pending remove component request.
+    pub fn take_pending_remove_component(&mut self) -> Option<(String, String)> {
+        self.pending_remove_component.take()
+    }
+
+    /// Check if there is a pending remove component request.
+    pub fn has_pending_remove_component(&self) -> bool {
+        self.pending_remove_component.is_some()
+    }
+
+    /// Handle successful component change.
+    pub fn handle_component_change_success(&mut self, updated_issue: Issue) {
+        info!(key = %updated_issue.key, "Component changed successfully");
+
+        // Add any new components to filter options
+        for component in &updated_issue.fields.components {
+            self.add_component_to_filter_options(&component.name);
         }
 
-        // Handle JQL input (blocks other input when visible)
-        if self.jql_input.is_visible() {
-            if let Some(action) = self.jql_input.handle_input(key_event) {
-                match action {
-                    JqlAction::Execute(jql) => {
-                        debug!(jql = %jql, "JQL query submitted");
-                        self.execute_jql(jql);
-                    }
-                    JqlAction::Cancel => {
-                        debug!("JQL input cancelled");
-                        self.state = AppState::IssueList;
-                    }
-                }
-            }
+        // Update the detail view with the updated issue
+        self.detail_view.set_issue(updated_issue.clone());
+
+        // Update the issue in the list view if present
+        self.list_view.update_issue(&updated_issue);
+
+        self.notify_success(format!("Issue {} components updated", updated_issue.key));
+    }
+
+    /// Handle failed component change.
+    pub fn handle_component_change_failure(&mut self, error: &str) {
+        warn!(error = %error, "Component change failed");
+        self.notify_error(format!("Failed to update components: {}", error));
+    }
+
+    // ========================================================================
+    // Versions methods
+    // ========================================================================
+
+    /// Take the pending fetch versions request.
+    pub fn take_pending_fetch_versions(&mut self) -> Option<(String, String)> {
+        self.pending_fetch_versions.take()
+    }
+
+    /// Set the available versions in the detail view's version editor.
+    pub fn set_versions(&mut self, versions: Vec<String>) {
+        self.detail_view.set_versions(versions);
+    }
+
+    /// Handle failure to fetch versions for the version editor.
+    pub fn handle_fetch_versions_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch versions");
+        self.detail_view.hide_version_editor();
+        self.notify_error(format!("Failed to load versions: {}", error));
+    }
+
+    /// Take the pending version change request.
+    pub fn take_pending_version_change(
+        &mut self,
+    ) -> Option<(String, VersionField, VersionOperation)> {
+        self.pending_version_change.take()
+    }
+
+    /// Handle successful fix or affects version change.
+    pub fn handle_version_change_success(&mut self, updated_issue: Issue) {
+        info!(key = %updated_issue.key, "Versions changed successfully");
+        let field = self.detail_view.version_field();
+
+        // Update the detail view with the updated issue
+        self.detail_view.set_issue(updated_issue.clone());
+
+        // Update the issue in the list view if present
+        self.list_view.update_issue(&updated_issue);
+
+        self.notify_success(format!(
+            "Issue {} {} updated",
+            updated_issue.key,
+            field.label().to_lowercase()
+        ));
+    }
+
+    /// Handle failed fix or affects version change.
+    pub fn handle_version_change_failure(&mut self, error: &str) {
+        warn!(error = %error, "Version change failed");
+        self.notify_error(format!("Failed to update versions: {}", error));
+    }
+
+    // ========================================================================
+    // Release view methods
+    // ========================================================================
+
+    /// Open the release view for the project of the current issue.
+    ///
+    /// Uses the issue shown in the detail view, or the selected issue in the list.
+    pub fn open_releases(&mut self) {
+        let issue = if self.state == AppState::IssueDetail {
+            self.detail_view.issue()
+        } else {
+            self.list_view.selected_issue()
+        };
+        let Some(project) = issue.and_then(|i| i.fields.project.clone()) else {
+            self.notify_error("Select an issue to open the releases of its project");
             return;
-        }
+        };
 
-        // Handle command palette (blocks other input when visible)
-        if self.command_palette.is_visible() {
-            if let Some(action) = self.command_palette.handle_input(key_event) {
-                match action {
-                    CommandPaletteAction::Execute(cmd_action) => {
-                        debug!(?cmd_action, "Command palette action executed");
-                        self.execute_command_action(cmd_action);
-                    }
-                    CommandPaletteAction::Cancel => {
-                        debug!("Command palette cancelled");
-                    }
-                }
-            }
+        debug!(project = %project.key, "Opening release view");
+        self.pending_fetch_release_versions = Some(project.key.clone());
+        self.release_view.show(project);
+        self.state = AppState::Releases;
+    }
+
+    /// Get the release view.
+    pub fn release_view(&self) -> &ReleaseView {
+        &self.release_view
+    }
+
+    /// Take the pending fetch release versions request.
+    pub fn take_pending_fetch_release_versions(&mut self) -> Option<String> {
+        self.pending_fetch_release_versions.take()
+    }
+
+    /// Set the versions shown in the release view and load the issues of the
+    /// selected version.
+    pub fn set_release_versions(&mut self, project_key: &str, versions: Vec<Version>) {
+        if self.release_view.project_key() != Some(project_key) {
             return;
         }
+        self.release_view.set_versions(versions);
+        self.pending_fetch_version_issues = self.release_view.start_loading_issues();
+    }
 
-        // Global key bindings (always available)
-        match (key_event.code, key_event.modifiers) {
-            // Quit on Ctrl+C (always works)
-            (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
-                self.should_quit = true;
-                self.state = AppState::Exiting;
-                return;
-            }
-            // Help on '?' - available in all views except text editing modes
-            (KeyCode::Char('?'), KeyModifiers::NONE) => {
-                // Don't open help when in text editing mode or already in help
-                if self.state != AppState::Help && self.state != AppState::CreateIssue {
-                    self.previous_state = Some(self.state);
-                    let current_context = KeyContext::from_app_state(&self.state);
-                    self.help_view = HelpView::new(current_context);
-                    self.state = AppState::Help;
-                }
-                return;
-            }
-            // Profile switcher on 'p' (quick switch, available in most views)
-            (KeyCode::Char('p'), KeyModifiers::NONE)
-                if self.state == AppState::IssueList || self.state == AppState::Loading =>
-            {
-                debug!("Opening profile picker");
-                self.show_profile_picker();
-                return;
-            }
-            // Profile management on 'P' (Shift+p, full management view)
-            (KeyCode::Char('P'), KeyModifiers::SHIFT)
+    /// Handle failure to fetch versions for the release view.
+    pub fn handle_fetch_release_versions_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch release versions");
+        self.release_view.fail_loading();
+        self.notify_error(format!("Failed to load versions: {}", error));
+    }
+
+    /// Take the pending fetch version issues request.
+    pub fn take_pending_fetch_version_issues(&mut self) -> Option<String> {
+        self.pending_fetch_version_issues.take()
+    }
+
+    /// Set the issues of a version in the release view.
+    pub fn set_version_issues(&mut self, version_id: &str, issues: Vec<Issue>) {
+        self.release_view.set_issues(version_id, issues);
+    }
+
+    /// Handle failure to fetch the issues of a version.
+    pub fn handle_fetch_version_issues_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch version issues");
+        self.release_view.fail_loading();
+        self.notify_error(format!("Failed to load version issues: {}", error));
+    }
+
+    /// Take the pending create version request.
+    pub fn take_pending_create_version(&mut self) -> Option<(String, String)> {
+        self.pending_create_version.take()
+    }
+
+    /// Handle successful version creation.
+    pub fn handle_create_version_success(&mut self, version: Version) {
+        info!(name = %version.name, "Version created");
+        self.stop_loading();
+        self.notify_success(format!("Version {} created", version.name));
+        self.release_view.add_version(version);
+        self.pending_fetch_version_issues = self.release_view.start_loading_issues();
+    }
+
+    /// Handle failed version creation.
+    pub fn handle_create_version_failure(&mut self, error: &str) {
+        warn!(error = %error, "Version creation failed");
+        self.stop_loading();
+        self.notify_error(format!("Failed to create version: {}", error));
+    }
+
+    /// Take the pending release version request.
+    pub fn take_pending_release_version(&mut self) -> Option<(String, String, Option<String>)> {
+        self.pending_release_version.take()
+    }
+
+    /// Handle successful version release.
+    ///
+    /// Reloads the versions, which also reloads the issues of the selected version.
+    pub fn handle_release_version_success(&mut self, version_id: &str) {
+        info!(version_id = %version_id, "Version released");
+        self.stop_loading();
+        let name = self
+            .release_view
+            .versions()
+            .iter()
+            .find(|v| v.id == version_id)
+            .map(|v| v.name.clone())
+            .unwrap_or_else(|| version_id.to_string());
+        self.notify_success(format!("Version {} released", name));
+        if let Some(project_key) = self.release_view.project_key() {
+            self.pending_fetch_release_versions = Some(project_key.to_string());
+            self.release_view.set_loading(true);
+        }
+    }
+
+    /// Handle failed version release.
+    pub fn handle_release_version_failure(&mut self, error: &str) {
+        warn!(error = %error, "Version release failed");
+        self.stop_loading();
+        self.notify_error(format!("Failed to release version: {}", error));
+    }
+
+    // ========================================================================
+    // Changelog methods
+    // ========================================================================
+
+    /// Take the pending fetch changelog request (issue_key, start_at).
+    pub fn take_pending_fetch_changelog(&mut self) -> Option<(String, u32)> {
+        self.pending_fetch_changelog.take()
+    }
+
+    /// Check if there is a pending fetch changelog request.
+    pub fn has_pending_fetch_changelog(&self) -> bool {
+        self.pending_fetch_changelog.is_some()
+    }
+
+    /// Handle successful changelog fetch.
+    pub fn handle_changelog_fetched(&mut self, changelog: Changelog, append: bool) {
+        debug!(
+            "Changelog fetched: {} entries (total: {})",
+            changelog.histories.len(),
+            changelog.total
+        );
+        if append {
+            self.detail_view.append_changelog(changelog);
+        } else {
+            self.detail_view.set_changelog(changelog);
+        }
+    }
+
+    /// Handle failure to fetch changelog.
+    pub fn handle_fetch_changelog_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch changelog");
+        self.detail_view.hide_history();
+        self.notify_error(format!("Failed to load history: {}", error));
+    }
+
+    // ========================================================================
+    // Linked issue navigation methods
+    // ========================================================================
+
+    /// Take the pending navigate to issue request.
+    pub fn take_pending_navigate_to_issue(&mut self) -> Option<String> {
+        self.pending_navigate_to_issue.take()
+    }
+
+    /// Handle successful linked issue navigation.
+    pub fn handle_navigate_to_issue_success(&mut self, issue: Issue) {
+        info!(key = %issue.key, "Navigated to linked issue");
+        self.stop_loading();
+        let jump = self.pending_jump.take().filter(
+            |location| matches!(location, Location::Issue { key, .. } if *key == issue.key),
+        );
+        match jump {
+            Some(location) => self.show_issue_location(issue, &location),
+            None => {
+                self.set_detail_issue(issue);
+                self.record_location();
+            }
+        }
+    }
+
+    /// Handle failure to navigate to linked issue.
+    pub fn handle_navigate_to_issue_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to navigate to linked issue");
+        self.pending_jump = None;
+        self.stop_loading();
+        self.notify_error(format!("Failed to load issue: {}", error));
+    }
+
+    /// Take the pending fetch link types request.
+    pub fn take_pending_fetch_link_types(&mut self) -> Option<String> {
+        self.pending_fetch_link_types.take()
+    }
+
+    /// Handle successful link types fetch.
+    pub fn handle_link_types_success(&mut self, link_types: Vec<crate::api::types::IssueLinkType>) {
+        info!(count = %link_types.len(), "Fetched link types");
+        self.detail_view.set_link_types(link_types);
+    }
+
+    /// Handle failure to fetch link types.
+    pub fn handle_link_types_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch link types");
+        self.notify_error(format!("Failed to load link types: {}", error));
+    }
+
+    /// Take the pending fetch recent issues for link request.
+    pub fn take_pending_fetch_recent_issues_for_link(&mut self) -> Option<String> {
+        self.pending_fetch_recent_issues_for_link.take()
+    }
+
+    /// Take the pending search issues for link request.
+    pub fn take_pending_search_issues_for_link(&mut self) -> Option<(String, String)> {
+        self.pending_search_issues_for_link.take()
+    }
+
+    /// Handle successful issue search for linking.
+    pub fn handle_issue_search_success(
+        &mut self,
+        suggestions: Vec<crate::api::types::IssueSuggestion>,
+    ) {
+        info!(count = %suggestions.len(), "Fetched issue suggestions");
+        self.detail_view.set_issue_search_suggestions(suggestions);
+    }
+
+    /// Handle failure to search issues.
+    pub fn handle_issue_search_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to search issues");
+        self.notify_error(format!("Failed to search issues: {}", error));
+    }
+
+    /// Take the pending create link request.
+    pub fn take_pending_create_link(&mut self) -> Option<(String, String, String, bool)> {
+        self.pending_create_link.take()
+    }
+
+    /// Handle successful link creation.
+    pub fn handle_create_link_success(&mut self, issue_key: &str) {
+        info!(key = %issue_key, "Link created successfully");
+        self.stop_loading();
+        self.notify_success("Link created successfully");
+        // Set up to refresh the issue details
+        self.pending_navigate_to_issue = Some(issue_key.to_string());
+    }
+
+    /// Handle failure to create link.
+    pub fn handle_create_link_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to create link");
+        self.stop_loading();
+        self.notify_error(format!("Failed to create link: {}", error));
+    }
+
+    /// Take the pending confirm delete link request.
+    pub fn take_pending_confirm_delete_link(&mut self) -> Option<(String, String)> {
+        self.pending_confirm_delete_link.take()
+    }
+
+    /// Show the delete link confirmation dialog.
+    pub fn show_delete_link_confirmation(&mut self, link_id: String, description: String) {
+        self.delete_link_confirm_dialog
+            .show_destructive("Delete Link", format!("Delete link to {}?", description));
+        // Store the link info for when confirmed (not in pending_delete_link yet)
+        self.pending_confirm_delete_link =
+            Some((link_id, self.selected_issue_key.clone().unwrap_or_default()));
+    }
+
+    /// Check if the delete link confirm dialog is visible.
+    pub fn is_delete_link_confirm_visible(&self) -> bool {
+        self.delete_link_confirm_dialog.is_visible()
+    }
+
+    /// Take the pending delete link request.
+    pub fn take_pending_delete_link(&mut self) -> Option<(String, String)> {
+        self.pending_delete_link.take()
+    }
+
+    /// Handle successful link deletion.
+    pub fn handle_delete_link_success(&mut self, issue_key: &str) {
+        info!(key = %issue_key, "Link deleted successfully");
+        self.stop_loading();
+        self.notify_success("Link deleted successfully");
+        // Set up to refresh the issue details
+        self.pending_navigate_to_issue = Some(issue_key.to_string());
+    }
+
+    /// Handle failure to delete link.
+    pub fn handle_delete_link_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to delete link");
+        self.stop_loading();
+        self.notify_error(format!("Failed to delete link: {}", error));
+    }
+
+    // ========================================================================
+    // Delete Issue methods
+    // ========================================================================
+
+    /// Show the delete issue confirmation dialog.
+    pub fn show_delete_issue_confirmation(&mut self, issue_key: String) {
+        self.delete_issue_confirm_dialog.show_destructive(
+            "Delete Issue",
+            format!(
+                "Are you sure you want to delete {}? This cannot be undone.",
+                issue_key
+            ),
+        );
+        self.pending_confirm_delete_issue = Some(issue_key);
+    }
+
+    /// Check if the delete issue confirm dialog is visible.
+    pub fn is_delete_issue_confirm_visible(&self) -> bool {
+        self.delete_issue_confirm_dialog.is_visible()
+    }
+
+    /// Take the pending delete issue request.
+    pub fn take_pending_delete_issue(&mut self) -> Option<String> {
+        self.pending_delete_issue.take()
+    }
+
+    /// Handle successful issue deletion.
+    pub fn handle_delete_issue_success(&mut self, issue_key: &str) {
+        info!(key = %issue_key, "Issue deleted successfully");
+        self.stop_loading();
+        self.notify_success(format!("Issue {} deleted successfully", issue_key));
+        // Go back to the issue list and refresh
+        self.detail_view.clear();
+        self.selected_issue_key = None;
+        self.state = AppState::IssueList;
+        self.list_view.set_loading(true);
+    }
+
+    /// Handle failure to delete issue.
+    pub fn handle_delete_issue_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to delete issue");
+        self.stop_loading();
+        self.notify_error(format!("Failed to delete issue: {}", error));
+    }
+
+    // ========================================================================
+    // Create Issue methods
+    // ========================================================================
+
+    /// Handle successful issue creation.
+    pub fn handle_create_issue_success(
+        &mut self,
+        response: crate::api::types::CreateIssueResponse,
+    ) {
+        info!(key = %response.key, "Issue created successfully");
+        self.stop_loading();
+        self.notify_success(format!("Issue {} created successfully", response.key));
+        let copies = self.clone_copies.take().filter(|c| !c.is_empty());
+        // Close the form and refresh the issue list
+        self.close_create_issue_form(true);
+
+        // Show the new issue, once links and subtasks are copied to a clone
+        match copies {
+            Some(copies) => {
+                self.start_loading("Copying links and subtasks...".to_string());
+                self.pending_copy_to_clone = Some((response.key, copies));
+            }
+            None => self.open_issue(&response.key),
+        }
+    }
+
+    /// Take the pending copy of links and subtasks to a new clone.
+    ///
+    /// Returns the clone's key, its links and the create requests for its
+    /// subtasks.
+    pub fn take_pending_copy_to_clone(
+        &mut self,
+    ) -> Option<(String, Vec<CloneLink>, Vec<CreateIssueRequest>)> {
+        let (issue_key, copies) = self.pending_copy_to_clone.take()?;
+        let project_key = issue_key
+            .rsplit_once('-')
+            .map(|(project, _)| project.to_string())
+            .unwrap_or_default();
+        let subtasks = copies
+            .subtasks
+            .into_iter()
+            .map(|(issue_type_id, summary)| CreateIssueRequest {
+                fields: CreateIssueFields {
+                    project: ProjectRef {
+                        key: project_key.clone(),
+                    },
+                    issuetype: IssueTypeRef { id: issue_type_id },
+                    summary,
+                    description: None,
+                    assignee: None,
+                    priority: None,
+                    parent: Some(ParentRef {
+                        key: issue_key.clone(),
+                    }),
+                    labels: Vec::new(),
+                    components: Vec::new(),
+                    extra: serde_json::Map::new(),
+                },
+            })
+            .collect();
+        Some((issue_key, copies.links, subtasks))
+    }
+
+    /// Handle completion of copying links and subtasks to a clone.
+    ///
+    /// `failures` holds an error message for each link or subtask that could
+    /// not be copied. The clone is shown either way.
+    pub fn handle_copy_to_clone_complete(&mut self, issue_key: &str, failures: Vec<String>) {
+        self.stop_loading();
+        if failures.is_empty() {
+            info!(key = %issue_key, "Copied links and subtasks to clone");
+        } else {
+            warn!(key = %issue_key, count = failures.len(), "Failed to copy to clone");
+            self.notify_warning(format!(
+                "Could not copy {} item(s) to {}: {}",
+                failures.len(),
+                issue_key,
+                failures.join("; ")
+            ));
+        }
+        self.open_issue(issue_key);
+    }
+
+    /// Handle failure to create issue.
+    pub fn handle_create_issue_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to create issue");
+        self.stop_loading();
+        self.create_issue_view.set_submitting(false);
+        self.notify_error(format!("Failed to create issue: {}", error));
+    }
+
+    /// Handle successful issue types fetch.
+    pub fn handle_issue_types_fetched(
+        &mut self,
+        issue_types: Vec<crate::api::types::IssueTypeMeta>,
+    ) {
+        debug!(count = issue_types.len(), "Loaded issue types");
+        self.stop_loading();
+        self.available_issue_types = issue_types;
+
+        // Select the issue type chosen by a template, clone or new subtask
+        if let Some(pending) = self.pending_issue_type.take() {
+            let issue_type = self
+                .available_issue_types
+                .iter()
+                .find(|t| match pending {
+                    PendingIssueType::Named(ref name) => t.name.eq_ignore_ascii_case(name),
+                    PendingIssueType::Subtask => t.subtask,
+                })
+                .map(|t| (t.id.clone(), t.name.clone()));
+            match (issue_type, pending) {
+                (Some((id, name)), _) => self.update_selected_issue_type(&id, &name),
+                (None, PendingIssueType::Named(name)) => self.notify_warning(format!(
+                    "Issue type '{}' is not available in this project",
+                    name
+                )),
+                (None, PendingIssueType::Subtask) => {
+                    self.notify_warning("This project has no subtask issue type")
+                }
+            }
+        }
+    }
+
+    /// Handle failure to fetch issue types.
+    pub fn handle_fetch_issue_types_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch issue types");
+        self.stop_loading();
+        self.notify_error(format!("Failed to load issue types: {}", error));
+    }
+
+    /// Take the pending fetch of the create fields (project key, issue type ID).
+    pub fn take_pending_fetch_create_fields(&mut self) -> Option<(String, String)> {
+        self.pending_fetch_create_fields.take()
+    }
+
+    /// Handle successful create fields fetch.
+    ///
+    /// Results for a project or issue type that is no longer selected are
+    /// ignored. Template labels and components move into their fields; any
+    /// that the fields cannot show stay on the form.
+    pub fn handle_create_fields_fetched(
+        &mut self,
+        project_key: &str,
+        issue_type_id: &str,
+        fields: Vec<crate::api::types::CreateFieldMeta>,
+    ) {
+        let form = &self.create_issue_form;
+        if self.state != AppState::CreateIssue
+            || form.project_key != project_key
+            || form.issue_type_id != issue_type_id
+        {
+            debug!(project = %project_key, "Ignoring stale create fields");
+            return;
+        }
+        debug!(count = fields.len(), "Loaded create fields");
+
+        let panel = self.create_issue_view.create_fields_mut();
+        panel.set_fields(fields);
+        if let Some(rest) = panel.prefill("labels", &self.create_issue_form.labels) {
+            self.create_issue_form.labels = rest;
+        }
+        if let Some(rest) = panel.prefill("components", &self.create_issue_form.components) {
+            self.create_issue_form.components = rest;
+        }
+    }
+
+    /// Handle failure to fetch the create fields.
+    ///
+    /// The form still works with its built-in fields.
+    pub fn handle_fetch_create_fields_failure(&mut self, error: &str) {
+        warn!(error = %error, "Failed to fetch create fields");
+        self.notify_warning(format!("Failed to load issue fields: {}", error));
+    }
+
+    // ========================================================================
+    // External editor methods
+    // ========================================================================
+
+    /// Take the pending external edit request.
+    pub fn take_pending_external_edit(&mut self) -> Option<ExternalEditRequest> {
+        self.pending_external_edit.take()
+    }
+
+    // ========================================================================
+    // Pagination methods
+    // ========================================================================
+
+    /// Check if there's a pending load more request and clear it.
+    ///
+    /// Returns true if pagination should load more issues.
+    pub fn take_pending_load_more(&mut self) -> bool {
+        std::mem::take(&mut self.pending_load_more)
+    }
+
+    /// Handle successful load more response.
+    ///
+    /// Appends the new issues to the list and updates pagination state.
+    pub fn handle_load_more_success(
+        &mut self,
+        issues: Vec<Issue>,
+        total: u32,
+        has_more: bool,
+        next_page_token: Option<String>,
+    ) {
+        if !self.list_view.pagination().loading {
+            debug!("Ignoring load more result for a tab that is no longer active");
+            return;
+        }
+        let count = issues.len() as u32;
+        let current_offset = self.list_view.pagination().current_offset;
+        info!(
+            count = count,
+            current_offset = current_offset,
+            total = total,
+            has_more = has_more,
+            has_token = next_page_token.is_some(),
+            "Appending issues from load more"
+        );
+        // Clear any previous pagination error on success
+        self.list_view.pagination_mut().clear_error();
+        self.list_view.append_issues(issues);
+        self.list_view.pagination_mut().update_from_response(
+            current_offset,
+            count,
+            total,
+            has_more,
+            next_page_token,
+        );
+    }
+
+    /// Handle failed load more request.
+    pub fn handle_load_more_failure(&mut self, error: &str) {
+        warn!(error = %error, "Load more failed");
+        if !self.list_view.pagination().loading {
+            return;
+        }
+        // Set error on pagination state (also stops loading)
+        self.list_view.pagination_mut().set_error(error);
+        // Also show a notification for visibility
+        self.notify_error(format!("Failed to load more issues: {}", error));
+    }
+
+    /// Apply the modified content from an external editor session.
+    ///
+    /// An issue description enters edit mode with the new content ready for
+    /// the user to review and save. A comment goes back into the comment
+    /// composer, and a new issue description into the create issue form.
+    pub fn apply_external_edit_result(&mut self, target: ExternalEditTarget, content: String) {
+        info!(?target, "Applying external edit result");
+        match target {
+            ExternalEditTarget::IssueDescription => {
+                self.detail_view.set_external_edit_content(content);
+            }
+            ExternalEditTarget::Comment => {
+                self.detail_view.set_comment_draft(&content);
+            }
+            ExternalEditTarget::NewIssueDescription => {
+                self.create_issue_view.set_description(&content);
+                self.create_issue_form.description = content;
+            }
+        }
+    }
+
+    /// Returns whether the application should quit.
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    /// Returns the current application state.
+    pub fn state(&self) -> AppState {
+        self.state
+    }
+
+    /// Update the application state based on an event.
+    ///
+    /// This implements the Update part of The Elm Architecture (TEA).
+    /// All state changes flow through this method for predictable behavior.
+    pub fn update(&mut self, event: Event) {
+        match event {
+            Event::Quit => {
+                info!("Quit event received");
+                self.should_quit = true;
+                self.state = AppState::Exiting;
+            }
+            Event::Key(key_event) => {
+                trace!(key = ?key_event.code, modifiers = ?key_event.modifiers, "Key event");
+                self.handle_key_event(key_event);
+            }
+            Event::Resize(width, height) => {
+                trace!(width, height, "Terminal resize event");
+                // Terminal resize is handled automatically by ratatui
+            }
+            Event::Tick => {
+                // Handle periodic tick for animations or background tasks
+                self.handle_tick();
+            }
+        }
+    }
+
+    /// Handle keyboard input events.
+    fn handle_key_event(&mut self, key_event: crossterm::event::KeyEvent) {
+        use crossterm::event::KeyCode;
+
+        // Handle error dialog first (blocks all other input)
+        if self.error_dialog.is_visible() {
+            match key_event.code {
+                KeyCode::Esc | KeyCode::Enter => {
+                    self.error_dialog.dismiss();
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Handle config error dialog (blocks other input)
+        if self.config_error_dialog.is_visible() {
+            if let Some(open) = self.config_error_dialog.handle_input(key_event) {
+                let target = self.config_edit_target.take();
+                if open {
+                    self.pending_config_edit = target;
+                }
+            }
+            return;
+        }
+
+        // Handle delete profile dialog (blocks other input)
+        if self.delete_profile_dialog.is_visible() {
+            if let Some(confirmed) = self.delete_profile_dialog.handle_input(key_event) {
+                if confirmed {
+                    if let Some(name) = self.profile_list_view.selected_profile_name() {
+                        let index = self.profile_list_view.selected();
+                        debug!(name = %name, "Delete profile confirmed");
+                        if let Err(e) = self.delete_profile(index) {
+                            self.notify_error(format!("Failed to delete profile: {}", e));
+                        }
+                    }
+                } else {
+                    debug!("Delete profile cancelled");
+                }
+            }
+            return;
+        }
+
+        // Handle import bundle conflict dialog (blocks other input)
+        if self.import_bundle_dialog.is_visible() {
+            if let Some(action) = self.import_bundle_dialog.handle_input(key_event) {
+                let bundle = self.pending_bundle.take();
+                match (action, bundle) {
+                    (ImportBundleAction::Import(resolution), Some(bundle)) => {
+                        if let Err(e) = self.import_bundle(bundle, resolution) {
+                            self.notify_error(format!("Failed to import profile: {}", e));
+                        }
+                    }
+                    _ => debug!("Import cancelled"),
+                }
+            }
+            return;
+        }
+
+        // Handle bundle file path prompt (blocks other input)
+        if self.state == AppState::ProfileManagement && self.profile_list_view.is_prompting() {
+            if let Some(action) = self.profile_list_view.handle_input(key_event) {
+                self.handle_profile_list_action(action);
+            }
+            return;
+        }
+
+        // Handle discard changes confirmation dialog (blocks other input)
+        if self.discard_confirm_dialog.is_visible() {
+            if let Some(confirmed) = self.discard_confirm_dialog.handle_input(key_event) {
+                if confirmed {
+                    debug!("Discard changes confirmed");
+                    self.detail_view.exit_edit_mode();
+                } else {
+                    debug!("Discard changes cancelled");
+                }
+            }
+            return;
+        }
+
+        // Handle transition confirmation dialog (blocks other input)
+        if self.transition_confirm_dialog.is_visible() {
+            if let Some(confirmed) = self.transition_confirm_dialog.handle_input(key_event) {
+                if confirmed {
+                    debug!("Transition confirmed");
+                    self.confirm_transition();
+                } else {
+                    debug!("Transition cancelled");
+                    self.cancel_transition_confirm();
+                }
+            }
+            return;
+        }
+
+        // Handle delete link confirmation dialog (blocks other input)
+        if self.delete_link_confirm_dialog.is_visible() {
+            if let Some(confirmed) = self.delete_link_confirm_dialog.handle_input(key_event) {
+                if confirmed {
+                    debug!("Delete link confirmed");
+                    // Move from pending_confirm to pending_delete to trigger the actual deletion
+                    if let Some((link_id, issue_key)) = self.pending_confirm_delete_link.take() {
+                        self.pending_delete_link = Some((link_id, issue_key));
+                        self.start_loading("Deleting link...".to_string());
+                    }
+                } else {
+                    debug!("Delete link cancelled");
+                    self.pending_confirm_delete_link = None;
+                }
+            }
+            return;
+        }
+
+        // Handle delete issue confirmation dialog (blocks other input)
+        if self.delete_issue_confirm_dialog.is_visible() {
+            if let Some(confirmed) = self.delete_issue_confirm_dialog.handle_input(key_event) {
+                if confirmed {
+                    debug!("Delete issue confirmed");
+                    // Move from pending_confirm to pending_delete to trigger the actual deletion
+                    if let Some(issue_key) = self.pending_confirm_delete_issue.take() {
+                        self.pending_delete_issue = Some(issue_key);
+                        self.start_loading("Deleting issue...".to_string());
+                    }
+                } else {
+                    debug!("Delete issue cancelled");
+                    self.pending_confirm_delete_issue = None;
+                }
+            }
+            return;
+        }
+
+        // Handle profile form (blocks other input when visible)
+        if self.profile_form_view.is_visible() {
+            if let Some(action) = self.profile_form_view.handle_input(key_event) {
+                match action {
+                    ProfileFormAction::Cancel => {
+                        debug!("Profile form cancelled");
+                    }
+                    ProfileFormAction::Submit(data) => {
+                        debug!("Profile form submitted");
+                        // Handle add/edit
+                        let result = if data.original_name.is_some() {
+                            self.update_profile(data)
+                        } else {
+                            self.add_profile(data)
+                        };
+
+                        match result {
+                            Ok(()) => {
+                                self.profile_form_view.hide();
+                            }
+                            Err(e) => {
+                                self.profile_form_view
+                                    .set_error(FormField::Name, format!("Failed to save: {}", e));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            return;
+        }
+
+        // Handle theme picker (blocks other input when visible)
+        if self.theme_picker.is_visible() {
+            if let Some(action) = self.theme_picker.handle_input(key_event) {
+                match action {
+                    ThemePickerAction::Preview(name) => self.preview_theme(&name),
+                    ThemePickerAction::Select(name) => {
+                        debug!(theme = %name, "Theme selected");
+                        self.apply_theme(&name);
+                    }
+                    ThemePickerAction::Cancel => {
+                        debug!("Theme selection cancelled");
+                        set_theme(self.config.theme(&self.settings));
+                    }
+                }
+            }
+            return;
+        }
+
+        // Handle profile picker (blocks other input when visible)
+        if self.profile_picker.is_visible() {
+            if let Some(action) = self.profile_picker.handle_input(key_event) {
+                match action {
+                    ProfilePickerAction::Select(profile_name) => {
+                        debug!(profile = %profile_name, "Profile selected");
+                        if let Err(e) = self.switch_profile(&profile_name) {
+                            self.notify_error(format!("Failed to switch profile: {}", e));
+                        }
+                    }
+                    ProfilePickerAction::Cancel => {
+                        debug!("Profile selection cancelled");
+                    }
+                }
+            }
+            return;
+        }
+
+        // Handle clone options dialog (blocks other input when visible)
+        if self.clone_options_dialog.is_visible() {
+            if let Some(action) = self.clone_options_dialog.handle_input(key_event) {
+                match action {
+                    CloneOptionsAction::Confirm { links, subtasks } => {
+                        self.open_clone_issue_form(links, subtasks);
+                    }
+                    CloneOptionsAction::Cancel => {
+                        debug!("Clone issue cancelled");
+                    }
+                }
+            }
+            return;
+        }
+
+        // Handle issue template picker (blocks other input when visible)
+        if self.template_picker.is_visible() {
+            if let Some(action) = self.template_picker.handle_input(key_event) {
+                match action {
+                    TemplatePickerAction::Select(Some(name)) => {
+                        self.open_create_issue_form_from_template(&name);
+                    }
+                    TemplatePickerAction::Select(None) => {
+                        self.open_create_issue_form();
+                    }
+                    TemplatePickerAction::Cancel => {
+                        debug!("Issue creation cancelled");
+                    }
+                }
+            }
+            return;
+        }
+
+        // Handle saved filters dialog (blocks other input when visible)
+        if self.saved_filters_dialog.is_visible() {
+            if let Some(action) = self.saved_filters_dialog.handle_input(key_event) {
+                match action {
+                    SavedFiltersAction::Select(saved_filter) => {
+                        debug!("Saved filter selected");
+                        self.apply_saved_filter(*saved_filter);
+                    }
+                    SavedFiltersAction::Save(name) => {
+                        debug!(name = %name, "Saving current filter");
+                        self.save_current_filter(name);
+                    }
+                    SavedFiltersAction::Delete(name) => {
+                        debug!(name = %name, "Deleting saved filter");
+                        self.delete_saved_filter(name);
+                    }
+                    SavedFiltersAction::Cancel => {
+                        debug!("Saved filters dialog cancelled");
+                    }
+                }
+            }
+            return;
+        }
+
+        // Handle JQL input (blocks other input when visible)
+        if self.jql_input.is_visible() {
+            if let Some(action) = self.jql_input.handle_input(key_event) {
+                match action {
+                    JqlAction::Execute(jql) => {
+                        debug!(jql = %jql, "JQL query submitted");
+                        self.execute_jql(jql);
+                    }
+                    JqlAction::Cancel => {
+                        debug!("JQL input cancelled");
+                        self.state = AppState::IssueList;
+                    }
+                }
+            }
+            return;
+        }
+
+        // Handle go to issue finder (blocks other input when visible)
+        if self.go_to_issue.is_visible() {
+            if let Some(action) = self.go_to_issue.handle_input(key_event) {
+                match action {
+                    GoToIssueAction::Open(key) => {
+                        debug!(key = %key, "Go to issue");
+                        self.open_issue(&key);
+                    }
+                    GoToIssueAction::Cancel => {
+                        debug!("Go to issue cancelled");
+                    }
+                }
+            }
+            return;
+        }
+
+        // Handle command palette (blocks other input when visible)
+        if self.command_palette.is_visible() {
+            if let Some(action) = self.command_palette.handle_input(key_event) {
+                match action {
+                    CommandPaletteAction::Execute(cmd_action) => {
+                        debug!(?cmd_action, "Command palette action executed");
+                        self.execute_command_action(cmd_action);
+                    }
+                    CommandPaletteAction::Cancel => {
+                        debug!("Command palette cancelled");
+                    }
+                }
+            }
+            return;
+        }
+
+        // Global key bindings (always available)
+        match keymap().action(KeyContext::Global, key_event) {
+            // Quit (always works)
+            Some(Action::Quit) => {
+                self.should_quit = true;
+                self.state = AppState::Exiting;
+                return;
+            }
+            // Keys typed into a filter panel input go to the input
+            _ if self.state == AppState::FilterPanel && self.filter_panel.is_editing() => {}
+            // Help - available in all views except text editing modes
+            Some(Action::Help) => {
+                // Don't open help when in text editing mode or already in help
+                if self.state != AppState::Help && self.state != AppState::CreateIssue {
+                    self.previous_state = Some(self.state);
+                    let current_context = KeyContext::from_app_state(&self.state);
+                    self.help_view = HelpView::new(current_context);
+                    self.state = AppState::Help;
+                }
+                return;
+            }
+            // Profile switcher (quick switch, available in most views)
+            Some(Action::SwitchProfile)
+                if self.state == AppState::IssueList || self.state == AppState::Loading =>
+            {
+                debug!("Opening profile picker");
+                self.show_profile_picker();
+                return;
+            }
+            // Profile management (full management view)
+            Some(Action::ManageProfiles)
                 if self.state == AppState::IssueList || self.state == AppState::Loading =>
             {
                 debug!("Opening profile management");
                 self.open_profile_management();
                 return;
             }
-            // Command palette on Ctrl+P or Ctrl+K
-            (KeyCode::Char('p'), KeyModifiers::CONTROL)
-            | (KeyCode::Char('k'), KeyModifiers::CONTROL) => {
+            Some(Action::CommandPalette) => {
                 debug!("Opening command palette");
+                self.refresh_recent_issues();
                 self.command_palette.show();
                 return;
             }
+            Some(Action::GoToIssue) if self.can_open_issue() => {
+                self.open_go_to_issue();
+                return;
+            }
+            // Back/forward through the navigation history.
+            // Terminals report Ctrl+I as Tab, which the history panel uses.
+            Some(Action::JumpBack) if self.can_jump() => {
+                self.jump_back();
+                return;
+            }
+            Some(Action::JumpForward)
+                if self.can_jump() && !self.detail_view.is_history_visible() =>
+            {
+                self.jump_forward();
+                return;
+            }
             _ => {}
         }
 
         // State-specific key handling
         match self.state {
             AppState::IssueList | AppState::Loading => {
-                // Handle 'q' to quit only in list view
-                if key_event.code == KeyCode::Char('q') && key_event.modifiers == KeyModifiers::NONE
-                {
-                    self.should_quit = true;
-                    self.state = AppState::Exiting;
-                    return;
-                }
-
                 if let Some(action) = self.list_view.handle_input(key_event) {
                     match action {
+                        ListAction::Quit => {
+                            self.should_quit = true;
+                            self.state = AppState::Exiting;
+                        }
                         ListAction::OpenIssue(key) => {
                             debug!(issue_key = %key, "Opening issue detail");
+                            self.save_location();
                             // Find the issue in the list and set it in detail view
                             if let Some(issue) = self
                                 .list_view
@@ -3139,6 +5400,7 @@ impl App {
                                 self.selected_issue_key = Some(key);
                             }
                             self.state = AppState::IssueDetail;
+                            self.record_location();
                         }
                         ListAction::Refresh => {
                             info!("Refreshing issue list");
@@ -3181,8 +5443,26 @@ impl App {
                             self.open_issue_in_browser(&issue_key);
                         }
                         ListAction::OpenCreateIssue => {
-                            self.open_create_issue_form();
+                            self.start_create_issue();
+                        }
+                        ListAction::NextTab => {
+                            self.next_tab();
+                            self.record_location();
+                        }
+                        ListAction::PrevTab => {
+                            self.prev_tab();
+                            self.record_location();
                         }
+                        ListAction::NewTab => {
+                            self.new_tab();
+                            self.record_location();
+                        }
+                        ListAction::CloseTab => {
+                            self.close_tab();
+                            self.record_location();
+                        }
+                        ListAction::GoToIssue => self.open_go_to_issue(),
+                        ListAction::OpenReleases => self.open_releases(),
                     }
                 }
             }
@@ -3192,8 +5472,10 @@ impl App {
                     match action {
                         DetailAction::GoBack => {
                             debug!("Going back to issue list");
+                            self.save_location();
                             self.state = AppState::IssueList;
                             self.detail_view.clear();
+                            self.record_location();
                         }
                         DetailAction::EditIssue => {
                             debug!("Entering edit mode");
@@ -3319,6 +5601,8 @@ impl App {
                         }
                         DetailAction::NavigateToIssue(issue_key) => {
                             info!(key = %issue_key, "Navigating to linked issue");
+                            self.save_location();
+                            self.pending_jump = None;
                             // Store the pending navigation for the runner to handle
                             self.pending_navigate_to_issue = Some(issue_key.clone());
                             // The runner will fetch the issue details
@@ -3374,14 +5658,32 @@ impl App {
                         }
                         DetailAction::OpenExternalEditor(issue_key) => {
                             if let Some(issue) = self.detail_view.issue() {
-                                let description = issue.description_text();
                                 info!(key = %issue_key, "Opening external editor for issue description");
-                                self.pending_external_edit = Some((issue_key, description));
+                                self.pending_external_edit =
+                                    Some(ExternalEditRequest::description(
+                                        &issue_key,
+                                        &issue.fields.summary,
+                                        issue.description_markdown(),
+                                    ));
+                            }
+                        }
+                        DetailAction::OpenExternalCommentEditor(issue_key, draft) => {
+                            if let Some(issue) = self.detail_view.issue() {
+                                info!(key = %issue_key, "Opening external editor for comment");
+                                self.pending_external_edit = Some(ExternalEditRequest::comment(
+                                    &issue_key,
+                                    &issue.fields.summary,
+                                    self.detail_view.comments(),
+                                    draft,
+                                ));
                             }
                         }
                         DetailAction::OpenInBrowser(issue_key) => {
                             self.open_issue_in_browser(&issue_key);
                         }
+                        DetailAction::OpenLink(url) => {
+                            self.open_link_in_browser(&url);
+                        }
                         DetailAction::ConfirmDeleteIssue(issue_key) => {
                             info!(key = %issue_key, "Confirming issue deletion");
                             self.show_delete_issue_confirmation(issue_key);
@@ -3391,6 +5693,34 @@ impl App {
                             self.pending_delete_issue = Some(issue_key);
                             self.start_loading("Deleting issue...".to_string());
                         }
+                        DetailAction::CloneIssue(issue_key) => {
+                            info!(key = %issue_key, "Cloning issue");
+                            self.start_clone_issue();
+                        }
+                        DetailAction::CreateSubtask(issue_key) => {
+                            info!(key = %issue_key, "Creating subtask");
+                            self.open_create_subtask_form();
+                        }
+                        DetailAction::FetchWatchers(issue_key) => {
+                            debug!(key = %issue_key, "Fetching watchers");
+                            self.pending_fetch_watchers = Some(issue_key);
+                        }
+                        DetailAction::FetchWatcherCandidates(issue_key, project_key) => {
+                            debug!(key = %issue_key, project = %project_key, "Fetching watcher candidates");
+                            self.pending_fetch_watcher_candidates = Some((issue_key, project_key));
+                        }
+                        DetailAction::ChangeWatch(issue_key, change) => {
+                            debug!(key = %issue_key, ?change, "Changing watchers or votes");
+                            self.pending_watch_change = Some((issue_key, change));
+                        }
+                        DetailAction::FetchVersions(issue_key, project_key, field) => {
+                            debug!(key = %issue_key, project = %project_key, ?field, "Fetching versions");
+                            self.pending_fetch_versions = Some((issue_key, project_key));
+                        }
+                        DetailAction::ChangeVersion(issue_key, field, operation) => {
+                            debug!(key = %issue_key, ?field, ?operation, "Changing versions");
+                            self.pending_version_change = Some((issue_key, field, operation));
+                        }
                     }
                 }
             }
@@ -3405,1125 +5735,1979 @@ impl App {
                     }
                 }
             }
-            AppState::FilterPanel => {
-                if let Some(action) = self.filter_panel.handle_input(key_event) {
-                    match action {
-                        FilterPanelAction::Apply(filter) => {
-                            self.apply_filter(filter);
-                        }
-                        FilterPanelAction::Cancel => {
-                            debug!("Filter panel cancelled");
-                            self.state = AppState::IssueList;
-                        }
-                    }
-                }
+            AppState::FilterPanel => {
+                if let Some(action) = self.filter_panel.handle_input(key_event) {
+                    match action {
+                        FilterPanelAction::Apply(filter) => {
+                            self.apply_filter(*filter);
+                        }
+                        FilterPanelAction::Cancel => {
+                            debug!("Filter panel cancelled");
+                            self.state = AppState::IssueList;
+                        }
+                    }
+                }
+            }
+            AppState::ProfileSelect => {
+                if key_event.code == KeyCode::Esc {
+                    self.state = AppState::IssueList;
+                }
+            }
+            AppState::ProfileManagement => {
+                // Handle profile list view input
+                if let Some(action) = self.profile_list_view.handle_input(key_event) {
+                    self.handle_profile_list_action(action);
+                }
+            }
+            AppState::Releases => {
+                if let Some(action) = self.release_view.handle_input(key_event) {
+                    match action {
+                        ReleaseAction::GoBack => {
+                            debug!("Going back from release view");
+                            self.state = AppState::IssueList;
+                        }
+                        ReleaseAction::Refresh(project_key) => {
+                            debug!(project = %project_key, "Refreshing versions");
+                            self.pending_fetch_release_versions = Some(project_key);
+                        }
+                        ReleaseAction::FetchIssues(version_id) => {
+                            debug!(version_id = %version_id, "Fetching version issues");
+                            self.pending_fetch_version_issues = Some(version_id);
+                        }
+                        ReleaseAction::OpenIssue(key) => {
+                            debug!(key = %key, "Opening issue from release view");
+                            self.open_issue(&key);
+                        }
+                        ReleaseAction::CreateVersion(project_id, name) => {
+                            debug!(project_id = %project_id, name = %name, "Creating version");
+                            self.start_loading(format!("Creating version {}...", name));
+                            self.pending_create_version = Some((project_id, name));
+                        }
+                        ReleaseAction::Release {
+                            version_id,
+                            release_date,
+                            move_to,
+                        } => {
+                            debug!(version_id = %version_id, ?move_to, "Releasing version");
+                            self.start_loading("Releasing version...");
+                            self.pending_release_version =
+                                Some((version_id, release_date, move_to));
+                        }
+                    }
+                }
+            }
+            AppState::JqlInput => {
+                // JQL input is handled earlier in this function
+                // when jql_input.is_visible() is checked
+            }
+            AppState::CreateIssue => {
+                // Handle create issue form input directly to avoid borrow issues
+                // (CreateIssueView is part of App, so we can't pass &mut self to it)
+                if let Some(action) = self.handle_create_issue_input(key_event) {
+                    match action {
+                        CreateIssueAction::Cancel => {
+                            self.close_create_issue_form(false);
+                        }
+                        CreateIssueAction::Submit => {
+                            // Form submission sets pending_create_issue flag
+                            // which is handled by the main loop
+                            self.start_loading("Creating issue...");
+                        }
+                        CreateIssueAction::FetchIssueTypes(_project_key) => {
+                            // Fetch is triggered by set_pending_fetch_issue_types
+                            // which is handled by the main loop
+                        }
+                        CreateIssueAction::FetchAssignableUsers(project_key) => {
+                            debug!(project = %project_key, "Fetching assignable users for create issue");
+                            // Store request with special marker to indicate create issue context
+                            self.pending_fetch_assignees =
+                                Some(("__create_issue__".to_string(), project_key));
+                        }
+                        CreateIssueAction::FetchPriorities => {
+                            debug!("Fetching priorities for create issue");
+                            // Store request with special marker to indicate create issue context
+                            self.pending_fetch_priorities = Some("__create_issue__".to_string());
+                        }
+                        CreateIssueAction::OpenExternalEditor => {
+                            let form = &self.create_issue_form;
+                            let project_key = if form.project_key.is_empty() {
+                                "NEW"
+                            } else {
+                                &form.project_key
+                            };
+                            self.pending_external_edit =
+                                Some(ExternalEditRequest::new_issue_description(
+                                    project_key,
+                                    &form.summary,
+                                    form.description.clone(),
+                                ));
+                        }
+                    }
+                }
+            }
+            AppState::Exiting => {
+                // No input handling while exiting
+            }
+        }
+    }
+
+    /// Handle periodic tick events.
+    fn handle_tick(&mut self) {
+        // Tick animations and timers
+        self.loading.tick();
+        self.notifications.tick();
+
+        // Apply changes made to the config file outside the app
+        self.poll_config_file(Instant::now());
+
+        // Run the go to issue search once typing pauses
+        if let Some(query) = self.go_to_issue.take_due_search(Instant::now()) {
+            self.pending_go_to_search = Some(query);
+        }
+
+        // Run the @mention user search once typing pauses
+        let now = Instant::now();
+        let mention_search = self
+            .detail_view
+            .take_due_mention_search(now)
+            .or_else(|| self.create_issue_view.take_due_mention_search(now));
+        if let Some(query) = mention_search {
+            self.pending_mention_search = Some(query);
+        }
+
+        // Fetch JQL value suggestions and validate the query once typing pauses
+        if let Some(request) = self.jql_input.take_due_suggestions(now) {
+            self.pending_jql_suggestions = Some(request);
+        }
+        if let Some(query) = self.jql_input.take_due_validation(now) {
+            self.pending_jql_validation = Some(query);
+        }
+
+        // Transition from Loading to IssueList after initial setup
+        if self.state == AppState::Loading {
+            debug!("Transitioning from Loading to IssueList");
+            self.state = AppState::IssueList;
+            self.loading.stop();
+        }
+    }
+
+    /// Render the application UI.
+    ///
+    /// This implements the View part of The Elm Architecture (TEA).
+    /// The view is a pure function of the current state.
+    pub fn view(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+
+        // Create the main layout with header, content, and footer
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(1),    // Content
+                Constraint::Length(1), // Footer/Status bar
+            ])
+            .split(area);
+
+        // Render header
+        self.render_header(frame, chunks[0]);
+
+        // Render main content based on current state
+        self.render_content(frame, chunks[1]);
+
+        // Render footer/status bar
+        self.render_footer(frame, chunks[2]);
+
+        // Render notifications (on top of everything except dialogs)
+        self.notifications.render(frame, area);
+
+        // Render JQL input (on top of list view)
+        self.jql_input.render(frame, area);
+
+        // Render command palette (on top of list view, similar priority to JQL input)
+        self.command_palette.render(frame, area);
+
+        // Render go to issue finder
+        self.go_to_issue.render(frame, area);
+
+        // Render profile picker (on top of everything except error dialogs)
+        self.profile_picker.render(frame, area);
+
+        // Render theme picker
+        self.theme_picker.render(frame, area);
+
+        // Render issue template picker
+        self.template_picker.render(frame, area);
+
+        // Render clone options dialog
+        self.clone_options_dialog.render(frame, area);
+
+        // Render saved filters dialog (on top of everything except error dialogs)
+        self.saved_filters_dialog.render(frame, area);
+
+        // Render profile form (on top of profile list)
+        self.profile_form_view.render(frame, area);
+
+        // Render delete profile dialog (on top of profile form)
+        self.delete_profile_dialog.render(frame, area);
+
+        // Render import bundle conflict dialog
+        self.import_bundle_dialog.render(frame, area);
+
+        // Render discard changes dialog (on top of profile form)
+        self.discard_confirm_dialog.render(frame, area);
+
+        // Render transition confirmation dialog
+        self.transition_confirm_dialog.render(frame, area);
+
+        // Render delete link confirmation dialog
+        self.delete_link_confirm_dialog.render(frame, area);
+
+        // Render delete issue confirmation dialog
+        self.delete_issue_confirm_dialog.render(frame, area);
+
+        // Render create issue view (on top of issue list)
+        if self.state == AppState::CreateIssue {
+            let render_data = self.create_issue_render_data();
+            self.create_issue_view.render(&render_data, frame, area);
+        }
+
+        // Render error dialog (on top of everything)
+        self.error_dialog.render(frame, area);
+        self.config_error_dialog.render(frame, area);
+    }
+
+    /// Render the application header.
+    fn render_header(&self, frame: &mut Frame, area: Rect) {
+        let title = Paragraph::new("LazyJira")
+            .style(Style::default().fg(Color::Cyan))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Style::default().fg(Color::DarkGray)),
+            );
+        frame.render_widget(title, area);
+    }
+
+    /// Render the main content area based on current state.
+    fn render_content(&mut self, frame: &mut Frame, area: Rect) {
+        match self.state {
+            AppState::Loading | AppState::IssueList => {
+                // Use the ListView for both loading and issue list states
+                self.render_list(frame, area);
+            }
+            AppState::IssueDetail => {
+                // Use the DetailView for issue detail state
+                self.detail_view.render(frame, area);
+            }
+            AppState::ProfileManagement => {
+                // Use the ProfileListView for profile management
+                self.profile_list_view.render(frame, area);
+            }
+            AppState::FilterPanel => {
+                // Render list view in background with filter panel overlay
+                self.render_list(frame, area);
+                self.filter_panel.render(frame, area);
+            }
+            AppState::Help => {
+                // Render the help view
+                self.help_view.render(frame, area);
+            }
+            AppState::Releases => {
+                self.release_view.render(frame, area);
+            }
+            _ => {
+                // For other states, use the placeholder rendering
+                let content = match self.state {
+                    AppState::ProfileSelect => self.render_profile_select_view(),
+                    AppState::Exiting => self.render_exiting_view(),
+                    _ => vec![],
+                };
+
+                let block = Block::default().borders(Borders::NONE);
+
+                let paragraph = Paragraph::new(content)
+                    .block(block)
+                    .alignment(Alignment::Center);
+
+                frame.render_widget(paragraph, area);
+            }
+        }
+    }
+
+    /// Render the issue list, with a tab bar above it when several tabs are open.
+    fn render_list(&mut self, frame: &mut Frame, area: Rect) {
+        if self.tabs.len() > 1 {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(1)])
+                .split(area);
+            render_tab_bar(frame, chunks[0], &self.tab_titles(), self.active_tab);
+            self.list_view.render(frame, chunks[1]);
+        } else {
+            self.list_view.render(frame, area);
+        }
+    }
+
+    /// Render the footer/status bar.
+    fn render_footer(&self, frame: &mut Frame, area: Rect) {
+        match self.state {
+            AppState::Loading | AppState::IssueList => {
+                // Use ListView's status bar
+                self.list_view.render_status_bar(frame, area);
             }
-            AppState::ProfileSelect => {
-                if key_event.code == KeyCode::Esc {
-                    self.state = AppState::IssueList;
-                }
+            AppState::IssueDetail => {
+                // Use DetailView's status bar
+                self.detail_view.render_status_bar(frame, area);
             }
             AppState::ProfileManagement => {
-                // Handle profile list view input
-                if let Some(action) = self.profile_list_view.handle_input(key_event) {
-                    match action {
-                        ProfileListAction::AddProfile => {
-                            debug!("Opening add profile form");
-                            self.profile_form_view.show_add();
-                        }
-                        ProfileListAction::EditProfile(index) => {
-                            if let Some(profile) = self.get_profile_by_index(index).cloned() {
-                                debug!(name = %profile.name, "Opening edit profile form");
-                                // Get token for editing (may be empty if not set)
-                                let token = auth::get_token(&profile.name).unwrap_or_default();
-                                self.profile_form_view.show_edit(&profile, &token);
-                            }
-                        }
-                        ProfileListAction::DeleteProfile(index) => {
-                            if let Some(profile) = self.get_profile_by_index(index).cloned() {
-                                debug!(name = %profile.name, "Showing delete confirmation");
-                                self.delete_profile_dialog.show(&profile.name);
-                            }
-                        }
-                        ProfileListAction::SetDefault(index) => {
-                            if let Err(e) = self.set_default_profile(index) {
-                                self.notify_error(format!("Failed to set default: {}", e));
-                            }
-                        }
-                        ProfileListAction::SwitchToProfile(index) => {
-                            if let Some(profile) = self.get_profile_by_index(index) {
-                                let name = profile.name.clone();
-                                if let Err(e) = self.switch_profile(&name) {
-                                    self.notify_error(format!("Failed to switch profile: {}", e));
-                                } else {
-                                    // Go back to issue list after switching
-                                    self.state = AppState::IssueList;
-                                }
-                            }
-                        }
-                        ProfileListAction::GoBack => {
-                            debug!("Going back from profile management");
-                            self.state = AppState::IssueList;
-                        }
-                    }
-                }
+                // Profile management status bar
+                let footer = Line::from(vec![
+                    Span::styled(
+                        " Profiles ",
+                        Style::default().fg(Color::Black).bg(Color::Cyan),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("{} profiles configured", self.config.profiles.len()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]);
+                let paragraph = Paragraph::new(footer);
+                frame.render_widget(paragraph, area);
             }
-            AppState::JqlInput => {
-                // JQL input is handled earlier in this function
-                // when jql_input.is_visible() is checked
+            AppState::Help => {
+                // Help view has its own footer hints
+                let footer = Line::from(vec![
+                    Span::styled(" Help ", Style::default().fg(Color::Black).bg(Color::Cyan)),
+                    Span::raw(" "),
+                    Span::styled(
+                        "[j/k] scroll  [g/G] top/bottom  [?/q/Esc] close",
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]);
+                let paragraph = Paragraph::new(footer);
+                frame.render_widget(paragraph, area);
+            }
+            AppState::FilterPanel => {
+                // Render contextual help for filter panel
+                render_context_help(frame, area, KeyContext::FilterPanel);
+            }
+            AppState::Releases => {
+                render_context_help(frame, area, KeyContext::Releases);
             }
             AppState::CreateIssue => {
-                // Handle create issue form input directly to avoid borrow issues
-                // (CreateIssueView is part of App, so we can't pass &mut self to it)
-                if let Some(action) = self.handle_create_issue_input(key_event) {
-                    match action {
-                        CreateIssueAction::Cancel => {
-                            self.close_create_issue_form(false);
-                        }
-                        CreateIssueAction::Submit => {
-                            // Form submission sets pending_create_issue flag
-                            // which is handled by the main loop
-                            self.start_loading("Creating issue...");
-                        }
-                        CreateIssueAction::FetchIssueTypes(_project_key) => {
-                            // Fetch is triggered by set_pending_fetch_issue_types
-                            // which is handled by the main loop
-                        }
-                        CreateIssueAction::FetchAssignableUsers(project_key) => {
-                            debug!(project = %project_key, "Fetching assignable users for create issue");
-                            // Store request with special marker to indicate create issue context
-                            self.pending_fetch_assignees =
-                                Some(("__create_issue__".to_string(), project_key));
-                        }
-                        CreateIssueAction::FetchPriorities => {
-                            debug!("Fetching priorities for create issue");
-                            // Store request with special marker to indicate create issue context
-                            self.pending_fetch_priorities = Some("__create_issue__".to_string());
-                        }
-                    }
-                }
+                // Create issue form status bar
+                let footer = Line::from(vec![
+                    Span::styled(
+                        " Create Issue ",
+                        Style::default().fg(Color::Black).bg(Color::Cyan),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        "[Tab] next field  [Shift+Tab] prev  [Enter] submit  [Esc] cancel",
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]);
+                let paragraph = Paragraph::new(footer);
+                frame.render_widget(paragraph, area);
             }
-            AppState::Exiting => {
-                // No input handling while exiting
+            _ => {
+                // Default status bar for other states
+                let state_str = match self.state {
+                    AppState::ProfileSelect => "Profile Select",
+                    AppState::Exiting => "Exiting...",
+                    _ => "",
+                };
+
+                let footer = Line::from(vec![
+                    Span::styled(
+                        format!(" {} ", state_str),
+                        Style::default().fg(Color::Black).bg(Color::Cyan),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        "Press 'q' to quit, '?' for help, Esc to go back",
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]);
+
+                let paragraph = Paragraph::new(footer);
+                frame.render_widget(paragraph, area);
             }
         }
     }
 
-    /// Handle periodic tick events.
-    fn handle_tick(&mut self) {
-        // Tick animations and timers
-        self.loading.tick();
-        self.notifications.tick();
+    /// Render profile select view content (placeholder).
+    fn render_profile_select_view(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::raw(""),
+            Line::styled("Profile Select", Style::default().fg(Color::Green)),
+            Line::raw(""),
+            Line::styled(
+                "No profiles configured yet.",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]
+    }
+
+    /// Render exiting view content.
+    fn render_exiting_view(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::raw(""),
+            Line::styled("Goodbye!", Style::default().fg(Color::Green)),
+        ]
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::{CreateFieldMeta, Issue, IssueFields, IssueType, Status};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn create_test_issue(key: &str, summary: &str) -> Issue {
+        Issue {
+            id: "1".to_string(),
+            key: key.to_string(),
+            self_url: "https://example.com".to_string(),
+            fields: IssueFields {
+                summary: summary.to_string(),
+                description: None,
+                status: Status {
+                    id: "1".to_string(),
+                    name: "Open".to_string(),
+                    status_category: None,
+                },
+                issuetype: IssueType {
+                    id: "1".to_string(),
+                    name: "Bug".to_string(),
+                    subtask: false,
+                    description: None,
+                    icon_url: None,
+                },
+                priority: None,
+                assignee: None,
+                reporter: None,
+                project: None,
+                labels: vec![],
+                components: vec![],
+                created: None,
+                updated: None,
+                duedate: None,
+                story_points: None,
+                issue_links: vec![],
+                subtasks: vec![],
+                parent: None,
+                extra_fields: Default::default(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_app_new() {
+        let app = App::new();
+        assert_eq!(app.state(), AppState::Loading);
+        assert!(!app.should_quit());
+        assert!(app.list_view.is_loading());
+    }
+
+    #[test]
+    fn test_app_default() {
+        let app = App::default();
+        assert_eq!(app.state(), AppState::Loading);
+        assert!(!app.should_quit());
+    }
+
+    #[test]
+    fn test_quit_on_q_key() {
+        let mut app = App::new();
+        let key_event = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        app.update(Event::Key(key_event));
+        assert!(app.should_quit());
+        assert_eq!(app.state(), AppState::Exiting);
+    }
+
+    #[test]
+    fn test_quit_on_ctrl_c() {
+        let mut app = App::new();
+        let key_event = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        app.update(Event::Key(key_event));
+        assert!(app.should_quit());
+        assert_eq!(app.state(), AppState::Exiting);
+    }
 
-        // Transition from Loading to IssueList after initial setup
-        if self.state == AppState::Loading {
-            debug!("Transitioning from Loading to IssueList");
-            self.state = AppState::IssueList;
-            self.loading.stop();
-        }
+    #[test]
+    fn test_help_on_question_mark() {
+        let mut app = App::new();
+        // First transition to IssueList via tick
+        app.update(Event::Tick);
+        assert_eq!(app.state(), AppState::IssueList);
+
+        // Then open help
+        let key_event = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
+        app.update(Event::Key(key_event));
+        assert_eq!(app.state(), AppState::Help);
     }
 
-    /// Render the application UI.
-    ///
-    /// This implements the View part of The Elm Architecture (TEA).
-    /// The view is a pure function of the current state.
-    pub fn view(&mut self, frame: &mut Frame) {
-        let area = frame.area();
+    #[test]
+    fn test_escape_closes_help() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
 
-        // Create the main layout with header, content, and footer
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3), // Header
-                Constraint::Min(1),    // Content
-                Constraint::Length(1), // Footer/Status bar
-            ])
-            .split(area);
+        // Open help
+        let key_event = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
+        app.update(Event::Key(key_event));
+        assert_eq!(app.state(), AppState::Help);
 
-        // Render header
-        self.render_header(frame, chunks[0]);
+        // Close help with Escape
+        let key_event = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        app.update(Event::Key(key_event));
+        assert_eq!(app.state(), AppState::IssueList);
+    }
 
-        // Render main content based on current state
-        self.render_content(frame, chunks[1]);
+    #[test]
+    fn test_help_opens_from_issue_detail() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
 
-        // Render footer/status bar
-        self.render_footer(frame, chunks[2]);
+        // Add an issue and open detail view
+        app.list_view
+            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::IssueDetail);
 
-        // Render notifications (on top of everything except dialogs)
-        self.notifications.render(frame, area);
+        // Press '?' to open help
+        let key = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::Help);
 
-        // Render JQL input (on top of list view)
-        self.jql_input.render(frame, area);
+        // previous_state should be IssueDetail so we return correctly
+        assert_eq!(app.previous_state, Some(AppState::IssueDetail));
+    }
 
-        // Render command palette (on top of list view, similar priority to JQL input)
-        self.command_palette.render(frame, area);
+    #[test]
+    fn test_help_closes_to_issue_detail() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
 
-        // Render profile picker (on top of everything except error dialogs)
-        self.profile_picker.render(frame, area);
+        // Add an issue and open detail view
+        app.list_view
+            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::IssueDetail);
 
-        // Render saved filters dialog (on top of everything except error dialogs)
-        self.saved_filters_dialog.render(frame, area);
+        // Open help
+        let key = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::Help);
 
-        // Render profile form (on top of profile list)
-        self.profile_form_view.render(frame, area);
+        // Close help - should return to IssueDetail
+        let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::IssueDetail);
+    }
 
-        // Render delete profile dialog (on top of profile form)
-        self.delete_profile_dialog.render(frame, area);
+    #[test]
+    fn test_help_does_not_open_in_create_issue() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
 
-        // Render discard changes dialog (on top of profile form)
-        self.discard_confirm_dialog.render(frame, area);
+        // Open create issue form
+        app.open_create_issue_form();
+        assert_eq!(app.state(), AppState::CreateIssue);
 
-        // Render transition confirmation dialog
-        self.transition_confirm_dialog.render(frame, area);
+        // Press '?' - should NOT open help (it's text input mode)
+        let key = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        // State should still be CreateIssue, not Help
+        assert_eq!(app.state(), AppState::CreateIssue);
+    }
+
+    #[test]
+    fn test_tick_transitions_from_loading() {
+        let mut app = App::new();
+        assert_eq!(app.state(), AppState::Loading);
+        app.update(Event::Tick);
+        assert_eq!(app.state(), AppState::IssueList);
+    }
+
+    #[test]
+    fn test_quit_event() {
+        let mut app = App::new();
+        app.update(Event::Quit);
+        assert!(app.should_quit());
+        assert_eq!(app.state(), AppState::Exiting);
+    }
+
+    #[test]
+    fn test_resize_event() {
+        let mut app = App::new();
+        let initial_state = app.state();
+        app.update(Event::Resize(100, 50));
+        // Resize should not change state
+        assert_eq!(app.state(), initial_state);
+        assert!(!app.should_quit());
+    }
+
+    #[test]
+    fn test_list_view_navigation() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
+
+        // Add some issues
+        app.list_view.set_issues(vec![
+            create_test_issue("TEST-1", "First"),
+            create_test_issue("TEST-2", "Second"),
+        ]);
+
+        // Navigate down
+        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.list_view.selected_index(), 1);
+
+        // Navigate up
+        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.list_view.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_open_issue_detail() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
+
+        app.list_view
+            .set_issues(vec![create_test_issue("TEST-123", "Test issue")]);
+
+        // Press Enter to open issue detail
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+
+        assert_eq!(app.state(), AppState::IssueDetail);
+        assert_eq!(app.selected_issue_key(), Some(&"TEST-123".to_string()));
+        // Verify the issue was set in the detail view
+        assert!(app.detail_view().issue().is_some());
+        assert_eq!(app.detail_view().issue().unwrap().key, "TEST-123");
+    }
+
+    #[test]
+    fn test_escape_from_detail() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
+
+        app.list_view
+            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
+
+        // Open issue detail
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::IssueDetail);
+
+        // Press Esc to go back
+        let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::IssueList);
+        // Detail view should be cleared
+        assert!(app.detail_view().issue().is_none());
+    }
+
+    #[test]
+    fn test_q_from_detail_goes_back() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
+
+        app.list_view
+            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
+
+        // Open issue detail
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::IssueDetail);
+
+        // Press 'q' to go back (not quit, since we're in detail view)
+        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.state(), AppState::IssueList);
+        assert!(!app.should_quit()); // Should not quit, just go back
+    }
+
+    #[test]
+    fn test_detail_view_scroll() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
+
+        app.list_view
+            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
+
+        // Open issue detail
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+
+        // Set max_scroll so we can scroll
+        app.detail_view_mut().set_max_scroll(10);
+
+        // Scroll down with 'j'
+        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.detail_view().scroll(), 1);
 
-        // Render delete link confirmation dialog
-        self.delete_link_confirm_dialog.render(frame, area);
+        // Scroll up with 'k'
+        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
+        assert_eq!(app.detail_view().scroll(), 0);
+    }
 
-        // Render delete issue confirmation dialog
-        self.delete_issue_confirm_dialog.render(frame, area);
+    #[test]
+    fn test_detail_view_accessors() {
+        let mut app = App::new();
+        let issue = create_test_issue("TEST-1", "Test issue");
 
-        // Render create issue view (on top of issue list)
-        if self.state == AppState::CreateIssue {
-            let render_data = self.create_issue_render_data();
-            self.create_issue_view.render(&render_data, frame, area);
-        }
+        app.set_detail_issue(issue);
 
-        // Render error dialog (on top of everything)
-        self.error_dialog.render(frame, area);
+        assert!(app.detail_view().issue().is_some());
+        assert_eq!(app.detail_view().issue().unwrap().key, "TEST-1");
+        assert_eq!(app.selected_issue_key(), Some(&"TEST-1".to_string()));
     }
 
-    /// Render the application header.
-    fn render_header(&self, frame: &mut Frame, area: Rect) {
-        let title = Paragraph::new("LazyJira")
-            .style(Style::default().fg(Color::Cyan))
-            .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .borders(Borders::BOTTOM)
-                    .border_style(Style::default().fg(Color::DarkGray)),
-            );
-        frame.render_widget(title, area);
-    }
+    #[test]
+    fn test_open_filter_panel() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
 
-    /// Render the main content area based on current state.
-    fn render_content(&mut self, frame: &mut Frame, area: Rect) {
-        match self.state {
-            AppState::Loading | AppState::IssueList => {
-                // Use the ListView for both loading and issue list states
-                self.list_view.render(frame, area);
-            }
-            AppState::IssueDetail => {
-                // Use the DetailView for issue detail state
-                self.detail_view.render(frame, area);
-            }
-            AppState::ProfileManagement => {
-                // Use the ProfileListView for profile management
-                self.profile_list_view.render(frame, area);
-            }
-            AppState::FilterPanel => {
-                // Render list view in background with filter panel overlay
-                self.list_view.render(frame, area);
-                self.filter_panel.render(frame, area);
-            }
-            AppState::Help => {
-                // Render the help view
-                self.help_view.render(frame, area);
-            }
-            _ => {
-                // For other states, use the placeholder rendering
-                let content = match self.state {
-                    AppState::ProfileSelect => self.render_profile_select_view(),
-                    AppState::Exiting => self.render_exiting_view(),
-                    _ => vec![],
-                };
+        // Press 'f' to open filter panel
+        let key = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
 
-                let block = Block::default().borders(Borders::NONE);
+        assert_eq!(app.state(), AppState::FilterPanel);
+    }
 
-                let paragraph = Paragraph::new(content)
-                    .block(block)
-                    .alignment(Alignment::Center);
+    #[test]
+    fn test_filter_panel_reads_current_jql() {
+        let mut app = App::new();
+        app.update(Event::Tick);
+        app.execute_jql(
+            r#"project = PROJ AND (labels NOT IN ("a \"b\"") OR labels IS EMPTY)"#.to_string(),
+        );
 
-                frame.render_widget(paragraph, area);
-            }
-        }
+        app.open_filter_panel();
+        assert_eq!(app.state(), AppState::FilterPanel);
+        let action = app
+            .filter_panel
+            .handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let Some(FilterPanelAction::Apply(filter)) = action else {
+            panic!("expected the filter to be applied");
+        };
+        assert_eq!(filter.project.as_deref(), Some("PROJ"));
+        assert_eq!(filter.labels, vec![r#"a "b""#]);
+
+        // Applying the panel replaces the JQL query
+        app.apply_filter(*filter);
+        assert!(app.current_jql().is_none());
+        assert_eq!(
+            app.effective_jql().unwrap(),
+            format!(
+                r#"project = "PROJ" AND (labels NOT IN ("a \"b\"") OR labels IS EMPTY) {}"#,
+                app.list_view.sort_jql()
+            )
+        );
     }
 
-    /// Render the footer/status bar.
-    fn render_footer(&self, frame: &mut Frame, area: Rect) {
-        match self.state {
-            AppState::Loading | AppState::IssueList => {
-                // Use ListView's status bar
-                self.list_view.render_status_bar(frame, area);
-            }
-            AppState::IssueDetail => {
-                // Use DetailView's status bar
-                self.detail_view.render_status_bar(frame, area);
-            }
-            AppState::ProfileManagement => {
-                // Profile management status bar
-                let footer = Line::from(vec![
-                    Span::styled(
-                        " Profiles ",
-                        Style::default().fg(Color::Black).bg(Color::Cyan),
-                    ),
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("{} profiles configured", self.config.profiles.len()),
-                        Style::default().fg(Color::DarkGray),
-                    ),
-                ]);
-                let paragraph = Paragraph::new(footer);
-                frame.render_widget(paragraph, area);
-            }
-            AppState::Help => {
-                // Help view has its own footer hints
-                let footer = Line::from(vec![
-                    Span::styled(" Help ", Style::default().fg(Color::Black).bg(Color::Cyan)),
-                    Span::raw(" "),
-                    Span::styled(
-                        "[j/k] scroll  [g/G] top/bottom  [?/q/Esc] close",
-                        Style::default().fg(Color::DarkGray),
-                    ),
-                ]);
-                let paragraph = Paragraph::new(footer);
-                frame.render_widget(paragraph, area);
-            }
-            AppState::FilterPanel => {
-                // Render contextual help for filter panel
-                render_context_help(frame, area, KeyContext::FilterPanel);
-            }
-            AppState::CreateIssue => {
-                // Create issue form status bar
-                let footer = Line::from(vec![
-                    Span::styled(
-                        " Create Issue ",
-                        Style::default().fg(Color::Black).bg(Color::Cyan),
-                    ),
-                    Span::raw(" "),
-                    Span::styled(
-                        "[Tab] next field  [Shift+Tab] prev  [Enter] submit  [Esc] cancel",
-                        Style::default().fg(Color::DarkGray),
-                    ),
-                ]);
-                let paragraph = Paragraph::new(footer);
-                frame.render_widget(paragraph, area);
-            }
-            _ => {
-                // Default status bar for other states
-                let state_str = match self.state {
-                    AppState::ProfileSelect => "Profile Select",
-                    AppState::Exiting => "Exiting...",
-                    _ => "",
-                };
+    #[test]
+    fn test_filter_panel_text_input_takes_global_keys() {
+        let mut app = App::new();
+        app.update(Event::Tick);
+        app.open_filter_panel();
+        let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+        // Tab back to the text and dates section, the last one
+        app.update(key(KeyCode::BackTab));
+        app.update(key(KeyCode::Char(' ')));
+        for c in "why?".chars() {
+            app.update(key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.state(), AppState::FilterPanel);
+        app.update(key(KeyCode::Enter));
 
-                let footer = Line::from(vec![
-                    Span::styled(
-                        format!(" {} ", state_str),
-                        Style::default().fg(Color::Black).bg(Color::Cyan),
-                    ),
-                    Span::raw(" "),
-                    Span::styled(
-                        "Press 'q' to quit, '?' for help, Esc to go back",
-                        Style::default().fg(Color::DarkGray),
-                    ),
-                ]);
+        // Outside the input, ? opens help again
+        app.update(key(KeyCode::Char('?')));
+        assert_eq!(app.state(), AppState::Help);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.state(), AppState::FilterPanel);
 
-                let paragraph = Paragraph::new(footer);
-                frame.render_widget(paragraph, area);
-            }
-        }
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.filter_state().text.as_deref(), Some("why?"));
     }
 
-    /// Render profile select view content (placeholder).
-    fn render_profile_select_view(&self) -> Vec<Line<'static>> {
-        vec![
-            Line::raw(""),
-            Line::styled("Profile Select", Style::default().fg(Color::Green)),
-            Line::raw(""),
-            Line::styled(
-                "No profiles configured yet.",
-                Style::default().fg(Color::DarkGray),
-            ),
-        ]
-    }
+    #[test]
+    fn test_refresh_sets_loading() {
+        let mut app = App::new();
+        app.update(Event::Tick); // Transition to IssueList
 
-    /// Render exiting view content.
-    fn render_exiting_view(&self) -> Vec<Line<'static>> {
-        vec![
-            Line::raw(""),
-            Line::styled("Goodbye!", Style::default().fg(Color::Green)),
-        ]
-    }
-}
+        // Clear loading state first
+        app.list_view.set_loading(false);
+        assert!(!app.list_view.is_loading());
 
-impl Default for App {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+        // Press 'r' to refresh
+        let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
+        app.update(Event::Key(key));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::api::types::{Issue, IssueFields, IssueType, Status};
-    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        assert!(app.list_view.is_loading());
+    }
 
-    fn create_test_issue(key: &str, summary: &str) -> Issue {
-        Issue {
-            id: "1".to_string(),
-            key: key.to_string(),
-            self_url: "https://example.com".to_string(),
-            fields: IssueFields {
-                summary: summary.to_string(),
-                description: None,
-                status: Status {
-                    id: "1".to_string(),
-                    name: "Open".to_string(),
-                    status_category: None,
-                },
-                issuetype: IssueType {
-                    id: "1".to_string(),
-                    name: "Bug".to_string(),
-                    subtask: false,
-                    description: None,
-                    icon_url: None,
-                },
-                priority: None,
-                assignee: None,
-                reporter: None,
-                project: None,
-                labels: vec![],
-                components: vec![],
-                created: None,
-                updated: None,
-                duedate: None,
-                story_points: None,
-                issue_links: vec![],
-                subtasks: vec![],
-                parent: None,
-            },
-        }
+    #[test]
+    fn test_list_view_accessors() {
+        let mut app = App::new();
+
+        // Test mutable accessor
+        app.list_view_mut()
+            .set_profile_name(Some("work".to_string()));
+
+        // Test immutable accessor
+        assert_eq!(app.list_view().issue_count(), 0);
     }
 
+    // ========================================================================
+    // Notification and error handling tests
+    // ========================================================================
+
     #[test]
-    fn test_app_new() {
-        let app = App::new();
-        assert_eq!(app.state(), AppState::Loading);
-        assert!(!app.should_quit());
-        assert!(app.list_view.is_loading());
+    fn test_notify_info() {
+        let mut app = App::new();
+        app.notify_info("Test info message");
+        assert_eq!(app.notifications().len(), 1);
     }
 
     #[test]
-    fn test_app_default() {
-        let app = App::default();
-        assert_eq!(app.state(), AppState::Loading);
-        assert!(!app.should_quit());
+    fn test_notify_success() {
+        let mut app = App::new();
+        app.notify_success("Operation completed");
+        assert_eq!(app.notifications().len(), 1);
     }
 
     #[test]
-    fn test_quit_on_q_key() {
+    fn test_notify_warning() {
         let mut app = App::new();
-        let key_event = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
-        app.update(Event::Key(key_event));
-        assert!(app.should_quit());
-        assert_eq!(app.state(), AppState::Exiting);
+        app.notify_warning("Warning message");
+        assert_eq!(app.notifications().len(), 1);
     }
 
     #[test]
-    fn test_quit_on_ctrl_c() {
+    fn test_notify_error() {
         let mut app = App::new();
-        let key_event = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
-        app.update(Event::Key(key_event));
-        assert!(app.should_quit());
-        assert_eq!(app.state(), AppState::Exiting);
+        app.notify_error("Error message");
+        assert_eq!(app.notifications().len(), 1);
     }
 
     #[test]
-    fn test_help_on_question_mark() {
+    fn test_error_dialog_show_hide() {
         let mut app = App::new();
-        // First transition to IssueList via tick
-        app.update(Event::Tick);
-        assert_eq!(app.state(), AppState::IssueList);
+        assert!(!app.is_error_dialog_visible());
 
-        // Then open help
-        let key_event = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
-        app.update(Event::Key(key_event));
-        assert_eq!(app.state(), AppState::Help);
+        app.show_error_dialog("Error", "Something went wrong");
+        assert!(app.is_error_dialog_visible());
+
+        app.dismiss_error_dialog();
+        assert!(!app.is_error_dialog_visible());
     }
 
     #[test]
-    fn test_escape_closes_help() {
+    fn test_error_dialog_blocks_input() {
         let mut app = App::new();
         app.update(Event::Tick); // Transition to IssueList
+        assert_eq!(app.state(), AppState::IssueList);
 
-        // Open help
-        let key_event = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
+        // Show error dialog
+        app.show_error_dialog("Error", "Test error");
+        assert!(app.is_error_dialog_visible());
+
+        // Try to quit with 'q' - should be blocked by error dialog
+        let key_event = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
         app.update(Event::Key(key_event));
-        assert_eq!(app.state(), AppState::Help);
+        assert!(!app.should_quit()); // Should NOT quit
+        assert!(app.is_error_dialog_visible()); // Dialog still visible
 
-        // Close help with Escape
+        // Dismiss with Esc
         let key_event = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
         app.update(Event::Key(key_event));
+        assert!(!app.is_error_dialog_visible());
+    }
+
+    #[test]
+    fn test_error_dialog_dismiss_with_enter() {
+        let mut app = App::new();
+        app.show_error_dialog("Error", "Test");
+
+        let key_event = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.update(Event::Key(key_event));
+        assert!(!app.is_error_dialog_visible());
+    }
+
+    #[test]
+    fn test_loading_indicator() {
+        let mut app = App::new();
+
+        // App starts with loading active
+        assert!(app.is_loading());
+
+        // Stop loading
+        app.stop_loading();
+        assert!(!app.is_loading());
+
+        // Start loading with message
+        app.start_loading("Fetching data...");
+        assert!(app.is_loading());
+    }
+
+    #[test]
+    fn test_loading_stops_on_tick() {
+        let mut app = App::new();
+        assert!(app.is_loading());
+        assert_eq!(app.state(), AppState::Loading);
+
+        // Tick should transition state and stop loading
+        app.update(Event::Tick);
         assert_eq!(app.state(), AppState::IssueList);
+        assert!(!app.is_loading());
     }
 
     #[test]
-    fn test_help_opens_from_issue_detail() {
+    fn test_notifications_tick() {
         let mut app = App::new();
-        app.update(Event::Tick); // Transition to IssueList
+        app.notify_info("Test");
+        assert_eq!(app.notifications().len(), 1);
 
-        // Add an issue and open detail view
+        // Notifications with short duration will be cleared after tick
+        // (Our default is 3 seconds, so this test just verifies tick runs)
+        app.update(Event::Tick);
+        // Notification should still exist (hasn't expired yet)
+        assert_eq!(app.notifications().len(), 1);
+    }
+
+    #[test]
+    fn test_notifications_mut() {
+        let mut app = App::new();
+        app.notifications_mut().info("Direct access");
+        assert_eq!(app.notifications().len(), 1);
+    }
+
+    #[test]
+    fn test_loading_mut() {
+        let mut app = App::new();
+        app.loading_mut().set_message("Custom message");
+        assert_eq!(app.loading().message(), "Custom message");
+    }
+
+    // ========================================================================
+    // Profile switching tests
+    // ========================================================================
+
+    fn create_test_config_with_profiles() -> Config {
+        Config {
+            settings: crate::config::Settings {
+                default_profile: Some("work".to_string()),
+                ..Default::default()
+            },
+            profiles: vec![
+                Profile::new(
+                    "work".to_string(),
+                    "https://work.atlassian.net".to_string(),
+                    "work@example.com".to_string(),
+                ),
+                Profile::new(
+                    "personal".to_string(),
+                    "https://personal.atlassian.net".to_string(),
+                    "personal@example.com".to_string(),
+                ),
+                Profile::new(
+                    "client".to_string(),
+                    "https://client.atlassian.net".to_string(),
+                    "client@example.com".to_string(),
+                ),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_with_config() {
+        let config = create_test_config_with_profiles();
+        let app = App::with_config(config);
+
+        assert_eq!(app.profile_count(), 3);
+        assert_eq!(app.current_profile_name(), Some("work"));
+    }
+
+    #[test]
+    fn test_current_profile() {
+        let config = create_test_config_with_profiles();
+        let app = App::with_config(config);
+
+        let profile = app.current_profile().expect("should have current profile");
+        assert_eq!(profile.name, "work");
+        assert_eq!(profile.url, "https://work.atlassian.net");
+    }
+
+    #[test]
+    fn test_switch_profile_success() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
+
+        // Add some issues to verify clearing
         app.list_view
-            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
-        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::IssueDetail);
+            .set_issues(vec![create_test_issue("TEST-1", "Test issue")]);
+        assert_eq!(app.list_view.issue_count(), 1);
 
-        // Press '?' to open help
-        let key = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::Help);
+        // Switch profile
+        let result = app.switch_profile("personal");
+        assert!(result.is_ok());
 
-        // previous_state should be IssueDetail so we return correctly
-        assert_eq!(app.previous_state, Some(AppState::IssueDetail));
+        // Verify profile changed
+        assert_eq!(app.current_profile_name(), Some("personal"));
+
+        // Verify session data cleared
+        assert_eq!(app.list_view.issue_count(), 0);
+        assert!(app.list_view.is_loading());
+
+        // Verify notification was created
+        assert!(!app.notifications().is_empty());
     }
 
     #[test]
-    fn test_help_closes_to_issue_detail() {
-        let mut app = App::new();
-        app.update(Event::Tick); // Transition to IssueList
+    fn test_switch_profile_not_found() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
 
-        // Add an issue and open detail view
+        let result = app.switch_profile("nonexistent");
+        assert!(result.is_err());
+
+        // Profile should not change
+        assert_eq!(app.current_profile_name(), Some("work"));
+    }
+
+    #[test]
+    fn test_switch_to_same_profile() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
+
+        // Add some issues
         app.list_view
-            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
-        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::IssueDetail);
+            .set_issues(vec![create_test_issue("TEST-1", "Test issue")]);
+        let initial_notification_count = app.notifications().len();
 
-        // Open help
-        let key = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::Help);
+        // Switch to same profile should be a no-op
+        let result = app.switch_profile("work");
+        assert!(result.is_ok());
+
+        // Issues should NOT be cleared
+        assert_eq!(app.list_view.issue_count(), 1);
 
-        // Close help - should return to IssueDetail
-        let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::IssueDetail);
+        // No new notification
+        assert_eq!(app.notifications().len(), initial_notification_count);
     }
 
     #[test]
-    fn test_help_does_not_open_in_create_issue() {
-        let mut app = App::new();
-        app.update(Event::Tick); // Transition to IssueList
+    fn test_show_profile_picker_multiple_profiles() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
 
-        // Open create issue form
-        app.open_create_issue_form();
-        assert_eq!(app.state(), AppState::CreateIssue);
+        assert!(!app.is_profile_picker_visible());
 
-        // Press '?' - should NOT open help (it's text input mode)
-        let key = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        // State should still be CreateIssue, not Help
-        assert_eq!(app.state(), AppState::CreateIssue);
-    }
+        app.show_profile_picker();
 
-    #[test]
-    fn test_tick_transitions_from_loading() {
-        let mut app = App::new();
-        assert_eq!(app.state(), AppState::Loading);
-        app.update(Event::Tick);
-        assert_eq!(app.state(), AppState::IssueList);
+        assert!(app.is_profile_picker_visible());
     }
 
     #[test]
-    fn test_quit_event() {
-        let mut app = App::new();
-        app.update(Event::Quit);
-        assert!(app.should_quit());
-        assert_eq!(app.state(), AppState::Exiting);
+    fn test_show_profile_picker_single_profile() {
+        let config = Config {
+            settings: crate::config::Settings::default(),
+            profiles: vec![Profile::new(
+                "only".to_string(),
+                "https://only.atlassian.net".to_string(),
+                "only@example.com".to_string(),
+            )],
+            ..Default::default()
+        };
+        let mut app = App::with_config(config);
+
+        app.show_profile_picker();
+
+        // Picker should not show for single profile
+        assert!(!app.is_profile_picker_visible());
+
+        // Should show notification instead
+        assert!(!app.notifications().is_empty());
     }
 
     #[test]
-    fn test_resize_event() {
-        let mut app = App::new();
-        let initial_state = app.state();
-        app.update(Event::Resize(100, 50));
-        // Resize should not change state
-        assert_eq!(app.state(), initial_state);
-        assert!(!app.should_quit());
+    fn test_show_profile_picker_no_profiles() {
+        let config = Config::default();
+        let mut app = App::with_config(config);
+
+        app.show_profile_picker();
+
+        // Picker should not show for no profiles
+        assert!(!app.is_profile_picker_visible());
+
+        // Should show warning notification
+        assert!(!app.notifications().is_empty());
     }
 
     #[test]
-    fn test_list_view_navigation() {
-        let mut app = App::new();
+    fn test_p_key_opens_profile_picker() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
         app.update(Event::Tick); // Transition to IssueList
 
-        // Add some issues
-        app.list_view.set_issues(vec![
-            create_test_issue("TEST-1", "First"),
-            create_test_issue("TEST-2", "Second"),
-        ]);
-
-        // Navigate down
-        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.list_view.selected_index(), 1);
+        let key_event = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE);
+        app.update(Event::Key(key_event));
 
-        // Navigate up
-        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.list_view.selected_index(), 0);
+        assert!(app.is_profile_picker_visible());
     }
 
     #[test]
-    fn test_open_issue_detail() {
-        let mut app = App::new();
+    fn test_profile_picker_select() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
         app.update(Event::Tick); // Transition to IssueList
 
-        app.list_view
-            .set_issues(vec![create_test_issue("TEST-123", "Test issue")]);
+        // Open profile picker
+        app.show_profile_picker();
+        assert!(app.is_profile_picker_visible());
 
-        // Press Enter to open issue detail
+        // Navigate down (from work to personal) using arrow key
+        let key = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        app.update(Event::Key(key));
+
+        // Select
         let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
         app.update(Event::Key(key));
 
-        assert_eq!(app.state(), AppState::IssueDetail);
-        assert_eq!(app.selected_issue_key(), Some(&"TEST-123".to_string()));
-        // Verify the issue was set in the detail view
-        assert!(app.detail_view().issue().is_some());
-        assert_eq!(app.detail_view().issue().unwrap().key, "TEST-123");
+        // Picker should be hidden
+        assert!(!app.is_profile_picker_visible());
+
+        // Profile should have switched
+        assert_eq!(app.current_profile_name(), Some("personal"));
     }
 
     #[test]
-    fn test_escape_from_detail() {
-        let mut app = App::new();
+    fn test_profile_picker_cancel() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
         app.update(Event::Tick); // Transition to IssueList
 
-        app.list_view
-            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
-
-        // Open issue detail
-        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::IssueDetail);
+        // Open profile picker
+        app.show_profile_picker();
+        assert!(app.is_profile_picker_visible());
 
-        // Press Esc to go back
+        // Cancel with Esc
         let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
         app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::IssueList);
-        // Detail view should be cleared
-        assert!(app.detail_view().issue().is_none());
+
+        // Picker should be hidden
+        assert!(!app.is_profile_picker_visible());
+
+        // Profile should NOT have changed
+        assert_eq!(app.current_profile_name(), Some("work"));
     }
 
     #[test]
-    fn test_q_from_detail_goes_back() {
-        let mut app = App::new();
+    fn test_profile_picker_blocks_other_input() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
         app.update(Event::Tick); // Transition to IssueList
 
-        app.list_view
-            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
+        // Open profile picker
+        app.show_profile_picker();
 
-        // Open issue detail
-        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        // Try to press 'r' (refresh) - should be ignored by the picker
+        let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
         app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::IssueDetail);
 
-        // Press 'q' to go back (not quit, since we're in detail view)
-        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.state(), AppState::IssueList);
-        assert!(!app.should_quit()); // Should not quit, just go back
+        // Picker should still be visible (r is not handled by picker)
+        assert!(app.is_profile_picker_visible());
+        // Profile should NOT have changed
+        assert_eq!(app.current_profile_name(), Some("work"));
     }
 
     #[test]
-    fn test_detail_view_scroll() {
-        let mut app = App::new();
+    fn test_profile_picker_q_cancels() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
         app.update(Event::Tick); // Transition to IssueList
 
-        app.list_view
-            .set_issues(vec![create_test_issue("TEST-1", "Test")]);
-
-        // Open issue detail
-        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
-        app.update(Event::Key(key));
-
-        // Set max_scroll so we can scroll
-        app.detail_view_mut().set_max_scroll(10);
+        // Open profile picker
+        app.show_profile_picker();
+        assert!(app.is_profile_picker_visible());
 
-        // Scroll down with 'j'
-        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        // Press 'q' - should cancel the picker (vim-style)
+        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
         app.update(Event::Key(key));
-        assert_eq!(app.detail_view().scroll(), 1);
 
-        // Scroll up with 'k'
-        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
-        assert_eq!(app.detail_view().scroll(), 0);
+        // Picker should be hidden
+        assert!(!app.is_profile_picker_visible());
     }
 
     #[test]
-    fn test_detail_view_accessors() {
-        let mut app = App::new();
-        let issue = create_test_issue("TEST-1", "Test issue");
+    fn test_profile_clears_detail_view() {
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config);
 
-        app.set_detail_issue(issue);
+        // Set a detail issue
+        app.set_detail_issue(create_test_issue("TEST-123", "Detail issue"));
+        assert!(app.selected_issue_key().is_some());
 
-        assert!(app.detail_view().issue().is_some());
-        assert_eq!(app.detail_view().issue().unwrap().key, "TEST-1");
-        assert_eq!(app.selected_issue_key(), Some(&"TEST-1".to_string()));
+        // Switch profile
+        app.switch_profile("personal").unwrap();
+
+        // Detail should be cleared
+        assert!(app.selected_issue_key().is_none());
     }
 
     #[test]
-    fn test_open_filter_panel() {
-        let mut app = App::new();
-        app.update(Event::Tick); // Transition to IssueList
-
-        // Press 'f' to open filter panel
-        let key = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
-
-        assert_eq!(app.state(), AppState::FilterPanel);
+    fn test_profile_count() {
+        let config = create_test_config_with_profiles();
+        let app = App::with_config(config);
+        assert_eq!(app.profile_count(), 3);
     }
 
     #[test]
-    fn test_refresh_sets_loading() {
-        let mut app = App::new();
-        app.update(Event::Tick); // Transition to IssueList
-
-        // Clear loading state first
-        app.list_view.set_loading(false);
-        assert!(!app.list_view.is_loading());
-
-        // Press 'r' to refresh
-        let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
+    fn test_config_accessor() {
+        let config = create_test_config_with_profiles();
+        let app = App::with_config(config);
 
-        assert!(app.list_view.is_loading());
+        let config = app.config();
+        assert_eq!(config.profiles.len(), 3);
+        assert_eq!(config.settings.default_profile, Some("work".to_string()));
     }
 
+    // ========================================================================
+    // List column tests
+    // ========================================================================
+
     #[test]
-    fn test_list_view_accessors() {
-        let mut app = App::new();
+    fn test_columns_resolved_from_profile_and_saved_filter() {
+        use crate::api::columns::ColumnConfig;
 
-        // Test mutable accessor
-        app.list_view_mut()
-            .set_profile_name(Some("work".to_string()));
+        let mut config = create_test_config_with_profiles();
+        config.settings.columns = vec![ColumnConfig::new("key"), ColumnConfig::new("summary")];
+        config.profiles[1].columns = Some(vec![ColumnConfig::new("key")]);
+        let mut saved = SavedFilter::new("Sprint", FilterState::new());
+        saved.columns = Some(vec![ColumnConfig::new("key"), ColumnConfig::new("sprint")]);
+
+        // Default profile has no columns, so settings apply
+        let mut app = App::with_config(config);
+        assert_eq!(app.list_view().columns().len(), 2);
+
+        // Profile columns override settings
+        app.switch_profile("personal").unwrap();
+        assert_eq!(app.list_view().columns().len(), 1);
 
-        // Test immutable accessor
-        assert_eq!(app.list_view().issue_count(), 0);
+        // Saved filter columns override profile columns
+        app.apply_saved_filter(saved);
+        assert_eq!(app.list_view().columns()[1].field, ColumnField::Sprint);
+
+        // Applying a plain filter restores the profile columns
+        app.apply_filter(FilterState::new());
+        assert_eq!(app.list_view().columns().len(), 1);
     }
 
     // ========================================================================
-    // Notification and error handling tests
+    // Tab tests
     // ========================================================================
 
     #[test]
-    fn test_notify_info() {
-        let mut app = App::new();
-        app.notify_info("Test info message");
-        assert_eq!(app.notifications().len(), 1);
+    fn test_tabs_restored_from_profile() {
+        let mut config = create_test_config_with_profiles();
+        config.profile_state_mut("work").tabs = vec![
+            TabConfig::default(),
+            TabConfig {
+                jql: Some("type = Bug".to_string()),
+                sort: Some("updated".to_string()),
+                sort_ascending: true,
+                ..TabConfig::default()
+            },
+        ];
+        config.profile_state_mut("work").active_tab = 1;
+        let expected = config.profile_state_mut("work").tabs.clone();
+
+        let app = App::with_config(config);
+
+        assert_eq!(app.tab_count(), 2);
+        assert_eq!(app.active_tab(), 1);
+        assert_eq!(app.current_jql(), Some("type = Bug"));
+        assert_eq!(app.list_view().sort().column, ColumnField::Updated);
+        assert_eq!(app.list_view().sort().direction, SortDirection::Ascending);
+        assert_eq!(app.tab_titles(), vec!["My Issues", "type = Bug"]);
+        assert_eq!(app.tab_configs(), expected);
     }
 
     #[test]
-    fn test_notify_success() {
-        let mut app = App::new();
-        app.notify_success("Operation completed");
-        assert_eq!(app.notifications().len(), 1);
+    fn test_single_default_tab_not_persisted() {
+        let app = App::with_config(create_test_config_with_profiles());
+        assert_eq!(app.tab_count(), 1);
+        assert!(app.tab_configs().is_empty());
     }
 
     #[test]
-    fn test_notify_warning() {
-        let mut app = App::new();
-        app.notify_warning("Warning message");
-        assert_eq!(app.notifications().len(), 1);
+    fn test_tabs_keep_their_own_query_and_list() {
+        let mut app = App::with_config(Config::default());
+        app.current_jql = Some("project = A".to_string());
+        app.list_view
+            .set_issues(vec![create_test_issue("A-1", "First")]);
+        app.take_pending_tab_fetch();
+
+        app.new_tab();
+        assert_eq!(app.tab_count(), 2);
+        assert_eq!(app.active_tab(), 1);
+        assert!(app.current_jql().is_none());
+        assert_eq!(app.list_view().issue_count(), 0);
+        assert!(app.take_pending_tab_fetch());
+
+        app.prev_tab();
+        assert_eq!(app.active_tab(), 0);
+        assert_eq!(app.current_jql(), Some("project = A"));
+        assert_eq!(app.list_view().issue_count(), 1);
+        // Already loaded, so no refetch
+        assert!(!app.take_pending_tab_fetch());
+
+        // Wraps around
+        app.prev_tab();
+        assert_eq!(app.active_tab(), 1);
+        app.next_tab();
+        assert_eq!(app.active_tab(), 0);
     }
 
     #[test]
-    fn test_notify_error() {
-        let mut app = App::new();
-        app.notify_error("Error message");
-        assert_eq!(app.notifications().len(), 1);
+    fn test_fetch_results_belong_to_their_tab() {
+        let mut app = App::with_config(Config::default());
+        app.current_jql = Some("project = A".to_string());
+        let first = app.begin_issue_fetch();
+
+        // A second tab with the same query starts its own fetch
+        app.new_tab();
+        app.current_jql = Some("project = A".to_string());
+        let second = app.begin_issue_fetch();
+        assert!(app.is_current_fetch(second));
+        assert!(!app.is_current_fetch(first));
+
+        // Switching back, only the first tab's fetch is shown
+        app.prev_tab();
+        assert!(app.is_current_fetch(first));
+        assert!(!app.is_current_fetch(second));
+
+        // A newer fetch in the same tab supersedes the older one
+        let third = app.begin_issue_fetch();
+        assert!(!app.is_current_fetch(first));
+        assert!(app.is_current_fetch(third));
     }
 
     #[test]
-    fn test_error_dialog_show_hide() {
-        let mut app = App::new();
-        assert!(!app.is_error_dialog_visible());
+    fn test_tab_position_persisted_and_restored() {
+        let mut config = create_test_config_with_profiles();
+        config.profile_state_mut("personal").tabs = vec![TabConfig {
+            jql: Some("project = A".to_string()),
+            selected: Some("A-2".to_string()),
+            ..TabConfig::default()
+        }];
+        let mut app = App::with_config(config);
+        app.switch_profile("personal").unwrap();
 
-        app.show_error_dialog("Error", "Something went wrong");
-        assert!(app.is_error_dialog_visible());
+        app.list_view.set_issues(vec![
+            create_test_issue("A-1", "First"),
+            create_test_issue("A-2", "Second"),
+        ]);
+        assert_eq!(app.list_view().selected_issue().unwrap().key, "A-2");
+        assert_eq!(app.tab_configs()[0].selected.as_deref(), Some("A-2"));
 
-        app.dismiss_error_dialog();
-        assert!(!app.is_error_dialog_visible());
+        // Only the first fetch restores the position
+        app.list_view.set_issues(vec![
+            create_test_issue("A-1", "First"),
+            create_test_issue("A-2", "Second"),
+        ]);
+        assert_eq!(app.list_view().selected_issue().unwrap().key, "A-1");
     }
 
     #[test]
-    fn test_error_dialog_blocks_input() {
-        let mut app = App::new();
-        app.update(Event::Tick); // Transition to IssueList
-        assert_eq!(app.state(), AppState::IssueList);
+    fn test_local_config_pins_profile_and_query() {
+        use crate::config::LocalConfig;
+
+        let mut config = create_test_config_with_profiles();
+        config.profile_state_mut("personal").tabs = vec![TabConfig {
+            jql: Some("type = Bug".to_string()),
+            ..TabConfig::default()
+        }];
+        config.local = Some(LocalConfig {
+            profile: Some("personal".to_string()),
+            jql: Some("project = PROJ".to_string()),
+            ..Default::default()
+        });
 
-        // Show error dialog
-        app.show_error_dialog("Error", "Test error");
-        assert!(app.is_error_dialog_visible());
+        let app = App::with_config(config.clone());
+        assert_eq!(app.current_profile().unwrap().name, "personal");
+        // The saved tab is kept and the pinned query opens in a new one
+        assert_eq!(app.tab_count(), 2);
+        assert_eq!(app.active_tab(), 1);
+        assert_eq!(app.current_jql(), Some("project = PROJ"));
+
+        // An open tab with the pinned query is reused
+        config.profile_state_mut("personal").tabs.push(TabConfig {
+            jql: Some("project = PROJ".to_string()),
+            ..TabConfig::default()
+        });
+        let app = App::with_config(config);
+        assert_eq!(app.tab_count(), 2);
+        assert_eq!(app.current_jql(), Some("project = PROJ"));
+    }
 
-        // Try to quit with 'q' - should be blocked by error dialog
-        let key_event = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
-        app.update(Event::Key(key_event));
-        assert!(!app.should_quit()); // Should NOT quit
-        assert!(app.is_error_dialog_visible()); // Dialog still visible
+    #[test]
+    fn test_open_query_with_aliases() {
+        let mut config = Config::default();
+        config.aliases.insert("mine", "assignee = currentUser()");
+        config
+            .aliases
+            .insert("recent", "updated >= -7d ORDER BY updated DESC");
+        let mut app = App::with_config(config);
 
-        // Dismiss with Esc
-        let key_event = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
-        app.update(Event::Key(key_event));
-        assert!(!app.is_error_dialog_visible());
+        app.open_query("@mine AND project = A".to_string());
+        assert_eq!(app.tab_count(), 1);
+        // The tab keeps the query as written and runs the expansion
+        assert_eq!(app.current_jql(), Some("@mine AND project = A"));
+        assert!(app
+            .search_jql()
+            .unwrap()
+            .starts_with("(assignee = currentUser()) AND project = A ORDER BY"));
+
+        app.open_query("@recent".to_string());
+        assert_eq!(app.tab_count(), 2);
+        assert_eq!(
+            app.search_jql().unwrap(),
+            "updated >= -7d ORDER BY updated DESC"
+        );
+
+        // An unknown alias is reported and opens nothing
+        app.open_query("@nope".to_string());
+        assert_eq!(app.tab_count(), 2);
+        assert_eq!(app.notifications().len(), 1);
     }
 
     #[test]
-    fn test_error_dialog_dismiss_with_enter() {
-        let mut app = App::new();
-        app.show_error_dialog("Error", "Test");
+    fn test_query_with_removed_alias_is_not_run() {
+        let mut config = Config::default();
+        config.aliases.insert("mine", "assignee = currentUser()");
+        let mut app = App::with_config(config);
+        app.open_query("@mine".to_string());
+        app.list_view
+            .set_issues(vec![create_test_issue("A-1", "First")]);
 
-        let key_event = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
-        app.update(Event::Key(key_event));
-        assert!(!app.is_error_dialog_visible());
+        app.config.aliases.remove("mine");
+        let error = app.search_jql().unwrap_err();
+        assert!(error.contains("mine"));
+
+        app.handle_invalid_query(&error);
+        assert_eq!(app.list_view().issue_count(), 0);
+        assert!(!app.list_view().is_loading());
+        assert_eq!(app.notifications().len(), 1);
     }
 
     #[test]
-    fn test_loading_indicator() {
-        let mut app = App::new();
+    fn test_jql_input_fetches_autocomplete_data_once() {
+        let mut app = App::with_config(Config::default());
+        app.open_jql_input();
+        assert!(app.take_pending_jql_autocomplete());
+        app.open_jql_input();
+        assert!(!app.take_pending_jql_autocomplete());
 
-        // App starts with loading active
-        assert!(app.is_loading());
+        // A failed fetch is retried the next time the input opens
+        app.handle_jql_autocomplete_fetched(Err("No JIRA connection".to_string()));
+        app.open_jql_input();
+        assert!(app.take_pending_jql_autocomplete());
 
-        // Stop loading
-        app.stop_loading();
-        assert!(!app.is_loading());
+        app.handle_jql_autocomplete_fetched(Ok(JqlAutocompleteData::default()));
+        app.open_jql_input();
+        assert!(!app.take_pending_jql_autocomplete());
 
-        // Start loading with message
-        app.start_loading("Fetching data...");
-        assert!(app.is_loading());
+        // Another server has other fields
+        app.jql_input.reset_autocomplete();
+        app.open_jql_input();
+        assert!(app.take_pending_jql_autocomplete());
     }
 
     #[test]
-    fn test_loading_stops_on_tick() {
-        let mut app = App::new();
-        assert!(app.is_loading());
-        assert_eq!(app.state(), AppState::Loading);
+    fn test_local_config_project_filters_blank_tab() {
+        use crate::config::LocalConfig;
 
-        // Tick should transition state and stop loading
-        app.update(Event::Tick);
-        assert_eq!(app.state(), AppState::IssueList);
-        assert!(!app.is_loading());
+        let config = Config {
+            local: Some(LocalConfig {
+                project: Some("PROJ".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut app = App::with_config(config);
+        assert_eq!(app.tab_count(), 1);
+        assert_eq!(app.filter_state.project.as_deref(), Some("PROJ"));
+
+        app.filter_state.project = None;
+        app.open_create_issue_form();
+        assert_eq!(app.create_issue_form().project_key, "PROJ");
     }
 
     #[test]
-    fn test_notifications_tick() {
-        let mut app = App::new();
-        app.notify_info("Test");
-        assert_eq!(app.notifications().len(), 1);
+    fn test_profile_settings_apply_on_switch() {
+        let mut config = create_test_config_with_profiles();
+        config.profiles[1].settings.page_size = Some(100);
+        config.profiles[1].settings.confirm_transitions = Some(true);
 
-        // Notifications with short duration will be cleared after tick
-        // (Our default is 3 seconds, so this test just verifies tick runs)
-        app.update(Event::Tick);
-        // Notification should still exist (hasn't expired yet)
-        assert_eq!(app.notifications().len(), 1);
-    }
+        let mut app = App::with_config(config);
+        assert_eq!(app.settings().page_size, 50);
+        assert!(!app.settings().confirm_transitions);
 
-    #[test]
-    fn test_notifications_mut() {
-        let mut app = App::new();
-        app.notifications_mut().info("Direct access");
-        assert_eq!(app.notifications().len(), 1);
+        app.switch_profile("personal").unwrap();
+        assert_eq!(app.settings().page_size, 100);
+        assert!(app.settings().confirm_transitions);
+        assert_eq!(app.list_view().pagination().page_size, 100);
+        // The global settings are unchanged
+        assert_eq!(app.config().settings.page_size, 50);
     }
 
     #[test]
-    fn test_loading_mut() {
-        let mut app = App::new();
-        app.loading_mut().set_message("Custom message");
-        assert_eq!(app.loading().message(), "Custom message");
-    }
+    fn test_config_load_error_shows_dialog() {
+        let error = toml::from_str::<Config>("[settings\n").unwrap_err();
+        let mut app = App::with_loaded_config(Err(ConfigError::ParseError(error)));
 
-    // ========================================================================
-    // Profile switching tests
-    // ========================================================================
+        assert!(app.config().load_failed);
+        assert!(app.current_profile().is_none());
+        assert!(app.is_config_error_dialog_visible());
 
-    fn create_test_config_with_profiles() -> Config {
-        Config {
-            settings: crate::config::Settings {
-                default_profile: Some("work".to_string()),
-                ..Default::default()
-            },
-            profiles: vec![
-                Profile::new(
-                    "work".to_string(),
-                    "https://work.atlassian.net".to_string(),
-                    "work@example.com".to_string(),
-                ),
-                Profile::new(
-                    "personal".to_string(),
-                    "https://personal.atlassian.net".to_string(),
-                    "personal@example.com".to_string(),
-                ),
-                Profile::new(
-                    "client".to_string(),
-                    "https://client.atlassian.net".to_string(),
-                    "client@example.com".to_string(),
-                ),
-            ],
-        }
+        // Confirming asks to open the config file
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(!app.is_config_error_dialog_visible());
+        let (path, _) = app.take_pending_config_edit().unwrap();
+        assert_eq!(path, Config::config_path().unwrap());
     }
 
     #[test]
-    fn test_with_config() {
-        let config = create_test_config_with_profiles();
-        let app = App::with_config(config);
+    fn test_apply_config_updates_settings_and_columns() {
+        use crate::api::columns::ColumnConfig;
 
-        assert_eq!(app.profile_count(), 3);
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config.clone());
+        let notifications = app.notifications().len();
+
+        // Reapplying the same configuration (e.g. after a save) does nothing
+        app.apply_config(config.clone());
+        assert_eq!(app.notifications().len(), notifications);
+
+        let mut changed = config;
+        changed.settings.columns = vec![ColumnConfig::new("key"), ColumnConfig::new("summary")];
+        changed.settings.confirm_transitions = true;
+        changed
+            .settings
+            .add_saved_filter(SavedFilter::new("Mine", FilterState::new()));
+        app.apply_config(changed);
+
+        assert!(app.settings().confirm_transitions);
+        assert_eq!(app.list_view().columns().len(), 2);
+        assert_eq!(app.config().settings.saved_filters.len(), 1);
         assert_eq!(app.current_profile_name(), Some("work"));
+        assert_eq!(app.notifications().len(), notifications + 1);
     }
 
     #[test]
-    fn test_current_profile() {
-        let config = create_test_config_with_profiles();
-        let app = App::with_config(config);
+    fn test_apply_config_after_load_error_opens_profile() {
+        let mut app = App::with_config(Config::fallback());
+        assert!(app.current_profile().is_none());
 
-        let profile = app.current_profile().expect("should have current profile");
-        assert_eq!(profile.name, "work");
-        assert_eq!(profile.url, "https://work.atlassian.net");
+        app.apply_config(create_test_config_with_profiles());
+        assert!(!app.config().load_failed);
+        assert_eq!(app.current_profile_name(), Some("work"));
     }
 
     #[test]
-    fn test_switch_profile_success() {
-        let config = create_test_config_with_profiles();
-        let mut app = App::with_config(config);
+    fn test_apply_config_switches_from_removed_profile() {
+        use crate::config::LocalConfig;
 
-        // Add some issues to verify clearing
+        let config = create_test_config_with_profiles();
+        let mut app = App::with_config(config.clone());
+        app.switch_profile("client").unwrap();
         app.list_view
-            .set_issues(vec![create_test_issue("TEST-1", "Test issue")]);
-        assert_eq!(app.list_view.issue_count(), 1);
+            .set_issues(vec![create_test_issue("A-1", "First")]);
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(app.state(), AppState::IssueDetail);
 
-        // Switch profile
-        let result = app.switch_profile("personal");
-        assert!(result.is_ok());
+        // Renaming the active profile opens the default one
+        let mut renamed = config.clone();
+        renamed.profiles[2].name = "customer".to_string();
+        app.apply_config(renamed.clone());
+        assert_eq!(app.current_profile_name(), Some("work"));
+        assert_eq!(app.state(), AppState::IssueList);
+        assert_eq!(
+            app.notifications().iter().last().unwrap().message,
+            "Profile 'client' no longer exists, switched to 'work'"
+        );
 
-        // Verify profile changed
+        // Removing it opens the profile pinned by `.lazyjira.toml`
+        app.switch_profile("customer").unwrap();
+        let mut removed = renamed;
+        removed.profiles.remove(2);
+        removed.local = Some(LocalConfig {
+            profile: Some("personal".to_string()),
+            ..Default::default()
+        });
+        app.apply_config(removed);
         assert_eq!(app.current_profile_name(), Some("personal"));
-
-        // Verify session data cleared
-        assert_eq!(app.list_view.issue_count(), 0);
-        assert!(app.list_view.is_loading());
-
-        // Verify notification was created
-        assert!(app.notifications().len() > 0);
+        assert_eq!(
+            app.notifications().iter().last().unwrap().message,
+            "Profile 'customer' no longer exists, switched to 'personal'"
+        );
     }
 
     #[test]
-    fn test_switch_profile_not_found() {
-        let config = create_test_config_with_profiles();
-        let mut app = App::with_config(config);
+    fn test_close_tab() {
+        let mut app = App::with_config(Config::default());
 
-        let result = app.switch_profile("nonexistent");
-        assert!(result.is_err());
+        // The last tab cannot be closed
+        app.close_tab();
+        assert_eq!(app.tab_count(), 1);
 
-        // Profile should not change
-        assert_eq!(app.current_profile_name(), Some("work"));
+        app.current_jql = Some("project = A".to_string());
+        app.new_tab();
+        app.current_jql = Some("project = B".to_string());
+        app.close_tab();
+
+        assert_eq!(app.tab_count(), 1);
+        assert_eq!(app.active_tab(), 0);
+        assert_eq!(app.current_jql(), Some("project = A"));
     }
 
     #[test]
-    fn test_switch_to_same_profile() {
-        let config = create_test_config_with_profiles();
-        let mut app = App::with_config(config);
-
-        // Add some issues
-        app.list_view
-            .set_issues(vec![create_test_issue("TEST-1", "Test issue")]);
-        let initial_notification_count = app.notifications().len();
+    fn test_close_tab_then_jump_back() {
+        let mut app = App::with_config(Config::default());
+        app.update(Event::Tick);
+        app.current_jql = Some("project = A".to_string());
+        press(&mut app, KeyCode::Char('t'), KeyModifiers::CONTROL);
+        app.current_jql = Some("project = B".to_string());
+        press(&mut app, KeyCode::Char('t'), KeyModifiers::CONTROL);
+        app.current_jql = Some("project = C".to_string());
+        assert_eq!(app.tab_count(), 3);
+
+        // Close the middle tab; the last tab moves down to index 1
+        app.switch_tab(1);
+        press(&mut app, KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(app.tab_count(), 2);
+        assert_eq!(app.current_jql(), Some("project = C"));
+
+        press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(app.active_tab(), 0);
+        assert_eq!(app.current_jql(), Some("project = A"));
+        press(&mut app, KeyCode::Char('i'), KeyModifiers::CONTROL);
+        assert_eq!(app.active_tab(), 1);
+        assert_eq!(app.current_jql(), Some("project = C"));
+    }
 
-        // Switch to same profile should be a no-op
-        let result = app.switch_profile("work");
-        assert!(result.is_ok());
+    #[test]
+    fn test_tab_named_after_saved_filter() {
+        let mut app = App::with_config(Config::default());
+        let filter = FilterState {
+            assignee_is_me: true,
+            ..FilterState::default()
+        };
 
-        // Issues should NOT be cleared
-        assert_eq!(app.list_view.issue_count(), 1);
+        app.apply_saved_filter(SavedFilter::new("Mine", filter.clone()));
+        assert_eq!(app.tab_titles(), vec!["Mine"]);
 
-        // No new notification
-        assert_eq!(app.notifications().len(), initial_notification_count);
+        app.apply_filter(filter);
+        assert_ne!(app.tab_titles(), vec!["Mine"]);
     }
 
     #[test]
-    fn test_show_profile_picker_multiple_profiles() {
-        let config = create_test_config_with_profiles();
-        let mut app = App::with_config(config);
+    fn test_load_more_result_ignored_after_tab_switch() {
+        let mut app = App::with_config(Config::default());
+        app.list_view
+            .set_issues(vec![create_test_issue("A-1", "First")]);
+        app.list_view.pagination_mut().start_loading();
 
-        assert!(!app.is_profile_picker_visible());
+        app.new_tab();
+        app.handle_load_more_success(vec![create_test_issue("A-2", "Second")], 2, false, None);
+        assert_eq!(app.list_view().issue_count(), 0);
 
-        app.show_profile_picker();
+        app.prev_tab();
+        assert_eq!(app.list_view().issue_count(), 1);
+        assert!(!app.list_view().pagination().loading);
+    }
 
-        assert!(app.is_profile_picker_visible());
+    // ========================================================================
+    // Navigation history tests
+    // ========================================================================
+
+    fn press(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+        app.update(Event::Key(KeyEvent::new(code, modifiers)));
     }
 
     #[test]
-    fn test_show_profile_picker_single_profile() {
-        let config = Config {
-            settings: crate::config::Settings::default(),
-            profiles: vec![Profile::new(
-                "only".to_string(),
-                "https://only.atlassian.net".to_string(),
-                "only@example.com".to_string(),
-            )],
-        };
-        let mut app = App::with_config(config);
+    fn test_jump_back_and_forward_restores_scroll() {
+        let mut app = App::new();
+        app.update(Event::Tick);
+        app.list_view.set_issues(vec![
+            create_test_issue("A-1", "First"),
+            create_test_issue("A-2", "Second"),
+        ]);
 
-        app.show_profile_picker();
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        app.detail_view.set_scroll(5);
+        press(&mut app, KeyCode::Char('q'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('j'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(app.detail_view().issue_key(), "A-2");
 
-        // Picker should not show for single profile
-        assert!(!app.is_profile_picker_visible());
+        press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(app.state(), AppState::IssueList);
 
-        // Should show notification instead
-        assert!(app.notifications().len() > 0);
+        press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(app.state(), AppState::IssueDetail);
+        assert_eq!(app.detail_view().issue_key(), "A-1");
+        assert_eq!(app.detail_view().scroll(), 5);
+
+        press(&mut app, KeyCode::Char('i'), KeyModifiers::CONTROL);
+        assert_eq!(app.state(), AppState::IssueList);
+        press(&mut app, KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!(app.detail_view().issue_key(), "A-2");
     }
 
     #[test]
-    fn test_show_profile_picker_no_profiles() {
-        let config = Config::default();
-        let mut app = App::with_config(config);
+    fn test_jump_restores_comments_panel() {
+        let mut app = App::new();
+        app.update(Event::Tick);
+        app.list_view
+            .set_issues(vec![create_test_issue("A-1", "First")]);
 
-        app.show_profile_picker();
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('c'), KeyModifiers::NONE);
+        assert!(app.take_pending_fetch_comments().is_some());
 
-        // Picker should not show for no profiles
-        assert!(!app.is_profile_picker_visible());
+        press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(app.state(), AppState::IssueList);
 
-        // Should show warning notification
-        assert!(app.notifications().len() > 0);
+        press(&mut app, KeyCode::Char('i'), KeyModifiers::CONTROL);
+        assert_eq!(app.state(), AppState::IssueDetail);
+        assert!(app.detail_view().is_comments_panel_visible());
+        assert_eq!(app.take_pending_fetch_comments(), Some("A-1".to_string()));
     }
 
     #[test]
-    fn test_p_key_opens_profile_picker() {
-        let config = create_test_config_with_profiles();
-        let mut app = App::with_config(config);
-        app.update(Event::Tick); // Transition to IssueList
+    fn test_tab_stays_in_label_editor() {
+        let mut app = App::new();
+        app.update(Event::Tick);
+        app.list_view.set_issues(vec![
+            create_test_issue("A-1", "First"),
+            create_test_issue("A-2", "Second"),
+        ]);
 
-        let key_event = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE);
-        app.update(Event::Key(key_event));
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('q'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(app.detail_view().issue_key(), "A-1");
 
-        assert!(app.is_profile_picker_visible());
+        app.detail_view
+            .set_labels(vec!["backend".to_string(), "frontend".to_string()]);
+        assert!(app.detail_view().is_label_editor_visible());
+
+        press(&mut app, KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!(app.state(), AppState::IssueDetail);
+        assert!(app.detail_view().is_label_editor_visible());
     }
 
     #[test]
-    fn test_profile_picker_select() {
-        let config = create_test_config_with_profiles();
-        let mut app = App::with_config(config);
-        app.update(Event::Tick); // Transition to IssueList
-
-        // Open profile picker
-        app.show_profile_picker();
-        assert!(app.is_profile_picker_visible());
-
-        // Navigate down (from work to personal) using arrow key
-        let key = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
-        app.update(Event::Key(key));
+    fn test_open_recent_issue_fetches_unloaded_issue() {
+        let mut app = App::new();
+        app.update(Event::Tick);
 
-        // Select
-        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
-        app.update(Event::Key(key));
+        app.execute_command_action(CommandAction::OpenIssue("B-9".to_string()));
+        assert_eq!(
+            app.take_pending_navigate_to_issue(),
+            Some("B-9".to_string())
+        );
+        assert_eq!(app.state(), AppState::IssueList);
 
-        // Picker should be hidden
-        assert!(!app.is_profile_picker_visible());
+        app.handle_navigate_to_issue_success(create_test_issue("B-9", "Remote"));
+        assert_eq!(app.state(), AppState::IssueDetail);
+        assert_eq!(app.history().recent_issues(), vec![("B-9", "Remote")]);
 
-        // Profile should have switched
-        assert_eq!(app.current_profile_name(), Some("personal"));
+        press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(app.state(), AppState::IssueList);
     }
 
     #[test]
-    fn test_profile_picker_cancel() {
-        let config = create_test_config_with_profiles();
+    fn test_session_kept_in_profile_state() {
+        let mut config = create_test_config_with_profiles();
+        config.profile_state_mut("personal").jql_history = vec!["project = P".to_string()];
+        config.profile_state_mut("personal").recent_commands = vec!["help.show".to_string()];
         let mut app = App::with_config(config);
-        app.update(Event::Tick); // Transition to IssueList
 
-        // Open profile picker
-        app.show_profile_picker();
-        assert!(app.is_profile_picker_visible());
-
-        // Cancel with Esc
-        let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
-        app.update(Event::Key(key));
-
-        // Picker should be hidden
-        assert!(!app.is_profile_picker_visible());
+        app.execute_jql("project = W".to_string());
+        app.new_tab();
+        app.persist_session();
+        let state = app.config().profile_state("work").unwrap();
+        assert_eq!(state.jql_history, vec!["project = W"]);
+        assert_eq!(state.tabs.len(), 2);
+        assert_eq!(state.active_tab, 1);
 
-        // Profile should NOT have changed
-        assert_eq!(app.current_profile_name(), Some("work"));
+        app.switch_profile("personal").unwrap();
+        assert_eq!(app.jql_input.history(), vec!["project = P"]);
+        assert_eq!(app.command_palette.recent_commands(), vec!["help.show"]);
     }
 
     #[test]
-    fn test_profile_picker_blocks_other_input() {
-        let config = create_test_config_with_profiles();
+    fn test_last_view_restored_on_startup() {
+        let mut app = App::with_config(create_test_config_with_profiles());
+        app.update(Event::Tick);
+        app.list_view
+            .set_issues(vec![create_test_issue("A-1", "First")]);
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        app.detail_view.set_scroll(3);
+        app.persist_session();
+
+        let config = app.config().clone();
+        assert!(matches!(
+            config.profile_state("work").unwrap().location,
+            Some(Location::Issue { ref key, scroll: 3, .. }) if key == "A-1"
+        ));
+
         let mut app = App::with_config(config);
-        app.update(Event::Tick); // Transition to IssueList
+        assert_eq!(
+            app.take_pending_navigate_to_issue(),
+            Some("A-1".to_string())
+        );
+        app.update(Event::Tick);
+        app.handle_navigate_to_issue_success(create_test_issue("A-1", "First"));
+        assert_eq!(app.state(), AppState::IssueDetail);
+        assert_eq!(app.detail_view().scroll(), 3);
 
-        // Open profile picker
-        app.show_profile_picker();
+        // Closing the app on the issue list opens the list next time
+        press(&mut app, KeyCode::Char('q'), KeyModifiers::NONE);
+        app.persist_session();
+        assert_eq!(app.config().profile_state("work").unwrap().location, None);
+    }
 
-        // Try to press 'r' (refresh) - should be ignored by the picker
-        let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
+    #[test]
+    fn test_import_conflicts_ask_for_resolution() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lazyjira-team.toml");
+        let mut app = App::with_config(create_test_config_with_profiles());
+        app.export_profile(0, &path).unwrap();
 
-        // Picker should still be visible (r is not handled by picker)
-        assert!(app.is_profile_picker_visible());
-        // Profile should NOT have changed
-        assert_eq!(app.current_profile_name(), Some("work"));
+        app.config.profiles[0].url = "https://changed.atlassian.net".to_string();
+        app.start_import(&path).unwrap();
+        assert!(app.is_import_bundle_dialog_visible());
+
+        // Cancelling leaves the configuration alone
+        press(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        assert!(!app.is_import_bundle_dialog_visible());
+        assert!(app.pending_bundle.is_none());
+        assert_eq!(app.config.profiles[0].url, "https://changed.atlassian.net");
     }
 
     #[test]
-    fn test_profile_picker_q_cancels() {
-        let config = create_test_config_with_profiles();
-        let mut app = App::with_config(config);
-        app.update(Event::Tick); // Transition to IssueList
-
-        // Open profile picker
-        app.show_profile_picker();
-        assert!(app.is_profile_picker_visible());
+    fn test_history_restored_from_profile() {
+        let mut config = create_test_config_with_profiles();
+        config
+            .profile_state_mut("work")
+            .history
+            .visit(Location::Issue {
+                key: "A-7".to_string(),
+                summary: "Saved".to_string(),
+                scroll: 0,
+                panel: None,
+            });
 
-        // Press 'q' - should cancel the picker (vim-style)
-        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
-        app.update(Event::Key(key));
+        let app = App::with_config(config);
 
-        // Picker should be hidden
-        assert!(!app.is_profile_picker_visible());
+        assert_eq!(app.history().recent_issues(), vec![("A-7", "Saved")]);
+        assert_eq!(app.history().current(), Some(&Location::List { tab: 0 }));
     }
 
+    // ========================================================================
+    // Go to issue tests
+    // ========================================================================
+
     #[test]
-    fn test_profile_clears_detail_view() {
-        let config = create_test_config_with_profiles();
-        let mut app = App::with_config(config);
+    fn test_go_to_issue_from_profile_management() {
+        let mut app = App::new();
+        app.update(Event::Tick);
+        app.list_view.set_issues(vec![
+            create_test_issue("A-1", "First"),
+            create_test_issue("A-2", "Second"),
+        ]);
+        app.open_profile_management();
 
-        // Set a detail issue
-        app.set_detail_issue(create_test_issue("TEST-123", "Detail issue"));
-        assert!(app.selected_issue_key().is_some());
+        press(&mut app, KeyCode::Char('g'), KeyModifiers::CONTROL);
+        assert!(app.is_go_to_issue_visible());
+        assert!(app.take_pending_cached_issues());
 
-        // Switch profile
-        app.switch_profile("personal").unwrap();
+        for c in "a-2".chars() {
+            press(&mut app, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
 
-        // Detail should be cleared
-        assert!(app.selected_issue_key().is_none());
+        assert!(!app.is_go_to_issue_visible());
+        assert_eq!(app.state(), AppState::IssueDetail);
+        assert_eq!(app.detail_view().issue_key(), "A-2");
     }
 
     #[test]
-    fn test_profile_count() {
-        let config = create_test_config_with_profiles();
-        let app = App::with_config(config);
-        assert_eq!(app.profile_count(), 3);
+    fn test_go_to_issue_lists_recent_and_cached_issues() {
+        let mut app = App::new();
+        app.update(Event::Tick);
+        app.list_view
+            .set_issues(vec![create_test_issue("A-1", "First")]);
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('q'), KeyModifiers::NONE);
+
+        press(&mut app, KeyCode::Char('g'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('i'), KeyModifiers::NONE);
+        assert!(app.is_go_to_issue_visible());
+        app.add_cached_issues(vec![
+            create_test_issue("A-1", "First"),
+            create_test_issue("C-5", "Cached"),
+        ]);
+
+        let results: Vec<(&str, IssueSource)> = app
+            .go_to_issue
+            .results()
+            .iter()
+            .map(|c| (c.key.as_str(), c.source))
+            .collect();
+        assert_eq!(
+            results,
+            vec![("A-1", IssueSource::Recent), ("C-5", IssueSource::Local)]
+        );
     }
 
     #[test]
-    fn test_config_accessor() {
-        let config = create_test_config_with_profiles();
-        let app = App::with_config(config);
+    fn test_go_to_unloaded_issue_fetches_it() {
+        let mut app = App::new();
+        app.update(Event::Tick);
 
-        let config = app.config();
-        assert_eq!(config.profiles.len(), 3);
-        assert_eq!(config.settings.default_profile, Some("work".to_string()));
+        press(&mut app, KeyCode::Char('g'), KeyModifiers::CONTROL);
+        for c in "B-42".chars() {
+            press(&mut app, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+
+        assert_eq!(
+            app.take_pending_navigate_to_issue(),
+            Some("B-42".to_string())
+        );
     }
 
     // ========================================================================
@@ -4541,14 +7725,18 @@ mod tests {
         let mut app = App::new();
 
         // Manually set pending external edit
-        app.pending_external_edit = Some(("TEST-123".to_string(), "Test description".to_string()));
+        app.pending_external_edit = Some(ExternalEditRequest::description(
+            "TEST-123",
+            "Summary",
+            "Test description",
+        ));
 
         // First take should return the value
         let result = app.take_pending_external_edit();
         assert!(result.is_some());
-        let (key, content) = result.unwrap();
-        assert_eq!(key, "TEST-123");
-        assert_eq!(content, "Test description");
+        let request = result.unwrap();
+        assert_eq!(request.file_key, "TEST-123");
+        assert_eq!(request.content, "Test description");
 
         // Second take should return None (state was cleared)
         assert!(app.take_pending_external_edit().is_none());
@@ -4609,12 +7797,56 @@ mod tests {
         assert!(!app.detail_view().is_editing());
 
         // Apply external edit result
-        app.apply_external_edit_result("New description from external editor".to_string());
+        app.apply_external_edit_result(
+            ExternalEditTarget::IssueDescription,
+            "New description from external editor".to_string(),
+        );
 
         // Should now be in edit mode
         assert!(app.detail_view().is_editing());
     }
 
+    #[test]
+    fn test_external_editor_for_comment_draft() {
+        let mut app = App::new();
+        app.update(Event::Tick);
+        app.list_view
+            .set_issues(vec![create_test_issue("A-1", "First")]);
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('c'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('a'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('h'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('i'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char('x'), KeyModifiers::CONTROL);
+
+        let request = app.take_pending_external_edit().unwrap();
+        assert_eq!(request.target, ExternalEditTarget::Comment);
+        assert_eq!(request.content, "hi");
+        assert!(request.header.starts_with("Comment on A-1: First"));
+
+        app.apply_external_edit_result(request.target, "hi there".to_string());
+        assert!(app.detail_view().is_composing_comment());
+    }
+
+    #[test]
+    fn test_external_editor_for_new_issue_description() {
+        let mut app = App::new();
+        app.open_create_issue_form();
+        app.create_issue_form.project_key = "PROJ".to_string();
+        app.create_issue_focus = CreateIssueFormField::Description;
+        app.create_issue_view.set_description("draft");
+        press(&mut app, KeyCode::Char('x'), KeyModifiers::CONTROL);
+
+        let request = app.take_pending_external_edit().unwrap();
+        assert_eq!(request.target, ExternalEditTarget::NewIssueDescription);
+        assert_eq!(request.content, "draft");
+        assert!(request.header.starts_with("New issue in PROJ"));
+
+        app.apply_external_edit_result(request.target, "from editor".to_string());
+        assert_eq!(app.create_issue_form().description, "from editor");
+        assert_eq!(app.create_issue_view.description(), "from editor");
+    }
+
     // ========================================================================
     // Create Issue Form Tests
     // ========================================================================
@@ -4658,6 +7890,325 @@ mod tests {
         assert!(app.is_fetch_issue_types_pending());
     }
 
+    fn create_test_config_with_templates() -> Config {
+        Config {
+            templates: vec![IssueTemplate {
+                name: "Bug report".to_string(),
+                project: Some("PROJ".to_string()),
+                issue_type: Some("bug".to_string()),
+                summary_prefix: "[Bug] ".to_string(),
+                description: "Steps to reproduce:\n".to_string(),
+                labels: vec!["bug".to_string()],
+                components: vec!["Backend".to_string()],
+                priority: Some("High".to_string()),
+                assignee: Some("abc123".to_string()),
+                assignee_name: None,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn issue_type(id: &str, name: &str) -> crate::api::types::IssueTypeMeta {
+        crate::api::types::IssueTypeMeta {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            subtask: false,
+            hierarchy_level: Some(0),
+        }
+    }
+
+    #[test]
+    fn test_start_create_issue_without_templates_opens_form() {
+        let mut app = App::with_config(Config::default());
+        app.start_create_issue();
+        assert!(!app.is_template_picker_visible());
+        assert_eq!(app.state(), AppState::CreateIssue);
+    }
+
+    #[test]
+    fn test_start_create_issue_shows_template_picker() {
+        let mut app = App::with_config(create_test_config_with_templates());
+        app.start_create_issue();
+        assert!(app.is_template_picker_visible());
+
+        // Esc cancels issue creation
+        app.update(Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+        assert!(!app.is_template_picker_visible());
+        assert_ne!(app.state(), AppState::CreateIssue);
+
+        // Enter on the first entry starts a blank issue
+        app.start_create_issue();
+        app.update(Event::Key(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(app.state(), AppState::CreateIssue);
+        assert!(app.create_issue_form().template_name.is_none());
+        assert!(app.create_issue_form().summary.is_empty());
+    }
+
+    #[test]
+    fn test_create_issue_from_template() {
+        let mut app = App::with_config(create_test_config_with_templates());
+        app.open_create_issue_form_from_template("Bug report");
+
+        assert_eq!(app.state(), AppState::CreateIssue);
+        let form = app.create_issue_form();
+        assert_eq!(form.template_name.as_deref(), Some("Bug report"));
+        assert_eq!(form.project_key, "PROJ");
+        assert_eq!(form.summary, "[Bug] ");
+        assert_eq!(form.description, "Steps to reproduce:\n");
+        assert_eq!(form.assignee_id.as_deref(), Some("abc123"));
+        assert_eq!(app.create_issue_focus(), CreateIssueFormField::Summary);
+        assert!(app.is_fetch_issue_types_pending());
+
+        // The issue type is matched by name once the types load
+        app.handle_issue_types_fetched(vec![issue_type("1", "Story"), issue_type("2", "Bug")]);
+        assert_eq!(app.create_issue_form().issue_type_id, "2");
+        assert_eq!(app.create_issue_form().issue_type_name, "Bug");
+
+        let request = serde_json::to_value(app.build_create_issue_request()).unwrap();
+        let fields = &request["fields"];
+        assert_eq!(fields["labels"], serde_json::json!(["bug"]));
+        assert_eq!(
+            fields["components"],
+            serde_json::json!([{"name": "Backend"}])
+        );
+        assert_eq!(fields["priority"], serde_json::json!({"name": "High"}));
+        assert_eq!(
+            fields["assignee"],
+            serde_json::json!({"accountId": "abc123"})
+        );
+    }
+
+    fn create_field(
+        field_id: &str,
+        name: &str,
+        field_type: &str,
+        required: bool,
+    ) -> CreateFieldMeta {
+        serde_json::from_value(serde_json::json!({
+            "fieldId": field_id,
+            "name": name,
+            "required": required,
+            "schema": {"type": field_type, "items": "string"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_create_fields_are_fetched_for_issue_type() {
+        let mut app = App::with_config(create_test_config_with_templates());
+        app.open_create_issue_form_from_template("Bug report");
+        app.handle_issue_types_fetched(vec![issue_type("1", "Story"), issue_type("2", "Bug")]);
+        assert_eq!(
+            app.take_pending_fetch_create_fields(),
+            Some(("PROJ".to_string(), "2".to_string()))
+        );
+
+        // Stale results for another issue type are ignored
+        app.handle_create_fields_fetched(
+            "PROJ",
+            "1",
+            vec![create_field("duedate", "Due date", "date", true)],
+        );
+        assert!(app.create_issue_view.create_fields().is_empty());
+
+        app.handle_create_fields_fetched(
+            "PROJ",
+            "2",
+            vec![
+                create_field("labels", "Labels", "array", false),
+                create_field("duedate", "Due date", "date", true),
+            ],
+        );
+        assert_eq!(app.create_issue_view.create_fields().len(), 2);
+        // Template labels move into the labels field
+        assert!(app.create_issue_form().labels.is_empty());
+
+        // The required due date blocks submit instead of failing server-side
+        app.create_issue_form_mut().summary = "Crash on start".to_string();
+        assert!(!app.validate_create_issue_form());
+        assert_eq!(app.create_issue_errors(), ["Due date is required"]);
+
+        let request = serde_json::to_value(app.build_create_issue_request()).unwrap();
+        assert_eq!(request["fields"]["labels"], serde_json::json!(["bug"]));
+        assert!(request["fields"].get("duedate").is_none());
+    }
+
+    #[test]
+    fn test_create_fields_merge_with_template_values() {
+        let mut app = App::with_config(create_test_config_with_templates());
+        app.open_create_issue_form_from_template("Bug report");
+        app.handle_issue_types_fetched(vec![issue_type("2", "Bug")]);
+
+        let mut components = create_field("components", "Components", "array", false);
+        components.schema.items = Some("component".to_string());
+        components.allowed_values = vec![crate::api::types::AllowedValue::new("11", "Frontend")];
+        app.handle_create_fields_fetched(
+            "PROJ",
+            "2",
+            vec![create_field("labels", "Labels", "array", false), components],
+        );
+        // "Backend" is not an allowed value, so it stays on the form
+        assert!(app.create_issue_form().labels.is_empty());
+        assert_eq!(app.create_issue_form().components, vec!["Backend"]);
+
+        app.create_issue_view
+            .create_fields_mut()
+            .prefill("components", &["Frontend".to_string()]);
+        app.create_issue_form_mut()
+            .labels
+            .push("triage".to_string());
+
+        let request = serde_json::to_value(app.build_create_issue_request()).unwrap();
+        let fields = &request["fields"];
+        assert_eq!(fields["labels"], serde_json::json!(["triage", "bug"]));
+        assert_eq!(
+            fields["components"],
+            serde_json::json!([{"id": "11"}, {"name": "Backend"}])
+        );
+    }
+
+    #[test]
+    fn test_create_fields_focus_is_skipped_when_empty() {
+        let mut app = App::with_config(Config::default());
+        app.start_create_issue();
+        app.create_issue_focus = CreateIssueFormField::Priority;
+        app.create_issue_focus_next();
+        assert_eq!(app.create_issue_focus(), CreateIssueFormField::Submit);
+        app.create_issue_focus_prev();
+        assert_eq!(app.create_issue_focus(), CreateIssueFormField::Priority);
+    }
+
+    fn create_detail_issue() -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "id": "10001",
+            "key": "PROJ-1",
+            "self": "https://example.com",
+            "fields": {
+                "summary": "Login fails",
+                "status": {"id": "1", "name": "Open"},
+                "issuetype": {"id": "2", "name": "Bug"},
+                "priority": {"id": "3", "name": "High"},
+                "project": {"id": "100", "key": "PROJ", "name": "Project"},
+                "labels": ["auth"],
+                "issuelinks": [{
+                    "id": "500",
+                    "type": {"id": "1", "name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+                    "outwardIssue": {
+                        "id": "10002",
+                        "key": "PROJ-2",
+                        "fields": {"summary": "Release", "status": {"id": "1", "name": "Open"}}
+                    }
+                }],
+                "subtasks": [{
+                    "id": "10003",
+                    "key": "PROJ-3",
+                    "fields": {
+                        "summary": "Add test",
+                        "status": {"id": "1", "name": "Open"},
+                        "issuetype": {"id": "5", "name": "Sub-task", "subtask": true}
+                    }
+                }]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_clone_issue_from_detail_view() {
+        let mut app = App::with_config(Config::default());
+        app.set_detail_issue(create_detail_issue());
+        app.state = AppState::IssueDetail;
+
+        press(&mut app, KeyCode::Char('Y'), KeyModifiers::SHIFT);
+        assert!(app.is_clone_options_visible());
+        // Copy the subtasks but not the links
+        press(&mut app, KeyCode::Char('j'), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Char(' '), KeyModifiers::NONE);
+        press(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+
+        assert_eq!(app.state(), AppState::CreateIssue);
+        let form = app.create_issue_form();
+        assert_eq!(form.clone_of.as_deref(), Some("PROJ-1"));
+        assert_eq!(form.project_key, "PROJ");
+        assert_eq!(form.summary, "CLONE - Login fails");
+        assert_eq!(form.labels, vec!["auth"]);
+        assert_eq!(form.priority_id.as_deref(), Some("3"));
+        assert!(app.is_fetch_issue_types_pending());
+
+        app.handle_issue_types_fetched(vec![issue_type("1", "Story"), issue_type("2", "Bug")]);
+        assert_eq!(app.create_issue_form().issue_type_id, "2");
+
+        app.handle_create_issue_success(crate::api::types::CreateIssueResponse {
+            id: "10009".to_string(),
+            key: "PROJ-9".to_string(),
+            self_url: String::new(),
+        });
+        let (key, links, subtasks) = app.take_pending_copy_to_clone().unwrap();
+        assert_eq!(key, "PROJ-9");
+        assert!(links.is_empty());
+        assert_eq!(subtasks.len(), 1);
+        let subtask = serde_json::to_value(&subtasks[0]).unwrap();
+        assert_eq!(subtask["fields"]["parent"]["key"], "PROJ-9");
+        assert_eq!(subtask["fields"]["issuetype"]["id"], "5");
+        assert_eq!(subtask["fields"]["project"]["key"], "PROJ");
+
+        // The clone is shown once copying finishes
+        app.handle_copy_to_clone_complete("PROJ-9", Vec::new());
+        assert_eq!(
+            app.take_pending_navigate_to_issue().as_deref(),
+            Some("PROJ-9")
+        );
+    }
+
+    #[test]
+    fn test_create_subtask_from_detail_view() {
+        let mut app = App::with_config(Config::default());
+        app.set_detail_issue(create_detail_issue());
+        app.state = AppState::IssueDetail;
+
+        press(&mut app, KeyCode::Char('S'), KeyModifiers::SHIFT);
+        assert_eq!(app.state(), AppState::CreateIssue);
+        assert_eq!(
+            app.create_issue_form().parent_issue_key.as_deref(),
+            Some("PROJ-1")
+        );
+
+        let mut subtask_type = issue_type("5", "Sub-task");
+        subtask_type.subtask = true;
+        subtask_type.hierarchy_level = Some(-1);
+        app.handle_issue_types_fetched(vec![issue_type("1", "Story"), subtask_type]);
+        assert_eq!(app.create_issue_form().issue_type_id, "5");
+        assert!(app.create_issue_form().is_subtask);
+
+        app.create_issue_form_mut().summary = "Write docs".to_string();
+        let request = serde_json::to_value(app.build_create_issue_request()).unwrap();
+        assert_eq!(request["fields"]["parent"]["key"], "PROJ-1");
+
+        // Cancelling returns to the issue
+        app.close_create_issue_form(false);
+        assert_eq!(app.state(), AppState::IssueDetail);
+    }
+
+    #[test]
+    fn test_create_issue_success_opens_new_issue() {
+        let mut app = App::with_config(Config::default());
+        app.open_create_issue_form();
+        app.handle_create_issue_success(crate::api::types::CreateIssueResponse {
+            id: "10009".to_string(),
+            key: "PROJ-9".to_string(),
+            self_url: String::new(),
+        });
+        assert!(app.take_pending_copy_to_clone().is_none());
+        assert_eq!(
+            app.take_pending_navigate_to_issue().as_deref(),
+            Some("PROJ-9")
+        );
+    }
+
     #[test]
     fn test_close_create_issue_form_without_refresh() {
         let mut app = App::new();
@@ -4701,4 +8252,80 @@ mod tests {
         // List should be loading (refresh triggered)
         assert!(app.list_view().is_loading());
     }
+
+    #[test]
+    fn test_open_releases_from_detail_view() {
+        let mut app = App::with_config(Config::default());
+        app.set_detail_issue(create_detail_issue());
+        app.state = AppState::IssueDetail;
+
+        app.execute_command_action(CommandAction::OpenReleases);
+
+        assert_eq!(app.state(), AppState::Releases);
+        assert_eq!(app.release_view().project_key(), Some("PROJ"));
+        assert_eq!(
+            app.take_pending_fetch_release_versions(),
+            Some("PROJ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_switch_theme_command_opens_theme_picker() {
+        let mut app = App::with_config(Config::default());
+        app.state = AppState::IssueList;
+
+        app.execute_command_action(CommandAction::SwitchTheme);
+        assert!(app.is_theme_picker_visible());
+        assert_eq!(app.theme_picker.selected_theme(), Some("dark"));
+    }
+
+    #[test]
+    fn test_open_releases_without_issue() {
+        let mut app = App::with_config(Config::default());
+        app.state = AppState::IssueList;
+
+        app.open_releases();
+
+        assert_eq!(app.state(), AppState::IssueList);
+        assert!(app.take_pending_fetch_release_versions().is_none());
+    }
+
+    #[test]
+    fn test_release_versions_load_selected_version_issues() {
+        let mut app = App::with_config(Config::default());
+        app.set_detail_issue(create_detail_issue());
+        app.state = AppState::IssueDetail;
+        app.open_releases();
+
+        let version: Version =
+            serde_json::from_value(serde_json::json!({"id": "7", "name": "1.0"})).unwrap();
+
+        // Versions for another project are ignored
+        app.set_release_versions("OTHER", vec![version.clone()]);
+        assert!(app.take_pending_fetch_version_issues().is_none());
+
+        app.set_release_versions("PROJ", vec![version]);
+        assert_eq!(
+            app.take_pending_fetch_version_issues(),
+            Some("7".to_string())
+        );
+
+        // Releasing reloads the versions
+        app.handle_release_version_success("7");
+        assert_eq!(
+            app.take_pending_fetch_release_versions(),
+            Some("PROJ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_release_view_back_to_list() {
+        let mut app = App::with_config(Config::default());
+        app.set_detail_issue(create_detail_issue());
+        app.state = AppState::IssueDetail;
+        app.open_releases();
+
+        press(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(app.state(), AppState::IssueList);
+    }
 }
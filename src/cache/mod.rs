@@ -127,6 +127,7 @@ pub struct CachedSearchResult {
 }
 
 /// Cache manager for storing and retrieving cached data.
+#[derive(Clone)]
 pub struct CacheManager {
     /// Base directory for cache storage.
     base_dir: PathBuf,
@@ -224,6 +225,42 @@ impl CacheManager {
         self.check_cache_size()
     }
 
+    /// Get every unexpired cached issue, from both single issue entries and
+    /// cached search results.
+    ///
+    /// Each issue appears once, even if it is in several cached searches.
+    pub fn cached_issues(&self) -> Vec<Issue> {
+        let mut issues: Vec<Issue> = Vec::new();
+
+        for path in self.cache_files("issues") {
+            if let Some(issue) = self.read_cache::<Issue>(&path) {
+                issues.push(issue);
+            }
+        }
+        for path in self.cache_files("search_results") {
+            if let Some(cached) = self.read_cache::<CachedSearchResult>(&path) {
+                issues.extend(cached.results.issues);
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        issues.retain(|issue| seen.insert(issue.key.clone()));
+        issues
+    }
+
+    /// List the cache files in a subdirectory of the profile cache.
+    fn cache_files(&self, subdir: &str) -> Vec<PathBuf> {
+        fs::read_dir(self.profile_dir().join(subdir))
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Read a cache entry from disk.
     fn read_cache<T: DeserializeOwned>(&self, path: &Path) -> Option<T> {
         let content = match fs::read_to_string(path) {
@@ -477,6 +514,7 @@ mod tests {
                 issue_links: vec![],
                 subtasks: vec![],
                 parent: None,
+                extra_fields: Default::default(),
             },
         }
     }
@@ -549,6 +587,32 @@ mod tests {
         assert_eq!(cached.fields.summary, "Test issue");
     }
 
+    #[test]
+    fn test_cached_issues_combines_issues_and_searches() {
+        let manager = create_test_cache_manager();
+        manager
+            .set_issue(&create_test_issue("TEST-1", "Single"))
+            .unwrap();
+        let results = SearchResult {
+            issues: vec![
+                create_test_issue("TEST-1", "Single"),
+                create_test_issue("TEST-2", "From search"),
+            ],
+            start_at: 0,
+            max_results: 50,
+            total: 2,
+            next_page_token: None,
+            is_last: true,
+        };
+        manager
+            .set_search_results("project = TEST", &results)
+            .unwrap();
+
+        let mut keys: Vec<String> = manager.cached_issues().into_iter().map(|i| i.key).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["TEST-1", "TEST-2"]);
+    }
+
     #[test]
     fn test_cache_issue_not_found() {
         let manager = create_test_cache_manager();
@@ -0,0 +1,277 @@
+//! Command-line interface.
+//!
+//! Without a subcommand LazyJira starts the TUI. Subcommands run without it
+//! and print their output to stdout.
+
+use std::fs;
+use std::path::Path;
+
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+
+use crate::config::{self, Config, ConfigError, ProfileState, Result, Settings, CURRENT_VERSION};
+
+/// Command-line arguments.
+#[derive(Debug, Parser)]
+#[command(
+    name = "lazyjira",
+    version,
+    about = "A terminal UI for JIRA",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    /// Subcommand to run instead of the TUI.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Open the TUI with a JQL query, which may use `@aliases`.
+    #[arg(long, value_name = "QUERY")]
+    pub jql: Option<String>,
+}
+
+/// Subcommands.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Inspect or migrate the configuration.
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+/// `config` subcommands.
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Print the configuration.
+    Show {
+        /// Print the settings after applying profile and local overrides.
+        #[arg(long)]
+        effective: bool,
+
+        /// Profile whose overrides apply (defaults to the startup profile).
+        #[arg(long, requires = "effective")]
+        profile: Option<String>,
+    },
+    /// Migrate the config file to the current schema version.
+    ///
+    /// The original file is backed up beside it. Loading an old config file
+    /// migrates it too; this shows what changes.
+    Migrate {
+        /// Print the changes without writing anything.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+impl Command {
+    /// Run the subcommand, returning its output.
+    pub fn run(&self) -> Result<String> {
+        match self {
+            Command::Config { command } => command.run(),
+        }
+    }
+}
+
+impl ConfigCommand {
+    /// Run the subcommand.
+    ///
+    /// Loading migrates the config file, so `migrate` reads it directly.
+    fn run(&self) -> Result<String> {
+        match self {
+            ConfigCommand::Show {
+                effective: false, ..
+            } => toml::to_string_pretty(&Config::load()?).map_err(ConfigError::SerializeError),
+            ConfigCommand::Show {
+                effective: true,
+                profile,
+            } => show_effective(&Config::load()?, profile.as_deref()),
+            ConfigCommand::Migrate { dry_run } => migrate(&Config::config_path()?, *dry_run),
+        }
+    }
+}
+
+/// The effective configuration printed by `config show --effective`.
+#[derive(Serialize)]
+struct EffectiveConfig<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jql: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<&'a str>,
+    settings: &'a Settings,
+}
+
+/// Render the effective settings for a profile, preceded by the layers they
+/// were built from.
+fn show_effective(config: &Config, profile: Option<&str>) -> Result<String> {
+    let profile = match profile {
+        Some(name) => Some(
+            config
+                .get_profile(name)
+                .ok_or_else(|| ConfigError::ProfileNotFound(name.to_string()))?,
+        ),
+        None => config.startup_profile(),
+    };
+    let profile_name = profile.map(|p| p.name.as_str());
+    let settings = config.effective_settings(profile_name);
+    let local = config.local.as_ref();
+
+    let mut layers = vec!["defaults".to_string()];
+    if let Ok(path) = Config::config_path() {
+        if path.exists() {
+            layers.push(path.display().to_string());
+        }
+    }
+    if let Some(profile) = profile.filter(|p| !p.settings.is_empty()) {
+        layers.push(format!("profile '{}'", profile.name));
+    }
+    if let Some(local) = local {
+        layers.push(local.path.display().to_string());
+    }
+
+    let effective = EffectiveConfig {
+        profile: profile_name,
+        jql: local.and_then(|l| l.jql.as_deref()),
+        project: local.and_then(|l| l.project.as_deref()),
+        settings: &settings,
+    };
+    let body = toml::to_string_pretty(&effective).map_err(ConfigError::SerializeError)?;
+    Ok(format!(
+        "# Effective configuration\n# Layers: {}\n\n{}",
+        layers.join(" → "),
+        body
+    ))
+}
+
+/// Migrate the config file at `path`, or describe the migration for a dry run.
+fn migrate(path: &Path, dry_run: bool) -> Result<String> {
+    if !path.exists() {
+        return Ok(format!("No config file at {}\n", path.display()));
+    }
+    let content = fs::read_to_string(path).map_err(ConfigError::ReadError)?;
+    let Some(migration) = config::migrate(&content)? else {
+        return Ok(format!(
+            "{} is up to date (version {})\n",
+            path.display(),
+            CURRENT_VERSION
+        ));
+    };
+
+    let mut output = format!(
+        "{} {} from version {} to {}:\n",
+        if dry_run { "Would migrate" } else { "Migrated" },
+        path.display(),
+        migration.from,
+        CURRENT_VERSION
+    );
+    for change in &migration.changes {
+        output.push_str(&format!("  - {}\n", change));
+    }
+    if dry_run {
+        output.push_str(&format!(
+            "A backup would be written to {}\n",
+            migration.backup_path(path).display()
+        ));
+    } else {
+        let backup = migration.write(path, &ProfileState::state_dir()?)?;
+        output.push_str(&format!("Backup written to {}\n", backup.display()));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{LocalConfig, Profile};
+
+    fn config() -> Config {
+        let mut work = Profile::new(
+            "work".to_string(),
+            "https://company.atlassian.net".to_string(),
+            "user@company.com".to_string(),
+        );
+        work.settings.page_size = Some(100);
+        Config {
+            profiles: vec![work],
+            local: Some(LocalConfig {
+                jql: Some("project = PROJ".to_string()),
+                path: ".lazyjira.toml".into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_config_show() {
+        let cli = Cli::try_parse_from(["lazyjira", "config", "show", "--effective"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config {
+                command: ConfigCommand::Show {
+                    effective: true,
+                    profile: None
+                }
+            })
+        ));
+
+        assert!(Cli::try_parse_from(["lazyjira"]).unwrap().command.is_none());
+        // --profile only applies to the effective view
+        assert!(Cli::try_parse_from(["lazyjira", "config", "show", "--profile", "work"]).is_err());
+        assert!(matches!(
+            Cli::try_parse_from(["lazyjira", "config", "migrate", "--dry-run"])
+                .unwrap()
+                .command,
+            Some(Command::Config {
+                command: ConfigCommand::Migrate { dry_run: true }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_jql() {
+        let cli = Cli::try_parse_from(["lazyjira", "--jql", "@mine AND project = A"]).unwrap();
+        assert_eq!(cli.jql.as_deref(), Some("@mine AND project = A"));
+        assert!(cli.command.is_none());
+
+        // The query only applies to the TUI
+        assert!(Cli::try_parse_from(["lazyjira", "--jql", "x", "config", "show"]).is_err());
+    }
+
+    #[test]
+    fn test_show_effective() {
+        let output = show_effective(&config(), None).unwrap();
+        assert!(output.contains("profile 'work' → .lazyjira.toml"));
+        assert!(output.contains("profile = \"work\""));
+        assert!(output.contains("jql = \"project = PROJ\""));
+        assert!(output.contains("page_size = 100"));
+    }
+
+    #[test]
+    fn test_show_effective_unknown_profile() {
+        assert!(matches!(
+            show_effective(&config(), Some("missing")),
+            Err(ConfigError::ProfileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_migrate_dry_run_writes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let original = "[settings]\njql_history = [\"project = A\"]\n";
+        fs::write(&path, original).unwrap();
+
+        let output = migrate(&path, true).unwrap();
+        assert!(output.starts_with("Would migrate"));
+        assert!(output.contains("  - removed settings.jql_history\n"));
+        assert!(output.contains("config.toml.v0.bak"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+
+        let output = migrate(&path, false).unwrap();
+        assert!(output.starts_with("Migrated"));
+        assert!(dir.path().join("config.toml.v0.bak").exists());
+        assert!(migrate(&path, true).unwrap().contains("up to date"));
+    }
+}
@@ -41,6 +41,8 @@ pub enum CommandCategory {
     Settings,
     /// Help and documentation.
     Help,
+    /// Recently viewed issues.
+    Recent,
 }
 
 impl CommandCategory {
@@ -53,6 +55,7 @@ impl CommandCategory {
             Self::Filter => "Filter",
             Self::Settings => "Settings",
             Self::Help => "Help",
+            Self::Recent => "Recent",
         }
     }
 }
@@ -78,6 +81,14 @@ pub enum CommandAction {
     ClearFilters,
     /// Clear the issue cache.
     ClearCache,
+    /// Open the go to issue finder.
+    GoToIssue,
+    /// Open an issue's detail view.
+    OpenIssue(String),
+    /// Open the release view for the current issue's project.
+    OpenReleases,
+    /// Open the theme switcher.
+    SwitchTheme,
 }
 
 /// Registry of all available commands with search and history.
@@ -104,6 +115,15 @@ impl CommandRegistry {
                 shortcut: Some("Esc".to_string()),
                 action: CommandAction::GoToList,
             },
+            Command {
+                id: "goto.issue".to_string(),
+                title: "Go to Issue".to_string(),
+                description: Some("Jump to an issue by key or text".to_string()),
+                category: CommandCategory::Navigation,
+                keywords: vec!["open".to_string(), "find".to_string(), "key".to_string()],
+                shortcut: Some("Ctrl+G".to_string()),
+                action: CommandAction::GoToIssue,
+            },
             Command {
                 id: "goto.profiles".to_string(),
                 title: "Manage Profiles".to_string(),
@@ -143,6 +163,19 @@ impl CommandRegistry {
                 shortcut: Some("r".to_string()),
                 action: CommandAction::RefreshIssues,
             },
+            Command {
+                id: "goto.releases".to_string(),
+                title: "Open Releases".to_string(),
+                description: Some("View and release the current project's versions".to_string()),
+                category: CommandCategory::Navigation,
+                keywords: vec![
+                    "versions".to_string(),
+                    "fix version".to_string(),
+                    "release".to_string(),
+                ],
+                shortcut: Some("R".to_string()),
+                action: CommandAction::OpenReleases,
+            },
             Command {
                 id: "filter.jql".to_string(),
                 title: "Enter JQL Query".to_string(),
@@ -191,6 +224,20 @@ impl CommandRegistry {
                 shortcut: None,
                 action: CommandAction::ClearCache,
             },
+            Command {
+                id: "settings.theme".to_string(),
+                title: "Switch Theme".to_string(),
+                description: Some("Preview and change the color theme".to_string()),
+                category: CommandCategory::Settings,
+                keywords: vec![
+                    "colors".to_string(),
+                    "appearance".to_string(),
+                    "dark".to_string(),
+                    "light".to_string(),
+                ],
+                shortcut: None,
+                action: CommandAction::SwitchTheme,
+            },
             Command {
                 id: "help.show".to_string(),
                 title: "Show Help".to_string(),
@@ -228,7 +275,7 @@ impl CommandRegistry {
                 })
                 .collect();
 
-            results.sort_by(|a, b| b.1.cmp(&a.1));
+            results.sort_by_key(|b| std::cmp::Reverse(b.1));
             results.into_iter().map(|(cmd, _)| cmd).collect()
         } else {
             let query_lower = query.to_lowercase();
@@ -245,7 +292,7 @@ impl CommandRegistry {
                 })
                 .collect();
 
-            results.sort_by(|a, b| b.1.cmp(&a.1));
+            results.sort_by_key(|b| std::cmp::Reverse(b.1));
             results.into_iter().map(|(cmd, _)| cmd).collect()
         }
     }
@@ -324,6 +371,30 @@ impl CommandRegistry {
         }
     }
 
+    /// Replace the recently viewed issue commands.
+    ///
+    /// Takes `(key, summary)` pairs, most recent first. The commands are
+    /// listed after the built-in ones and match on "recent" as well as the
+    /// issue key and summary.
+    pub fn set_recent_issues(&mut self, issues: &[(&str, &str)]) {
+        self.commands
+            .retain(|cmd| cmd.category != CommandCategory::Recent);
+        self.commands
+            .extend(issues.iter().map(|(key, summary)| Command {
+                id: format!("recent.{}", key),
+                title: if summary.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{} {}", key, summary)
+                },
+                description: Some("Recently viewed issue".to_string()),
+                category: CommandCategory::Recent,
+                keywords: vec!["recent".to_string(), "viewed".to_string()],
+                shortcut: None,
+                action: CommandAction::OpenIssue(key.to_string()),
+            }));
+    }
+
     /// Get all registered commands.
     pub fn commands(&self) -> &[Command] {
         &self.commands
@@ -333,6 +404,12 @@ impl CommandRegistry {
     pub fn recent(&self) -> Vec<&str> {
         self.recent.iter().map(|s| s.as_str()).collect()
     }
+
+    /// Replace the recent command IDs (most recent first), such as when
+    /// restoring them from a previous session.
+    pub fn set_recent(&mut self, ids: &[String]) {
+        self.recent = ids.iter().take(Self::MAX_RECENT).cloned().collect();
+    }
 }
 
 impl Default for CommandRegistry {
@@ -430,6 +507,17 @@ mod tests {
         assert_eq!(registry.recent(), vec!["help.show", "issue.refresh"]);
     }
 
+    #[test]
+    fn test_set_recent() {
+        let mut registry = CommandRegistry::new();
+        registry.record_used("help.show");
+
+        let ids: Vec<String> = (0..15).map(|i| format!("cmd.{}", i)).collect();
+        registry.set_recent(&ids);
+        assert_eq!(registry.recent().len(), CommandRegistry::MAX_RECENT);
+        assert_eq!(registry.recent()[0], "cmd.0");
+    }
+
     #[test]
     fn test_record_used_max_size() {
         let mut registry = CommandRegistry::new();
@@ -506,4 +594,23 @@ mod tests {
         // First result should start with "Go"
         assert!(results[0].title.to_lowercase().starts_with("go"));
     }
+
+    #[test]
+    fn test_set_recent_issues() {
+        let mut registry = CommandRegistry::new();
+        let builtin = registry.commands().len();
+
+        registry.set_recent_issues(&[("PROJ-2", "Second"), ("PROJ-1", "")]);
+        registry.set_recent_issues(&[("PROJ-3", "Third"), ("PROJ-2", "Second")]);
+        assert_eq!(registry.commands().len(), builtin + 2);
+
+        let results = registry.search("recent");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "PROJ-3 Third");
+        assert_eq!(
+            results[0].action,
+            CommandAction::OpenIssue("PROJ-3".to_string())
+        );
+        assert_eq!(registry.search("second")[0].id, "recent.PROJ-2");
+    }
 }
@@ -0,0 +1,566 @@
+//! JQL aliases and query macros.
+//!
+//! The `[aliases]` table in `config.toml` gives short names to JQL snippets,
+//! used in queries as `@name`. A macro lists its parameters after its name
+//! and refers to them in its body as `$param`:
+//!
+//! ```toml
+//! [aliases]
+//! mine = "assignee = currentUser() AND resolution = Unresolved"
+//! "sprint(team)" = "sprint in openSprints() AND team = $team"
+//! ```
+//!
+//! `@mine AND @sprint("Platform")` then runs as
+//! `(assignee = currentUser() AND resolution = Unresolved) AND (sprint in
+//! openSprints() AND team = "Platform")`. Arguments are inserted as written,
+//! so quote values that need quoting. Aliases may use other aliases.
+//! An alias whose body has an ORDER BY clause can only end a query.
+//!
+//! An `@` inside a quoted string or a word (as in `user@company.com`) is not
+//! an alias.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum nesting of aliases used in other aliases.
+const MAX_DEPTH: usize = 10;
+
+/// User-defined JQL aliases, keyed by their name and parameter list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct JqlAliases(BTreeMap<String, String>);
+
+/// A parsed alias definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JqlAlias<'a> {
+    /// Name used after `@`.
+    pub name: &'a str,
+    /// Parameter names.
+    pub params: Vec<&'a str>,
+    /// The JQL the alias stands for.
+    pub body: &'a str,
+    /// The definition's key in the `[aliases]` table.
+    pub key: &'a str,
+}
+
+impl JqlAlias<'_> {
+    /// Get the alias as written in a query, with its parameters as
+    /// placeholders (e.g. `@sprint(team)`).
+    pub fn usage(&self) -> String {
+        if self.params.is_empty() {
+            format!("@{}", self.name)
+        } else {
+            format!("@{}({})", self.name, self.params.join(", "))
+        }
+    }
+}
+
+impl JqlAliases {
+    /// Create an empty set of aliases.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether no aliases are defined.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Define an alias. `key` is the name, optionally followed by a
+    /// parameter list (e.g. `sprint(team)`).
+    pub fn insert(&mut self, key: impl Into<String>, body: impl Into<String>) {
+        self.0.insert(key.into(), body.into());
+    }
+
+    /// Remove the alias with the given name.
+    pub fn remove(&mut self, name: &str) {
+        let key = self.get(name).map(|alias| alias.key.to_string());
+        if let Some(key) = key {
+            self.0.remove(&key);
+        }
+    }
+
+    /// Iterate over the aliases with a valid definition key, by name.
+    pub fn iter(&self) -> impl Iterator<Item = JqlAlias<'_>> {
+        self.0
+            .iter()
+            .filter_map(|(key, body)| parse_key(key).ok().map(|(n, p)| (key, n, p, body)))
+            .map(|(key, name, params, body)| JqlAlias {
+                name,
+                params,
+                body,
+                key,
+            })
+    }
+
+    /// Get an alias by name.
+    pub fn get(&self, name: &str) -> Option<JqlAlias<'_>> {
+        self.iter().find(|alias| alias.name == name)
+    }
+
+    /// Get the aliases whose name starts with `prefix`.
+    pub fn completions(&self, prefix: &str) -> Vec<JqlAlias<'_>> {
+        self.iter()
+            .filter(|alias| alias.name.starts_with(prefix))
+            .collect()
+    }
+
+    /// Check that every definition is well-formed and expands.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first invalid alias.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut names = std::collections::HashSet::new();
+        for (key, body) in &self.0 {
+            let (name, params) = parse_key(key)?;
+            if !names.insert(name) {
+                return Err(format!("alias @{} is defined more than once", name));
+            }
+            if body.trim().is_empty() {
+                return Err(format!("alias @{} is empty", name));
+            }
+            for param in &params {
+                if params.iter().filter(|p| *p == param).count() > 1 {
+                    return Err(format!("alias @{}: duplicate parameter '{}'", name, param));
+                }
+            }
+            if let Some(unknown) = references(body).find(|r| !params.contains(r)) {
+                return Err(format!("alias @{}: unknown parameter ${}", name, unknown));
+            }
+            // Expanding with the parameter names as arguments reports unknown
+            // and recursive aliases in the body
+            let usage = if params.is_empty() {
+                format!("@{}", name)
+            } else {
+                format!("@{}({})", name, params.join(", "))
+            };
+            self.expand(&usage)?;
+        }
+        Ok(())
+    }
+
+    /// Expand the aliases used in a query.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if the query uses an unknown
+    /// alias, passes the wrong number of arguments, or an alias uses itself.
+    pub fn expand(&self, jql: &str) -> Result<String, String> {
+        self.expand_depth(jql, 0)
+    }
+
+    fn expand_depth(&self, jql: &str, depth: usize) -> Result<String, String> {
+        let mut output = String::with_capacity(jql.len());
+        let mut rest = jql;
+        while let Some(usage) = next_usage(rest) {
+            output.push_str(&rest[..usage.start]);
+            let alias = self
+                .get(usage.name)
+                .ok_or_else(|| format!("unknown alias @{}", usage.name))?;
+            if depth >= MAX_DEPTH {
+                return Err(format!("alias @{} uses itself", alias.name));
+            }
+
+            let args = match usage.args {
+                Some(args) => split_args(args),
+                None => Vec::new(),
+            };
+            if args.len() != alias.params.len() {
+                return Err(match alias.params.len() {
+                    0 => format!("@{} takes no arguments", alias.name),
+                    n => format!(
+                        "{} takes {} argument{}, got {}",
+                        alias.usage(),
+                        n,
+                        if n == 1 { "" } else { "s" },
+                        args.len()
+                    ),
+                });
+            }
+            let body = substitute(alias.body, &alias.params, &args);
+            let expanded = self.expand_depth(&body, depth + 1)?;
+
+            // Parenthesize so the snippet combines as a unit, unless it is
+            // the whole query or sorts. ORDER BY can't be nested, so a
+            // sorting alias must end the query.
+            let at_end = rest[usage.end..].trim().is_empty();
+            let whole_query =
+                at_end && rest[..usage.start].trim().is_empty() && output.trim().is_empty();
+            let sorts = has_order_by(&expanded);
+            if sorts && !at_end {
+                return Err(format!(
+                    "@{} sorts with ORDER BY and must end the query",
+                    alias.name
+                ));
+            }
+            if whole_query || sorts {
+                output.push_str(&expanded);
+            } else {
+                output.push('(');
+                output.push_str(&expanded);
+                output.push(')');
+            }
+            rest = &rest[usage.end..];
+        }
+        output.push_str(rest);
+        Ok(output)
+    }
+}
+
+/// Check whether a character can be part of an alias or parameter name.
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Split a definition key like `sprint(team, board)` into its name and
+/// parameters.
+fn parse_key(key: &str) -> Result<(&str, Vec<&str>), String> {
+    let invalid = || format!("invalid alias name '{}'", key);
+    let key = key.trim();
+    let (name, params) = match key.split_once('(') {
+        Some((name, params)) => {
+            let params = params.strip_suffix(')').ok_or_else(invalid)?;
+            let params: Vec<&str> = params.split(',').map(str::trim).collect();
+            (name.trim(), params)
+        }
+        None => (key, Vec::new()),
+    };
+    let valid = |s: &str| !s.is_empty() && s.chars().all(is_name_char);
+    if !valid(name) || !params.iter().all(|p| valid(p)) {
+        return Err(invalid());
+    }
+    Ok((name, params))
+}
+
+/// An alias used in a query.
+struct Usage<'a> {
+    /// Byte offset of the `@`.
+    start: usize,
+    /// Byte offset just past the alias and its arguments.
+    end: usize,
+    name: &'a str,
+    /// Text between the parentheses, if any.
+    args: Option<&'a str>,
+}
+
+/// Find the first alias used in `jql`, skipping quoted strings and `@`s
+/// inside words.
+fn next_usage(jql: &str) -> Option<Usage<'_>> {
+    let mut quote = None;
+    let mut escaped = false;
+    let mut prev = None;
+    for (i, c) in jql.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '@' && !prev.is_some_and(|p: char| is_name_char(p) || p == '.') => {
+                let name_len = jql[i + 1..]
+                    .find(|c: char| !is_name_char(c))
+                    .unwrap_or(jql.len() - i - 1);
+                if name_len == 0 {
+                    prev = Some(c);
+                    continue;
+                }
+                let name_end = i + 1 + name_len;
+                let name = &jql[i + 1..name_end];
+                let (args, end) = match jql[name_end..].starts_with('(') {
+                    true => match closing_paren(&jql[name_end..]) {
+                        Some(close) => (
+                            Some(&jql[name_end + 1..name_end + close]),
+                            name_end + close + 1,
+                        ),
+                        None => (None, name_end),
+                    },
+                    false => (None, name_end),
+                };
+                return Some(Usage {
+                    start: i,
+                    end,
+                    name,
+                    args,
+                });
+            }
+            None => {}
+        }
+        prev = Some(c);
+    }
+    None
+}
+
+/// Find the byte offset of the parenthesis closing the one `s` starts with.
+fn closing_paren(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i);
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    None
+}
+
+/// Split macro arguments at top-level commas.
+fn split_args(args: &str) -> Vec<&str> {
+    if args.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(args[start..i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            },
+        }
+    }
+    parts.push(args[start..].trim());
+    parts
+}
+
+/// Iterate over the `$param` references in a macro body.
+fn references(body: &str) -> impl Iterator<Item = &str> {
+    body.match_indices('$').filter_map(move |(i, _)| {
+        let name = &body[i + 1..];
+        let len = name.find(|c: char| !is_name_char(c)).unwrap_or(name.len());
+        (len > 0).then(|| &name[..len])
+    })
+}
+
+/// Replace `$param` references in a macro body with the arguments.
+fn substitute(body: &str, params: &[&str], args: &[&str]) -> String {
+    let mut output = String::with_capacity(body.len());
+    let mut rest = body;
+    while let Some(i) = rest.find('$') {
+        output.push_str(&rest[..i]);
+        let name = &rest[i + 1..];
+        let len = name.find(|c: char| !is_name_char(c)).unwrap_or(name.len());
+        match params.iter().position(|p| *p == &name[..len]) {
+            Some(index) if len > 0 => output.push_str(args[index]),
+            _ => output.push_str(&rest[i..i + 1 + len]),
+        }
+        rest = &name[len..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Check whether a query has an ORDER BY clause outside quoted strings.
+fn has_order_by(jql: &str) -> bool {
+    let mut unquoted = String::with_capacity(jql.len());
+    let mut quote = None;
+    let mut escaped = false;
+    for c in jql.chars() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None => unquoted.push(c.to_ascii_lowercase()),
+        }
+    }
+    unquoted
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .windows(2)
+        .any(|w| w == ["order", "by"])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases() -> JqlAliases {
+        let mut aliases = JqlAliases::new();
+        aliases.insert(
+            "mine",
+            "assignee = currentUser() AND resolution = Unresolved",
+        );
+        aliases.insert("sprint(team)", "sprint in openSprints() AND team = $team");
+        aliases.insert("between(from, to)", "created >= $from AND created < $to");
+        aliases.insert("my-sprint", "@mine AND @sprint(\"Platform\")");
+        aliases.insert("recent", "updated >= -7d ORDER BY updated DESC");
+        aliases
+    }
+
+    #[test]
+    fn test_expand_simple_alias() {
+        let aliases = aliases();
+        assert_eq!(
+            aliases.expand("@mine").unwrap(),
+            "assignee = currentUser() AND resolution = Unresolved"
+        );
+        assert_eq!(
+            aliases.expand("project = A AND @mine").unwrap(),
+            "project = A AND (assignee = currentUser() AND resolution = Unresolved)"
+        );
+    }
+
+    #[test]
+    fn test_expand_macro_arguments() {
+        let aliases = aliases();
+        assert_eq!(
+            aliases.expand("@sprint(\"Team, A\")").unwrap(),
+            "sprint in openSprints() AND team = \"Team, A\""
+        );
+        assert_eq!(
+            aliases.expand("@between(-14d, startOfDay())").unwrap(),
+            "created >= -14d AND created < startOfDay()"
+        );
+    }
+
+    #[test]
+    fn test_expand_nested_aliases() {
+        assert_eq!(
+            aliases().expand("@my-sprint").unwrap(),
+            "(assignee = currentUser() AND resolution = Unresolved) AND \
+             (sprint in openSprints() AND team = \"Platform\")"
+        );
+    }
+
+    #[test]
+    fn test_order_by_is_not_parenthesized() {
+        assert_eq!(
+            aliases().expand("project = A AND @recent").unwrap(),
+            "project = A AND updated >= -7d ORDER BY updated DESC"
+        );
+        assert_eq!(
+            aliases().expand("project = A AND @recent  ").unwrap(),
+            "project = A AND updated >= -7d ORDER BY updated DESC  "
+        );
+    }
+
+    #[test]
+    fn test_order_by_alias_must_end_query() {
+        let mut aliases = aliases();
+        aliases.insert("recent-mine", "@recent AND @mine");
+        aliases.insert("mine-recent", "@mine AND @recent");
+
+        let error = "@recent sorts with ORDER BY and must end the query";
+        assert_eq!(
+            aliases.expand("@recent AND project = A").unwrap_err(),
+            error
+        );
+        assert_eq!(
+            aliases.expand("(@recent) AND project = A").unwrap_err(),
+            error
+        );
+        assert_eq!(aliases.expand("@recent-mine").unwrap_err(), error);
+        assert_eq!(
+            aliases.expand("@mine-recent AND project = A").unwrap_err(),
+            "@mine-recent sorts with ORDER BY and must end the query"
+        );
+        assert_eq!(
+            aliases.expand("project = A AND @mine-recent").unwrap(),
+            "project = A AND (assignee = currentUser() AND resolution = Unresolved) AND \
+             updated >= -7d ORDER BY updated DESC"
+        );
+    }
+
+    #[test]
+    fn test_at_in_strings_and_words_is_kept() {
+        let aliases = aliases();
+        for jql in [
+            "assignee = user@company.com",
+            "summary ~ \"@mine\"",
+            "summary ~ 'it\\'s @mine'",
+            "text ~ \"a @ b\"",
+        ] {
+            assert_eq!(aliases.expand(jql).unwrap(), jql);
+        }
+    }
+
+    #[test]
+    fn test_expand_errors() {
+        let aliases = aliases();
+        assert_eq!(aliases.expand("@nope").unwrap_err(), "unknown alias @nope");
+        assert_eq!(
+            aliases.expand("@sprint").unwrap_err(),
+            "@sprint(team) takes 1 argument, got 0"
+        );
+        assert_eq!(
+            aliases.expand("@between(a)").unwrap_err(),
+            "@between(from, to) takes 2 arguments, got 1"
+        );
+        assert_eq!(
+            aliases.expand("@mine(x)").unwrap_err(),
+            "@mine takes no arguments"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(aliases().validate().is_ok());
+
+        let invalid = |key: &str, body: &str| {
+            let mut aliases = aliases();
+            aliases.insert(key, body);
+            aliases.validate().unwrap_err()
+        };
+        assert_eq!(
+            invalid("bad name", "x = 1"),
+            "invalid alias name 'bad name'"
+        );
+        assert_eq!(invalid("empty", " "), "alias @empty is empty");
+        assert_eq!(
+            invalid("team(name)", "team = $nmae"),
+            "alias @team: unknown parameter $nmae"
+        );
+        assert_eq!(invalid("loop", "@loop"), "alias @loop uses itself");
+        assert_eq!(invalid("broken", "@nope"), "unknown alias @nope");
+    }
+
+    #[test]
+    fn test_completions() {
+        let aliases = aliases();
+        let names: Vec<&str> = aliases.completions("m").iter().map(|a| a.name).collect();
+        assert_eq!(names, vec!["mine", "my-sprint"]);
+        assert_eq!(aliases.get("sprint").unwrap().usage(), "@sprint(team)");
+        assert_eq!(aliases.completions("x"), Vec::new());
+    }
+
+    #[test]
+    fn test_aliases_toml() {
+        let config: BTreeMap<String, JqlAliases> = toml::from_str(
+            "[aliases]\nmine = \"assignee = currentUser()\"\n\"sprint(team)\" = \"team = $team\"\n",
+        )
+        .unwrap();
+        let aliases = &config["aliases"];
+        assert_eq!(aliases.get("sprint").unwrap().params, vec!["team"]);
+        assert_eq!(aliases.expand("@mine").unwrap(), "assignee = currentUser()");
+    }
+}
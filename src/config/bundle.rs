@@ -0,0 +1,468 @@
+//! Shareable profile bundles.
+//!
+//! A bundle is a single TOML file holding a profile together with the saved
+//! filters, issue templates, JQL aliases and list columns used with it, so a team can
+//! commit e.g. `lazyjira-team.toml` to its repository and new teammates can
+//! import it from the profile list. API tokens live in the OS keychain and
+//! are never exported:
+//!
+//! ```toml
+//! [profile]
+//! name = "team"
+//! url = "https://company.atlassian.net"
+//! email = "you@company.com"
+//! columns = [{ field = "key" }, { field = "summary" }, { field = "status" }]
+//!
+//! [[saved_filters]]
+//! name = "Open bugs"
+//!
+//! [saved_filters.filter]
+//! statuses = ["Open"]
+//! assignees = []
+//! assignee_is_me = false
+//! labels = ["bug"]
+//! components = []
+//! epics = []
+//!
+//! [[templates]]
+//! name = "Bug report"
+//! issue_type = "Bug"
+//!
+//! [aliases]
+//! mine = "assignee = currentUser() AND resolution = Unresolved"
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::columns::validate_columns;
+use super::templates::validate_templates;
+use super::{Config, ConfigError, IssueTemplate, JqlAliases, Profile, Result};
+use crate::api::types::SavedFilter;
+
+/// Comment written at the top of exported bundles.
+const HEADER: &str = "# LazyJira profile bundle. Import it from the profile list with 'i'.\n\
+                      # API tokens are not included.\n\n";
+
+/// A profile and its shared setup, as exported to a bundle file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProfileBundle {
+    /// The profile, including its list columns and settings overrides.
+    pub profile: Profile,
+
+    /// Saved filters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub saved_filters: Vec<SavedFilter>,
+
+    /// Issue templates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub templates: Vec<IssueTemplate>,
+
+    /// JQL aliases.
+    #[serde(default, skip_serializing_if = "JqlAliases::is_empty")]
+    pub aliases: JqlAliases,
+}
+
+/// How to import bundle entries whose names are already taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Replace the existing entries.
+    Replace,
+    /// Keep the existing entries and skip the imported ones.
+    KeepExisting,
+    /// Import the entries under new names.
+    Rename,
+}
+
+/// The result of importing a bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Name of the imported profile, or of the existing one that was kept.
+    pub profile: String,
+    /// Number of entries added or replaced.
+    pub imported: usize,
+    /// Number of entries skipped in favour of existing ones.
+    pub skipped: usize,
+}
+
+impl ProfileBundle {
+    /// Read and validate a bundle file.
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(ConfigError::ReadError)?;
+        let bundle: Self = toml::from_str(&content).map_err(ConfigError::ParseError)?;
+        bundle.profile.validate()?;
+        for filter in &bundle.saved_filters {
+            if let Some(ref columns) = filter.columns {
+                validate_columns(columns, &format!("saved filter '{}'", filter.name))?;
+            }
+        }
+        validate_templates(bundle.templates.iter())?;
+        bundle
+            .aliases
+            .validate()
+            .map_err(ConfigError::ValidationError)?;
+        Ok(bundle)
+    }
+
+    /// Write the bundle to `path`.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let content = toml::to_string_pretty(self).map_err(ConfigError::SerializeError)?;
+        fs::write(path, format!("{}{}", HEADER, content)).map_err(ConfigError::WriteError)
+    }
+
+    /// Describe the entries that differ from existing ones of the same name.
+    ///
+    /// Entries identical to existing ones are not conflicts, so importing a
+    /// bundle twice changes nothing.
+    pub fn conflicts(&self, config: &Config) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        if let Some(existing) = config.get_profile(&self.profile.name) {
+            if *existing != self.profile {
+                conflicts.push(format!("profile '{}'", self.profile.name));
+            }
+        }
+        for filter in &self.saved_filters {
+            let existing = config
+                .settings
+                .saved_filters
+                .iter()
+                .find(|f| f.name == filter.name);
+            if existing.is_some_and(|existing| existing != filter) {
+                conflicts.push(format!("saved filter '{}'", filter.name));
+            }
+        }
+        for template in &self.templates {
+            if config
+                .get_issue_template(&template.name)
+                .is_some_and(|existing| existing != template)
+            {
+                conflicts.push(format!("template '{}'", template.name));
+            }
+        }
+        for alias in self.aliases.iter() {
+            if config
+                .aliases
+                .get(alias.name)
+                .is_some_and(|existing| existing != alias)
+            {
+                conflicts.push(format!("alias @{}", alias.name));
+            }
+        }
+        conflicts
+    }
+}
+
+impl Config {
+    /// Bundle a profile with the saved filters, templates and aliases for
+    /// export.
+    ///
+    /// A profile without its own columns gets the global list columns, so
+    /// the bundle shows the same list wherever it is imported.
+    pub fn export_profile(&self, name: &str) -> Result<ProfileBundle> {
+        let mut profile = self
+            .get_profile(name)
+            .cloned()
+            .ok_or_else(|| ConfigError::ProfileNotFound(name.to_string()))?;
+        if profile.columns.is_none() && !self.settings.columns.is_empty() {
+            profile.columns = Some(self.settings.columns.clone());
+        }
+        Ok(ProfileBundle {
+            profile,
+            saved_filters: self.settings.saved_filters.clone(),
+            templates: self.issue_templates().cloned().collect(),
+            aliases: self.aliases.clone(),
+        })
+    }
+
+    /// Add a bundle's profile, saved filters, templates and aliases.
+    ///
+    /// `resolution` decides what happens to entries whose name is already
+    /// taken by a different entry. Templates loaded from template files are
+    /// never replaced; a conflicting template is skipped instead.
+    pub fn import_bundle(
+        &mut self,
+        bundle: ProfileBundle,
+        resolution: ConflictResolution,
+    ) -> Result<ImportSummary> {
+        let mut summary = ImportSummary {
+            profile: bundle.profile.name.clone(),
+            imported: 0,
+            skipped: 0,
+        };
+
+        let mut profile = bundle.profile;
+        match self.profiles.iter().position(|p| p.name == profile.name) {
+            Some(index) if self.profiles[index] == profile => {}
+            Some(index) => match resolution {
+                ConflictResolution::Replace => {
+                    self.profiles[index] = profile;
+                    summary.imported += 1;
+                }
+                ConflictResolution::KeepExisting => summary.skipped += 1,
+                ConflictResolution::Rename => {
+                    profile.name =
+                        unused_name(&profile.name, "-", |name| self.get_profile(name).is_some());
+                    summary.profile = profile.name.clone();
+                    self.add_profile(profile)?;
+                    summary.imported += 1;
+                }
+            },
+            None => {
+                self.add_profile(profile)?;
+                summary.imported += 1;
+            }
+        }
+
+        for mut filter in bundle.saved_filters {
+            let filters = &mut self.settings.saved_filters;
+            match filters.iter().position(|f| f.name == filter.name) {
+                Some(index) if filters[index] == filter => {}
+                Some(index) => match resolution {
+                    ConflictResolution::Replace => {
+                        filters[index] = filter;
+                        summary.imported += 1;
+                    }
+                    ConflictResolution::KeepExisting => summary.skipped += 1,
+                    ConflictResolution::Rename => {
+                        filter.name = unused_name(&filter.name, " ", |name| {
+                            filters.iter().any(|f| f.name == name)
+                        });
+                        filters.push(filter);
+                        summary.imported += 1;
+                    }
+                },
+                None => {
+                    filters.push(filter);
+                    summary.imported += 1;
+                }
+            }
+        }
+
+        for mut template in bundle.templates {
+            match self.get_issue_template(&template.name) {
+                Some(existing) if *existing == template => {}
+                Some(_) => {
+                    let in_file = self.file_templates.iter().any(|t| t.name == template.name);
+                    match resolution {
+                        ConflictResolution::Replace if !in_file => {
+                            if let Some(existing) =
+                                self.templates.iter_mut().find(|t| t.name == template.name)
+                            {
+                                *existing = template;
+                            }
+                            summary.imported += 1;
+                        }
+                        ConflictResolution::Rename => {
+                            template.name = unused_name(&template.name, " ", |name| {
+                                self.get_issue_template(name).is_some()
+                            });
+                            self.templates.push(template);
+                            summary.imported += 1;
+                        }
+                        _ => summary.skipped += 1,
+                    }
+                }
+                None => {
+                    self.templates.push(template);
+                    summary.imported += 1;
+                }
+            }
+        }
+
+        for alias in bundle.aliases.iter() {
+            match self.aliases.get(alias.name) {
+                Some(existing) if existing == alias => {}
+                Some(_) => match resolution {
+                    ConflictResolution::Replace => {
+                        self.aliases.remove(alias.name);
+                        self.aliases.insert(alias.key, alias.body);
+                        summary.imported += 1;
+                    }
+                    ConflictResolution::KeepExisting => summary.skipped += 1,
+                    ConflictResolution::Rename => {
+                        let name =
+                            unused_name(alias.name, "-", |name| self.aliases.get(name).is_some());
+                        let key = match alias.params.is_empty() {
+                            true => name,
+                            false => format!("{}({})", name, alias.params.join(", ")),
+                        };
+                        self.aliases.insert(key, alias.body);
+                        summary.imported += 1;
+                    }
+                },
+                None => {
+                    self.aliases.insert(alias.key, alias.body);
+                    summary.imported += 1;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Find a name like `name-2` (with `separator` = `-`) that isn't taken.
+fn unused_name(name: &str, separator: &str, taken: impl Fn(&str) -> bool) -> String {
+    (2..)
+        .map(|n| format!("{}{}{}", name, separator, n))
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_else(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::columns::ColumnConfig;
+    use crate::api::types::FilterState;
+    use tempfile::tempdir;
+
+    fn config() -> Config {
+        let mut config = Config::default();
+        config.profiles.push(Profile::new(
+            "team".to_string(),
+            "https://company.atlassian.net".to_string(),
+            "me@company.com".to_string(),
+        ));
+        config.settings.columns = vec![ColumnConfig::new("key"), ColumnConfig::new("summary")];
+        config
+            .settings
+            .add_saved_filter(SavedFilter::new("Mine", FilterState::default()));
+        config.templates.push(IssueTemplate {
+            name: "Bug report".to_string(),
+            issue_type: Some("Bug".to_string()),
+            ..IssueTemplate::default()
+        });
+        config.aliases.insert("mine", "assignee = currentUser()");
+        config
+    }
+
+    #[test]
+    fn test_export_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("lazyjira-team.toml");
+
+        let bundle = config().export_profile("team").unwrap();
+        // The global columns go with the profile
+        assert_eq!(bundle.profile.columns.as_ref().unwrap().len(), 2);
+        bundle.write(&path).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with(HEADER));
+        assert!(!content[HEADER.len()..].contains("token"));
+        assert_eq!(ProfileBundle::read(&path).unwrap(), bundle);
+    }
+
+    #[test]
+    fn test_export_unknown_profile() {
+        assert!(matches!(
+            config().export_profile("missing"),
+            Err(ConfigError::ProfileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_validates_bundle() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("bundle.toml");
+        fs::write(
+            &path,
+            "[profile]\nname = \"team\"\nurl = \"ftp://x\"\nemail = \"a@b.c\"\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            ProfileBundle::read(&path),
+            Err(ConfigError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_import_into_empty_config() {
+        let bundle = config().export_profile("team").unwrap();
+        let mut target = Config::default();
+
+        assert!(bundle.conflicts(&target).is_empty());
+        let summary = target
+            .import_bundle(bundle.clone(), ConflictResolution::Replace)
+            .unwrap();
+        assert_eq!(summary.profile, "team");
+        assert_eq!(summary.imported, 4);
+        assert_eq!(target.profiles[0], bundle.profile);
+        assert!(target.validate().is_ok());
+
+        // Importing again changes nothing
+        assert!(bundle.conflicts(&target).is_empty());
+        let summary = target
+            .import_bundle(bundle, ConflictResolution::Rename)
+            .unwrap();
+        assert_eq!(summary.imported, 0);
+        assert_eq!(target.profiles.len(), 1);
+    }
+
+    #[test]
+    fn test_import_conflicts() {
+        let mut bundle = config().export_profile("team").unwrap();
+        bundle.profile.url = "https://other.atlassian.net".to_string();
+        bundle.saved_filters[0].filter.assignee_is_me = true;
+        bundle.templates[0].priority = Some("High".to_string());
+        bundle.aliases.insert("mine", "reporter = currentUser()");
+        let target = config();
+
+        assert_eq!(
+            bundle.conflicts(&target),
+            vec![
+                "profile 'team'",
+                "saved filter 'Mine'",
+                "template 'Bug report'",
+                "alias @mine"
+            ]
+        );
+
+        let mut replaced = target.clone();
+        let summary = replaced
+            .import_bundle(bundle.clone(), ConflictResolution::Replace)
+            .unwrap();
+        assert_eq!((summary.imported, summary.skipped), (4, 0));
+        assert_eq!(replaced.profiles[0].url, "https://other.atlassian.net");
+        assert!(replaced.settings.saved_filters[0].filter.assignee_is_me);
+
+        let mut kept = target.clone();
+        let summary = kept
+            .import_bundle(bundle.clone(), ConflictResolution::KeepExisting)
+            .unwrap();
+        assert_eq!((summary.imported, summary.skipped), (0, 4));
+        assert_eq!(kept.profiles, target.profiles);
+
+        let mut renamed = target.clone();
+        let summary = renamed
+            .import_bundle(bundle, ConflictResolution::Rename)
+            .unwrap();
+        assert_eq!(summary.profile, "team-2");
+        assert_eq!(renamed.profiles[1].name, "team-2");
+        assert_eq!(renamed.settings.saved_filters[1].name, "Mine 2");
+        assert_eq!(renamed.templates[1].name, "Bug report 2");
+        assert_eq!(
+            renamed.aliases.get("mine-2").unwrap().body,
+            "reporter = currentUser()"
+        );
+        assert!(renamed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_file_templates_are_not_replaced() {
+        let mut bundle = config().export_profile("team").unwrap();
+        bundle.templates[0].priority = Some("High".to_string());
+        let mut target = Config::default();
+        target.file_templates.push(IssueTemplate {
+            name: "Bug report".to_string(),
+            ..IssueTemplate::default()
+        });
+
+        let summary = target
+            .import_bundle(bundle, ConflictResolution::Replace)
+            .unwrap();
+        assert_eq!(summary.skipped, 1);
+        assert!(target.templates.is_empty());
+    }
+}
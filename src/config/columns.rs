@@ -0,0 +1,126 @@
+//! Issue list column configuration.
+//!
+//! Columns can be configured globally in `[settings]`, per profile, or per
+//! saved filter. The most specific non-empty set wins:
+//! saved filter → profile → settings → built-in defaults.
+//!
+//! ```toml
+//! [[settings.columns]]
+//! field = "key"
+//!
+//! [[settings.columns]]
+//! field = "summary"
+//!
+//! [[settings.columns]]
+//! field = "updated"
+//! width = 10
+//!
+//! [[settings.columns]]
+//! field = "customfield_10050"
+//! title = "Team"
+//! width = 16
+//! ```
+
+use super::{ConfigError, Result};
+use crate::api::columns::{ColumnConfig, ColumnField};
+
+/// Validate a column, reporting errors with the given context.
+///
+/// # Errors
+///
+/// Returns a `ConfigError::ValidationError` if the field is unknown or the
+/// width is zero.
+fn validate_column(column: &ColumnConfig, context: &str) -> Result<()> {
+    if ColumnField::from_field_id(&column.field).is_none() {
+        return Err(ConfigError::ValidationError(format!(
+            "{}: unknown column field '{}'",
+            context, column.field
+        )));
+    }
+
+    if column.width == Some(0) {
+        return Err(ConfigError::ValidationError(format!(
+            "{}: column '{}' cannot have zero width",
+            context, column.field
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate a list of columns, reporting errors with the given context.
+pub fn validate_columns(columns: &[ColumnConfig], context: &str) -> Result<()> {
+    columns.iter().try_for_each(|c| validate_column(c, context))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[test]
+    fn test_validate_known_fields() {
+        for field in [
+            "key",
+            "summary",
+            "status",
+            "assignee",
+            "priority",
+            "type",
+            "reporter",
+            "created",
+            "updated",
+            "due",
+            "story_points",
+            "sprint",
+            "labels",
+            "components",
+            "customfield_10050",
+        ] {
+            assert!(
+                validate_column(&ColumnConfig::new(field), "settings").is_ok(),
+                "{} should be valid",
+                field
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_unknown_field() {
+        let result = validate_column(&ColumnConfig::new("bogus"), "settings");
+        assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_validate_zero_width() {
+        let column = ColumnConfig {
+            width: Some(0),
+            ..ColumnConfig::new("key")
+        };
+        assert!(validate_column(&column, "settings").is_err());
+    }
+
+    #[test]
+    fn test_parse_columns_toml() {
+        let toml_content = r#"
+[[columns]]
+field = "key"
+
+[[columns]]
+field = "customfield_10050"
+title = "Team"
+width = 16
+"#;
+
+        #[derive(Deserialize)]
+        struct Wrapper {
+            columns: Vec<ColumnConfig>,
+        }
+
+        let wrapper: Wrapper = toml::from_str(toml_content).unwrap();
+        assert_eq!(wrapper.columns.len(), 2);
+        assert_eq!(wrapper.columns[0], ColumnConfig::new("key"));
+        assert_eq!(wrapper.columns[1].title.as_deref(), Some("Team"));
+        assert_eq!(wrapper.columns[1].width, Some(16));
+    }
+}
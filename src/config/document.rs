@@ -0,0 +1,252 @@
+//! Writing config files without losing their formatting.
+//!
+//! Saving serializes the whole configuration, which on its own would drop the
+//! comments and layout of a hand-edited file. Instead the serialized
+//! configuration is merged into the existing document: unchanged values keep
+//! their formatting and comments, changed values are replaced in place and new
+//! keys are appended. Keys are only dropped if the app removed them; keys it
+//! does not know (such as settings from a newer version or hand-added notes)
+//! are kept.
+
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table, Value};
+
+/// Merge the serialized configuration `new` into the existing file content.
+///
+/// `known` is the serialized configuration the app read from `existing`; keys
+/// of `existing` missing from it are unknown to the app and kept.
+///
+/// Returns `new` unchanged if the existing content is not valid TOML.
+pub fn merge(existing: &str, known: &str, new: &str) -> String {
+    let (Ok(mut doc), Ok(new_doc)) = (existing.parse::<DocumentMut>(), new.parse::<DocumentMut>())
+    else {
+        return new.to_string();
+    };
+    let known = known.parse::<DocumentMut>().unwrap_or_default();
+    merge_table(
+        doc.as_table_mut(),
+        Some(known.as_table()),
+        new_doc.as_table(),
+    );
+    doc.to_string()
+}
+
+/// Merge the entries of `new` into `table`, removing the entries of `known`
+/// that `new` no longer has.
+fn merge_table(table: &mut Table, known: Option<&Table>, new: &Table) {
+    let removed: Vec<String> = table
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .filter(|key| !new.contains_key(key) && known.is_some_and(|k| k.contains_key(key)))
+        .collect();
+    for key in removed {
+        table.remove(&key);
+    }
+
+    for (key, new_item) in new.iter() {
+        match table.get_mut(key) {
+            Some(item) => merge_item(item, known.and_then(|k| k.get(key)), new_item),
+            None => {
+                table.insert(key, new_item.clone());
+            }
+        }
+    }
+}
+
+/// Merge `new` into `item`, keeping the existing formatting where possible.
+fn merge_item(item: &mut Item, known: Option<&Item>, new: &Item) {
+    match (item, new) {
+        (Item::Table(table), Item::Table(new)) => {
+            merge_table(table, known.and_then(Item::as_table), new)
+        }
+        (Item::ArrayOfTables(array), Item::ArrayOfTables(new)) => {
+            merge_array(array, known.and_then(Item::as_array_of_tables), new)
+        }
+        (Item::Value(value), Item::Value(new)) => {
+            if !same_value(value, new) {
+                let decor = value.decor().clone();
+                *value = new.clone();
+                *value.decor_mut() = decor;
+            }
+        }
+        (item, new) => *item = new.clone(),
+    }
+}
+
+/// Merge an array of tables, matching entries by their `name` key (profiles,
+/// templates, saved filters) and otherwise by position.
+fn merge_array(array: &mut ArrayOfTables, known: Option<&ArrayOfTables>, new: &ArrayOfTables) {
+    let mut old: Vec<Option<Table>> = array.iter().cloned().map(Some).collect();
+    let mut merged = ArrayOfTables::new();
+    for (index, new_table) in new.iter().enumerate() {
+        let name = new_table.get("name").and_then(Item::as_str);
+        let matching = match name {
+            Some(name) => old
+                .iter()
+                .position(|t| t.as_ref().and_then(table_name) == Some(name)),
+            None => Some(index).filter(|&i| i < old.len()),
+        };
+        match matching.and_then(|i| old[i].take()) {
+            Some(mut table) => {
+                let known = known.and_then(|known| match name {
+                    Some(name) => known.iter().find(|t| table_name(t) == Some(name)),
+                    None => known.get(index),
+                });
+                merge_table(&mut table, known, new_table);
+                merged.push(table);
+            }
+            None => merged.push(new_table.clone()),
+        }
+    }
+    *array = merged;
+}
+
+/// Get the `name` key of a table in an array of tables.
+fn table_name(table: &Table) -> Option<&str> {
+    table.get("name").and_then(Item::as_str)
+}
+
+/// Check whether two values are equal, ignoring formatting.
+fn same_value(a: &Value, b: &Value) -> bool {
+    let parse = |value: &Value| toml::from_str::<toml::Table>(&format!("v = {}", value)).ok();
+    match (parse(a), parse(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_comments_of_unchanged_values() {
+        let existing = r#"# LazyJira settings
+[settings]
+theme = "dark"   # easier on the eyes
+vim_mode = true
+
+# Work account
+[[profiles]]
+name = "work"
+url = "https://company.atlassian.net"
+"#;
+        let new = r#"[settings]
+theme = "light"
+vim_mode = true
+page_size = 100
+
+[[profiles]]
+name = "work"
+url = "https://company.atlassian.net"
+"#;
+        assert_eq!(
+            merge(existing, existing, new),
+            r#"# LazyJira settings
+[settings]
+theme = "light"   # easier on the eyes
+vim_mode = true
+page_size = 100
+
+# Work account
+[[profiles]]
+name = "work"
+url = "https://company.atlassian.net"
+"#
+        );
+    }
+
+    #[test]
+    fn test_removes_missing_entries() {
+        let existing = r#"[settings]
+theme = "dark"
+page_size = 25
+
+# Personal
+[[profiles]]
+name = "personal"
+email = "me@example.com"
+
+# Work
+[[profiles]]
+name = "work"
+email = "me@company.com"
+"#;
+        let new = r#"[settings]
+theme = "dark"
+
+[[profiles]]
+name = "work"
+email = "me@company.com"
+"#;
+        assert_eq!(
+            merge(existing, existing, new),
+            r#"[settings]
+theme = "dark"
+
+# Work
+[[profiles]]
+name = "work"
+email = "me@company.com"
+"#
+        );
+    }
+
+    #[test]
+    fn test_keeps_unknown_keys() {
+        let existing = r#"# Added by hand
+notes = "remember to rotate the token"
+
+[settings]
+theme = "dark"
+page_size = 25
+future_option = true
+
+[[profiles]]
+name = "work"
+email = "me@company.com"
+team = "platform"
+"#;
+        let known = r#"[settings]
+theme = "dark"
+page_size = 25
+
+[[profiles]]
+name = "work"
+email = "me@company.com"
+"#;
+        let new = r#"[settings]
+theme = "dark"
+
+[[profiles]]
+name = "work"
+email = "me@company.com"
+"#;
+        assert_eq!(
+            merge(existing, known, new),
+            r#"# Added by hand
+notes = "remember to rotate the token"
+
+[settings]
+theme = "dark"
+future_option = true
+
+[[profiles]]
+name = "work"
+email = "me@company.com"
+team = "platform"
+"#
+        );
+    }
+
+    #[test]
+    fn test_value_formatting_is_ignored() {
+        let existing = "columns = [ 'key', 'summary' ] # short\n";
+        let new = "columns = [\"key\", \"summary\"]\n";
+        assert_eq!(merge(existing, existing, new), existing);
+    }
+
+    #[test]
+    fn test_invalid_existing_content_is_replaced() {
+        assert_eq!(merge("[settings", "", "version = 2\n"), "version = 2\n");
+    }
+}
@@ -0,0 +1,119 @@
+//! Project-local configuration.
+//!
+//! A `.lazyjira.toml` file in the current directory or any of its parents
+//! (e.g. at the root of a repository) applies on top of the global and
+//! profile settings. It can pin the profile, the query shown on startup and
+//! the default project, and override settings:
+//!
+//! ```toml
+//! profile = "work"
+//! jql = "project = PROJ AND sprint in openSprints()"
+//! project = "PROJ"
+//!
+//! [settings]
+//! page_size = 100
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ConfigError, Result, SettingsOverrides};
+
+/// File name of the project-local configuration.
+pub const LOCAL_CONFIG_FILE: &str = ".lazyjira.toml";
+
+/// Configuration from a project-local `.lazyjira.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct LocalConfig {
+    /// Profile used on startup instead of the default profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+
+    /// JQL query opened in a tab on startup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jql: Option<String>,
+
+    /// Project key used for new issues and the first tab's filter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    /// Settings overridden in this directory tree.
+    #[serde(default, skip_serializing_if = "SettingsOverrides::is_empty")]
+    pub settings: SettingsOverrides,
+
+    /// Path of the file this configuration was read from.
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+impl LocalConfig {
+    /// Find and load the `.lazyjira.toml` closest to `start`, walking up
+    /// through its parent directories.
+    ///
+    /// Returns `None` if no directory has one.
+    pub fn discover(start: &Path) -> Result<Option<Self>> {
+        let Some(path) = start
+            .ancestors()
+            .map(|dir| dir.join(LOCAL_CONFIG_FILE))
+            .find(|path| path.is_file())
+        else {
+            return Ok(None);
+        };
+
+        let content = fs::read_to_string(&path).map_err(ConfigError::ReadError)?;
+        let mut local: LocalConfig = toml::from_str(&content).map_err(|e| {
+            ConfigError::ValidationError(format!("invalid '{}': {}", path.display(), e))
+        })?;
+        local.path = path;
+        Ok(Some(local))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_discover_walks_up() {
+        let dir = tempdir().unwrap();
+        let nested = dir.path().join("src").join("module");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(
+            dir.path().join(LOCAL_CONFIG_FILE),
+            "profile = \"work\"\nproject = \"PROJ\"\n\n[settings]\npage_size = 100\n",
+        )
+        .unwrap();
+
+        let local = LocalConfig::discover(&nested).unwrap().unwrap();
+        assert_eq!(local.profile.as_deref(), Some("work"));
+        assert_eq!(local.project.as_deref(), Some("PROJ"));
+        assert!(local.jql.is_none());
+        assert_eq!(local.settings.page_size, Some(100));
+        assert_eq!(local.path, dir.path().join(LOCAL_CONFIG_FILE));
+    }
+
+    #[test]
+    fn test_discover_prefers_closest_file() {
+        let dir = tempdir().unwrap();
+        let nested = dir.path().join("app");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.path().join(LOCAL_CONFIG_FILE), "project = \"OUTER\"\n").unwrap();
+        fs::write(nested.join(LOCAL_CONFIG_FILE), "project = \"INNER\"\n").unwrap();
+
+        let local = LocalConfig::discover(&nested).unwrap().unwrap();
+        assert_eq!(local.project.as_deref(), Some("INNER"));
+    }
+
+    #[test]
+    fn test_unknown_key_rejected() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join(LOCAL_CONFIG_FILE), "profle = \"work\"\n").unwrap();
+
+        let err = LocalConfig::discover(dir.path()).unwrap_err().to_string();
+        assert!(err.contains(LOCAL_CONFIG_FILE));
+    }
+}
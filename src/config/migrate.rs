@@ -0,0 +1,400 @@
+//! Config file schema versions and migrations.
+//!
+//! `config.toml` records the schema version it was written for. Older files
+//! are upgraded on load by a chain of migrations, one per version, that edit
+//! the TOML document in place so comments and formatting survive. The
+//! original file is kept as `config.toml.v<version>.bak` beside it.
+//! Migrations may also move session state out of the file into the profiles'
+//! state files (see [`super::ProfileState`]).
+//!
+//! Files without a `version` key are version 0.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml_edit::{value, Array, DocumentMut, Item, TableLike};
+
+use super::{ConfigError, ProfileState, Result};
+
+/// Current config schema version.
+pub const CURRENT_VERSION: u32 = 2;
+
+/// A migration to the next version, returning a description of each change.
+type Step = fn(&mut Migrating) -> Vec<String>;
+
+/// Migrations indexed by the version they upgrade from.
+const STEPS: [Step; CURRENT_VERSION as usize] =
+    [move_jql_history_to_profile, move_session_to_state];
+
+/// Profile session keys moved to the state files in version 2.
+const SESSION_KEYS: [&str; 4] = ["jql_history", "tabs", "active_tab", "history"];
+
+/// A config file being migrated.
+struct Migrating {
+    /// The config document.
+    doc: DocumentMut,
+    /// State files to create, by profile name.
+    state: Vec<(String, DocumentMut)>,
+}
+
+/// The current config schema version, as a serde default.
+pub(super) fn current_version() -> u32 {
+    CURRENT_VERSION
+}
+
+/// The result of migrating a config file to the current version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    /// Version the file was written for.
+    pub from: u32,
+    /// Description of each change made.
+    pub changes: Vec<String>,
+    /// The migrated file content.
+    pub content: String,
+    /// Content of the state files moved out of the config, by profile name.
+    pub state: Vec<(String, String)>,
+}
+
+impl Migration {
+    /// Path of the backup written beside the config file at `path`.
+    pub fn backup_path(&self, path: &Path) -> PathBuf {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".v{}.bak", self.from));
+        path.with_file_name(name)
+    }
+
+    /// Back up the original file at `path` and write the migrated content.
+    ///
+    /// State files are written to `state_dir`, unless a profile already has
+    /// one. Returns the backup path.
+    pub fn write(&self, path: &Path, state_dir: &Path) -> Result<PathBuf> {
+        for (profile, content) in &self.state {
+            let state_path = ProfileState::path(state_dir, profile);
+            if !state_path.exists() {
+                fs::create_dir_all(state_dir).map_err(ConfigError::CreateDirError)?;
+                fs::write(state_path, content).map_err(ConfigError::WriteError)?;
+            }
+        }
+        let backup = self.backup_path(path);
+        fs::copy(path, &backup).map_err(ConfigError::WriteError)?;
+        fs::write(path, &self.content).map_err(ConfigError::WriteError)?;
+        Ok(backup)
+    }
+}
+
+/// Migrate config file content to the current version.
+///
+/// Returns `None` if the content is already current.
+///
+/// # Errors
+///
+/// Returns an error if the content is not valid TOML or was written for a
+/// newer version than this one supports.
+pub fn migrate(content: &str) -> Result<Option<Migration>> {
+    // Report syntax errors with their location, like a regular load
+    toml::from_str::<toml::Table>(content).map_err(ConfigError::ParseError)?;
+    let doc: DocumentMut = content
+        .parse()
+        .map_err(|e| ConfigError::ValidationError(format!("invalid TOML: {}", e)))?;
+
+    let from = match doc.get("version") {
+        None => 0,
+        Some(item) => item
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| {
+                ConfigError::ValidationError("version must be a non-negative integer".to_string())
+            })?,
+    };
+    if from > CURRENT_VERSION {
+        return Err(ConfigError::ValidationError(format!(
+            "config version {} is newer than this version of LazyJira supports ({})",
+            from, CURRENT_VERSION
+        )));
+    }
+    if from == CURRENT_VERSION {
+        return Ok(None);
+    }
+
+    let mut migrating = Migrating {
+        doc,
+        state: Vec::new(),
+    };
+    let mut changes = Vec::new();
+    for step in &STEPS[from as usize..] {
+        changes.extend(step(&mut migrating));
+    }
+    set_version(&mut migrating.doc);
+    changes.push(format!(
+        "updated version from {} to {}",
+        from, CURRENT_VERSION
+    ));
+
+    Ok(Some(Migration {
+        from,
+        changes,
+        content: migrating.doc.to_string(),
+        state: migrating
+            .state
+            .into_iter()
+            .map(|(profile, state)| (profile, state.to_string()))
+            .collect(),
+    }))
+}
+
+/// Set the version key, placing it above the other top-level keys.
+fn set_version(doc: &mut DocumentMut) {
+    let root = doc.as_table_mut();
+    root.insert("version", value(i64::from(CURRENT_VERSION)));
+    root.sort_values_by(|a, _, b, _| (a.get() != "version").cmp(&(b.get() != "version")));
+}
+
+/// Version 0 → 1: the JQL history moves from `[settings]` to the default
+/// profile, since queries usually only make sense on one JIRA instance.
+fn move_jql_history_to_profile(migrating: &mut Migrating) -> Vec<String> {
+    let doc = &mut migrating.doc;
+    let Some(settings) = doc.get_mut("settings").and_then(Item::as_table_like_mut) else {
+        return Vec::new();
+    };
+    let default_profile = settings
+        .get("default_profile")
+        .and_then(Item::as_str)
+        .map(str::to_string);
+    let Some(history) = settings.remove("jql_history") else {
+        return Vec::new();
+    };
+    let history = history.as_array().cloned().unwrap_or_else(Array::new);
+
+    let mut profiles = profiles_mut(doc);
+    let index = default_profile
+        .and_then(|name| {
+            profiles
+                .iter()
+                .position(|p| p.get("name").and_then(Item::as_str) == Some(name.as_str()))
+        })
+        .unwrap_or(0);
+
+    match profiles.get_mut(index) {
+        Some(profile) if !history.is_empty() && !profile.contains_key("jql_history") => {
+            let name = profile
+                .get("name")
+                .and_then(Item::as_str)
+                .unwrap_or_default()
+                .to_string();
+            profile.insert("jql_history", value(history));
+            vec![format!("moved settings.jql_history to profile '{}'", name)]
+        }
+        _ => vec!["removed settings.jql_history".to_string()],
+    }
+}
+
+/// Version 1 → 2: the JQL history, tabs and navigation history of each
+/// profile move to its state file, so using the app no longer rewrites
+/// `config.toml`.
+fn move_session_to_state(migrating: &mut Migrating) -> Vec<String> {
+    let mut changes = Vec::new();
+    for profile in profiles_mut(&mut migrating.doc) {
+        let mut state = DocumentMut::new();
+        for key in SESSION_KEYS {
+            if let Some(item) = profile.remove(key) {
+                state.insert(key, item);
+            }
+        }
+        if state.is_empty() {
+            continue;
+        }
+        let name = profile
+            .get("name")
+            .and_then(Item::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let keys: Vec<&str> = state.iter().map(|(key, _)| key).collect();
+        changes.push(format!(
+            "moved {} of profile '{}' to its state file",
+            keys.join(", "),
+            name
+        ));
+        migrating.state.push((name, state));
+    }
+    changes
+}
+
+/// Get the profile tables of a config document, whether written as an array
+/// of tables or an inline array.
+fn profiles_mut(doc: &mut DocumentMut) -> Vec<&mut dyn TableLike> {
+    match doc.get_mut("profiles") {
+        Some(Item::ArrayOfTables(tables)) => {
+            tables.iter_mut().map(|t| t as &mut dyn TableLike).collect()
+        }
+        Some(Item::Value(toml_edit::Value::Array(array))) => array
+            .iter_mut()
+            .filter_map(|v| v.as_inline_table_mut())
+            .map(|t| t as &mut dyn TableLike)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_version_is_unchanged() {
+        assert_eq!(migrate("version = 2\n").unwrap(), None);
+    }
+
+    #[test]
+    fn test_newer_version_rejected() {
+        let err = migrate("version = 99\n").unwrap_err();
+        assert!(err.to_string().contains("newer"));
+    }
+
+    #[test]
+    fn test_invalid_toml_reports_parse_error() {
+        assert!(matches!(
+            migrate("[settings\n"),
+            Err(ConfigError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_unversioned_file_gets_version() {
+        let migration = migrate("[settings]\ntheme = \"light\" # keep me\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(migration.from, 0);
+        assert_eq!(
+            migration.content,
+            "version = 2\n[settings]\ntheme = \"light\" # keep me\n"
+        );
+        assert_eq!(migration.changes, vec!["updated version from 0 to 2"]);
+    }
+
+    #[test]
+    fn test_jql_history_moves_to_default_profile() {
+        let content = r#"[settings]
+default_profile = "personal"
+jql_history = ["project = A"]
+
+[[profiles]]
+name = "work"
+
+[[profiles]]
+name = "personal"
+"#;
+        let migration = migrate(content).unwrap().unwrap();
+        assert_eq!(
+            migration.changes[0],
+            "moved settings.jql_history to profile 'personal'"
+        );
+
+        // ...and from there on to the profile's state file
+        assert!(!migration.content.contains("jql_history"));
+        assert_eq!(migration.state.len(), 1);
+        assert_eq!(migration.state[0].0, "personal");
+        let state: ProfileState = toml::from_str(&migration.state[0].1).unwrap();
+        assert_eq!(state.jql_history, vec!["project = A"]);
+    }
+
+    #[test]
+    fn test_session_moves_to_state() {
+        let content = r#"version = 1
+
+[[profiles]]
+name = "work"
+url = "https://company.atlassian.net" # main instance
+jql_history = ["project = A"]
+active_tab = 1
+
+[[profiles.tabs]]
+jql = "type = Bug"
+
+[[profiles.tabs]]
+name = "Mine"
+
+[[profiles.history]]
+view = "issue"
+key = "PROJ-1"
+
+[[profiles]]
+name = "personal"
+"#;
+        let migration = migrate(content).unwrap().unwrap();
+        assert_eq!(
+            migration.changes[0],
+            "moved jql_history, tabs, active_tab, history of profile 'work' to its state file"
+        );
+        assert_eq!(
+            migration.content,
+            r#"version = 2
+
+[[profiles]]
+name = "work"
+url = "https://company.atlassian.net" # main instance
+
+[[profiles]]
+name = "personal"
+"#
+        );
+
+        assert_eq!(migration.state.len(), 1);
+        let state: ProfileState = toml::from_str(&migration.state[0].1).unwrap();
+        assert_eq!(state.jql_history, vec!["project = A"]);
+        assert_eq!(state.active_tab, 1);
+        assert_eq!(state.tabs.len(), 2);
+        assert_eq!(state.tabs[1].name.as_deref(), Some("Mine"));
+        assert_eq!(state.history.len(), 1);
+    }
+
+    #[test]
+    fn test_write_keeps_existing_state_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let state_dir = dir.path().join("state");
+        let content = "version = 1\n[[profiles]]\nname = \"work\"\nactive_tab = 2\n\n[[profiles]]\nname = \"personal\"\nactive_tab = 1\n";
+        fs::write(&path, content).unwrap();
+        fs::create_dir_all(&state_dir).unwrap();
+        fs::write(
+            ProfileState::path(&state_dir, "personal"),
+            "active_tab = 3\n",
+        )
+        .unwrap();
+
+        let migration = migrate(content).unwrap().unwrap();
+        migration.write(&path, &state_dir).unwrap();
+
+        assert_eq!(
+            ProfileState::load(&state_dir, "work").unwrap().active_tab,
+            2
+        );
+        assert_eq!(
+            ProfileState::load(&state_dir, "personal")
+                .unwrap()
+                .active_tab,
+            3
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("config.toml.v1.bak")).unwrap(),
+            content
+        );
+    }
+
+    #[test]
+    fn test_jql_history_without_profiles_is_removed() {
+        let migration = migrate("[settings]\njql_history = [\"project = A\"]\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(migration.changes[0], "removed settings.jql_history");
+        assert!(!migration.content.contains("jql_history"));
+    }
+
+    #[test]
+    fn test_backup_path() {
+        let migration = migrate("").unwrap().unwrap();
+        assert_eq!(
+            migration.backup_path(Path::new("/tmp/lazyjira/config.toml")),
+            Path::new("/tmp/lazyjira/config.toml.v0.bak")
+        );
+    }
+}
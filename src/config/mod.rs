@@ -16,12 +16,33 @@
 //! ```text
 //! <config_dir>/lazyjira/
 //! ├── config.toml      # Main configuration file
+//! ├── templates/       # Issue templates (`*.md`)
+//! ├── themes/          # Theme files (`*.toml`, base16 `*.yaml`)
 //! └── cache/           # Issue cache (future)
 //! ```
 //!
+//! Files written for an older schema [`version`](CURRENT_VERSION) are migrated
+//! on load (see [`migrate`]).
+//!
+//! Session state such as open tabs and the JQL history is kept per profile
+//! in the data directory (see [`ProfileState`]), so the app only writes
+//! `config.toml` for explicit settings changes, keeping its comments and
+//! formatting.
+//!
+//! A project-local `.lazyjira.toml` (see [`LocalConfig`]) is picked up from the
+//! current directory or its parents.
+//!
+//! # Settings Layers
+//!
+//! The effective settings are built from the defaults, then `[settings]`,
+//! then the active profile's `[profiles.settings]`, then the `[settings]` of
+//! the local `.lazyjira.toml` (see [`Config::effective_settings`]).
+//!
 //! # Configuration File Format
 //!
 //! ```toml
+//! version = 1
+//!
 //! [settings]
 //! default_profile = "work"
 //! theme = "dark"
@@ -32,18 +53,51 @@
 //! name = "work"
 //! url = "https://company.atlassian.net"
 //! email = "user@company.com"
+//!
+//! [profiles.settings]
+//! page_size = 100
+//! confirm_transitions = true
+//!
+//! [keys.issue_list]
+//! "Ctrl+j" = "move_down"
+//!
+//! [aliases]
+//! mine = "assignee = currentUser() AND resolution = Unresolved"
 //! ```
 
+mod aliases;
+mod bundle;
+mod columns;
+mod document;
+mod local;
+mod migrate;
+mod navigation;
 mod profile;
 mod settings;
-
+mod state;
+mod tabs;
+mod templates;
+mod themes;
+mod watcher;
+
+pub use aliases::JqlAliases;
+pub use bundle::{ConflictResolution, ProfileBundle};
+pub use local::LocalConfig;
+pub use migrate::{migrate, CURRENT_VERSION};
+pub use navigation::{IssuePanel, JumpList, Location};
 pub use profile::Profile;
-pub use settings::Settings;
+pub use settings::{Settings, SettingsOverrides};
+pub use state::ProfileState;
+pub use tabs::TabConfig;
+pub use templates::IssueTemplate;
+pub use themes::ThemeFile;
+pub use watcher::ConfigWatcher;
 
 // Re-export Config and ConfigError at the module level
 
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 /// Errors that can occur when working with configuration.
@@ -53,6 +107,10 @@ pub enum ConfigError {
     #[error("could not determine configuration directory")]
     NoConfigDir,
 
+    /// Could not determine the data directory for session state.
+    #[error("could not determine data directory")]
+    NoDataDir,
+
     /// Failed to create the configuration directory.
     #[error("failed to create configuration directory: {0}")]
     CreateDirError(#[source] std::io::Error),
@@ -80,14 +138,47 @@ pub enum ConfigError {
     /// Profile not found.
     #[error("profile '{0}' not found")]
     ProfileNotFound(String),
+
+    /// The configuration file failed to load, so saving would overwrite it.
+    #[error("configuration file has errors; fix it before changing settings")]
+    LoadFailed,
+}
+
+impl ConfigError {
+    /// Get the 1-based line and column of a parse error in `content`.
+    ///
+    /// Returns `None` for other errors or if the parser reported no position.
+    pub fn location(&self, content: &str) -> Option<(usize, usize)> {
+        let ConfigError::ParseError(e) = self else {
+            return None;
+        };
+        let offset = e.span()?.start.min(content.len());
+        let before = content.get(..offset)?;
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+        Some((line, column))
+    }
+
+    /// Get the error message without the parser's source excerpt.
+    pub fn summary(&self) -> String {
+        match self {
+            ConfigError::ParseError(e) => e.message().to_string(),
+            other => other.to_string(),
+        }
+    }
 }
 
 /// Result type for configuration operations.
 pub type Result<T> = std::result::Result<T, ConfigError>;
 
 /// The root configuration structure for LazyJira.
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Config {
+    /// Schema version the configuration was written for.
+    #[serde(default = "migrate::current_version")]
+    pub version: u32,
+
     /// Application-wide settings.
     #[serde(default)]
     pub settings: Settings,
@@ -95,6 +186,71 @@ pub struct Config {
     /// List of JIRA profiles.
     #[serde(default)]
     pub profiles: Vec<Profile>,
+
+    /// Issue templates for the create issue form.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub templates: Vec<IssueTemplate>,
+
+    /// Issue templates loaded from the templates directory.
+    ///
+    /// These are never written back to `config.toml`.
+    #[serde(skip)]
+    pub file_templates: Vec<IssueTemplate>,
+
+    /// Themes loaded from the themes directory.
+    #[serde(skip)]
+    pub file_themes: Vec<ThemeFile>,
+
+    /// Project-local configuration found from the current directory.
+    #[serde(skip)]
+    pub local: Option<LocalConfig>,
+
+    /// Key binding overrides: `[keys.<context>]` tables mapping key
+    /// sequences to action names.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<String, BTreeMap<String, String>>,
+
+    /// JQL aliases and macros usable in queries as `@name`.
+    #[serde(default, skip_serializing_if = "JqlAliases::is_empty")]
+    pub aliases: JqlAliases,
+
+    /// Whether this configuration stands in for a config file that failed to
+    /// load.
+    ///
+    /// Such a configuration is never saved, so the broken file is left for
+    /// the user to fix.
+    #[serde(skip)]
+    pub load_failed: bool,
+
+    /// Session state of each profile, by profile name.
+    #[serde(skip)]
+    pub state: BTreeMap<String, ProfileState>,
+
+    /// Directory the session state is saved to.
+    ///
+    /// Only set when loaded from the default location, so configurations
+    /// built in code never write state files.
+    #[serde(skip)]
+    pub state_dir: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: CURRENT_VERSION,
+            settings: Settings::default(),
+            profiles: Vec::new(),
+            templates: Vec::new(),
+            file_templates: Vec::new(),
+            file_themes: Vec::new(),
+            local: None,
+            keys: BTreeMap::new(),
+            aliases: JqlAliases::new(),
+            load_failed: false,
+            state: BTreeMap::new(),
+            state_dir: None,
+        }
+    }
 }
 
 impl Config {
@@ -120,6 +276,26 @@ impl Config {
         Self::config_dir().map(|p| p.join("config.toml"))
     }
 
+    /// Get the issue templates directory path.
+    pub fn templates_dir() -> Result<PathBuf> {
+        Self::config_dir().map(|p| p.join("templates"))
+    }
+
+    /// Get the theme files directory path.
+    pub fn themes_dir() -> Result<PathBuf> {
+        Self::config_dir().map(|p| p.join("themes"))
+    }
+
+    /// Create the default configuration used while the config file has errors.
+    ///
+    /// Saving it fails with [`ConfigError::LoadFailed`].
+    pub fn fallback() -> Self {
+        Self {
+            load_failed: true,
+            ..Self::default()
+        }
+    }
+
     /// Load configuration from the default location.
     ///
     /// If the configuration file does not exist, returns a default configuration.
@@ -134,22 +310,111 @@ impl Config {
     /// - The configuration fails validation
     pub fn load() -> Result<Self> {
         let config_path = Self::config_path()?;
+        let state_dir = ProfileState::state_dir()?;
 
-        if !config_path.exists() {
-            return Ok(Self::default());
-        }
+        let mut config = if config_path.exists() {
+            Self::read_file(&config_path, &state_dir)?
+        } else {
+            Self::default()
+        };
+        config.load_state(state_dir);
 
-        let content = fs::read_to_string(&config_path).map_err(ConfigError::ReadError)?;
+        config.file_templates = templates::load_template_files(&Self::templates_dir()?)?;
+        config.file_themes = themes::load_theme_files(&Self::themes_dir()?)?;
+        if let Ok(cwd) = std::env::current_dir() {
+            config.local = LocalConfig::discover(&cwd)?;
+        }
+        config.validate()?;
+        Ok(config)
+    }
 
+    /// Read a config file, migrating it to the current version first.
+    ///
+    /// A migrated file is rewritten with a backup of the original beside it;
+    /// session state moved out of it is written to `state_dir`.
+    fn read_file(path: &Path, state_dir: &Path) -> Result<Self> {
+        let mut content = fs::read_to_string(path).map_err(ConfigError::ReadError)?;
+        if let Some(migration) = migrate::migrate(&content)? {
+            let backup = migration.write(path, state_dir)?;
+            tracing::info!(
+                from = migration.from,
+                backup = %backup.display(),
+                "Migrated config file to version {}",
+                CURRENT_VERSION
+            );
+            content = migration.content;
+        }
         let mut config: Config = toml::from_str(&content).map_err(ConfigError::ParseError)?;
         config.settings.validate_page_size();
-        config.validate()?;
         Ok(config)
     }
 
+    /// Load the session state of every profile from `dir`.
+    ///
+    /// A state file that fails to load is reported and replaced by an empty
+    /// state, as losing it only loses history.
+    fn load_state(&mut self, dir: PathBuf) {
+        for profile in &self.profiles {
+            let state = ProfileState::load(&dir, &profile.name).unwrap_or_else(|e| {
+                tracing::warn!(profile = %profile.name, error = %e, "Failed to load state");
+                ProfileState::default()
+            });
+            self.state.insert(profile.name.clone(), state);
+        }
+        self.state_dir = Some(dir);
+    }
+
+    /// Get a profile's session state.
+    pub fn profile_state(&self, profile: &str) -> Option<&ProfileState> {
+        self.state.get(profile)
+    }
+
+    /// Get a profile's session state for changing it.
+    pub fn profile_state_mut(&mut self, profile: &str) -> &mut ProfileState {
+        self.state.entry(profile.to_string()).or_default()
+    }
+
+    /// Save a profile's session state to its state file.
+    ///
+    /// Does nothing if the configuration was not loaded from the default
+    /// location.
+    pub fn save_state(&self, profile: &str) -> Result<()> {
+        match (&self.state_dir, self.state.get(profile)) {
+            (Some(dir), Some(state)) => state.save(dir, profile),
+            _ => Ok(()),
+        }
+    }
+
+    /// Move a profile's session state to its new name after a rename.
+    pub fn rename_state(&mut self, from: &str, to: &str) -> Result<()> {
+        if let Some(state) = self.state.remove(from) {
+            self.state.insert(to.to_string(), state);
+        }
+        if let Some(dir) = &self.state_dir {
+            let path = ProfileState::path(dir, from);
+            if path.exists() {
+                fs::rename(path, ProfileState::path(dir, to)).map_err(ConfigError::WriteError)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove a deleted profile's session state and its state file.
+    pub fn remove_state(&mut self, profile: &str) -> Result<()> {
+        self.state.remove(profile);
+        if let Some(dir) = &self.state_dir {
+            let path = ProfileState::path(dir, profile);
+            if path.exists() {
+                fs::remove_file(path).map_err(ConfigError::WriteError)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Save configuration to the default location.
     ///
-    /// Creates the configuration directory if it does not exist.
+    /// Creates the configuration directory if it does not exist. Comments
+    /// and formatting of an existing file are kept.
     ///
     /// # Errors
     ///
@@ -157,7 +422,11 @@ impl Config {
     /// - The configuration directory cannot be determined or created
     /// - The configuration cannot be serialized
     /// - The configuration file cannot be written
+    /// - This is the [`fallback`](Self::fallback) for a file that failed to load
     pub fn save(&self) -> Result<()> {
+        if self.load_failed {
+            return Err(ConfigError::LoadFailed);
+        }
         let config_dir = Self::config_dir()?;
         let config_path = Self::config_path()?;
 
@@ -166,7 +435,15 @@ impl Config {
             fs::create_dir_all(&config_dir).map_err(ConfigError::CreateDirError)?;
         }
 
-        let content = toml::to_string_pretty(self).map_err(ConfigError::SerializeError)?;
+        let mut content = toml::to_string_pretty(self).map_err(ConfigError::SerializeError)?;
+        if let Ok(existing) = fs::read_to_string(&config_path) {
+            // Keys the app does not read from the file are kept as they are
+            let known = toml::from_str::<Config>(&existing)
+                .ok()
+                .and_then(|config| toml::to_string_pretty(&config).ok())
+                .unwrap_or_default();
+            content = document::merge(&existing, &known, &content);
+        }
 
         fs::write(&config_path, content).map_err(ConfigError::WriteError)?;
 
@@ -181,6 +458,11 @@ impl Config {
     /// - All profiles have valid email addresses
     /// - Profile names are unique
     /// - The default profile (if set) exists
+    /// - All configured list columns refer to known fields
+    /// - Issue template names are set and unique
+    /// - Theme files and custom theme colors are valid
+    /// - Key bindings refer to known contexts, keys and actions
+    /// - JQL aliases are well-formed and expand
     ///
     /// # Errors
     ///
@@ -199,6 +481,33 @@ impl Config {
             }
         }
 
+        columns::validate_columns(&self.settings.columns, "settings")?;
+        for filter in &self.settings.saved_filters {
+            if let Some(ref columns) = filter.columns {
+                columns::validate_columns(columns, &format!("saved filter '{}'", filter.name))?;
+            }
+        }
+
+        templates::validate_templates(self.issue_templates())?;
+
+        themes::validate_theme_files(&self.file_themes)?;
+        let custom_themes = std::iter::once(self.settings.custom_theme.as_ref())
+            .chain(
+                self.profiles
+                    .iter()
+                    .map(|p| p.settings.custom_theme.as_ref()),
+            )
+            .chain(self.local.iter().map(|l| l.settings.custom_theme.as_ref()));
+        for custom in custom_themes.flatten() {
+            themes::validate_theme_colors(&custom.colors)?;
+        }
+
+        crate::events::Keymap::from_config(&self.keys).map_err(ConfigError::ValidationError)?;
+
+        self.aliases
+            .validate()
+            .map_err(ConfigError::ValidationError)?;
+
         // Validate default_profile references an existing profile
         if let Some(ref default_profile) = self.settings.default_profile {
             if !self.profiles.iter().any(|p| &p.name == default_profile) {
@@ -229,6 +538,37 @@ impl Config {
         }
     }
 
+    /// Get the profile to use on startup.
+    ///
+    /// This is the profile pinned by the local `.lazyjira.toml`, if it exists,
+    /// and otherwise the default profile.
+    pub fn startup_profile(&self) -> Option<&Profile> {
+        let pinned = self.local.as_ref().and_then(|l| l.profile.as_deref());
+        if let Some(name) = pinned {
+            match self.get_profile(name) {
+                Some(profile) => return Some(profile),
+                None => tracing::warn!("Local config pins unknown profile '{}'", name),
+            }
+        }
+        self.get_default_profile()
+    }
+
+    /// Get the effective settings for a profile.
+    ///
+    /// Layers the global settings, the profile's overrides and the local
+    /// `.lazyjira.toml` overrides, in that order.
+    pub fn effective_settings(&self, profile: Option<&str>) -> Settings {
+        let mut settings = self.settings.clone();
+        if let Some(profile) = profile.and_then(|name| self.get_profile(name)) {
+            profile.settings.apply_to(&mut settings);
+        }
+        if let Some(ref local) = self.local {
+            local.settings.apply_to(&mut settings);
+        }
+        settings.validate_page_size();
+        settings
+    }
+
     /// Add a profile to the configuration.
     ///
     /// Returns an error if a profile with the same name already exists.
@@ -263,27 +603,57 @@ impl Config {
     }
 
     // ========================================================================
-    // JQL history methods
+    // Issue template methods
     // ========================================================================
 
-    /// Get the JQL query history.
-    pub fn jql_history(&self) -> &[String] {
-        &self.settings.jql_history
+    /// Get all issue templates: those in `config.toml` first, then template files.
+    pub fn issue_templates(&self) -> impl Iterator<Item = &IssueTemplate> {
+        self.templates.iter().chain(self.file_templates.iter())
+    }
+
+    /// Get an issue template by name.
+    pub fn get_issue_template(&self, name: &str) -> Option<&IssueTemplate> {
+        self.issue_templates().find(|t| t.name == name)
     }
 
-    /// Add a JQL query to the history.
+    // ========================================================================
+    // Theme methods
+    // ========================================================================
+
+    /// Get the names of all themes: the built-in themes, then theme files.
     ///
-    /// The query is added to the front of the history. If the query already
-    /// exists in the history, it is moved to the front. The history is
-    /// limited to 10 entries.
-    pub fn add_jql_to_history(&mut self, query: String) {
-        self.settings.add_jql_to_history(query);
+    /// A theme file with the name of a built-in theme replaces it.
+    pub fn theme_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = crate::ui::theme::Theme::BUILTIN_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        for file in &self.file_themes {
+            if !names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&file.name))
+            {
+                names.push(file.name.clone());
+            }
+        }
+        names
+    }
+
+    /// Build the theme selected by settings, including custom color overrides.
+    pub fn theme(&self, settings: &Settings) -> crate::ui::theme::Theme {
+        crate::ui::theme::load_theme(
+            &settings.theme,
+            settings.custom_theme.as_ref(),
+            &self.file_themes,
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::columns::ColumnConfig;
+    use crate::api::types::SavedFilter;
 
     #[test]
     fn test_default_config() {
@@ -302,14 +672,15 @@ mod tests {
                 default_profile: Some("work".to_string()),
                 theme: "light".to_string(),
                 vim_mode: false,
+                vim_editing: false,
                 cache_ttl_minutes: 60,
                 cache_max_size_mb: 100,
                 page_size: 25,
-                jql_history: vec!["project = TEST".to_string()],
                 confirm_transitions: false,
                 confirm_discard_changes: true,
                 custom_theme: None,
                 saved_filters: Vec::new(),
+                columns: Vec::new(),
             },
             profiles: vec![
                 Profile::new(
@@ -323,6 +694,7 @@ mod tests {
                     "user@personal.com".to_string(),
                 ),
             ],
+            ..Default::default()
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
@@ -336,7 +708,7 @@ mod tests {
         assert_eq!(parsed.profiles.len(), 2);
         assert_eq!(parsed.profiles[0].name, "work");
         assert_eq!(parsed.profiles[1].name, "personal");
-        assert_eq!(parsed.settings.jql_history, config.settings.jql_history);
+        assert_eq!(parsed.version, CURRENT_VERSION);
     }
 
     #[test]
@@ -355,6 +727,7 @@ mod tests {
                     "other@company.com".to_string(),
                 ),
             ],
+            ..Default::default()
         };
 
         let result = config.validate();
@@ -377,6 +750,7 @@ mod tests {
                 "https://company.atlassian.net".to_string(),
                 "user@company.com".to_string(),
             )],
+            ..Default::default()
         };
 
         let result = config.validate();
@@ -384,6 +758,27 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("does not exist"));
     }
 
+    #[test]
+    fn test_saved_filter_columns_validated() {
+        let mut filter = SavedFilter::new("Bugs", Default::default());
+        filter.columns = Some(vec![ColumnConfig::new("not_a_field")]);
+        let config = Config {
+            settings: Settings {
+                saved_filters: vec![filter],
+                ..Settings::default()
+            },
+            profiles: Vec::new(),
+            ..Default::default()
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("saved filter 'Bugs'"));
+    }
+
     #[test]
     fn test_get_profile() {
         let config = Config {
@@ -400,6 +795,7 @@ mod tests {
                     "user@personal.com".to_string(),
                 ),
             ],
+            ..Default::default()
         };
 
         assert!(config.get_profile("work").is_some());
@@ -407,6 +803,89 @@ mod tests {
         assert!(config.get_profile("nonexistent").is_none());
     }
 
+    #[test]
+    fn test_effective_settings_layers() {
+        let mut work = Profile::new(
+            "work".to_string(),
+            "https://company.atlassian.net".to_string(),
+            "user@company.com".to_string(),
+        );
+        work.settings.page_size = Some(100);
+        work.settings.theme = Some("high-contrast".to_string());
+        work.settings.confirm_transitions = Some(true);
+        let personal = Profile::new(
+            "personal".to_string(),
+            "https://personal.atlassian.net".to_string(),
+            "user@personal.com".to_string(),
+        );
+
+        let mut config = Config {
+            settings: Settings {
+                page_size: 25,
+                theme: "light".to_string(),
+                ..Settings::default()
+            },
+            profiles: vec![work, personal],
+            ..Default::default()
+        };
+
+        let settings = config.effective_settings(Some("personal"));
+        assert_eq!(settings.page_size, 25);
+        assert_eq!(settings.theme, "light");
+
+        let settings = config.effective_settings(Some("work"));
+        assert_eq!(settings.page_size, 100);
+        assert_eq!(settings.theme, "high-contrast");
+        assert!(settings.confirm_transitions);
+
+        // Local overrides win over the profile
+        config.local = Some(LocalConfig {
+            settings: SettingsOverrides {
+                page_size: Some(10),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let settings = config.effective_settings(Some("work"));
+        assert_eq!(settings.page_size, 10);
+        assert_eq!(settings.theme, "high-contrast");
+
+        // The global layer itself is unchanged
+        assert_eq!(config.settings.page_size, 25);
+    }
+
+    #[test]
+    fn test_startup_profile_uses_local_pin() {
+        let mut config = Config {
+            profiles: vec![
+                Profile::new(
+                    "work".to_string(),
+                    "https://company.atlassian.net".to_string(),
+                    "user@company.com".to_string(),
+                ),
+                Profile::new(
+                    "personal".to_string(),
+                    "https://personal.atlassian.net".to_string(),
+                    "user@personal.com".to_string(),
+                ),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(config.startup_profile().unwrap().name, "work");
+
+        config.local = Some(LocalConfig {
+            profile: Some("personal".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(config.startup_profile().unwrap().name, "personal");
+        // The default profile is unaffected
+        assert_eq!(config.get_default_profile().unwrap().name, "work");
+
+        // An unknown pinned profile falls back to the default
+        config.local.as_mut().unwrap().profile = Some("missing".to_string());
+        assert_eq!(config.startup_profile().unwrap().name, "work");
+    }
+
     #[test]
     fn test_get_default_profile() {
         // With explicit default
@@ -427,6 +906,7 @@ mod tests {
                     "user@personal.com".to_string(),
                 ),
             ],
+            ..Default::default()
         };
         assert_eq!(config.get_default_profile().unwrap().name, "personal");
 
@@ -438,6 +918,7 @@ mod tests {
                 "https://company.atlassian.net".to_string(),
                 "user@company.com".to_string(),
             )],
+            ..Default::default()
         };
         assert_eq!(
             config_no_default.get_default_profile().unwrap().name,
@@ -487,6 +968,7 @@ mod tests {
                     "user@personal.com".to_string(),
                 ),
             ],
+            ..Default::default()
         };
 
         assert!(config.remove_profile("work"));
@@ -554,20 +1036,48 @@ email = "user@company.com"
     }
 
     #[test]
-    fn test_jql_history_methods() {
-        let mut config = Config::default();
-
-        assert!(config.jql_history().is_empty());
-
-        config.add_jql_to_history("query1".to_string());
-        config.add_jql_to_history("query2".to_string());
+    fn test_parse_config_with_templates() {
+        let toml_content = r#"
+[[templates]]
+name = "Bug report"
+project = "PROJ"
+issue_type = "Bug"
+labels = ["bug"]
+description = """
+Steps to reproduce:
+
+Expected:
+
+Actual:
+"""
+"#;
 
-        assert_eq!(config.jql_history(), &["query2", "query1"]);
+        let mut config: Config = toml::from_str(toml_content).unwrap();
+        assert!(config.validate().is_ok());
+        let template = config.get_issue_template("Bug report").unwrap();
+        assert_eq!(template.issue_type.as_deref(), Some("Bug"));
+        assert!(template.description.starts_with("Steps to reproduce:"));
+
+        // File templates are listed after config templates and must not clash
+        config.file_templates.push(IssueTemplate {
+            name: "Story".to_string(),
+            ..Default::default()
+        });
+        let names: Vec<_> = config.issue_templates().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Bug report", "Story"]);
+        config.file_templates[0].name = "Bug report".to_string();
+        assert!(config.validate().is_err());
+
+        // File templates are never saved to config.toml
+        let saved = toml::to_string_pretty(&config).unwrap();
+        assert_eq!(saved.matches("[[templates]]").count(), 1);
     }
 
     #[test]
-    fn test_parse_config_with_jql_history() {
-        let toml_content = r#"
+    fn test_read_file_migrates_old_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let original = r#"# My settings
 [settings]
 jql_history = ["project = TEST", "status = Open"]
 
@@ -575,9 +1085,96 @@ jql_history = ["project = TEST", "status = Open"]
 name = "work"
 url = "https://company.atlassian.net"
 email = "user@company.com"
+"#;
+        fs::write(&path, original).unwrap();
+
+        let state_dir = dir.path().join("state");
+        let config = Config::read_file(&path, &state_dir).unwrap();
+        assert_eq!(config.version, CURRENT_VERSION);
+        assert_eq!(
+            ProfileState::load(&state_dir, "work").unwrap().jql_history,
+            vec!["project = TEST", "status = Open"]
+        );
+
+        // The original is backed up and the file rewritten in place
+        let backup = dir.path().join("config.toml.v0.bak");
+        assert_eq!(fs::read_to_string(backup).unwrap(), original);
+        let migrated = fs::read_to_string(&path).unwrap();
+        assert!(migrated.starts_with("version = 2\n"));
+        assert!(migrated.contains("# My settings"));
+
+        // Reading the current version changes nothing
+        Config::read_file(&path, &state_dir).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), migrated);
+    }
+
+    #[test]
+    fn test_rename_state_keeps_other_profiles_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config {
+            state_dir: Some(dir.path().to_path_buf()),
+            ..Config::default()
+        };
+        config.profile_state_mut("a b").active_tab = 1;
+        config.profile_state_mut("a_b").active_tab = 2;
+        config.save_state("a b").unwrap();
+        config.save_state("a_b").unwrap();
+
+        config.rename_state("a b", "a/b").unwrap();
+
+        let load = |name: &str| ProfileState::load(dir.path(), name).unwrap().active_tab;
+        assert_eq!(load("a/b"), 1);
+        assert_eq!(load("a_b"), 2);
+        assert_eq!(load("a b"), 0);
+    }
+
+    #[test]
+    fn test_parse_config_with_keys() {
+        let toml_content = r#"
+[keys.issue_list]
+"Ctrl+j" = "move_down"
+"n" = "none"
+
+[keys.issue_detail]
+"x" = "change_status"
 "#;
 
         let config: Config = toml::from_str(toml_content).unwrap();
-        assert_eq!(config.jql_history(), &["project = TEST", "status = Open"]);
+        assert_eq!(config.keys["issue_list"]["Ctrl+j"], "move_down");
+        assert_eq!(config.keys["issue_detail"]["x"], "change_status");
+        assert!(config.validate().is_ok());
+
+        let saved = toml::to_string_pretty(&config).unwrap();
+        assert!(saved.contains("[keys.issue_list]"));
+    }
+
+    #[test]
+    fn test_invalid_keys_rejected() {
+        let toml_content = r#"
+[keys.issue_list]
+"x" = "launch_rockets"
+"#;
+
+        let config: Config = toml::from_str(toml_content).unwrap();
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("unknown action"));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let content = "[settings]\ntheme = \"dark\"\nvim_mode = yes\n";
+        let err = ConfigError::ParseError(toml::from_str::<Config>(content).unwrap_err());
+        assert_eq!(err.location(content), Some((3, 12)));
+        assert!(!err.summary().contains('|'));
+
+        assert_eq!(ConfigError::NoConfigDir.location(content), None);
+    }
+
+    #[test]
+    fn test_fallback_is_not_saved() {
+        let config = Config::fallback();
+        assert!(config.profiles.is_empty());
+        assert!(matches!(config.save(), Err(ConfigError::LoadFailed)));
     }
 }
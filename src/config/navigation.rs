@@ -0,0 +1,334 @@
+//! Navigation history (jump list).
+//!
+//! Records the issues and views visited so `Ctrl+O` / `Ctrl+I` can move back
+//! and forward through them, like Vim's jump list. Each profile keeps its own
+//! history, which also feeds the "recently viewed" entries in the command
+//! palette. It is kept in the profile's state file (see
+//! [`ProfileState`](super::ProfileState)).
+//!
+//! ```toml
+//! [[history]]
+//! view = "list"
+//! tab = 0
+//!
+//! [[history]]
+//! view = "issue"
+//! key = "PROJ-123"
+//! summary = "Fix login timeout"
+//! scroll = 12
+//! panel = "comments"
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// A panel open over the issue detail view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuePanel {
+    /// The comments panel.
+    Comments,
+    /// The change history panel.
+    History,
+}
+
+/// A visited location.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "view", rename_all = "snake_case")]
+pub enum Location {
+    /// The issue list in a tab.
+    List {
+        /// Index of the tab.
+        #[serde(default)]
+        tab: usize,
+    },
+    /// An issue's detail view.
+    Issue {
+        /// The issue key.
+        key: String,
+        /// The issue summary, shown in the recently viewed list.
+        #[serde(default)]
+        summary: String,
+        /// Scroll position of the detail view.
+        #[serde(default)]
+        scroll: u16,
+        /// Panel open over the detail view.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        panel: Option<IssuePanel>,
+    },
+}
+
+impl Location {
+    /// Check whether two locations refer to the same view, ignoring scroll
+    /// and panel state.
+    pub fn same_target(&self, other: &Location) -> bool {
+        match (self, other) {
+            (Location::List { tab: a }, Location::List { tab: b }) => a == b,
+            (Location::Issue { key: a, .. }, Location::Issue { key: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// A jump list of visited locations.
+///
+/// Visiting a location moves it to the end of the list, so each view appears
+/// at most once and the list doubles as a most-recently-visited history.
+/// Moving back and forward does not reorder entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "Vec<Location>", into = "Vec<Location>")]
+pub struct JumpList {
+    /// Visited locations, oldest first.
+    entries: Vec<Location>,
+    /// Index of the current location.
+    position: usize,
+}
+
+impl JumpList {
+    /// Maximum number of locations to remember.
+    pub const MAX_ENTRIES: usize = 100;
+
+    /// Create an empty jump list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if no locations have been visited.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the number of remembered locations.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Get the current location.
+    pub fn current(&self) -> Option<&Location> {
+        self.entries.get(self.position)
+    }
+
+    /// Record a visit to a location, making it the current one.
+    pub fn visit(&mut self, location: Location) {
+        self.entries.retain(|entry| !entry.same_target(&location));
+        self.entries.push(location);
+        if self.entries.len() > Self::MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.position = self.entries.len() - 1;
+    }
+
+    /// Update the scroll and panel state of the current location.
+    ///
+    /// Does nothing if the current entry is a different view.
+    pub fn update_current(&mut self, location: Location) {
+        if let Some(entry) = self.entries.get_mut(self.position) {
+            if entry.same_target(&location) {
+                *entry = location;
+            }
+        }
+    }
+
+    /// Move back to the previous location.
+    pub fn back(&mut self) -> Option<&Location> {
+        if self.position == 0 || self.entries.is_empty() {
+            return None;
+        }
+        self.position -= 1;
+        self.entries.get(self.position)
+    }
+
+    /// Move forward to the next location.
+    pub fn forward(&mut self) -> Option<&Location> {
+        if self.position + 1 >= self.entries.len() {
+            return None;
+        }
+        self.position += 1;
+        self.entries.get(self.position)
+    }
+
+    /// Forget a closed tab.
+    ///
+    /// Drops the tab's list entries and shifts the indices of later tabs down
+    /// so the remaining entries still point at the same tabs.
+    pub fn remove_tab(&mut self, index: usize) {
+        let mut position = self.position;
+        let mut retained = Vec::with_capacity(self.entries.len());
+        for (i, mut entry) in self.entries.drain(..).enumerate() {
+            match &mut entry {
+                Location::List { tab } if *tab == index => {
+                    if i <= self.position {
+                        position = position.saturating_sub(1);
+                    }
+                    continue;
+                }
+                Location::List { tab } if *tab > index => *tab -= 1,
+                _ => {}
+            }
+            retained.push(entry);
+        }
+        self.entries = retained;
+        self.position = position.min(self.entries.len().saturating_sub(1));
+    }
+
+    /// Get the visited issues as `(key, summary)` pairs, most recent first.
+    pub fn recent_issues(&self) -> Vec<(&str, &str)> {
+        self.entries
+            .iter()
+            .rev()
+            .filter_map(|entry| match entry {
+                Location::Issue { key, summary, .. } => Some((key.as_str(), summary.as_str())),
+                Location::List { .. } => None,
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<Location>> for JumpList {
+    fn from(mut entries: Vec<Location>) -> Self {
+        if entries.len() > Self::MAX_ENTRIES {
+            entries.drain(..entries.len() - Self::MAX_ENTRIES);
+        }
+        let position = entries.len().saturating_sub(1);
+        Self { entries, position }
+    }
+}
+
+impl From<JumpList> for Vec<Location> {
+    fn from(list: JumpList) -> Self {
+        list.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(key: &str) -> Location {
+        Location::Issue {
+            key: key.to_string(),
+            summary: String::new(),
+            scroll: 0,
+            panel: None,
+        }
+    }
+
+    #[test]
+    fn test_back_and_forward() {
+        let mut list = JumpList::new();
+        list.visit(Location::List { tab: 0 });
+        list.visit(issue("A-1"));
+        list.visit(issue("A-2"));
+
+        assert_eq!(list.back(), Some(&issue("A-1")));
+        assert_eq!(list.back(), Some(&Location::List { tab: 0 }));
+        assert_eq!(list.back(), None);
+        assert_eq!(list.forward(), Some(&issue("A-1")));
+        assert_eq!(list.forward(), Some(&issue("A-2")));
+        assert_eq!(list.forward(), None);
+    }
+
+    #[test]
+    fn test_visit_moves_existing_entry_to_end() {
+        let mut list = JumpList::new();
+        list.visit(issue("A-1"));
+        list.visit(issue("A-2"));
+        list.back();
+        list.visit(issue("A-3"));
+        list.visit(issue("A-1"));
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list.recent_issues(),
+            vec![("A-1", ""), ("A-3", ""), ("A-2", "")]
+        );
+        assert_eq!(list.forward(), None);
+    }
+
+    #[test]
+    fn test_update_current_keeps_scroll_and_panel() {
+        let mut list = JumpList::new();
+        list.visit(issue("A-1"));
+        list.update_current(Location::Issue {
+            key: "A-1".to_string(),
+            summary: "First".to_string(),
+            scroll: 7,
+            panel: Some(IssuePanel::History),
+        });
+        // A different view does not overwrite the current entry
+        list.update_current(issue("A-2"));
+
+        match list.current() {
+            Some(Location::Issue { scroll, panel, .. }) => {
+                assert_eq!(*scroll, 7);
+                assert_eq!(*panel, Some(IssuePanel::History));
+            }
+            other => panic!("unexpected location: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_visit_caps_entries() {
+        let mut list = JumpList::new();
+        for i in 0..JumpList::MAX_ENTRIES + 5 {
+            list.visit(issue(&format!("A-{}", i)));
+        }
+        assert_eq!(list.len(), JumpList::MAX_ENTRIES);
+        assert_eq!(list.recent_issues().last().unwrap().0, "A-5");
+    }
+
+    #[test]
+    fn test_remove_tab_shifts_later_tabs() {
+        let mut list = JumpList::new();
+        list.visit(Location::List { tab: 0 });
+        list.visit(Location::List { tab: 1 });
+        list.visit(issue("A-1"));
+        list.visit(Location::List { tab: 2 });
+
+        list.remove_tab(1);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.current(), Some(&Location::List { tab: 1 }));
+        assert_eq!(list.back(), Some(&issue("A-1")));
+        assert_eq!(list.back(), Some(&Location::List { tab: 0 }));
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_remove_current_tab_moves_to_previous_entry() {
+        let mut list = JumpList::new();
+        list.visit(Location::List { tab: 0 });
+        list.visit(issue("A-1"));
+        list.visit(Location::List { tab: 1 });
+
+        list.remove_tab(1);
+
+        assert_eq!(list.current(), Some(&issue("A-1")));
+        assert_eq!(list.forward(), None);
+    }
+
+    #[test]
+    fn test_roundtrip_starts_at_latest_entry() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            history: JumpList,
+        }
+
+        let mut list = JumpList::new();
+        list.visit(Location::List { tab: 1 });
+        list.visit(Location::Issue {
+            key: "A-1".to_string(),
+            summary: "First".to_string(),
+            scroll: 3,
+            panel: Some(IssuePanel::Comments),
+        });
+        list.back();
+
+        let toml_str = toml::to_string(&Wrapper {
+            history: list.clone(),
+        })
+        .unwrap();
+        let parsed: Wrapper = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.history.recent_issues(), list.recent_issues());
+        assert_eq!(parsed.history.current(), list.forward());
+    }
+}
@@ -5,7 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{ConfigError, Result};
+use super::columns::validate_columns;
+use super::{ConfigError, Result, SettingsOverrides};
+use crate::api::columns::ColumnConfig;
 
 /// A JIRA profile configuration.
 ///
@@ -27,12 +29,28 @@ pub struct Profile {
     ///
     /// Used for JIRA API authentication along with the API token.
     pub email: String,
+
+    /// Issue list columns for this profile.
+    ///
+    /// Overrides `settings.columns` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<ColumnConfig>>,
+
+    /// Settings overridden while this profile is active.
+    #[serde(default, skip_serializing_if = "SettingsOverrides::is_empty")]
+    pub settings: SettingsOverrides,
 }
 
 impl Profile {
     /// Create a new profile.
     pub fn new(name: String, url: String, email: String) -> Self {
-        Self { name, url, email }
+        Self {
+            name,
+            url,
+            email,
+            columns: None,
+            settings: SettingsOverrides::default(),
+        }
     }
 
     /// Validate this profile.
@@ -41,6 +59,7 @@ impl Profile {
     /// - The name is non-empty
     /// - The URL is non-empty and has a valid format
     /// - The email is non-empty and has a valid format
+    /// - Any configured columns refer to known fields
     ///
     /// # Errors
     ///
@@ -90,6 +109,10 @@ impl Profile {
             )));
         }
 
+        if let Some(ref columns) = self.columns {
+            validate_columns(columns, &format!("profile '{}'", self.name))?;
+        }
+
         Ok(())
     }
 
@@ -118,6 +141,31 @@ mod tests {
         assert_eq!(profile.email, "user@company.com");
     }
 
+    #[test]
+    fn test_profile_settings_overrides() {
+        let toml_str = r#"
+            name = "work"
+            url = "https://company.atlassian.net"
+            email = "user@company.com"
+
+            [settings]
+            page_size = 100
+            confirm_transitions = true
+        "#;
+        let profile: Profile = toml::from_str(toml_str).unwrap();
+        assert_eq!(profile.settings.page_size, Some(100));
+        assert_eq!(profile.settings.confirm_transitions, Some(true));
+        assert!(profile.settings.theme.is_none());
+
+        // Empty overrides are not written back
+        let plain = Profile::new(
+            "home".to_string(),
+            "https://home.atlassian.net".to_string(),
+            "me@home.com".to_string(),
+        );
+        assert!(!toml::to_string(&plain).unwrap().contains("[settings]"));
+    }
+
     #[test]
     fn test_valid_profile() {
         let profile = Profile::new(
@@ -257,4 +305,24 @@ mod tests {
 
         assert_eq!(parsed, profile);
     }
+
+    #[test]
+    fn test_profile_columns() {
+        let mut profile = Profile::new(
+            "work".to_string(),
+            "https://company.atlassian.net".to_string(),
+            "user@company.com".to_string(),
+        );
+        profile.columns = Some(vec![ColumnConfig::new("key"), ColumnConfig::new("sprint")]);
+        assert!(profile.validate().is_ok());
+
+        let toml_str = toml::to_string(&profile).unwrap();
+        let parsed: Profile = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed, profile);
+
+        profile.columns = Some(vec![ColumnConfig::new("nonsense")]);
+        let result = profile.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("unknown column"));
+    }
 }
@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::columns::ColumnConfig;
 use crate::api::types::SavedFilter;
 use crate::ui::theme::CustomThemeConfig;
 
@@ -41,9 +42,6 @@ fn default_confirm_discard() -> bool {
     true
 }
 
-/// Maximum number of JQL queries to keep in history.
-const MAX_JQL_HISTORY: usize = 10;
-
 /// Application-wide settings.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Settings {
@@ -53,7 +51,8 @@ pub struct Settings {
     #[serde(default)]
     pub default_profile: Option<String>,
 
-    /// The UI theme to use.
+    /// The UI theme to use: a built-in theme ("dark", "light",
+    /// "high-contrast") or the name of a theme file.
     ///
     /// Defaults to "dark".
     #[serde(default = "default_theme")]
@@ -65,6 +64,12 @@ pub struct Settings {
     #[serde(default = "default_vim_mode")]
     pub vim_mode: bool,
 
+    /// Whether description and comment editors use Vim-style modal editing.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub vim_editing: bool,
+
     /// Cache time-to-live in minutes.
     ///
     /// Defaults to 30 minutes.
@@ -85,12 +90,6 @@ pub struct Settings {
     #[serde(default = "default_page_size")]
     pub page_size: u32,
 
-    /// JQL query history (most recent first).
-    ///
-    /// Limited to 10 entries.
-    #[serde(default)]
-    pub jql_history: Vec<String>,
-
     /// Whether to show confirmation dialog for status transitions.
     ///
     /// Defaults to `false` (transitions execute immediately).
@@ -114,6 +113,13 @@ pub struct Settings {
     /// Named filters that can be quickly applied.
     #[serde(default)]
     pub saved_filters: Vec<SavedFilter>,
+
+    /// Columns shown in the issue list.
+    ///
+    /// Empty means the built-in default columns. Can be overridden per profile
+    /// and per saved filter.
+    #[serde(default)]
+    pub columns: Vec<ColumnConfig>,
 }
 
 impl Default for Settings {
@@ -122,14 +128,15 @@ impl Default for Settings {
             default_profile: None,
             theme: default_theme(),
             vim_mode: default_vim_mode(),
+            vim_editing: false,
             cache_ttl_minutes: default_cache_ttl(),
             cache_max_size_mb: default_cache_max_size(),
             page_size: default_page_size(),
-            jql_history: Vec::new(),
             confirm_transitions: false,
             confirm_discard_changes: default_confirm_discard(),
             custom_theme: None,
             saved_filters: Vec::new(),
+            columns: Vec::new(),
         }
     }
 }
@@ -153,22 +160,6 @@ impl Settings {
         }
     }
 
-    /// Add a JQL query to the history.
-    ///
-    /// The query is added to the front of the history. If the query already
-    /// exists in the history, it is moved to the front. The history is
-    /// limited to 10 entries.
-    pub fn add_jql_to_history(&mut self, query: String) {
-        // Remove duplicate if exists
-        self.jql_history.retain(|q| q != &query);
-
-        // Add to front
-        self.jql_history.insert(0, query);
-
-        // Trim to max size
-        self.jql_history.truncate(MAX_JQL_HISTORY);
-    }
-
     /// Add a saved filter.
     ///
     /// If a filter with the same name already exists, it is replaced.
@@ -189,6 +180,87 @@ impl Settings {
     }
 }
 
+/// Settings that a profile or a project-local `.lazyjira.toml` can override.
+///
+/// Each field that is set replaces the corresponding [`Settings`] field.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SettingsOverrides {
+    /// Override `theme`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+
+    /// Override `vim_mode`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vim_mode: Option<bool>,
+
+    /// Override `vim_editing`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vim_editing: Option<bool>,
+
+    /// Override `cache_ttl_minutes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_ttl_minutes: Option<u32>,
+
+    /// Override `cache_max_size_mb`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_max_size_mb: Option<u64>,
+
+    /// Override `page_size`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<u32>,
+
+    /// Override `confirm_transitions`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm_transitions: Option<bool>,
+
+    /// Override `confirm_discard_changes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm_discard_changes: Option<bool>,
+
+    /// Override `custom_theme`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_theme: Option<CustomThemeConfig>,
+}
+
+impl SettingsOverrides {
+    /// Check whether no setting is overridden.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Apply the overrides to settings.
+    pub fn apply_to(&self, settings: &mut Settings) {
+        if let Some(ref theme) = self.theme {
+            settings.theme = theme.clone();
+        }
+        if let Some(vim_mode) = self.vim_mode {
+            settings.vim_mode = vim_mode;
+        }
+        if let Some(vim_editing) = self.vim_editing {
+            settings.vim_editing = vim_editing;
+        }
+        if let Some(ttl) = self.cache_ttl_minutes {
+            settings.cache_ttl_minutes = ttl;
+        }
+        if let Some(size) = self.cache_max_size_mb {
+            settings.cache_max_size_mb = size;
+        }
+        if let Some(page_size) = self.page_size {
+            settings.page_size = page_size;
+        }
+        if let Some(confirm) = self.confirm_transitions {
+            settings.confirm_transitions = confirm;
+        }
+        if let Some(confirm) = self.confirm_discard_changes {
+            settings.confirm_discard_changes = confirm;
+        }
+        if let Some(ref custom_theme) = self.custom_theme {
+            settings.custom_theme = Some(custom_theme.clone());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,14 +271,15 @@ mod tests {
         assert!(settings.default_profile.is_none());
         assert_eq!(settings.theme, "dark");
         assert!(settings.vim_mode);
+        assert!(!settings.vim_editing);
         assert_eq!(settings.cache_ttl_minutes, 30);
         assert_eq!(settings.cache_max_size_mb, 100);
         assert_eq!(settings.page_size, 50);
-        assert!(settings.jql_history.is_empty());
         assert!(!settings.confirm_transitions);
         assert!(settings.confirm_discard_changes);
         assert!(settings.custom_theme.is_none());
         assert!(settings.saved_filters.is_empty());
+        assert!(settings.columns.is_empty());
     }
 
     #[test]
@@ -215,14 +288,15 @@ mod tests {
             default_profile: Some("work".to_string()),
             theme: "light".to_string(),
             vim_mode: false,
+            vim_editing: true,
             cache_ttl_minutes: 60,
             cache_max_size_mb: 200,
             page_size: 25,
-            jql_history: vec!["project = TEST".to_string()],
             confirm_transitions: true,
             confirm_discard_changes: false,
             custom_theme: None,
             saved_filters: Vec::new(),
+            columns: vec![ColumnConfig::new("key"), ColumnConfig::new("updated")],
         };
 
         let toml_str = toml::to_string(&settings).unwrap();
@@ -242,10 +316,10 @@ theme = "monokai"
         assert!(settings.default_profile.is_none()); // default
         assert_eq!(settings.theme, "monokai"); // specified
         assert!(settings.vim_mode); // default
+        assert!(!settings.vim_editing); // default
         assert_eq!(settings.cache_ttl_minutes, 30); // default
         assert_eq!(settings.cache_max_size_mb, 100); // default
         assert_eq!(settings.page_size, 50); // default
-        assert!(settings.jql_history.is_empty()); // default
         assert!(!settings.confirm_transitions); // default
         assert!(settings.confirm_discard_changes); // default
     }
@@ -261,11 +335,37 @@ theme = "monokai"
         assert_eq!(settings.cache_ttl_minutes, 30);
         assert_eq!(settings.cache_max_size_mb, 100);
         assert_eq!(settings.page_size, 50);
-        assert!(settings.jql_history.is_empty());
         assert!(!settings.confirm_transitions);
         assert!(settings.confirm_discard_changes);
     }
 
+    #[test]
+    fn test_settings_overrides() {
+        let overrides: SettingsOverrides = toml::from_str(
+            r#"
+page_size = 100
+confirm_transitions = true
+theme = "high-contrast"
+vim_editing = true
+"#,
+        )
+        .unwrap();
+        assert!(!overrides.is_empty());
+
+        let mut settings = Settings::default();
+        overrides.apply_to(&mut settings);
+        assert_eq!(settings.page_size, 100);
+        assert!(settings.confirm_transitions);
+        assert_eq!(settings.theme, "high-contrast");
+        assert!(settings.vim_editing);
+        // Unset fields keep their value
+        assert!(settings.vim_mode);
+        assert_eq!(settings.cache_ttl_minutes, 30);
+
+        assert!(SettingsOverrides::default().is_empty());
+        assert!(toml::from_str::<SettingsOverrides>("jql_history = []").is_err());
+    }
+
     #[test]
     fn test_confirmation_settings() {
         let toml_content = r#"
@@ -278,44 +378,6 @@ confirm_discard_changes = false
         assert!(!settings.confirm_discard_changes);
     }
 
-    #[test]
-    fn test_add_jql_to_history() {
-        let mut settings = Settings::default();
-
-        settings.add_jql_to_history("query1".to_string());
-        assert_eq!(settings.jql_history, vec!["query1"]);
-
-        settings.add_jql_to_history("query2".to_string());
-        assert_eq!(settings.jql_history, vec!["query2", "query1"]);
-    }
-
-    #[test]
-    fn test_add_jql_to_history_deduplication() {
-        let mut settings = Settings::default();
-
-        settings.add_jql_to_history("query1".to_string());
-        settings.add_jql_to_history("query2".to_string());
-        settings.add_jql_to_history("query1".to_string());
-
-        // query1 should be moved to front
-        assert_eq!(settings.jql_history, vec!["query1", "query2"]);
-    }
-
-    #[test]
-    fn test_add_jql_to_history_max_size() {
-        let mut settings = Settings::default();
-
-        // Add more than MAX_JQL_HISTORY items
-        for i in 0..15 {
-            settings.add_jql_to_history(format!("query{}", i));
-        }
-
-        // Should be limited to MAX_JQL_HISTORY (10)
-        assert_eq!(settings.jql_history.len(), 10);
-        // Most recent should be first
-        assert_eq!(settings.jql_history[0], "query14");
-    }
-
     #[test]
     fn test_custom_theme_config() {
         let toml_content = r##"
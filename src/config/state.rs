@@ -0,0 +1,225 @@
+//! Per-profile session state.
+//!
+//! What changes just from using the app — the JQL history, recently used
+//! commands, open tabs, the last open view and the navigation history — is
+//! kept in a state file
+//! per profile in the data directory rather than in `config.toml`, so a
+//! hand-edited config file is only written for explicit settings changes:
+//!
+//! | Platform | Path |
+//! |----------|------|
+//! | Linux | `~/.local/share/lazyjira/state/<profile>.toml` |
+//! | macOS | `~/Library/Application Support/lazyjira/state/<profile>.toml` |
+//! | Windows | `C:\Users\<User>\AppData\Local\lazyjira\state\<profile>.toml` |
+//!
+//! ```toml
+//! jql_history = ["project = PROJ AND status = Open"]
+//! recent_commands = ["nav.profiles", "issue.refresh"]
+//! active_tab = 1
+//!
+//! [[tabs]]
+//! jql = "type = Bug AND assignee = currentUser()"
+//!
+//! [[tabs]]
+//! name = "Recently updated"
+//! jql = "project = PROJ"
+//!
+//! [location]
+//! view = "issue"
+//! key = "PROJ-123"
+//! scroll = 4
+//!
+//! [[history]]
+//! view = "issue"
+//! key = "PROJ-123"
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ConfigError, JumpList, Location, Result, TabConfig};
+
+/// Maximum number of JQL queries to keep in history.
+const MAX_JQL_HISTORY: usize = 10;
+
+/// Session state of one profile.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProfileState {
+    /// JQL queries run with this profile (most recent first).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub jql_history: Vec<String>,
+
+    /// IDs of recently used commands (most recent first).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_commands: Vec<String>,
+
+    /// Index of the active tab.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub active_tab: usize,
+
+    /// Issue list tabs, restored on startup.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tabs: Vec<TabConfig>,
+
+    /// The issue open when the app was last closed, reopened on startup.
+    ///
+    /// Unset when the issue list was open; `active_tab` restores that.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+
+    /// Recently visited issues and views, restored on startup.
+    #[serde(default, skip_serializing_if = "JumpList::is_empty")]
+    pub history: JumpList,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+impl ProfileState {
+    /// Get the state directory path.
+    pub fn state_dir() -> Result<PathBuf> {
+        dirs::data_local_dir()
+            .ok_or(ConfigError::NoDataDir)
+            .map(|p| p.join("lazyjira").join("state"))
+    }
+
+    /// Get the path of a profile's state file in `dir`.
+    ///
+    /// Bytes other than lowercase ASCII letters, digits, `-`, `_` and `.` are
+    /// percent-encoded, so every profile gets its own file even on
+    /// case-insensitive file systems.
+    pub fn path(dir: &Path, profile: &str) -> PathBuf {
+        let mut name = String::with_capacity(profile.len());
+        for byte in profile.bytes() {
+            if byte.is_ascii_lowercase() || byte.is_ascii_digit() || b"-_.".contains(&byte) {
+                name.push(char::from(byte));
+            } else {
+                name.push_str(&format!("%{:02X}", byte));
+            }
+        }
+        dir.join(format!("{}.toml", name))
+    }
+
+    /// Load a profile's state from `dir`.
+    ///
+    /// Returns the default state if the profile has no state file yet.
+    pub fn load(dir: &Path, profile: &str) -> Result<Self> {
+        let path = Self::path(dir, profile);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path).map_err(ConfigError::ReadError)?;
+        toml::from_str(&content).map_err(ConfigError::ParseError)
+    }
+
+    /// Save a profile's state to `dir`, creating the directory if needed.
+    pub fn save(&self, dir: &Path, profile: &str) -> Result<()> {
+        fs::create_dir_all(dir).map_err(ConfigError::CreateDirError)?;
+        let content = toml::to_string_pretty(self).map_err(ConfigError::SerializeError)?;
+        fs::write(Self::path(dir, profile), content).map_err(ConfigError::WriteError)
+    }
+
+    /// Add a JQL query to the history.
+    ///
+    /// The query is added to the front of the history. If the query already
+    /// exists in the history, it is moved to the front. The history is
+    /// limited to 10 entries.
+    pub fn add_jql_to_history(&mut self, query: String) {
+        self.jql_history.retain(|q| q != &query);
+        self.jql_history.insert(0, query);
+        self.jql_history.truncate(MAX_JQL_HISTORY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_state_roundtrip() {
+        let dir = tempdir().unwrap();
+        let mut state = ProfileState {
+            jql_history: vec!["project = A".to_string()],
+            recent_commands: vec!["nav.profiles".to_string()],
+            active_tab: 1,
+            tabs: vec![
+                TabConfig::default(),
+                TabConfig {
+                    jql: Some("type = Bug".to_string()),
+                    ..TabConfig::default()
+                },
+            ],
+            location: Some(Location::Issue {
+                key: "A-1".to_string(),
+                summary: "First".to_string(),
+                scroll: 4,
+                panel: None,
+            }),
+            history: JumpList::new(),
+        };
+        state.history.visit(Location::List { tab: 1 });
+
+        state.save(dir.path(), "work").unwrap();
+        assert_eq!(ProfileState::load(dir.path(), "work").unwrap(), state);
+        // Profiles without a state file start empty
+        assert_eq!(
+            ProfileState::load(dir.path(), "personal").unwrap(),
+            ProfileState::default()
+        );
+    }
+
+    #[test]
+    fn test_path_is_safe_file_name() {
+        let dir = Path::new("/state");
+        assert_eq!(
+            ProfileState::path(dir, "work"),
+            Path::new("/state/work.toml")
+        );
+        assert_eq!(
+            ProfileState::path(dir, "../a/b"),
+            Path::new("/state/..%2Fa%2Fb.toml")
+        );
+        assert_eq!(
+            ProfileState::path(dir, "Jira Cloud"),
+            Path::new("/state/%4Aira%20%43loud.toml")
+        );
+    }
+
+    #[test]
+    fn test_paths_do_not_collide() {
+        let dir = Path::new("/state");
+        let names = ["a b", "a/b", "a_b", "a%5Fb", "A_b", "a_B", "ä_b"];
+        // Compared case-insensitively, as some file systems do
+        let paths: std::collections::HashSet<_> = names
+            .iter()
+            .map(|name| {
+                ProfileState::path(dir, name)
+                    .to_string_lossy()
+                    .to_lowercase()
+            })
+            .collect();
+        assert_eq!(paths.len(), names.len());
+    }
+
+    #[test]
+    fn test_add_jql_to_history() {
+        let mut state = ProfileState::default();
+
+        state.add_jql_to_history("query1".to_string());
+        state.add_jql_to_history("query2".to_string());
+        state.add_jql_to_history("query1".to_string());
+        // query1 should be moved to front
+        assert_eq!(state.jql_history, vec!["query1", "query2"]);
+
+        for i in 0..15 {
+            state.add_jql_to_history(format!("query{}", i));
+        }
+        // Limited to MAX_JQL_HISTORY, most recent first
+        assert_eq!(state.jql_history.len(), MAX_JQL_HISTORY);
+        assert_eq!(state.jql_history[0], "query14");
+    }
+}
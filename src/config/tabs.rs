@@ -0,0 +1,94 @@
+//! Issue list tab persistence.
+//!
+//! Each profile remembers its open tabs in its state file (see
+//! [`ProfileState`](super::ProfileState)) so they can be restored on the next
+//! start. The query, sort and position in the list are stored; issues are
+//! always refetched.
+//!
+//! ```toml
+//! active_tab = 1
+//!
+//! [[tabs]]
+//! jql = "type = Bug AND assignee = currentUser()"
+//!
+//! [[tabs]]
+//! name = "Recently updated"
+//! jql = "project = PROJ"
+//! sort = "updated"
+//! selected = "PROJ-42"
+//! scroll = 20
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::types::FilterState;
+
+/// A persisted issue list tab.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TabConfig {
+    /// Tab title, e.g. the name of the saved filter it shows.
+    ///
+    /// Derived from the query when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Direct JQL query shown in this tab.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jql: Option<String>,
+
+    /// Filter panel state used when no JQL is set.
+    #[serde(default, skip_serializing_if = "FilterState::is_empty")]
+    pub filter: FilterState,
+
+    /// Sort column field identifier (e.g. `updated`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+
+    /// Whether the sort is ascending (descending by default).
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub sort_ascending: bool,
+
+    /// Key of the selected issue, selected again once the issues are fetched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected: Option<String>,
+
+    /// Index of the first visible row.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub scroll: usize,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_tab_serializes_empty() {
+        let toml_str = toml::to_string(&TabConfig::default()).unwrap();
+        assert!(toml_str.trim().is_empty());
+    }
+
+    #[test]
+    fn test_tab_roundtrip() {
+        let tab = TabConfig {
+            name: Some("Bugs".to_string()),
+            jql: Some("type = Bug".to_string()),
+            filter: FilterState::default(),
+            sort: Some("updated".to_string()),
+            sort_ascending: true,
+            selected: Some("PROJ-42".to_string()),
+            scroll: 20,
+        };
+
+        let toml_str = toml::to_string(&tab).unwrap();
+        let parsed: TabConfig = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed, tab);
+    }
+}
@@ -0,0 +1,293 @@
+//! Issue templates for the create issue form.
+//!
+//! A template pre-fills the create issue form with a project, issue type,
+//! summary prefix, description skeleton, labels, components, priority and
+//! assignee. Templates are defined in `config.toml`:
+//!
+//! ```toml
+//! [[templates]]
+//! name = "Bug report"
+//! project = "PROJ"
+//! issue_type = "Bug"
+//! summary_prefix = "[Bug] "
+//! priority = "High"
+//! labels = ["bug", "triage"]
+//! components = ["Backend"]
+//! description = """
+//! ## Steps to reproduce
+//!
+//! 1.
+//!
+//! ## Expected
+//!
+//! ## Actual
+//! """
+//! ```
+//!
+//! or as Markdown files in `<config_dir>/templates/`. The file body is the
+//! description; the other fields go in optional `+++`-delimited TOML front
+//! matter. The template is named after the file unless `name` is set:
+//!
+//! ```text
+//! +++
+//! issue_type = "Bug"
+//! labels = ["bug"]
+//! +++
+//! ## Steps to reproduce
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ConfigError, Result};
+
+/// Line that opens and closes the front matter of a template file.
+const FRONT_MATTER_DELIMITER: &str = "+++";
+
+/// A named template for new issues.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IssueTemplate {
+    /// Template name shown in the template picker.
+    #[serde(default)]
+    pub name: String,
+
+    /// Project key the issue is created in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    /// Issue type name (e.g. `Bug`), matched case-insensitively.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+
+    /// Text the summary starts with.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub summary_prefix: String,
+
+    /// Markdown description skeleton.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+
+    /// Labels added to the issue.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    /// Component names added to the issue.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<String>,
+
+    /// Priority name (e.g. `High`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+
+    /// Account ID of the default assignee.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+
+    /// Display name of the default assignee, shown in the form.
+    ///
+    /// Falls back to the account ID when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_name: Option<String>,
+}
+
+impl IssueTemplate {
+    /// Parse a template file.
+    ///
+    /// `default_name` is used when the front matter does not set a name.
+    pub fn parse_file(default_name: &str, content: &str) -> Result<Self> {
+        let (mut template, body) = match split_front_matter(content) {
+            Some((front_matter, body)) => {
+                let template: IssueTemplate =
+                    toml::from_str(front_matter).map_err(ConfigError::ParseError)?;
+                (template, body)
+            }
+            None => (IssueTemplate::default(), content),
+        };
+
+        if template.name.trim().is_empty() {
+            template.name = default_name.to_string();
+        }
+        if template.description.is_empty() {
+            template.description = body.trim_start_matches(['\r', '\n']).to_string();
+        }
+        Ok(template)
+    }
+
+    /// Validate the template.
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(ConfigError::ValidationError(
+                "issue template name cannot be empty".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get a one-line description of what the template sets.
+    ///
+    /// Used as the detail text in the template picker, e.g. `PROJ · Bug`.
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<&str> = Vec::new();
+        if let Some(ref project) = self.project {
+            parts.push(project);
+        }
+        if let Some(ref issue_type) = self.issue_type {
+            parts.push(issue_type);
+        }
+        if let Some(ref priority) = self.priority {
+            parts.push(priority);
+        }
+        parts.join(" · ")
+    }
+}
+
+/// Split a template file into its front matter and body.
+///
+/// Returns `None` if the file has no front matter.
+fn split_front_matter(content: &str) -> Option<(&str, &str)> {
+    let rest = content.strip_prefix(FRONT_MATTER_DELIMITER)?;
+    let rest = rest
+        .strip_prefix("\r\n")
+        .or_else(|| rest.strip_prefix('\n'))?;
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Load the template files (`*.md`) in a directory.
+///
+/// Templates are sorted by file name. A missing directory yields no templates.
+pub fn load_template_files(dir: &Path) -> Result<Vec<IssueTemplate>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map_err(ConfigError::ReadError)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let content = fs::read_to_string(path).map_err(ConfigError::ReadError)?;
+            let stem = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            IssueTemplate::parse_file(&stem, &content).map_err(|e| {
+                ConfigError::ValidationError(format!(
+                    "invalid template file '{}': {}",
+                    path.display(),
+                    e
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Validate a list of templates, checking that names are set and unique.
+pub fn validate_templates<'a>(
+    templates: impl IntoIterator<Item = &'a IssueTemplate>,
+) -> Result<()> {
+    let mut seen = std::collections::HashSet::new();
+    for template in templates {
+        template.validate()?;
+        if !seen.insert(template.name.as_str()) {
+            return Err(ConfigError::ValidationError(format!(
+                "duplicate issue template name: '{}'",
+                template.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_config_template() {
+        let toml_str = r#"
+            name = "Bug report"
+            project = "PROJ"
+            issue_type = "Bug"
+            labels = ["bug", "triage"]
+            description = "Steps to reproduce:\n"
+        "#;
+        let template: IssueTemplate = toml::from_str(toml_str).unwrap();
+        assert_eq!(template.name, "Bug report");
+        assert_eq!(template.project.as_deref(), Some("PROJ"));
+        assert_eq!(template.labels, vec!["bug", "triage"]);
+        assert!(template.components.is_empty());
+        assert_eq!(template.summary(), "PROJ · Bug");
+    }
+
+    #[test]
+    fn test_parse_file_with_front_matter() {
+        let content = "+++\nissue_type = \"Bug\"\nlabels = [\"bug\"]\n+++\n\n## Expected\n";
+        let template = IssueTemplate::parse_file("bug", content).unwrap();
+        assert_eq!(template.name, "bug");
+        assert_eq!(template.issue_type.as_deref(), Some("Bug"));
+        assert_eq!(template.labels, vec!["bug"]);
+        assert_eq!(template.description, "## Expected\n");
+    }
+
+    #[test]
+    fn test_parse_file_without_front_matter() {
+        let template = IssueTemplate::parse_file("spike", "## Goal\n").unwrap();
+        assert_eq!(template.name, "spike");
+        assert_eq!(template.description, "## Goal\n");
+        assert!(template.issue_type.is_none());
+    }
+
+    #[test]
+    fn test_parse_file_invalid_front_matter() {
+        let content = "+++\nlabels = \"bug\"\n+++\nbody";
+        assert!(IssueTemplate::parse_file("bug", content).is_err());
+    }
+
+    #[test]
+    fn test_load_template_files() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("b-story.md"),
+            "+++\nname = \"Story\"\n+++\nAs a user",
+        )
+        .unwrap();
+        fs::write(dir.path().join("a-bug.md"), "## Steps to reproduce").unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let templates = load_template_files(dir.path()).unwrap();
+        let names: Vec<_> = templates.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["a-bug", "Story"]);
+        assert_eq!(templates[1].description, "As a user");
+
+        assert!(load_template_files(&dir.path().join("missing"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_validate_templates() {
+        let named = |name: &str| IssueTemplate {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        assert!(validate_templates(&[named("Bug"), named("Story")]).is_ok());
+        assert!(validate_templates(&[named("Bug"), named("Bug")]).is_err());
+        assert!(validate_templates(&[named(" ")]).is_err());
+    }
+}
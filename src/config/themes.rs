@@ -0,0 +1,510 @@
+//! Theme files.
+//!
+//! Complete themes live in `<config_dir>/themes/`. A TOML theme file can set
+//! every theme color, plus colors for individual status names and issue types.
+//! The theme is named after the file unless `name` is set, and colors it does
+//! not set come from its `base` built-in theme (dark by default):
+//!
+//! ```toml
+//! name = "Solarized Light"
+//! base = "light"
+//!
+//! [colors]
+//! fg = "#657b83"
+//! accent = "#268bd2"
+//! selection_bg = "#eee8d5"
+//!
+//! [status]
+//! "In Review" = "#6c71c4"
+//!
+//! [issue_types]
+//! Bug = "#dc322f"
+//! Epic = "magenta"
+//! ```
+//!
+//! base16 and base24 color schemes (`*.yaml` / `*.yml`) in the same directory
+//! are imported by mapping their palette onto the theme colors.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ConfigError, Result};
+use crate::ui::theme::{parse_color, Theme};
+
+/// The base16 palette slots, all of which a scheme must define.
+const BASE16_SLOTS: [&str; 16] = [
+    "base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07", "base08",
+    "base09", "base0A", "base0B", "base0C", "base0D", "base0E", "base0F",
+];
+
+/// Theme colors filled from base16 palette slots.
+///
+/// Follows the base16 styling guidelines: `base00`-`base07` are backgrounds
+/// to foregrounds, `base08`-`base0F` are red, orange, yellow, green, cyan,
+/// blue, magenta and brown.
+const BASE16_MAPPING: [(&str, &str); 33] = [
+    ("fg", "base05"),
+    ("bg", "base00"),
+    ("muted", "base04"),
+    ("dim", "base03"),
+    ("accent", "base0D"),
+    ("accent_dim", "base0C"),
+    ("success", "base0B"),
+    ("warning", "base0A"),
+    ("error", "base08"),
+    ("info", "base0C"),
+    ("priority_highest", "base08"),
+    ("priority_high", "base09"),
+    ("priority_medium", "base0A"),
+    ("priority_low", "base0B"),
+    ("priority_lowest", "base03"),
+    ("status_new", "base0D"),
+    ("status_in_progress", "base0A"),
+    ("status_done", "base0B"),
+    ("border", "base03"),
+    ("border_focused", "base0D"),
+    ("selection_bg", "base02"),
+    ("selection_fg", "base06"),
+    ("header_bg", "base01"),
+    ("header_fg", "base05"),
+    ("input_bg", "base01"),
+    ("input_fg", "base05"),
+    ("input_placeholder", "base03"),
+    ("tag_bg", "base0E"),
+    ("tag_fg", "base00"),
+    ("component_bg", "base0C"),
+    ("component_fg", "base00"),
+    ("search_match_bg", "base0A"),
+    ("search_match_fg", "base00"),
+];
+
+/// Theme colors that use the brighter base24 slots when a scheme has them.
+const BASE24_MAPPING: [(&str, &str); 3] = [
+    ("priority_highest", "base12"),
+    ("info", "base15"),
+    ("border_focused", "base16"),
+];
+
+/// A theme defined in a theme file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ThemeFile {
+    /// Theme name used in `settings.theme` and the theme switcher.
+    #[serde(default)]
+    pub name: String,
+
+    /// Built-in theme providing the colors this file does not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+
+    /// Theme colors keyed by field name (e.g. `accent`, `selection_bg`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub colors: BTreeMap<String, String>,
+
+    /// Colors for status names, matched case-insensitively.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub status: BTreeMap<String, String>,
+
+    /// Colors for issue type names, matched case-insensitively.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub issue_types: BTreeMap<String, String>,
+}
+
+impl ThemeFile {
+    /// Parse a TOML theme file.
+    ///
+    /// `default_name` is used when the file does not set a name.
+    pub fn parse_toml(default_name: &str, content: &str) -> Result<Self> {
+        let mut file: ThemeFile = toml::from_str(content).map_err(ConfigError::ParseError)?;
+        if file.name.trim().is_empty() {
+            file.name = default_name.to_string();
+        }
+        file.validate()?;
+        Ok(file)
+    }
+
+    /// Import a base16 or base24 YAML color scheme.
+    ///
+    /// The theme is named after the scheme, or `default_name` if the scheme
+    /// has no name. Dark schemes build on the dark theme and light schemes on
+    /// the light theme.
+    pub fn parse_base16(default_name: &str, content: &str) -> Result<Self> {
+        let values = parse_flat_yaml(content);
+
+        let mut palette = BTreeMap::new();
+        for (key, value) in &values {
+            if is_palette_slot(key) {
+                let hex = value.trim_start_matches('#');
+                if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(ConfigError::ValidationError(format!(
+                        "invalid color '{}' for {}",
+                        value, key
+                    )));
+                }
+                palette.insert(key.to_ascii_lowercase(), format!("#{}", hex.to_lowercase()));
+            }
+        }
+        if let Some(missing) = BASE16_SLOTS
+            .iter()
+            .find(|slot| !palette.contains_key(&slot.to_ascii_lowercase()))
+        {
+            return Err(ConfigError::ValidationError(format!(
+                "base16 scheme is missing {}",
+                missing
+            )));
+        }
+
+        let slot = |name: &str| palette.get(&name.to_ascii_lowercase()).cloned();
+        let mut colors = BTreeMap::new();
+        for (field, base) in BASE16_MAPPING {
+            colors.insert(field.to_string(), slot(base).unwrap_or_default());
+        }
+        for (field, base) in BASE24_MAPPING {
+            if let Some(color) = slot(base) {
+                colors.insert(field.to_string(), color);
+            }
+        }
+
+        let base = match parse_color(&colors["bg"]) {
+            Some(ratatui::style::Color::Rgb(r, g, b))
+                if u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114 > 128_000 =>
+            {
+                "light"
+            }
+            _ => "dark",
+        };
+
+        let name = values
+            .get("scheme")
+            .or_else(|| values.get("name"))
+            .filter(|name| !name.trim().is_empty())
+            .cloned()
+            .unwrap_or_else(|| default_name.to_string());
+
+        Ok(Self {
+            name,
+            base: Some(base.to_string()),
+            colors,
+            ..Default::default()
+        })
+    }
+
+    /// Validate the theme file.
+    ///
+    /// Checks that the name is set, the base theme exists and every color
+    /// refers to a known field and parses.
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(ConfigError::ValidationError(
+                "theme name cannot be empty".to_string(),
+            ));
+        }
+        if let Some(ref base) = self.base {
+            if Theme::builtin(base).is_none() {
+                return Err(ConfigError::ValidationError(format!(
+                    "unknown base theme '{}' (expected one of: {})",
+                    base,
+                    Theme::BUILTIN_NAMES.join(", ")
+                )));
+            }
+        }
+        validate_theme_colors(&self.colors)?;
+        for (name, value) in self.status.iter().chain(self.issue_types.iter()) {
+            check_color(name, value)?;
+        }
+        Ok(())
+    }
+
+    /// Build the theme.
+    ///
+    /// Colors that do not parse are skipped; [`ThemeFile::validate`] reports them.
+    pub fn to_theme(&self) -> Theme {
+        let mut theme = self
+            .base
+            .as_deref()
+            .map(Theme::by_name)
+            .unwrap_or_else(Theme::dark);
+        theme.name = self.name.clone();
+
+        for (field, value) in &self.colors {
+            if let (Some(slot), Some(color)) = (theme.color_mut(field), parse_color(value)) {
+                *slot = color;
+            }
+        }
+        for (name, value) in &self.status {
+            if let Some(color) = parse_color(value) {
+                theme.status_colors.insert(name.to_lowercase(), color);
+            }
+        }
+        for (name, value) in &self.issue_types {
+            if let Some(color) = parse_color(value) {
+                theme.issue_type_colors.insert(name.to_lowercase(), color);
+            }
+        }
+        theme
+    }
+}
+
+/// Validate theme colors keyed by field name, as used in theme files and
+/// `[settings.custom_theme]`.
+pub fn validate_theme_colors(colors: &BTreeMap<String, String>) -> Result<()> {
+    for (field, value) in colors {
+        if !Theme::COLOR_FIELDS.contains(&field.as_str()) {
+            return Err(ConfigError::ValidationError(format!(
+                "unknown theme color '{}'",
+                field
+            )));
+        }
+        check_color(field, value)?;
+    }
+    Ok(())
+}
+
+/// Check that a color value parses.
+fn check_color(name: &str, value: &str) -> Result<()> {
+    match parse_color(value) {
+        Some(_) => Ok(()),
+        None => Err(ConfigError::ValidationError(format!(
+            "invalid color '{}' for '{}'",
+            value, name
+        ))),
+    }
+}
+
+/// Check whether a key names a base16 or base24 palette slot (`base00`-`base17`).
+fn is_palette_slot(key: &str) -> bool {
+    key.len() == 6
+        && key.starts_with("base")
+        && u8::from_str_radix(&key[4..], 16).is_ok_and(|n| n <= 0x17)
+}
+
+/// Parse the `key: value` pairs of a flat YAML document.
+///
+/// Enough for base16 schemes, whose palette is either at the top level or in
+/// a `palette:` mapping. Comments and quotes are stripped.
+fn parse_flat_yaml(content: &str) -> BTreeMap<String, String> {
+    let mut values = BTreeMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line == "---" {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim().trim_matches(['"', '\'']);
+        let value = value.trim();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value.split(" #").next().unwrap_or_default().trim(),
+        };
+        if !value.is_empty() {
+            values.insert(key.to_string(), value.to_string());
+        }
+    }
+    values
+}
+
+/// Load the theme files (`*.toml`, `*.yaml`, `*.yml`) in a directory.
+///
+/// Themes are sorted by file name. A missing directory yields no themes.
+pub fn load_theme_files(dir: &Path) -> Result<Vec<ThemeFile>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map_err(ConfigError::ReadError)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext == "toml" || ext == "yaml" || ext == "yml")
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let content = fs::read_to_string(path).map_err(ConfigError::ReadError)?;
+            let stem = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let theme = if path.extension().is_some_and(|ext| ext == "toml") {
+                ThemeFile::parse_toml(&stem, &content)
+            } else {
+                ThemeFile::parse_base16(&stem, &content)
+            };
+            theme.map_err(|e| {
+                ConfigError::ValidationError(format!(
+                    "invalid theme file '{}': {}",
+                    path.display(),
+                    e
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Validate a list of theme files, checking that names are unique.
+pub fn validate_theme_files(themes: &[ThemeFile]) -> Result<()> {
+    let mut seen = std::collections::HashSet::new();
+    for theme in themes {
+        theme.validate()?;
+        if !seen.insert(theme.name.to_lowercase()) {
+            return Err(ConfigError::ValidationError(format!(
+                "duplicate theme name: '{}'",
+                theme.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+    use tempfile::tempdir;
+
+    const SOLARIZED: &str = r##"
+scheme: "Solarized Dark"
+author: "Ethan Schoonover (modified by aramisgithub)"
+base00: "002b36" # background
+base01: "073642"
+base02: "586e75"
+base03: "657b83"
+base04: "839496"
+base05: "93a1a1"
+base06: "eee8d5"
+base07: "fdf6e3"
+base08: "dc322f"
+base09: "cb4b16"
+base0A: "b58900"
+base0B: "859900"
+base0C: "2aa198"
+base0D: "268bd2"
+base0E: "6c71c4"
+base0F: "d33682"
+"##;
+
+    #[test]
+    fn test_parse_toml_theme() {
+        let content = r##"
+            base = "light"
+
+            [colors]
+            accent = "#268bd2"
+            selection_bg = "rgb(238, 232, 213)"
+
+            [status]
+            "In Review" = "magenta"
+
+            [issue_types]
+            Bug = "#dc322f"
+        "##;
+        let file = ThemeFile::parse_toml("solarized", content).unwrap();
+        assert_eq!(file.name, "solarized");
+
+        let theme = file.to_theme();
+        assert_eq!(theme.name, "solarized");
+        assert_eq!(theme.accent, Color::Rgb(0x26, 0x8b, 0xd2));
+        assert_eq!(theme.selection_bg, Color::Rgb(238, 232, 213));
+        assert_eq!(theme.fg, Theme::light().fg);
+        assert_eq!(theme.status_colors["in review"], Color::Magenta);
+        assert_eq!(theme.issue_type_colors["bug"], Color::Rgb(0xdc, 0x32, 0x2f));
+    }
+
+    #[test]
+    fn test_invalid_toml_theme_rejected() {
+        let unknown_field = "[colors]\naccentt = \"red\"\n";
+        assert!(ThemeFile::parse_toml("t", unknown_field).is_err());
+
+        let bad_color = "[issue_types]\nBug = \"reddish\"\n";
+        assert!(ThemeFile::parse_toml("t", bad_color).is_err());
+
+        let bad_base = "base = \"solarized\"\n";
+        assert!(ThemeFile::parse_toml("t", bad_base).is_err());
+    }
+
+    #[test]
+    fn test_import_base16() {
+        let file = ThemeFile::parse_base16("solarized-dark", SOLARIZED).unwrap();
+        assert_eq!(file.name, "Solarized Dark");
+        assert_eq!(file.base.as_deref(), Some("dark"));
+        file.validate().unwrap();
+
+        let theme = file.to_theme();
+        assert_eq!(theme.bg, Color::Rgb(0x00, 0x2b, 0x36));
+        assert_eq!(theme.fg, Color::Rgb(0x93, 0xa1, 0xa1));
+        assert_eq!(theme.accent, Color::Rgb(0x26, 0x8b, 0xd2));
+        assert_eq!(theme.error, Color::Rgb(0xdc, 0x32, 0x2f));
+    }
+
+    #[test]
+    fn test_import_base24_palette() {
+        let content = SOLARIZED
+            .replace("base00: \"002b36\" # background", "base00: \"#fdf6e3\"")
+            .replace("scheme: \"Solarized Dark\"", "name: \"Bright\"\npalette:")
+            + "  base12: \"#ff0000\"\n";
+        let file = ThemeFile::parse_base16("bright", &content).unwrap();
+        assert_eq!(file.name, "Bright");
+        assert_eq!(file.base.as_deref(), Some("light"));
+        assert_eq!(file.to_theme().priority_highest, Color::Rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_base16_missing_slot_rejected() {
+        let content = SOLARIZED.replace("base0F: \"d33682\"", "");
+        assert!(ThemeFile::parse_base16("t", &content).is_err());
+    }
+
+    #[test]
+    fn test_load_theme_files() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("ocean.toml"),
+            "[colors]\naccent = \"blue\"\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("solarized.yaml"), SOLARIZED).unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let themes = load_theme_files(dir.path()).unwrap();
+        let names: Vec<_> = themes.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["ocean", "Solarized Dark"]);
+        validate_theme_files(&themes).unwrap();
+
+        assert!(load_theme_files(&dir.path().join("missing"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_invalid_theme_file_names_path() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("broken.toml"), "[colors]\nnope = \"red\"\n").unwrap();
+        let err = load_theme_files(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("broken.toml"));
+    }
+
+    #[test]
+    fn test_duplicate_theme_names_rejected() {
+        let themes = vec![
+            ThemeFile {
+                name: "Ocean".to_string(),
+                ..Default::default()
+            },
+            ThemeFile {
+                name: "ocean".to_string(),
+                ..Default::default()
+            },
+        ];
+        assert!(validate_theme_files(&themes).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! Change detection for the configuration file.
+//!
+//! The file is polled rather than watched through OS notifications: checking
+//! its modification time about once a second is cheap and works the same on
+//! every platform and for editors that replace the file on save.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the configuration file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Modification time and size identifying a version of the file.
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Detects changes to the configuration file by polling it.
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    /// Path of the watched file.
+    path: PathBuf,
+    /// Stamp of the last version seen (`None` if the file didn't exist).
+    stamp: Stamp,
+    /// When the file was last checked.
+    last_check: Instant,
+}
+
+impl ConfigWatcher {
+    /// Start watching the file at `path` from its current version.
+    pub fn new(path: PathBuf) -> Self {
+        let stamp = stamp(&path);
+        Self {
+            path,
+            stamp,
+            last_check: Instant::now(),
+        }
+    }
+
+    /// Get the watched path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Check for changes if the poll interval has passed.
+    ///
+    /// Returns `true` once per change of the file.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        if now.duration_since(self.last_check) < POLL_INTERVAL {
+            return false;
+        }
+        self.last_check = now;
+        self.check()
+    }
+
+    /// Check for changes now, returning `true` if the file changed since the
+    /// last check.
+    pub fn check(&mut self) -> bool {
+        let stamp = stamp(&self.path);
+        if stamp == self.stamp {
+            return false;
+        }
+        self.stamp = stamp;
+        true
+    }
+}
+
+/// Read the modification time and size of the file at `path`.
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_check_detects_changes_once() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[settings]\n").unwrap();
+
+        let mut watcher = ConfigWatcher::new(path.clone());
+        assert!(!watcher.check());
+
+        fs::write(&path, "[settings]\ntheme = \"light\"\n").unwrap();
+        assert!(watcher.check());
+        assert!(!watcher.check());
+
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.check());
+    }
+
+    #[test]
+    fn test_check_detects_created_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+
+        let mut watcher = ConfigWatcher::new(path.clone());
+        assert!(!watcher.check());
+
+        fs::write(&path, "[settings]\n").unwrap();
+        assert!(watcher.check());
+    }
+
+    #[test]
+    fn test_poll_is_throttled() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut watcher = ConfigWatcher::new(path.clone());
+        let start = watcher.last_check;
+
+        fs::write(&path, "[settings]\n").unwrap();
+        assert!(!watcher.poll(start + Duration::from_millis(100)));
+        assert!(watcher.poll(start + POLL_INTERVAL));
+    }
+}
@@ -62,6 +62,9 @@ impl AppError {
                     "Could not find configuration directory. Please check your system settings."
                         .to_string()
                 }
+                ConfigError::NoDataDir => {
+                    "Could not find data directory. Please check your system settings.".to_string()
+                }
                 ConfigError::CreateDirError(_) => {
                     "Could not create configuration directory. Check file permissions.".to_string()
                 }
@@ -81,6 +84,9 @@ impl AppError {
                 ConfigError::ProfileNotFound(name) => {
                     format!("Profile '{}' not found.", name)
                 }
+                ConfigError::LoadFailed => {
+                    "Configuration file has errors. Fix it before changing settings.".to_string()
+                }
             },
             AppError::Api(e) => match e {
                 ApiError::Unauthorized => {
@@ -0,0 +1,1048 @@
+//! User-remappable key bindings.
+//!
+//! Every bindable command is an [`Action`]. The [`Keymap`] maps key sequences
+//! to actions per [`KeyContext`], starting from the built-in defaults and
+//! applying the `[keys.<context>]` tables from `config.toml`:
+//!
+//! ```toml
+//! [keys.issue_list]
+//! "Ctrl+j" = "move_down"
+//! "gh" = "go_top"
+//! "n" = "none"        # unbind the key
+//! ```
+//!
+//! Keys are written as in the help panel: a single character (`G`), a named
+//! key (`Enter`, `Esc`, `PageDown`, `F1`), a key with modifiers (`Ctrl+d`,
+//! `Alt+Enter`) or a sequence of them (`gg`, `g t`, `Ctrl+x Ctrl+s`).
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::keys::{KeyContext, DEFAULT_BINDINGS};
+
+/// Global keymap instance for application-wide access.
+static KEYMAP: RwLock<Option<Arc<Keymap>>> = RwLock::new(None);
+
+/// Replace the global keymap.
+pub fn set_keymap(keymap: Keymap) {
+    *KEYMAP.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(keymap));
+}
+
+/// Get the current keymap.
+///
+/// Returns the default keymap if none has been set.
+pub fn keymap() -> Arc<Keymap> {
+    static DEFAULT: OnceLock<Arc<Keymap>> = OnceLock::new();
+
+    KEYMAP
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .unwrap_or_else(|| DEFAULT.get_or_init(|| Arc::new(Keymap::default())).clone())
+}
+
+/// A bindable command.
+///
+/// Actions are shared between contexts where they mean the same thing, e.g.
+/// `move_down` moves the selection in the issue list and scrolls the detail
+/// view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    // Global
+    Help,
+    Quit,
+    SwitchProfile,
+    ManageProfiles,
+    CommandPalette,
+    GoToIssue,
+    JumpBack,
+    JumpForward,
+    // Navigation
+    MoveDown,
+    MoveUp,
+    GoTop,
+    GoBottom,
+    PageDown,
+    PageUp,
+    // Common
+    Open,
+    Back,
+    Cancel,
+    Submit,
+    Refresh,
+    OpenInBrowser,
+    Add,
+    Edit,
+    Delete,
+    // Issue list
+    Search,
+    Sort,
+    OpenFilter,
+    SavedFilters,
+    JqlQuery,
+    LoadMore,
+    NewIssue,
+    CycleGroupBy,
+    ToggleGroup,
+    ToggleAllGroups,
+    NextTab,
+    PrevTab,
+    NewTab,
+    CloseTab,
+    OpenReleases,
+    // Issue detail
+    ExternalEditor,
+    Comments,
+    ChangeStatus,
+    Assign,
+    ChangePriority,
+    EditLabels,
+    EditComponents,
+    EditFixVersions,
+    EditAffectsVersions,
+    History,
+    Links,
+    CloneIssue,
+    CreateSubtask,
+    ToggleWatch,
+    ManageWatchers,
+    ToggleVote,
+    // Releases
+    SwitchPane,
+    FocusLeft,
+    FocusRight,
+    NewVersion,
+    ReleaseVersion,
+    // Profile management
+    SetDefault,
+    UseProfile,
+    Export,
+    Import,
+    // Filter panel
+    NextSection,
+    PrevSection,
+    Toggle,
+    SelectAll,
+    Negate,
+    Clear,
+    // JQL input
+    HistoryPrev,
+    HistoryNext,
+    Complete,
+}
+
+impl Action {
+    /// All actions.
+    pub const ALL: &'static [Action] = &[
+        Self::Help,
+        Self::Quit,
+        Self::SwitchProfile,
+        Self::ManageProfiles,
+        Self::CommandPalette,
+        Self::GoToIssue,
+        Self::JumpBack,
+        Self::JumpForward,
+        Self::MoveDown,
+        Self::MoveUp,
+        Self::GoTop,
+        Self::GoBottom,
+        Self::PageDown,
+        Self::PageUp,
+        Self::Open,
+        Self::Back,
+        Self::Cancel,
+        Self::Submit,
+        Self::Refresh,
+        Self::OpenInBrowser,
+        Self::Add,
+        Self::Edit,
+        Self::Delete,
+        Self::Search,
+        Self::Sort,
+        Self::OpenFilter,
+        Self::SavedFilters,
+        Self::JqlQuery,
+        Self::LoadMore,
+        Self::NewIssue,
+        Self::CycleGroupBy,
+        Self::ToggleGroup,
+        Self::ToggleAllGroups,
+        Self::NextTab,
+        Self::PrevTab,
+        Self::NewTab,
+        Self::CloseTab,
+        Self::OpenReleases,
+        Self::ExternalEditor,
+        Self::Comments,
+        Self::ChangeStatus,
+        Self::Assign,
+        Self::ChangePriority,
+        Self::EditLabels,
+        Self::EditComponents,
+        Self::EditFixVersions,
+        Self::EditAffectsVersions,
+        Self::History,
+        Self::Links,
+        Self::CloneIssue,
+        Self::CreateSubtask,
+        Self::ToggleWatch,
+        Self::ManageWatchers,
+        Self::ToggleVote,
+        Self::SwitchPane,
+        Self::FocusLeft,
+        Self::FocusRight,
+        Self::NewVersion,
+        Self::ReleaseVersion,
+        Self::SetDefault,
+        Self::UseProfile,
+        Self::Export,
+        Self::Import,
+        Self::NextSection,
+        Self::PrevSection,
+        Self::Toggle,
+        Self::SelectAll,
+        Self::Negate,
+        Self::Clear,
+        Self::HistoryPrev,
+        Self::HistoryNext,
+        Self::Complete,
+    ];
+
+    /// Get the identifier used for this action in the config file.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Help => "help",
+            Self::Quit => "quit",
+            Self::SwitchProfile => "switch_profile",
+            Self::ManageProfiles => "manage_profiles",
+            Self::CommandPalette => "command_palette",
+            Self::GoToIssue => "go_to_issue",
+            Self::JumpBack => "jump_back",
+            Self::JumpForward => "jump_forward",
+            Self::MoveDown => "move_down",
+            Self::MoveUp => "move_up",
+            Self::GoTop => "go_top",
+            Self::GoBottom => "go_bottom",
+            Self::PageDown => "page_down",
+            Self::PageUp => "page_up",
+            Self::Open => "open",
+            Self::Back => "back",
+            Self::Cancel => "cancel",
+            Self::Submit => "submit",
+            Self::Refresh => "refresh",
+            Self::OpenInBrowser => "open_in_browser",
+            Self::Add => "add",
+            Self::Edit => "edit",
+            Self::Delete => "delete",
+            Self::Search => "search",
+            Self::Sort => "sort",
+            Self::OpenFilter => "open_filter",
+            Self::SavedFilters => "saved_filters",
+            Self::JqlQuery => "jql_query",
+            Self::LoadMore => "load_more",
+            Self::NewIssue => "new_issue",
+            Self::CycleGroupBy => "cycle_group_by",
+            Self::ToggleGroup => "toggle_group",
+            Self::ToggleAllGroups => "toggle_all_groups",
+            Self::NextTab => "next_tab",
+            Self::PrevTab => "prev_tab",
+            Self::NewTab => "new_tab",
+            Self::CloseTab => "close_tab",
+            Self::OpenReleases => "open_releases",
+            Self::ExternalEditor => "external_editor",
+            Self::Comments => "comments",
+            Self::ChangeStatus => "change_status",
+            Self::Assign => "assign",
+            Self::ChangePriority => "change_priority",
+            Self::EditLabels => "edit_labels",
+            Self::EditComponents => "edit_components",
+            Self::EditFixVersions => "edit_fix_versions",
+            Self::EditAffectsVersions => "edit_affects_versions",
+            Self::History => "history",
+            Self::Links => "links",
+            Self::CloneIssue => "clone_issue",
+            Self::CreateSubtask => "create_subtask",
+            Self::ToggleWatch => "toggle_watch",
+            Self::ManageWatchers => "manage_watchers",
+            Self::ToggleVote => "toggle_vote",
+            Self::SwitchPane => "switch_pane",
+            Self::FocusLeft => "focus_left",
+            Self::FocusRight => "focus_right",
+            Self::NewVersion => "new_version",
+            Self::ReleaseVersion => "release_version",
+            Self::SetDefault => "set_default",
+            Self::UseProfile => "use_profile",
+            Self::Export => "export",
+            Self::Import => "import",
+            Self::NextSection => "next_section",
+            Self::PrevSection => "prev_section",
+            Self::Toggle => "toggle",
+            Self::SelectAll => "select_all",
+            Self::Negate => "negate",
+            Self::Clear => "clear",
+            Self::HistoryPrev => "history_prev",
+            Self::HistoryNext => "history_next",
+            Self::Complete => "complete",
+        }
+    }
+
+    /// Look up an action by its config identifier.
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|a| a.id() == id)
+    }
+
+    /// Check whether this action can be bound in the given context.
+    pub fn is_available_in(&self, context: KeyContext) -> bool {
+        DEFAULT_BINDINGS
+            .iter()
+            .any(|(ctx, _, action, _)| *ctx == context && action == self)
+    }
+}
+
+/// A single key press with modifiers.
+///
+/// Characters carry their own case, so Shift is dropped for them: `G` and
+/// `Shift+g` are the same chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    /// The key code.
+    pub code: KeyCode,
+    /// Ctrl, Alt and (for non-character keys) Shift.
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Create a normalized chord.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let mut modifiers =
+            modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        let code = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                code
+            }
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            code => code,
+        };
+        Self { code, modifiers }
+    }
+
+    /// Create a chord from a terminal key event.
+    pub fn from_event(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+
+    /// Check whether this is a plain character without modifiers.
+    fn is_plain_char(&self) -> bool {
+        matches!(self.code, KeyCode::Char(c) if c != ' ') && self.modifiers.is_empty()
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::Enter => f.write_str("Enter"),
+            KeyCode::Esc => f.write_str("Esc"),
+            KeyCode::Tab => f.write_str("Tab"),
+            KeyCode::BackTab => f.write_str("Shift+Tab"),
+            KeyCode::Backspace => f.write_str("Backspace"),
+            KeyCode::Delete => f.write_str("Del"),
+            KeyCode::Insert => f.write_str("Ins"),
+            KeyCode::Up => f.write_str("↑"),
+            KeyCode::Down => f.write_str("↓"),
+            KeyCode::Left => f.write_str("←"),
+            KeyCode::Right => f.write_str("→"),
+            KeyCode::Home => f.write_str("Home"),
+            KeyCode::End => f.write_str("End"),
+            KeyCode::PageUp => f.write_str("PgUp"),
+            KeyCode::PageDown => f.write_str("PgDn"),
+            _ => f.write_str("?"),
+        }
+    }
+}
+
+/// Parse a named key such as `Enter` or `F5`.
+fn named_key(name: &str) -> Option<KeyCode> {
+    let code = match name.to_ascii_lowercase().as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "space" => KeyCode::Char(' '),
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "up" | "↑" => KeyCode::Up,
+        "down" | "↓" => KeyCode::Down,
+        "left" | "←" => KeyCode::Left,
+        "right" | "→" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        lower => {
+            let n: u8 = lower.strip_prefix('f')?.parse().ok()?;
+            if !(1..=12).contains(&n) {
+                return None;
+            }
+            KeyCode::F(n)
+        }
+    };
+    Some(code)
+}
+
+/// Parse one whitespace-free token into one or more chords.
+///
+/// Plain characters without modifiers form a sequence (`gg`), everything
+/// else is a single chord.
+fn parse_token(token: &str) -> Result<Vec<KeyChord>, String> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = token;
+    loop {
+        let lower = rest.to_ascii_lowercase();
+        let prefix = [
+            ("ctrl+", KeyModifiers::CONTROL),
+            ("alt+", KeyModifiers::ALT),
+            ("shift+", KeyModifiers::SHIFT),
+        ]
+        .into_iter()
+        .find(|(prefix, _)| lower.starts_with(prefix) && rest.len() > prefix.len());
+        match prefix {
+            Some((prefix, modifier)) => {
+                modifiers |= modifier;
+                rest = &rest[prefix.len()..];
+            }
+            None => break,
+        }
+    }
+
+    if let Some(code) = named_key(rest) {
+        return Ok(vec![KeyChord::new(code, modifiers)]);
+    }
+
+    let mut chars = rest.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if !modifiers.is_empty() => {
+            let c = if modifiers.contains(KeyModifiers::SHIFT) {
+                c.to_ascii_uppercase()
+            } else if modifiers.contains(KeyModifiers::CONTROL) {
+                c.to_ascii_lowercase()
+            } else {
+                c
+            };
+            Ok(vec![KeyChord::new(KeyCode::Char(c), modifiers)])
+        }
+        (Some(_), _) if modifiers.is_empty() && (rest == "+" || !rest.contains('+')) => Ok(rest
+            .chars()
+            .map(|c| KeyChord::new(KeyCode::Char(c), KeyModifiers::NONE))
+            .collect()),
+        _ => Err(format!("unknown key '{}'", token)),
+    }
+}
+
+/// A sequence of chords, e.g. `gg` or `Ctrl+x Ctrl+s`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeySequence(Vec<KeyChord>);
+
+impl KeySequence {
+    /// Get the chords of this sequence.
+    pub fn chords(&self) -> &[KeyChord] {
+        &self.0
+    }
+
+    /// Get the number of chords in this sequence.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check whether this sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Check whether this sequence is a strict prefix of another.
+    fn is_prefix_of(&self, other: &KeySequence) -> bool {
+        self.len() < other.len() && other.0.starts_with(&self.0)
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chords = Vec::new();
+        for token in s.split_whitespace() {
+            chords.extend(parse_token(token)?);
+        }
+        if chords.is_empty() {
+            return Err("empty key".to_string());
+        }
+        Ok(Self(chords))
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if self.0.iter().all(KeyChord::is_plain_char) {
+            ""
+        } else {
+            " "
+        };
+        for (i, chord) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
+            write!(f, "{}", chord)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of looking up a key sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyMatch {
+    /// The sequence is bound to an action.
+    Action(Action),
+    /// The sequence is the start of a longer binding.
+    Pending,
+    /// The sequence is not bound.
+    Unbound,
+}
+
+/// A key sequence bound to an action in a context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Binding {
+    context: KeyContext,
+    keys: KeySequence,
+    action: Action,
+    /// Whether the binding comes from the config file.
+    user: bool,
+}
+
+/// The effective key bindings of all contexts.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<Binding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = DEFAULT_BINDINGS
+            .iter()
+            .flat_map(|(context, keys, action, _)| {
+                keys.iter().map(move |keys| Binding {
+                    context: *context,
+                    keys: keys.parse().expect("default key binding must parse"),
+                    action: *action,
+                    user: false,
+                })
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    /// Build the keymap from the `[keys.<context>]` tables of the config.
+    ///
+    /// User bindings replace default bindings of the same key. Bindings that
+    /// make others unreachable are reported as conflicts; the user binding
+    /// wins over a default one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown contexts, keys or actions, and for
+    /// actions that are not available in the context they are bound in.
+    pub fn from_config(
+        overrides: &BTreeMap<String, BTreeMap<String, String>>,
+    ) -> Result<(Self, Vec<String>), String> {
+        let mut keymap = Self::default();
+        let mut conflicts = Vec::new();
+
+        for (context_id, table) in overrides {
+            let context = KeyContext::from_id(context_id)
+                .ok_or_else(|| format!("unknown key context [keys.{}]", context_id))?;
+            let mut seen: HashMap<KeySequence, &str> = HashMap::new();
+
+            for (keys, action_id) in table {
+                let sequence: KeySequence = keys
+                    .parse()
+                    .map_err(|e| format!("[keys.{}] {}", context_id, e))?;
+                if sequence.len() > 1 && !context.supports_sequences() {
+                    return Err(format!(
+                        "[keys.{}] '{}': key sequences are not supported in this context",
+                        context_id, keys
+                    ));
+                }
+                let action = match action_id.as_str() {
+                    "none" => None,
+                    id => {
+                        let action = Action::from_id(id).ok_or_else(|| {
+                            format!("[keys.{}] '{}': unknown action '{}'", context_id, keys, id)
+                        })?;
+                        if !action.is_available_in(context) {
+                            return Err(format!(
+                                "[keys.{}] '{}': action '{}' is not available in this context",
+                                context_id, keys, id
+                            ));
+                        }
+                        Some(action)
+                    }
+                };
+
+                if let Some(previous) = seen.insert(sequence.clone(), keys) {
+                    conflicts.push(format!(
+                        "[keys.{}] '{}' and '{}' are the same key",
+                        context_id, previous, keys
+                    ));
+                }
+
+                keymap
+                    .bindings
+                    .retain(|b| !(b.context == context && b.keys == sequence));
+                if let Some(action) = action {
+                    keymap.bindings.push(Binding {
+                        context,
+                        keys: sequence,
+                        action,
+                        user: true,
+                    });
+                }
+            }
+        }
+
+        keymap.resolve_conflicts(&mut conflicts);
+        Ok((keymap, conflicts))
+    }
+
+    /// Drop default bindings hidden by user bindings and report conflicts.
+    fn resolve_conflicts(&mut self, conflicts: &mut Vec<String>) {
+        let mut hidden = Vec::new();
+
+        for user in self.bindings.iter().filter(|b| b.user) {
+            for other in &self.bindings {
+                if other == user {
+                    continue;
+                }
+                let context = user.context.id();
+                if other.context == user.context
+                    && (user.keys.is_prefix_of(&other.keys) || other.keys.is_prefix_of(&user.keys))
+                {
+                    if !other.user {
+                        conflicts.push(format!(
+                            "[keys.{}] '{}' ({}) hides the default '{}' ({})",
+                            context,
+                            user.keys,
+                            user.action.id(),
+                            other.keys,
+                            other.action.id()
+                        ));
+                        hidden.push(other.clone());
+                    } else if user.keys.is_prefix_of(&other.keys) {
+                        conflicts.push(format!(
+                            "[keys.{}] '{}' ({}) makes '{}' ({}) unreachable",
+                            context,
+                            user.keys,
+                            user.action.id(),
+                            other.keys,
+                            other.action.id()
+                        ));
+                    }
+                } else if other.context != user.context
+                    && (other.context == KeyContext::Global
+                        || (user.context == KeyContext::Global && !other.user))
+                    && other.keys == user.keys
+                    && other.action != user.action
+                {
+                    let (global, local) = if user.context == KeyContext::Global {
+                        (user, other)
+                    } else {
+                        (other, user)
+                    };
+                    conflicts.push(format!(
+                        "[keys.{}] '{}' ({}) is shadowed by the global binding ({})",
+                        local.context.id(),
+                        local.keys,
+                        local.action.id(),
+                        global.action.id()
+                    ));
+                }
+            }
+        }
+
+        self.bindings.retain(|b| !hidden.contains(b));
+    }
+
+    /// Look up a (possibly partial) key sequence in a context.
+    pub fn lookup(&self, context: KeyContext, chords: &[KeyChord]) -> KeyMatch {
+        let mut pending = false;
+        for binding in self.bindings.iter().filter(|b| b.context == context) {
+            if binding.keys.chords() == chords {
+                return KeyMatch::Action(binding.action);
+            }
+            pending |= binding.keys.chords().starts_with(chords);
+        }
+        if pending {
+            KeyMatch::Pending
+        } else {
+            KeyMatch::Unbound
+        }
+    }
+
+    /// Get the action bound to a single key press in a context.
+    pub fn action(&self, context: KeyContext, key: KeyEvent) -> Option<Action> {
+        match self.lookup(context, &[KeyChord::from_event(key)]) {
+            KeyMatch::Action(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    /// Check whether a single key press triggers the action in a context.
+    pub fn matches(&self, context: KeyContext, action: Action, key: KeyEvent) -> bool {
+        self.action(context, key) == Some(action)
+    }
+
+    /// Get the key sequences bound to an action in a context.
+    pub fn keys_for(&self, context: KeyContext, action: Action) -> Vec<&KeySequence> {
+        self.bindings
+            .iter()
+            .filter(|b| b.context == context && b.action == action)
+            .map(|b| &b.keys)
+            .collect()
+    }
+}
+
+/// Partially typed key sequence of a view.
+///
+/// Views that support multi-key bindings such as `gg` keep one of these and
+/// feed every key press through [`PendingKeys::resolve`].
+#[derive(Debug, Clone, Default)]
+pub struct PendingKeys(Vec<KeyChord>);
+
+impl PendingKeys {
+    /// Feed a key press and return the action it completes, if any.
+    pub fn resolve(&mut self, context: KeyContext, key: KeyEvent) -> Option<Action> {
+        self.resolve_in(&keymap(), context, key)
+    }
+
+    /// Feed a key press using the given keymap.
+    ///
+    /// A key that does not continue the pending sequence starts a new one.
+    pub fn resolve_in(
+        &mut self,
+        keymap: &Keymap,
+        context: KeyContext,
+        key: KeyEvent,
+    ) -> Option<Action> {
+        self.0.push(KeyChord::from_event(key));
+        match keymap.lookup(context, &self.0) {
+            KeyMatch::Action(action) => {
+                self.0.clear();
+                Some(action)
+            }
+            KeyMatch::Pending => None,
+            KeyMatch::Unbound => {
+                let retry = self.0.len() > 1;
+                self.0.clear();
+                if retry {
+                    self.resolve_in(keymap, context, key)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Check whether a key sequence has been started.
+    pub fn is_pending(&self) -> bool {
+        !self.0.is_empty()
+    }
+
+    /// Discard the pending key sequence.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn char_key(c: char) -> KeyEvent {
+        key(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    fn overrides(
+        context: &str,
+        entries: &[(&str, &str)],
+    ) -> BTreeMap<String, BTreeMap<String, String>> {
+        let table = entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        BTreeMap::from([(context.to_string(), table)])
+    }
+
+    #[test]
+    fn test_parse_single_keys() {
+        let seq: KeySequence = "G".parse().unwrap();
+        assert_eq!(
+            seq.chords(),
+            &[KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE)]
+        );
+
+        let seq: KeySequence = "Ctrl+D".parse().unwrap();
+        assert_eq!(
+            seq.chords(),
+            &[KeyChord::new(KeyCode::Char('d'), KeyModifiers::CONTROL)]
+        );
+
+        let seq: KeySequence = "shift+g".parse().unwrap();
+        assert_eq!(
+            seq.chords(),
+            &[KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE)]
+        );
+
+        let seq: KeySequence = "PageDown".parse().unwrap();
+        assert_eq!(
+            seq.chords(),
+            &[KeyChord::new(KeyCode::PageDown, KeyModifiers::NONE)]
+        );
+
+        let seq: KeySequence = "Shift+Tab".parse().unwrap();
+        assert_eq!(
+            seq.chords(),
+            &[KeyChord::new(KeyCode::BackTab, KeyModifiers::NONE)]
+        );
+
+        let seq: KeySequence = "F5".parse().unwrap();
+        assert_eq!(
+            seq.chords(),
+            &[KeyChord::new(KeyCode::F(5), KeyModifiers::NONE)]
+        );
+    }
+
+    #[test]
+    fn test_parse_sequences() {
+        let seq: KeySequence = "gg".parse().unwrap();
+        assert_eq!(seq.len(), 2);
+        assert_eq!(seq, "g g".parse().unwrap());
+
+        let seq: KeySequence = "Ctrl+x Ctrl+s".parse().unwrap();
+        assert_eq!(seq.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_invalid_keys() {
+        assert!("".parse::<KeySequence>().is_err());
+        assert!("Ctrl+foo".parse::<KeySequence>().is_err());
+        assert!("F13".parse::<KeySequence>().is_ok()); // the characters F, 1, 3
+        assert!("Ctrl+F13".parse::<KeySequence>().is_err());
+    }
+
+    #[test]
+    fn test_sequence_display() {
+        for keys in ["gg", "gT", "Ctrl+d", "Enter", "Space", "Ctrl+x Ctrl+s", "↓"] {
+            let seq: KeySequence = keys.parse().unwrap();
+            assert_eq!(seq.to_string(), keys);
+        }
+    }
+
+    #[test]
+    fn test_chord_from_event_drops_shift_for_chars() {
+        let chord = KeyChord::from_event(key(KeyCode::Char('G'), KeyModifiers::SHIFT));
+        assert_eq!(chord, KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE));
+
+        let chord = KeyChord::from_event(key(KeyCode::Tab, KeyModifiers::SHIFT));
+        assert_eq!(chord.code, KeyCode::BackTab);
+    }
+
+    #[test]
+    fn test_action_ids_round_trip() {
+        for action in Action::ALL {
+            assert_eq!(Action::from_id(action.id()), Some(*action));
+        }
+        assert_eq!(Action::from_id("nope"), None);
+    }
+
+    #[test]
+    fn test_every_action_has_a_default_binding() {
+        for action in Action::ALL {
+            assert!(
+                DEFAULT_BINDINGS.iter().any(|(_, _, a, _)| a == action),
+                "{} has no default binding",
+                action.id()
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_keymap_has_no_ambiguous_sequences() {
+        let keymap = Keymap::default();
+        for a in &keymap.bindings {
+            for b in &keymap.bindings {
+                if a.context == b.context && a != b {
+                    assert_ne!(a.keys, b.keys, "duplicate default '{}'", a.keys);
+                    assert!(
+                        !a.keys.is_prefix_of(&b.keys),
+                        "'{}' hides '{}'",
+                        a.keys,
+                        b.keys
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_lookup() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.action(KeyContext::IssueList, char_key('j')),
+            Some(Action::MoveDown)
+        );
+        assert_eq!(
+            keymap.action(
+                KeyContext::IssueList,
+                key(KeyCode::Down, KeyModifiers::NONE)
+            ),
+            Some(Action::MoveDown)
+        );
+        assert_eq!(
+            keymap.action(
+                KeyContext::Global,
+                key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+            ),
+            Some(Action::Quit)
+        );
+        assert_eq!(keymap.action(KeyContext::IssueList, char_key('g')), None);
+    }
+
+    #[test]
+    fn test_pending_keys_resolve_sequence() {
+        let keymap = Keymap::default();
+        let mut pending = PendingKeys::default();
+
+        assert_eq!(
+            pending.resolve_in(&keymap, KeyContext::IssueList, char_key('g')),
+            None
+        );
+        assert!(pending.is_pending());
+        assert_eq!(
+            pending.resolve_in(&keymap, KeyContext::IssueList, char_key('g')),
+            Some(Action::GoTop)
+        );
+        assert!(!pending.is_pending());
+    }
+
+    #[test]
+    fn test_pending_keys_falls_through_to_new_key() {
+        let keymap = Keymap::default();
+        let mut pending = PendingKeys::default();
+
+        pending.resolve_in(&keymap, KeyContext::IssueList, char_key('g'));
+        assert_eq!(
+            pending.resolve_in(&keymap, KeyContext::IssueList, char_key('j')),
+            Some(Action::MoveDown)
+        );
+        assert!(!pending.is_pending());
+    }
+
+    #[test]
+    fn test_from_config_rebinds_key() {
+        let (keymap, conflicts) = Keymap::from_config(&overrides(
+            "issue_list",
+            &[("x", "open_filter"), ("f", "none")],
+        ))
+        .unwrap();
+        assert!(conflicts.is_empty());
+        assert_eq!(
+            keymap.action(KeyContext::IssueList, char_key('x')),
+            Some(Action::OpenFilter)
+        );
+        assert_eq!(keymap.action(KeyContext::IssueList, char_key('f')), None);
+    }
+
+    #[test]
+    fn test_from_config_replaces_default_with_same_key() {
+        let (keymap, conflicts) =
+            Keymap::from_config(&overrides("issue_detail", &[("e", "comments")])).unwrap();
+        assert!(conflicts.is_empty());
+        assert_eq!(
+            keymap.action(KeyContext::IssueDetail, char_key('e')),
+            Some(Action::Comments)
+        );
+        assert!(keymap
+            .keys_for(KeyContext::IssueDetail, Action::Edit)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_from_config_reports_prefix_conflicts() {
+        let (keymap, conflicts) =
+            Keymap::from_config(&overrides("issue_list", &[("g", "refresh")])).unwrap();
+        // 'g' hides gg, gt, gT and gi
+        assert_eq!(conflicts.len(), 4);
+        assert!(conflicts[0].contains("hides the default"));
+        assert_eq!(
+            keymap.action(KeyContext::IssueList, char_key('g')),
+            Some(Action::Refresh)
+        );
+        assert!(keymap
+            .keys_for(KeyContext::IssueList, Action::NextTab)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_from_config_reports_global_shadowing() {
+        let (_, conflicts) =
+            Keymap::from_config(&overrides("issue_list", &[("?", "refresh")])).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].contains("shadowed by the global binding (help)"));
+    }
+
+    #[test]
+    fn test_from_config_reports_duplicate_spellings() {
+        let (_, conflicts) = Keymap::from_config(&overrides(
+            "issue_list",
+            &[("G", "go_top"), ("Shift+g", "go_bottom")],
+        ))
+        .unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].contains("are the same key"));
+    }
+
+    #[test]
+    fn test_from_config_errors() {
+        assert!(Keymap::from_config(&overrides("nowhere", &[("x", "refresh")])).is_err());
+        assert!(Keymap::from_config(&overrides("issue_list", &[("x", "fly")])).is_err());
+        assert!(Keymap::from_config(&overrides("issue_list", &[("Ctrl+", "refresh")])).is_err());
+        // Not an issue list action
+        assert!(Keymap::from_config(&overrides("issue_list", &[("x", "change_status")])).is_err());
+        // Sequences are not supported in text input contexts
+        assert!(Keymap::from_config(&overrides("jql_input", &[("jj", "cancel")])).is_err());
+    }
+}
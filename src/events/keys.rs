@@ -1,30 +1,13 @@
 //! Key binding definitions and registry.
 //!
-//! Provides a centralized registry of all keyboard shortcuts organized by context.
+//! Provides the default keyboard shortcuts organized by context and renders
+//! the effective bindings of the [`keymap`] for the help panel and help bar.
 
+use super::keymap::{keymap, Action};
 use crate::app::AppState;
 
-/// Key binding configuration.
-pub struct KeyBindings {
-    /// Whether vim-style bindings are enabled.
-    pub vim_mode: bool,
-}
-
-impl KeyBindings {
-    /// Create new key bindings.
-    pub fn new(vim_mode: bool) -> Self {
-        Self { vim_mode }
-    }
-}
-
-impl Default for KeyBindings {
-    fn default() -> Self {
-        Self::new(true)
-    }
-}
-
 /// The context in which a keybinding is active.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeyContext {
     /// Global keybindings available in all views.
     Global,
@@ -40,9 +23,51 @@ pub enum KeyContext {
     Editor,
     /// Keybindings for JQL input.
     JqlInput,
+    /// Keybindings for the release view.
+    Releases,
 }
 
 impl KeyContext {
+    /// All contexts, in the order they are shown in the help panel.
+    pub const ALL: [KeyContext; 8] = [
+        Self::Global,
+        Self::IssueList,
+        Self::IssueDetail,
+        Self::Releases,
+        Self::JqlInput,
+        Self::ProfileManagement,
+        Self::FilterPanel,
+        Self::Editor,
+    ];
+
+    /// Get the identifier used for this context in `[keys.<context>]`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::IssueList => "issue_list",
+            Self::IssueDetail => "issue_detail",
+            Self::ProfileManagement => "profile_management",
+            Self::FilterPanel => "filter_panel",
+            Self::Editor => "editor",
+            Self::JqlInput => "jql_input",
+            Self::Releases => "releases",
+        }
+    }
+
+    /// Look up a context by its config identifier.
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.id() == id)
+    }
+
+    /// Check whether multi-key sequences such as `gg` can be bound.
+    ///
+    /// Global bindings are checked before any view sees the key, and text
+    /// input contexts would swallow typed characters, so both only support
+    /// single keys.
+    pub fn supports_sequences(&self) -> bool {
+        !matches!(self, Self::Global | Self::JqlInput | Self::Editor)
+    }
+
     /// Get the display name for this context.
     pub fn display(&self) -> &'static str {
         match self {
@@ -53,6 +78,7 @@ impl KeyContext {
             Self::FilterPanel => "Filter Panel",
             Self::Editor => "Editor",
             Self::JqlInput => "JQL Input",
+            Self::Releases => "Releases",
         }
     }
 
@@ -65,6 +91,7 @@ impl KeyContext {
             AppState::FilterPanel => KeyContext::FilterPanel,
             AppState::JqlInput => KeyContext::JqlInput,
             AppState::CreateIssue => KeyContext::Editor,
+            AppState::Releases => KeyContext::Releases,
             AppState::Help | AppState::Exiting => KeyContext::Global,
         }
     }
@@ -95,160 +122,648 @@ impl Keybinding {
     }
 }
 
-/// Get all keybindings organized by context.
+/// Default key bindings: context, keys, action and description.
+///
+/// The order is the order of the help panel.
+pub(super) const DEFAULT_BINDINGS: &[(KeyContext, &[&str], Action, &str)] = &[
+    // Global keybindings
+    (
+        KeyContext::Global,
+        &["?"],
+        Action::Help,
+        "Show this help panel",
+    ),
+    (
+        KeyContext::Global,
+        &["Ctrl+c"],
+        Action::Quit,
+        "Quit application",
+    ),
+    (
+        KeyContext::Global,
+        &["p"],
+        Action::SwitchProfile,
+        "Switch JIRA profile (quick)",
+    ),
+    (
+        KeyContext::Global,
+        &["P"],
+        Action::ManageProfiles,
+        "Manage profiles (CRUD)",
+    ),
+    (
+        KeyContext::Global,
+        &["Ctrl+p", "Ctrl+k"],
+        Action::CommandPalette,
+        "Open command palette",
+    ),
+    (
+        KeyContext::Global,
+        &["Ctrl+g"],
+        Action::GoToIssue,
+        "Go to issue by key or text",
+    ),
+    (
+        KeyContext::Global,
+        &["Ctrl+o"],
+        Action::JumpBack,
+        "Back to previous issue or view",
+    ),
+    (
+        KeyContext::Global,
+        &["Ctrl+i", "Tab"],
+        Action::JumpForward,
+        "Forward to next issue or view",
+    ),
+    // Issue List keybindings
+    (
+        KeyContext::IssueList,
+        &["j", "Down"],
+        Action::MoveDown,
+        "Move down",
+    ),
+    (
+        KeyContext::IssueList,
+        &["k", "Up"],
+        Action::MoveUp,
+        "Move up",
+    ),
+    (
+        KeyContext::IssueList,
+        &["gg", "Home"],
+        Action::GoTop,
+        "Go to first issue",
+    ),
+    (
+        KeyContext::IssueList,
+        &["G", "End"],
+        Action::GoBottom,
+        "Go to last issue",
+    ),
+    (
+        KeyContext::IssueList,
+        &["Ctrl+d", "PageDown"],
+        Action::PageDown,
+        "Page down",
+    ),
+    (
+        KeyContext::IssueList,
+        &["Ctrl+u", "PageUp"],
+        Action::PageUp,
+        "Page up",
+    ),
+    (
+        KeyContext::IssueList,
+        &["Enter"],
+        Action::Open,
+        "Open issue details",
+    ),
+    (
+        KeyContext::IssueList,
+        &["/"],
+        Action::Search,
+        "Quick search in list",
+    ),
+    (
+        KeyContext::IssueList,
+        &["s"],
+        Action::Sort,
+        "Sort by column",
+    ),
+    (
+        KeyContext::IssueList,
+        &["f"],
+        Action::OpenFilter,
+        "Open filter panel",
+    ),
+    (
+        KeyContext::IssueList,
+        &["F"],
+        Action::SavedFilters,
+        "Open saved filters",
+    ),
+    (
+        KeyContext::IssueList,
+        &[":"],
+        Action::JqlQuery,
+        "Open JQL query input",
+    ),
+    (
+        KeyContext::IssueList,
+        &["r"],
+        Action::Refresh,
+        "Refresh issue list",
+    ),
+    (
+        KeyContext::IssueList,
+        &["o"],
+        Action::OpenInBrowser,
+        "Open in browser",
+    ),
+    (
+        KeyContext::IssueList,
+        &["Ctrl+l"],
+        Action::LoadMore,
+        "Load more issues",
+    ),
+    (
+        KeyContext::IssueList,
+        &["q"],
+        Action::Quit,
+        "Quit application",
+    ),
+    (
+        KeyContext::IssueList,
+        &["n"],
+        Action::NewIssue,
+        "Create new issue",
+    ),
+    (
+        KeyContext::IssueList,
+        &["v"],
+        Action::CycleGroupBy,
+        "Cycle group by (status, assignee, ...)",
+    ),
+    (
+        KeyContext::IssueList,
+        &["z"],
+        Action::ToggleGroup,
+        "Collapse/expand current group",
+    ),
+    (
+        KeyContext::IssueList,
+        &["Z"],
+        Action::ToggleAllGroups,
+        "Collapse/expand all groups",
+    ),
+    (KeyContext::IssueList, &["gt"], Action::NextTab, "Next tab"),
+    (
+        KeyContext::IssueList,
+        &["gT"],
+        Action::PrevTab,
+        "Previous tab",
+    ),
+    (
+        KeyContext::IssueList,
+        &["gi"],
+        Action::GoToIssue,
+        "Go to issue",
+    ),
+    (
+        KeyContext::IssueList,
+        &["R"],
+        Action::OpenReleases,
+        "Open releases of the issue's project",
+    ),
+    (
+        KeyContext::IssueList,
+        &["Ctrl+t"],
+        Action::NewTab,
+        "Open new tab",
+    ),
+    (
+        KeyContext::IssueList,
+        &["Ctrl+w"],
+        Action::CloseTab,
+        "Close current tab",
+    ),
+    // Issue Detail keybindings
+    (
+        KeyContext::IssueDetail,
+        &["j", "Down"],
+        Action::MoveDown,
+        "Scroll down",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["k", "Up"],
+        Action::MoveUp,
+        "Scroll up",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["g", "Home"],
+        Action::GoTop,
+        "Go to top",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["G", "End"],
+        Action::GoBottom,
+        "Go to bottom",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["Ctrl+d", "PageDown"],
+        Action::PageDown,
+        "Page down",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["Ctrl+u", "PageUp"],
+        Action::PageUp,
+        "Page up",
+    ),
+    (KeyContext::IssueDetail, &["e"], Action::Edit, "Edit issue"),
+    (
+        KeyContext::IssueDetail,
+        &["E"],
+        Action::ExternalEditor,
+        "Edit description in $EDITOR",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["s"],
+        Action::ChangeStatus,
+        "Change status",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["c"],
+        Action::Comments,
+        "Comments",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["a"],
+        Action::Assign,
+        "Change assignee",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["p"],
+        Action::ChangePriority,
+        "Change priority",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["l"],
+        Action::EditLabels,
+        "Edit labels",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["C"],
+        Action::EditComponents,
+        "Edit components",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["V"],
+        Action::EditFixVersions,
+        "Edit fix versions",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["A"],
+        Action::EditAffectsVersions,
+        "Edit affects versions",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["h"],
+        Action::History,
+        "Show change history",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["o"],
+        Action::OpenInBrowser,
+        "Open in browser",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["L"],
+        Action::Links,
+        "Manage issue links",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["D"],
+        Action::Delete,
+        "Delete issue",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["Y"],
+        Action::CloneIssue,
+        "Clone issue",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["S"],
+        Action::CreateSubtask,
+        "Create subtask",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["w"],
+        Action::ToggleWatch,
+        "Watch / unwatch issue",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["W"],
+        Action::ManageWatchers,
+        "Manage watchers",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["v"],
+        Action::ToggleVote,
+        "Vote / unvote issue",
+    ),
+    (
+        KeyContext::IssueDetail,
+        &["q", "Esc"],
+        Action::Back,
+        "Go back to list",
+    ),
+    // Releases keybindings
+    (
+        KeyContext::Releases,
+        &["j", "Down"],
+        Action::MoveDown,
+        "Move down in focused pane",
+    ),
+    (
+        KeyContext::Releases,
+        &["k", "Up"],
+        Action::MoveUp,
+        "Move up in focused pane",
+    ),
+    (
+        KeyContext::Releases,
+        &["Tab", "BackTab"],
+        Action::SwitchPane,
+        "Switch between versions and issues",
+    ),
+    (
+        KeyContext::Releases,
+        &["h", "Left"],
+        Action::FocusLeft,
+        "Focus versions",
+    ),
+    (
+        KeyContext::Releases,
+        &["l", "Right"],
+        Action::FocusRight,
+        "Focus issues",
+    ),
+    (KeyContext::Releases, &["Enter"], Action::Open, "Open issue"),
+    (
+        KeyContext::Releases,
+        &["n"],
+        Action::NewVersion,
+        "Create version",
+    ),
+    (
+        KeyContext::Releases,
+        &["R"],
+        Action::ReleaseVersion,
+        "Release selected version",
+    ),
+    (
+        KeyContext::Releases,
+        &["r"],
+        Action::Refresh,
+        "Reload versions",
+    ),
+    (KeyContext::Releases, &["q", "Esc"], Action::Back, "Go back"),
+    // JQL Input keybindings
+    (
+        KeyContext::JqlInput,
+        &["Enter"],
+        Action::Submit,
+        "Execute query",
+    ),
+    (
+        KeyContext::JqlInput,
+        &["Up"],
+        Action::HistoryPrev,
+        "Previous query in history",
+    ),
+    (
+        KeyContext::JqlInput,
+        &["Down"],
+        Action::HistoryNext,
+        "Next query in history",
+    ),
+    (
+        KeyContext::JqlInput,
+        &["Tab"],
+        Action::Complete,
+        "Complete alias or suggestion",
+    ),
+    (
+        KeyContext::JqlInput,
+        &["Ctrl+n"],
+        Action::MoveDown,
+        "Next suggestion",
+    ),
+    (
+        KeyContext::JqlInput,
+        &["Ctrl+p"],
+        Action::MoveUp,
+        "Previous suggestion",
+    ),
+    (KeyContext::JqlInput, &["Esc"], Action::Cancel, "Cancel"),
+    // Profile Management keybindings
+    (
+        KeyContext::ProfileManagement,
+        &["j", "Down"],
+        Action::MoveDown,
+        "Move down",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["k", "Up"],
+        Action::MoveUp,
+        "Move up",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["a"],
+        Action::Add,
+        "Add new profile",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["e", "Enter"],
+        Action::Edit,
+        "Edit selected profile",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["d"],
+        Action::Delete,
+        "Delete selected profile",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["s"],
+        Action::SetDefault,
+        "Set as default profile",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["Space"],
+        Action::UseProfile,
+        "Switch to profile",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["x"],
+        Action::Export,
+        "Export profile to a bundle file",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["i"],
+        Action::Import,
+        "Import a profile bundle file",
+    ),
+    (
+        KeyContext::ProfileManagement,
+        &["q", "Esc"],
+        Action::Back,
+        "Go back",
+    ),
+    // Filter Panel keybindings
+    (
+        KeyContext::FilterPanel,
+        &["Tab", "l", "Right"],
+        Action::NextSection,
+        "Next section",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["BackTab", "h", "Left"],
+        Action::PrevSection,
+        "Previous section",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["j", "Down"],
+        Action::MoveDown,
+        "Move down in section",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["k", "Up"],
+        Action::MoveUp,
+        "Move up in section",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["Home"],
+        Action::GoTop,
+        "Go to first item",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["End"],
+        Action::GoBottom,
+        "Go to last item",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["Space"],
+        Action::Toggle,
+        "Toggle selection or edit input",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["Ctrl+a"],
+        Action::SelectAll,
+        "Select all in section",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["!"],
+        Action::Negate,
+        "Negate section",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["c"],
+        Action::Clear,
+        "Clear all filters",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["Enter"],
+        Action::Submit,
+        "Apply filters",
+    ),
+    (
+        KeyContext::FilterPanel,
+        &["Esc", "q"],
+        Action::Cancel,
+        "Cancel",
+    ),
+    // Editor keybindings
+    (
+        KeyContext::Editor,
+        &["Ctrl+s"],
+        Action::Submit,
+        "Save changes",
+    ),
+    (
+        KeyContext::Editor,
+        &["Ctrl+x"],
+        Action::ExternalEditor,
+        "Continue in $EDITOR",
+    ),
+    (
+        KeyContext::Editor,
+        &["Esc"],
+        Action::Cancel,
+        "Cancel editing",
+    ),
+];
+
+/// Keys that are handled directly by a view and cannot be remapped.
+const FIXED_BINDINGS: &[(KeyContext, &str, &str, &str)] = &[
+    (
+        KeyContext::IssueList,
+        "n / N",
+        "search_match",
+        "Next / previous quick search match",
+    ),
+    (
+        KeyContext::IssueDetail,
+        "1-9",
+        "open_link",
+        "Open description link",
+    ),
+];
+
+/// Get all effective keybindings organized by context.
 pub fn get_keybindings() -> Vec<Keybinding> {
-    vec![
-        // Global keybindings
-        Keybinding::new("?", "help", "Show this help panel", KeyContext::Global),
-        Keybinding::new("Ctrl+C", "quit", "Quit application", KeyContext::Global),
-        Keybinding::new(
-            "p",
-            "switch_profile",
-            "Switch JIRA profile (quick)",
-            KeyContext::Global,
-        ),
-        Keybinding::new(
-            "P",
-            "manage_profiles",
-            "Manage profiles (CRUD)",
-            KeyContext::Global,
-        ),
-        Keybinding::new("r", "refresh", "Refresh current view", KeyContext::Global),
-        Keybinding::new(
-            "Ctrl+P / Ctrl+K",
-            "command_palette",
-            "Open command palette",
-            KeyContext::Global,
-        ),
-        // Issue List keybindings
-        Keybinding::new("j / ↓", "move_down", "Move down", KeyContext::IssueList),
-        Keybinding::new("k / ↑", "move_up", "Move up", KeyContext::IssueList),
-        Keybinding::new("gg", "go_top", "Go to first issue", KeyContext::IssueList),
-        Keybinding::new("G", "go_bottom", "Go to last issue", KeyContext::IssueList),
-        Keybinding::new("Ctrl+d", "page_down", "Page down", KeyContext::IssueList),
-        Keybinding::new("Ctrl+u", "page_up", "Page up", KeyContext::IssueList),
-        Keybinding::new(
-            "Enter",
-            "open_issue",
-            "Open issue details",
-            KeyContext::IssueList,
-        ),
-        Keybinding::new("f", "filter", "Open filter panel", KeyContext::IssueList),
-        Keybinding::new(
-            "F",
-            "saved_filters",
-            "Open saved filters",
-            KeyContext::IssueList,
-        ),
-        Keybinding::new(
-            ": / /",
-            "jql",
-            "Open JQL query input",
-            KeyContext::IssueList,
-        ),
-        Keybinding::new(
-            "o",
-            "open_browser",
-            "Open in browser",
-            KeyContext::IssueList,
-        ),
-        Keybinding::new(
-            "Ctrl+L",
-            "load_more",
-            "Load more issues",
-            KeyContext::IssueList,
-        ),
-        Keybinding::new("q", "quit", "Quit application", KeyContext::IssueList),
-        Keybinding::new("n", "new_issue", "Create new issue", KeyContext::IssueList),
-        // Issue Detail keybindings
-        Keybinding::new(
-            "j / ↓",
-            "scroll_down",
-            "Scroll down",
-            KeyContext::IssueDetail,
-        ),
-        Keybinding::new("k / ↑", "scroll_up", "Scroll up", KeyContext::IssueDetail),
-        Keybinding::new("g", "go_top", "Go to top", KeyContext::IssueDetail),
-        Keybinding::new("G", "go_bottom", "Go to bottom", KeyContext::IssueDetail),
-        Keybinding::new("Ctrl+d", "page_down", "Page down", KeyContext::IssueDetail),
-        Keybinding::new("Ctrl+u", "page_up", "Page up", KeyContext::IssueDetail),
-        Keybinding::new("e", "edit", "Edit issue", KeyContext::IssueDetail),
-        Keybinding::new("s", "status", "Change status", KeyContext::IssueDetail),
-        Keybinding::new("c", "comment", "Add comment", KeyContext::IssueDetail),
-        Keybinding::new("a", "assign", "Change assignee", KeyContext::IssueDetail),
-        Keybinding::new("y", "priority", "Change priority", KeyContext::IssueDetail),
-        Keybinding::new("l", "labels", "Edit labels", KeyContext::IssueDetail),
-        Keybinding::new(
-            "o",
-            "open_browser",
-            "Open in browser",
-            KeyContext::IssueDetail,
-        ),
-        Keybinding::new("L", "link", "Link issue", KeyContext::IssueDetail),
-        Keybinding::new("D", "delete", "Delete issue", KeyContext::IssueDetail),
-        Keybinding::new(
-            "q / Esc",
-            "back",
-            "Go back to list",
-            KeyContext::IssueDetail,
-        ),
-        // JQL Input keybindings
-        Keybinding::new("Enter", "execute", "Execute query", KeyContext::JqlInput),
-        Keybinding::new("↑ / ↓", "history", "Browse history", KeyContext::JqlInput),
-        Keybinding::new("Esc", "cancel", "Cancel", KeyContext::JqlInput),
-        // Profile Management keybindings
-        Keybinding::new("a", "add", "Add new profile", KeyContext::ProfileManagement),
-        Keybinding::new(
-            "e",
-            "edit",
-            "Edit selected profile",
-            KeyContext::ProfileManagement,
-        ),
-        Keybinding::new(
-            "d",
-            "delete",
-            "Delete selected profile",
-            KeyContext::ProfileManagement,
-        ),
-        Keybinding::new(
-            "s",
-            "set_default",
-            "Set as default profile",
-            KeyContext::ProfileManagement,
-        ),
-        Keybinding::new(
-            "Space",
-            "switch",
-            "Switch to profile",
-            KeyContext::ProfileManagement,
-        ),
-        Keybinding::new("q / Esc", "back", "Go back", KeyContext::ProfileManagement),
-        // Filter Panel keybindings
-        Keybinding::new(
-            "Tab / ← / →",
-            "switch_section",
-            "Switch section",
-            KeyContext::FilterPanel,
-        ),
-        Keybinding::new(
-            "↑ / ↓",
-            "navigate",
-            "Navigate in section",
-            KeyContext::FilterPanel,
-        ),
-        Keybinding::new(
-            "Space",
-            "toggle",
-            "Toggle selection",
-            KeyContext::FilterPanel,
-        ),
-        Keybinding::new("c", "clear", "Clear all filters", KeyContext::FilterPanel),
-        Keybinding::new("Enter", "apply", "Apply filters", KeyContext::FilterPanel),
-        Keybinding::new("Esc", "cancel", "Cancel", KeyContext::FilterPanel),
-        // Editor keybindings
-        Keybinding::new("Ctrl+S", "save", "Save changes", KeyContext::Editor),
-        Keybinding::new("Esc", "cancel", "Cancel editing", KeyContext::Editor),
-    ]
+    let keymap = keymap();
+    let mut bindings = Vec::new();
+
+    for context in KeyContext::ALL {
+        for (_, _, action, description) in DEFAULT_BINDINGS.iter().filter(|b| b.0 == context) {
+            let keys = keymap.keys_for(context, *action);
+            if keys.is_empty() {
+                continue;
+            }
+            let keys: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+            bindings.push(Keybinding::new(
+                &keys.join(" / "),
+                action.id(),
+                description,
+                context,
+            ));
+        }
+        for (_, key, action, description) in FIXED_BINDINGS.iter().filter(|b| b.0 == context) {
+            bindings.push(Keybinding::new(key, action, description, context));
+        }
+    }
+
+    bindings
 }
 
 /// Get keybindings filtered by context.
@@ -261,19 +776,9 @@ pub fn get_keybindings_for_context(context: KeyContext) -> Vec<Keybinding> {
 
 /// Get keybindings grouped by context.
 pub fn get_keybindings_grouped() -> Vec<(KeyContext, Vec<Keybinding>)> {
-    let contexts = [
-        KeyContext::Global,
-        KeyContext::IssueList,
-        KeyContext::IssueDetail,
-        KeyContext::JqlInput,
-        KeyContext::ProfileManagement,
-        KeyContext::FilterPanel,
-        KeyContext::Editor,
-    ];
-
     let all_bindings = get_keybindings();
 
-    contexts
+    KeyContext::ALL
         .iter()
         .filter_map(|ctx| {
             let bindings: Vec<Keybinding> = all_bindings
@@ -290,39 +795,90 @@ pub fn get_keybindings_grouped() -> Vec<(KeyContext, Vec<Keybinding>)> {
         .collect()
 }
 
-/// Get contextual hint text for a given context.
-pub fn get_context_hints(context: KeyContext) -> &'static str {
+/// Help bar hints per context: the actions whose keys are shown and a label.
+fn hint_actions(context: KeyContext) -> &'static [(&'static [Action], &'static str)] {
     match context {
-        KeyContext::Global => "[?] help",
-        KeyContext::IssueList => {
-            "[j/k] navigate  [Enter] open  [n] new  [f] filter  [F] saved  [/] search  [?] help"
-        }
-        KeyContext::IssueDetail => {
-            "[e] edit  [c] comment  [s] status  [a] assign  [q] back  [?] help"
-        }
-        KeyContext::ProfileManagement => "[a] add  [e] edit  [d] delete  [s] default  [q] back",
-        KeyContext::FilterPanel => {
-            "[Space] toggle  [Tab] section  [Enter] apply  [c] clear  [Esc] close"
-        }
-        KeyContext::JqlInput => "[Enter] execute  [↑/↓] history  [Esc] cancel",
-        KeyContext::Editor => "[Ctrl+S] save  [Esc] cancel",
+        KeyContext::Global => &[(&[Action::Help], "help")],
+        KeyContext::IssueList => &[
+            (&[Action::MoveDown, Action::MoveUp], "navigate"),
+            (&[Action::Open], "open"),
+            (&[Action::NewIssue], "new"),
+            (&[Action::OpenFilter], "filter"),
+            (&[Action::SavedFilters], "saved"),
+            (&[Action::Search], "search"),
+            (&[Action::Help], "help"),
+        ],
+        KeyContext::IssueDetail => &[
+            (&[Action::Edit], "edit"),
+            (&[Action::Comments], "comment"),
+            (&[Action::ChangeStatus], "status"),
+            (&[Action::Assign], "assign"),
+            (&[Action::Back], "back"),
+            (&[Action::Help], "help"),
+        ],
+        KeyContext::ProfileManagement => &[
+            (&[Action::Add], "add"),
+            (&[Action::Edit], "edit"),
+            (&[Action::Delete], "delete"),
+            (&[Action::SetDefault], "default"),
+            (&[Action::Back], "back"),
+        ],
+        KeyContext::FilterPanel => &[
+            (&[Action::Toggle], "toggle"),
+            (&[Action::NextSection], "section"),
+            (&[Action::Submit], "apply"),
+            (&[Action::Clear], "clear"),
+            (&[Action::Cancel], "close"),
+        ],
+        KeyContext::JqlInput => &[
+            (&[Action::Submit], "execute"),
+            (&[Action::HistoryPrev, Action::HistoryNext], "history"),
+            (&[Action::Cancel], "cancel"),
+        ],
+        KeyContext::Releases => &[
+            (&[Action::MoveDown, Action::MoveUp], "navigate"),
+            (&[Action::SwitchPane], "pane"),
+            (&[Action::Open], "open"),
+            (&[Action::NewVersion], "new"),
+            (&[Action::ReleaseVersion], "release"),
+            (&[Action::Back], "back"),
+        ],
+        KeyContext::Editor => &[(&[Action::Submit], "save"), (&[Action::Cancel], "cancel")],
     }
 }
 
+/// Get contextual hint text for a given context.
+///
+/// Shows the first key bound to each hinted action; actions of the global
+/// context (like help) are looked up there.
+pub fn get_context_hints(context: KeyContext) -> String {
+    let keymap = keymap();
+    let first_key = |action: Action| {
+        [context, KeyContext::Global]
+            .into_iter()
+            .find_map(|ctx| keymap.keys_for(ctx, action).first().map(|k| k.to_string()))
+    };
+
+    hint_actions(context)
+        .iter()
+        .filter_map(|(actions, label)| {
+            let keys: Vec<String> = actions.iter().filter_map(|a| first_key(*a)).collect();
+            (!keys.is_empty()).then(|| format!("[{}] {}", keys.join("/"), label))
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_keybindings_new() {
-        let bindings = KeyBindings::new(true);
-        assert!(bindings.vim_mode);
-    }
-
-    #[test]
-    fn test_keybindings_default() {
-        let bindings = KeyBindings::default();
-        assert!(bindings.vim_mode);
+    fn test_key_context_ids_round_trip() {
+        for context in KeyContext::ALL {
+            assert_eq!(KeyContext::from_id(context.id()), Some(context));
+        }
+        assert_eq!(KeyContext::from_id("nowhere"), None);
     }
 
     #[test]
@@ -386,8 +942,21 @@ mod tests {
     #[test]
     fn test_get_context_hints() {
         let hints = get_context_hints(KeyContext::IssueList);
-        assert!(hints.contains("navigate"));
-        assert!(hints.contains("help"));
+        assert!(hints.contains("[j/k] navigate"));
+        assert!(hints.contains("[?] help"));
+    }
+
+    #[test]
+    fn test_get_keybindings_joins_keys_of_an_action() {
+        let bindings = get_keybindings_for_context(KeyContext::IssueList);
+        let go_top = bindings.iter().find(|b| b.action == "go_top").unwrap();
+        assert_eq!(go_top.key, "gg / Home");
+    }
+
+    #[test]
+    fn test_get_keybindings_includes_fixed_bindings() {
+        let bindings = get_keybindings_for_context(KeyContext::IssueDetail);
+        assert!(bindings.iter().any(|b| b.key == "1-9"));
     }
 
     #[test]
@@ -6,9 +6,11 @@
 #![allow(dead_code)]
 
 mod handler;
+mod keymap;
 mod keys;
 
 pub use handler::EventHandler;
+pub use keymap::{keymap, set_keymap, Action, Keymap, PendingKeys};
 pub use keys::{get_context_hints, get_keybindings_grouped, KeyContext, Keybinding};
 
 use crossterm::event::KeyEvent;
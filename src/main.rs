@@ -5,6 +5,7 @@
 mod api;
 mod app;
 mod cache;
+mod cli;
 mod commands;
 mod config;
 mod error;
@@ -23,28 +24,46 @@ use crossterm::{
 use ratatui::prelude::*;
 
 use app::App;
+use clap::Parser;
+use cli::Cli;
 use config::Config;
 use events::EventHandler;
-use ui::{init_theme, load_theme};
+use ui::{init_theme, init_vim_editing};
 
 /// Application result type.
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    // Subcommands run without the TUI
+    let cli = Cli::parse();
+    if let Some(command) = cli.command {
+        match command.run() {
+            Ok(output) => {
+                print!("{}", output);
+                return Ok(());
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Initialize logging first (before any other operations)
     if let Err(e) = logging::init() {
         eprintln!("Warning: Failed to initialize logging: {}", e);
         // Continue without logging rather than failing completely
     }
 
-    // Load configuration and initialize theme before anything else
-    let config = Config::load().unwrap_or_default();
-    let theme = load_theme(
-        &config.settings.theme,
-        config.settings.custom_theme.as_ref(),
-    );
-    init_theme(theme);
+    // Load configuration and initialize theme before anything else. Load
+    // errors are reported by the app, which starts with defaults.
+    let loaded = Config::load();
+    let fallback = Config::fallback();
+    let config = loaded.as_ref().unwrap_or(&fallback);
+    let settings = config.effective_settings(config.startup_profile().map(|p| p.name.as_str()));
+    init_theme(config.theme(&settings));
+    init_vim_editing(settings.vim_editing);
 
     // Set up panic hook to restore terminal on crash
     setup_panic_hook();
@@ -53,7 +72,7 @@ async fn main() -> Result<()> {
     let mut terminal = setup_terminal()?;
 
     // Run the application
-    let result = run_app(&mut terminal).await;
+    let result = run_app(&mut terminal, loaded, cli.jql).await;
 
     // Restore terminal state
     restore_terminal(&mut terminal)?;
@@ -203,14 +222,21 @@ impl<'a> Drop for TuiSuspendGuard<'a> {
 ///
 /// The async task system keeps the UI responsive by running API calls in
 /// background tasks and communicating results through channels.
-async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+async fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    loaded: config::Result<Config>,
+    jql: Option<String>,
+) -> Result<()> {
     use api::JiraClient;
     use cache::{CacheManager, CacheStatus};
     use tasks::{create_task_channel, ApiMessage};
     use tracing::{debug, error, info, warn};
     use ui::ExternalEditor;
 
-    let mut app = App::new();
+    let mut app = App::with_loaded_config(loaded);
+    if let Some(jql) = jql {
+        app.open_query(jql);
+    }
     let event_handler = EventHandler::new();
 
     // Create the async task channel for background operations
@@ -222,11 +248,11 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
 
     if let Some(profile) = app.current_profile().cloned() {
         // Initialize cache manager
-        let cache_ttl = app.config().settings.cache_ttl_minutes;
+        let cache_ttl = app.settings().cache_ttl_minutes;
         match CacheManager::with_max_size(
             &profile.name,
             cache_ttl,
-            app.config().settings.cache_max_size_mb,
+            app.settings().cache_max_size_mb,
         ) {
             Ok(cm) => {
                 debug!("Cache manager initialized for profile: {}", profile.name);
@@ -240,6 +266,7 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
         match JiraClient::new(&profile).await {
             Ok(c) => {
                 info!("Connected to JIRA as profile: {}", profile.name);
+                app.set_field_ids(c.field_ids().clone());
                 client = Some(c);
             }
             Err(e) => {
@@ -271,6 +298,7 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                 ApiMessage::ClientConnected(result) => match result {
                     Ok(c) => {
                         info!("Connected to JIRA");
+                        app.set_field_ids(c.field_ids().clone());
                         client = Some(c);
                         needs_fetch = true;
                         needs_filter_options = true;
@@ -285,6 +313,7 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                     }
                 },
                 ApiMessage::IssuesFetched {
+                    request,
                     jql,
                     result,
                     is_background_refresh,
@@ -305,12 +334,22 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                     issues_count, search_result.total, has_more, next_page_token.is_some()
                                 );
                             }
+                            // The tab or query may have changed while fetching
+                            if !app.is_current_fetch(request) {
+                                debug!("Ignoring results of a fetch that is no longer shown");
+                                continue;
+                            }
                             // Update cache
                             if let Some(ref cm) = cache_manager {
                                 if let Err(e) = cm.set_search_results(&jql, &search_result) {
                                     debug!("Failed to cache results: {}", e);
                                 }
                             }
+                            if is_background_refresh {
+                                // Keep the cursor where it was on the cached list
+                                let (selected, scroll) = app.list_view().position();
+                                app.list_view_mut().restore_position(selected, scroll);
+                            }
                             app.list_view_mut().set_issues(search_result.issues);
                             app.list_view_mut().set_loading(false);
                             app.list_view_mut().clear_error();
@@ -327,6 +366,8 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                         Err(e) => {
                             if is_background_refresh {
                                 debug!("Background refresh failed (using cached data): {}", e);
+                            } else if !app.is_current_fetch(request) {
+                                debug!("Ignoring fetch error for a query that is no longer shown");
                             } else {
                                 error!("Failed to fetch issues: {}", e);
                                 let error_msg = format!("Failed to fetch issues: {}", e);
@@ -426,6 +467,99 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                         app.handle_assignee_change_failure(&e);
                     }
                 },
+                ApiMessage::WatchersFetched { result } => match result {
+                    Ok(watchers) => {
+                        debug!("Loaded {} watchers", watchers.watchers.len());
+                        app.set_watchers(watchers);
+                    }
+                    Err(e) => {
+                        error!("Failed to fetch watchers: {}", e);
+                        app.handle_fetch_watchers_failure(&e);
+                    }
+                },
+                ApiMessage::WatcherCandidatesFetched { result } => match result {
+                    Ok(users) => {
+                        debug!("Loaded {} watcher candidates", users.len());
+                        app.set_watcher_candidates(users);
+                    }
+                    Err(e) => {
+                        error!("Failed to fetch watcher candidates: {}", e);
+                        app.handle_fetch_watcher_candidates_failure(&e);
+                    }
+                },
+                ApiMessage::WatchChanged { change, result } => match result {
+                    Ok((updated_issue, watchers)) => {
+                        info!("Watchers or votes changed for issue {}", updated_issue.key);
+                        app.handle_watch_change_success(&change, updated_issue, watchers);
+                    }
+                    Err(e) => {
+                        error!("Failed to change watchers or votes: {}", e);
+                        app.handle_watch_change_failure(&change, &e);
+                    }
+                },
+                ApiMessage::VersionsFetched(result) => match result {
+                    Ok(versions) => {
+                        debug!("Loaded {} versions", versions.len());
+                        app.set_versions(versions);
+                    }
+                    Err(e) => {
+                        error!("Failed to fetch versions: {}", e);
+                        app.handle_fetch_versions_failure(&e);
+                    }
+                },
+                ApiMessage::VersionChanged { result } => match result {
+                    Ok(updated_issue) => {
+                        info!("Versions changed for issue {}", updated_issue.key);
+                        app.handle_version_change_success(updated_issue);
+                    }
+                    Err(e) => {
+                        error!("Failed to change versions: {}", e);
+                        app.handle_version_change_failure(&e);
+                    }
+                },
+                ApiMessage::ReleaseVersionsFetched {
+                    project_key,
+                    result,
+                } => match result {
+                    Ok(versions) => {
+                        debug!("Loaded {} versions for {}", versions.len(), project_key);
+                        app.set_release_versions(&project_key, versions);
+                    }
+                    Err(e) => {
+                        error!("Failed to fetch versions for {}: {}", project_key, e);
+                        app.handle_fetch_release_versions_failure(&e);
+                    }
+                },
+                ApiMessage::VersionIssuesFetched { version_id, result } => match result {
+                    Ok(issues) => {
+                        debug!("Loaded {} issues for version {}", issues.len(), version_id);
+                        app.set_version_issues(&version_id, issues);
+                    }
+                    Err(e) => {
+                        error!("Failed to fetch issues for version {}: {}", version_id, e);
+                        app.handle_fetch_version_issues_failure(&e);
+                    }
+                },
+                ApiMessage::VersionCreated { result } => match result {
+                    Ok(version) => {
+                        info!("Created version {}", version.name);
+                        app.handle_create_version_success(version);
+                    }
+                    Err(e) => {
+                        error!("Failed to create version: {}", e);
+                        app.handle_create_version_failure(&e);
+                    }
+                },
+                ApiMessage::VersionReleased { version_id, result } => match result {
+                    Ok(()) => {
+                        info!("Released version {}", version_id);
+                        app.handle_release_version_success(&version_id);
+                    }
+                    Err(e) => {
+                        error!("Failed to release version {}: {}", version_id, e);
+                        app.handle_release_version_failure(&e);
+                    }
+                },
                 ApiMessage::PrioritiesFetched(result) => match result {
                     Ok(priorities) => {
                         debug!("Loaded {} priorities", priorities.len());
@@ -571,6 +705,35 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                         app.handle_issue_search_failure(&e);
                     }
                 },
+                ApiMessage::CachedIssuesLoaded(issues) => {
+                    debug!("Loaded {} cached issues for go to issue", issues.len());
+                    app.add_cached_issues(issues);
+                }
+                ApiMessage::GoToIssueResults { query, result } => match result {
+                    Ok(suggestions) => {
+                        debug!("Found {} issues for go to issue", suggestions.len());
+                        app.handle_go_to_search_success(&query, suggestions);
+                    }
+                    Err(e) => app.handle_go_to_search_failure(&query, &e),
+                },
+                ApiMessage::MentionUsersFetched { query, result } => match result {
+                    Ok(users) => {
+                        debug!("Found {} users for mention", users.len());
+                        app.handle_mention_search_success(&query, users);
+                    }
+                    Err(e) => app.handle_mention_search_failure(&query, &e),
+                },
+                ApiMessage::JqlAutocompleteFetched(result) => {
+                    app.handle_jql_autocomplete_fetched(result);
+                }
+                ApiMessage::JqlSuggestionsFetched {
+                    field,
+                    value,
+                    result,
+                } => app.handle_jql_suggestions_fetched(&field, &value, result),
+                ApiMessage::JqlValidated { query, result } => {
+                    app.handle_jql_validated(&query, result);
+                }
                 ApiMessage::LinkCreated { issue_key, result } => match result {
                     Ok(()) => {
                         info!("Link created successfully");
@@ -611,6 +774,17 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                         app.handle_create_issue_failure(&e);
                     }
                 },
+                ApiMessage::CopiedToClone {
+                    issue_key,
+                    failures,
+                } => {
+                    info!(
+                        "Copied links and subtasks to {} ({} failed)",
+                        issue_key,
+                        failures.len()
+                    );
+                    app.handle_copy_to_clone_complete(&issue_key, failures);
+                }
                 ApiMessage::IssueTypesFetched(result) => match result {
                     Ok(issue_types) => {
                         debug!("Loaded {} issue types", issue_types.len());
@@ -621,6 +795,20 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                         app.handle_fetch_issue_types_failure(&e);
                     }
                 },
+                ApiMessage::CreateFieldsFetched {
+                    project_key,
+                    issue_type_id,
+                    result,
+                } => match result {
+                    Ok(fields) => {
+                        debug!("Loaded {} create fields", fields.len());
+                        app.handle_create_fields_fetched(&project_key, &issue_type_id, fields);
+                    }
+                    Err(e) => {
+                        error!("Failed to fetch create fields: {}", e);
+                        app.handle_fetch_create_fields_failure(&e);
+                    }
+                },
             }
         }
 
@@ -628,67 +816,68 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
         // STEP 2: Spawn background tasks for pending fetch operations
         // =================================================================
 
+        // Fetch issues for a newly activated tab
+        if app.take_pending_tab_fetch() && client.is_some() {
+            needs_fetch = true;
+        }
+
         // Fetch issues if needed (spawn in background)
         if needs_fetch {
             needs_fetch = false;
-            let jql = app.effective_jql();
-            let default_jql = format!(
-                "assignee = currentUser() OR reporter = currentUser() {}",
-                app.list_view().sort().to_jql()
-            );
-            let jql_query = if jql.is_empty() {
-                default_jql.clone()
-            } else {
-                jql.clone()
-            };
-
-            debug!("Fetching issues with JQL: {}", jql_query);
-
-            // Try cache first
-            let cached_result = cache_manager
-                .as_ref()
-                .and_then(|cm| cm.get_search_results(&jql_query));
-
-            if let Some(cached) = cached_result {
-                // Use cached data immediately
-                let issues_count = cached.results.issues.len() as u32;
-                let has_more = cached.results.has_more();
-                // Note: cached token may be stale, but background refresh will update it
-                let next_page_token = cached.results.next_page_token.clone();
-                info!(
-                    "Loaded {} issues from cache (total: {}, has_more: {}, has_token: {})",
-                    issues_count,
-                    cached.results.total,
-                    has_more,
-                    next_page_token.is_some()
-                );
-                app.list_view_mut().set_issues(cached.results.issues);
-                app.list_view_mut().set_loading(false);
-                app.list_view_mut().clear_error();
-                app.list_view_mut().pagination_mut().update_from_response(
-                    0,
-                    issues_count,
-                    cached.results.total,
-                    has_more,
-                    next_page_token,
-                );
-                app.list_view_mut()
-                    .set_cache_status(Some(CacheStatus::FromCache));
-
-                // Also spawn background refresh (non-blocking)
-                if let Some(ref c) = client {
-                    let page_size = app.list_view().pagination().page_size;
-                    task_spawner.spawn_fetch_issues(c, jql_query, 0, page_size, true);
+            let request = app.begin_issue_fetch();
+            match app.search_jql() {
+                Err(e) => app.handle_invalid_query(&e),
+                Ok(jql_query) => {
+                    debug!("Fetching issues with JQL: {}", jql_query);
+
+                    // Try cache first
+                    let cached_result = cache_manager
+                        .as_ref()
+                        .and_then(|cm| cm.get_search_results(&jql_query));
+
+                    if let Some(cached) = cached_result {
+                        // Use cached data immediately
+                        let issues_count = cached.results.issues.len() as u32;
+                        let has_more = cached.results.has_more();
+                        // Note: cached token may be stale, but background refresh will update it
+                        let next_page_token = cached.results.next_page_token.clone();
+                        info!(
+                            "Loaded {} issues from cache (total: {}, has_more: {}, has_token: {})",
+                            issues_count,
+                            cached.results.total,
+                            has_more,
+                            next_page_token.is_some()
+                        );
+                        app.list_view_mut().set_issues(cached.results.issues);
+                        app.list_view_mut().set_loading(false);
+                        app.list_view_mut().clear_error();
+                        app.list_view_mut().pagination_mut().update_from_response(
+                            0,
+                            issues_count,
+                            cached.results.total,
+                            has_more,
+                            next_page_token,
+                        );
+                        app.list_view_mut()
+                            .set_cache_status(Some(CacheStatus::FromCache));
+
+                        // Also spawn background refresh (non-blocking)
+                        if let Some(ref c) = client {
+                            let page_size = app.list_view().pagination().page_size;
+                            task_spawner
+                                .spawn_fetch_issues(c, request, jql_query, 0, page_size, true);
+                        }
+                    } else if let Some(ref c) = client {
+                        // No cache, spawn fetch from API (non-blocking)
+                        let page_size = app.list_view().pagination().page_size;
+                        task_spawner.spawn_fetch_issues(c, request, jql_query, 0, page_size, false);
+                    } else {
+                        // No client available
+                        app.list_view_mut().set_loading(false);
+                        app.list_view_mut()
+                            .set_cache_status(Some(CacheStatus::Offline));
+                    }
                 }
-            } else if let Some(ref c) = client {
-                // No cache, spawn fetch from API (non-blocking)
-                let page_size = app.list_view().pagination().page_size;
-                task_spawner.spawn_fetch_issues(c, jql_query, 0, page_size, false);
-            } else {
-                // No client available
-                app.list_view_mut().set_loading(false);
-                app.list_view_mut()
-                    .set_cache_status(Some(CacheStatus::Offline));
             }
         }
 
@@ -717,7 +906,8 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
         app.update(event);
 
         // Handle pending external editor request (must be synchronous)
-        if let Some((issue_key, current_content)) = app.take_pending_external_edit() {
+        if let Some(request) = app.take_pending_external_edit() {
+            let issue_key = &request.file_key;
             debug!(issue_key = %issue_key, "Opening external editor");
 
             // Get a reference to stdout for suspend/resume
@@ -728,7 +918,7 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
 
             // Launch external editor synchronously
             let editor = ExternalEditor::new();
-            let result = editor.open(&issue_key, &current_content);
+            let result = editor.open_with_header(issue_key, &request.header, &request.content);
 
             // Guard is dropped here, restoring TUI
 
@@ -736,7 +926,7 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
             match result {
                 Ok(edit_result) if edit_result.was_modified => {
                     info!(issue_key = %issue_key, "External editor content modified");
-                    app.apply_external_edit_result(edit_result.content);
+                    app.apply_external_edit_result(request.target, edit_result.content);
                 }
                 Ok(_) => {
                     debug!(issue_key = %issue_key, "External editor content unchanged");
@@ -752,6 +942,24 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
             continue;
         }
 
+        // Handle pending request to fix the config file in the editor
+        if let Some((path, line)) = app.take_pending_config_edit() {
+            debug!(path = %path.display(), ?line, "Opening config file in editor");
+            let mut stdout = stdout();
+            let guard = TuiSuspendGuard::new(&mut stdout, terminal)?;
+            let result = ExternalEditor::new().open_file(&path, line);
+            drop(guard);
+
+            match result {
+                // Reloading may connect a profile, which is handled below
+                Ok(()) => app.reload_config(),
+                Err(e) => {
+                    error!(error = %e, "External editor error");
+                    app.notify_error(format!("Editor error: {}", e));
+                }
+            }
+        }
+
         // =================================================================
         // STEP 5: Spawn background tasks for pending operations
         // =================================================================
@@ -764,11 +972,11 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
         if old_profile != new_profile {
             if let Some(profile) = app.current_profile().cloned() {
                 // Recreate cache manager for new profile
-                let cache_ttl = app.config().settings.cache_ttl_minutes;
+                let cache_ttl = app.settings().cache_ttl_minutes;
                 match CacheManager::with_max_size(
                     &profile.name,
                     cache_ttl,
-                    app.config().settings.cache_max_size_mb,
+                    app.settings().cache_max_size_mb,
                 ) {
                     Ok(cm) => {
                         debug!("Cache manager initialized for profile: {}", profile.name);
@@ -799,11 +1007,11 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                 info!("Reconnecting to JIRA for profile: {}", profile.name);
 
                 // Reinitialize cache manager
-                let cache_ttl = app.config().settings.cache_ttl_minutes;
+                let cache_ttl = app.settings().cache_ttl_minutes;
                 match CacheManager::with_max_size(
                     &profile.name,
                     cache_ttl,
-                    app.config().settings.cache_max_size_mb,
+                    app.settings().cache_max_size_mb,
                 ) {
                     Ok(cm) => {
                         debug!("Cache manager reinitialized for profile: {}", profile.name);
@@ -825,26 +1033,22 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
 
         // Handle pending load more request (pagination) - spawn in background
         if app.take_pending_load_more() {
-            if let Some(ref c) = client {
-                let jql = app.effective_jql();
-                let default_jql = format!(
-                    "assignee = currentUser() OR reporter = currentUser() {}",
-                    app.list_view().sort().to_jql()
-                );
-                let jql_query = if jql.is_empty() { default_jql } else { jql };
-                let page_size = app.list_view().pagination().page_size;
-                let next_page_token = app.list_view().pagination().next_page_token.clone();
-
-                info!(
-                    "Loading more issues: page_size={}, has_token={}, jql={}",
-                    page_size,
-                    next_page_token.is_some(),
-                    jql_query
-                );
+            match (&client, app.search_jql()) {
+                (Some(c), Ok(jql_query)) => {
+                    let page_size = app.list_view().pagination().page_size;
+                    let next_page_token = app.list_view().pagination().next_page_token.clone();
 
-                task_spawner.spawn_load_more(c, jql_query, page_size, next_page_token);
-            } else {
-                app.handle_load_more_failure("No JIRA connection");
+                    info!(
+                        "Loading more issues: page_size={}, has_token={}, jql={}",
+                        page_size,
+                        next_page_token.is_some(),
+                        jql_query
+                    );
+
+                    task_spawner.spawn_load_more(c, jql_query, page_size, next_page_token);
+                }
+                (None, _) => app.handle_load_more_failure("No JIRA connection"),
+                (_, Err(e)) => app.handle_load_more_failure(&format!("Invalid query: {}", e)),
             }
         }
 
@@ -894,6 +1098,104 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
             }
         }
 
+        // Handle pending fetch watchers request - spawn in background
+        if let Some(issue_key) = app.take_pending_fetch_watchers() {
+            if let Some(ref c) = client {
+                debug!("Fetching watchers for issue: {}", issue_key);
+                task_spawner.spawn_fetch_watchers(c, issue_key);
+            } else {
+                app.handle_fetch_watchers_failure("No JIRA connection");
+            }
+        }
+
+        // Handle pending fetch watcher candidates request - spawn in background
+        if let Some((_issue_key, project_key)) = app.take_pending_fetch_watcher_candidates() {
+            if let Some(ref c) = client {
+                debug!("Fetching watcher candidates for project: {}", project_key);
+                task_spawner.spawn_fetch_watcher_candidates(c, project_key);
+            } else {
+                app.handle_fetch_watcher_candidates_failure("No JIRA connection");
+            }
+        }
+
+        // Handle pending watch or vote change - spawn in background
+        if let Some((issue_key, change)) = app.take_pending_watch_change() {
+            if let Some(ref c) = client {
+                debug!(
+                    "Changing watchers or votes on issue {}: {:?}",
+                    issue_key, change
+                );
+                task_spawner.spawn_change_watch(c, issue_key, change);
+            } else {
+                app.handle_watch_change_failure(&change, "No JIRA connection");
+            }
+        }
+
+        // Handle fetch versions request for the version editor - spawn in background
+        if let Some((_issue_key, project_key)) = app.take_pending_fetch_versions() {
+            if let Some(ref c) = client {
+                debug!("Fetching versions for project {}", project_key);
+                task_spawner.spawn_fetch_versions(c, project_key);
+            } else {
+                app.handle_fetch_versions_failure("No JIRA connection");
+            }
+        }
+
+        // Handle fix or affects version change - spawn in background
+        if let Some((issue_key, field, operation)) = app.take_pending_version_change() {
+            if let Some(ref c) = client {
+                debug!(
+                    "Changing {} of issue {}: {:?}",
+                    field.field_id(),
+                    issue_key,
+                    operation
+                );
+                task_spawner.spawn_change_version(c, issue_key, field, operation);
+            } else {
+                app.handle_version_change_failure("No JIRA connection");
+            }
+        }
+
+        // Handle release view requests - spawn in background
+        if let Some(project_key) = app.take_pending_fetch_release_versions() {
+            if let Some(ref c) = client {
+                debug!("Fetching release versions for project {}", project_key);
+                task_spawner.spawn_fetch_release_versions(c, project_key);
+            } else {
+                app.handle_fetch_release_versions_failure("No JIRA connection");
+            }
+        }
+
+        if let Some(version_id) = app.take_pending_fetch_version_issues() {
+            if let Some(ref c) = client {
+                debug!("Fetching issues for version {}", version_id);
+                task_spawner.spawn_fetch_version_issues(c, version_id);
+            } else {
+                app.handle_fetch_version_issues_failure("No JIRA connection");
+            }
+        }
+
+        if let Some((project_id, name)) = app.take_pending_create_version() {
+            if let Some(ref c) = client {
+                debug!("Creating version {} in project {}", name, project_id);
+                task_spawner.spawn_create_version(c, project_id, name);
+            } else {
+                app.handle_create_version_failure("No JIRA connection");
+            }
+        }
+
+        if let Some((version_id, release_date, move_to)) = app.take_pending_release_version() {
+            if let Some(ref c) = client {
+                debug!(
+                    "Releasing version {} (move unresolved to {:?})",
+                    version_id, move_to
+                );
+                task_spawner.spawn_release_version(c, version_id, release_date, move_to);
+            } else {
+                app.handle_release_version_failure("No JIRA connection");
+            }
+        }
+
         // Handle pending fetch priorities request - spawn in background
         if let Some(_issue_key) = app.take_pending_fetch_priorities() {
             if let Some(ref c) = client {
@@ -1061,6 +1363,56 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
             }
         }
 
+        // Handle go to issue search request - spawn in background
+        if let Some(query) = app.take_pending_go_to_search() {
+            if let Some(ref c) = client {
+                debug!("Searching issues for go to issue: {}", query);
+                task_spawner.spawn_search_issues_for_go_to(c, query);
+            } else {
+                app.handle_go_to_search_failure(&query, "No JIRA connection");
+            }
+        }
+
+        // Handle pending @mention user search - spawn in background
+        if let Some(query) = app.take_pending_mention_search() {
+            if let Some(ref c) = client {
+                debug!("Searching users for mention: {}", query);
+                task_spawner.spawn_search_users_for_mention(c, query);
+            } else {
+                app.handle_mention_search_failure(&query, "No JIRA connection");
+            }
+        }
+
+        // Handle JQL autocomplete requests - spawn in background. Without a
+        // connection the JQL input falls back to its static hints.
+        if app.take_pending_jql_autocomplete() {
+            if let Some(ref c) = client {
+                debug!("Fetching JQL autocomplete data");
+                task_spawner.spawn_fetch_jql_autocomplete(c);
+            } else {
+                app.handle_jql_autocomplete_fetched(Err("No JIRA connection".to_string()));
+            }
+        }
+        if let Some((field, value)) = app.take_pending_jql_suggestions() {
+            if let Some(ref c) = client {
+                debug!("Fetching JQL suggestions for {}: {}", field, value);
+                task_spawner.spawn_fetch_jql_suggestions(c, field, value);
+            }
+        }
+        if let Some(query) = app.take_pending_jql_validation() {
+            if let Some(ref c) = client {
+                debug!("Validating JQL: {}", query);
+                task_spawner.spawn_validate_jql(c, query);
+            }
+        }
+
+        // Load cached issues for the go to issue finder - spawn in background
+        if app.take_pending_cached_issues() {
+            if let Some(ref cm) = cache_manager {
+                task_spawner.spawn_load_cached_issues(cm.clone());
+            }
+        }
+
         // Handle create link request - spawn in background
         if let Some((current_key, target_key, link_type_name, is_outward)) =
             app.take_pending_create_link()
@@ -1122,12 +1474,41 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
             }
         }
 
+        // Handle pending copy to a new clone - spawn in background
+        if let Some((issue_key, links, subtasks)) = app.take_pending_copy_to_clone() {
+            if let Some(ref c) = client {
+                debug!("Copying links and subtasks to clone: {}", issue_key);
+                task_spawner.spawn_copy_to_clone(c, issue_key, links, subtasks);
+            } else {
+                app.handle_copy_to_clone_complete(
+                    &issue_key,
+                    vec!["No JIRA connection".to_string()],
+                );
+            }
+        }
+
+        // Handle pending fetch create fields request - spawn in background
+        if let Some((project_key, issue_type_id)) = app.take_pending_fetch_create_fields() {
+            if let Some(ref c) = client {
+                debug!(
+                    "Fetching create fields for {} issue type {}",
+                    project_key, issue_type_id
+                );
+                task_spawner.spawn_fetch_create_fields(c, project_key, issue_type_id);
+            } else {
+                app.handle_fetch_create_fields_failure("No JIRA connection");
+            }
+        }
+
         // Check if we should quit
         if app.should_quit() {
             break;
         }
     }
 
+    // Remember the open tabs for the next start
+    app.persist_session();
+
     Ok(())
 }
 
@@ -1204,6 +1585,7 @@ mod tests {
         // The Drop implementation is critical for ensuring terminal restoration
         // even if an editor crashes or panics.
 
+        #[allow(drop_bounds)]
         fn _assert_drop_impl<T: Drop>() {}
 
         // This will fail to compile if TuiSuspendGuard doesn't implement Drop
@@ -23,11 +23,13 @@
 use tokio::sync::mpsc;
 
 use crate::api::types::{
-    Changelog, Comment, CreateIssueRequest, CreateIssueResponse, FieldUpdates, FilterOptions,
-    Issue, IssueLinkType, IssueSuggestion, IssueTypeMeta, IssueUpdateRequest, Priority,
-    SearchResult, Transition, User,
+    Changelog, Comment, CreateFieldMeta, CreateIssueRequest, CreateIssueResponse, FieldUpdates,
+    FilterOptions, Issue, IssueLinkType, IssueSuggestion, IssueTypeMeta, IssueUpdateRequest,
+    JqlAutocompleteData, JqlSuggestion, Priority, SearchResult, Transition, User, Version,
+    VersionField, VersionOperation, WatchChange, Watchers,
 };
 use crate::api::JiraClient;
+use crate::cache::CacheManager;
 use crate::config::Profile;
 
 /// Messages sent from background tasks to the main event loop.
@@ -41,6 +43,8 @@ pub enum ApiMessage {
 
     /// Issue search results (initial fetch or refresh)
     IssuesFetched {
+        /// Fetch id from `App::begin_issue_fetch`.
+        request: u64,
         jql: String,
         result: Result<SearchResult, String>,
         is_background_refresh: bool,
@@ -73,6 +77,45 @@ pub enum ApiMessage {
     /// Assignee change result
     AssigneeChanged { result: Result<Issue, String> },
 
+    /// Watchers fetched for an issue
+    WatchersFetched { result: Result<Watchers, String> },
+
+    /// Users that can be added as watchers
+    WatcherCandidatesFetched { result: Result<Vec<User>, String> },
+
+    /// Watch or vote change result, with the refreshed issue and watchers
+    WatchChanged {
+        change: WatchChange,
+        result: Result<(Issue, Watchers), String>,
+    },
+
+    /// Version names fetched for the version editor
+    VersionsFetched(Result<Vec<String>, String>),
+
+    /// Fix or affects version added/removed result
+    VersionChanged { result: Result<Issue, String> },
+
+    /// Versions fetched for the release view
+    ReleaseVersionsFetched {
+        project_key: String,
+        result: Result<Vec<Version>, String>,
+    },
+
+    /// Issues of a version fetched
+    VersionIssuesFetched {
+        version_id: String,
+        result: Result<Vec<Issue>, String>,
+    },
+
+    /// Version created result
+    VersionCreated { result: Result<Version, String> },
+
+    /// Version released result
+    VersionReleased {
+        version_id: String,
+        result: Result<(), String>,
+    },
+
     /// Priorities fetched
     PrioritiesFetched(Result<Vec<Priority>, String>),
 
@@ -119,6 +162,37 @@ pub enum ApiMessage {
         result: Result<Vec<IssueSuggestion>, String>,
     },
 
+    /// Issues read from the local cache for the go to issue finder
+    CachedIssuesLoaded(Vec<Issue>),
+
+    /// Issue search for the go to issue finder
+    GoToIssueResults {
+        query: String,
+        result: Result<Vec<IssueSuggestion>, String>,
+    },
+
+    /// User search for an @mention picker
+    MentionUsersFetched {
+        query: String,
+        result: Result<Vec<User>, String>,
+    },
+
+    /// JQL autocomplete data fetched
+    JqlAutocompleteFetched(Result<JqlAutocompleteData, String>),
+
+    /// JQL value suggestions fetched
+    JqlSuggestionsFetched {
+        field: String,
+        value: String,
+        result: Result<Vec<JqlSuggestion>, String>,
+    },
+
+    /// JQL query validated, with the errors found in it
+    JqlValidated {
+        query: String,
+        result: Result<Vec<String>, String>,
+    },
+
     /// Link created
     LinkCreated {
         issue_key: String,
@@ -140,8 +214,22 @@ pub enum ApiMessage {
     /// Issue created
     IssueCreated(Result<CreateIssueResponse, String>),
 
+    /// Links and subtasks copied to a cloned issue
+    CopiedToClone {
+        issue_key: String,
+        /// Errors for the links and subtasks that could not be copied.
+        failures: Vec<String>,
+    },
+
     /// Issue types fetched for a project
     IssueTypesFetched(Result<Vec<IssueTypeMeta>, String>),
+
+    /// Create fields fetched for a project's issue type
+    CreateFieldsFetched {
+        project_key: String,
+        issue_type_id: String,
+        result: Result<Vec<CreateFieldMeta>, String>,
+    },
 }
 
 /// Spawns background tasks for async operations.
@@ -170,9 +258,12 @@ impl TaskSpawner {
     }
 
     /// Spawn a task to fetch issues with the given JQL query.
+    ///
+    /// The results carry `request` so stale responses can be told apart.
     pub fn spawn_fetch_issues(
         &self,
         client: &JiraClient,
+        request: u64,
         jql: String,
         start_at: u32,
         page_size: u32,
@@ -187,6 +278,7 @@ impl TaskSpawner {
                 .await
                 .map_err(|e| e.to_string());
             let _ = tx.send(ApiMessage::IssuesFetched {
+                request,
                 jql,
                 result,
                 is_background_refresh,
@@ -300,6 +392,178 @@ impl TaskSpawner {
         });
     }
 
+    /// Spawn a task to fetch the watchers of an issue.
+    pub fn spawn_fetch_watchers(&self, client: &JiraClient, issue_key: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_watchers(&issue_key)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::WatchersFetched { result });
+        });
+    }
+
+    /// Spawn a task to fetch the users that can be added as watchers.
+    pub fn spawn_fetch_watcher_candidates(&self, client: &JiraClient, project_key: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_assignable_users(&project_key)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::WatcherCandidatesFetched { result });
+        });
+    }
+
+    /// Spawn a task to change the watchers or votes of an issue.
+    pub fn spawn_change_watch(&self, client: &JiraClient, issue_key: String, change: WatchChange) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = async {
+                match &change {
+                    WatchChange::Watch => client.set_watching(&issue_key, true).await,
+                    WatchChange::Unwatch => client.set_watching(&issue_key, false).await,
+                    WatchChange::AddWatcher(account_id, _) => {
+                        client.add_watcher(&issue_key, account_id).await
+                    }
+                    WatchChange::RemoveWatcher(account_id, _) => {
+                        client.remove_watcher(&issue_key, account_id).await
+                    }
+                    WatchChange::Vote => client.set_vote(&issue_key, true).await,
+                    WatchChange::Unvote => client.set_vote(&issue_key, false).await,
+                }
+                .map_err(|e| e.to_string())?;
+                let watchers = client
+                    .get_watchers(&issue_key)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let issue = client
+                    .get_issue(&issue_key)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok((issue, watchers))
+            }
+            .await;
+            let _ = tx.send(ApiMessage::WatchChanged { change, result });
+        });
+    }
+
+    /// Spawn a task to fetch the unarchived version names of a project.
+    pub fn spawn_fetch_versions(&self, client: &JiraClient, project_key: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_project_versions(&project_key)
+                .await
+                .map(|versions| {
+                    versions
+                        .into_iter()
+                        .filter(|v| !v.archived)
+                        .map(|v| v.name)
+                        .collect()
+                })
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::VersionsFetched(result));
+        });
+    }
+
+    /// Spawn a task to add or remove a fix or affects version of an issue.
+    pub fn spawn_change_version(
+        &self,
+        client: &JiraClient,
+        issue_key: String,
+        field: VersionField,
+        operation: VersionOperation,
+    ) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = async {
+                match operation {
+                    VersionOperation::Add { name } => {
+                        client.add_versions(&issue_key, field, vec![name]).await
+                    }
+                    VersionOperation::Remove { name } => {
+                        client.remove_versions(&issue_key, field, vec![name]).await
+                    }
+                }
+                .map_err(|e| e.to_string())?;
+                client
+                    .get_issue(&issue_key)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            .await;
+            let _ = tx.send(ApiMessage::VersionChanged { result });
+        });
+    }
+
+    /// Spawn a task to fetch the versions of a project for the release view.
+    pub fn spawn_fetch_release_versions(&self, client: &JiraClient, project_key: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_project_versions(&project_key)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::ReleaseVersionsFetched {
+                project_key,
+                result,
+            });
+        });
+    }
+
+    /// Spawn a task to fetch the issues of a version.
+    pub fn spawn_fetch_version_issues(&self, client: &JiraClient, version_id: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_version_issues(&version_id)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::VersionIssuesFetched { version_id, result });
+        });
+    }
+
+    /// Spawn a task to create a version.
+    pub fn spawn_create_version(&self, client: &JiraClient, project_id: String, name: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .create_version(&project_id, &name)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::VersionCreated { result });
+        });
+    }
+
+    /// Spawn a task to release a version, optionally moving its unresolved issues.
+    pub fn spawn_release_version(
+        &self,
+        client: &JiraClient,
+        version_id: String,
+        release_date: String,
+        move_to: Option<String>,
+    ) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .release_version(&version_id, &release_date, move_to.as_deref())
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::VersionReleased { version_id, result });
+        });
+    }
+
     /// Spawn a task to fetch priorities.
     pub fn spawn_fetch_priorities(&self, client: &JiraClient) {
         let tx = self.tx.clone();
@@ -559,6 +823,83 @@ impl TaskSpawner {
         });
     }
 
+    /// Spawn a task to search issues for the go to issue finder.
+    pub fn spawn_search_issues_for_go_to(&self, client: &JiraClient, query: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .search_issues_for_picker(&query, None)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::GoToIssueResults { query, result });
+        });
+    }
+
+    /// Spawn a task to read every cached issue for the go to issue finder.
+    ///
+    /// This touches every cache file, so it runs on the blocking thread pool
+    /// instead of the event loop.
+    pub fn spawn_load_cached_issues(&self, cache: CacheManager) {
+        let tx = self.tx.clone();
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(ApiMessage::CachedIssuesLoaded(cache.cached_issues()));
+        });
+    }
+
+    /// Spawn a task to search users for an @mention picker.
+    pub fn spawn_search_users_for_mention(&self, client: &JiraClient, query: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .search_users(&query, 20)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::MentionUsersFetched { query, result });
+        });
+    }
+
+    /// Spawn a task to fetch the JQL autocomplete data.
+    pub fn spawn_fetch_jql_autocomplete(&self, client: &JiraClient) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_jql_autocomplete_data()
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::JqlAutocompleteFetched(result));
+        });
+    }
+
+    /// Spawn a task to fetch suggested values for a JQL field.
+    pub fn spawn_fetch_jql_suggestions(&self, client: &JiraClient, field: String, value: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_jql_suggestions(&field, &value)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::JqlSuggestionsFetched {
+                field,
+                value,
+                result,
+            });
+        });
+    }
+
+    /// Spawn a task to validate a JQL query.
+    pub fn spawn_validate_jql(&self, client: &JiraClient, query: String) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client.parse_jql(&query).await.map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::JqlValidated { query, result });
+        });
+    }
+
     /// Spawn a task to fetch recent issues for the link picker.
     pub fn spawn_fetch_recent_issues_for_link(&self, client: &JiraClient, exclude_key: String) {
         let tx = self.tx.clone();
@@ -642,6 +983,47 @@ impl TaskSpawner {
         });
     }
 
+    /// Spawn a task to copy links and subtasks to a cloned issue.
+    ///
+    /// Links are (link type name, other issue key, whether the clone is the
+    /// outward issue); subtasks are fully built create requests.
+    pub fn spawn_copy_to_clone(
+        &self,
+        client: &JiraClient,
+        issue_key: String,
+        links: Vec<(String, String, bool)>,
+        subtasks: Vec<CreateIssueRequest>,
+    ) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let mut failures = Vec::new();
+            for (link_type_name, other_key, is_outward) in links {
+                let (outward_key, inward_key) = if is_outward {
+                    (issue_key.as_str(), other_key.as_str())
+                } else {
+                    (other_key.as_str(), issue_key.as_str())
+                };
+                if let Err(e) = client
+                    .create_issue_link(&link_type_name, outward_key, inward_key)
+                    .await
+                {
+                    failures.push(format!("link to {}: {}", other_key, e));
+                }
+            }
+            for request in subtasks {
+                let summary = request.fields.summary.clone();
+                if let Err(e) = client.create_issue(request).await {
+                    failures.push(format!("subtask '{}': {}", summary, e));
+                }
+            }
+            let _ = tx.send(ApiMessage::CopiedToClone {
+                issue_key,
+                failures,
+            });
+        });
+    }
+
     /// Spawn a task to fetch issue types for a project.
     pub fn spawn_fetch_issue_types(&self, client: &JiraClient, project_key: String) {
         let tx = self.tx.clone();
@@ -654,6 +1036,28 @@ impl TaskSpawner {
             let _ = tx.send(ApiMessage::IssueTypesFetched(result));
         });
     }
+
+    /// Spawn a task to fetch the create fields of a project's issue type.
+    pub fn spawn_fetch_create_fields(
+        &self,
+        client: &JiraClient,
+        project_key: String,
+        issue_type_id: String,
+    ) {
+        let tx = self.tx.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_create_fields(&project_key, &issue_type_id)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(ApiMessage::CreateFieldsFetched {
+                project_key,
+                issue_type_id,
+                result,
+            });
+        });
+    }
 }
 
 /// Create a new task channel and spawner.
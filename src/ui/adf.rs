@@ -0,0 +1,1253 @@
+//! Rich rendering of Atlassian Document Format (ADF) content.
+//!
+//! Converts descriptions and comments into styled, pre-wrapped ratatui lines.
+//! Block nodes (lists, tables, code blocks, panels, quotes) keep their
+//! indentation on wrapped lines, inline nodes (mentions, dates, status
+//! lozenges, emoji) get their own styling, and links are numbered and listed
+//! as references at the end of the document so they can be opened by number.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use serde_json::Value;
+
+use crate::api::adf::{attr, attr_str, content, marks, node_type};
+use crate::api::types::AtlassianDoc;
+use crate::ui::theme::{parse_color, Theme};
+
+/// Rendered ADF content.
+#[derive(Debug, Clone, Default)]
+pub struct RenderedDoc {
+    /// The styled lines, already wrapped to the requested width.
+    pub lines: Vec<Line<'static>>,
+    /// Link targets in reference order (`[1]` is the first entry).
+    pub links: Vec<String>,
+}
+
+/// Render an ADF document to styled lines.
+///
+/// Lines are wrapped to `width` columns; a width of zero disables wrapping.
+pub fn render_adf(doc: &AtlassianDoc, theme: &Theme, width: usize) -> RenderedDoc {
+    let mut renderer = Renderer::new(theme, width);
+    renderer.blocks(&doc.content);
+    renderer.finish()
+}
+
+/// Get the link targets of an ADF document, numbered as in [`render_adf`].
+pub fn link_targets(doc: &AtlassianDoc) -> Vec<String> {
+    render_adf(doc, &Theme::dark(), 0).links
+}
+
+// ============================================================================
+// Renderer
+// ============================================================================
+
+/// Prefix added to lines inside a container block (list item, quote, panel).
+struct Prefix {
+    /// Spans for the first line of the container.
+    first: Vec<Span<'static>>,
+    /// Spans for every following line.
+    rest: Vec<Span<'static>>,
+    /// Whether the first line has been emitted.
+    used: bool,
+}
+
+impl Prefix {
+    fn uniform(text: &str, style: Style) -> Self {
+        let span = Span::styled(text.to_string(), style);
+        Self {
+            first: vec![span.clone()],
+            rest: vec![span],
+            used: false,
+        }
+    }
+
+    fn marker(marker: String, style: Style) -> Self {
+        let indent = " ".repeat(Span::raw(marker.as_str()).width());
+        Self {
+            first: vec![Span::styled(marker, style)],
+            rest: vec![Span::raw(indent)],
+            used: false,
+        }
+    }
+}
+
+struct Renderer<'a> {
+    theme: &'a Theme,
+    width: usize,
+    lines: Vec<Line<'static>>,
+    current: Vec<Span<'static>>,
+    prefixes: Vec<Prefix>,
+    links: Vec<String>,
+    /// Reference number of the link whose text is being emitted.
+    open_link: Option<usize>,
+    /// Nesting depth of bullet and ordered lists.
+    list_depth: usize,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(theme: &'a Theme, width: usize) -> Self {
+        Self {
+            theme,
+            width,
+            lines: Vec::new(),
+            current: Vec::new(),
+            prefixes: Vec::new(),
+            links: Vec::new(),
+            open_link: None,
+            list_depth: 0,
+        }
+    }
+
+    fn finish(mut self) -> RenderedDoc {
+        if !self.current.is_empty() {
+            self.flush();
+        }
+
+        // Drop trailing blank lines left by empty paragraphs
+        while self.lines.last().is_some_and(|line| line.width() == 0) {
+            self.lines.pop();
+        }
+
+        if !self.links.is_empty() {
+            let t = self.theme;
+            self.lines.push(Line::raw(""));
+            for (i, url) in self.links.iter().enumerate() {
+                // Reference lines are never wrapped so terminals can detect the URL
+                self.lines.push(Line::from(vec![
+                    Span::styled(format!("[{}] ", i + 1), Style::default().fg(t.accent)),
+                    Span::styled(
+                        url.clone(),
+                        Style::default()
+                            .fg(t.info)
+                            .add_modifier(Modifier::UNDERLINED),
+                    ),
+                ]));
+            }
+        }
+
+        RenderedDoc {
+            lines: self.lines,
+            links: self.links,
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Line assembly
+    // ------------------------------------------------------------------------
+
+    /// Take the prefix spans for the next line and its continuation lines.
+    fn take_prefix(&mut self) -> (Vec<Span<'static>>, Vec<Span<'static>>) {
+        let mut first = Vec::new();
+        let mut rest = Vec::new();
+        for prefix in &mut self.prefixes {
+            if prefix.used {
+                first.extend(prefix.rest.iter().cloned());
+            } else {
+                first.extend(prefix.first.iter().cloned());
+                prefix.used = true;
+            }
+            rest.extend(prefix.rest.iter().cloned());
+        }
+        (first, rest)
+    }
+
+    /// Emit the current line, wrapping it to the available width.
+    fn flush(&mut self) {
+        self.close_link();
+        let spans = std::mem::take(&mut self.current);
+        let (first, rest) = self.take_prefix();
+        let prefix_width: usize = first.iter().map(|s| s.width()).sum();
+        let available = if self.width == 0 {
+            usize::MAX
+        } else {
+            self.width.saturating_sub(prefix_width).max(1)
+        };
+
+        for (i, row) in wrap_spans(spans, available).into_iter().enumerate() {
+            let mut line = if i == 0 { first.clone() } else { rest.clone() };
+            line.extend(row);
+            self.lines.push(Line::from(line));
+        }
+    }
+
+    /// Emit a line as-is, without wrapping.
+    fn push_raw(&mut self, spans: Vec<Span<'static>>) {
+        let (mut line, _) = self.take_prefix();
+        line.extend(spans);
+        self.lines.push(Line::from(line));
+    }
+
+    /// Emit the current line if it has content.
+    fn end_block(&mut self) {
+        if !self.current.is_empty() || self.open_link.is_some() {
+            self.flush();
+        }
+    }
+
+    /// Register a link target, returning its reference number.
+    fn link_ref(&mut self, url: &str) -> usize {
+        match self.links.iter().position(|link| link == url) {
+            Some(index) => index + 1,
+            None => {
+                self.links.push(url.to_string());
+                self.links.len()
+            }
+        }
+    }
+
+    /// Emit the reference marker for the link being written, if any.
+    fn close_link(&mut self) {
+        if let Some(number) = self.open_link.take() {
+            self.current.push(Span::styled(
+                format!("[{}]", number),
+                Style::default().fg(self.theme.accent),
+            ));
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Block nodes
+    // ------------------------------------------------------------------------
+
+    fn blocks(&mut self, nodes: &[Value]) {
+        for node in nodes {
+            self.block(node);
+        }
+    }
+
+    fn block(&mut self, node: &Value) {
+        let t = self.theme;
+        match node_type(node) {
+            "paragraph" => {
+                self.inlines(content(node), Style::default());
+                self.flush();
+            }
+            "heading" => {
+                let level = attr(node, "level").and_then(Value::as_u64).unwrap_or(1);
+                let mut style = Style::default().fg(t.accent).add_modifier(Modifier::BOLD);
+                if level == 1 {
+                    style = style.add_modifier(Modifier::UNDERLINED);
+                }
+                self.inlines(content(node), style);
+                self.flush();
+            }
+            "bulletList" | "orderedList" => self.list(node),
+            "taskList" | "decisionList" => {
+                for item in content(node) {
+                    self.block(item);
+                }
+            }
+            "taskItem" => {
+                let done = attr_str(node, "state") == Some("DONE");
+                let (marker, color) = if done {
+                    ("☑ ", t.success)
+                } else {
+                    ("☐ ", t.muted)
+                };
+                self.item_with_inlines(node, marker, color);
+            }
+            "decisionItem" => self.item_with_inlines(node, "◆ ", t.accent),
+            "codeBlock" => self.code_block(node),
+            "blockquote" => {
+                let style = Style::default().fg(t.muted);
+                self.prefixes.push(Prefix::uniform("│ ", style));
+                self.blocks(content(node));
+                self.end_block();
+                self.prefixes.pop();
+            }
+            "panel" => self.panel(node),
+            "expand" | "nestedExpand" => {
+                let title = attr_str(node, "title").unwrap_or("").to_string();
+                let title = if title.is_empty() {
+                    "Details".to_string()
+                } else {
+                    title
+                };
+                self.current.push(Span::styled(
+                    format!("▾ {}", title),
+                    Style::default().fg(t.fg).add_modifier(Modifier::BOLD),
+                ));
+                self.flush();
+                self.prefixes.push(Prefix::uniform("  ", Style::default()));
+                self.blocks(content(node));
+                self.end_block();
+                self.prefixes.pop();
+            }
+            "rule" => {
+                let width = if self.width == 0 {
+                    40
+                } else {
+                    self.width.min(80)
+                };
+                self.push_raw(vec![Span::styled(
+                    "─".repeat(width),
+                    Style::default().fg(t.dim),
+                )]);
+            }
+            "table" => self.table(node),
+            "mediaSingle" | "mediaGroup" => {
+                for media in content(node) {
+                    self.media(media);
+                    self.flush();
+                }
+            }
+            "media" => {
+                self.media(node);
+                self.flush();
+            }
+            "blockCard" | "embedCard" => {
+                if let Some(url) = attr_str(node, "url") {
+                    let url = url.to_string();
+                    self.card(&url);
+                    self.flush();
+                }
+            }
+            "text" | "hardBreak" | "mention" | "emoji" | "date" | "status" | "inlineCard" => {
+                self.inline(node, Style::default());
+            }
+            _ => {
+                self.blocks(content(node));
+                self.end_block();
+            }
+        }
+    }
+
+    fn list(&mut self, node: &Value) {
+        let t = self.theme;
+        let ordered = node_type(node) == "orderedList";
+        let start = attr(node, "order").and_then(Value::as_u64).unwrap_or(1) as usize;
+        let depth = self.list_depth;
+        let marker_style = Style::default().fg(t.accent);
+
+        self.list_depth += 1;
+        for (i, item) in content(node).iter().enumerate() {
+            let marker = if ordered {
+                format!("{}. ", list_number(start + i, depth))
+            } else {
+                format!("{} ", ["•", "◦", "▪"][depth % 3])
+            };
+            self.prefixes.push(Prefix::marker(marker, marker_style));
+            self.blocks(content(item));
+            self.end_block();
+            if self.prefixes.last().is_some_and(|p| !p.used) {
+                // Empty item: still show its marker
+                self.flush();
+            }
+            self.prefixes.pop();
+        }
+        self.list_depth -= 1;
+    }
+
+    /// Render a task or decision item, whose content is inline.
+    fn item_with_inlines(&mut self, node: &Value, marker: &str, color: Color) {
+        self.prefixes.push(Prefix::marker(
+            marker.to_string(),
+            Style::default().fg(color),
+        ));
+        for child in content(node) {
+            match node_type(child) {
+                "taskList" | "decisionList" => {
+                    self.end_block();
+                    self.block(child);
+                }
+                _ => self.inline(child, Style::default()),
+            }
+        }
+        self.end_block();
+        if self.prefixes.last().is_some_and(|p| !p.used) {
+            self.flush();
+        }
+        self.prefixes.pop();
+    }
+
+    fn code_block(&mut self, node: &Value) {
+        let t = self.theme;
+        let language = attr_str(node, "language").unwrap_or("").to_string();
+        let code: String = content(node)
+            .iter()
+            .filter_map(|n| n.get("text").and_then(Value::as_str))
+            .collect();
+
+        self.prefixes
+            .push(Prefix::uniform("▏ ", Style::default().fg(t.dim)));
+        if !language.is_empty() {
+            self.current.push(Span::styled(
+                language.clone(),
+                Style::default().fg(t.muted).add_modifier(Modifier::ITALIC),
+            ));
+            self.flush();
+        }
+        let code = code.strip_suffix('\n').unwrap_or(&code);
+        for line in code.split('\n') {
+            self.current = tint_code(line, &language, t);
+            self.flush();
+        }
+        self.prefixes.pop();
+    }
+
+    fn panel(&mut self, node: &Value) {
+        let t = self.theme;
+        let panel_type = attr_str(node, "panelType").unwrap_or("info");
+        let (label, color) = match panel_type {
+            "note" => ("Note", t.accent),
+            "warning" => ("Warning", t.warning),
+            "error" => ("Error", t.error),
+            "success" => ("Success", t.success),
+            _ => ("Info", t.info),
+        };
+        let bar = Style::default().fg(color);
+
+        self.prefixes.push(Prefix::uniform("┃ ", bar));
+        self.current
+            .push(Span::styled(label, bar.add_modifier(Modifier::BOLD)));
+        self.flush();
+        self.blocks(content(node));
+        self.end_block();
+        self.prefixes.pop();
+    }
+
+    fn table(&mut self, node: &Value) {
+        let t = self.theme;
+        let rows: Vec<(bool, Vec<String>)> = content(node)
+            .iter()
+            .filter(|row| node_type(row) == "tableRow")
+            .map(|row| {
+                let cells = content(row);
+                let header =
+                    !cells.is_empty() && cells.iter().all(|c| node_type(c) == "tableHeader");
+                (header, cells.iter().map(plain_text).collect())
+            })
+            .collect();
+        let columns = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        let mut widths = vec![1usize; columns];
+        for (_, cells) in &rows {
+            for (i, cell) in cells.iter().enumerate() {
+                widths[i] = widths[i].max(text_width(cell));
+            }
+        }
+
+        // Shrink the widest columns until the table fits
+        if self.width > 0 {
+            let prefix_width: usize = self
+                .prefixes
+                .iter()
+                .flat_map(|p| p.rest.iter())
+                .map(|s| s.width())
+                .sum();
+            let available = self
+                .width
+                .saturating_sub(prefix_width + columns * 3 + 1)
+                .max(columns);
+            while widths.iter().sum::<usize>() > available {
+                let widest = (0..columns).max_by_key(|&i| widths[i]).unwrap_or(0);
+                if widths[widest] <= 1 {
+                    break;
+                }
+                widths[widest] -= 1;
+            }
+        }
+
+        let border = Style::default().fg(t.border);
+        let rule = |left: &str, mid: &str, right: &str| -> Vec<Span<'static>> {
+            let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+            vec![Span::styled(
+                format!("{}{}{}", left, segments.join(mid), right),
+                border,
+            )]
+        };
+
+        let top = rule("┌", "┬", "┐");
+        self.push_raw(top);
+        for (index, (header, cells)) in rows.iter().enumerate() {
+            let mut spans = vec![Span::styled("│", border)];
+            for (i, width) in widths.iter().enumerate() {
+                let cell = cells.get(i).map(String::as_str).unwrap_or("");
+                let text = fit(cell, *width);
+                let style = if *header {
+                    Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(t.fg)
+                };
+                spans.push(Span::styled(format!(" {} ", text), style));
+                spans.push(Span::styled("│", border));
+            }
+            self.push_raw(spans);
+            if *header && index + 1 < rows.len() {
+                let separator = rule("├", "┼", "┤");
+                self.push_raw(separator);
+            }
+        }
+        let bottom = rule("└", "┴", "┘");
+        self.push_raw(bottom);
+    }
+
+    fn media(&mut self, node: &Value) {
+        let t = self.theme;
+        let name = attr_str(node, "alt")
+            .or_else(|| attr_str(node, "filename"))
+            .filter(|name| !name.is_empty())
+            .unwrap_or("attachment")
+            .to_string();
+        self.current.push(Span::styled(
+            format!("[▣ {}]", name),
+            Style::default().fg(t.muted),
+        ));
+        if let Some(url) = attr_str(node, "url") {
+            let number = self.link_ref(url);
+            self.current.push(Span::styled(
+                format!("[{}]", number),
+                Style::default().fg(t.accent),
+            ));
+        }
+    }
+
+    fn card(&mut self, url: &str) {
+        let t = self.theme;
+        let number = self.link_ref(url);
+        self.current.push(Span::styled(
+            card_title(url),
+            Style::default()
+                .fg(t.info)
+                .add_modifier(Modifier::UNDERLINED),
+        ));
+        self.current.push(Span::styled(
+            format!("[{}]", number),
+            Style::default().fg(t.accent),
+        ));
+    }
+
+    // ------------------------------------------------------------------------
+    // Inline nodes
+    // ------------------------------------------------------------------------
+
+    fn inlines(&mut self, nodes: &[Value], base: Style) {
+        for node in nodes {
+            self.inline(node, base);
+        }
+    }
+
+    fn inline(&mut self, node: &Value, base: Style) {
+        let t = self.theme;
+        let node_kind = node_type(node);
+        if node_kind != "text" {
+            self.close_link();
+        }
+
+        match node_kind {
+            "text" => {
+                let text = node.get("text").and_then(Value::as_str).unwrap_or("");
+                let (style, link) = apply_marks(node, base, t);
+                match link {
+                    Some(url) => {
+                        let number = self.link_ref(url);
+                        if self.open_link != Some(number) {
+                            self.close_link();
+                            self.open_link = Some(number);
+                        }
+                    }
+                    None => self.close_link(),
+                }
+                // Text inside code blocks and hard-wrapped content may contain newlines
+                let mut parts = text.split('\n').peekable();
+                while let Some(part) = parts.next() {
+                    if !part.is_empty() {
+                        self.current.push(Span::styled(part.to_string(), style));
+                    }
+                    if parts.peek().is_some() {
+                        self.flush();
+                    }
+                }
+            }
+            "hardBreak" => self.flush(),
+            "mention" => {
+                let name = attr_str(node, "text").unwrap_or("unknown");
+                let name = name.trim_start_matches('@');
+                self.current.push(Span::styled(
+                    format!("@{}", name),
+                    Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+                ));
+            }
+            "emoji" => {
+                let text = attr_str(node, "text")
+                    .filter(|s| !s.is_empty())
+                    .or_else(|| attr_str(node, "shortName"))
+                    .unwrap_or("");
+                self.current.push(Span::styled(text.to_string(), base));
+            }
+            "date" => {
+                let date = attr(node, "timestamp")
+                    .and_then(|ts| match ts {
+                        Value::String(s) => s.parse::<i64>().ok(),
+                        other => other.as_i64(),
+                    })
+                    .map(format_timestamp)
+                    .unwrap_or_else(|| "date".to_string());
+                self.current.push(Span::styled(
+                    format!(" {} ", date),
+                    Style::default().fg(t.selection_fg).bg(t.selection_bg),
+                ));
+            }
+            "status" => {
+                let text = attr_str(node, "text").unwrap_or("").to_uppercase();
+                let bg = status_color(attr_str(node, "color").unwrap_or("neutral"), t);
+                self.current.push(Span::styled(
+                    format!(" {} ", text),
+                    Style::default()
+                        .fg(t.selection_fg)
+                        .bg(bg)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            "inlineCard" => {
+                if let Some(url) = attr_str(node, "url") {
+                    let url = url.to_string();
+                    self.card(&url);
+                }
+            }
+            "mediaInline" => self.media(node),
+            "placeholder" => {}
+            _ => {
+                if let Some(text) = attr_str(node, "text") {
+                    self.current.push(Span::styled(text.to_string(), base));
+                } else {
+                    self.inlines(content(node), base);
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Apply a text node's marks to a base style, returning the link target if any.
+fn apply_marks<'v>(node: &'v Value, base: Style, t: &Theme) -> (Style, Option<&'v str>) {
+    let mut style = base;
+    let mut link = None;
+
+    for mark in marks(node) {
+        match node_type(mark) {
+            "strong" => style = style.add_modifier(Modifier::BOLD),
+            "em" => style = style.add_modifier(Modifier::ITALIC),
+            "strike" => style = style.add_modifier(Modifier::CROSSED_OUT),
+            "underline" => style = style.add_modifier(Modifier::UNDERLINED),
+            "code" => style = style.fg(t.warning),
+            "textColor" => {
+                if let Some(color) = attr_str(mark, "color").and_then(parse_color) {
+                    style = style.fg(color);
+                }
+            }
+            "link" => {
+                link = attr_str(mark, "href");
+                style = style.fg(t.info).add_modifier(Modifier::UNDERLINED);
+            }
+            _ => {}
+        }
+    }
+    (style, link)
+}
+
+/// Get the background color of a status lozenge.
+fn status_color(color: &str, t: &Theme) -> Color {
+    match color {
+        "blue" => t.status_new,
+        "yellow" => t.status_in_progress,
+        "green" => t.status_done,
+        "red" => t.error,
+        "purple" => t.component_bg,
+        _ => t.dim,
+    }
+}
+
+/// Format the number of an ordered list item for the given nesting depth.
+///
+/// Alternates between numbers and letters so nested levels are distinguishable.
+fn list_number(n: usize, depth: usize) -> String {
+    if depth % 2 == 1 {
+        let mut n = n;
+        let mut letters = Vec::new();
+        while n > 0 {
+            n -= 1;
+            letters.push((b'a' + (n % 26) as u8) as char);
+            n /= 26;
+        }
+        letters.iter().rev().collect()
+    } else {
+        n.to_string()
+    }
+}
+
+/// Get a short title for a smart link: the issue key for JIRA issue links,
+/// otherwise the URL itself.
+fn card_title(url: &str) -> String {
+    url.split("/browse/")
+        .nth(1)
+        .map(|rest| rest.split(['?', '#', '/']).next().unwrap_or(rest))
+        .filter(|key| !key.is_empty())
+        .unwrap_or(url)
+        .to_string()
+}
+
+/// Format a Unix timestamp in milliseconds as `YYYY-MM-DD`.
+fn format_timestamp(millis: i64) -> String {
+    let days = millis.div_euclid(86_400_000);
+    // Civil-from-days conversion (proleptic Gregorian calendar)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Extract a node's text on a single line, for table cells.
+fn plain_text(node: &Value) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    collect_text(node, &mut parts);
+    parts
+        .join("")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn collect_text(node: &Value, parts: &mut Vec<String>) {
+    match node_type(node) {
+        "text" => {
+            if let Some(text) = node.get("text").and_then(Value::as_str) {
+                parts.push(text.to_string());
+            }
+        }
+        "mention" => {
+            let name = attr_str(node, "text").unwrap_or("unknown");
+            parts.push(format!("@{}", name.trim_start_matches('@')));
+        }
+        "emoji" => {
+            if let Some(text) = attr_str(node, "text").or_else(|| attr_str(node, "shortName")) {
+                parts.push(text.to_string());
+            }
+        }
+        "status" => {
+            if let Some(text) = attr_str(node, "text") {
+                parts.push(format!("[{}]", text.to_uppercase()));
+            }
+        }
+        "date" => {
+            if let Some(millis) = attr_str(node, "timestamp").and_then(|s| s.parse().ok()) {
+                parts.push(format_timestamp(millis));
+            }
+        }
+        "inlineCard" => {
+            if let Some(url) = attr_str(node, "url") {
+                parts.push(card_title(url));
+            }
+        }
+        "hardBreak" => parts.push(" ".to_string()),
+        kind => {
+            for child in content(node) {
+                collect_text(child, parts);
+            }
+            if kind == "paragraph" {
+                parts.push(" ".to_string());
+            }
+        }
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+fn char_width(c: char) -> usize {
+    let mut buf = [0u8; 4];
+    Span::raw(&*c.encode_utf8(&mut buf)).width()
+}
+
+/// Pad or truncate text to exactly `width` columns.
+fn fit(text: &str, width: usize) -> String {
+    if text_width(text) <= width {
+        let padding = width - text_width(text);
+        return format!("{}{}", text, " ".repeat(padding));
+    }
+    let mut result = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = char_width(c);
+        if used + w + 1 > width {
+            break;
+        }
+        result.push(c);
+        used += w;
+    }
+    result.push('…');
+    used += 1;
+    result.push_str(&" ".repeat(width.saturating_sub(used)));
+    result
+}
+
+/// Word-wrap styled spans to the given width.
+///
+/// Always returns at least one (possibly empty) row.
+fn wrap_spans(spans: Vec<Span<'static>>, width: usize) -> Vec<Vec<Span<'static>>> {
+    let mut rows: Vec<Vec<(char, Style)>> = Vec::new();
+    let mut row: Vec<(char, Style)> = Vec::new();
+    let mut row_width = 0;
+    let mut last_space: Option<usize> = None;
+
+    for span in &spans {
+        for c in span.content.chars() {
+            let w = char_width(c);
+            if row_width + w > width && !row.is_empty() {
+                if c == ' ' {
+                    // Break at this space and drop it
+                    rows.push(std::mem::take(&mut row));
+                    row_width = 0;
+                    last_space = None;
+                    continue;
+                }
+                match last_space.take() {
+                    Some(index) => {
+                        let tail = row.split_off(index + 1);
+                        row.pop();
+                        rows.push(std::mem::replace(&mut row, tail));
+                        row_width = row.iter().map(|(c, _)| char_width(*c)).sum();
+                    }
+                    None => {
+                        rows.push(std::mem::take(&mut row));
+                        row_width = 0;
+                    }
+                }
+                if row_width + w > width && !row.is_empty() {
+                    rows.push(std::mem::take(&mut row));
+                    row_width = 0;
+                }
+            }
+            if c == ' ' {
+                last_space = Some(row.len());
+            }
+            row.push((c, span.style));
+            row_width += w;
+        }
+    }
+    rows.push(row);
+
+    rows.into_iter()
+        .map(|row| {
+            let mut spans: Vec<Span<'static>> = Vec::new();
+            let mut text = String::new();
+            let mut style: Option<Style> = None;
+            for (c, s) in row {
+                if style.is_some_and(|current| current != s) {
+                    spans.push(Span::styled(std::mem::take(&mut text), style.unwrap()));
+                }
+                style = Some(s);
+                text.push(c);
+            }
+            if let Some(style) = style {
+                spans.push(Span::styled(text, style));
+            }
+            spans
+        })
+        .collect()
+}
+
+// ============================================================================
+// Code tinting
+// ============================================================================
+
+const KEYWORDS: &[&str] = &[
+    "as",
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "def",
+    "default",
+    "do",
+    "elif",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "final",
+    "fn",
+    "for",
+    "from",
+    "func",
+    "function",
+    "if",
+    "impl",
+    "import",
+    "in",
+    "interface",
+    "let",
+    "loop",
+    "match",
+    "mod",
+    "mut",
+    "new",
+    "nil",
+    "None",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "pub",
+    "public",
+    "return",
+    "self",
+    "Self",
+    "static",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "trait",
+    "True",
+    "true",
+    "False",
+    "try",
+    "type",
+    "use",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Get the line comment token for a code block language.
+fn comment_token(language: &str) -> &'static str {
+    match language.to_lowercase().as_str() {
+        "python" | "py" | "ruby" | "rb" | "shell" | "bash" | "sh" | "zsh" | "yaml" | "yml"
+        | "toml" | "perl" | "r" | "powershell" | "dockerfile" | "makefile" => "#",
+        "sql" | "lua" | "haskell" => "--",
+        _ => "//",
+    }
+}
+
+/// Split a line of code into spans tinted by token kind.
+fn tint_code(line: &str, language: &str, t: &Theme) -> Vec<Span<'static>> {
+    let plain = Style::default().fg(t.fg);
+    let keyword = Style::default().fg(t.accent);
+    let string = Style::default().fg(t.success);
+    let number = Style::default().fg(t.warning);
+    let comment = Style::default().fg(t.dim).add_modifier(Modifier::ITALIC);
+    let comment_start = comment_token(language);
+
+    let chars: Vec<char> = line.chars().collect();
+    let mut spans = Vec::new();
+    let mut plain_text = String::new();
+    let mut i = 0;
+
+    let flush_plain = |plain_text: &mut String, spans: &mut Vec<Span<'static>>| {
+        if !plain_text.is_empty() {
+            spans.push(Span::styled(std::mem::take(plain_text), plain));
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let rest: String = chars[i..].iter().collect();
+
+        if rest.starts_with(comment_start) {
+            flush_plain(&mut plain_text, &mut spans);
+            spans.push(Span::styled(rest, comment));
+            break;
+        }
+
+        if c == '"' || c == '\'' || c == '`' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            flush_plain(&mut plain_text, &mut spans);
+            spans.push(Span::styled(
+                chars[start..i].iter().collect::<String>(),
+                string,
+            ));
+            continue;
+        }
+
+        if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let style = if c.is_ascii_digit() {
+                Some(number)
+            } else if KEYWORDS.contains(&word.as_str()) {
+                Some(keyword)
+            } else {
+                None
+            };
+            match style {
+                Some(style) => {
+                    flush_plain(&mut plain_text, &mut spans);
+                    spans.push(Span::styled(word, style));
+                }
+                None => plain_text.push_str(&word),
+            }
+            continue;
+        }
+
+        plain_text.push(c);
+        i += 1;
+    }
+    flush_plain(&mut plain_text, &mut spans);
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn doc(content: Value) -> AtlassianDoc {
+        AtlassianDoc {
+            doc_type: "doc".to_string(),
+            version: Some(1),
+            content: content.as_array().cloned().unwrap_or_default(),
+        }
+    }
+
+    fn text_lines(rendered: &RenderedDoc) -> Vec<String> {
+        rendered
+            .lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    fn paragraph(text: &str) -> Value {
+        json!({"type": "paragraph", "content": [{"type": "text", "text": text}]})
+    }
+
+    #[test]
+    fn test_nested_lists_are_numbered_and_indented() {
+        let d = doc(json!([{
+            "type": "orderedList",
+            "content": [
+                {"type": "listItem", "content": [
+                    paragraph("First"),
+                    {"type": "orderedList", "content": [
+                        {"type": "listItem", "content": [paragraph("Nested")]},
+                        {"type": "listItem", "content": [paragraph("Again")]}
+                    ]}
+                ]},
+                {"type": "listItem", "content": [
+                    paragraph("Second"),
+                    {"type": "bulletList", "content": [
+                        {"type": "listItem", "content": [paragraph("Point")]}
+                    ]}
+                ]}
+            ]
+        }]));
+
+        let rendered = render_adf(&d, &Theme::dark(), 80);
+        assert_eq!(
+            text_lines(&rendered),
+            vec![
+                "1. First",
+                "   a. Nested",
+                "   b. Again",
+                "2. Second",
+                "   ◦ Point",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrapped_list_item_keeps_indent() {
+        let d = doc(json!([{
+            "type": "bulletList",
+            "content": [{"type": "listItem", "content": [paragraph("one two three four")]}]
+        }]));
+
+        let rendered = render_adf(&d, &Theme::dark(), 11);
+        assert_eq!(
+            text_lines(&rendered),
+            vec!["• one two", "  three", "  four"]
+        );
+    }
+
+    #[test]
+    fn test_table_has_borders_and_header_separator() {
+        let cell = |kind: &str, text: &str| json!({"type": kind, "content": [paragraph(text)]});
+        let d = doc(json!([{
+            "type": "table",
+            "content": [
+                {"type": "tableRow", "content": [cell("tableHeader", "Name"), cell("tableHeader", "Value")]},
+                {"type": "tableRow", "content": [cell("tableCell", "a"), cell("tableCell", "1")]}
+            ]
+        }]));
+
+        let rendered = render_adf(&d, &Theme::dark(), 80);
+        assert_eq!(
+            text_lines(&rendered),
+            vec![
+                "┌──────┬───────┐",
+                "│ Name │ Value │",
+                "├──────┼───────┤",
+                "│ a    │ 1     │",
+                "└──────┴───────┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_shrinks_to_fit() {
+        let cell = |text: &str| json!({"type": "tableCell", "content": [paragraph(text)]});
+        let d = doc(json!([{
+            "type": "table",
+            "content": [{"type": "tableRow", "content": [cell("a very long cell value"), cell("b")]}]
+        }]));
+
+        let rendered = render_adf(&d, &Theme::dark(), 20);
+        for line in &rendered.lines {
+            assert!(line.width() <= 20);
+        }
+        assert!(text_lines(&rendered)[1].contains('…'));
+    }
+
+    #[test]
+    fn test_links_are_numbered_and_listed() {
+        let d = doc(json!([{
+            "type": "paragraph",
+            "content": [
+                {"type": "text", "text": "See "},
+                {"type": "text", "text": "docs", "marks": [{"type": "link", "attrs": {"href": "https://example.com/docs"}}]},
+                {"type": "text", "text": " and "},
+                {"type": "inlineCard", "attrs": {"url": "https://jira.example.com/browse/PROJ-7"}}
+            ]
+        }]));
+
+        let rendered = render_adf(&d, &Theme::dark(), 80);
+        assert_eq!(
+            text_lines(&rendered),
+            vec![
+                "See docs[1] and PROJ-7[2]",
+                "",
+                "[1] https://example.com/docs",
+                "[2] https://jira.example.com/browse/PROJ-7",
+            ]
+        );
+        assert_eq!(link_targets(&d), rendered.links);
+    }
+
+    #[test]
+    fn test_inline_nodes() {
+        let d = doc(json!([{
+            "type": "paragraph",
+            "content": [
+                {"type": "mention", "attrs": {"id": "1", "text": "@Jane Doe"}},
+                {"type": "text", "text": " "},
+                {"type": "status", "attrs": {"text": "In review", "color": "blue"}},
+                {"type": "text", "text": " "},
+                {"type": "date", "attrs": {"timestamp": "1705276800000"}},
+                {"type": "emoji", "attrs": {"shortName": ":smile:", "text": "😄"}}
+            ]
+        }]));
+
+        let theme = Theme::dark();
+        let rendered = render_adf(&d, &theme, 80);
+        assert_eq!(
+            text_lines(&rendered),
+            vec!["@Jane Doe  IN REVIEW   2024-01-15 😄"]
+        );
+        let status = &rendered.lines[0].spans[2];
+        assert_eq!(status.style.bg, Some(theme.status_new));
+    }
+
+    #[test]
+    fn test_panel_and_code_block() {
+        let d = doc(json!([
+            {"type": "panel", "attrs": {"panelType": "warning"}, "content": [paragraph("Careful")]},
+            {"type": "codeBlock", "attrs": {"language": "rust"}, "content": [
+                {"type": "text", "text": "let x = \"hi\"; // note\nfn main() {}"}
+            ]}
+        ]));
+
+        let theme = Theme::dark();
+        let rendered = render_adf(&d, &theme, 80);
+        assert_eq!(
+            text_lines(&rendered),
+            vec![
+                "┃ Warning",
+                "┃ Careful",
+                "▏ rust",
+                "▏ let x = \"hi\"; // note",
+                "▏ fn main() {}",
+            ]
+        );
+        assert_eq!(rendered.lines[0].spans[0].style.fg, Some(theme.warning));
+
+        let code = &rendered.lines[3].spans;
+        let styled = |text: &str| code.iter().find(|s| s.content == text).map(|s| s.style.fg);
+        assert_eq!(styled("let"), Some(Some(theme.accent)));
+        assert_eq!(styled("\"hi\""), Some(Some(theme.success)));
+        assert_eq!(styled("// note"), Some(Some(theme.dim)));
+    }
+
+    #[test]
+    fn test_marks_and_headings() {
+        let d = doc(json!([
+            {"type": "heading", "attrs": {"level": 2}, "content": [{"type": "text", "text": "Title"}]},
+            {"type": "paragraph", "content": [
+                {"type": "text", "text": "bold", "marks": [{"type": "strong"}]},
+                {"type": "text", "text": "gone", "marks": [{"type": "strike"}]}
+            ]}
+        ]));
+
+        let theme = Theme::dark();
+        let rendered = render_adf(&d, &theme, 80);
+        let heading = &rendered.lines[0].spans[0];
+        assert_eq!(heading.style.fg, Some(theme.accent));
+        assert!(heading.style.add_modifier.contains(Modifier::BOLD));
+        let body = &rendered.lines[1].spans;
+        assert!(body[0].style.add_modifier.contains(Modifier::BOLD));
+        assert!(body[1].style.add_modifier.contains(Modifier::CROSSED_OUT));
+    }
+
+    #[test]
+    fn test_text_color_mark() {
+        let d = doc(json!([
+            {"type": "paragraph", "content": [
+                {"type": "text", "text": "red", "marks": [{"type": "textColor", "attrs": {"color": "#ff5630"}}]},
+                {"type": "text", "text": "odd", "marks": [{"type": "textColor", "attrs": {"color": "#ff56é"}}]}
+            ]}
+        ]));
+
+        let rendered = render_adf(&d, &Theme::dark(), 80);
+        let spans = &rendered.lines[0].spans;
+        assert_eq!(spans[0].style.fg, Some(Color::Rgb(0xff, 0x56, 0x30)));
+        assert_eq!(spans[1].style.fg, None);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01");
+        assert_eq!(format_timestamp(951_782_400_000), "2000-02-29");
+        assert_eq!(format_timestamp(1_705_276_800_000), "2024-01-15");
+    }
+}
@@ -1,7 +1,8 @@
 //! Assignee picker component for user assignment.
 //!
 //! Displays assignable users for an issue and allows the user to select one
-//! to change the assignee.
+//! to change the assignee. Also used as a plain user picker, e.g. for adding
+//! watchers.
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
@@ -52,6 +53,10 @@ pub struct AssigneePicker {
     filtered_indices: Vec<usize>,
     /// Current input mode (Normal for navigation, Insert for typing).
     input_mode: InputMode,
+    /// Dialog title.
+    title: &'static str,
+    /// Whether the "Unassigned" option is offered.
+    allow_unassign: bool,
 }
 
 impl AssigneePicker {
@@ -66,6 +71,19 @@ impl AssigneePicker {
             search_query: String::new(),
             filtered_indices: Vec::new(),
             input_mode: InputMode::Normal,
+            title: " Change Assignee ",
+            allow_unassign: true,
+        }
+    }
+
+    /// Create a user picker without the "Unassigned" option.
+    ///
+    /// `current` passed to `show` is displayed as the current selection.
+    pub fn user_picker(title: &'static str) -> Self {
+        Self {
+            title,
+            allow_unassign: false,
+            ..Self::new()
         }
     }
 
@@ -137,9 +155,14 @@ impl AssigneePicker {
         self.selected = 0;
     }
 
+    /// Number of list entries before the users (the "Unassigned" option).
+    fn option_offset(&self) -> usize {
+        usize::from(self.allow_unassign)
+    }
+
     /// Get total number of selectable items (Unassigned + filtered users).
     fn selectable_count(&self) -> usize {
-        1 + self.filtered_indices.len() // 1 for "Unassigned" option
+        self.option_offset() + self.filtered_indices.len()
     }
 
     /// Handle keyboard input.
@@ -248,12 +271,12 @@ impl AssigneePicker {
     /// Select the current item and close the picker.
     fn select_current(&mut self) -> Option<AssigneeAction> {
         self.hide();
-        if self.selected == 0 {
+        if self.allow_unassign && self.selected == 0 {
             // "Unassigned" selected
             Some(AssigneeAction::Unassign)
         } else {
             // Get the actual user from filtered indices
-            let filtered_idx = self.selected - 1;
+            let filtered_idx = self.selected - self.option_offset();
             if let Some(&user_idx) = self.filtered_indices.get(filtered_idx) {
                 if let Some(user) = self.users.get(user_idx) {
                     return Some(AssigneeAction::Select(
@@ -283,7 +306,7 @@ impl AssigneePicker {
 
         // Create the dialog block
         let block = Block::default()
-            .title(" Change Assignee ")
+            .title(self.title)
             .title_alignment(Alignment::Center)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan));
@@ -364,10 +387,14 @@ impl AssigneePicker {
             frame.render_widget(empty_text, chunks[2]);
         } else {
             // Build list items: "Unassigned" first, then filtered users
-            let mut items: Vec<ListItem> = Vec::with_capacity(1 + self.filtered_indices.len());
+            let mut items: Vec<ListItem> = Vec::with_capacity(self.selectable_count());
 
             // Add "Unassigned" option
-            items.push(ListItem::new("  Unassigned").style(Style::default().fg(Color::DarkGray)));
+            if self.allow_unassign {
+                items.push(
+                    ListItem::new("  Unassigned").style(Style::default().fg(Color::DarkGray)),
+                );
+            }
 
             // Add filtered users
             for &idx in &self.filtered_indices {
@@ -439,7 +466,6 @@ fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::api::types::AvatarUrls;
 
     fn create_test_user(account_id: &str, display_name: &str) -> User {
         User {
@@ -772,6 +798,38 @@ mod tests {
         assert_eq!(picker.filtered_indices.len(), 2); // Back to both Alices
     }
 
+    #[test]
+    fn test_user_picker_has_no_unassign_option() {
+        let mut picker = AssigneePicker::user_picker(" Add Watcher ");
+        let users = vec![
+            create_test_user("user1", "Alice"),
+            create_test_user("user2", "Bob"),
+        ];
+        picker.show(users, "Carol");
+        assert_eq!(picker.selectable_count(), 2);
+
+        // The first entry is the first user
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        let action = picker.handle_input(key);
+        assert_eq!(
+            action,
+            Some(AssigneeAction::Select(
+                "user1".to_string(),
+                "Alice".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_user_picker_empty_selects_nothing() {
+        let mut picker = AssigneePicker::user_picker(" Add Watcher ");
+        picker.show(Vec::new(), "");
+
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert!(picker.handle_input(key).is_none());
+        assert!(!picker.is_visible());
+    }
+
     #[test]
     fn test_default_impl() {
         let picker = AssigneePicker::default();
@@ -0,0 +1,272 @@
+//! Clone issue options component.
+//!
+//! This module provides the popup shown before the create issue form when
+//! cloning an issue that has links or subtasks, asking which of them should
+//! be copied to the clone.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Action returned from the clone options dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloneOptionsAction {
+    /// User confirmed which links and subtasks to copy.
+    Confirm {
+        /// Whether to copy the issue links.
+        links: bool,
+        /// Whether to copy the subtasks.
+        subtasks: bool,
+    },
+    /// User cancelled cloning.
+    Cancel,
+}
+
+/// A copy option shown in the dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CloneOption {
+    Links,
+    Subtasks,
+}
+
+/// A popup component for choosing what to copy when cloning an issue.
+#[derive(Debug, Default)]
+pub struct CloneOptionsDialog {
+    /// Key of the issue being cloned.
+    issue_key: String,
+    /// Number of links on the issue.
+    link_count: usize,
+    /// Number of subtasks on the issue.
+    subtask_count: usize,
+    /// Options shown, in order.
+    options: Vec<CloneOption>,
+    /// Whether to copy links.
+    copy_links: bool,
+    /// Whether to copy subtasks.
+    copy_subtasks: bool,
+    /// Currently selected option index.
+    selected: usize,
+    /// Whether the dialog is visible.
+    visible: bool,
+}
+
+impl CloneOptionsDialog {
+    /// Create a new clone options dialog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the dialog for an issue with the given number of links and subtasks.
+    ///
+    /// Only options with something to copy are shown, and none start checked.
+    pub fn show(&mut self, issue_key: &str, link_count: usize, subtask_count: usize) {
+        self.issue_key = issue_key.to_string();
+        self.link_count = link_count;
+        self.subtask_count = subtask_count;
+        self.options.clear();
+        if link_count > 0 {
+            self.options.push(CloneOption::Links);
+        }
+        if subtask_count > 0 {
+            self.options.push(CloneOption::Subtasks);
+        }
+        self.copy_links = false;
+        self.copy_subtasks = false;
+        self.selected = 0;
+        self.visible = true;
+    }
+
+    /// Hide the dialog.
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Check if the dialog is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Toggle the selected option.
+    fn toggle_selected(&mut self) {
+        match self.options.get(self.selected) {
+            Some(CloneOption::Links) => self.copy_links = !self.copy_links,
+            Some(CloneOption::Subtasks) => self.copy_subtasks = !self.copy_subtasks,
+            None => {}
+        }
+    }
+
+    /// Handle keyboard input.
+    ///
+    /// Returns an optional action when the user confirms or cancels.
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<CloneOptionsAction> {
+        match (key.code, key.modifiers) {
+            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => {
+                if self.selected + 1 < self.options.len() {
+                    self.selected += 1;
+                }
+                None
+            }
+            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => {
+                self.selected = self.selected.saturating_sub(1);
+                None
+            }
+            (KeyCode::Char(' '), KeyModifiers::NONE) => {
+                self.toggle_selected();
+                None
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                self.visible = false;
+                Some(CloneOptionsAction::Confirm {
+                    links: self.copy_links,
+                    subtasks: self.copy_subtasks,
+                })
+            }
+            (KeyCode::Esc, _) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                self.visible = false;
+                Some(CloneOptionsAction::Cancel)
+            }
+            _ => None,
+        }
+    }
+
+    /// Render the clone options dialog.
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let dialog_width = 44u16.min(area.width.saturating_sub(4));
+        // Height: border (2) + options + blank line + hint (1)
+        let dialog_height = (self.options.len() as u16 + 4).min(area.height.saturating_sub(4));
+        let dialog_area = centered_rect(area, dialog_width, dialog_height);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Clone {} ", self.issue_key),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let mut lines: Vec<Line> = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| {
+                let (checked, label) = match option {
+                    CloneOption::Links => (
+                        self.copy_links,
+                        format!("Copy {} link{}", self.link_count, plural(self.link_count)),
+                    ),
+                    CloneOption::Subtasks => (
+                        self.copy_subtasks,
+                        format!(
+                            "Copy {} subtask{}",
+                            self.subtask_count,
+                            plural(self.subtask_count)
+                        ),
+                    ),
+                };
+                let checkbox = if checked { "[x] " } else { "[ ] " };
+                let style = if i == self.selected {
+                    Style::default()
+                        .fg(Color::White)
+                        .bg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::from(Span::styled(format!("{}{}", checkbox, label), style))
+            })
+            .collect();
+        lines.push(Line::default());
+        lines.push(
+            Line::from(Span::styled(
+                "Space:toggle  Enter:continue  Esc:cancel",
+                Style::default().fg(Color::DarkGray),
+            ))
+            .alignment(Alignment::Center),
+        );
+
+        frame.render_widget(Paragraph::new(lines).block(block), dialog_area);
+    }
+}
+
+/// Get the plural suffix for a count.
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+/// Calculate a centered rectangle within the given area.
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width.min(area.width), height.min(area.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_nothing_is_copied_by_default() {
+        let mut dialog = CloneOptionsDialog::new();
+        dialog.show("PROJ-1", 2, 1);
+        assert!(dialog.is_visible());
+
+        let action = dialog.handle_input(key(KeyCode::Enter));
+        assert_eq!(
+            action,
+            Some(CloneOptionsAction::Confirm {
+                links: false,
+                subtasks: false
+            })
+        );
+        assert!(!dialog.is_visible());
+    }
+
+    #[test]
+    fn test_toggle_options() {
+        let mut dialog = CloneOptionsDialog::new();
+        dialog.show("PROJ-1", 0, 3);
+        // Only the subtasks option is shown
+        dialog.handle_input(key(KeyCode::Down));
+        dialog.handle_input(key(KeyCode::Char(' ')));
+
+        let action = dialog.handle_input(key(KeyCode::Enter));
+        assert_eq!(
+            action,
+            Some(CloneOptionsAction::Confirm {
+                links: false,
+                subtasks: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut dialog = CloneOptionsDialog::new();
+        dialog.show("PROJ-1", 1, 0);
+        dialog.handle_input(key(KeyCode::Char(' ')));
+        assert_eq!(
+            dialog.handle_input(key(KeyCode::Esc)),
+            Some(CloneOptionsAction::Cancel)
+        );
+    }
+}
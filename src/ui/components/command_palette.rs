@@ -66,6 +66,27 @@ impl CommandPalette {
         self.selected = 0;
     }
 
+    /// Replace the recently viewed issues listed in the palette.
+    ///
+    /// Takes `(key, summary)` pairs, most recent first.
+    pub fn set_recent_issues(&mut self, issues: &[(&str, &str)]) {
+        self.registry.set_recent_issues(issues);
+    }
+
+    /// Get the IDs of recently run commands (most recent first).
+    pub fn recent_commands(&self) -> Vec<String> {
+        self.registry
+            .recent()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    /// Restore the recently run commands (most recent first).
+    pub fn set_recent_commands(&mut self, ids: &[String]) {
+        self.registry.set_recent(ids);
+    }
+
     /// Hide the command palette.
     pub fn hide(&mut self) {
         self.visible = false;
@@ -345,6 +366,7 @@ impl CommandPalette {
             CommandCategory::Filter => Style::default().fg(Color::Yellow),
             CommandCategory::Settings => Style::default().fg(Color::Gray),
             CommandCategory::Help => Style::default().fg(Color::Cyan),
+            CommandCategory::Recent => Style::default().fg(Color::LightBlue),
         }
     }
 }
@@ -3,6 +3,8 @@
 //! Displays comments for an issue and provides an input form
 //! for adding new comments.
 
+use std::time::Instant;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -15,7 +17,10 @@ use ratatui::{
 };
 
 use super::TextEditor;
-use crate::api::types::Comment;
+use crate::api::types::{Comment, User};
+use crate::events::{keymap, Action, KeyContext};
+use crate::ui::adf::render_adf;
+use crate::ui::theme::theme;
 
 /// Action resulting from comments panel input.
 #[derive(Debug, Clone, PartialEq)]
@@ -26,6 +31,8 @@ pub enum CommentAction {
     Cancel,
     /// Request to load comments for an issue.
     LoadComments(String),
+    /// Continue writing the comment (the current draft) in the external editor.
+    OpenExternalEditor(String),
 }
 
 /// The current mode of the comments panel.
@@ -76,7 +83,7 @@ impl CommentsPanel {
             scroll_offset: 0,
             max_scroll: 0,
             mode: CommentPanelMode::Viewing,
-            editor: TextEditor::empty(),
+            editor: TextEditor::empty().with_mentions(),
             submitting: false,
             issue_key: String::new(),
         }
@@ -102,6 +109,34 @@ impl CommentsPanel {
         self.mode
     }
 
+    /// Check if a comment is being written.
+    pub fn is_composing(&self) -> bool {
+        self.mode == CommentPanelMode::Composing
+    }
+
+    /// Take the user search due for the @mention picker of the comment editor.
+    pub fn take_due_mention_search(&mut self, now: Instant) -> Option<String> {
+        if !self.is_composing() {
+            return None;
+        }
+        self.editor.take_due_mention_search(now)
+    }
+
+    /// Set the results of a mention user search.
+    pub fn set_mention_results(&mut self, query: &str, users: Vec<User>) {
+        self.editor.set_mention_results(query, users);
+    }
+
+    /// Mark a mention user search as failed.
+    pub fn set_mention_search_failed(&mut self, query: &str) {
+        self.editor.set_mention_search_failed(query);
+    }
+
+    /// Get the loaded comments, newest first.
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
     /// Get the number of loaded comments.
     pub fn comment_count(&self) -> usize {
         self.comments.len()
@@ -120,7 +155,7 @@ impl CommentsPanel {
         self.scroll_offset = 0;
         self.max_scroll = 0;
         self.mode = CommentPanelMode::Viewing;
-        self.editor = TextEditor::empty();
+        self.editor = TextEditor::empty().with_mentions();
         self.loading = true;
         self.visible = true;
         self.submitting = false;
@@ -149,7 +184,7 @@ impl CommentsPanel {
         self.total_comments += 1;
         self.submitting = false;
         self.mode = CommentPanelMode::Viewing;
-        self.editor = TextEditor::empty();
+        self.editor = TextEditor::empty().with_mentions();
         self.scroll_offset = 0;
     }
 
@@ -166,13 +201,19 @@ impl CommentsPanel {
     /// Start composing a new comment.
     pub fn start_composing(&mut self) {
         self.mode = CommentPanelMode::Composing;
-        self.editor = TextEditor::empty();
+        self.editor = TextEditor::empty().with_mentions();
+    }
+
+    /// Continue composing with the given draft (from the external editor).
+    pub fn set_draft(&mut self, content: &str) {
+        self.mode = CommentPanelMode::Composing;
+        self.editor = TextEditor::new(content).with_mentions();
     }
 
     /// Cancel composing and return to viewing.
     pub fn cancel_composing(&mut self) {
         self.mode = CommentPanelMode::Viewing;
-        self.editor = TextEditor::empty();
+        self.editor = TextEditor::empty().with_mentions();
     }
 
     /// Get the issue key.
@@ -265,9 +306,10 @@ impl CommentsPanel {
 
     /// Handle input in composing mode.
     fn handle_composing_input(&mut self, key: KeyEvent) -> Option<CommentAction> {
+        let keymap = keymap();
         match (key.code, key.modifiers) {
-            // Submit comment with Ctrl+S (consistent with edit mode, works on macOS)
-            (KeyCode::Char('s'), KeyModifiers::CONTROL) => {
+            // Submit comment (Ctrl+S by default, consistent with edit mode)
+            _ if keymap.matches(KeyContext::Editor, Action::Submit, key) => {
                 let content = self.editor.content().trim().to_string();
                 if !content.is_empty() {
                     self.submitting = true;
@@ -275,8 +317,17 @@ impl CommentsPanel {
                 }
                 None
             }
+            // Continue in the external editor
+            _ if keymap.matches(KeyContext::Editor, Action::ExternalEditor, key) => {
+                Some(CommentAction::OpenExternalEditor(self.editor.content()))
+            }
+            // Leave insert/visual mode of a modal editor
+            (KeyCode::Esc, KeyModifiers::NONE) if self.editor.wants_escape() => {
+                self.editor.handle_input(key);
+                None
+            }
             // Cancel composing
-            (KeyCode::Esc, KeyModifiers::NONE) => {
+            _ if keymap.matches(KeyContext::Editor, Action::Cancel, key) => {
                 self.cancel_composing();
                 None
             }
@@ -406,13 +457,7 @@ impl CommentsPanel {
             ]));
 
             // Comment body
-            let body_text = comment.body.to_plain_text();
-            for line in body_text.lines() {
-                // Wrap long lines
-                for wrapped_line in wrap_text(line, content_width) {
-                    lines.push(Line::from(wrapped_line));
-                }
-            }
+            lines.extend(render_adf(&comment.body, theme(), content_width).lines);
             lines.push(Line::raw(""));
         }
 
@@ -476,8 +521,14 @@ impl CommentsPanel {
         let help_text = Line::from(vec![
             Span::styled("Ctrl+S", Style::default().fg(Color::Green)),
             Span::raw(": submit  "),
+            Span::styled("Ctrl+X", Style::default().fg(Color::Yellow)),
+            Span::raw(": $EDITOR  "),
             Span::styled("Esc", Style::default().fg(Color::Red)),
-            Span::raw(": cancel"),
+            Span::raw(if self.editor.wants_escape() {
+                ": normal mode"
+            } else {
+                ": cancel"
+            }),
         ]);
         let help_paragraph = Paragraph::new(help_text).alignment(Alignment::Center);
         frame.render_widget(help_paragraph, chunks[1]);
@@ -510,38 +561,6 @@ fn format_date(date_str: &str) -> String {
     date_str.to_string()
 }
 
-/// Wrap text to fit within a given width.
-fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
-    if text.is_empty() || max_width == 0 {
-        return vec![String::new()];
-    }
-
-    let mut lines = Vec::new();
-    let mut current_line = String::new();
-
-    for word in text.split_whitespace() {
-        if current_line.is_empty() {
-            current_line = word.to_string();
-        } else if current_line.len() + 1 + word.len() <= max_width {
-            current_line.push(' ');
-            current_line.push_str(word);
-        } else {
-            lines.push(current_line);
-            current_line = word.to_string();
-        }
-    }
-
-    if !current_line.is_empty() {
-        lines.push(current_line);
-    }
-
-    if lines.is_empty() {
-        lines.push(String::new());
-    }
-
-    lines
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -738,16 +757,6 @@ mod tests {
         assert_eq!(format_date("invalid"), "invalid");
     }
 
-    #[test]
-    fn test_wrap_text() {
-        assert_eq!(wrap_text("hello world", 20), vec!["hello world"]);
-        assert_eq!(
-            wrap_text("hello world test", 10),
-            vec!["hello", "world test"]
-        );
-        assert_eq!(wrap_text("", 10), vec![""]);
-    }
-
     #[test]
     fn test_default_impl() {
         let panel = CommentsPanel::default();
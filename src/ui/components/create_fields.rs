@@ -0,0 +1,913 @@
+//! Additional fields of the create issue form.
+//!
+//! The fields an issue type accepts on creation come from JIRA's create
+//! metadata. Those without a dedicated input in the form (components, fix
+//! versions, labels, sprint, due date, custom selects, user pickers, ...)
+//! are listed in this panel, one per line. Text fields are typed into
+//! directly; select and user fields open a picker on Enter.
+
+use std::collections::HashSet;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use serde_json::{json, Map, Value};
+
+use crate::api::types::{AtlassianDoc, CreateFieldKind, CreateFieldMeta, User};
+use crate::ui::components::{AssigneeAction, AssigneePicker, DropdownItem, TextInput};
+use crate::ui::theme::theme;
+
+/// Maximum number of field rows shown at once.
+const MAX_VISIBLE_ROWS: usize = 6;
+
+/// Widest field name column.
+const MAX_NAME_WIDTH: usize = 20;
+
+/// Actions returned from the create fields panel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreateFieldsAction {
+    /// Fetch assignable users for the open user picker.
+    FetchUsers,
+    /// Move focus past the last field.
+    Next,
+}
+
+/// A field and its current value.
+#[derive(Debug, Clone)]
+struct CreateField {
+    /// Field metadata.
+    meta: CreateFieldMeta,
+    /// How the field is edited.
+    kind: CreateFieldKind,
+    /// Value of text-like fields.
+    input: TextInput,
+    /// Chosen values of select-like and user fields.
+    choices: Vec<DropdownItem>,
+}
+
+impl CreateField {
+    fn new(meta: CreateFieldMeta) -> Self {
+        let kind = meta.kind();
+        let mut input = TextInput::new();
+        match kind {
+            CreateFieldKind::Date => input.set_placeholder("YYYY-MM-DD"),
+            CreateFieldKind::DateTime => input.set_placeholder("YYYY-MM-DDThh:mm:ss.000+0000"),
+            CreateFieldKind::Labels => input.set_placeholder("comma separated"),
+            _ => {}
+        }
+        Self {
+            meta,
+            kind,
+            input,
+            choices: Vec::new(),
+        }
+    }
+
+    /// Check if the field is edited through a picker.
+    fn uses_picker(&self) -> bool {
+        matches!(
+            self.kind,
+            CreateFieldKind::Select
+                | CreateFieldKind::MultiSelect
+                | CreateFieldKind::Sprint
+                | CreateFieldKind::User
+        )
+    }
+
+    /// Check if the field has no value.
+    fn is_empty(&self) -> bool {
+        if self.uses_picker() {
+            self.choices.is_empty()
+        } else {
+            self.input.value().trim().is_empty()
+        }
+    }
+
+    /// Get the items the field's picker offers.
+    fn options(&self) -> Vec<DropdownItem> {
+        self.meta
+            .allowed_values
+            .iter()
+            .map(|v| DropdownItem::new(v.id.clone(), v.label()))
+            .collect()
+    }
+
+    /// Get the labels typed into a labels field.
+    fn labels(&self) -> Vec<String> {
+        self.input
+            .value()
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Check the value, returning an error message if it is invalid.
+    fn validate(&self) -> Option<String> {
+        let name = &self.meta.name;
+        if self.is_empty() {
+            return match (self.meta.must_be_set(), self.kind) {
+                (false, _) => None,
+                (true, CreateFieldKind::Unsupported) => {
+                    Some(format!("{} is required but cannot be set here", name))
+                }
+                (true, _) => Some(format!("{} is required", name)),
+            };
+        }
+
+        let value = self.input.value().trim();
+        match self.kind {
+            CreateFieldKind::Number if value.parse::<f64>().is_err() => {
+                Some(format!("{} must be a number", name))
+            }
+            CreateFieldKind::Date if !is_valid_date(value) => {
+                Some(format!("{} must be a date (YYYY-MM-DD)", name))
+            }
+            CreateFieldKind::DateTime if value.get(..10).map_or(true, |d| !is_valid_date(d)) => {
+                Some(format!("{} must start with a date (YYYY-MM-DD)", name))
+            }
+            _ => None,
+        }
+    }
+
+    /// Convert the value to the JSON JIRA expects, if set.
+    fn to_json(&self) -> Option<Value> {
+        if self.is_empty() {
+            return None;
+        }
+        let text = self.input.value().trim();
+        let value = match self.kind {
+            CreateFieldKind::Text | CreateFieldKind::Date | CreateFieldKind::DateTime => {
+                json!(text)
+            }
+            CreateFieldKind::RichText => {
+                serde_json::to_value(AtlassianDoc::from_markdown(text)).ok()?
+            }
+            CreateFieldKind::Number => match text.parse::<i64>() {
+                Ok(n) => json!(n),
+                Err(_) => json!(text.parse::<f64>().ok()?),
+            },
+            CreateFieldKind::Labels => json!(self.labels()),
+            CreateFieldKind::User => json!({ "accountId": self.choices[0].id }),
+            CreateFieldKind::Select => json!({ "id": self.choices[0].id }),
+            CreateFieldKind::MultiSelect => {
+                Value::Array(self.choices.iter().map(|c| json!({ "id": c.id })).collect())
+            }
+            CreateFieldKind::Sprint => json!(self.choices[0].id.parse::<i64>().ok()?),
+            CreateFieldKind::Unsupported => return None,
+        };
+        Some(value)
+    }
+
+    /// Get the text shown for the value.
+    fn display_value(&self) -> String {
+        if self.uses_picker() {
+            self.choices
+                .iter()
+                .map(|c| c.label.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        } else {
+            self.input.value().to_string()
+        }
+    }
+
+    /// Get the hint shown when the field has no value.
+    fn placeholder(&self) -> &'static str {
+        match self.kind {
+            CreateFieldKind::Select | CreateFieldKind::Sprint | CreateFieldKind::User => {
+                "Enter: choose"
+            }
+            CreateFieldKind::MultiSelect => "Enter: choose (Space toggles)",
+            CreateFieldKind::Date => "YYYY-MM-DD",
+            CreateFieldKind::DateTime => "YYYY-MM-DDThh:mm:ss.000+0000",
+            CreateFieldKind::Labels => "comma separated",
+            CreateFieldKind::Unsupported => "not supported, set it in the browser",
+            _ => "",
+        }
+    }
+}
+
+/// Check that a string is a `YYYY-MM-DD` date.
+fn is_valid_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return false;
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return false;
+    }
+    match (
+        year.parse::<u32>(),
+        month.parse::<u32>(),
+        day.parse::<u32>(),
+    ) {
+        (Ok(_), Ok(month), Ok(day)) => (1..=12).contains(&month) && (1..=31).contains(&day),
+        _ => false,
+    }
+}
+
+/// Popup for choosing the values of a select-like field.
+#[derive(Debug, Clone)]
+struct OptionPicker {
+    /// Field name shown as the title.
+    title: String,
+    /// Items to choose from.
+    items: Vec<DropdownItem>,
+    /// Highlighted row (for single select, row 0 is "None").
+    cursor: usize,
+    /// IDs of the checked items (multi-select).
+    checked: HashSet<String>,
+    /// Whether several items can be chosen.
+    multi: bool,
+}
+
+impl OptionPicker {
+    /// Get the number of rows in the list.
+    fn row_count(&self) -> usize {
+        if self.multi {
+            self.items.len()
+        } else {
+            self.items.len() + 1
+        }
+    }
+}
+
+/// Panel listing the create fields not covered by the form's own inputs.
+#[derive(Debug, Default)]
+pub struct CreateFieldsPanel {
+    /// The fields, in the order JIRA returned them.
+    fields: Vec<CreateField>,
+    /// Index of the selected field.
+    selected: usize,
+    /// Open picker for the selected field.
+    picker: Option<OptionPicker>,
+    /// User picker for user fields.
+    user_picker: AssigneePicker,
+}
+
+impl CreateFieldsPanel {
+    /// Create an empty panel.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the fields of the chosen issue type.
+    ///
+    /// Fields the form has dedicated inputs for are left out, as are optional
+    /// fields that cannot be edited. Values of fields that are still present
+    /// are kept, so switching issue types does not lose input.
+    pub fn set_fields(&mut self, metas: Vec<CreateFieldMeta>) {
+        let previous = std::mem::take(&mut self.fields);
+        self.fields = metas
+            .into_iter()
+            .filter(|meta| {
+                !meta.is_built_in()
+                    && (meta.kind() != CreateFieldKind::Unsupported || meta.must_be_set())
+            })
+            .map(|meta| {
+                match previous
+                    .iter()
+                    .find(|f| f.meta.field_id == meta.field_id && f.kind == meta.kind())
+                {
+                    Some(old) => CreateField {
+                        meta,
+                        ..old.clone()
+                    },
+                    None => CreateField::new(meta),
+                }
+            })
+            .collect();
+        self.selected = 0;
+        self.picker = None;
+    }
+
+    /// Remove all fields.
+    pub fn clear(&mut self) {
+        self.fields.clear();
+        self.selected = 0;
+        self.picker = None;
+        self.user_picker.hide();
+    }
+
+    /// Check if there are no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Get the number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Check if a field is listed.
+    pub fn has_field(&self, field_id: &str) -> bool {
+        self.fields.iter().any(|f| f.meta.field_id == field_id)
+    }
+
+    /// Pre-fill a field with values given by name (e.g. from a template).
+    ///
+    /// Select-like values are matched against the allowed values by name.
+    /// Returns the values that could not be set, or `None` if the field is
+    /// not listed.
+    pub fn prefill(&mut self, field_id: &str, values: &[String]) -> Option<Vec<String>> {
+        let field = self
+            .fields
+            .iter_mut()
+            .find(|f| f.meta.field_id == field_id)?;
+        if !field.uses_picker() {
+            field.input.set_value(values.join(", "));
+            return Some(Vec::new());
+        }
+
+        field.choices = field
+            .options()
+            .into_iter()
+            .filter(|o| values.iter().any(|v| v.eq_ignore_ascii_case(&o.label)))
+            .collect();
+        if field.kind != CreateFieldKind::MultiSelect {
+            field.choices.truncate(1);
+        }
+        let choices = &field.choices;
+        Some(
+            values
+                .iter()
+                .filter(|v| !choices.iter().any(|c| v.eq_ignore_ascii_case(&c.label)))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Get the index of the selected field.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select the first field.
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+    }
+
+    /// Select the last field.
+    pub fn select_last(&mut self) {
+        self.selected = self.fields.len().saturating_sub(1);
+    }
+
+    /// Select the next field. Returns false if the last field is selected.
+    pub fn select_next(&mut self) -> bool {
+        if self.selected + 1 < self.fields.len() {
+            self.selected += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Select the previous field. Returns false if the first field is selected.
+    pub fn select_prev(&mut self) -> bool {
+        if self.selected > 0 {
+            self.selected -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Check if a picker is open and takes all input.
+    pub fn is_picker_open(&self) -> bool {
+        self.picker.is_some() || self.user_picker.is_visible()
+    }
+
+    /// Check if the user picker is waiting for users.
+    pub fn is_user_picker_loading(&self) -> bool {
+        self.user_picker.is_visible() && self.user_picker.is_loading()
+    }
+
+    /// Show the users in the user picker.
+    pub fn set_users(&mut self, users: Vec<User>) {
+        let current = self
+            .fields
+            .get(self.selected)
+            .map(|f| f.display_value())
+            .unwrap_or_default();
+        self.user_picker.show(users, &current);
+    }
+
+    /// Hide the user picker (e.g., on fetch failure).
+    pub fn hide_user_picker(&mut self) {
+        self.user_picker.hide();
+    }
+
+    /// Handle keyboard input for the selected field.
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<CreateFieldsAction> {
+        if self.user_picker.is_visible() {
+            self.handle_user_picker_input(key);
+            return None;
+        }
+        if self.picker.is_some() {
+            self.handle_picker_input(key);
+            return None;
+        }
+
+        let field = self.fields.get_mut(self.selected)?;
+        match (key.code, key.modifiers) {
+            (KeyCode::Up, _) => {
+                self.select_prev();
+                None
+            }
+            (KeyCode::Down, _) => {
+                self.select_next();
+                None
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) if field.kind == CreateFieldKind::User => {
+                let current = field.display_value();
+                self.user_picker.show_loading(&current);
+                Some(CreateFieldsAction::FetchUsers)
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) | (KeyCode::Char(' '), KeyModifiers::NONE)
+                if field.uses_picker() =>
+            {
+                self.open_picker();
+                None
+            }
+            (KeyCode::Backspace | KeyCode::Delete, _) if field.uses_picker() => {
+                field.choices.clear();
+                None
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                if self.select_next() {
+                    None
+                } else {
+                    Some(CreateFieldsAction::Next)
+                }
+            }
+            _ => {
+                if !field.uses_picker() && field.kind != CreateFieldKind::Unsupported {
+                    field.input.handle_input(key);
+                }
+                None
+            }
+        }
+    }
+
+    /// Open the option picker for the selected field.
+    fn open_picker(&mut self) {
+        let Some(field) = self.fields.get(self.selected) else {
+            return;
+        };
+        let multi = field.kind == CreateFieldKind::MultiSelect;
+        let items = field.options();
+        let checked: HashSet<String> = field.choices.iter().map(|c| c.id.clone()).collect();
+        let cursor = match field.choices.first() {
+            Some(chosen) if !multi => items
+                .iter()
+                .position(|i| i.id == chosen.id)
+                .map_or(0, |i| i + 1),
+            _ => 0,
+        };
+        self.picker = Some(OptionPicker {
+            title: field.meta.name.clone(),
+            items,
+            cursor,
+            checked,
+            multi,
+        });
+    }
+
+    /// Handle input while the option picker is open.
+    fn handle_picker_input(&mut self, key: KeyEvent) {
+        let Some(picker) = self.picker.as_mut() else {
+            return;
+        };
+        match (key.code, key.modifiers) {
+            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => {
+                picker.cursor = (picker.cursor + 1).min(picker.row_count().saturating_sub(1));
+            }
+            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => {
+                picker.cursor = picker.cursor.saturating_sub(1);
+            }
+            (KeyCode::Char(' '), KeyModifiers::NONE) if picker.multi => {
+                if let Some(item) = picker.items.get(picker.cursor) {
+                    if !picker.checked.remove(&item.id) {
+                        picker.checked.insert(item.id.clone());
+                    }
+                }
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                let choices = if picker.multi {
+                    picker
+                        .items
+                        .iter()
+                        .filter(|i| picker.checked.contains(&i.id))
+                        .cloned()
+                        .collect()
+                } else {
+                    picker
+                        .cursor
+                        .checked_sub(1)
+                        .and_then(|i| picker.items.get(i))
+                        .cloned()
+                        .into_iter()
+                        .collect()
+                };
+                if let Some(field) = self.fields.get_mut(self.selected) {
+                    field.choices = choices;
+                }
+                self.picker = None;
+            }
+            (KeyCode::Esc, _) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                self.picker = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle input while the user picker is open.
+    fn handle_user_picker_input(&mut self, key: KeyEvent) {
+        let Some(action) = self.user_picker.handle_input(key) else {
+            return;
+        };
+        let Some(field) = self.fields.get_mut(self.selected) else {
+            return;
+        };
+        match action {
+            AssigneeAction::Select(account_id, display_name) => {
+                field.choices = vec![DropdownItem::new(account_id, display_name)];
+            }
+            AssigneeAction::Unassign => field.choices.clear(),
+            AssigneeAction::Cancel => {}
+        }
+    }
+
+    /// Validate the fields, returning an error message per invalid field.
+    pub fn validate(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .filter_map(CreateField::validate)
+            .collect()
+    }
+
+    /// Get the values of all set fields, keyed by field ID.
+    pub fn values(&self) -> Map<String, Value> {
+        self.fields
+            .iter()
+            .filter_map(|f| Some((f.meta.field_id.clone(), f.to_json()?)))
+            .collect()
+    }
+
+    /// Get the height the panel needs, or 0 when there are no fields.
+    pub fn height(&self) -> u16 {
+        if self.fields.is_empty() {
+            0
+        } else {
+            self.fields.len().min(MAX_VISIBLE_ROWS) as u16 + 2
+        }
+    }
+
+    /// Render the field list.
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool) {
+        if self.fields.is_empty() || area.height < 3 {
+            return;
+        }
+        let t = theme();
+
+        let (border_style, title_style) = if focused {
+            (
+                Style::default().fg(t.border_focused),
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+            )
+        } else {
+            (Style::default().fg(t.border), Style::default().fg(t.fg))
+        };
+        let mut block = Block::default()
+            .title(Span::styled(" Fields ", title_style))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        if self.fields.len() > MAX_VISIBLE_ROWS {
+            block = block.title_bottom(Span::styled(
+                format!(" {}/{} ", self.selected + 1, self.fields.len()),
+                Style::default().fg(t.muted),
+            ));
+        }
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let visible = inner.height as usize;
+        let offset = (self.selected + 1).saturating_sub(visible);
+        let name_width = self
+            .fields
+            .iter()
+            .map(|f| f.meta.name.chars().count() + 2)
+            .max()
+            .unwrap_or(0)
+            .min(MAX_NAME_WIDTH);
+
+        let lines: Vec<Line> = self
+            .fields
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .map(|(i, field)| {
+                let is_selected = focused && i == self.selected;
+                let marker = if field.meta.must_be_set() { " *" } else { "" };
+                let name: String = format!("{}{}", field.meta.name, marker)
+                    .chars()
+                    .take(name_width)
+                    .collect();
+                let name_style = if is_selected {
+                    Style::default().fg(t.accent).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(t.muted)
+                };
+                let mut spans = vec![Span::styled(
+                    format!("{:<width$} ", name, width = name_width),
+                    name_style,
+                )];
+
+                let value = field.display_value();
+                if is_selected && !field.uses_picker() && field.kind != CreateFieldKind::Unsupported
+                {
+                    // Show the cursor in the focused text field
+                    let cursor = field.input.cursor();
+                    let before: String = value.chars().take(cursor).collect();
+                    let at: String = value.chars().nth(cursor).map_or(" ".into(), String::from);
+                    let after: String = value.chars().skip(cursor + 1).collect();
+                    spans.push(Span::styled(before, Style::default().fg(t.input_fg)));
+                    spans.push(Span::styled(
+                        at,
+                        Style::default().add_modifier(Modifier::REVERSED),
+                    ));
+                    spans.push(Span::styled(after, Style::default().fg(t.input_fg)));
+                    if value.is_empty() {
+                        spans.push(Span::styled(
+                            field.placeholder(),
+                            Style::default().fg(t.input_placeholder),
+                        ));
+                    }
+                } else if value.is_empty() {
+                    spans.push(Span::styled(
+                        field.placeholder(),
+                        Style::default().fg(t.input_placeholder),
+                    ));
+                } else {
+                    spans.push(Span::styled(value, Style::default().fg(t.input_fg)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Render the open picker on top of the form.
+    pub fn render_overlays(&self, frame: &mut Frame, screen: Rect) {
+        self.user_picker.render(frame, screen);
+
+        let Some(ref picker) = self.picker else {
+            return;
+        };
+        let t = theme();
+
+        let width = 50u16.min(screen.width.saturating_sub(4));
+        let height = (picker.row_count().clamp(1, 12) as u16 + 3).min(screen.height);
+        let area = Rect::new(
+            screen.x + screen.width.saturating_sub(width) / 2,
+            screen.y + screen.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+        frame.render_widget(Clear, area);
+
+        let hint = if picker.multi {
+            " Space: toggle  Enter: done  Esc: cancel "
+        } else {
+            " Enter: choose  Esc: cancel "
+        };
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" {} ", picker.title),
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Span::styled(hint, Style::default().fg(t.muted)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.border_focused));
+
+        let mut items: Vec<ListItem> = Vec::new();
+        if !picker.multi {
+            items.push(ListItem::new(Span::styled(
+                "None",
+                Style::default().fg(t.muted),
+            )));
+        }
+        items.extend(picker.items.iter().map(|item| {
+            if picker.multi {
+                let checkbox = if picker.checked.contains(&item.id) {
+                    "[x] "
+                } else {
+                    "[ ] "
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(checkbox, Style::default().fg(t.success)),
+                    Span::raw(item.label.clone()),
+                ]))
+            } else {
+                ListItem::new(Span::raw(item.label.clone()))
+            }
+        }));
+        if items.is_empty() {
+            items.push(ListItem::new(Span::styled(
+                "No values available",
+                Style::default().fg(t.muted),
+            )));
+        }
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .fg(t.selection_fg)
+                    .bg(t.selection_bg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+        let mut state = ListState::default();
+        state.select(Some(picker.cursor));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::{AllowedValue, FieldSchema};
+
+    fn meta(field_id: &str, name: &str, field_type: &str, required: bool) -> CreateFieldMeta {
+        CreateFieldMeta {
+            field_id: field_id.to_string(),
+            name: name.to_string(),
+            required,
+            has_default_value: false,
+            schema: FieldSchema {
+                field_type: field_type.to_string(),
+                ..Default::default()
+            },
+            allowed_values: Vec::new(),
+        }
+    }
+
+    fn components() -> CreateFieldMeta {
+        let mut meta = meta("components", "Components", "array", true);
+        meta.schema.items = Some("component".to_string());
+        meta.allowed_values = vec![
+            AllowedValue::new("10", "Backend"),
+            AllowedValue::new("11", "Frontend"),
+        ];
+        meta
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn type_text(panel: &mut CreateFieldsPanel, text: &str) {
+        for c in text.chars() {
+            panel.handle_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_built_in_and_unsupported_optional_fields_are_hidden() {
+        let mut panel = CreateFieldsPanel::new();
+        panel.set_fields(vec![
+            meta("summary", "Summary", "string", true),
+            meta("duedate", "Due date", "date", false),
+            meta("timetracking", "Time tracking", "timetracking", false),
+            meta("customfield_1", "Audit", "any", true),
+        ]);
+        assert_eq!(panel.len(), 2);
+        assert!(panel.has_field("duedate"));
+        assert!(!panel.has_field("summary"));
+        assert_eq!(
+            panel.validate(),
+            vec!["Audit is required but cannot be set here".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_required_fields_are_validated() {
+        let mut panel = CreateFieldsPanel::new();
+        panel.set_fields(vec![
+            components(),
+            meta("duedate", "Due date", "date", false),
+            meta("customfield_2", "Story Points", "number", false),
+        ]);
+        assert_eq!(panel.validate(), vec!["Components is required".to_string()]);
+
+        panel.select_next();
+        type_text(&mut panel, "2024-13-01");
+        panel.select_next();
+        type_text(&mut panel, "lots");
+        let errors = panel.validate();
+        assert!(errors.contains(&"Due date must be a date (YYYY-MM-DD)".to_string()));
+        assert!(errors.contains(&"Story Points must be a number".to_string()));
+    }
+
+    #[test]
+    fn test_multi_select_picker() {
+        let mut panel = CreateFieldsPanel::new();
+        panel.set_fields(vec![components()]);
+
+        panel.handle_input(key(KeyCode::Enter));
+        assert!(panel.is_picker_open());
+        panel.handle_input(key(KeyCode::Char(' ')));
+        panel.handle_input(key(KeyCode::Down));
+        panel.handle_input(key(KeyCode::Char(' ')));
+        panel.handle_input(key(KeyCode::Enter));
+        assert!(!panel.is_picker_open());
+
+        assert!(panel.validate().is_empty());
+        assert_eq!(
+            panel.values()["components"],
+            json!([{"id": "10"}, {"id": "11"}])
+        );
+    }
+
+    #[test]
+    fn test_values_are_converted_by_kind() {
+        let mut labels = meta("labels", "Labels", "array", false);
+        labels.schema.items = Some("string".to_string());
+        let mut sprint = meta("customfield_3", "Sprint", "array", false);
+        sprint.schema.custom = Some("com.pyxis.greenhopper.jira:gh-sprint".to_string());
+        sprint.allowed_values = vec![AllowedValue::new("42", "Sprint 7")];
+
+        let mut panel = CreateFieldsPanel::new();
+        panel.set_fields(vec![
+            labels,
+            sprint,
+            meta("customfield_2", "Story Points", "number", false),
+            meta("duedate", "Due date", "date", false),
+        ]);
+        assert_eq!(
+            panel.prefill("labels", &["bug".to_string(), "ui".to_string()]),
+            Some(Vec::new())
+        );
+        assert_eq!(
+            panel.prefill(
+                "customfield_3",
+                &["sprint 7".to_string(), "sprint 8".to_string()]
+            ),
+            Some(vec!["sprint 8".to_string()])
+        );
+        assert_eq!(panel.prefill("fixVersions", &["1.0".to_string()]), None);
+        panel.select_next();
+        panel.select_next();
+        type_text(&mut panel, "5");
+
+        let values = panel.values();
+        assert_eq!(values["labels"], json!(["bug", "ui"]));
+        assert_eq!(values["customfield_3"], json!(42));
+        assert_eq!(values["customfield_2"], json!(5));
+        assert!(!values.contains_key("duedate"));
+    }
+
+    #[test]
+    fn test_values_survive_issue_type_change() {
+        let mut panel = CreateFieldsPanel::new();
+        panel.set_fields(vec![meta("duedate", "Due date", "date", false)]);
+        type_text(&mut panel, "2024-05-01");
+
+        panel.set_fields(vec![
+            components(),
+            meta("duedate", "Due date", "date", true),
+        ]);
+        assert_eq!(panel.values()["duedate"], json!("2024-05-01"));
+    }
+
+    #[test]
+    fn test_enter_moves_through_fields() {
+        let mut panel = CreateFieldsPanel::new();
+        panel.set_fields(vec![
+            meta("duedate", "Due date", "date", false),
+            meta("environment", "Environment", "string", false),
+        ]);
+        assert_eq!(panel.handle_input(key(KeyCode::Enter)), None);
+        assert_eq!(panel.selected(), 1);
+        assert_eq!(
+            panel.handle_input(key(KeyCode::Enter)),
+            Some(CreateFieldsAction::Next)
+        );
+    }
+
+    #[test]
+    fn test_is_valid_date() {
+        assert!(is_valid_date("2024-02-29"));
+        assert!(!is_valid_date("2024-2-29"));
+        assert!(!is_valid_date("tomorrow"));
+    }
+}
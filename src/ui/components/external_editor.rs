@@ -5,15 +5,31 @@
 //! - Create temporary files with content for editing
 //! - Launch the external editor and wait for it to complete
 //! - Read back the modified content and clean up temporary files
+//! - Show context (issue key, summary, recent comments) in a commented header
+//!   at the top of the file, which is stripped again on save
 
 use std::env;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use thiserror::Error;
 
+use crate::api::types::Comment;
+
+/// First line of the context header written above the editable content.
+const HEADER_START: &str = "<!-- lazyjira:";
+
+/// Last line of the context header.
+const HEADER_END: &str = "-->";
+
+/// Number of recent comments shown as reply context.
+const CONTEXT_COMMENTS: usize = 3;
+
+/// Maximum number of lines shown per context comment.
+const CONTEXT_COMMENT_LINES: usize = 8;
+
 /// Errors that can occur during external editor operations.
 #[derive(Debug, Error)]
 pub enum ExternalEditorError {
@@ -51,6 +67,99 @@ pub struct ExternalEditResult {
     pub was_modified: bool,
 }
 
+/// What an external editor session edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalEditTarget {
+    /// The description of the issue in the detail view.
+    IssueDescription,
+    /// The comment being written in the comments panel.
+    Comment,
+    /// The description in the create issue form.
+    NewIssueDescription,
+}
+
+/// A request to edit content in the external editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalEditRequest {
+    /// Where the edited content goes.
+    pub target: ExternalEditTarget,
+    /// Key used in the temporary file name (usually the issue key).
+    pub file_key: String,
+    /// Context shown in the commented header (empty for no header).
+    pub header: String,
+    /// The initial content to edit.
+    pub content: String,
+}
+
+impl ExternalEditRequest {
+    /// Edit the description of an existing issue.
+    pub fn description(issue_key: &str, summary: &str, content: impl Into<String>) -> Self {
+        Self {
+            target: ExternalEditTarget::IssueDescription,
+            file_key: issue_key.to_string(),
+            header: format!("Description of {}: {}", issue_key, summary),
+            content: content.into(),
+        }
+    }
+
+    /// Write a comment on an issue.
+    ///
+    /// `comments` are the issue's comments, newest first; the last few are
+    /// shown in the header as reply context.
+    pub fn comment(
+        issue_key: &str,
+        summary: &str,
+        comments: &[Comment],
+        draft: impl Into<String>,
+    ) -> Self {
+        let mut header = format!("Comment on {}: {}", issue_key, summary);
+        let recent: Vec<&Comment> = comments.iter().take(CONTEXT_COMMENTS).collect();
+        if !recent.is_empty() {
+            header.push_str("\n\nRecent comments:");
+        }
+        for comment in recent.into_iter().rev() {
+            let date = comment.created.get(0..10).unwrap_or(&comment.created);
+            header.push_str(&format!(
+                "\n\n  {} ({}):",
+                comment.author.display_name, date
+            ));
+            let body = comment.body.to_plain_text();
+            let lines: Vec<&str> = body.lines().filter(|l| !l.trim().is_empty()).collect();
+            for line in lines.iter().take(CONTEXT_COMMENT_LINES) {
+                header.push_str(&format!("\n    {}", line.trim_end()));
+            }
+            if lines.len() > CONTEXT_COMMENT_LINES {
+                header.push_str("\n    ...");
+            }
+        }
+        Self {
+            target: ExternalEditTarget::Comment,
+            file_key: format!("{}-comment", issue_key),
+            header,
+            content: draft.into(),
+        }
+    }
+
+    /// Write the description of a new issue.
+    pub fn new_issue_description(
+        project_key: &str,
+        summary: &str,
+        content: impl Into<String>,
+    ) -> Self {
+        let summary = if summary.trim().is_empty() {
+            "(no summary yet)"
+        } else {
+            summary
+        };
+        Self {
+            target: ExternalEditTarget::NewIssueDescription,
+            file_key: format!("{}-new", project_key),
+            header: format!("New issue in {}: {}", project_key, summary),
+            content: content.into(),
+        }
+    }
+}
+
 /// External editor utility for launching text editors with temporary files.
 #[derive(Debug, Clone)]
 pub struct ExternalEditor {
@@ -105,9 +214,23 @@ impl ExternalEditor {
         &self,
         issue_key: &str,
         content: &str,
+    ) -> Result<ExternalEditResult, ExternalEditorError> {
+        self.open_with_header(issue_key, "", content)
+    }
+
+    /// Open content in the external editor below a commented context header.
+    ///
+    /// The header is removed from the edited text before it is returned, so
+    /// only the content below it is compared and used.
+    pub fn open_with_header(
+        &self,
+        file_key: &str,
+        header: &str,
+        content: &str,
     ) -> Result<ExternalEditResult, ExternalEditorError> {
         // Create the temporary file
-        let temp_path = create_temp_file(issue_key, content)?;
+        let text = format!("{}{}", format_header(header), content);
+        let temp_path = create_temp_file(file_key, &text)?;
 
         // Launch the editor and wait for it to exit
         let result = self.launch_editor(&temp_path);
@@ -122,14 +245,31 @@ impl ExternalEditor {
         read_result
     }
 
+    /// Open an existing file in the external editor, in place.
+    ///
+    /// If `line` is given the cursor is placed on it with the `+<line>`
+    /// argument understood by vi, Emacs, nano and most terminal editors.
+    pub fn open_file(&self, path: &Path, line: Option<usize>) -> Result<(), ExternalEditorError> {
+        let mut command = Command::new(&self.editor);
+        if let Some(line) = line {
+            command.arg(format!("+{}", line));
+        }
+        self.run(command.arg(path))
+    }
+
     /// Launch the editor process with the given file path.
     fn launch_editor(&self, path: &PathBuf) -> Result<(), ExternalEditorError> {
-        let status = Command::new(&self.editor).arg(path).status().map_err(|e| {
-            ExternalEditorError::EditorSpawn {
+        self.run(Command::new(&self.editor).arg(path))
+    }
+
+    /// Run the editor command and wait for it to exit.
+    fn run(&self, command: &mut Command) -> Result<(), ExternalEditorError> {
+        let status = command
+            .status()
+            .map_err(|e| ExternalEditorError::EditorSpawn {
                 editor: self.editor.clone(),
                 source: e,
-            }
-        })?;
+            })?;
 
         if status.success() {
             Ok(())
@@ -160,6 +300,43 @@ pub fn get_editor() -> String {
         .unwrap_or_else(|_| "vi".to_string())
 }
 
+/// Format the commented context header written above the content.
+///
+/// Returns an empty string for an empty header.
+fn format_header(header: &str) -> String {
+    if header.is_empty() {
+        return String::new();
+    }
+    let mut text = format!(
+        "{} everything up to the closing marker is removed on save.\n",
+        HEADER_START
+    );
+    for line in header.lines() {
+        // Keep the comment from being closed early
+        text.push_str(&line.replace(HEADER_END, "- ->"));
+        text.push('\n');
+    }
+    text.push_str(HEADER_END);
+    text.push('\n');
+    text
+}
+
+/// Remove the commented context header from edited text.
+pub fn strip_header(text: &str) -> &str {
+    if !text.starts_with(HEADER_START) {
+        return text;
+    }
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        offset += line.len();
+        if line.trim_end() == HEADER_END {
+            return &text[offset..];
+        }
+    }
+    // An unterminated header is left for the user to see
+    text
+}
+
 /// Create a temporary file with the given content.
 ///
 /// The file is created in the system's temp directory with the pattern:
@@ -182,8 +359,9 @@ fn read_and_cleanup(
     path: &PathBuf,
     original_content: &str,
 ) -> Result<ExternalEditResult, ExternalEditorError> {
-    // Read the content
+    // Read the content without the context header
     let content = fs::read_to_string(path).map_err(ExternalEditorError::ContentRead)?;
+    let content = strip_header(&content).to_string();
 
     // Clean up the temp file (non-fatal if it fails)
     if let Err(e) = fs::remove_file(path) {
@@ -468,4 +646,98 @@ mod tests {
             other => panic!("Expected EditorSpawn error, got {:?}", other),
         }
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_open_file_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[settings]\n").unwrap();
+
+        // `true` accepts and ignores the +line argument
+        assert!(ExternalEditor::with_editor("true")
+            .open_file(&path, Some(3))
+            .is_ok());
+        assert!(path.exists());
+        assert!(matches!(
+            ExternalEditor::with_editor("false").open_file(&path, None),
+            Err(ExternalEditorError::EditorExecution(1))
+        ));
+    }
+
+    fn comment(author: &str, body: &str) -> Comment {
+        Comment {
+            id: "1".to_string(),
+            body: crate::api::types::AtlassianDoc::from_text(body),
+            author: crate::api::types::User {
+                account_id: "abc".to_string(),
+                display_name: author.to_string(),
+                email_address: None,
+                avatar_urls: None,
+                active: true,
+            },
+            created: "2024-01-15T10:30:00.000+0000".to_string(),
+            updated: "2024-01-15T10:30:00.000+0000".to_string(),
+            self_url: None,
+        }
+    }
+
+    #[test]
+    fn test_header_is_stripped() {
+        let text = format!("{}Draft text\n", format_header("Comment on TEST-1: Title"));
+        assert!(text.starts_with(HEADER_START));
+        assert_eq!(strip_header(&text), "Draft text\n");
+
+        // Text without a header is unchanged
+        assert_eq!(strip_header("plain"), "plain");
+        assert_eq!(format_header(""), "");
+    }
+
+    #[test]
+    fn test_header_cannot_be_closed_early() {
+        let text = format!("{}body", format_header("a --> b"));
+        assert_eq!(strip_header(&text), "body");
+    }
+
+    #[test]
+    fn test_read_and_cleanup_strips_header() {
+        let path = env::temp_dir().join(format!("test-header-{}.md", std::process::id()));
+        let original = "same content";
+        fs::write(&path, format!("{}{}", format_header("context"), original))
+            .expect("Should write file");
+
+        let result = read_and_cleanup(&path, original).expect("Should read and cleanup");
+
+        assert_eq!(result.content, original);
+        assert!(!result.was_modified);
+    }
+
+    #[test]
+    fn test_comment_request_shows_recent_comments() {
+        // Newest first, as loaded by the comments panel
+        let comments = vec![
+            comment("Carol", "third"),
+            comment("Bob", "second"),
+            comment("Alice", "first"),
+            comment("Dave", "too old"),
+        ];
+        let request = ExternalEditRequest::comment("TEST-1", "Login fails", &comments, "draft");
+
+        assert_eq!(request.target, ExternalEditTarget::Comment);
+        assert_eq!(request.content, "draft");
+        assert!(request.header.starts_with("Comment on TEST-1: Login fails"));
+        assert!(!request.header.contains("too old"));
+        let alice = request.header.find("Alice (2024-01-15):").unwrap();
+        let carol = request.header.find("Carol (2024-01-15):").unwrap();
+        assert!(alice < carol, "Oldest context comment comes first");
+        assert!(request.header.contains("    third"));
+    }
+
+    #[test]
+    fn test_new_issue_request_header() {
+        let request = ExternalEditRequest::new_issue_description("PROJ", "", "text");
+        assert_eq!(request.target, ExternalEditTarget::NewIssueDescription);
+        assert_eq!(request.header, "New issue in PROJ: (no summary yet)");
+        assert_eq!(request.file_key, "PROJ-new");
+    }
 }
@@ -0,0 +1,617 @@
+//! Go to issue finder.
+//!
+//! A global picker for jumping straight to an issue's detail view. Typing an
+//! issue key or free text filters the locally known issues (recently viewed,
+//! loaded and cached) as you type, and a JIRA issue picker search runs once
+//! typing pauses. Its results are listed after the local matches.
+
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::api::types::IssueSuggestion;
+
+/// How long typing must pause before the remote search runs.
+const SEARCH_DELAY: Duration = Duration::from_millis(300);
+
+/// Minimum query length for a remote search.
+const MIN_SEARCH_LEN: usize = 2;
+
+/// Maximum number of results shown.
+const MAX_RESULTS: usize = 50;
+
+/// Action resulting from go to issue finder input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoToIssueAction {
+    /// Open the issue with this key.
+    Open(String),
+    /// The finder was closed.
+    Cancel,
+}
+
+/// Where a finder entry came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSource {
+    /// Recently viewed issue.
+    Recent,
+    /// Issue loaded in a list or the cache.
+    Local,
+    /// JIRA issue picker search result.
+    Search,
+    /// The query itself, when it looks like an issue key.
+    Key,
+}
+
+impl IssueSource {
+    /// Get the label shown next to entries from this source.
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Recent => "recent",
+            Self::Local => "local",
+            Self::Search => "search",
+            Self::Key => "key",
+        }
+    }
+}
+
+/// An issue the finder can jump to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueCandidate {
+    /// The issue key.
+    pub key: String,
+    /// The issue summary.
+    pub summary: String,
+    /// Where the entry came from.
+    pub source: IssueSource,
+}
+
+impl IssueCandidate {
+    /// Create a new candidate.
+    pub fn new(key: impl Into<String>, summary: impl Into<String>, source: IssueSource) -> Self {
+        Self {
+            key: key.into(),
+            summary: summary.into(),
+            source,
+        }
+    }
+}
+
+/// Go to issue finder component.
+#[derive(Debug)]
+pub struct GoToIssuePicker {
+    /// Current query.
+    query: String,
+    /// Locally known issues, in priority order.
+    local: Vec<IssueCandidate>,
+    /// Results of the last remote search.
+    remote: Vec<IssueCandidate>,
+    /// Entries matching the current query.
+    results: Vec<IssueCandidate>,
+    /// Currently selected result index.
+    selected: usize,
+    /// Whether the finder is visible.
+    visible: bool,
+    /// Whether a remote search is in flight.
+    loading: bool,
+    /// When the pending remote search should run.
+    search_due: Option<Instant>,
+}
+
+impl GoToIssuePicker {
+    /// Create a new finder.
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            local: Vec::new(),
+            remote: Vec::new(),
+            results: Vec::new(),
+            selected: 0,
+            visible: false,
+            loading: false,
+            search_due: None,
+        }
+    }
+
+    /// Check if the finder is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Check if a remote search is in flight.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Get the current query.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Get the entries matching the current query.
+    pub fn results(&self) -> &[IssueCandidate] {
+        &self.results
+    }
+
+    /// Show the finder with the given locally known issues.
+    pub fn show(&mut self, local: Vec<IssueCandidate>) {
+        self.query.clear();
+        self.local.clear();
+        self.remote.clear();
+        self.selected = 0;
+        self.loading = false;
+        self.search_due = None;
+        self.visible = true;
+        self.add_local(local);
+    }
+
+    /// Hide the finder.
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.loading = false;
+        self.search_due = None;
+    }
+
+    /// Add locally known issues, skipping ones already listed.
+    pub fn add_local(&mut self, candidates: Vec<IssueCandidate>) {
+        for candidate in candidates {
+            if !self.local.iter().any(|c| c.key == candidate.key) {
+                self.local.push(candidate);
+            }
+        }
+        self.update_results();
+    }
+
+    /// Take the query to search for remotely, if typing has paused long enough.
+    pub fn take_due_search(&mut self, now: Instant) -> Option<String> {
+        match self.search_due {
+            Some(due) if self.visible && now >= due => {
+                self.search_due = None;
+                self.loading = true;
+                Some(self.query.trim().to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Set the results of a remote search.
+    ///
+    /// Results for a query other than the current one are ignored.
+    pub fn set_remote_results(&mut self, query: &str, suggestions: Vec<IssueSuggestion>) {
+        if query != self.query.trim() {
+            return;
+        }
+        self.loading = false;
+        self.remote = suggestions
+            .into_iter()
+            .map(|s| IssueCandidate::new(s.key.clone(), s.display_summary(), IssueSource::Search))
+            .collect();
+        self.update_results();
+    }
+
+    /// Mark the remote search for a query as failed.
+    pub fn set_search_failed(&mut self, query: &str) {
+        if query == self.query.trim() {
+            self.loading = false;
+        }
+    }
+
+    /// Handle a change to the query.
+    fn query_changed(&mut self) {
+        self.remote.clear();
+        self.loading = false;
+        self.search_due =
+            (self.query.trim().len() >= MIN_SEARCH_LEN).then(|| Instant::now() + SEARCH_DELAY);
+        self.selected = 0;
+        self.update_results();
+    }
+
+    /// Rebuild the results for the current query.
+    ///
+    /// Local matches come first (best match first), then remote results. A
+    /// query that looks like an issue key is offered as-is if nothing else
+    /// has that key.
+    fn update_results(&mut self) {
+        let query = self.query.trim().to_lowercase();
+
+        let mut scored: Vec<(i32, &IssueCandidate)> = self
+            .local
+            .iter()
+            .filter_map(|c| match_score(c, &query).map(|score| (score, c)))
+            .collect();
+        // Stable sort keeps the priority order for equal scores
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+        let mut results: Vec<IssueCandidate> = scored.into_iter().map(|(_, c)| c.clone()).collect();
+        for candidate in &self.remote {
+            if !results.iter().any(|c| c.key == candidate.key) {
+                results.push(candidate.clone());
+            }
+        }
+
+        if is_issue_key(self.query.trim()) {
+            let key = self.query.trim().to_uppercase();
+            if !results.iter().any(|c| c.key == key) {
+                results.insert(0, IssueCandidate::new(key, "", IssueSource::Key));
+            }
+        }
+
+        results.truncate(MAX_RESULTS);
+        self.results = results;
+        if self.selected >= self.results.len() {
+            self.selected = self.results.len().saturating_sub(1);
+        }
+    }
+
+    /// Handle keyboard input.
+    ///
+    /// Returns an optional action to be handled by the parent view.
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<GoToIssueAction> {
+        if !self.visible {
+            return None;
+        }
+
+        match (key.code, key.modifiers) {
+            (KeyCode::Esc, _) => {
+                self.hide();
+                Some(GoToIssueAction::Cancel)
+            }
+            (KeyCode::Enter, _) => {
+                let key = self.results.get(self.selected)?.key.clone();
+                self.hide();
+                Some(GoToIssueAction::Open(key))
+            }
+            (KeyCode::Down, _)
+            | (KeyCode::Tab, KeyModifiers::NONE)
+            | (KeyCode::Char('n'), KeyModifiers::CONTROL)
+            | (KeyCode::Char('j'), KeyModifiers::CONTROL) => {
+                if self.selected + 1 < self.results.len() {
+                    self.selected += 1;
+                }
+                None
+            }
+            (KeyCode::Up, _)
+            | (KeyCode::BackTab, _)
+            | (KeyCode::Char('p'), KeyModifiers::CONTROL)
+            | (KeyCode::Char('k'), KeyModifiers::CONTROL) => {
+                self.selected = self.selected.saturating_sub(1);
+                None
+            }
+            (KeyCode::Backspace, _) => {
+                if self.query.pop().is_some() {
+                    self.query_changed();
+                }
+                None
+            }
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+                self.query.clear();
+                self.query_changed();
+                None
+            }
+            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+                self.query.push(c);
+                self.query_changed();
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Render the finder.
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let dialog_width = 70.min(area.width.saturating_sub(4));
+        let dialog_height = 20.min(area.height.saturating_sub(4));
+        let dialog_area = centered_rect(dialog_width, dialog_height, area);
+
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .title(" Go to Issue ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2), // Query
+                Constraint::Min(3),    // Results
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner);
+
+        let mut query_spans = vec![
+            Span::styled(
+                "> ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(&self.query, Style::default().fg(Color::White)),
+            Span::styled("▏", Style::default().fg(Color::Yellow)),
+        ];
+        if self.loading {
+            query_spans.push(Span::styled(
+                "  searching...",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(query_spans)), chunks[0]);
+
+        if self.results.is_empty() {
+            let hint = if self.query.is_empty() {
+                "Type an issue key or text to search"
+            } else if self.loading || self.search_due.is_some() {
+                "Searching..."
+            } else {
+                "No matching issues"
+            };
+            let empty_text = Paragraph::new(hint)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty_text, chunks[1]);
+        } else {
+            let summary_width = (chunks[1].width as usize).saturating_sub(30);
+            let items: Vec<ListItem> = self
+                .results
+                .iter()
+                .map(|c| {
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!("{:<12}", c.key), Style::default().fg(Color::Cyan)),
+                        Span::styled(
+                            format!("{:<8}", c.source.label()),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                        Span::styled(
+                            truncate(&c.summary, summary_width),
+                            Style::default().fg(Color::White),
+                        ),
+                    ]))
+                })
+                .collect();
+
+            let list = List::new(items)
+                .highlight_style(
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("> ");
+
+            let mut state = ListState::default();
+            state.select(Some(self.selected));
+            frame.render_stateful_widget(list, chunks[1], &mut state);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
+            Span::raw(": navigate  "),
+            Span::styled("Enter", Style::default().fg(Color::Green)),
+            Span::raw(": open  "),
+            Span::styled("Esc", Style::default().fg(Color::Red)),
+            Span::raw(": cancel"),
+        ]);
+        frame.render_widget(
+            Paragraph::new(help_text).alignment(Alignment::Center),
+            chunks[2],
+        );
+    }
+}
+
+impl Default for GoToIssuePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Score how well a candidate matches a lowercase query.
+///
+/// Key matches rank above summary matches, and prefix matches above
+/// substring matches. Falls back to a subsequence match on the key and
+/// summary. Returns `None` if the candidate does not match; an empty query
+/// matches everything.
+fn match_score(candidate: &IssueCandidate, query: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let key = candidate.key.to_lowercase();
+    let summary = candidate.summary.to_lowercase();
+
+    if key == query {
+        Some(400)
+    } else if key.starts_with(query) {
+        Some(300)
+    } else if key.contains(query) {
+        Some(200)
+    } else if summary.contains(query) {
+        Some(100)
+    } else if is_subsequence(&format!("{} {}", key, summary), query) {
+        Some(10)
+    } else {
+        None
+    }
+}
+
+/// Check whether all characters of `needle` appear in order in `haystack`.
+fn is_subsequence(haystack: &str, needle: &str) -> bool {
+    let mut chars = haystack.chars();
+    needle.chars().all(|n| chars.any(|h| h == n))
+}
+
+/// Check whether a string looks like an issue key (e.g. `PROJ-123`).
+fn is_issue_key(s: &str) -> bool {
+    let Some((project, number)) = s.split_once('-') else {
+        return false;
+    };
+    project
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && project
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Truncate a string to the given maximum number of characters.
+fn truncate(s: &str, max_len: usize) -> String {
+    if s.chars().count() <= max_len {
+        s.to_string()
+    } else {
+        let truncated: String = s.chars().take(max_len.saturating_sub(3)).collect();
+        format!("{}...", truncated)
+    }
+}
+
+/// Create a centered rectangle.
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width.min(area.width), height.min(area.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_query(picker: &mut GoToIssuePicker, query: &str) {
+        for c in query.chars() {
+            picker.handle_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    fn picker_with_local() -> GoToIssuePicker {
+        let mut picker = GoToIssuePicker::new();
+        picker.show(vec![
+            IssueCandidate::new("PROJ-12", "Fix login timeout", IssueSource::Recent),
+            IssueCandidate::new("PROJ-123", "Add dark mode", IssueSource::Local),
+            IssueCandidate::new("OTHER-1", "Login page redesign", IssueSource::Local),
+        ]);
+        picker
+    }
+
+    #[test]
+    fn test_empty_query_lists_local_in_order() {
+        let picker = picker_with_local();
+        let keys: Vec<&str> = picker.results().iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["PROJ-12", "PROJ-123", "OTHER-1"]);
+    }
+
+    #[test]
+    fn test_key_matches_rank_above_summary_matches() {
+        let mut picker = picker_with_local();
+        type_query(&mut picker, "proj-123");
+        assert_eq!(picker.results()[0].key, "PROJ-123");
+
+        let mut picker = picker_with_local();
+        type_query(&mut picker, "login");
+        let keys: Vec<&str> = picker.results().iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["PROJ-12", "OTHER-1"]);
+    }
+
+    #[test]
+    fn test_unknown_key_offered_directly() {
+        let mut picker = picker_with_local();
+        type_query(&mut picker, "abc-9");
+
+        assert_eq!(
+            picker.results()[0],
+            IssueCandidate::new("ABC-9", "", IssueSource::Key)
+        );
+        let action = picker.handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(action, Some(GoToIssueAction::Open("ABC-9".to_string())));
+        assert!(!picker.is_visible());
+    }
+
+    #[test]
+    fn test_remote_search_after_pause() {
+        let mut picker = picker_with_local();
+        type_query(&mut picker, "dark");
+
+        assert_eq!(picker.take_due_search(Instant::now()), None);
+        let later = Instant::now() + SEARCH_DELAY;
+        assert_eq!(picker.take_due_search(later), Some("dark".to_string()));
+        assert!(picker.is_loading());
+
+        picker.set_remote_results(
+            "dark",
+            vec![
+                IssueSuggestion {
+                    key: "PROJ-123".to_string(),
+                    summary_text: Some("Add dark mode".to_string()),
+                    summary: None,
+                    id: None,
+                },
+                IssueSuggestion {
+                    key: "PROJ-7".to_string(),
+                    summary_text: Some("Dark theme contrast".to_string()),
+                    summary: None,
+                    id: None,
+                },
+            ],
+        );
+
+        assert!(!picker.is_loading());
+        let keys: Vec<&str> = picker.results().iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["PROJ-123", "PROJ-7"]);
+        assert_eq!(picker.results()[1].source, IssueSource::Search);
+    }
+
+    #[test]
+    fn test_stale_remote_results_ignored() {
+        let mut picker = picker_with_local();
+        type_query(&mut picker, "da");
+        picker.take_due_search(Instant::now() + SEARCH_DELAY);
+        type_query(&mut picker, "rk");
+
+        picker.set_remote_results(
+            "da",
+            vec![IssueSuggestion {
+                key: "PROJ-9".to_string(),
+                summary_text: None,
+                summary: None,
+                id: None,
+            }],
+        );
+        assert!(picker.results().iter().all(|c| c.key != "PROJ-9"));
+    }
+
+    #[test]
+    fn test_navigation_and_cancel() {
+        let mut picker = picker_with_local();
+        picker.handle_input(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        picker.handle_input(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        picker.handle_input(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        let action = picker.handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(action, Some(GoToIssueAction::Open("OTHER-1".to_string())));
+
+        let mut picker = picker_with_local();
+        let action = picker.handle_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(action, Some(GoToIssueAction::Cancel));
+        assert!(!picker.is_visible());
+    }
+
+    #[test]
+    fn test_is_issue_key() {
+        assert!(is_issue_key("PROJ-123"));
+        assert!(is_issue_key("ab_2-1"));
+        assert!(!is_issue_key("PROJ-"));
+        assert!(!is_issue_key("123-4"));
+        assert!(!is_issue_key("login page"));
+    }
+}
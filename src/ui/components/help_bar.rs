@@ -19,7 +19,7 @@ use crate::events::{get_context_hints, KeyContext};
 pub fn render_context_help(frame: &mut Frame, area: Rect, context: KeyContext) {
     let hints = get_context_hints(context);
 
-    let spans = parse_hints_to_spans(hints);
+    let spans = parse_hints_to_spans(&hints);
     let line = Line::from(spans);
     let paragraph = Paragraph::new(line);
 
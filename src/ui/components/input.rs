@@ -142,23 +142,15 @@ impl TextInput {
                 true
             }
             // Backspace - delete character before cursor
-            (KeyCode::Backspace, _) => {
-                if self.cursor > 0 {
-                    self.cursor -= 1;
-                    self.value.remove(self.cursor);
-                    true
-                } else {
-                    false
-                }
+            (KeyCode::Backspace, _) if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.value.remove(self.cursor);
+                true
             }
             // Delete - delete character at cursor
-            (KeyCode::Delete, _) => {
-                if self.cursor < self.value.len() {
-                    self.value.remove(self.cursor);
-                    true
-                } else {
-                    false
-                }
+            (KeyCode::Delete, _) if self.cursor < self.value.len() => {
+                self.value.remove(self.cursor);
+                true
             }
             // Left arrow - move cursor left
             (KeyCode::Left, KeyModifiers::NONE) => {
@@ -195,30 +187,22 @@ impl TextInput {
                 false
             }
             // Ctrl+U - clear line
-            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
-                if !self.value.is_empty() {
-                    self.value.clear();
-                    self.cursor = 0;
-                    true
-                } else {
-                    false
-                }
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) if !self.value.is_empty() => {
+                self.value.clear();
+                self.cursor = 0;
+                true
             }
             // Ctrl+W - delete word before cursor
-            (KeyCode::Char('w'), KeyModifiers::CONTROL) => {
-                if self.cursor > 0 {
-                    let before = &self.value[..self.cursor];
-                    // Find the start of the previous word
-                    let word_start = before
-                        .rfind(|c: char| !c.is_alphanumeric())
-                        .map(|i| i + 1)
-                        .unwrap_or(0);
-                    self.value.replace_range(word_start..self.cursor, "");
-                    self.cursor = word_start;
-                    true
-                } else {
-                    false
-                }
+            (KeyCode::Char('w'), KeyModifiers::CONTROL) if self.cursor > 0 => {
+                let before = &self.value[..self.cursor];
+                // Find the start of the previous word
+                let word_start = before
+                    .rfind(|c: char| !c.is_alphanumeric())
+                    .map(|i| i + 1)
+                    .unwrap_or(0);
+                self.value.replace_range(word_start..self.cursor, "");
+                self.cursor = word_start;
+                true
             }
             _ => false,
         }
@@ -230,6 +214,19 @@ impl TextInput {
         self.cursor += 1;
     }
 
+    /// Insert text at the cursor position.
+    pub fn insert_str(&mut self, s: &str) {
+        for c in s.chars() {
+            self.insert_char(c);
+        }
+    }
+
+    /// Replace a range of the value, moving the cursor after the new text.
+    pub fn replace_range(&mut self, range: std::ops::Range<usize>, s: &str) {
+        self.value.replace_range(range.clone(), s);
+        self.cursor = range.start + s.len();
+    }
+
     /// Render the input field.
     ///
     /// # Arguments
@@ -6,12 +6,18 @@
 //! - Up/down arrows to cycle through history
 //! - Syntax hints displayed below input
 //! - Error message display for invalid JQL
+//! - `@alias` completion on Tab and a preview of the expanded query
+//! - Field, operator and value suggestions from the server's JQL
+//!   autocomplete data, accepted with Tab
+//! - Live validation of the query, underlining the error
 //! - Query execution on Enter
 //! - Cancel on Escape
 
 use std::collections::VecDeque;
+use std::ops::Range;
+use std::time::{Duration, Instant};
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::KeyEvent;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Position, Rect},
     style::{Color, Modifier, Style},
@@ -21,6 +27,24 @@ use ratatui::{
 };
 
 use super::TextInput;
+use crate::api::jql::{tokenize, Token, Value};
+use crate::api::types::{JqlAutocompleteData, JqlSuggestion};
+use crate::config::JqlAliases;
+use crate::events::{keymap, Action, KeyContext};
+
+/// Delay after typing before value suggestions are fetched.
+const SUGGEST_DELAY: Duration = Duration::from_millis(250);
+
+/// Delay after typing before the query is validated.
+const VALIDATE_DELAY: Duration = Duration::from_millis(500);
+
+/// Maximum number of suggestions listed at once.
+const MAX_VISIBLE_SUGGESTIONS: usize = 6;
+
+/// Operators offered for fields without autocomplete data.
+const DEFAULT_OPERATORS: &[&str] = &[
+    "=", "!=", "~", "!~", ">", ">=", "<", "<=", "in", "not in", "is", "is not",
+];
 
 /// Actions that can be returned from the JQL input.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -63,6 +87,221 @@ fn get_hint_for_input(input: &str) -> Option<&'static str> {
     None
 }
 
+/// Find the partial alias name being typed at the end of `before`, if any.
+///
+/// Returns the byte offset of the `@` and the name typed so far.
+fn alias_prefix(before: &str) -> Option<(usize, &str)> {
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+    let start = before.rfind('@')?;
+    let prefix = &before[start + 1..];
+    let in_word = before[..start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| is_name_char(c) || c == '.');
+    let in_string = before[..start].matches('"').count() % 2 == 1;
+    (prefix.chars().all(is_name_char) && !in_word && !in_string).then_some((start, prefix))
+}
+
+/// What a query expects next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expect {
+    /// A field to compare.
+    Field,
+    /// An operator for the field.
+    Operator(String),
+    /// A value for the field.
+    Value { field: String, in_list: bool },
+    /// A comma or the end of a value list.
+    ListSeparator(String),
+    /// `AND`, `OR` or `ORDER BY`.
+    Keyword,
+    /// `BY` after `ORDER`.
+    By,
+    /// A field to sort by.
+    SortField,
+    /// A sort direction.
+    Direction,
+    /// A comma before the next sort field.
+    SortSeparator,
+    /// Something the completion doesn't follow.
+    Unknown,
+}
+
+/// Work out what is expected after the given tokens.
+fn expectation(tokens: &[Token<'_>]) -> Expect {
+    let mut expect = Expect::Field;
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        expect = match expect {
+            Expect::Field | Expect::Keyword if token.is("order") => Expect::By,
+            Expect::Field if token == Token::Open || token.is("not") => Expect::Field,
+            Expect::Field => match token.text() {
+                Some(field) => Expect::Operator(field.to_string()),
+                None => Expect::Unknown,
+            },
+            Expect::Operator(field) => match token {
+                Token::Operator(_) => Expect::Value {
+                    field,
+                    in_list: false,
+                },
+                token if token.is("not") => Expect::Operator(field),
+                token if token.is("in") || token.is("is") || token.is("was") => Expect::Value {
+                    field,
+                    in_list: false,
+                },
+                _ => Expect::Unknown,
+            },
+            Expect::Value { field, in_list } => match token {
+                Token::Open if !in_list => Expect::Value {
+                    field,
+                    in_list: true,
+                },
+                Token::Word(_) | Token::Quoted(_) => {
+                    // Skip the arguments of a function
+                    if tokens.get(i + 1) == Some(&Token::Open) {
+                        let mut depth = 0;
+                        for (j, token) in tokens.iter().enumerate().skip(i + 1) {
+                            match token {
+                                Token::Open => depth += 1,
+                                Token::Close => depth -= 1,
+                                _ => {}
+                            }
+                            if depth == 0 {
+                                i = j;
+                                break;
+                            }
+                        }
+                        if depth != 0 {
+                            return Expect::Unknown;
+                        }
+                    }
+                    if in_list {
+                        Expect::ListSeparator(field)
+                    } else {
+                        Expect::Keyword
+                    }
+                }
+                _ => Expect::Unknown,
+            },
+            Expect::ListSeparator(field) => match token {
+                Token::Comma => Expect::Value {
+                    field,
+                    in_list: true,
+                },
+                Token::Close => Expect::Keyword,
+                _ => Expect::Unknown,
+            },
+            Expect::Keyword => match token {
+                token if token.is("and") || token.is("or") => Expect::Field,
+                Token::Close => Expect::Keyword,
+                _ => Expect::Unknown,
+            },
+            Expect::By if token.is("by") => Expect::SortField,
+            Expect::SortField if token.text().is_some() => Expect::Direction,
+            Expect::Direction | Expect::SortSeparator if token == Token::Comma => Expect::SortField,
+            Expect::Direction if token.is("asc") || token.is("desc") => Expect::SortSeparator,
+            _ => return Expect::Unknown,
+        };
+        i += 1;
+    }
+    expect
+}
+
+/// The completion context at the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Context<'a> {
+    /// What the query expects at the cursor.
+    expect: Expect,
+    /// The word typed so far.
+    partial: &'a str,
+    /// Byte range of the partial word.
+    range: Range<usize>,
+}
+
+/// Get the completion context at the end of `before`.
+fn context(before: &str) -> Context<'_> {
+    let tokens = tokenize(before);
+    let partial = tokens
+        .last()
+        .filter(|(token, range)| token.text().is_some() && range.end == before.len());
+    let (partial, range, count) = match partial {
+        Some((token, range)) => (token.text().unwrap_or(""), range.clone(), tokens.len() - 1),
+        None => ("", before.len()..before.len(), tokens.len()),
+    };
+    let tokens: Vec<Token<'_>> = tokens[..count].iter().map(|(token, _)| *token).collect();
+    Context {
+        expect: expectation(&tokens),
+        partial,
+        range,
+    }
+}
+
+/// Quote a value for JQL unless it is a plain word.
+fn quote_value(value: &str) -> String {
+    if value.starts_with('"') {
+        value.to_string()
+    } else {
+        Value::Word(value.to_string()).to_string()
+    }
+}
+
+/// Find the part of a query a server error refers to.
+///
+/// Uses the `character N` position given by syntax errors, or else the
+/// first quoted name in the message that appears in the query.
+fn error_span(query: &str, message: &str) -> Option<Range<usize>> {
+    let tokens = tokenize(query);
+    let character = message
+        .split("character ")
+        .nth(1)
+        .and_then(|rest| rest.split(|c: char| !c.is_ascii_digit()).next())
+        .and_then(|n| n.parse::<usize>().ok());
+    if let Some(character) = character {
+        let index = query
+            .char_indices()
+            .nth(character.saturating_sub(1))
+            .map_or(query.len(), |(i, _)| i);
+        // The token at the position, or the last one at the end of the query
+        return tokens
+            .iter()
+            .find(|(_, range)| range.end > index)
+            .or(tokens.last())
+            .map(|(_, range)| range.clone());
+    }
+    message
+        .split('\'')
+        .skip(1)
+        .step_by(2)
+        .filter(|name| !name.is_empty())
+        .find_map(|name| {
+            tokens.iter().find(|(token, _)| {
+                token
+                    .text()
+                    .is_some_and(|text| text.trim_matches('"').eq_ignore_ascii_case(name))
+            })
+        })
+        .map(|(_, range)| range.clone())
+}
+
+/// A completion offered in the suggestion list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Suggestion {
+    /// Text inserted for the suggestion.
+    text: String,
+    /// Description shown beside it.
+    detail: String,
+}
+
+impl Suggestion {
+    fn new(text: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            detail: detail.into(),
+        }
+    }
+}
+
 /// JQL input component for entering JIRA queries.
 pub struct JqlInput {
     /// The text input widget.
@@ -77,6 +316,30 @@ pub struct JqlInput {
     visible: bool,
     /// Error message to display.
     error: Option<String>,
+    /// Aliases that can be used in queries.
+    aliases: JqlAliases,
+    /// Aliases matching the last completion, listed in the hint line.
+    candidates: Vec<String>,
+    /// Fields, operators and functions from the server.
+    autocomplete: Option<JqlAutocompleteData>,
+    /// Whether the autocomplete data has been requested.
+    autocomplete_requested: bool,
+    /// Suggestions for the word at the cursor.
+    suggestions: Vec<Suggestion>,
+    /// Index of the selected suggestion.
+    selected: usize,
+    /// Byte range of the text a suggestion replaces.
+    replace_range: Range<usize>,
+    /// Field and partial value to suggest values for.
+    value_query: Option<(String, String)>,
+    /// When the value suggestions should be fetched.
+    suggest_due: Option<Instant>,
+    /// Fetched value suggestions and the field and value they are for.
+    value_suggestions: Option<((String, String), Vec<JqlSuggestion>)>,
+    /// When the query should be validated.
+    validate_due: Option<Instant>,
+    /// Errors the server found and the (expanded) query they are for.
+    validation: Option<(String, Vec<String>)>,
 }
 
 impl JqlInput {
@@ -92,6 +355,18 @@ impl JqlInput {
             pre_history_text: String::new(),
             visible: false,
             error: None,
+            aliases: JqlAliases::new(),
+            candidates: Vec::new(),
+            autocomplete: None,
+            autocomplete_requested: false,
+            suggestions: Vec::new(),
+            selected: 0,
+            replace_range: 0..0,
+            value_query: None,
+            suggest_due: None,
+            value_suggestions: None,
+            validate_due: None,
+            validation: None,
         }
     }
 
@@ -107,6 +382,11 @@ impl JqlInput {
         self.history = history.into_iter().take(Self::MAX_HISTORY).collect();
     }
 
+    /// Set the aliases that can be used in queries.
+    pub fn set_aliases(&mut self, aliases: JqlAliases) {
+        self.aliases = aliases;
+    }
+
     /// Get the current history as a vector.
     pub fn history(&self) -> Vec<String> {
         self.history.iter().cloned().collect()
@@ -119,12 +399,18 @@ impl JqlInput {
         self.history_index = None;
         self.pre_history_text.clear();
         self.error = None;
+        self.clear_suggestions();
+        self.value_suggestions = None;
+        self.validate_due = None;
+        self.validation = None;
     }
 
     /// Hide the JQL input.
     pub fn hide(&mut self) {
         self.visible = false;
         self.error = None;
+        self.suggest_due = None;
+        self.validate_due = None;
     }
 
     /// Check if the input is visible.
@@ -147,37 +433,181 @@ impl JqlInput {
         self.input.value()
     }
 
+    // ========================================================================
+    // Server autocomplete and validation
+    // ========================================================================
+
+    /// Check whether the autocomplete data should be fetched, marking it as
+    /// requested.
+    pub fn take_autocomplete_request(&mut self) -> bool {
+        let request = self.autocomplete.is_none() && !self.autocomplete_requested;
+        self.autocomplete_requested = true;
+        request
+    }
+
+    /// Set the fetched autocomplete data.
+    ///
+    /// `None` marks the fetch as failed, so it is retried the next time the
+    /// input opens.
+    pub fn set_autocomplete_data(&mut self, data: Option<JqlAutocompleteData>) {
+        self.autocomplete_requested = data.is_some();
+        self.autocomplete = data;
+        if self.visible {
+            self.update_suggestions();
+        }
+    }
+
+    /// Forget the autocomplete data, e.g. after switching to another server.
+    pub fn reset_autocomplete(&mut self) {
+        self.autocomplete = None;
+        self.autocomplete_requested = false;
+        self.value_suggestions = None;
+        self.validation = None;
+    }
+
+    /// Take the field and partial value to fetch suggestions for, if typing
+    /// has paused long enough.
+    pub fn take_due_suggestions(&mut self, now: Instant) -> Option<(String, String)> {
+        match self.suggest_due {
+            Some(due) if self.visible && now >= due => {
+                self.suggest_due = None;
+                self.value_query.clone()
+            }
+            _ => None,
+        }
+    }
+
+    /// Set the value suggestions fetched for a field and partial value.
+    ///
+    /// Suggestions for a value other than the one being typed are ignored.
+    pub fn set_value_suggestions(
+        &mut self,
+        field: &str,
+        value: &str,
+        suggestions: Vec<JqlSuggestion>,
+    ) {
+        let key = (field.to_string(), value.to_string());
+        if self.value_query.as_ref() != Some(&key) {
+            return;
+        }
+        self.value_suggestions = Some((key, suggestions));
+        self.update_suggestions();
+    }
+
+    /// Take the query to validate, with its aliases expanded, if typing has
+    /// paused long enough.
+    pub fn take_due_validation(&mut self, now: Instant) -> Option<String> {
+        match self.validate_due {
+            Some(due) if self.visible && now >= due => {
+                self.validate_due = None;
+                self.expanded_query()
+            }
+            _ => None,
+        }
+    }
+
+    /// Set the errors the server found in a query.
+    pub fn set_validation(&mut self, query: &str, errors: Vec<String>) {
+        self.validation = Some((query.to_string(), errors));
+    }
+
+    /// Get the errors the server found in the current query.
+    fn validation_errors(&self) -> &[String] {
+        match (&self.validation, self.expanded_query()) {
+            (Some((query, errors)), Some(expanded)) if *query == expanded => errors,
+            _ => &[],
+        }
+    }
+
+    /// Get the current query with its aliases expanded, unless it is empty
+    /// or uses unknown aliases.
+    fn expanded_query(&self) -> Option<String> {
+        let query = self.input.value().trim();
+        if query.is_empty() {
+            return None;
+        }
+        self.aliases.expand(query).ok()
+    }
+
+    /// Get the byte range of the input the first validation error refers
+    /// to.
+    ///
+    /// Error positions refer to the expanded query, so queries using aliases
+    /// are not underlined.
+    fn error_range(&self) -> Option<Range<usize>> {
+        let error = self.validation_errors().first()?;
+        let value = self.input.value();
+        let query = value.trim();
+        if self.expanded_query()? != query {
+            return None;
+        }
+        let offset = value.len() - value.trim_start().len();
+        let span = error_span(query, error)?;
+        Some(span.start + offset..span.end + offset)
+    }
+
     /// Handle keyboard input.
     ///
     /// Returns an action if one should be performed.
     pub fn handle_input(&mut self, key: KeyEvent) -> Option<JqlAction> {
-        // Clear error on any input
+        // Clear error and completions on any input
         self.error = None;
+        self.candidates.clear();
 
-        match (key.code, key.modifiers) {
-            // Enter - execute query
-            (KeyCode::Enter, KeyModifiers::NONE) => {
+        match keymap().action(KeyContext::JqlInput, key) {
+            // Execute query
+            Some(Action::Submit) => {
                 let query = self.input.value().trim().to_string();
                 if !query.is_empty() {
+                    // Keep the input open to fix unknown aliases or arguments
+                    if let Err(error) = self.aliases.expand(&query) {
+                        self.error = Some(error);
+                        return None;
+                    }
                     self.add_to_history(query.clone());
                     self.hide();
                     return Some(JqlAction::Execute(query));
                 }
                 None
             }
-            // Escape - cancel
-            (KeyCode::Esc, KeyModifiers::NONE) => {
+            // Cancel
+            Some(Action::Cancel) => {
                 self.hide();
                 Some(JqlAction::Cancel)
             }
-            // Up arrow - previous history entry
-            (KeyCode::Up, KeyModifiers::NONE) => {
+            // Previous history entry
+            Some(Action::HistoryPrev) => {
                 self.history_prev();
+                self.history_changed();
                 None
             }
-            // Down arrow - next history entry
-            (KeyCode::Down, KeyModifiers::NONE) => {
+            // Next history entry
+            Some(Action::HistoryNext) => {
                 self.history_next();
+                self.history_changed();
+                None
+            }
+            // Complete the alias before the cursor, or accept a suggestion
+            Some(Action::Complete) => {
+                if !self.complete_alias() {
+                    self.accept_suggestion();
+                }
+                None
+            }
+            // Select a suggestion
+            Some(Action::MoveDown) => {
+                if !self.suggestions.is_empty() {
+                    self.selected = (self.selected + 1) % self.suggestions.len();
+                }
+                None
+            }
+            Some(Action::MoveUp) => {
+                if !self.suggestions.is_empty() {
+                    self.selected = self
+                        .selected
+                        .checked_sub(1)
+                        .unwrap_or(self.suggestions.len() - 1);
+                }
                 None
             }
             // Any other input - delegate to TextInput
@@ -186,7 +616,9 @@ impl JqlInput {
                 if modified {
                     // Reset history navigation when user types
                     self.history_index = None;
+                    self.query_changed();
                 }
+                self.update_suggestions();
                 None
             }
         }
@@ -239,6 +671,190 @@ impl JqlInput {
         }
     }
 
+    /// Handle a history entry replacing the query.
+    ///
+    /// Suggestions are hidden until the user edits the query.
+    fn history_changed(&mut self) {
+        self.clear_suggestions();
+        if self.history_index.is_some() || !self.input.value().is_empty() {
+            self.query_changed();
+        }
+    }
+
+    /// Schedule validation of the changed query.
+    fn query_changed(&mut self) {
+        self.validate_due = self
+            .expanded_query()
+            .map(|_| Instant::now() + VALIDATE_DELAY);
+    }
+
+    /// Complete the alias name being typed before the cursor.
+    ///
+    /// A single match is completed in full, followed by `(` if it takes
+    /// arguments. Several matches are completed to their common prefix and
+    /// listed in the hint line. Returns false if no alias is being typed.
+    fn complete_alias(&mut self) -> bool {
+        let before = &self.input.value()[..self.input.cursor()];
+        let Some((_, prefix)) = alias_prefix(before) else {
+            return false;
+        };
+        let matches = self.aliases.completions(prefix);
+        let completion = match matches.as_slice() {
+            [] => return true,
+            [alias] => {
+                let suffix = if alias.params.is_empty() { " " } else { "(" };
+                format!("{}{}", &alias.name[prefix.len()..], suffix)
+            }
+            [first, rest @ ..] => {
+                let common = rest.iter().fold(first.name, |common, alias| {
+                    let len = common
+                        .char_indices()
+                        .zip(alias.name.chars())
+                        .take_while(|((_, a), b)| a == b)
+                        .last()
+                        .map_or(0, |((i, a), _)| i + a.len_utf8());
+                    &common[..len]
+                });
+                self.candidates = matches.iter().map(|alias| alias.usage()).collect();
+                common[prefix.len()..].to_string()
+            }
+        };
+        self.input.insert_str(&completion);
+        self.history_index = None;
+        self.query_changed();
+        self.update_suggestions();
+        true
+    }
+
+    /// Replace the word at the cursor with the selected suggestion.
+    fn accept_suggestion(&mut self) {
+        let Some(suggestion) = self.suggestions.get(self.selected) else {
+            return;
+        };
+        let text = format!("{} ", suggestion.text);
+        self.input.replace_range(self.replace_range.clone(), &text);
+        self.history_index = None;
+        self.query_changed();
+        self.update_suggestions();
+    }
+
+    /// Hide the suggestion list.
+    fn clear_suggestions(&mut self) {
+        self.suggestions.clear();
+        self.selected = 0;
+        self.value_query = None;
+        self.suggest_due = None;
+    }
+
+    /// Rebuild the suggestions for the word at the cursor.
+    ///
+    /// The list shows while a word is being typed and after a field or
+    /// operator, and schedules fetching value suggestions from the server.
+    fn update_suggestions(&mut self) {
+        let value = self.input.value();
+        let context = context(&value[..self.input.cursor()]);
+
+        let value_query = match &context.expect {
+            Expect::Value { field, .. } if self.autocomplete.is_some() => Some((
+                field.trim_matches('"').to_string(),
+                context.partial.trim_matches('"').to_string(),
+            )),
+            _ => None,
+        };
+        if value_query != self.value_query {
+            self.suggest_due = value_query.as_ref().map(|_| Instant::now() + SUGGEST_DELAY);
+            self.value_query = value_query;
+        }
+
+        let show = !context.partial.is_empty()
+            || matches!(context.expect, Expect::Operator(_) | Expect::Value { .. });
+        let suggestions = if show {
+            self.suggestions_for(&context)
+        } else {
+            Vec::new()
+        };
+        if suggestions != self.suggestions {
+            self.selected = 0;
+        }
+        self.suggestions = suggestions;
+        self.replace_range = context.range;
+    }
+
+    /// Get the suggestions matching the word at the cursor.
+    fn suggestions_for(&self, context: &Context<'_>) -> Vec<Suggestion> {
+        let prefix = context.partial.trim_start_matches('"').to_lowercase();
+        let matches = |text: &str| {
+            let text = text.trim_start_matches('"').to_lowercase();
+            text.starts_with(&prefix) && text != prefix
+        };
+        let keywords = |keywords: &[&str]| {
+            keywords
+                .iter()
+                .filter(|keyword| matches(keyword))
+                .map(|keyword| Suggestion::new(*keyword, ""))
+                .collect()
+        };
+
+        match &context.expect {
+            Expect::Field | Expect::SortField => self
+                .autocomplete
+                .iter()
+                .flat_map(|data| &data.visible_field_names)
+                .filter(|field| matches(&field.value))
+                .map(|field| Suggestion::new(&field.value, &field.display_name))
+                .collect(),
+            Expect::Operator(field) => {
+                let operators = self
+                    .autocomplete
+                    .as_ref()
+                    .and_then(|data| data.field(field))
+                    .map(|field| &field.operators)
+                    .filter(|operators| !operators.is_empty());
+                match operators {
+                    Some(operators) => operators
+                        .iter()
+                        .filter(|operator| matches(operator))
+                        .map(|operator| Suggestion::new(operator, ""))
+                        .collect(),
+                    None => keywords(DEFAULT_OPERATORS),
+                }
+            }
+            Expect::Value { .. } => {
+                let values = self
+                    .value_suggestions
+                    .as_ref()
+                    .filter(|(key, _)| Some(key) == self.value_query.as_ref())
+                    .map(|(_, values)| values.as_slice())
+                    .unwrap_or_default();
+                values
+                    .iter()
+                    .map(|value| Suggestion::new(quote_value(&value.value), value.display_text()))
+                    .filter(|suggestion| suggestion.text.trim_matches('"') != context.partial)
+                    .chain(
+                        self.autocomplete
+                            .iter()
+                            .flat_map(|data| &data.visible_function_names)
+                            .filter(|function| matches(&function.value))
+                            .map(|function| Suggestion::new(&function.value, "function")),
+                    )
+                    .collect()
+            }
+            Expect::Keyword => keywords(&["AND", "OR", "ORDER BY"]),
+            Expect::By => keywords(&["BY"]),
+            Expect::Direction => keywords(&["ASC", "DESC"]),
+            Expect::ListSeparator(_) | Expect::SortSeparator | Expect::Unknown => Vec::new(),
+        }
+    }
+
+    /// Get the query with its aliases expanded, if it uses any.
+    fn expansion(&self) -> Option<String> {
+        let query = self.input.value().trim();
+        self.aliases
+            .expand(query)
+            .ok()
+            .filter(|expanded| expanded != query)
+    }
+
     /// Add a query to the history.
     fn add_to_history(&mut self, query: String) {
         // Remove duplicate if exists
@@ -260,8 +876,10 @@ impl JqlInput {
         }
 
         // Calculate panel size (centered, ~70% width)
+        let suggestion_rows = self.suggestions.len().min(MAX_VISIBLE_SUGGESTIONS) as u16;
         let panel_width = (area.width as f32 * 0.70).min(100.0) as u16;
-        let panel_height = 5; // Input (3) + hint/error (1) + padding (1)
+        // Input (3) + hint/error (1) + padding (1) + suggestions
+        let panel_height = 5 + suggestion_rows;
         let panel_x = (area.width.saturating_sub(panel_width)) / 2;
         let panel_y = (area.height.saturating_sub(panel_height)) / 2;
 
@@ -270,12 +888,13 @@ impl JqlInput {
         // Clear the background
         frame.render_widget(Clear, panel_area);
 
-        // Create layout for input and hint
+        // Create layout for input, hint and suggestions
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Input box
-                Constraint::Length(1), // Hint/error line
+                Constraint::Length(3),               // Input box
+                Constraint::Length(1),               // Hint/error line
+                Constraint::Length(suggestion_rows), // Suggestions
             ])
             .split(panel_area);
 
@@ -295,9 +914,25 @@ impl JqlInput {
         let input_inner = input_block.inner(chunks[0]);
         frame.render_widget(input_block, chunks[0]);
 
-        // Render the input text with colon prefix
-        let display = format!(":{}", self.input.value());
-        let input_paragraph = Paragraph::new(display).style(Style::default().fg(Color::White));
+        // Render the input text with colon prefix, underlining the part a
+        // validation error refers to
+        let value = self.input.value();
+        let mut spans = vec![Span::raw(":")];
+        match self.error_range() {
+            Some(range) => {
+                spans.push(Span::raw(&value[..range.start]));
+                spans.push(Span::styled(
+                    &value[range.clone()],
+                    Style::default()
+                        .fg(Color::Red)
+                        .add_modifier(Modifier::UNDERLINED),
+                ));
+                spans.push(Span::raw(&value[range.end..]));
+            }
+            None => spans.push(Span::raw(value)),
+        }
+        let input_paragraph =
+            Paragraph::new(Line::from(spans)).style(Style::default().fg(Color::White));
         frame.render_widget(input_paragraph, input_inner);
 
         // Set cursor position (account for ':' prefix)
@@ -312,6 +947,21 @@ impl JqlInput {
                 error.as_str(),
                 Style::default().fg(Color::Red),
             ))
+        } else if !self.candidates.is_empty() {
+            Line::from(Span::styled(
+                self.candidates.join("  "),
+                Style::default().fg(Color::Cyan),
+            ))
+        } else if let Some(error) = self.validation_errors().first() {
+            Line::from(Span::styled(
+                error.as_str(),
+                Style::default().fg(Color::Red),
+            ))
+        } else if let Some(expanded) = self.expansion() {
+            Line::from(vec![
+                Span::styled("→ ", Style::default().fg(Color::Cyan)),
+                Span::styled(expanded, Style::default().fg(Color::DarkGray)),
+            ])
         } else if let Some(hint) = get_hint_for_input(self.input.value()) {
             Line::from(Span::styled(hint, Style::default().fg(Color::Yellow)))
         } else {
@@ -321,12 +971,40 @@ impl JqlInput {
                     Style::default().fg(Color::DarkGray),
                 ),
                 Span::raw(" "),
-                Span::styled("↑↓:history", Style::default().fg(Color::Cyan)),
+                Span::styled("↑↓:history Tab:complete", Style::default().fg(Color::Cyan)),
             ])
         };
 
         let hint_paragraph = Paragraph::new(hint_line);
         frame.render_widget(hint_paragraph, chunks[1]);
+
+        // Render suggestions, scrolled to keep the selection visible
+        if suggestion_rows > 0 {
+            let first = (self.selected + 1).saturating_sub(MAX_VISIBLE_SUGGESTIONS);
+            let lines: Vec<Line> = self
+                .suggestions
+                .iter()
+                .enumerate()
+                .skip(first)
+                .take(MAX_VISIBLE_SUGGESTIONS)
+                .map(|(i, suggestion)| {
+                    let style = if i == self.selected {
+                        Style::default().fg(Color::Black).bg(Color::Cyan)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+                    let mut spans = vec![Span::styled(format!(" {} ", suggestion.text), style)];
+                    if !suggestion.detail.is_empty() && suggestion.detail != suggestion.text {
+                        spans.push(Span::styled(
+                            format!(" {}", suggestion.detail),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+                    Line::from(spans)
+                })
+                .collect();
+            frame.render_widget(Paragraph::new(lines), chunks[2]);
+        }
     }
 }
 
@@ -339,6 +1017,7 @@ impl Default for JqlInput {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
 
     #[test]
     fn test_new() {
@@ -604,4 +1283,252 @@ mod tests {
         assert!(input.history_index.is_none());
         assert!(input.value().is_empty());
     }
+
+    fn alias_input() -> JqlInput {
+        let mut aliases = JqlAliases::new();
+        aliases.insert("mine", "assignee = currentUser()");
+        aliases.insert("my-bugs", "@mine AND type = Bug");
+        aliases.insert("sprint(team)", "sprint in openSprints() AND team = $team");
+        let mut input = JqlInput::new();
+        input.set_aliases(aliases);
+        input.show();
+        input
+    }
+
+    fn type_text(input: &mut JqlInput, text: &str) {
+        for c in text.chars() {
+            input.handle_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    #[test]
+    fn test_complete_alias() {
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        let mut input = alias_input();
+
+        // Several matches complete to their common prefix and are listed
+        type_text(&mut input, "project = A AND @m");
+        input.handle_input(tab);
+        assert_eq!(input.value(), "project = A AND @m");
+        assert_eq!(input.candidates, vec!["@mine", "@my-bugs"]);
+
+        type_text(&mut input, "i");
+        assert!(input.candidates.is_empty());
+        input.handle_input(tab);
+        assert_eq!(input.value(), "project = A AND @mine ");
+
+        // Macros are completed with an opening parenthesis
+        type_text(&mut input, "AND @sp");
+        input.handle_input(tab);
+        assert_eq!(input.value(), "project = A AND @mine AND @sprint(");
+    }
+
+    #[test]
+    fn test_complete_ignores_emails_and_strings() {
+        assert_eq!(alias_prefix("x AND @mi"), Some((6, "mi")));
+        assert_eq!(alias_prefix("@"), Some((0, "")));
+        assert_eq!(alias_prefix("assignee = me@mi"), None);
+        assert_eq!(alias_prefix("summary ~ \"@mi"), None);
+        assert_eq!(alias_prefix("@mine AND x"), None);
+    }
+
+    #[test]
+    fn test_submit_checks_aliases() {
+        let mut input = alias_input();
+        type_text(&mut input, "@nope");
+
+        let action = input.handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(action.is_none());
+        assert!(input.is_visible());
+        assert_eq!(input.error.as_deref(), Some("unknown alias @nope"));
+
+        // The query is executed with its aliases, as typed
+        input.input.set_value("@my-bugs");
+        assert_eq!(
+            input.expansion().as_deref(),
+            Some("(assignee = currentUser()) AND type = Bug")
+        );
+        let action = input.handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(action, Some(JqlAction::Execute("@my-bugs".to_string())));
+        assert_eq!(input.history(), vec!["@my-bugs"]);
+    }
+
+    fn autocomplete_data() -> JqlAutocompleteData {
+        serde_json::from_str(
+            r#"{
+                "visibleFieldNames": [
+                    {"value": "status", "displayName": "Status", "operators": ["=", "!=", "in", "not in"]},
+                    {"value": "summary", "displayName": "Summary", "operators": ["~", "!~"]},
+                    {"value": "\"Story Points\"", "displayName": "Story Points"}
+                ],
+                "visibleFunctionNames": [{"value": "currentUser()", "displayName": "currentUser()"}]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn texts(input: &JqlInput) -> Vec<&str> {
+        input.suggestions.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_context() {
+        let expect = |before: &str| context(before).expect;
+        let value = |field: &str, in_list| Expect::Value {
+            field: field.to_string(),
+            in_list,
+        };
+
+        assert_eq!(expect(""), Expect::Field);
+        assert_eq!(expect("sta"), Expect::Field);
+        assert_eq!(context("project = A AND sta").partial, "sta");
+        assert_eq!(expect("status "), Expect::Operator("status".to_string()));
+        assert_eq!(
+            expect("status not "),
+            Expect::Operator("status".to_string())
+        );
+        assert_eq!(expect("status = "), value("status", false));
+        assert_eq!(expect("status in (Open, "), value("status", true));
+        assert_eq!(expect("status in (Open"), value("status", true));
+        assert_eq!(expect("status in (Open) "), Expect::Keyword);
+        assert_eq!(expect("assignee = currentUser() "), Expect::Keyword);
+        assert_eq!(
+            expect("\"Story Points\" > "),
+            value("\"Story Points\"", false)
+        );
+        assert_eq!(expect("summary ~ \"a b\" OR (NOT "), Expect::Field);
+        assert_eq!(expect("status = Open ORDER "), Expect::By);
+        assert_eq!(expect("status = Open ORDER BY "), Expect::SortField);
+        assert_eq!(expect("ORDER BY created "), Expect::Direction);
+        assert_eq!(expect("status = = "), Expect::Unknown);
+
+        // A partial quoted value
+        let context = context("status = \"In Pr");
+        assert_eq!(context.partial, "\"In Pr");
+        assert_eq!(context.range, 9..15);
+    }
+
+    #[test]
+    fn test_field_and_operator_suggestions() {
+        let mut input = JqlInput::new();
+        input.show();
+        // Operators are offered without server data
+        type_text(&mut input, "status ");
+        assert!(texts(&input).contains(&"is not"));
+
+        input.set_autocomplete_data(Some(autocomplete_data()));
+        assert_eq!(texts(&input), vec!["=", "!=", "in", "not in"]);
+
+        input.input.clear();
+        type_text(&mut input, "s");
+        assert_eq!(texts(&input), vec!["status", "summary", "\"Story Points\""]);
+
+        // Ctrl+n selects, Tab replaces the word and moves on to operators
+        input.handle_input(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL));
+        input.handle_input(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(input.value(), "summary ");
+        assert_eq!(texts(&input), vec!["~", "!~"]);
+
+        // Nothing is listed for an empty query
+        input.input.clear();
+        input.handle_input(KeyEvent::new(KeyCode::End, KeyModifiers::NONE));
+        assert!(input.suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_value_suggestions() {
+        let mut input = JqlInput::new();
+        input.set_autocomplete_data(Some(autocomplete_data()));
+        input.show();
+        type_text(&mut input, "status = \"In");
+        assert_eq!(input.take_due_suggestions(Instant::now()), None);
+        let later = Instant::now() + SUGGEST_DELAY;
+        let request = input.take_due_suggestions(later).unwrap();
+        assert_eq!(request, ("status".to_string(), "In".to_string()));
+
+        // Results for another value are ignored
+        let suggestion = |value: &str| JqlSuggestion {
+            value: value.to_string(),
+            display_name: format!("<b>In</b>{}", &value[2..]),
+        };
+        input.set_value_suggestions("status", "I", vec![suggestion("Invalid")]);
+        assert!(input.suggestions.is_empty());
+
+        input.set_value_suggestions(
+            "status",
+            "In",
+            vec![suggestion("In Progress"), suggestion("In Review")],
+        );
+        assert_eq!(texts(&input), vec!["\"In Progress\"", "\"In Review\""]);
+        assert_eq!(input.suggestions[0].detail, "In Progress");
+
+        input.handle_input(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(input.value(), "status = \"In Progress\" ");
+        assert_eq!(texts(&input), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_live_validation() {
+        let mut input = alias_input();
+        type_text(&mut input, "status = Opne");
+        assert_eq!(input.take_due_validation(Instant::now()), None);
+        let later = Instant::now() + VALIDATE_DELAY;
+        assert_eq!(
+            input.take_due_validation(later).as_deref(),
+            Some("status = Opne")
+        );
+
+        input.set_validation(
+            "status = Opne",
+            vec!["The value 'Opne' does not exist for the field 'status'.".to_string()],
+        );
+        assert_eq!(input.validation_errors().len(), 1);
+        assert_eq!(input.error_range(), Some(9..13));
+
+        // Errors for an older query no longer apply
+        type_text(&mut input, "n");
+        assert!(input.validation_errors().is_empty());
+
+        // Queries using aliases are validated expanded, without underlining
+        input.input.set_value("@mine AND x");
+        input.set_validation(
+            "(assignee = currentUser()) AND x",
+            vec!["Error in the JQL Query (line 1, character 33)".to_string()],
+        );
+        assert_eq!(input.validation_errors().len(), 1);
+        assert_eq!(input.error_range(), None);
+    }
+
+    #[test]
+    fn test_error_span() {
+        let query = "status = Open AND foo bar";
+        assert_eq!(
+            error_span(
+                query,
+                "Error in the JQL Query: Expecting operator but got 'bar'. (line 1, character 23)"
+            ),
+            Some(22..25)
+        );
+        // A position past the end marks the last token
+        assert_eq!(
+            error_span("status =", "Expecting a value (line 1, character 9)"),
+            Some(7..8)
+        );
+        assert_eq!(
+            error_span(
+                query,
+                "Field 'foo' does not exist or you do not have permission."
+            ),
+            Some(18..21)
+        );
+        assert_eq!(error_span(query, "Something went wrong"), None);
+    }
+
+    #[test]
+    fn test_quote_value() {
+        assert_eq!(quote_value("PROJ-1"), "PROJ-1");
+        assert_eq!(quote_value("In Progress"), "\"In Progress\"");
+        assert_eq!(quote_value("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote_value("empty"), "\"empty\"");
+    }
 }
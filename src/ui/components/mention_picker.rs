@@ -0,0 +1,327 @@
+//! Inline @mention picker for text editors.
+//!
+//! Typing `@` at the start of a word in a mention-enabled [`TextEditor`]
+//! opens this picker below the cursor. The text typed after the `@` filters
+//! the users found so far, and a JIRA user search runs once typing pauses.
+//! The picked user is inserted as a `[@Name](mention:accountId)` link, which
+//! is converted to an ADF `mention` node when the text is saved.
+//!
+//! [`TextEditor`]: super::TextEditor
+
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::api::types::User;
+
+/// How long typing must pause before the user search runs.
+const SEARCH_DELAY: Duration = Duration::from_millis(250);
+
+/// Maximum number of users listed.
+const MAX_RESULTS: usize = 8;
+
+/// Inline user picker for @mentions.
+#[derive(Debug, Clone)]
+pub struct MentionPicker {
+    /// Position of the `@` in the editor (line, character column).
+    anchor: (usize, usize),
+    /// Text typed after the `@`.
+    query: String,
+    /// Users found by the last search.
+    users: Vec<User>,
+    /// Currently selected index into the matching users.
+    selected: usize,
+    /// Whether a search is running.
+    loading: bool,
+    /// When the next search should run (set while typing).
+    search_due: Option<Instant>,
+    /// Whether the last search failed.
+    failed: bool,
+}
+
+impl MentionPicker {
+    /// Create a picker for an `@` at the given editor position.
+    pub fn new(anchor: (usize, usize)) -> Self {
+        Self {
+            anchor,
+            query: String::new(),
+            users: Vec::new(),
+            selected: 0,
+            loading: false,
+            search_due: None,
+            failed: false,
+        }
+    }
+
+    /// Get the position of the `@` in the editor.
+    pub fn anchor(&self) -> (usize, usize) {
+        self.anchor
+    }
+
+    /// Get the text typed after the `@`.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Update the text typed after the `@`, scheduling a search if it changed.
+    pub fn set_query(&mut self, query: &str) {
+        if query == self.query {
+            return;
+        }
+        self.query = query.to_string();
+        self.selected = 0;
+        self.failed = false;
+        self.search_due = if self.query.trim().is_empty() {
+            None
+        } else {
+            Some(Instant::now() + SEARCH_DELAY)
+        };
+    }
+
+    /// Take the query to search for, if typing has paused long enough.
+    pub fn take_due_search(&mut self, now: Instant) -> Option<String> {
+        match self.search_due {
+            Some(due) if now >= due => {
+                self.search_due = None;
+                self.loading = true;
+                Some(self.query.trim().to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Set the results of a user search.
+    ///
+    /// Results for a query other than the current one are ignored.
+    pub fn set_results(&mut self, query: &str, users: Vec<User>) {
+        if query != self.query.trim() {
+            return;
+        }
+        self.loading = false;
+        self.users = users.into_iter().filter(|u| u.active).collect();
+        self.selected = 0;
+    }
+
+    /// Mark the search for a query as failed.
+    pub fn set_search_failed(&mut self, query: &str) {
+        if query == self.query.trim() {
+            self.loading = false;
+            self.failed = true;
+        }
+    }
+
+    /// Get the users matching the current query.
+    ///
+    /// Results of an earlier search are narrowed down locally while the
+    /// search for the current query is pending.
+    pub fn matches(&self) -> Vec<&User> {
+        let query = self.query.trim().to_lowercase();
+        self.users
+            .iter()
+            .filter(|u| {
+                u.display_name.to_lowercase().contains(&query)
+                    || u.email_address
+                        .as_deref()
+                        .is_some_and(|e| e.to_lowercase().contains(&query))
+            })
+            .take(MAX_RESULTS)
+            .collect()
+    }
+
+    /// Get the selected user.
+    pub fn selected_user(&self) -> Option<&User> {
+        self.matches().get(self.selected).copied()
+    }
+
+    /// Move selection down.
+    pub fn select_next(&mut self) {
+        let count = self.matches().len();
+        if count > 0 {
+            self.selected = (self.selected + 1) % count;
+        }
+    }
+
+    /// Move selection up.
+    pub fn select_prev(&mut self) {
+        let count = self.matches().len();
+        if count > 0 {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+
+    /// Render the picker below (or above) the cursor.
+    ///
+    /// `cursor` is the screen position of the editor cursor; `bounds` limits
+    /// where the popup may be drawn.
+    pub fn render(&self, frame: &mut Frame, cursor: Position, bounds: Rect) {
+        let matches = self.matches();
+        let rows = matches.len().max(1) as u16;
+        let width = 44.min(bounds.width);
+        let height = (rows + 2).min(bounds.height);
+        if width < 10 || height < 3 {
+            return;
+        }
+
+        let below = cursor.y + 1;
+        let y = if below + height <= bounds.y + bounds.height {
+            below
+        } else {
+            cursor.y.saturating_sub(height).max(bounds.y)
+        };
+        let x = cursor
+            .x
+            .saturating_sub(1)
+            .min(bounds.x + bounds.width - width)
+            .max(bounds.x);
+        let area = Rect::new(x, y, width, height);
+
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" @{} ", self.query),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Span::styled(
+                " Enter: mention  Esc: close ",
+                Style::default().fg(Color::DarkGray),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        if matches.is_empty() {
+            let hint = if self.query.trim().is_empty() {
+                "Type a name to search"
+            } else if self.loading || self.search_due.is_some() {
+                "Searching..."
+            } else if self.failed {
+                "User search failed"
+            } else {
+                "No matching users"
+            };
+            let paragraph = Paragraph::new(Line::from(Span::styled(
+                hint,
+                Style::default().fg(Color::DarkGray),
+            )))
+            .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|user| {
+                let mut spans = vec![Span::raw(user.display_name.clone())];
+                if let Some(email) = user.email_address.as_deref().filter(|e| !e.is_empty()) {
+                    spans.push(Span::styled(
+                        format!("  {}", email),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+}
+
+/// Format a user as mention markup for the editor.
+///
+/// Brackets are dropped from the name so the link text stays intact.
+pub fn mention_markup(user: &User) -> String {
+    let name: String = user
+        .display_name
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']'))
+        .collect();
+    format!("[@{}](mention:{})", name, user.account_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(account_id: &str, name: &str) -> User {
+        User {
+            account_id: account_id.to_string(),
+            display_name: name.to_string(),
+            email_address: None,
+            active: true,
+            avatar_urls: None,
+        }
+    }
+
+    #[test]
+    fn test_search_runs_after_typing_pauses() {
+        let mut picker = MentionPicker::new((0, 0));
+        assert_eq!(picker.take_due_search(Instant::now()), None);
+
+        picker.set_query("jo");
+        assert_eq!(picker.take_due_search(Instant::now()), None);
+        let later = Instant::now() + SEARCH_DELAY;
+        assert_eq!(picker.take_due_search(later), Some("jo".to_string()));
+        assert_eq!(picker.take_due_search(later), None);
+    }
+
+    #[test]
+    fn test_results_are_filtered_by_query() {
+        let mut picker = MentionPicker::new((0, 0));
+        picker.set_query("j");
+        picker.set_results("j", vec![user("1", "Jane Doe"), user("2", "John Roe")]);
+        assert_eq!(picker.matches().len(), 2);
+
+        // Narrowed locally until the next search returns
+        picker.set_query("joh");
+        let matches = picker.matches();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].account_id, "2");
+
+        // Stale results are ignored
+        picker.set_results("j", vec![user("3", "Johan")]);
+        assert_eq!(picker.selected_user().unwrap().account_id, "2");
+    }
+
+    #[test]
+    fn test_inactive_users_are_skipped() {
+        let mut picker = MentionPicker::new((0, 0));
+        picker.set_query("a");
+        let mut inactive = user("1", "Ann");
+        inactive.active = false;
+        picker.set_results("a", vec![inactive, user("2", "Alan")]);
+        assert_eq!(picker.matches().len(), 1);
+    }
+
+    #[test]
+    fn test_selection_wraps() {
+        let mut picker = MentionPicker::new((0, 0));
+        picker.set_query("a");
+        picker.set_results("a", vec![user("1", "Ann"), user("2", "Alan")]);
+
+        picker.select_prev();
+        assert_eq!(picker.selected_user().unwrap().account_id, "2");
+        picker.select_next();
+        assert_eq!(picker.selected_user().unwrap().account_id, "1");
+    }
+
+    #[test]
+    fn test_mention_markup() {
+        assert_eq!(
+            mention_markup(&user("abc:123", "Jane [QA] Doe")),
+            "[@Jane QA Doe](mention:abc:123)"
+        );
+    }
+}
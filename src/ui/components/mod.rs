@@ -4,10 +4,13 @@
 #![allow(dead_code)]
 
 mod assignee_picker;
+mod clone_options;
 mod command_palette;
 mod comments;
+mod create_fields;
 mod dropdown;
 mod external_editor;
+mod go_to_issue;
 mod help_bar;
 mod input;
 mod issue_search_picker;
@@ -15,6 +18,7 @@ mod jql_input;
 mod link_type_picker;
 mod linked_issues;
 mod loading;
+mod mention_picker;
 mod modal;
 mod multiselect;
 mod notification;
@@ -22,16 +26,23 @@ mod priority_picker;
 mod profile_picker;
 mod saved_filters;
 mod search_bar;
+mod tab_bar;
 mod table;
 mod tag_editor;
+mod template_picker;
 mod text_editor;
+mod theme_picker;
 mod transition_picker;
+mod watchers;
 
 pub use assignee_picker::{AssigneeAction, AssigneePicker};
+pub use clone_options::{CloneOptionsAction, CloneOptionsDialog};
 pub use command_palette::{CommandPalette, CommandPaletteAction};
 pub use comments::{CommentAction, CommentsPanel};
+pub use create_fields::{CreateFieldsAction, CreateFieldsPanel};
 pub use dropdown::{Dropdown, DropdownAction, DropdownItem};
-pub use external_editor::ExternalEditor;
+pub use external_editor::{ExternalEditRequest, ExternalEditTarget, ExternalEditor};
+pub use go_to_issue::{GoToIssueAction, GoToIssuePicker, IssueCandidate, IssueSource};
 pub use help_bar::render_context_help;
 pub use input::{InputMode, TextInput};
 pub use issue_search_picker::{IssueSearchPicker, IssueSearchPickerAction};
@@ -46,6 +57,10 @@ pub use priority_picker::{PriorityAction, PriorityPicker};
 pub use profile_picker::{ProfilePicker, ProfilePickerAction};
 pub use saved_filters::{SavedFiltersAction, SavedFiltersDialog};
 pub use search_bar::{highlight_text, render_search_bar, QuickSearch};
+pub use tab_bar::render_tab_bar;
 pub use tag_editor::{TagAction, TagEditor};
-pub use text_editor::TextEditor;
+pub use template_picker::{TemplatePicker, TemplatePickerAction};
+pub use text_editor::{init_vim_editing, TextEditor};
+pub use theme_picker::{ThemePicker, ThemePickerAction};
 pub use transition_picker::{TransitionAction, TransitionPicker};
+pub use watchers::{WatcherAction, WatcherManager};
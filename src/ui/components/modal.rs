@@ -353,7 +353,14 @@ impl ConfirmDialog {
         }
 
         let dialog_width = (area.width * 50 / 100).clamp(40, 60);
-        let dialog_height = 8;
+        // Border and margin (4) + message lines + buttons (1), at least 3 message lines
+        let text_width = usize::from(dialog_width.saturating_sub(4)).max(1);
+        let message_lines: usize = self
+            .message
+            .lines()
+            .map(|line| (line.chars().count() + text_width - 1).max(text_width) / text_width)
+            .sum();
+        let dialog_height = (message_lines.max(3) as u16 + 5).min(area.height);
 
         let dialog_area = centered_rect(area, dialog_width, dialog_height);
 
@@ -63,6 +63,11 @@ impl MultiSelect {
         }
     }
 
+    /// Set the widget title.
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
     /// Set the items to display.
     pub fn set_items(&mut self, items: Vec<SelectItem>) {
         self.items = items;
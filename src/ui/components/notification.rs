@@ -236,11 +236,11 @@ impl NotificationManager {
             .iter()
             .map(|n| {
                 let text_len = n.message.len() + 2; // +2 for icon and space
-                let lines_needed = if inner_width > 0 {
-                    ((text_len + inner_width - 1) / inner_width) as u16 // Ceiling division
-                } else {
-                    1
-                };
+
+                // Ceiling division; a zero-width area still needs one line
+                let lines_needed = (text_len + inner_width - 1)
+                    .checked_div(inner_width)
+                    .unwrap_or(1) as u16;
                 lines_needed + 2 // Add 2 for top and bottom borders
             })
             .collect();
@@ -371,7 +371,7 @@ mod tests {
     fn test_notification_progress() {
         let n = Notification::new("Test", NotificationType::Info, Duration::from_millis(100));
         let progress = n.progress();
-        assert!(progress >= 0.0 && progress <= 1.0);
+        assert!((0.0..=1.0).contains(&progress));
     }
 
     #[test]
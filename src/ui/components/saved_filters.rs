@@ -32,7 +32,7 @@ pub enum SavedFiltersMode {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SavedFiltersAction {
     /// User selected a saved filter to apply.
-    Select(FilterState),
+    Select(Box<SavedFilter>),
     /// User wants to save the current filter with a name.
     Save(String),
     /// User wants to delete a saved filter.
@@ -182,7 +182,7 @@ impl SavedFiltersDialog {
             (KeyCode::Enter, KeyModifiers::NONE) => {
                 if let Some(filter) = self.filters.get(self.selected) {
                     self.visible = false;
-                    Some(SavedFiltersAction::Select(filter.filter.clone()))
+                    Some(SavedFiltersAction::Select(Box::new(filter.clone())))
                 } else {
                     None
                 }
@@ -557,13 +557,13 @@ mod tests {
         let mut dialog = SavedFiltersDialog::new();
         let mut filter_state = FilterState::default();
         filter_state.statuses.push("Open".to_string());
-        let filters = vec![SavedFilter::new("My Filter", filter_state.clone())];
-        dialog.show(filters, FilterState::default());
+        let saved = SavedFilter::new("My Filter", filter_state);
+        dialog.show(vec![saved.clone()], FilterState::default());
 
         let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
         let action = dialog.handle_input(key);
 
-        assert_eq!(action, Some(SavedFiltersAction::Select(filter_state)));
+        assert_eq!(action, Some(SavedFiltersAction::Select(Box::new(saved))));
         assert!(!dialog.is_visible());
     }
 
@@ -288,6 +288,7 @@ mod tests {
                 issue_links: vec![],
                 subtasks: vec![],
                 parent: None,
+                extra_fields: Default::default(),
             },
         }
     }
@@ -0,0 +1,66 @@
+//! Tab bar component.
+//!
+//! Displays the open issue list tabs on a single line, highlighting the
+//! active one.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::ui::theme::{theme, truncate, Theme};
+
+/// Maximum width of a single tab title.
+const MAX_TAB_TITLE_WIDTH: usize = 24;
+
+/// Build the tab bar line for the given titles.
+fn tab_bar_line(t: &Theme, titles: &[String], active: usize) -> Line<'static> {
+    let mut spans = Vec::with_capacity(titles.len() * 2);
+
+    for (i, title) in titles.iter().enumerate() {
+        let text = format!(" {}:{} ", i + 1, truncate(title, MAX_TAB_TITLE_WIDTH));
+        let style = if i == active {
+            Style::default()
+                .fg(t.selection_fg)
+                .bg(t.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(t.muted)
+        };
+        spans.push(Span::styled(text, style));
+        spans.push(Span::raw(" "));
+    }
+
+    Line::from(spans)
+}
+
+/// Render a tab bar with the given tab titles.
+pub fn render_tab_bar(frame: &mut Frame, area: Rect, titles: &[String], active: usize) {
+    frame.render_widget(Paragraph::new(tab_bar_line(theme(), titles, active)), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_bar_line_numbers_tabs() {
+        let titles = vec!["My Issues".to_string(), "Bugs".to_string()];
+        let line = tab_bar_line(&Theme::dark(), &titles, 1);
+
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, " 1:My Issues   2:Bugs  ");
+    }
+
+    #[test]
+    fn test_tab_bar_highlights_active_tab() {
+        let titles = vec!["A".to_string(), "B".to_string()];
+        let line = tab_bar_line(&Theme::dark(), &titles, 1);
+
+        assert!(line.spans[2].style.add_modifier.contains(Modifier::BOLD));
+        assert!(!line.spans[0].style.add_modifier.contains(Modifier::BOLD));
+    }
+}
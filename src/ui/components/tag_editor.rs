@@ -1,4 +1,4 @@
-//! Tag editor component for labels and components.
+//! Tag editor component for labels, components and versions.
 //!
 //! A reusable chip-based editor that displays current tags as chips and allows
 //! adding/removing tags from a list of available options.
@@ -13,6 +13,7 @@ use ratatui::{
 };
 
 use super::InputMode;
+use crate::api::types::VersionField;
 
 /// Action resulting from tag editor input.
 #[derive(Debug, Clone, PartialEq)]
@@ -60,6 +61,17 @@ impl TagEditorConfig {
             available_label: "Available Components".to_string(),
         }
     }
+
+    /// Create config for editing fix or affects versions.
+    pub fn versions(field: VersionField) -> Self {
+        let label = field.label();
+        Self {
+            title: format!(" Edit {} ", label),
+            tag_color: Color::Green,
+            current_label: format!("Current {}", label),
+            available_label: "Available Versions".to_string(),
+        }
+    }
 }
 
 /// Tag editor component.
@@ -121,6 +133,11 @@ impl TagEditor {
         Self::new(TagEditorConfig::components())
     }
 
+    /// Create a new tag editor for a version field.
+    pub fn for_versions(field: VersionField) -> Self {
+        Self::new(TagEditorConfig::versions(field))
+    }
+
     /// Check if the editor is visible.
     pub fn is_visible(&self) -> bool {
         self.visible
@@ -1052,6 +1069,14 @@ mod tests {
         assert!(config.title.contains("Components"));
     }
 
+    #[test]
+    fn test_config_versions() {
+        let config = TagEditorConfig::versions(VersionField::Fix);
+        assert_eq!(config.tag_color, Color::Green);
+        assert_eq!(config.title, " Edit Fix versions ");
+        assert_eq!(config.current_label, "Current Fix versions");
+    }
+
     #[test]
     fn test_remove_adjusts_selection() {
         let mut editor = TagEditor::for_labels();
@@ -0,0 +1,253 @@
+//! Issue template picker component.
+//!
+//! This module provides the popup shown before the create issue form when
+//! issue templates are configured. The first entry starts a blank issue.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Label of the entry that starts a blank issue.
+const BLANK_ISSUE: &str = "Blank issue";
+
+/// Action returned from the template picker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplatePickerAction {
+    /// User picked a template by name, or a blank issue (`None`).
+    Select(Option<String>),
+    /// User cancelled issue creation.
+    Cancel,
+}
+
+/// A popup component for choosing the template of a new issue.
+#[derive(Debug, Default)]
+pub struct TemplatePicker {
+    /// Template names and their detail text (e.g. `PROJ · Bug`).
+    templates: Vec<(String, String)>,
+    /// Currently selected index (0 is the blank issue).
+    selected: usize,
+    /// Whether the picker is visible.
+    visible: bool,
+    /// List state for ratatui.
+    list_state: ListState,
+}
+
+impl TemplatePicker {
+    /// Create a new template picker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the picker with the given (name, detail) template entries.
+    pub fn show(&mut self, templates: Vec<(String, String)>) {
+        self.templates = templates;
+        self.selected = 0;
+        self.list_state.select(Some(0));
+        self.visible = true;
+    }
+
+    /// Hide the picker.
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Check if the picker is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Get the number of entries, including the blank issue.
+    fn entry_count(&self) -> usize {
+        self.templates.len() + 1
+    }
+
+    /// Get the selected template name, or `None` for a blank issue.
+    pub fn selected_template(&self) -> Option<&str> {
+        self.selected
+            .checked_sub(1)
+            .and_then(|i| self.templates.get(i))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Move selection down.
+    fn move_down(&mut self) {
+        if self.selected + 1 < self.entry_count() {
+            self.selected += 1;
+            self.list_state.select(Some(self.selected));
+        }
+    }
+
+    /// Move selection up.
+    fn move_up(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.list_state.select(Some(self.selected));
+        }
+    }
+
+    /// Handle keyboard input.
+    ///
+    /// Returns an optional action when the user makes a selection or cancels.
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<TemplatePickerAction> {
+        match (key.code, key.modifiers) {
+            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => {
+                self.move_down();
+                None
+            }
+            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => {
+                self.move_up();
+                None
+            }
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                self.visible = false;
+                Some(TemplatePickerAction::Select(
+                    self.selected_template().map(str::to_string),
+                ))
+            }
+            (KeyCode::Esc, _) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                self.visible = false;
+                Some(TemplatePickerAction::Cancel)
+            }
+            _ => None,
+        }
+    }
+
+    /// Render the template picker.
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let dialog_width = 56u16.min(area.width.saturating_sub(4));
+        let max_visible_items = 12u16;
+        // Height: border (2) + items + hint (1)
+        let item_count = self.entry_count() as u16;
+        let dialog_height =
+            (item_count.min(max_visible_items) + 3).min(area.height.saturating_sub(4));
+
+        let dialog_area = centered_rect(area, dialog_width, dialog_height);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .title(Span::styled(
+                " New Issue From Template ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner_area = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let list_height = inner_area.height.saturating_sub(1);
+        let list_area = Rect {
+            height: list_height,
+            ..inner_area
+        };
+        let hint_area = Rect {
+            y: inner_area.y + list_height,
+            height: 1,
+            ..inner_area
+        };
+
+        let mut items = vec![ListItem::new(Span::styled(
+            BLANK_ISSUE,
+            Style::default().fg(Color::DarkGray),
+        ))];
+        items.extend(self.templates.iter().map(|(name, detail)| {
+            let mut spans = vec![Span::raw(name.clone())];
+            if !detail.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", detail),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        }));
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, list_area, &mut self.list_state);
+
+        let hint = Paragraph::new(Span::styled(
+            "j/k:navigate  Enter:select  q/Esc:cancel",
+            Style::default().fg(Color::DarkGray),
+        ))
+        .alignment(Alignment::Center);
+        frame.render_widget(hint, hint_area);
+    }
+}
+
+/// Calculate a centered rectangle within the given area.
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width.min(area.width), height.min(area.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn picker() -> TemplatePicker {
+        let mut picker = TemplatePicker::new();
+        picker.show(vec![
+            ("Bug report".to_string(), "PROJ · Bug".to_string()),
+            ("Story".to_string(), String::new()),
+        ]);
+        picker
+    }
+
+    #[test]
+    fn test_blank_issue_is_selected_first() {
+        let mut picker = picker();
+        assert!(picker.is_visible());
+        assert_eq!(picker.selected_template(), None);
+
+        let action = picker.handle_input(key(KeyCode::Enter));
+        assert_eq!(action, Some(TemplatePickerAction::Select(None)));
+        assert!(!picker.is_visible());
+    }
+
+    #[test]
+    fn test_select_template() {
+        let mut picker = picker();
+        picker.handle_input(key(KeyCode::Char('j')));
+        picker.handle_input(key(KeyCode::Down));
+        // Should not go past the end
+        picker.handle_input(key(KeyCode::Down));
+        assert_eq!(picker.selected_template(), Some("Story"));
+
+        picker.handle_input(key(KeyCode::Char('k')));
+        let action = picker.handle_input(key(KeyCode::Enter));
+        assert_eq!(
+            action,
+            Some(TemplatePickerAction::Select(Some("Bug report".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut picker = picker();
+        let action = picker.handle_input(key(KeyCode::Esc));
+        assert_eq!(action, Some(TemplatePickerAction::Cancel));
+        assert!(!picker.is_visible());
+    }
+}
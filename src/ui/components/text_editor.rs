@@ -5,6 +5,18 @@
 //! - Cursor movement (arrows, home/end, page up/down)
 //! - Scrolling for content longer than visible area
 //! - Change tracking for unsaved changes indicator
+//! - Optional Vim-style modal editing (normal, insert, and visual modes)
+//!
+//! Editors for descriptions and comments can also open an inline @mention
+//! picker (see [`MentionPicker`]) when `@` is typed at the start of a word.
+//!
+//! Modal editing follows the `vim_editing` setting. Editors start in insert mode;
+//! `Esc` switches to normal mode, where the core motions (`h j k l w b e 0 ^ $
+//! gg G`), operators (`d c y` with a motion, or doubled for whole lines), `p`,
+//! `u` / `Ctrl+R`, `.` and `/` search are available.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
@@ -15,8 +27,153 @@ use ratatui::{
     Frame,
 };
 
+use super::mention_picker::{mention_markup, MentionPicker};
+use crate::api::types::User;
 use crate::ui::theme::theme;
 
+/// Whether new editors use Vim-style modal editing.
+static VIM_EDITING: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable Vim-style modal editing for new editors.
+///
+/// This should be called once at application startup from the `vim_editing`
+/// setting.
+pub fn init_vim_editing(enabled: bool) {
+    VIM_EDITING.store(enabled, Ordering::Relaxed);
+}
+
+/// Editing mode of a Vim-style editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorMode {
+    /// Typing inserts text.
+    Insert,
+    /// Keys are commands and motions.
+    Normal,
+    /// Characters are being selected.
+    Visual,
+    /// Whole lines are being selected.
+    VisualLine,
+}
+
+impl EditorMode {
+    /// Get the label shown in the editor border.
+    pub fn label(&self) -> &'static str {
+        match self {
+            EditorMode::Insert => "INSERT",
+            EditorMode::Normal => "NORMAL",
+            EditorMode::Visual => "VISUAL",
+            EditorMode::VisualLine => "VISUAL LINE",
+        }
+    }
+}
+
+/// A cursor position as (line, character column).
+type Pos = (usize, usize);
+
+/// Text held by the yank register.
+#[derive(Debug, Clone, Default)]
+struct Register {
+    text: String,
+    /// Whether the text is whole lines (pasted above/below the cursor line).
+    linewise: bool,
+}
+
+/// Editor state saved for undo and redo.
+#[derive(Debug, Clone)]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: Pos,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Delete,
+    Change,
+    Yank,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    WordForward,
+    WordBackward,
+    WordEnd,
+    LineStart,
+    FirstNonBlank,
+    LineEnd,
+    FileStart,
+    FileEnd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MotionKind {
+    /// The character at the target is not included.
+    Exclusive,
+    /// The character at the target is included.
+    Inclusive,
+    /// Whole lines between cursor and target.
+    Linewise,
+}
+
+/// State of Vim-style modal editing.
+#[derive(Debug, Clone)]
+struct VimState {
+    mode: EditorMode,
+    /// Count typed before a command.
+    count: Option<usize>,
+    /// Operator waiting for a motion, with the count typed before it.
+    operator: Option<(Operator, Option<usize>)>,
+    /// Whether `g` was pressed (waiting for `gg`).
+    pending_g: bool,
+    /// Whether `r` was pressed (waiting for the replacement character).
+    pending_replace: bool,
+    /// Start of the visual selection.
+    anchor: Pos,
+    register: Register,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// Keys of the command being typed, for `.` repeat.
+    command_keys: Vec<KeyEvent>,
+    /// Keys of the last change.
+    last_change: Vec<KeyEvent>,
+    /// Whether the last change is being replayed.
+    replaying: bool,
+    /// Search pattern being typed after `/`.
+    search_input: Option<String>,
+    last_search: Option<String>,
+}
+
+impl VimState {
+    /// Maximum number of undo steps to keep.
+    const MAX_UNDO: usize = 100;
+
+    fn new() -> Self {
+        Self {
+            mode: EditorMode::Insert,
+            count: None,
+            operator: None,
+            pending_g: false,
+            pending_replace: false,
+            anchor: (0, 0),
+            register: Register::default(),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            command_keys: Vec::new(),
+            last_change: Vec::new(),
+            replaying: false,
+            search_input: None,
+            last_search: None,
+        }
+    }
+
+    fn has_pending(&self) -> bool {
+        self.count.is_some() || self.operator.is_some() || self.pending_g || self.pending_replace
+    }
+}
+
 /// A multi-line text editor component.
 #[derive(Debug, Clone)]
 pub struct TextEditor {
@@ -24,12 +181,18 @@ pub struct TextEditor {
     lines: Vec<String>,
     /// Current line (0-indexed).
     cursor_line: usize,
-    /// Current column within the line.
+    /// Current column within the line, in characters.
     cursor_col: usize,
     /// Scroll offset (first visible line).
     scroll: usize,
     /// Original content for change detection.
     original_content: String,
+    /// Modal editing state (None when Vim-style editing is off).
+    vim: Option<VimState>,
+    /// Whether typing `@` opens the mention picker.
+    mentions_enabled: bool,
+    /// Open mention picker.
+    mention: Option<MentionPicker>,
 }
 
 impl TextEditor {
@@ -46,13 +209,18 @@ impl TextEditor {
             lines
         };
 
-        Self {
+        let mut editor = Self {
             lines,
             cursor_line: 0,
             cursor_col: 0,
             scroll: 0,
             original_content: content.to_string(),
-        }
+            vim: None,
+            mentions_enabled: false,
+            mention: None,
+        };
+        editor.set_vim_mode(VIM_EDITING.load(Ordering::Relaxed));
+        editor
     }
 
     /// Create an empty text editor.
@@ -78,6 +246,67 @@ impl TextEditor {
         self.original_content = content.to_string();
     }
 
+    /// Enable or disable Vim-style modal editing.
+    ///
+    /// The editor starts in insert mode.
+    pub fn set_vim_mode(&mut self, enabled: bool) {
+        if enabled == self.vim.is_some() {
+            return;
+        }
+        self.vim = enabled.then(|| {
+            let mut vim = VimState::new();
+            vim.undo.push(self.snapshot());
+            vim
+        });
+    }
+
+    /// Enable the @mention picker for this editor.
+    pub fn with_mentions(mut self) -> Self {
+        self.mentions_enabled = true;
+        self
+    }
+
+    /// Check if the mention picker is open.
+    pub fn is_mention_picker_open(&self) -> bool {
+        self.mention.is_some()
+    }
+
+    /// Take the user search to run for the mention picker, if one is due.
+    pub fn take_due_mention_search(&mut self, now: Instant) -> Option<String> {
+        self.mention.as_mut()?.take_due_search(now)
+    }
+
+    /// Set the results of a mention user search.
+    pub fn set_mention_results(&mut self, query: &str, users: Vec<User>) {
+        if let Some(picker) = &mut self.mention {
+            picker.set_results(query, users);
+        }
+    }
+
+    /// Mark a mention user search as failed.
+    pub fn set_mention_search_failed(&mut self, query: &str) {
+        if let Some(picker) = &mut self.mention {
+            picker.set_search_failed(query);
+        }
+    }
+
+    /// Get the current editing mode, or `None` when modal editing is off.
+    pub fn mode(&self) -> Option<EditorMode> {
+        self.vim.as_ref().map(|vim| vim.mode)
+    }
+
+    /// Check whether `Esc` should go to the editor rather than its container.
+    ///
+    /// `Esc` closes the mention picker. In modal editing, it also leaves
+    /// insert and visual mode and cancels a pending command; only a plain
+    /// normal mode lets it through.
+    pub fn wants_escape(&self) -> bool {
+        self.mention.is_some()
+            || self.vim.as_ref().is_some_and(|vim| {
+                vim.mode != EditorMode::Normal || vim.has_pending() || vim.search_input.is_some()
+            })
+    }
+
     /// Get the current cursor line.
     pub fn cursor_line(&self) -> usize {
         self.cursor_line
@@ -108,9 +337,19 @@ impl TextEditor {
         &mut self.lines[self.cursor_line]
     }
 
+    /// Get the length of a line in characters.
+    fn line_len(&self, line: usize) -> usize {
+        self.lines[line].chars().count()
+    }
+
+    /// Get the byte offset of the cursor in the current line.
+    fn cursor_byte(&self) -> usize {
+        byte_index(self.current_line(), self.cursor_col)
+    }
+
     /// Ensure the cursor column is within bounds for the current line.
     fn clamp_cursor_col(&mut self) {
-        let line_len = self.current_line().len();
+        let line_len = self.line_len(self.cursor_line);
         if self.cursor_col > line_len {
             self.cursor_col = line_len;
         }
@@ -135,6 +374,106 @@ impl TextEditor {
     ///
     /// Returns true if the content was modified.
     pub fn handle_input(&mut self, key: KeyEvent) -> bool {
+        if let Some(modified) = self.handle_mention_key(key) {
+            return modified;
+        }
+        let modified = if self.vim.is_some() {
+            let before = self.lines.clone();
+            self.handle_vim_input(key);
+            self.lines != before
+        } else {
+            self.handle_insert_input(key)
+        };
+        self.update_mention(key);
+        modified
+    }
+
+    /// Handle a key for the open mention picker, if it uses it.
+    fn handle_mention_key(&mut self, key: KeyEvent) -> Option<bool> {
+        let picker = self.mention.as_mut()?;
+        match (key.code, key.modifiers) {
+            (KeyCode::Esc, _) => {
+                self.mention = None;
+                Some(false)
+            }
+            (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                picker.select_prev();
+                Some(false)
+            }
+            (KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                picker.select_next();
+                Some(false)
+            }
+            (KeyCode::Enter | KeyCode::Tab, _) => match picker.selected_user().cloned() {
+                Some(user) => {
+                    self.insert_mention(&user);
+                    Some(true)
+                }
+                None => {
+                    // Nothing to pick; the key goes to the editor
+                    self.mention = None;
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Open, update or close the mention picker after a key was handled.
+    fn update_mention(&mut self, key: KeyEvent) {
+        if !self.mentions_enabled {
+            return;
+        }
+        let inserting = self.mode().map_or(true, |mode| mode == EditorMode::Insert);
+
+        let Some(picker) = &mut self.mention else {
+            // `@` opens the picker at the start of a word
+            if inserting && typed_char(key) == Some('@') && self.cursor_col > 0 {
+                let at = self.cursor_col - 1;
+                let at_word_start = at == 0
+                    || self.lines[self.cursor_line]
+                        .chars()
+                        .nth(at - 1)
+                        .is_some_and(|c| c.is_whitespace() || c == '(');
+                if at_word_start {
+                    self.mention = Some(MentionPicker::new((self.cursor_line, at)));
+                }
+            }
+            return;
+        };
+
+        let (line, col) = picker.anchor();
+        let still_typing = inserting
+            && self.cursor_line == line
+            && self.cursor_col > col
+            && self.lines[line].chars().nth(col) == Some('@');
+        let query: String = self.lines[line]
+            .chars()
+            .skip(col + 1)
+            .take(self.cursor_col.saturating_sub(col + 1))
+            .collect();
+        if still_typing && !query.starts_with(char::is_whitespace) {
+            picker.set_query(&query);
+        } else {
+            self.mention = None;
+        }
+    }
+
+    /// Replace the typed `@query` with a mention of the user.
+    fn insert_mention(&mut self, user: &User) {
+        let Some(picker) = self.mention.take() else {
+            return;
+        };
+        let (line, col) = picker.anchor();
+        let markup = mention_markup(user);
+        let start = byte_index(&self.lines[line], col);
+        let end = byte_index(&self.lines[line], self.cursor_col);
+        self.lines[line].replace_range(start..end, &markup);
+        self.cursor_col = col + markup.chars().count();
+    }
+
+    /// Handle a key that edits text directly (insert mode).
+    fn handle_insert_input(&mut self, key: KeyEvent) -> bool {
         match (key.code, key.modifiers) {
             // Character input
             (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
@@ -173,7 +512,7 @@ impl TextEditor {
                 false
             }
             (KeyCode::End, _) => {
-                self.cursor_col = self.current_line().len();
+                self.cursor_col = self.line_len(self.cursor_line);
                 false
             }
             // Ctrl+A - beginning of line (emacs style)
@@ -183,31 +522,27 @@ impl TextEditor {
             }
             // Ctrl+E - end of line (emacs style)
             (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
-                self.cursor_col = self.current_line().len();
+                self.cursor_col = self.line_len(self.cursor_line);
                 false
             }
             // Ctrl+U - delete line content before cursor
-            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
-                if self.cursor_col > 0 {
-                    let cursor = self.cursor_col;
-                    self.lines[self.cursor_line].replace_range(..cursor, "");
-                    self.cursor_col = 0;
-                    true
-                } else {
-                    false
-                }
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) if self.cursor_col > 0 => {
+                let cursor = self.cursor_byte();
+                self.current_line_mut().replace_range(..cursor, "");
+                self.cursor_col = 0;
+                true
             }
             // Ctrl+K - delete from cursor to end of line
             (KeyCode::Char('k'), KeyModifiers::CONTROL) => {
-                let line_len = self.current_line().len();
+                let line_len = self.line_len(self.cursor_line);
                 if self.cursor_col < line_len {
-                    let cursor = self.cursor_col;
-                    self.lines[self.cursor_line].truncate(cursor);
+                    let cursor = self.cursor_byte();
+                    self.current_line_mut().truncate(cursor);
                     true
                 } else if self.cursor_line < self.lines.len() - 1 {
                     // Join with next line
                     let next_line = self.lines.remove(self.cursor_line + 1);
-                    self.lines[self.cursor_line].push_str(&next_line);
+                    self.current_line_mut().push_str(&next_line);
                     true
                 } else {
                     false
@@ -219,14 +554,14 @@ impl TextEditor {
 
     /// Insert a character at the cursor position.
     fn insert_char(&mut self, c: char) {
-        let cursor = self.cursor_col;
-        self.lines[self.cursor_line].insert(cursor, c);
+        let cursor = self.cursor_byte();
+        self.current_line_mut().insert(cursor, c);
         self.cursor_col += 1;
     }
 
     /// Insert a newline at the cursor position.
     fn insert_newline(&mut self) {
-        let cursor = self.cursor_col;
+        let cursor = self.cursor_byte();
         let line_idx = self.cursor_line;
         let new_line = self.lines[line_idx].split_off(cursor);
         self.lines.insert(line_idx + 1, new_line);
@@ -238,15 +573,15 @@ impl TextEditor {
     fn delete_backward(&mut self) -> bool {
         if self.cursor_col > 0 {
             self.cursor_col -= 1;
-            let cursor = self.cursor_col;
-            self.lines[self.cursor_line].remove(cursor);
+            let cursor = self.cursor_byte();
+            self.current_line_mut().remove(cursor);
             true
         } else if self.cursor_line > 0 {
             // Join with previous line
             let current_line = self.lines.remove(self.cursor_line);
             self.cursor_line -= 1;
-            self.cursor_col = self.lines[self.cursor_line].len();
-            self.lines[self.cursor_line].push_str(&current_line);
+            self.cursor_col = self.line_len(self.cursor_line);
+            self.current_line_mut().push_str(&current_line);
             true
         } else {
             false
@@ -255,15 +590,15 @@ impl TextEditor {
 
     /// Delete the character at the cursor.
     fn delete_forward(&mut self) -> bool {
-        let line_len = self.current_line().len();
+        let line_len = self.line_len(self.cursor_line);
         if self.cursor_col < line_len {
-            let cursor = self.cursor_col;
-            self.lines[self.cursor_line].remove(cursor);
+            let cursor = self.cursor_byte();
+            self.current_line_mut().remove(cursor);
             true
         } else if self.cursor_line < self.lines.len() - 1 {
             // Join with next line
             let next_line = self.lines.remove(self.cursor_line + 1);
-            self.lines[self.cursor_line].push_str(&next_line);
+            self.current_line_mut().push_str(&next_line);
             true
         } else {
             false
@@ -276,13 +611,13 @@ impl TextEditor {
             self.cursor_col -= 1;
         } else if self.cursor_line > 0 {
             self.cursor_line -= 1;
-            self.cursor_col = self.current_line().len();
+            self.cursor_col = self.line_len(self.cursor_line);
         }
     }
 
     /// Move cursor right.
     fn move_right(&mut self) {
-        let line_len = self.current_line().len();
+        let line_len = self.line_len(self.cursor_line);
         if self.cursor_col < line_len {
             self.cursor_col += 1;
         } else if self.cursor_line < self.lines.len() - 1 {
@@ -307,6 +642,906 @@ impl TextEditor {
         }
     }
 
+    // ========================================================================
+    // Modal editing: key dispatch
+    // ========================================================================
+
+    fn handle_vim_input(&mut self, key: KeyEvent) {
+        let Some(vim) = &self.vim else {
+            return;
+        };
+        if vim.search_input.is_some() {
+            self.handle_search_key(key);
+            return;
+        }
+        match vim.mode {
+            EditorMode::Insert => {
+                // Only inserts started by a command are part of a repeatable change
+                if let Some(vim) = self.vim.as_mut() {
+                    if !vim.replaying && !vim.command_keys.is_empty() {
+                        vim.command_keys.push(key);
+                    }
+                }
+                if key.code == KeyCode::Esc {
+                    self.leave_insert();
+                } else {
+                    self.handle_insert_input(key);
+                }
+            }
+            EditorMode::Normal => self.handle_normal_key(key),
+            EditorMode::Visual | EditorMode::VisualLine => self.handle_visual_key(key),
+        }
+    }
+
+    fn handle_normal_key(&mut self, key: KeyEvent) {
+        self.record_key(key);
+        let ch = typed_char(key);
+
+        if self.vim_ref().pending_replace {
+            let count = self.take_count().unwrap_or(1);
+            self.vim_mut().pending_replace = false;
+            match ch {
+                Some(c) => self.replace_chars(c, count),
+                None => self.finish_command(false),
+            }
+            return;
+        }
+
+        if self.push_count_digit(ch) {
+            return;
+        }
+
+        if self.vim_ref().pending_g {
+            self.vim_mut().pending_g = false;
+            if ch == Some('g') {
+                self.run_motion(Motion::FileStart);
+            } else {
+                self.cancel_command();
+            }
+            return;
+        }
+
+        if key.code == KeyCode::Esc {
+            self.cancel_command();
+            return;
+        }
+
+        if ch == Some('g') {
+            self.vim_mut().pending_g = true;
+            return;
+        }
+
+        if let Some(motion) = motion_for_key(key) {
+            self.run_motion(motion);
+            return;
+        }
+
+        let operator = match ch {
+            Some('d') => Some(Operator::Delete),
+            Some('c') => Some(Operator::Change),
+            Some('y') => Some(Operator::Yank),
+            _ => None,
+        };
+        if let Some(operator) = operator {
+            match self.vim_ref().operator {
+                Some((pending, _)) if pending == operator => self.run_line_operator(),
+                Some(_) => self.cancel_command(),
+                None => {
+                    let count = self.take_count();
+                    self.vim_mut().operator = Some((operator, count));
+                }
+            }
+            return;
+        }
+        if self.vim_ref().operator.is_some() {
+            self.cancel_command();
+            return;
+        }
+
+        let count = self.take_count();
+        let n = count.unwrap_or(1);
+        match (key.code, key.modifiers) {
+            (KeyCode::Char('r'), KeyModifiers::CONTROL) => {
+                self.redo(n);
+                self.finish_command(false);
+            }
+            (KeyCode::Char(_), KeyModifiers::CONTROL) => self.cancel_command(),
+            _ => match ch {
+                Some('i') => self.begin_insert(),
+                Some('a') => {
+                    if self.line_len(self.cursor_line) > 0 {
+                        self.cursor_col += 1;
+                    }
+                    self.begin_insert();
+                }
+                Some('I') => {
+                    self.cursor_col = self.first_non_blank(self.cursor_line);
+                    self.begin_insert();
+                }
+                Some('A') => {
+                    self.cursor_col = self.line_len(self.cursor_line);
+                    self.begin_insert();
+                }
+                Some('o') => self.open_line(true),
+                Some('O') => self.open_line(false),
+                Some('s') => self.apply_operator_motion(Operator::Change, Motion::Right, count),
+                Some('S') => {
+                    self.vim_mut().operator = Some((Operator::Change, count));
+                    self.run_line_operator();
+                }
+                Some('C') => self.apply_operator_motion(Operator::Change, Motion::LineEnd, count),
+                Some('D') => self.apply_operator_motion(Operator::Delete, Motion::LineEnd, count),
+                Some('x') => self.apply_operator_motion(Operator::Delete, Motion::Right, count),
+                Some('X') => self.apply_operator_motion(Operator::Delete, Motion::Left, count),
+                Some('J') => self.join_lines(n),
+                Some('r') => {
+                    self.vim_mut().count = count;
+                    self.vim_mut().pending_replace = true;
+                }
+                Some('p') => self.paste(n, true),
+                Some('P') => self.paste(n, false),
+                Some('u') => {
+                    self.undo(n);
+                    self.finish_command(false);
+                }
+                Some('.') => {
+                    self.finish_command(false);
+                    self.repeat_last_change();
+                }
+                Some('/') => {
+                    self.vim_mut().search_input = Some(String::new());
+                    self.finish_command(false);
+                }
+                Some('n') => {
+                    self.search_next(true);
+                    self.finish_command(false);
+                }
+                Some('N') => {
+                    self.search_next(false);
+                    self.finish_command(false);
+                }
+                Some('v') => self.begin_visual(EditorMode::Visual),
+                Some('V') => self.begin_visual(EditorMode::VisualLine),
+                _ => self.cancel_command(),
+            },
+        }
+    }
+
+    fn handle_visual_key(&mut self, key: KeyEvent) {
+        self.record_key(key);
+        let ch = typed_char(key);
+        let mode = self.vim_ref().mode;
+
+        if self.push_count_digit(ch) {
+            return;
+        }
+
+        if self.vim_ref().pending_g {
+            self.vim_mut().pending_g = false;
+            if ch == Some('g') {
+                self.run_motion(Motion::FileStart);
+            }
+            return;
+        }
+
+        if let Some(motion) = motion_for_key(key) {
+            self.run_motion(motion);
+            return;
+        }
+
+        let operator = match ch {
+            Some('d') | Some('x') => Some(Operator::Delete),
+            Some('c') | Some('s') => Some(Operator::Change),
+            Some('y') => Some(Operator::Yank),
+            _ => None,
+        };
+        if let Some(operator) = operator {
+            let anchor = self.vim_ref().anchor;
+            let kind = if mode == EditorMode::VisualLine {
+                MotionKind::Linewise
+            } else {
+                MotionKind::Inclusive
+            };
+            self.vim_mut().mode = EditorMode::Normal;
+            self.vim_mut().count = None;
+            self.apply_operator(operator, anchor, self.cursor(), kind);
+            return;
+        }
+
+        match (key.code, ch) {
+            (KeyCode::Esc, _) => self.end_visual(),
+            (_, Some('v')) if mode == EditorMode::Visual => self.end_visual(),
+            (_, Some('V')) if mode == EditorMode::VisualLine => self.end_visual(),
+            (_, Some('v')) => self.vim_mut().mode = EditorMode::Visual,
+            (_, Some('V')) => self.vim_mut().mode = EditorMode::VisualLine,
+            (_, Some('g')) => self.vim_mut().pending_g = true,
+            (_, Some('o')) => {
+                let cursor = self.cursor();
+                let anchor = std::mem::replace(&mut self.vim_mut().anchor, cursor);
+                self.set_cursor(anchor);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_search_key(&mut self, key: KeyEvent) {
+        let vim = self.vim_mut();
+        let Some(input) = vim.search_input.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => vim.search_input = None,
+            KeyCode::Enter => {
+                let pattern = input.clone();
+                vim.search_input = None;
+                if !pattern.is_empty() {
+                    vim.last_search = Some(pattern);
+                }
+                self.search_next(true);
+            }
+            // Backspace on an empty prompt cancels the search
+            KeyCode::Backspace if input.is_empty() => vim.search_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c)
+                if matches!(key.modifiers, KeyModifiers::NONE | KeyModifiers::SHIFT) =>
+            {
+                input.push(c);
+            }
+            _ => {}
+        }
+    }
+
+    // ========================================================================
+    // Modal editing: command state
+    // ========================================================================
+
+    fn vim_ref(&self) -> &VimState {
+        self.vim.as_ref().expect("modal editing enabled")
+    }
+
+    fn vim_mut(&mut self) -> &mut VimState {
+        self.vim.as_mut().expect("modal editing enabled")
+    }
+
+    fn record_key(&mut self, key: KeyEvent) {
+        let vim = self.vim_mut();
+        if !vim.replaying {
+            vim.command_keys.push(key);
+        }
+    }
+
+    /// Accumulate a count digit, returning true if the key was consumed.
+    fn push_count_digit(&mut self, ch: Option<char>) -> bool {
+        let Some(digit) = ch.and_then(|c| c.to_digit(10)) else {
+            return false;
+        };
+        let vim = self.vim_mut();
+        if digit == 0 && vim.count.is_none() {
+            // A leading 0 is the line start motion
+            return false;
+        }
+        vim.count = Some(
+            vim.count
+                .unwrap_or(0)
+                .saturating_mul(10)
+                .saturating_add(digit as usize),
+        );
+        true
+    }
+
+    fn take_count(&mut self) -> Option<usize> {
+        self.vim_mut().count.take()
+    }
+
+    /// Finish the current command, remembering it for `.` if it changed text.
+    fn finish_command(&mut self, change: bool) {
+        let vim = self.vim_mut();
+        vim.count = None;
+        vim.operator = None;
+        vim.pending_g = false;
+        vim.pending_replace = false;
+        if vim.replaying {
+            return;
+        }
+        let keys = std::mem::take(&mut vim.command_keys);
+        if change {
+            vim.last_change = keys;
+        }
+    }
+
+    fn cancel_command(&mut self) {
+        self.finish_command(false);
+    }
+
+    fn cursor(&self) -> Pos {
+        (self.cursor_line, self.cursor_col)
+    }
+
+    /// Move the cursor, keeping it on a character in normal mode.
+    fn set_cursor(&mut self, (line, col): Pos) {
+        self.cursor_line = line.min(self.lines.len() - 1);
+        let len = self.line_len(self.cursor_line);
+        let max = match self.mode() {
+            Some(EditorMode::Insert) | None => len,
+            _ => len.saturating_sub(1),
+        };
+        self.cursor_col = col.min(max);
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            lines: self.lines.clone(),
+            cursor: self.cursor(),
+        }
+    }
+
+    /// Save the current state as an undo step before a change.
+    fn checkpoint(&mut self) {
+        let snapshot = self.snapshot();
+        let vim = self.vim_mut();
+        vim.undo.push(snapshot);
+        if vim.undo.len() > VimState::MAX_UNDO {
+            vim.undo.remove(0);
+        }
+        vim.redo.clear();
+    }
+
+    fn undo(&mut self, count: usize) {
+        for _ in 0..count {
+            let current = self.snapshot();
+            let Some(snapshot) = self.vim_mut().undo.pop() else {
+                break;
+            };
+            self.vim_mut().redo.push(current);
+            self.restore(snapshot);
+        }
+    }
+
+    fn redo(&mut self, count: usize) {
+        for _ in 0..count {
+            let current = self.snapshot();
+            let Some(snapshot) = self.vim_mut().redo.pop() else {
+                break;
+            };
+            self.vim_mut().undo.push(current);
+            self.restore(snapshot);
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.lines = snapshot.lines;
+        self.set_cursor(snapshot.cursor);
+    }
+
+    /// Enter insert mode as a new undo step.
+    fn begin_insert(&mut self) {
+        self.checkpoint();
+        self.vim_mut().mode = EditorMode::Insert;
+    }
+
+    fn leave_insert(&mut self) {
+        let unchanged = self
+            .vim_ref()
+            .undo
+            .last()
+            .is_some_and(|snapshot| snapshot.lines == self.lines);
+        let vim = self.vim_mut();
+        vim.mode = EditorMode::Normal;
+        if unchanged {
+            vim.undo.pop();
+        }
+        let change = !vim.command_keys.is_empty();
+        self.finish_command(change);
+        self.set_cursor((self.cursor_line, self.cursor_col.saturating_sub(1)));
+    }
+
+    fn begin_visual(&mut self, mode: EditorMode) {
+        let cursor = self.cursor();
+        let vim = self.vim_mut();
+        vim.mode = mode;
+        vim.anchor = cursor;
+    }
+
+    fn end_visual(&mut self) {
+        self.vim_mut().mode = EditorMode::Normal;
+        self.finish_command(false);
+    }
+
+    fn repeat_last_change(&mut self) {
+        let keys = self.vim_ref().last_change.clone();
+        if keys.is_empty() {
+            return;
+        }
+        self.vim_mut().replaying = true;
+        for key in keys {
+            self.handle_vim_input(key);
+        }
+        let vim = self.vim_mut();
+        vim.replaying = false;
+        vim.command_keys.clear();
+    }
+
+    // ========================================================================
+    // Modal editing: motions
+    // ========================================================================
+
+    fn run_motion(&mut self, motion: Motion) {
+        let count = self.take_count();
+        match self.vim_mut().operator.take() {
+            Some((operator, op_count)) => {
+                let count = match (op_count, count) {
+                    (None, None) => None,
+                    (a, b) => Some(a.unwrap_or(1) * b.unwrap_or(1)),
+                };
+                self.apply_operator_motion(operator, motion, count);
+            }
+            None => {
+                let (target, _) = self.motion_target(motion, count, false);
+                self.set_cursor(target);
+                if self.mode() == Some(EditorMode::Normal) {
+                    self.finish_command(false);
+                }
+            }
+        }
+    }
+
+    /// Get the character class at a position (end of line counts as space).
+    fn char_class(&self, (line, col): Pos) -> u8 {
+        match self.lines[line].chars().nth(col) {
+            None => 0,
+            Some(c) if c.is_whitespace() => 0,
+            Some(c) if c.is_alphanumeric() || c == '_' => 1,
+            Some(_) => 2,
+        }
+    }
+
+    fn is_empty_line(&self, line: usize) -> bool {
+        self.lines[line].is_empty()
+    }
+
+    /// Get the next position, treating each line end as a position.
+    fn next_pos(&self, (line, col): Pos) -> Option<Pos> {
+        if col < self.line_len(line) {
+            Some((line, col + 1))
+        } else if line + 1 < self.lines.len() {
+            Some((line + 1, 0))
+        } else {
+            None
+        }
+    }
+
+    fn prev_pos(&self, (line, col): Pos) -> Option<Pos> {
+        if col > 0 {
+            Some((line, col - 1))
+        } else if line > 0 {
+            Some((line - 1, self.line_len(line - 1)))
+        } else {
+            None
+        }
+    }
+
+    fn word_forward(&self, start: Pos) -> Pos {
+        let class = self.char_class(start);
+        let mut pos = start;
+        if class != 0 {
+            loop {
+                match self.next_pos(pos) {
+                    Some(next) => pos = next,
+                    None => return pos,
+                }
+                if self.char_class(pos) != class {
+                    break;
+                }
+            }
+        }
+        // Skip whitespace, stopping at empty lines
+        loop {
+            if self.char_class(pos) != 0 || (pos != start && self.is_empty_line(pos.0)) {
+                return pos;
+            }
+            match self.next_pos(pos) {
+                Some(next) => pos = next,
+                None => return pos,
+            }
+        }
+    }
+
+    fn word_end(&self, start: Pos) -> Pos {
+        let Some(mut pos) = self.next_pos(start) else {
+            return start;
+        };
+        while self.char_class(pos) == 0 {
+            match self.next_pos(pos) {
+                Some(next) => pos = next,
+                None => return pos,
+            }
+        }
+        let class = self.char_class(pos);
+        while let Some(next) = self.next_pos(pos) {
+            if self.char_class(next) != class {
+                break;
+            }
+            pos = next;
+        }
+        pos
+    }
+
+    fn word_backward(&self, start: Pos) -> Pos {
+        let Some(mut pos) = self.prev_pos(start) else {
+            return start;
+        };
+        while self.char_class(pos) == 0 && !self.is_empty_line(pos.0) {
+            match self.prev_pos(pos) {
+                Some(prev) => pos = prev,
+                None => return pos,
+            }
+        }
+        let class = self.char_class(pos);
+        if class == 0 {
+            return pos;
+        }
+        while let Some(prev) = self.prev_pos(pos) {
+            if self.char_class(prev) != class {
+                break;
+            }
+            pos = prev;
+        }
+        pos
+    }
+
+    fn first_non_blank(&self, line: usize) -> usize {
+        self.lines[line]
+            .chars()
+            .position(|c| !c.is_whitespace())
+            .unwrap_or(0)
+    }
+
+    /// Get the target of a motion and how the text up to it is selected.
+    fn motion_target(
+        &self,
+        motion: Motion,
+        count: Option<usize>,
+        for_operator: bool,
+    ) -> (Pos, MotionKind) {
+        let n = count.unwrap_or(1).max(1);
+        let (line, col) = self.cursor();
+        let last = self.lines.len() - 1;
+        let repeat = |step: &dyn Fn(Pos) -> Pos| (0..n).fold((line, col), |pos, _| step(pos));
+
+        match motion {
+            Motion::Left => ((line, col.saturating_sub(n)), MotionKind::Exclusive),
+            Motion::Right => {
+                let len = self.line_len(line);
+                let max = if for_operator {
+                    len
+                } else {
+                    len.saturating_sub(1)
+                };
+                ((line, (col + n).min(max)), MotionKind::Exclusive)
+            }
+            Motion::Up => ((line.saturating_sub(n), col), MotionKind::Linewise),
+            Motion::Down => (((line + n).min(last), col), MotionKind::Linewise),
+            Motion::WordForward => {
+                let mut target = repeat(&|pos| self.word_forward(pos));
+                // An operator on the last word of a line stops at the line end
+                if for_operator && target.0 > line {
+                    target = (target.0 - 1, self.line_len(target.0 - 1));
+                }
+                (target, MotionKind::Exclusive)
+            }
+            Motion::WordBackward => (
+                repeat(&|pos| self.word_backward(pos)),
+                MotionKind::Exclusive,
+            ),
+            Motion::WordEnd => (repeat(&|pos| self.word_end(pos)), MotionKind::Inclusive),
+            Motion::LineStart => ((line, 0), MotionKind::Exclusive),
+            Motion::FirstNonBlank => ((line, self.first_non_blank(line)), MotionKind::Exclusive),
+            Motion::LineEnd => {
+                let target = (line + n - 1).min(last);
+                (
+                    (target, self.line_len(target).saturating_sub(1)),
+                    MotionKind::Inclusive,
+                )
+            }
+            Motion::FileStart => {
+                let target = count.map(|c| c.saturating_sub(1)).unwrap_or(0).min(last);
+                ((target, self.first_non_blank(target)), MotionKind::Linewise)
+            }
+            Motion::FileEnd => {
+                let target = count.map(|c| c.saturating_sub(1)).unwrap_or(last).min(last);
+                ((target, self.first_non_blank(target)), MotionKind::Linewise)
+            }
+        }
+    }
+
+    // ========================================================================
+    // Modal editing: operators
+    // ========================================================================
+
+    fn apply_operator_motion(&mut self, operator: Operator, motion: Motion, count: Option<usize>) {
+        // `cw` on a word changes to the end of the word, like `ce`
+        let motion = if operator == Operator::Change
+            && motion == Motion::WordForward
+            && self.char_class(self.cursor()) != 0
+        {
+            Motion::WordEnd
+        } else {
+            motion
+        };
+        let (target, kind) = self.motion_target(motion, count, true);
+        self.apply_operator(operator, self.cursor(), target, kind);
+    }
+
+    /// Apply an operator to whole lines (`dd`, `cc`, `yy`).
+    fn run_line_operator(&mut self) {
+        let count = self.take_count();
+        let Some((operator, op_count)) = self.vim_mut().operator.take() else {
+            return;
+        };
+        let n = op_count.unwrap_or(1) * count.unwrap_or(1);
+        let end = (self.cursor_line + n.max(1) - 1).min(self.lines.len() - 1);
+        self.apply_operator(operator, self.cursor(), (end, 0), MotionKind::Linewise);
+    }
+
+    fn apply_operator(&mut self, operator: Operator, a: Pos, b: Pos, kind: MotionKind) {
+        let (start, end) = if a <= b { (a, b) } else { (b, a) };
+
+        if kind == MotionKind::Linewise {
+            let (first, last) = (start.0, end.0);
+            let text = self.lines[first..=last].join("\n");
+            self.vim_mut().register = Register {
+                text,
+                linewise: true,
+            };
+            match operator {
+                Operator::Yank => {
+                    self.set_cursor((first, if a.0 == first { a.1 } else { b.1 }));
+                    self.finish_command(false);
+                }
+                Operator::Delete => {
+                    self.checkpoint();
+                    self.lines.drain(first..=last);
+                    if self.lines.is_empty() {
+                        self.lines.push(String::new());
+                    }
+                    let line = first.min(self.lines.len() - 1);
+                    self.set_cursor((line, self.first_non_blank(line)));
+                    self.finish_command(true);
+                }
+                Operator::Change => {
+                    self.checkpoint();
+                    self.lines.splice(first..=last, [String::new()]);
+                    self.cursor_line = first;
+                    self.cursor_col = 0;
+                    self.vim_mut().mode = EditorMode::Insert;
+                }
+            }
+            return;
+        }
+
+        let mut end = end;
+        if kind == MotionKind::Inclusive {
+            end.1 += 1;
+        }
+        end.1 = end.1.min(self.line_len(end.0));
+        let start = (start.0, start.1.min(self.line_len(start.0)));
+
+        if start == end && operator != Operator::Change {
+            self.finish_command(false);
+            return;
+        }
+        let text = self.text_between(start, end);
+        if !text.is_empty() {
+            self.vim_mut().register = Register {
+                text,
+                linewise: false,
+            };
+        }
+        match operator {
+            Operator::Yank => {
+                self.set_cursor(start);
+                self.finish_command(false);
+            }
+            Operator::Delete => {
+                self.checkpoint();
+                self.remove_between(start, end);
+                self.set_cursor(start);
+                self.finish_command(true);
+            }
+            Operator::Change => {
+                self.checkpoint();
+                self.remove_between(start, end);
+                self.vim_mut().mode = EditorMode::Insert;
+                self.set_cursor(start);
+            }
+        }
+    }
+
+    /// Get the text from `start` up to (not including) `end`.
+    fn text_between(&self, start: Pos, end: Pos) -> String {
+        if start.0 == end.0 {
+            return self.lines[start.0]
+                .chars()
+                .skip(start.1)
+                .take(end.1.saturating_sub(start.1))
+                .collect();
+        }
+        let mut parts: Vec<String> = vec![self.lines[start.0].chars().skip(start.1).collect()];
+        parts.extend(self.lines[start.0 + 1..end.0].iter().cloned());
+        parts.push(self.lines[end.0].chars().take(end.1).collect());
+        parts.join("\n")
+    }
+
+    /// Remove the text from `start` up to (not including) `end`.
+    fn remove_between(&mut self, start: Pos, end: Pos) {
+        let head = &self.lines[start.0][..byte_index(&self.lines[start.0], start.1)];
+        let tail = &self.lines[end.0][byte_index(&self.lines[end.0], end.1)..];
+        let joined = format!("{}{}", head, tail);
+        self.lines.splice(start.0..=end.0, [joined]);
+    }
+
+    /// Insert text at a position, returning the position after it.
+    fn insert_text(&mut self, (line, col): Pos, text: &str) -> Pos {
+        let split = byte_index(&self.lines[line], col);
+        let tail = self.lines[line].split_off(split);
+        let mut parts = text.split('\n');
+        self.lines[line].push_str(parts.next().unwrap_or(""));
+        let mut end = (line, self.line_len(line));
+        for (i, part) in parts.enumerate() {
+            self.lines.insert(line + i + 1, part.to_string());
+            end = (line + i + 1, part.chars().count());
+        }
+        self.lines[end.0].push_str(&tail);
+        end
+    }
+
+    fn paste(&mut self, count: usize, after: bool) {
+        let register = self.vim_ref().register.clone();
+        if register.text.is_empty() {
+            self.finish_command(false);
+            return;
+        }
+        self.checkpoint();
+
+        if register.linewise {
+            let at = if after {
+                self.cursor_line + 1
+            } else {
+                self.cursor_line
+            };
+            let new_lines: Vec<String> = (0..count)
+                .flat_map(|_| register.text.split('\n').map(String::from))
+                .collect();
+            self.lines.splice(at..at, new_lines);
+            self.set_cursor((at, self.first_non_blank(at)));
+        } else {
+            let col = if after && self.line_len(self.cursor_line) > 0 {
+                self.cursor_col + 1
+            } else {
+                self.cursor_col
+            };
+            let end = self.insert_text((self.cursor_line, col), &register.text.repeat(count));
+            self.set_cursor((end.0, end.1.saturating_sub(1)));
+        }
+        self.finish_command(true);
+    }
+
+    fn open_line(&mut self, below: bool) {
+        self.checkpoint();
+        let at = if below {
+            self.cursor_line + 1
+        } else {
+            self.cursor_line
+        };
+        self.lines.insert(at, String::new());
+        self.cursor_line = at;
+        self.cursor_col = 0;
+        self.vim_mut().mode = EditorMode::Insert;
+    }
+
+    fn replace_chars(&mut self, c: char, count: usize) {
+        let (line, col) = self.cursor();
+        if col + count > self.line_len(line) {
+            self.finish_command(false);
+            return;
+        }
+        self.checkpoint();
+        let start = byte_index(&self.lines[line], col);
+        let end = byte_index(&self.lines[line], col + count);
+        self.lines[line].replace_range(start..end, &c.to_string().repeat(count));
+        self.set_cursor((line, col + count - 1));
+        self.finish_command(true);
+    }
+
+    fn join_lines(&mut self, count: usize) {
+        let joins = count.max(2) - 1;
+        if self.cursor_line + 1 >= self.lines.len() {
+            self.finish_command(false);
+            return;
+        }
+        self.checkpoint();
+        for _ in 0..joins {
+            let line = self.cursor_line;
+            if line + 1 >= self.lines.len() {
+                break;
+            }
+            let next = self.lines.remove(line + 1);
+            let next = next.trim_start();
+            let current = &mut self.lines[line];
+            current.truncate(current.trim_end().len());
+            let col = current.chars().count();
+            if !current.is_empty() && !next.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(next);
+            self.cursor_col = col;
+        }
+        self.set_cursor(self.cursor());
+        self.finish_command(true);
+    }
+
+    // ========================================================================
+    // Modal editing: search
+    // ========================================================================
+
+    /// Move to the next match of the last search, wrapping around.
+    fn search_next(&mut self, forward: bool) {
+        let Some(pattern) = self.vim_ref().last_search.clone() else {
+            return;
+        };
+        let matches: Vec<Pos> = self
+            .lines
+            .iter()
+            .enumerate()
+            .flat_map(|(line, text)| {
+                text.match_indices(pattern.as_str())
+                    .map(move |(byte, _)| (line, text[..byte].chars().count()))
+            })
+            .collect();
+        let cursor = self.cursor();
+        let target = if forward {
+            matches
+                .iter()
+                .find(|&&pos| pos > cursor)
+                .or_else(|| matches.first())
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&pos| pos < cursor)
+                .or_else(|| matches.last())
+        };
+        if let Some(&target) = target {
+            self.set_cursor(target);
+        }
+    }
+
+    // ========================================================================
+    // Rendering
+    // ========================================================================
+
+    /// Get the selected character range of a line in visual mode.
+    fn selection_in_line(&self, line: usize) -> Option<(usize, usize)> {
+        let vim = self.vim.as_ref()?;
+        let (a, b) = (vim.anchor, self.cursor());
+        let (start, end) = if a <= b { (a, b) } else { (b, a) };
+        if line < start.0 || line > end.0 {
+            return None;
+        }
+        let len = self.line_len(line);
+        match vim.mode {
+            EditorMode::VisualLine => Some((0, len.max(1))),
+            EditorMode::Visual => {
+                let from = if line == start.0 { start.1 } else { 0 };
+                let to = if line == end.0 { end.1 + 1 } else { len.max(1) };
+                Some((from, to))
+            }
+            _ => None,
+        }
+    }
+
     /// Render the text editor.
     ///
     /// # Arguments
@@ -342,14 +1577,40 @@ impl TextEditor {
         } else {
             Style::default().fg(t.input_fg)
         };
+        let selection_style = Style::default().fg(t.selection_fg).bg(t.selection_bg);
 
         // Build lines to display with proper styling
         let display_lines: Vec<Line> = self
             .lines
             .iter()
+            .enumerate()
             .skip(self.scroll)
             .take(visible_height)
-            .map(|line| Line::from(Span::styled(line.as_str(), text_style)))
+            .map(|(index, line)| match self.selection_in_line(index) {
+                Some((from, to)) => {
+                    let chars: Vec<char> = line.chars().collect();
+                    let to = to.min(chars.len().max(1));
+                    let selected: String = if chars.is_empty() {
+                        " ".to_string()
+                    } else {
+                        chars[from.min(chars.len())..to.min(chars.len())]
+                            .iter()
+                            .collect()
+                    };
+                    Line::from(vec![
+                        Span::styled(
+                            chars[..from.min(chars.len())].iter().collect::<String>(),
+                            text_style,
+                        ),
+                        Span::styled(selected, selection_style),
+                        Span::styled(
+                            chars[to.min(chars.len())..].iter().collect::<String>(),
+                            text_style,
+                        ),
+                    ])
+                }
+                None => Line::from(Span::styled(line.as_str(), text_style)),
+            })
             .collect();
 
         let border_style = if let Some(color) = border_color {
@@ -368,7 +1629,7 @@ impl TextEditor {
             Style::default().fg(t.fg)
         };
 
-        let block = if let Some(title) = title {
+        let mut block = if let Some(title) = title {
             Block::default()
                 .title(Span::styled(title, title_style))
                 .borders(Borders::ALL)
@@ -379,18 +1640,42 @@ impl TextEditor {
                 .border_style(border_style)
         };
 
+        // Show the editing mode (or search prompt) in the bottom border
+        if let (Some(vim), true) = (&self.vim, focused) {
+            let indicator = match &vim.search_input {
+                Some(input) => Span::styled(format!(" /{} ", input), Style::default().fg(t.fg)),
+                None => {
+                    let color = match vim.mode {
+                        EditorMode::Insert => t.success,
+                        EditorMode::Normal => t.accent,
+                        EditorMode::Visual | EditorMode::VisualLine => t.warning,
+                    };
+                    Span::styled(
+                        format!(" {} ", vim.mode.label()),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    )
+                }
+            };
+            block = block.title_bottom(indicator);
+        }
+
         let paragraph = Paragraph::new(display_lines).block(block);
 
         frame.render_widget(paragraph, area);
 
         // Render cursor if focused
         if focused {
-            let cursor_x = area.x + 1 + self.cursor_col as u16;
+            let before: String = self.current_line().chars().take(self.cursor_col).collect();
+            let cursor_x = area.x + 1 + Span::raw(before).width() as u16;
             let cursor_y = area.y + 1 + (self.cursor_line - self.scroll) as u16;
 
             // Only show cursor if it's within the visible area
             if cursor_y < area.y + area.height - 1 && cursor_x < area.x + area.width - 1 {
-                frame.set_cursor_position(Position::new(cursor_x, cursor_y));
+                let cursor = Position::new(cursor_x, cursor_y);
+                frame.set_cursor_position(cursor);
+                if let Some(picker) = &self.mention {
+                    picker.render(frame, cursor, frame.area());
+                }
             }
         }
     }
@@ -407,6 +1692,49 @@ impl Default for TextEditor {
     }
 }
 
+/// Get the byte offset of a character column in a line.
+fn byte_index(line: &str, col: usize) -> usize {
+    line.char_indices()
+        .nth(col)
+        .map(|(byte, _)| byte)
+        .unwrap_or(line.len())
+}
+
+/// Get the character typed by a key, if it is a plain character.
+fn typed_char(key: KeyEvent) -> Option<char> {
+    match (key.code, key.modifiers) {
+        (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => Some(c),
+        _ => None,
+    }
+}
+
+/// Get the motion for a key in normal or visual mode.
+fn motion_for_key(key: KeyEvent) -> Option<Motion> {
+    match key.code {
+        KeyCode::Left | KeyCode::Backspace => return Some(Motion::Left),
+        KeyCode::Right => return Some(Motion::Right),
+        KeyCode::Up => return Some(Motion::Up),
+        KeyCode::Down | KeyCode::Enter => return Some(Motion::Down),
+        KeyCode::Home => return Some(Motion::LineStart),
+        KeyCode::End => return Some(Motion::LineEnd),
+        _ => {}
+    }
+    match typed_char(key)? {
+        'h' => Some(Motion::Left),
+        'l' | ' ' => Some(Motion::Right),
+        'k' => Some(Motion::Up),
+        'j' => Some(Motion::Down),
+        'w' => Some(Motion::WordForward),
+        'b' => Some(Motion::WordBackward),
+        'e' => Some(Motion::WordEnd),
+        '0' => Some(Motion::LineStart),
+        '^' => Some(Motion::FirstNonBlank),
+        '$' => Some(Motion::LineEnd),
+        'G' => Some(Motion::FileEnd),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -662,4 +1990,364 @@ mod tests {
         let editor = TextEditor::new("line1\nline2\nline3");
         assert_eq!(editor.scroll_info(), (1, 3)); // Line 1 of 3
     }
+
+    // ========================================================================
+    // Modal editing
+    // ========================================================================
+
+    fn vim_editor(content: &str) -> TextEditor {
+        let mut editor = TextEditor::new(content);
+        editor.set_vim_mode(true);
+        editor.handle_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        editor
+    }
+
+    fn type_keys(editor: &mut TextEditor, keys: &str) {
+        for c in keys.chars() {
+            let key = match c {
+                '\x1b' => KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+                '\n' => KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+                _ => KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE),
+            };
+            editor.handle_input(key);
+        }
+    }
+
+    #[test]
+    fn test_vim_starts_in_insert_mode() {
+        let mut editor = TextEditor::empty();
+        editor.set_vim_mode(true);
+        assert_eq!(editor.mode(), Some(EditorMode::Insert));
+        assert!(editor.wants_escape());
+
+        type_keys(&mut editor, "hello\x1b");
+        assert_eq!(editor.content(), "hello");
+        assert_eq!(editor.mode(), Some(EditorMode::Normal));
+        assert_eq!(editor.cursor_col(), 4);
+        assert!(!editor.wants_escape());
+
+        // The first insert session can be undone
+        type_keys(&mut editor, "u");
+        assert_eq!(editor.content(), "");
+    }
+
+    #[test]
+    fn test_vim_disabled_by_default() {
+        let editor = TextEditor::new("text");
+        assert_eq!(editor.mode(), None);
+        assert!(!editor.wants_escape());
+    }
+
+    #[test]
+    fn test_vim_word_motions() {
+        let mut editor = vim_editor("foo bar.baz qux");
+        type_keys(&mut editor, "0w");
+        assert_eq!(editor.cursor_col(), 4);
+        type_keys(&mut editor, "w");
+        assert_eq!(editor.cursor_col(), 7);
+        type_keys(&mut editor, "e");
+        assert_eq!(editor.cursor_col(), 10);
+        type_keys(&mut editor, "b");
+        assert_eq!(editor.cursor_col(), 8);
+        type_keys(&mut editor, "$");
+        assert_eq!(editor.cursor_col(), 14);
+        type_keys(&mut editor, "0");
+        assert_eq!(editor.cursor_col(), 0);
+        type_keys(&mut editor, "2w");
+        assert_eq!(editor.cursor_col(), 7);
+    }
+
+    #[test]
+    fn test_vim_line_motions() {
+        let mut editor = vim_editor("one\n  two\nthree\nfour");
+        type_keys(&mut editor, "gg");
+        assert_eq!(editor.cursor_line(), 0);
+        type_keys(&mut editor, "G");
+        assert_eq!(editor.cursor_line(), 3);
+        type_keys(&mut editor, "2G");
+        assert_eq!((editor.cursor_line(), editor.cursor_col()), (1, 2));
+        type_keys(&mut editor, "k");
+        assert_eq!(editor.cursor_line(), 0);
+        type_keys(&mut editor, "2j");
+        assert_eq!(editor.cursor_line(), 2);
+    }
+
+    #[test]
+    fn test_vim_delete_and_change_with_motions() {
+        let mut editor = vim_editor("foo bar baz");
+        type_keys(&mut editor, "0dw");
+        assert_eq!(editor.content(), "bar baz");
+
+        type_keys(&mut editor, "cwqux\x1b");
+        assert_eq!(editor.content(), "qux baz");
+
+        type_keys(&mut editor, "0d$");
+        assert_eq!(editor.content(), "");
+    }
+
+    #[test]
+    fn test_vim_delete_word_at_line_end_keeps_next_line() {
+        let mut editor = vim_editor("foo bar\nbaz");
+        type_keys(&mut editor, "gg0wdw");
+        assert_eq!(editor.content(), "foo \nbaz");
+    }
+
+    #[test]
+    fn test_vim_line_operators() {
+        let mut editor = vim_editor("one\ntwo\nthree");
+        type_keys(&mut editor, "ggdd");
+        assert_eq!(editor.content(), "two\nthree");
+
+        type_keys(&mut editor, "yyp");
+        assert_eq!(editor.content(), "two\ntwo\nthree");
+        assert_eq!(editor.cursor_line(), 1);
+
+        type_keys(&mut editor, "2dd");
+        assert_eq!(editor.content(), "two");
+
+        type_keys(&mut editor, "ccnew\x1b");
+        assert_eq!(editor.content(), "new");
+    }
+
+    #[test]
+    fn test_vim_yank_and_put_characters() {
+        let mut editor = vim_editor("hello world");
+        type_keys(&mut editor, "0yeP");
+        assert_eq!(editor.content(), "hellohello world");
+
+        type_keys(&mut editor, "0xp");
+        assert_eq!(editor.content(), "ehllohello world");
+    }
+
+    #[test]
+    fn test_vim_undo_redo() {
+        let mut editor = vim_editor("one two");
+        type_keys(&mut editor, "0dwx");
+        assert_eq!(editor.content(), "wo");
+
+        type_keys(&mut editor, "u");
+        assert_eq!(editor.content(), "two");
+        type_keys(&mut editor, "u");
+        assert_eq!(editor.content(), "one two");
+
+        editor.handle_input(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert_eq!(editor.content(), "two");
+    }
+
+    #[test]
+    fn test_vim_insert_session_is_one_undo_step() {
+        let mut editor = vim_editor("x");
+        type_keys(&mut editor, "Aabc\ndef\x1b");
+        assert_eq!(editor.content(), "xabc\ndef");
+
+        type_keys(&mut editor, "u");
+        assert_eq!(editor.content(), "x");
+    }
+
+    #[test]
+    fn test_vim_dot_repeats_last_change() {
+        let mut editor = vim_editor("a b c d");
+        type_keys(&mut editor, "0dw..");
+        assert_eq!(editor.content(), "d");
+
+        let mut editor = vim_editor("one\ntwo");
+        type_keys(&mut editor, "ggA;\x1bj.");
+        assert_eq!(editor.content(), "one;\ntwo;");
+    }
+
+    #[test]
+    fn test_vim_counts_and_single_char_commands() {
+        let mut editor = vim_editor("abcdef");
+        type_keys(&mut editor, "03x");
+        assert_eq!(editor.content(), "def");
+
+        type_keys(&mut editor, "rX");
+        assert_eq!(editor.content(), "Xef");
+
+        type_keys(&mut editor, "$X");
+        assert_eq!(editor.content(), "Xf");
+    }
+
+    #[test]
+    fn test_vim_open_line_and_join() {
+        let mut editor = vim_editor("first");
+        type_keys(&mut editor, "osecond\x1bOmiddle\x1b");
+        assert_eq!(editor.content(), "first\nmiddle\nsecond");
+
+        type_keys(&mut editor, "ggJ");
+        assert_eq!(editor.content(), "first middle\nsecond");
+    }
+
+    #[test]
+    fn test_vim_visual_mode() {
+        let mut editor = vim_editor("hello world");
+        type_keys(&mut editor, "0vey");
+        assert_eq!(editor.mode(), Some(EditorMode::Normal));
+        type_keys(&mut editor, "$p");
+        assert_eq!(editor.content(), "hello worldhello");
+
+        type_keys(&mut editor, "0wvld");
+        assert_eq!(editor.content(), "hello rldhello");
+    }
+
+    #[test]
+    fn test_vim_visual_line_mode() {
+        let mut editor = vim_editor("one\ntwo\nthree");
+        type_keys(&mut editor, "ggVjd");
+        assert_eq!(editor.content(), "three");
+
+        let mut editor = vim_editor("one\ntwo");
+        type_keys(&mut editor, "ggV");
+        assert!(editor.wants_escape());
+        type_keys(&mut editor, "\x1b");
+        assert_eq!(editor.mode(), Some(EditorMode::Normal));
+        assert_eq!(editor.content(), "one\ntwo");
+    }
+
+    #[test]
+    fn test_vim_search() {
+        let mut editor = vim_editor("foo bar\nbaz foo\nfoo");
+        type_keys(&mut editor, "gg/foo\n");
+        assert_eq!((editor.cursor_line(), editor.cursor_col()), (1, 4));
+
+        type_keys(&mut editor, "n");
+        assert_eq!((editor.cursor_line(), editor.cursor_col()), (2, 0));
+
+        // Wraps around to the start
+        type_keys(&mut editor, "n");
+        assert_eq!((editor.cursor_line(), editor.cursor_col()), (0, 0));
+
+        type_keys(&mut editor, "N");
+        assert_eq!((editor.cursor_line(), editor.cursor_col()), (2, 0));
+    }
+
+    #[test]
+    fn test_vim_search_prompt_wants_escape() {
+        let mut editor = vim_editor("text");
+        type_keys(&mut editor, "/te");
+        assert!(editor.wants_escape());
+        type_keys(&mut editor, "\x1b");
+        assert!(!editor.wants_escape());
+        assert_eq!(editor.content(), "text");
+    }
+
+    #[test]
+    fn test_vim_pending_operator_wants_escape() {
+        let mut editor = vim_editor("text");
+        type_keys(&mut editor, "d");
+        assert!(editor.wants_escape());
+        type_keys(&mut editor, "\x1b");
+        assert!(!editor.wants_escape());
+    }
+
+    #[test]
+    fn test_vim_handles_multibyte_text() {
+        let mut editor = vim_editor("héllo wörld");
+        type_keys(&mut editor, "0wdw");
+        assert_eq!(editor.content(), "héllo ");
+
+        type_keys(&mut editor, "0lx");
+        assert_eq!(editor.content(), "hllo ");
+    }
+
+    // ========================================================================
+    // Mentions
+    // ========================================================================
+
+    fn mention_user() -> User {
+        User {
+            account_id: "5b10ac8d82e05b22cc7d4ef5".to_string(),
+            display_name: "Jane Doe".to_string(),
+            email_address: None,
+            active: true,
+            avatar_urls: None,
+        }
+    }
+
+    #[test]
+    fn test_mention_picker_opens_at_word_start() {
+        let mut editor = TextEditor::empty().with_mentions();
+        type_keys(&mut editor, "hi @ja");
+        assert!(editor.is_mention_picker_open());
+        assert!(editor.wants_escape());
+        assert_eq!(editor.mention.as_ref().unwrap().query(), "ja");
+
+        // Not inside a word, like an email address
+        let mut editor = TextEditor::empty().with_mentions();
+        type_keys(&mut editor, "jane@example");
+        assert!(!editor.is_mention_picker_open());
+
+        // Not without mentions enabled
+        let mut editor = TextEditor::empty();
+        type_keys(&mut editor, "@ja");
+        assert!(!editor.is_mention_picker_open());
+    }
+
+    #[test]
+    fn test_mention_picker_inserts_mention_markup() {
+        let mut editor = TextEditor::empty().with_mentions();
+        type_keys(&mut editor, "cc @ja");
+        let query = editor
+            .take_due_mention_search(Instant::now() + std::time::Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(query, "ja");
+        editor.set_mention_results(&query, vec![mention_user()]);
+
+        assert!(editor.handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(!editor.is_mention_picker_open());
+        assert_eq!(
+            editor.content(),
+            "cc [@Jane Doe](mention:5b10ac8d82e05b22cc7d4ef5)"
+        );
+        assert_eq!(editor.cursor_col(), editor.content().chars().count());
+
+        type_keys(&mut editor, " thanks");
+        assert!(editor
+            .content()
+            .ends_with("(mention:5b10ac8d82e05b22cc7d4ef5) thanks"));
+    }
+
+    #[test]
+    fn test_mention_picker_closes() {
+        // Esc closes the picker and keeps the typed text
+        let mut editor = TextEditor::empty().with_mentions();
+        type_keys(&mut editor, "@ja");
+        editor.handle_input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(!editor.is_mention_picker_open());
+        assert_eq!(editor.content(), "@ja");
+
+        // Deleting the @ closes it
+        let mut editor = TextEditor::empty().with_mentions();
+        type_keys(&mut editor, "@j");
+        editor.handle_input(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        assert!(editor.is_mention_picker_open());
+        editor.handle_input(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        assert!(!editor.is_mention_picker_open());
+
+        // Enter without a match inserts a newline
+        let mut editor = TextEditor::empty().with_mentions();
+        type_keys(&mut editor, "@x\n");
+        assert!(!editor.is_mention_picker_open());
+        assert_eq!(editor.content(), "@x\n");
+    }
+
+    #[test]
+    fn test_mention_picker_in_vim_insert_mode() {
+        let mut editor = TextEditor::new("note").with_mentions();
+        editor.set_vim_mode(true);
+        type_keys(&mut editor, "\x1bA @ja");
+        assert!(editor.is_mention_picker_open());
+        editor.set_mention_results("ja", vec![mention_user()]);
+        editor.handle_input(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+
+        // The first Esc leaves insert mode, the mention is one undo step with the insert
+        type_keys(&mut editor, "\x1b");
+        assert_eq!(editor.mode(), Some(EditorMode::Normal));
+        assert_eq!(
+            editor.content(),
+            "note [@Jane Doe](mention:5b10ac8d82e05b22cc7d4ef5)"
+        );
+        type_keys(&mut editor, "u");
+        assert_eq!(editor.content(), "note");
+    }
 }
@@ -0,0 +1,258 @@
+//! Theme picker component.
+//!
+//! This module provides the popup opened from the command palette for
+//! switching themes. Moving the selection previews the highlighted theme;
+//! the caller applies or reverts it.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::ui::theme::theme;
+
+/// Action returned from the theme picker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemePickerAction {
+    /// The highlighted theme changed and should be previewed.
+    Preview(String),
+    /// User chose a theme.
+    Select(String),
+    /// User cancelled; the original theme should be restored.
+    Cancel,
+}
+
+/// A popup component for choosing the UI theme.
+#[derive(Debug, Default)]
+pub struct ThemePicker {
+    /// Theme names.
+    themes: Vec<String>,
+    /// Currently selected index.
+    selected: usize,
+    /// Name of the theme in use when the picker was opened.
+    current: String,
+    /// Whether the picker is visible.
+    visible: bool,
+    /// List state for ratatui.
+    list_state: ListState,
+}
+
+impl ThemePicker {
+    /// Create a new theme picker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the picker with the given themes, selecting the current one.
+    pub fn show(&mut self, themes: Vec<String>, current: &str) {
+        self.selected = themes
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(current))
+            .unwrap_or(0);
+        self.themes = themes;
+        self.current = current.to_string();
+        self.list_state.select(Some(self.selected));
+        self.visible = true;
+    }
+
+    /// Hide the picker.
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Check if the picker is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Get the selected theme name.
+    pub fn selected_theme(&self) -> Option<&str> {
+        self.themes.get(self.selected).map(String::as_str)
+    }
+
+    /// Move the selection by `delta` entries, returning whether it moved.
+    fn move_by(&mut self, delta: isize) -> bool {
+        let Some(last) = self.themes.len().checked_sub(1) else {
+            return false;
+        };
+        let target = self.selected.saturating_add_signed(delta).min(last);
+        if target == self.selected {
+            return false;
+        }
+        self.selected = target;
+        self.list_state.select(Some(self.selected));
+        true
+    }
+
+    /// Handle keyboard input.
+    ///
+    /// Returns a preview action whenever the selection moves, and a final
+    /// action when the user makes a selection or cancels.
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<ThemePickerAction> {
+        let moved = match (key.code, key.modifiers) {
+            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => self.move_by(1),
+            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => self.move_by(-1),
+            (KeyCode::Enter, KeyModifiers::NONE) => {
+                self.visible = false;
+                return self
+                    .selected_theme()
+                    .map(|name| ThemePickerAction::Select(name.to_string()));
+            }
+            (KeyCode::Esc, _) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                self.visible = false;
+                return Some(ThemePickerAction::Cancel);
+            }
+            _ => false,
+        };
+        if moved {
+            self.selected_theme()
+                .map(|name| ThemePickerAction::Preview(name.to_string()))
+        } else {
+            None
+        }
+    }
+
+    /// Render the theme picker using the previewed theme's colors.
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let t = theme();
+        let dialog_width = 40u16.min(area.width.saturating_sub(4));
+        let max_visible_items = 12u16;
+        // Height: border (2) + items + hint (1)
+        let item_count = self.themes.len() as u16;
+        let dialog_height =
+            (item_count.min(max_visible_items) + 3).min(area.height.saturating_sub(4));
+
+        let dialog_area = centered_rect(area, dialog_width, dialog_height);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .title(Span::styled(
+                " Switch Theme ",
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.border_focused));
+
+        let inner_area = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let list_height = inner_area.height.saturating_sub(1);
+        let list_area = Rect {
+            height: list_height,
+            ..inner_area
+        };
+        let hint_area = Rect {
+            y: inner_area.y + list_height,
+            height: 1,
+            ..inner_area
+        };
+
+        let items: Vec<ListItem> = self
+            .themes
+            .iter()
+            .map(|name| {
+                if name.eq_ignore_ascii_case(&self.current) {
+                    ListItem::new(Span::styled(
+                        format!("{} (current)", name),
+                        Style::default().fg(t.success),
+                    ))
+                } else {
+                    ListItem::new(Span::styled(name.clone(), t.style_normal()))
+                }
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(t.style_selected())
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, list_area, &mut self.list_state);
+
+        let hint = Paragraph::new(Span::styled(
+            "j/k:preview  Enter:apply  q/Esc:cancel",
+            t.style_muted(),
+        ))
+        .alignment(Alignment::Center);
+        frame.render_widget(hint, hint_area);
+    }
+}
+
+/// Calculate a centered rectangle within the given area.
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width.min(area.width), height.min(area.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn picker() -> ThemePicker {
+        let mut picker = ThemePicker::new();
+        picker.show(
+            vec![
+                "dark".to_string(),
+                "light".to_string(),
+                "Solarized".to_string(),
+            ],
+            "light",
+        );
+        picker
+    }
+
+    #[test]
+    fn test_show_selects_current_theme() {
+        let picker = picker();
+        assert!(picker.is_visible());
+        assert_eq!(picker.selected_theme(), Some("light"));
+    }
+
+    #[test]
+    fn test_moving_previews_theme() {
+        let mut picker = picker();
+        assert_eq!(
+            picker.handle_input(key(KeyCode::Char('j'))),
+            Some(ThemePickerAction::Preview("Solarized".to_string()))
+        );
+        // At the end of the list nothing moves, so nothing is previewed
+        assert_eq!(picker.handle_input(key(KeyCode::Down)), None);
+        assert_eq!(
+            picker.handle_input(key(KeyCode::Up)),
+            Some(ThemePickerAction::Preview("light".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_select_theme() {
+        let mut picker = picker();
+        picker.handle_input(key(KeyCode::Char('k')));
+        assert_eq!(
+            picker.handle_input(key(KeyCode::Enter)),
+            Some(ThemePickerAction::Select("dark".to_string()))
+        );
+        assert!(!picker.is_visible());
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut picker = picker();
+        picker.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(
+            picker.handle_input(key(KeyCode::Esc)),
+            Some(ThemePickerAction::Cancel)
+        );
+        assert!(!picker.is_visible());
+    }
+}
@@ -0,0 +1,384 @@
+//! Watcher manager component.
+//!
+//! This module provides the popup listing the watchers of an issue, from
+//! which teammates can be added or removed and the current user can start
+//! or stop watching the issue.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::api::types::{User, Watchers};
+
+/// Action returned from the watcher manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatcherAction {
+    /// Open the user picker to add a watcher.
+    Add,
+    /// Remove a watcher (account ID, display name).
+    Remove(String, String),
+    /// Start or stop watching the issue as the current user.
+    ToggleWatch,
+    /// Close the manager.
+    Close,
+}
+
+/// A popup component for viewing and managing the watchers of an issue.
+#[derive(Debug, Default)]
+pub struct WatcherManager {
+    /// Key of the issue whose watchers are shown.
+    issue_key: String,
+    /// The users watching the issue.
+    watchers: Vec<User>,
+    /// Whether the current user is watching the issue.
+    is_watching: bool,
+    /// Currently selected watcher index.
+    selected: usize,
+    /// Whether the watchers are loading.
+    loading: bool,
+    /// Whether the manager is visible.
+    visible: bool,
+}
+
+impl WatcherManager {
+    /// Create a new watcher manager.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the manager in loading state for an issue.
+    pub fn show_loading(&mut self, issue_key: &str) {
+        self.issue_key = issue_key.to_string();
+        self.watchers.clear();
+        self.is_watching = false;
+        self.selected = 0;
+        self.loading = true;
+        self.visible = true;
+    }
+
+    /// Show the manager with the given watchers.
+    ///
+    /// Keeps the selection where it was, clamped to the new list.
+    pub fn show(&mut self, issue_key: &str, watchers: Watchers) {
+        self.issue_key = issue_key.to_string();
+        self.watchers = watchers.watchers;
+        self.is_watching = watchers.is_watching;
+        self.selected = self.selected.min(self.watchers.len().saturating_sub(1));
+        self.loading = false;
+        self.visible = true;
+    }
+
+    /// Hide the manager.
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.loading = false;
+    }
+
+    /// Check if the manager is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Check if the watchers are loading.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Check if the current user is watching the issue.
+    pub fn is_watching(&self) -> bool {
+        self.is_watching
+    }
+
+    /// Get the users watching the issue.
+    pub fn watchers(&self) -> &[User] {
+        &self.watchers
+    }
+
+    /// Get the currently selected watcher.
+    fn selected_watcher(&self) -> Option<&User> {
+        self.watchers.get(self.selected)
+    }
+
+    /// Handle keyboard input.
+    ///
+    /// Returns an optional action to be handled by the parent view.
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<WatcherAction> {
+        if !self.visible {
+            return None;
+        }
+
+        match (key.code, key.modifiers) {
+            (KeyCode::Esc, _) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                self.hide();
+                Some(WatcherAction::Close)
+            }
+            // Ignore everything else while loading
+            _ if self.loading => None,
+            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => {
+                if self.selected + 1 < self.watchers.len() {
+                    self.selected += 1;
+                }
+                None
+            }
+            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => {
+                self.selected = self.selected.saturating_sub(1);
+                None
+            }
+            (KeyCode::Char('a'), KeyModifiers::NONE) => Some(WatcherAction::Add),
+            (KeyCode::Char('d'), KeyModifiers::NONE)
+            | (KeyCode::Char('x'), KeyModifiers::NONE)
+            | (KeyCode::Delete, _) => self.selected_watcher().map(|user| {
+                WatcherAction::Remove(user.account_id.clone(), user.display_name.clone())
+            }),
+            (KeyCode::Char('w'), KeyModifiers::NONE) => Some(WatcherAction::ToggleWatch),
+            _ => None,
+        }
+    }
+
+    /// Render the watcher manager.
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let dialog_width = 50u16.min(area.width.saturating_sub(4));
+        let dialog_height = 16u16.min(area.height.saturating_sub(4));
+        let dialog_area = centered_rect(area, dialog_width, dialog_height);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" Watchers of {} ", self.issue_key),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2), // Watch state
+                Constraint::Min(3),    // Watchers list
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner);
+
+        let state_line = if self.loading {
+            Line::default()
+        } else if self.is_watching {
+            Line::from(Span::styled(
+                "You are watching this issue",
+                Style::default().fg(Color::Green),
+            ))
+        } else {
+            Line::from(Span::styled(
+                "You are not watching this issue",
+                Style::default().fg(Color::DarkGray),
+            ))
+        };
+        frame.render_widget(Paragraph::new(state_line), chunks[0]);
+
+        if self.loading {
+            let loading_text = Paragraph::new("Loading watchers...")
+                .style(Style::default().fg(Color::Gray))
+                .alignment(Alignment::Center);
+            frame.render_widget(loading_text, chunks[1]);
+        } else if self.watchers.is_empty() {
+            let empty_text = Paragraph::new("No watchers")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty_text, chunks[1]);
+        } else {
+            let items: Vec<ListItem> = self
+                .watchers
+                .iter()
+                .map(|user| ListItem::new(format!("  {}", user.display_name)))
+                .collect();
+
+            let list = List::new(items)
+                .highlight_style(
+                    Style::default()
+                        .fg(Color::White)
+                        .bg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("> ");
+
+            let mut state = ListState::default();
+            state.select(Some(self.selected));
+            frame.render_stateful_widget(list, chunks[1], &mut state);
+        }
+
+        let watch_hint = if self.is_watching {
+            ": unwatch  "
+        } else {
+            ": watch  "
+        };
+        let help_text = Line::from(vec![
+            Span::styled("a", Style::default().fg(Color::Green)),
+            Span::raw(": add  "),
+            Span::styled("d", Style::default().fg(Color::Red)),
+            Span::raw(": remove  "),
+            Span::styled("w", Style::default().fg(Color::Cyan)),
+            Span::raw(watch_hint),
+            Span::styled("q", Style::default().fg(Color::Yellow)),
+            Span::raw(": close"),
+        ]);
+        frame.render_widget(
+            Paragraph::new(help_text).alignment(Alignment::Center),
+            chunks[2],
+        );
+    }
+}
+
+/// Create a centered rectangle.
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width.min(area.width), height.min(area.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_user(account_id: &str, display_name: &str) -> User {
+        User {
+            account_id: account_id.to_string(),
+            display_name: display_name.to_string(),
+            email_address: None,
+            active: true,
+            avatar_urls: None,
+        }
+    }
+
+    fn create_test_watchers(is_watching: bool) -> Watchers {
+        Watchers {
+            watch_count: 2,
+            is_watching,
+            watchers: vec![
+                create_test_user("user1", "Alice"),
+                create_test_user("user2", "Bob"),
+            ],
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_show_loading() {
+        let mut manager = WatcherManager::new();
+        manager.show_loading("PROJ-1");
+
+        assert!(manager.is_visible());
+        assert!(manager.is_loading());
+        assert!(manager.watchers().is_empty());
+    }
+
+    #[test]
+    fn test_show_watchers() {
+        let mut manager = WatcherManager::new();
+        manager.show("PROJ-1", create_test_watchers(true));
+
+        assert!(manager.is_visible());
+        assert!(!manager.is_loading());
+        assert_eq!(manager.watchers().len(), 2);
+        assert!(manager.is_watching());
+    }
+
+    #[test]
+    fn test_remove_selected_watcher() {
+        let mut manager = WatcherManager::new();
+        manager.show("PROJ-1", create_test_watchers(false));
+
+        manager.handle_input(key(KeyCode::Char('j')));
+        let action = manager.handle_input(key(KeyCode::Char('d')));
+
+        assert_eq!(
+            action,
+            Some(WatcherAction::Remove(
+                "user2".to_string(),
+                "Bob".to_string()
+            ))
+        );
+        // The manager stays open to show the updated list
+        assert!(manager.is_visible());
+    }
+
+    #[test]
+    fn test_remove_with_no_watchers() {
+        let mut manager = WatcherManager::new();
+        manager.show("PROJ-1", Watchers::default());
+
+        assert!(manager.handle_input(key(KeyCode::Char('d'))).is_none());
+    }
+
+    #[test]
+    fn test_add_and_toggle_watch() {
+        let mut manager = WatcherManager::new();
+        manager.show("PROJ-1", create_test_watchers(false));
+
+        assert_eq!(
+            manager.handle_input(key(KeyCode::Char('a'))),
+            Some(WatcherAction::Add)
+        );
+        assert_eq!(
+            manager.handle_input(key(KeyCode::Char('w'))),
+            Some(WatcherAction::ToggleWatch)
+        );
+    }
+
+    #[test]
+    fn test_selection_clamped_when_list_shrinks() {
+        let mut manager = WatcherManager::new();
+        manager.show("PROJ-1", create_test_watchers(false));
+        manager.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(manager.selected, 1);
+
+        let watchers = Watchers {
+            watch_count: 1,
+            is_watching: false,
+            watchers: vec![create_test_user("user1", "Alice")],
+        };
+        manager.show("PROJ-1", watchers);
+        assert_eq!(manager.selected, 0);
+    }
+
+    #[test]
+    fn test_input_ignored_while_loading() {
+        let mut manager = WatcherManager::new();
+        manager.show_loading("PROJ-1");
+
+        assert!(manager.handle_input(key(KeyCode::Char('a'))).is_none());
+        assert_eq!(
+            manager.handle_input(key(KeyCode::Esc)),
+            Some(WatcherAction::Close)
+        );
+        assert!(!manager.is_visible());
+    }
+
+    #[test]
+    fn test_close_with_q() {
+        let mut manager = WatcherManager::new();
+        manager.show("PROJ-1", create_test_watchers(true));
+
+        assert_eq!(
+            manager.handle_input(key(KeyCode::Char('q'))),
+            Some(WatcherAction::Close)
+        );
+        assert!(!manager.is_visible());
+    }
+}
@@ -3,20 +3,25 @@
 //! This module contains all TUI rendering logic, including views for different
 //! screens and reusable UI components.
 
+pub mod adf;
 mod components;
 pub mod theme;
 mod views;
 
 pub use components::{
-    render_context_help, AssigneeAction, CommandPalette, CommandPaletteAction, ConfirmDialog,
-    DropdownAction, DropdownItem, ErrorDialog, ExternalEditor, JqlAction, JqlInput,
-    LoadingIndicator, Notification, NotificationManager, PriorityAction, ProfilePicker,
-    ProfilePickerAction, SavedFiltersAction, SavedFiltersDialog,
+    init_vim_editing, render_context_help, render_tab_bar, AssigneeAction, CloneOptionsAction,
+    CloneOptionsDialog, CommandPalette, CommandPaletteAction, ConfirmDialog, CreateFieldsAction,
+    DropdownAction, DropdownItem, ErrorDialog, ExternalEditRequest, ExternalEditTarget,
+    ExternalEditor, GoToIssueAction, GoToIssuePicker, IssueCandidate, IssueSource, JqlAction,
+    JqlInput, LoadingIndicator, Notification, NotificationManager, PriorityAction, ProfilePicker,
+    ProfilePickerAction, SavedFiltersAction, SavedFiltersDialog, TemplatePicker,
+    TemplatePickerAction, ThemePicker, ThemePickerAction,
 };
-pub use theme::{init_theme, load_theme};
+pub use theme::init_theme;
 pub use views::{
     CreateIssueAction, CreateIssueRenderData, CreateIssueView, DeleteProfileDialog, DetailAction,
-    DetailView, FilterPanelAction, FilterPanelView, FormField, HelpAction, HelpView, ListAction,
-    ListView, ProfileFormAction, ProfileFormData, ProfileFormView, ProfileListAction,
-    ProfileListView, ProfileSummary,
+    DetailView, FilterPanelAction, FilterPanelView, FormField, HelpAction, HelpView,
+    ImportBundleAction, ImportBundleDialog, ListAction, ListColumn, ListView, ProfileFormAction,
+    ProfileFormData, ProfileFormView, ProfileListAction, ProfileListView, ProfileSummary,
+    ReleaseAction, ReleaseView, SortDirection, SortState,
 };
@@ -1,26 +1,58 @@
 //! Theme and styling configuration.
 //!
 //! This module provides a comprehensive theming system with built-in themes
-//! (dark, light, high-contrast) and support for custom themes via configuration.
+//! (dark, light, high-contrast) and support for custom themes via configuration
+//! and theme files (see [`crate::config::ThemeFile`]).
 
 // Theme style methods are part of the public API
 #![allow(dead_code)]
 
 use ratatui::style::{Color, Modifier, Style};
 use serde::{Deserialize, Serialize};
-use std::sync::OnceLock;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError, RwLock};
 
 use crate::api::types::{Priority, Status};
+use crate::config::ThemeFile;
 
 /// Global theme instance for application-wide access.
-static THEME: OnceLock<Theme> = OnceLock::new();
+static THEME: RwLock<Option<&'static Theme>> = RwLock::new(None);
+
+/// Every theme that has been made global.
+///
+/// Themes are leaked so `theme()` can hand out `'static` references; switching
+/// back to a theme that was used before reuses its allocation.
+static INSTALLED_THEMES: Mutex<Vec<&'static Theme>> = Mutex::new(Vec::new());
 
 /// Initialize the global theme.
 ///
 /// This should be called once at application startup.
 /// Subsequent calls will be ignored.
 pub fn init_theme(theme: Theme) {
-    let _ = THEME.set(theme);
+    let mut current = THEME.write().unwrap_or_else(PoisonError::into_inner);
+    if current.is_none() {
+        *current = Some(install(theme));
+    }
+}
+
+/// Replace the global theme, e.g. when previewing or switching themes.
+pub fn set_theme(theme: Theme) {
+    let theme = install(theme);
+    *THEME.write().unwrap_or_else(PoisonError::into_inner) = Some(theme);
+}
+
+/// Get a `'static` reference to a theme, leaking it unless an equal theme
+/// was installed before.
+fn install(theme: Theme) -> &'static Theme {
+    let mut installed = INSTALLED_THEMES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(existing) = installed.iter().find(|t| ***t == theme) {
+        return existing;
+    }
+    let theme: &'static Theme = Box::leak(Box::new(theme));
+    installed.push(theme);
+    theme
 }
 
 /// Get a reference to the current theme.
@@ -29,16 +61,14 @@ pub fn init_theme(theme: Theme) {
 ///
 /// Panics if the theme has not been initialized.
 pub fn theme() -> &'static Theme {
-    THEME
-        .get()
-        .expect("Theme not initialized. Call init_theme() first.")
+    try_theme().expect("Theme not initialized. Call init_theme() first.")
 }
 
 /// Try to get a reference to the current theme.
 ///
 /// Returns `None` if the theme has not been initialized.
 pub fn try_theme() -> Option<&'static Theme> {
-    THEME.get()
+    *THEME.read().unwrap_or_else(PoisonError::into_inner)
 }
 
 /// Color theme for the application.
@@ -130,6 +160,13 @@ pub struct Theme {
     pub search_match_bg: Color,
     /// Foreground color for search matches.
     pub search_match_fg: Color,
+
+    // === Per-Name Colors ===
+    /// Colors for individual status names (lowercase), overriding the
+    /// status category color.
+    pub status_colors: BTreeMap<String, Color>,
+    /// Colors for individual issue type names (lowercase).
+    pub issue_type_colors: BTreeMap<String, Color>,
 }
 
 impl Default for Theme {
@@ -192,6 +229,9 @@ impl Theme {
             // Search colors
             search_match_bg: Color::Yellow,
             search_match_fg: Color::Black,
+
+            status_colors: BTreeMap::new(),
+            issue_type_colors: BTreeMap::new(),
         }
     }
 
@@ -248,6 +288,9 @@ impl Theme {
             // Search colors
             search_match_bg: Color::Yellow,
             search_match_fg: Color::Black,
+
+            status_colors: BTreeMap::new(),
+            issue_type_colors: BTreeMap::new(),
         }
     }
 
@@ -304,6 +347,59 @@ impl Theme {
             // Search colors
             search_match_bg: Color::Yellow,
             search_match_fg: Color::Black,
+
+            status_colors: BTreeMap::new(),
+            issue_type_colors: BTreeMap::new(),
+        }
+    }
+
+    /// Names of the built-in themes.
+    pub const BUILTIN_NAMES: [&'static str; 3] = ["dark", "light", "high-contrast"];
+
+    /// Names of every color field, as used in theme files and `[custom_theme]`.
+    pub const COLOR_FIELDS: [&'static str; 33] = [
+        "fg",
+        "bg",
+        "muted",
+        "dim",
+        "accent",
+        "accent_dim",
+        "success",
+        "warning",
+        "error",
+        "info",
+        "priority_highest",
+        "priority_high",
+        "priority_medium",
+        "priority_low",
+        "priority_lowest",
+        "status_new",
+        "status_in_progress",
+        "status_done",
+        "border",
+        "border_focused",
+        "selection_bg",
+        "selection_fg",
+        "header_bg",
+        "header_fg",
+        "input_bg",
+        "input_fg",
+        "input_placeholder",
+        "tag_bg",
+        "tag_fg",
+        "component_bg",
+        "component_fg",
+        "search_match_bg",
+        "search_match_fg",
+    ];
+
+    /// Get a built-in theme by name, if there is one.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" | "highcontrast" | "high_contrast" => Some(Self::high_contrast()),
+            _ => None,
         }
     }
 
@@ -312,11 +408,50 @@ impl Theme {
     /// Supported names: "dark", "light", "high-contrast"
     /// Returns the dark theme for unknown names.
     pub fn by_name(name: &str) -> Self {
-        match name.to_lowercase().as_str() {
-            "light" => Self::light(),
-            "high-contrast" | "highcontrast" | "high_contrast" => Self::high_contrast(),
-            _ => Self::dark(),
-        }
+        Self::builtin(name).unwrap_or_else(Self::dark)
+    }
+
+    /// Get a mutable reference to a color field by name.
+    ///
+    /// Returns `None` for names not in [`Theme::COLOR_FIELDS`].
+    pub fn color_mut(&mut self, field: &str) -> Option<&mut Color> {
+        let color = match field {
+            "fg" => &mut self.fg,
+            "bg" => &mut self.bg,
+            "muted" => &mut self.muted,
+            "dim" => &mut self.dim,
+            "accent" => &mut self.accent,
+            "accent_dim" => &mut self.accent_dim,
+            "success" => &mut self.success,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "info" => &mut self.info,
+            "priority_highest" => &mut self.priority_highest,
+            "priority_high" => &mut self.priority_high,
+            "priority_medium" => &mut self.priority_medium,
+            "priority_low" => &mut self.priority_low,
+            "priority_lowest" => &mut self.priority_lowest,
+            "status_new" => &mut self.status_new,
+            "status_in_progress" => &mut self.status_in_progress,
+            "status_done" => &mut self.status_done,
+            "border" => &mut self.border,
+            "border_focused" => &mut self.border_focused,
+            "selection_bg" => &mut self.selection_bg,
+            "selection_fg" => &mut self.selection_fg,
+            "header_bg" => &mut self.header_bg,
+            "header_fg" => &mut self.header_fg,
+            "input_bg" => &mut self.input_bg,
+            "input_fg" => &mut self.input_fg,
+            "input_placeholder" => &mut self.input_placeholder,
+            "tag_bg" => &mut self.tag_bg,
+            "tag_fg" => &mut self.tag_fg,
+            "component_bg" => &mut self.component_bg,
+            "component_fg" => &mut self.component_fg,
+            "search_match_bg" => &mut self.search_match_bg,
+            "search_match_fg" => &mut self.search_match_fg,
+            _ => return None,
+        };
+        Some(color)
     }
 
     // === Style Helper Methods ===
@@ -422,8 +557,13 @@ impl Theme {
             .fg(self.search_match_fg)
     }
 
-    /// Get style for status based on its category.
+    /// Get style for status based on its name or category.
+    ///
+    /// A color set for the status name takes precedence over the category color.
     pub fn status_style(&self, status: &Status) -> Style {
+        if let Some(color) = self.status_colors.get(&status.name.to_lowercase()) {
+            return Style::default().fg(*color);
+        }
         match status.status_category.as_ref().map(|c| c.key.as_str()) {
             Some("new") => Style::default().fg(self.status_new),
             Some("indeterminate") => Style::default().fg(self.status_in_progress),
@@ -432,6 +572,16 @@ impl Theme {
         }
     }
 
+    /// Get style for an issue type name.
+    ///
+    /// Issue types without a color get the default style.
+    pub fn issue_type_style(&self, issue_type: &str) -> Style {
+        match self.issue_type_colors.get(&issue_type.to_lowercase()) {
+            Some(color) => Style::default().fg(*color),
+            None => Style::default(),
+        }
+    }
+
     /// Get style for priority.
     pub fn priority_style(&self, priority: Option<&Priority>) -> Style {
         match priority.map(|p| p.name.as_str()) {
@@ -517,6 +667,15 @@ pub fn priority_style(priority: Option<&Priority>) -> Style {
     }
 }
 
+/// Get the style for an issue type name.
+///
+/// Uses the color the theme sets for the issue type, if any.
+pub fn issue_type_style(issue_type: &str) -> Style {
+    try_theme()
+        .map(|theme| theme.issue_type_style(issue_type))
+        .unwrap_or_default()
+}
+
 /// Get a prefix/icon for an issue type.
 ///
 /// Issue type prefixes:
@@ -581,6 +740,9 @@ pub struct CustomThemeConfig {
     /// Override tag foreground color.
     #[serde(default)]
     pub tag_fg: Option<String>,
+    /// Overrides for any other theme color, keyed by field name.
+    #[serde(flatten)]
+    pub colors: BTreeMap<String, String>,
 }
 
 impl CustomThemeConfig {
@@ -613,6 +775,11 @@ impl CustomThemeConfig {
         if let Some(color) = self.tag_fg.as_ref().and_then(|s| parse_color(s)) {
             theme.tag_fg = color;
         }
+        for (field, value) in &self.colors {
+            if let (Some(slot), Some(color)) = (theme.color_mut(field), parse_color(value)) {
+                *slot = color;
+            }
+        }
     }
 }
 
@@ -650,6 +817,10 @@ pub fn parse_color(s: &str) -> Option<Color> {
 
     // Hex colors: #rgb or #rrggbb
     if let Some(hex) = s.strip_prefix('#') {
+        // Slicing below is by byte, so reject anything multi-byte first.
+        if !hex.is_ascii() {
+            return None;
+        }
         match hex.len() {
             3 => {
                 // #rgb -> #rrggbb
@@ -685,9 +856,18 @@ pub fn parse_color(s: &str) -> Option<Color> {
 
 /// Load a theme from settings.
 ///
-/// Uses the base theme specified in settings and applies any custom color overrides.
-pub fn load_theme(theme_name: &str, custom_config: Option<&CustomThemeConfig>) -> Theme {
-    let mut theme = Theme::by_name(theme_name);
+/// Looks the theme up among the theme files first, then the built-in themes,
+/// and applies any custom color overrides.
+pub fn load_theme(
+    theme_name: &str,
+    custom_config: Option<&CustomThemeConfig>,
+    theme_files: &[ThemeFile],
+) -> Theme {
+    let mut theme = theme_files
+        .iter()
+        .find(|file| file.name.eq_ignore_ascii_case(theme_name))
+        .map(ThemeFile::to_theme)
+        .unwrap_or_else(|| Theme::by_name(theme_name));
 
     if let Some(custom) = custom_config {
         custom.apply_to(&mut theme);
@@ -753,6 +933,8 @@ mod tests {
         assert_eq!(parse_color("#0000ff"), Some(Color::Rgb(0, 0, 255)));
         assert_eq!(parse_color("#f00"), Some(Color::Rgb(255, 0, 0)));
         assert_eq!(parse_color("#0f0"), Some(Color::Rgb(0, 255, 0)));
+        assert_eq!(parse_color("#éa"), None);
+        assert_eq!(parse_color("#ff00é"), None);
     }
 
     #[test]
@@ -791,18 +973,77 @@ mod tests {
 
     #[test]
     fn test_load_theme() {
-        let theme = load_theme("dark", None);
+        let theme = load_theme("dark", None, &[]);
         assert_eq!(theme.name, "dark");
 
         let custom = CustomThemeConfig {
             accent: Some("magenta".to_string()),
             ..Default::default()
         };
-        let theme = load_theme("light", Some(&custom));
+        let theme = load_theme("light", Some(&custom), &[]);
         assert_eq!(theme.name, "light");
         assert_eq!(theme.accent, Color::Magenta);
     }
 
+    #[test]
+    fn test_load_theme_from_file() {
+        let file = ThemeFile::parse_toml(
+            "ocean",
+            "base = \"light\"\n[colors]\naccent = \"#0088cc\"\n",
+        )
+        .unwrap();
+        let theme = load_theme("Ocean", None, &[file]);
+        assert_eq!(theme.name, "ocean");
+        assert_eq!(theme.accent, Color::Rgb(0, 136, 204));
+        assert_eq!(theme.fg, Theme::light().fg);
+    }
+
+    #[test]
+    fn test_custom_theme_any_field() {
+        let custom: CustomThemeConfig =
+            toml::from_str("accent = \"red\"\nselection_bg = \"#222222\"\n").unwrap();
+        assert_eq!(custom.accent.as_deref(), Some("red"));
+        let mut theme = Theme::dark();
+        custom.apply_to(&mut theme);
+        assert_eq!(theme.accent, Color::Red);
+        assert_eq!(theme.selection_bg, Color::Rgb(0x22, 0x22, 0x22));
+    }
+
+    #[test]
+    fn test_color_mut_covers_every_field() {
+        let mut theme = Theme::dark();
+        for field in Theme::COLOR_FIELDS {
+            *theme.color_mut(field).unwrap() = Color::Rgb(1, 2, 3);
+        }
+        assert_eq!(theme.search_match_fg, Color::Rgb(1, 2, 3));
+        assert!(theme.color_mut("nope").is_none());
+    }
+
+    #[test]
+    fn test_status_and_issue_type_colors() {
+        let mut theme = Theme::dark();
+        theme
+            .status_colors
+            .insert("in review".to_string(), Color::Magenta);
+        theme
+            .issue_type_colors
+            .insert("bug".to_string(), Color::Red);
+
+        let status = Status {
+            id: "1".to_string(),
+            name: "In Review".to_string(),
+            status_category: Some(StatusCategory {
+                id: 4,
+                key: "indeterminate".to_string(),
+                name: "In Progress".to_string(),
+                color_name: None,
+            }),
+        };
+        assert_eq!(theme.status_style(&status).fg, Some(Color::Magenta));
+        assert_eq!(theme.issue_type_style("Bug").fg, Some(Color::Red));
+        assert_eq!(theme.issue_type_style("Story"), Style::default());
+    }
+
     #[test]
     fn test_status_style_new() {
         let status = Status {
@@ -4,6 +4,8 @@
 //! the issue list view. It follows the same patterns as `ProfileFormView`
 //! for form structure and keyboard navigation.
 
+use std::time::Instant;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -15,9 +17,10 @@ use ratatui::{
 
 use crate::api::types::{IssueTypeMeta, Priority, User};
 use crate::app::{App, CreateIssueFormData, CreateIssueFormField};
+use crate::events::{keymap, Action, KeyContext};
 use crate::ui::components::{
-    AssigneeAction, AssigneePicker, Dropdown, DropdownAction, DropdownItem, PriorityAction,
-    PriorityPicker, TextEditor, TextInput,
+    AssigneeAction, AssigneePicker, CreateFieldsAction, CreateFieldsPanel, Dropdown,
+    DropdownAction, DropdownItem, PriorityAction, PriorityPicker, TextEditor, TextInput,
 };
 use crate::ui::theme::theme;
 
@@ -63,6 +66,8 @@ pub enum CreateIssueAction {
     FetchAssignableUsers(String),
     /// Request to fetch available priorities.
     FetchPriorities,
+    /// Continue writing the description in the external editor.
+    OpenExternalEditor,
 }
 
 /// The create issue view for adding new JIRA issues.
@@ -87,6 +92,8 @@ pub struct CreateIssueView {
     assignee_picker: AssigneePicker,
     /// Priority picker.
     priority_picker: PriorityPicker,
+    /// Additional fields from the issue type's create metadata.
+    create_fields: CreateFieldsPanel,
     /// Whether the form is submitting.
     submitting: bool,
 }
@@ -122,11 +129,12 @@ impl CreateIssueView {
             summary_input,
             parent_input,
             epic_dropdown,
-            description_editor: TextEditor::empty(),
+            description_editor: TextEditor::empty().with_mentions(),
             project_dropdown,
             issue_type_dropdown,
             assignee_picker: AssigneePicker::new(),
             priority_picker: PriorityPicker::new(),
+            create_fields: CreateFieldsPanel::new(),
             submitting: false,
         }
     }
@@ -136,11 +144,12 @@ impl CreateIssueView {
         self.summary_input.clear();
         self.parent_input.clear();
         self.epic_dropdown.reset();
-        self.description_editor = TextEditor::empty();
+        self.description_editor = TextEditor::empty().with_mentions();
         self.project_dropdown.reset();
         self.issue_type_dropdown.reset();
         self.assignee_picker.hide();
         self.priority_picker.hide();
+        self.create_fields.clear();
         self.submitting = false;
     }
 
@@ -156,7 +165,7 @@ impl CreateIssueView {
 
     /// Set the description value.
     pub fn set_description(&mut self, content: &str) {
-        self.description_editor = TextEditor::new(content);
+        self.description_editor = TextEditor::new(content).with_mentions();
     }
 
     /// Get the description value.
@@ -164,6 +173,31 @@ impl CreateIssueView {
         self.description_editor.content()
     }
 
+    /// Check whether `Esc` should go to the description editor.
+    pub fn description_wants_escape(&self) -> bool {
+        self.description_editor.wants_escape()
+    }
+
+    /// Check if the @mention picker of the description is open.
+    pub fn is_mention_picker_open(&self) -> bool {
+        self.description_editor.is_mention_picker_open()
+    }
+
+    /// Take the user search due for the @mention picker of the description.
+    pub fn take_due_mention_search(&mut self, now: Instant) -> Option<String> {
+        self.description_editor.take_due_mention_search(now)
+    }
+
+    /// Set the results of a mention user search.
+    pub fn set_mention_results(&mut self, query: &str, users: Vec<User>) {
+        self.description_editor.set_mention_results(query, users);
+    }
+
+    /// Mark a mention user search as failed.
+    pub fn set_mention_search_failed(&mut self, query: &str) {
+        self.description_editor.set_mention_search_failed(query);
+    }
+
     /// Set the submitting state.
     pub fn set_submitting(&mut self, submitting: bool) {
         self.submitting = submitting;
@@ -315,14 +349,39 @@ impl CreateIssueView {
 
     /// Set the available users in the assignee picker.
     ///
-    /// Call this after receiving the users from the API.
+    /// Call this after receiving the users from the API. The users go to the
+    /// user picker of an additional field instead if that one is waiting.
     pub fn set_assignable_users(&mut self, users: Vec<User>, current_assignee: &str) {
-        self.assignee_picker.show(users, current_assignee);
+        if self.create_fields.is_user_picker_loading() {
+            self.create_fields.set_users(users);
+        } else {
+            self.assignee_picker.show(users, current_assignee);
+        }
     }
 
     /// Hide the assignee picker.
     pub fn hide_assignee_picker(&mut self) {
         self.assignee_picker.hide();
+        self.create_fields.hide_user_picker();
+    }
+
+    // ========================================================================
+    // Additional Fields Methods
+    // ========================================================================
+
+    /// Get the additional fields panel.
+    pub fn create_fields(&self) -> &CreateFieldsPanel {
+        &self.create_fields
+    }
+
+    /// Get a mutable reference to the additional fields panel.
+    pub fn create_fields_mut(&mut self) -> &mut CreateFieldsPanel {
+        &mut self.create_fields
+    }
+
+    /// Check if a picker of the additional fields is open.
+    pub fn is_create_fields_picker_open(&self) -> bool {
+        self.create_fields.is_picker_open()
     }
 
     // ========================================================================
@@ -395,9 +454,17 @@ impl CreateIssueView {
             return self.handle_epic_input(app, key);
         }
 
+        // If a picker of the additional fields is open, it takes all input
+        if self.create_fields.is_picker_open() {
+            return self.handle_create_fields_input(app, key);
+        }
+
         match (key.code, key.modifiers) {
-            // Tab - next field
-            (KeyCode::Tab, KeyModifiers::NONE) => {
+            // Tab - next field (unless it picks a user in the mention picker)
+            (KeyCode::Tab, KeyModifiers::NONE)
+                if !(focus == CreateIssueFormField::Description
+                    && self.description_editor.is_mention_picker_open()) =>
+            {
                 self.sync_to_app(app);
                 app.create_issue_focus_next();
                 self.sync_from_app(app);
@@ -410,8 +477,23 @@ impl CreateIssueView {
                 self.sync_from_app(app);
                 None
             }
-            // Escape - cancel
-            (KeyCode::Esc, _) => {
+            // Continue the description in the external editor
+            _ if focus == CreateIssueFormField::Description
+                && keymap().matches(KeyContext::Editor, Action::ExternalEditor, key) =>
+            {
+                self.sync_to_app(app);
+                Some(CreateIssueAction::OpenExternalEditor)
+            }
+            // Escape in a modal description editor leaves insert/visual mode
+            (KeyCode::Esc, _)
+                if focus == CreateIssueFormField::Description
+                    && self.description_editor.wants_escape() =>
+            {
+                self.description_editor.handle_input(key);
+                None
+            }
+            // Cancel
+            _ if keymap().matches(KeyContext::Editor, Action::Cancel, key) => {
                 self.reset();
                 Some(CreateIssueAction::Cancel)
             }
@@ -431,7 +513,8 @@ impl CreateIssueView {
                 if focus != CreateIssueFormField::Description
                     && focus != CreateIssueFormField::Project
                     && focus != CreateIssueFormField::IssueType
-                    && focus != CreateIssueFormField::EpicParent =>
+                    && focus != CreateIssueFormField::EpicParent
+                    && focus != CreateIssueFormField::Fields =>
             {
                 self.sync_to_app(app);
                 app.create_issue_focus_next();
@@ -468,6 +551,7 @@ impl CreateIssueView {
             }
             CreateIssueFormField::Assignee => self.handle_assignee_input(app, key),
             CreateIssueFormField::Priority => self.handle_priority_input(app, key),
+            CreateIssueFormField::Fields => self.handle_create_fields_input(app, key),
             CreateIssueFormField::Submit => None,
         }
     }
@@ -602,6 +686,24 @@ impl CreateIssueView {
         self.assignee_picker.handle_input(key)
     }
 
+    /// Handle input for the additional fields panel.
+    fn handle_create_fields_input(
+        &mut self,
+        app: &mut App,
+        key: KeyEvent,
+    ) -> Option<CreateIssueAction> {
+        match self.create_fields.handle_input(key)? {
+            CreateFieldsAction::FetchUsers => {
+                let project_key = app.create_issue_form().project_key.clone();
+                Some(CreateIssueAction::FetchAssignableUsers(project_key))
+            }
+            CreateFieldsAction::Next => {
+                app.create_issue_focus_next();
+                None
+            }
+        }
+    }
+
     /// Handle priority field input - opens the picker on Enter.
     fn handle_priority_input(&mut self, app: &mut App, key: KeyEvent) -> Option<CreateIssueAction> {
         match (key.code, key.modifiers) {
@@ -704,7 +806,7 @@ impl CreateIssueView {
             self.summary_input.set_value(&form.summary);
         }
         if self.description_editor.content() != form.description {
-            self.description_editor = TextEditor::new(&form.description);
+            self.description_editor = TextEditor::new(&form.description).with_mentions();
         }
 
         // Update project dropdown items and selection
@@ -751,7 +853,8 @@ impl CreateIssueView {
         // Determine if we need an extra row for parent/epic field
         let has_parent_field = data.form.is_subtask || data.form.can_have_epic_parent;
         let base_height = if has_parent_field { 33u16 } else { 30u16 };
-        let dialog_height = base_height.min(area.height.saturating_sub(4));
+        let fields_height = self.create_fields.height();
+        let dialog_height = (base_height + fields_height).min(area.height.saturating_sub(4));
 
         let dialog_area = centered_rect(area, dialog_width, dialog_height);
 
@@ -760,14 +863,24 @@ impl CreateIssueView {
 
         // Create the outer block
         let t = theme();
-        let block = Block::default()
+        let title = match (&data.form.clone_of, &data.form.template_name) {
+            (Some(key), _) => format!(" Clone {} ", key),
+            (None, Some(name)) => format!(" Create New Issue: {} ", name),
+            (None, None) => " Create New Issue ".to_string(),
+        };
+        let mut block = Block::default()
             .title(Span::styled(
-                " Create New Issue ",
+                title,
                 Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
             ))
             .borders(Borders::ALL)
             .border_style(Style::default().fg(t.accent));
 
+        // Labels and components come from templates and have no field of their own
+        if let Some(extras) = template_extras(&data.form) {
+            block = block.title_bottom(Span::styled(extras, Style::default().fg(t.muted)));
+        }
+
         let inner = block.inner(dialog_area);
         frame.render_widget(block, dialog_area);
 
@@ -780,15 +893,16 @@ impl CreateIssueView {
                 .direction(Direction::Vertical)
                 .margin(1)
                 .constraints([
-                    Constraint::Length(3), // Project
-                    Constraint::Length(3), // Issue Type
-                    Constraint::Length(3), // Parent Issue (required for subtasks)
-                    Constraint::Length(3), // Summary
-                    Constraint::Length(6), // Description (multi-line)
-                    Constraint::Length(3), // Assignee (optional)
-                    Constraint::Length(3), // Priority (optional)
-                    Constraint::Length(2), // Errors
-                    Constraint::Length(1), // Submit button
+                    Constraint::Length(3),             // Project
+                    Constraint::Length(3),             // Issue Type
+                    Constraint::Length(3),             // Parent Issue (required for subtasks)
+                    Constraint::Length(3),             // Summary
+                    Constraint::Length(6),             // Description (multi-line)
+                    Constraint::Length(3),             // Assignee (optional)
+                    Constraint::Length(3),             // Priority (optional)
+                    Constraint::Length(fields_height), // Additional fields
+                    Constraint::Length(2),             // Errors
+                    Constraint::Length(1),             // Submit button
                 ])
                 .split(inner);
 
@@ -831,8 +945,10 @@ impl CreateIssueView {
                 chunks[6],
                 focus == CreateIssueFormField::Priority,
             );
-            self.render_errors(data, frame, chunks[7]);
-            self.render_submit_button(frame, chunks[8], focus == CreateIssueFormField::Submit);
+            self.create_fields
+                .render(frame, chunks[7], focus == CreateIssueFormField::Fields);
+            self.render_errors(data, frame, chunks[8]);
+            self.render_submit_button(frame, chunks[9], focus == CreateIssueFormField::Submit);
 
             // Render dropdown overlays LAST so they appear on top
             self.render_project_dropdown_overlay(frame, project_area, area);
@@ -841,21 +957,23 @@ impl CreateIssueView {
             // Render picker overlays (on top of everything)
             self.assignee_picker.render(frame, area);
             self.priority_picker.render(frame, area);
+            self.create_fields.render_overlays(frame, area);
         } else if data.form.can_have_epic_parent {
             // Standard issue layout with optional Epic field
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(1)
                 .constraints([
-                    Constraint::Length(3), // Project
-                    Constraint::Length(3), // Issue Type
-                    Constraint::Length(3), // Epic (optional)
-                    Constraint::Length(3), // Summary
-                    Constraint::Length(6), // Description (multi-line)
-                    Constraint::Length(3), // Assignee (optional)
-                    Constraint::Length(3), // Priority (optional)
-                    Constraint::Length(2), // Errors
-                    Constraint::Length(1), // Submit button
+                    Constraint::Length(3),             // Project
+                    Constraint::Length(3),             // Issue Type
+                    Constraint::Length(3),             // Epic (optional)
+                    Constraint::Length(3),             // Summary
+                    Constraint::Length(6),             // Description (multi-line)
+                    Constraint::Length(3),             // Assignee (optional)
+                    Constraint::Length(3),             // Priority (optional)
+                    Constraint::Length(fields_height), // Additional fields
+                    Constraint::Length(2),             // Errors
+                    Constraint::Length(1),             // Submit button
                 ])
                 .split(inner);
 
@@ -899,8 +1017,10 @@ impl CreateIssueView {
                 chunks[6],
                 focus == CreateIssueFormField::Priority,
             );
-            self.render_errors(data, frame, chunks[7]);
-            self.render_submit_button(frame, chunks[8], focus == CreateIssueFormField::Submit);
+            self.create_fields
+                .render(frame, chunks[7], focus == CreateIssueFormField::Fields);
+            self.render_errors(data, frame, chunks[8]);
+            self.render_submit_button(frame, chunks[9], focus == CreateIssueFormField::Submit);
 
             // Render dropdown overlays LAST so they appear on top
             self.render_project_dropdown_overlay(frame, project_area, area);
@@ -910,20 +1030,22 @@ impl CreateIssueView {
             // Render picker overlays (on top of everything)
             self.assignee_picker.render(frame, area);
             self.priority_picker.render(frame, area);
+            self.create_fields.render_overlays(frame, area);
         } else {
             // Epic or other top-level type layout without parent field
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(1)
                 .constraints([
-                    Constraint::Length(3), // Project
-                    Constraint::Length(3), // Issue Type
-                    Constraint::Length(3), // Summary
-                    Constraint::Length(6), // Description (multi-line)
-                    Constraint::Length(3), // Assignee (optional)
-                    Constraint::Length(3), // Priority (optional)
-                    Constraint::Length(2), // Errors
-                    Constraint::Length(1), // Submit button
+                    Constraint::Length(3),             // Project
+                    Constraint::Length(3),             // Issue Type
+                    Constraint::Length(3),             // Summary
+                    Constraint::Length(6),             // Description (multi-line)
+                    Constraint::Length(3),             // Assignee (optional)
+                    Constraint::Length(3),             // Priority (optional)
+                    Constraint::Length(fields_height), // Additional fields
+                    Constraint::Length(2),             // Errors
+                    Constraint::Length(1),             // Submit button
                 ])
                 .split(inner);
 
@@ -960,8 +1082,10 @@ impl CreateIssueView {
                 chunks[5],
                 focus == CreateIssueFormField::Priority,
             );
-            self.render_errors(data, frame, chunks[6]);
-            self.render_submit_button(frame, chunks[7], focus == CreateIssueFormField::Submit);
+            self.create_fields
+                .render(frame, chunks[6], focus == CreateIssueFormField::Fields);
+            self.render_errors(data, frame, chunks[7]);
+            self.render_submit_button(frame, chunks[8], focus == CreateIssueFormField::Submit);
 
             // Render dropdown overlays LAST so they appear on top
             self.render_project_dropdown_overlay(frame, project_area, area);
@@ -970,6 +1094,7 @@ impl CreateIssueView {
             // Render picker overlays (on top of everything)
             self.assignee_picker.render(frame, area);
             self.priority_picker.render(frame, area);
+            self.create_fields.render_overlays(frame, area);
         }
     }
 
@@ -1137,8 +1262,13 @@ impl CreateIssueView {
 
     /// Render the description editor field.
     fn render_description_field(&mut self, frame: &mut Frame, area: Rect, focused: bool) {
+        let title = if focused {
+            " Description (Ctrl+X: $EDITOR) "
+        } else {
+            " Description "
+        };
         self.description_editor
-            .render(frame, area, focused, Some(" Description "));
+            .render(frame, area, focused, Some(title));
     }
 
     /// Render the assignee picker field (optional).
@@ -1283,6 +1413,22 @@ impl CreateIssueView {
 // Helper Functions
 // ============================================================================
 
+/// Describe the labels and components set on the form, if any.
+fn template_extras(form: &CreateIssueFormData) -> Option<String> {
+    let mut parts = Vec::new();
+    if !form.labels.is_empty() {
+        parts.push(format!("Labels: {}", form.labels.join(", ")));
+    }
+    if !form.components.is_empty() {
+        parts.push(format!("Components: {}", form.components.join(", ")));
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(format!(" {} ", parts.join("  ")))
+    }
+}
+
 /// Calculate a centered rectangle within the given area.
 fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
     let x = area.x + (area.width.saturating_sub(width)) / 2;
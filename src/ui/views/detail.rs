@@ -4,6 +4,8 @@
 //! Supports scrolling for long descriptions and keyboard navigation.
 //! Supports edit mode for summary and description fields.
 
+use std::time::Instant;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -15,13 +17,16 @@ use ratatui::{
 
 use crate::api::types::{
     AtlassianDoc, Changelog, Comment, FieldUpdates, Issue, IssueLinkType, IssueSuggestion,
-    IssueUpdateRequest, Priority, Transition, User,
+    IssueUpdateRequest, Priority, Transition, User, VersionField, VersionOperation, Votes,
+    WatchChange, Watchers, Watches,
 };
+use crate::events::{keymap, Action, KeyContext, PendingKeys};
+use crate::ui::adf::{link_targets, render_adf};
 use crate::ui::components::{
     AssigneeAction, AssigneePicker, CommentAction, CommentsPanel, IssueSearchPicker,
     IssueSearchPickerAction, LinkManager, LinkManagerAction, LinkedIssuesSection, PriorityAction,
     PriorityPicker, TagAction, TagEditor, TextEditor, TextInput, TransitionAction,
-    TransitionPicker,
+    TransitionPicker, WatcherAction, WatcherManager,
 };
 use crate::ui::theme::{issue_type_prefix, priority_style, status_style, theme};
 use crate::ui::views::history::{HistoryAction, HistoryView};
@@ -71,6 +76,10 @@ pub enum DetailAction {
     AddComponent(String, String),
     /// Remove a component from the issue (issue key, component name).
     RemoveComponent(String, String),
+    /// Request versions from the API (issue key, project key, version field).
+    FetchVersions(String, String, VersionField),
+    /// Add or remove a fix or affects version (issue key, version field, operation).
+    ChangeVersion(String, VersionField, VersionOperation),
     /// Open the history panel (issue key).
     OpenHistory(String),
     /// Request changelog from the API (issue key).
@@ -95,12 +104,26 @@ pub enum DetailAction {
     DeleteLink(String, String),
     /// Open description in external editor (issue key).
     OpenExternalEditor(String),
+    /// Continue writing a comment in the external editor (issue key, draft).
+    OpenExternalCommentEditor(String, String),
     /// Open the issue in the browser (issue key).
     OpenInBrowser(String),
+    /// Open a link referenced in the description (URL).
+    OpenLink(String),
     /// Confirm deletion of the current issue (issue key).
     ConfirmDeleteIssue(String),
     /// Delete the current issue (issue key).
     DeleteIssue(String),
+    /// Clone the current issue (issue key).
+    CloneIssue(String),
+    /// Create a subtask of the current issue (issue key).
+    CreateSubtask(String),
+    /// Request the watchers of the issue from the API (issue key).
+    FetchWatchers(String),
+    /// Request users that can be added as watchers (issue key, project key).
+    FetchWatcherCandidates(String, String),
+    /// Change the watchers or votes of the issue (issue key, change).
+    ChangeWatch(String, WatchChange),
 }
 
 /// Which field is currently being edited.
@@ -159,6 +182,10 @@ pub struct DetailView {
     history_view: HistoryView,
     /// Component editor for adding/removing components.
     component_editor: TagEditor,
+    /// Version editor for adding/removing fix or affects versions.
+    version_editor: TagEditor,
+    /// The version field being edited in the version editor.
+    version_field: VersionField,
     /// Linked issues section for displaying related issues.
     linked_issues: LinkedIssuesSection,
     /// Link manager for viewing/managing issue links.
@@ -167,6 +194,14 @@ pub struct DetailView {
     issue_search_picker: IssueSearchPicker,
     /// The selected link type when creating a link.
     pending_link_type: Option<(IssueLinkType, bool)>,
+    /// Link targets referenced in the description, opened with `1`-`9`.
+    description_links: Vec<String>,
+    /// Watcher manager for viewing/managing issue watchers.
+    watcher_manager: WatcherManager,
+    /// User picker for adding a watcher.
+    watcher_picker: AssigneePicker,
+    /// Partially typed key sequence of a multi-key binding.
+    keys: PendingKeys,
 }
 
 impl DetailView {
@@ -187,10 +222,16 @@ impl DetailView {
             label_editor: TagEditor::for_labels(),
             history_view: HistoryView::new(),
             component_editor: TagEditor::for_components(),
+            version_editor: TagEditor::for_versions(VersionField::Fix),
+            version_field: VersionField::Fix,
             linked_issues: LinkedIssuesSection::empty(),
             link_manager: LinkManager::new(),
             issue_search_picker: IssueSearchPicker::new(),
             pending_link_type: None,
+            description_links: Vec::new(),
+            watcher_manager: WatcherManager::new(),
+            watcher_picker: AssigneePicker::user_picker(" Add Watcher "),
+            keys: PendingKeys::default(),
         }
     }
 
@@ -202,6 +243,10 @@ impl DetailView {
             &issue.fields.subtasks,
             issue.fields.parent.clone(),
         );
+        self.description_links = issue
+            .description_doc()
+            .map(|doc| link_targets(&doc))
+            .unwrap_or_default();
         self.issue = Some(issue);
         self.scroll = 0;
         self.max_scroll = 0;
@@ -214,6 +259,9 @@ impl DetailView {
         self.label_editor.hide();
         self.history_view.hide();
         self.component_editor.hide();
+        self.version_editor.hide();
+        self.watcher_manager.hide();
+        self.watcher_picker.hide();
     }
 
     /// Clear the current issue.
@@ -230,6 +278,9 @@ impl DetailView {
         self.label_editor.hide();
         self.history_view.hide();
         self.component_editor.hide();
+        self.version_editor.hide();
+        self.watcher_manager.hide();
+        self.watcher_picker.hide();
         self.linked_issues = LinkedIssuesSection::empty();
     }
 
@@ -243,6 +294,13 @@ impl DetailView {
         self.scroll
     }
 
+    /// Set the scroll position.
+    ///
+    /// Clamped to the content height on the next render.
+    pub fn set_scroll(&mut self, scroll: u16) {
+        self.scroll = scroll;
+    }
+
     /// Get the maximum scroll position.
     pub fn max_scroll(&self) -> u16 {
         self.max_scroll
@@ -259,6 +317,23 @@ impl DetailView {
         self.edit_state.is_some()
     }
 
+    /// Check if a picker or editor popup is open over the issue.
+    ///
+    /// The comments and history panels are not counted; they are part of
+    /// the view's navigation location.
+    pub fn has_popup(&self) -> bool {
+        self.transition_picker.is_visible()
+            || self.assignee_picker.is_visible()
+            || self.priority_picker.is_visible()
+            || self.label_editor.is_visible()
+            || self.component_editor.is_visible()
+            || self.version_editor.is_visible()
+            || self.link_manager.is_visible()
+            || self.issue_search_picker.is_visible()
+            || self.watcher_picker.is_visible()
+            || self.watcher_manager.is_visible()
+    }
+
     /// Check if there are unsaved changes.
     pub fn has_unsaved_changes(&self) -> bool {
         if let Some(edit_state) = &self.edit_state {
@@ -489,6 +564,124 @@ impl DetailView {
         self.component_editor.hide();
     }
 
+    // ========================================================================
+    // Version editor methods
+    // ========================================================================
+
+    /// Check if the version editor is visible.
+    pub fn is_version_editor_visible(&self) -> bool {
+        self.version_editor.is_visible()
+    }
+
+    /// Get the version field being edited.
+    pub fn version_field(&self) -> VersionField {
+        self.version_field
+    }
+
+    /// Get the names of the versions in a version field of the current issue.
+    fn current_versions(&self, field: VersionField) -> Vec<String> {
+        self.issue
+            .as_ref()
+            .map(|issue| version_names(issue, field))
+            .unwrap_or_default()
+    }
+
+    /// Show the version editor for a version field in loading state.
+    ///
+    /// This should be called when the user presses 'V' or 'A' to open the
+    /// editor, and then the API call to get versions should be made.
+    pub fn show_version_editor_loading(&mut self, field: VersionField) {
+        if self.issue.is_some() {
+            if field != self.version_field {
+                self.version_field = field;
+                self.version_editor = TagEditor::for_versions(field);
+            }
+            let current_versions = self.current_versions(field);
+            self.version_editor.show_loading(current_versions);
+        }
+    }
+
+    /// Set the available versions in the editor.
+    ///
+    /// Call this after receiving the versions from the API.
+    pub fn set_versions(&mut self, versions: Vec<String>) {
+        if self.issue.is_some() {
+            let current_versions = self.current_versions(self.version_field);
+            self.version_editor.show(current_versions, versions);
+        }
+    }
+
+    /// Hide the version editor.
+    pub fn hide_version_editor(&mut self) {
+        self.version_editor.hide();
+    }
+
+    // ========================================================================
+    // Watcher methods
+    // ========================================================================
+
+    /// Check if the watcher manager is visible.
+    pub fn is_watcher_manager_visible(&self) -> bool {
+        self.watcher_manager.is_visible()
+    }
+
+    /// Check if the watcher user picker is visible.
+    pub fn is_watcher_picker_visible(&self) -> bool {
+        self.watcher_picker.is_visible()
+    }
+
+    /// Show the watcher manager in loading state.
+    ///
+    /// This should be called when the user presses 'W' to open the manager,
+    /// and then the API call to get watchers should be made.
+    pub fn show_watcher_manager_loading(&mut self) {
+        if let Some(issue) = &self.issue {
+            self.watcher_manager.show_loading(&issue.key);
+        }
+    }
+
+    /// Set the watchers shown in the watcher manager.
+    ///
+    /// Call this after receiving the watchers from the API.
+    pub fn set_watchers(&mut self, watchers: Watchers) {
+        if let Some(issue) = &self.issue {
+            self.watcher_manager.show(&issue.key, watchers);
+        }
+    }
+
+    /// Set the users offered in the watcher user picker.
+    ///
+    /// Users already watching the issue are left out.
+    pub fn set_watcher_candidates(&mut self, users: Vec<User>) {
+        let watchers = self.watcher_manager.watchers();
+        let candidates = users
+            .into_iter()
+            .filter(|u| !watchers.iter().any(|w| w.account_id == u.account_id))
+            .collect();
+        let current = self.watcher_names();
+        self.watcher_picker.show(candidates, &current);
+    }
+
+    /// Hide the watcher manager.
+    pub fn hide_watcher_manager(&mut self) {
+        self.watcher_manager.hide();
+    }
+
+    /// Hide the watcher user picker.
+    pub fn hide_watcher_picker(&mut self) {
+        self.watcher_picker.hide();
+    }
+
+    /// Get the names of the current watchers, comma separated.
+    fn watcher_names(&self) -> String {
+        self.watcher_manager
+            .watchers()
+            .iter()
+            .map(|w| w.display_name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     // ========================================================================
     // Comments panel methods
     // ========================================================================
@@ -498,6 +691,53 @@ impl DetailView {
         self.comments_panel.is_visible()
     }
 
+    /// Check if a comment is being written in the comments panel.
+    pub fn is_composing_comment(&self) -> bool {
+        self.comments_panel.is_visible() && self.comments_panel.is_composing()
+    }
+
+    /// Take the user search due for an @mention picker in the comment or
+    /// description editor.
+    pub fn take_due_mention_search(&mut self, now: Instant) -> Option<String> {
+        if let Some(query) = self.comments_panel.take_due_mention_search(now) {
+            return Some(query);
+        }
+        self.edit_state
+            .as_mut()?
+            .description_editor
+            .take_due_mention_search(now)
+    }
+
+    /// Set the results of a mention user search.
+    pub fn set_mention_results(&mut self, query: &str, users: Vec<User>) {
+        if let Some(edit_state) = &mut self.edit_state {
+            edit_state
+                .description_editor
+                .set_mention_results(query, users.clone());
+        }
+        self.comments_panel.set_mention_results(query, users);
+    }
+
+    /// Mark a mention user search as failed.
+    pub fn set_mention_search_failed(&mut self, query: &str) {
+        if let Some(edit_state) = &mut self.edit_state {
+            edit_state
+                .description_editor
+                .set_mention_search_failed(query);
+        }
+        self.comments_panel.set_mention_search_failed(query);
+    }
+
+    /// Get the loaded comments, newest first.
+    pub fn comments(&self) -> &[Comment] {
+        self.comments_panel.comments()
+    }
+
+    /// Continue composing a comment with the given draft.
+    pub fn set_comment_draft(&mut self, content: &str) {
+        self.comments_panel.set_draft(content);
+    }
+
     /// Check if comments are loading.
     pub fn is_comments_loading(&self) -> bool {
         self.comments_panel.is_loading()
@@ -627,12 +867,12 @@ impl DetailView {
     pub fn enter_edit_mode(&mut self) {
         if let Some(issue) = &self.issue {
             let summary = issue.fields.summary.clone();
-            let description = issue.description_text();
+            let description = issue.description_markdown();
 
             let mut summary_input = TextInput::with_value(&summary);
             summary_input.set_placeholder("Enter summary...");
 
-            let description_editor = TextEditor::new(&description);
+            let description_editor = TextEditor::new(&description).with_mentions();
 
             self.edit_state = Some(EditState {
                 field: EditField::Summary,
@@ -652,14 +892,14 @@ impl DetailView {
     pub fn set_external_edit_content(&mut self, content: String) {
         if let Some(issue) = &self.issue {
             let summary = issue.fields.summary.clone();
-            let original_description = issue.description_text();
+            let original_description = issue.description_markdown();
 
             let mut summary_input = TextInput::with_value(&summary);
             summary_input.set_placeholder("Enter summary...");
 
             // Create description editor with the external editor content
             // but set the original content to the issue's description for change tracking
-            let mut description_editor = TextEditor::new(&content);
+            let mut description_editor = TextEditor::new(&content).with_mentions();
             description_editor.set_original_content(&original_description);
 
             self.edit_state = Some(EditState {
@@ -710,7 +950,7 @@ impl DetailView {
         // Check if description changed
         if edit_state.description_editor.has_changes() {
             let new_description = edit_state.description_editor.content();
-            fields.description = Some(AtlassianDoc::from_plain_text(&new_description));
+            fields.description = Some(AtlassianDoc::from_markdown(&new_description));
             has_changes = true;
         }
 
@@ -763,6 +1003,11 @@ impl DetailView {
             return self.handle_component_editor_input(key);
         }
 
+        // Handle version editor (blocks other input when visible)
+        if self.version_editor.is_visible() {
+            return self.handle_version_editor_input(key);
+        }
+
         // Handle link manager (blocks other input when visible)
         if self.link_manager.is_visible() {
             return self.handle_link_manager_input(key);
@@ -773,56 +1018,75 @@ impl DetailView {
             return self.handle_issue_search_picker_input(key);
         }
 
+        // Handle watcher user picker (shown over the watcher manager)
+        if self.watcher_picker.is_visible() {
+            return self.handle_watcher_picker_input(key);
+        }
+
+        // Handle watcher manager (blocks other input when visible)
+        if self.watcher_manager.is_visible() {
+            return self.handle_watcher_manager_input(key);
+        }
+
         // If in edit mode, handle edit-specific input
         if self.edit_state.is_some() {
             return self.handle_edit_input(key);
         }
 
+        // Open a link referenced in the description
+        if let (KeyCode::Char(c @ '1'..='9'), KeyModifiers::NONE) = (key.code, key.modifiers) {
+            if !self.keys.is_pending() {
+                let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+                return self
+                    .description_links
+                    .get(index)
+                    .map(|url| DetailAction::OpenLink(url.clone()));
+            }
+        }
+
         // Normal (view) mode input handling
-        match (key.code, key.modifiers) {
+        match self.keys.resolve(KeyContext::IssueDetail, key)? {
             // Navigation - go back
-            (KeyCode::Char('q'), KeyModifiers::NONE) | (KeyCode::Esc, KeyModifiers::NONE) => {
-                Some(DetailAction::GoBack)
-            }
+            Action::Back => Some(DetailAction::GoBack),
             // Scroll down
-            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => {
+            Action::MoveDown => {
                 self.scroll_down();
                 None
             }
             // Scroll up
-            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => {
+            Action::MoveUp => {
                 self.scroll_up();
                 None
             }
             // Page down
-            (KeyCode::PageDown, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
+            Action::PageDown => {
                 self.page_down();
                 None
             }
             // Page up
-            (KeyCode::PageUp, _) | (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+            Action::PageUp => {
                 self.page_up();
                 None
             }
             // Go to top
-            (KeyCode::Home, _) | (KeyCode::Char('g'), KeyModifiers::NONE) => {
+            Action::GoTop => {
                 self.scroll = 0;
                 None
             }
             // Go to bottom
-            (KeyCode::End, _) | (KeyCode::Char('G'), KeyModifiers::SHIFT) => {
+            Action::GoBottom => {
                 self.scroll = self.max_scroll;
                 None
             }
             // Edit issue
-            (KeyCode::Char('e'), KeyModifiers::NONE) => Some(DetailAction::EditIssue),
+            Action::Edit => Some(DetailAction::EditIssue),
             // Open description in external editor
-            (KeyCode::Char('E'), KeyModifiers::SHIFT) => self
+            Action::ExternalEditor => self
                 .issue
                 .as_ref()
                 .map(|issue| DetailAction::OpenExternalEditor(issue.key.clone())),
             // Open comments panel
-            (KeyCode::Char('c'), KeyModifiers::NONE) => {
+            Action::Comments => {
                 if let Some(issue) = &self.issue {
                     let issue_key = issue.key.clone();
                     self.show_comments_panel();
@@ -832,7 +1096,7 @@ impl DetailView {
                 }
             }
             // Change status (open transition picker)
-            (KeyCode::Char('s'), KeyModifiers::NONE) => {
+            Action::ChangeStatus => {
                 if let Some(issue) = &self.issue {
                     let issue_key = issue.key.clone();
                     let current_status = issue.fields.status.name.clone();
@@ -843,7 +1107,7 @@ impl DetailView {
                 }
             }
             // Change assignee (open assignee picker)
-            (KeyCode::Char('a'), KeyModifiers::NONE) => {
+            Action::Assign => {
                 if let Some(issue) = &self.issue {
                     let issue_key = issue.key.clone();
                     let project_key = issue.project_key().unwrap_or("").to_string();
@@ -854,7 +1118,7 @@ impl DetailView {
                 }
             }
             // Change priority (open priority picker)
-            (KeyCode::Char('p'), KeyModifiers::NONE) => {
+            Action::ChangePriority => {
                 if let Some(issue) = &self.issue {
                     let issue_key = issue.key.clone();
                     self.show_priority_picker_loading();
@@ -864,7 +1128,7 @@ impl DetailView {
                 }
             }
             // Edit labels (open label editor)
-            (KeyCode::Char('l'), KeyModifiers::NONE) => {
+            Action::EditLabels => {
                 if let Some(issue) = &self.issue {
                     let issue_key = issue.key.clone();
                     self.show_label_editor_loading();
@@ -874,7 +1138,7 @@ impl DetailView {
                 }
             }
             // Edit components (open component editor)
-            (KeyCode::Char('C'), KeyModifiers::SHIFT) => {
+            Action::EditComponents => {
                 if let Some(issue) = &self.issue {
                     let issue_key = issue.key.clone();
                     let project_key = issue.project_key().unwrap_or("").to_string();
@@ -884,8 +1148,11 @@ impl DetailView {
                     None
                 }
             }
+            // Edit fix versions / affects versions (open version editor)
+            Action::EditFixVersions => self.open_version_editor(VersionField::Fix),
+            Action::EditAffectsVersions => self.open_version_editor(VersionField::Affects),
             // View history (open history panel)
-            (KeyCode::Char('h'), KeyModifiers::NONE) => {
+            Action::History => {
                 if let Some(issue) = &self.issue {
                     let issue_key = issue.key.clone();
                     self.show_history();
@@ -895,22 +1162,60 @@ impl DetailView {
                 }
             }
             // Open link manager (view/manage issue links)
-            (KeyCode::Char('L'), KeyModifiers::SHIFT) => {
+            Action::Links => {
                 if self.issue.is_some() {
                     self.show_link_manager();
                 }
                 None
             }
             // Open in browser
-            (KeyCode::Char('o'), KeyModifiers::NONE) => self
+            Action::OpenInBrowser => self
                 .issue
                 .as_ref()
                 .map(|issue| DetailAction::OpenInBrowser(issue.key.clone())),
             // Delete issue (with confirmation)
-            (KeyCode::Char('D'), KeyModifiers::SHIFT) => self
+            Action::Delete => self
                 .issue
                 .as_ref()
                 .map(|issue| DetailAction::ConfirmDeleteIssue(issue.key.clone())),
+            // Clone issue
+            Action::CloneIssue => self
+                .issue
+                .as_ref()
+                .map(|issue| DetailAction::CloneIssue(issue.key.clone())),
+            // Create subtask
+            Action::CreateSubtask => self
+                .issue
+                .as_ref()
+                .map(|issue| DetailAction::CreateSubtask(issue.key.clone())),
+            // Watch/unwatch
+            Action::ToggleWatch => self.issue.as_ref().map(|issue| {
+                let change = if issue.watches().unwrap_or_default().is_watching {
+                    WatchChange::Unwatch
+                } else {
+                    WatchChange::Watch
+                };
+                DetailAction::ChangeWatch(issue.key.clone(), change)
+            }),
+            // Manage watchers (open watcher manager)
+            Action::ManageWatchers => {
+                if let Some(issue) = &self.issue {
+                    let issue_key = issue.key.clone();
+                    self.show_watcher_manager_loading();
+                    Some(DetailAction::FetchWatchers(issue_key))
+                } else {
+                    None
+                }
+            }
+            // Vote/unvote
+            Action::ToggleVote => self.issue.as_ref().map(|issue| {
+                let change = if issue.votes().unwrap_or_default().has_voted {
+                    WatchChange::Unvote
+                } else {
+                    WatchChange::Vote
+                };
+                DetailAction::ChangeWatch(issue.key.clone(), change)
+            }),
             _ => None,
         }
     }
@@ -1030,6 +1335,31 @@ impl DetailView {
         }
     }
 
+    /// Open the version editor for a version field.
+    fn open_version_editor(&mut self, field: VersionField) -> Option<DetailAction> {
+        let issue = self.issue.as_ref()?;
+        let issue_key = issue.key.clone();
+        let project_key = issue.project_key().unwrap_or("").to_string();
+        self.show_version_editor_loading(field);
+        Some(DetailAction::FetchVersions(issue_key, project_key, field))
+    }
+
+    /// Handle keyboard input for the version editor.
+    fn handle_version_editor_input(&mut self, key: KeyEvent) -> Option<DetailAction> {
+        let operation = match self.version_editor.handle_input(key)? {
+            // Versions must exist in the project; JIRA rejects unknown names
+            TagAction::Add(name) | TagAction::Create(name) => VersionOperation::Add { name },
+            TagAction::Remove(name) => VersionOperation::Remove { name },
+            TagAction::Cancel => return None,
+        };
+        let issue_key = self.issue.as_ref()?.key.clone();
+        Some(DetailAction::ChangeVersion(
+            issue_key,
+            self.version_field,
+            operation,
+        ))
+    }
+
     /// Handle keyboard input for the component editor.
     fn handle_component_editor_input(&mut self, key: KeyEvent) -> Option<DetailAction> {
         if let Some(action) = self.component_editor.handle_input(key) {
@@ -1137,6 +1467,47 @@ impl DetailView {
         }
     }
 
+    /// Handle keyboard input for the watcher manager.
+    fn handle_watcher_manager_input(&mut self, key: KeyEvent) -> Option<DetailAction> {
+        let action = self.watcher_manager.handle_input(key)?;
+        let issue = self.issue.as_ref()?;
+        let issue_key = issue.key.clone();
+        match action {
+            WatcherAction::Add => {
+                let project_key = issue.project_key().unwrap_or("").to_string();
+                let current = self.watcher_names();
+                self.watcher_picker.show_loading(&current);
+                Some(DetailAction::FetchWatcherCandidates(issue_key, project_key))
+            }
+            WatcherAction::Remove(account_id, display_name) => Some(DetailAction::ChangeWatch(
+                issue_key,
+                WatchChange::RemoveWatcher(account_id, display_name),
+            )),
+            WatcherAction::ToggleWatch => {
+                let change = if self.watcher_manager.is_watching() {
+                    WatchChange::Unwatch
+                } else {
+                    WatchChange::Watch
+                };
+                Some(DetailAction::ChangeWatch(issue_key, change))
+            }
+            WatcherAction::Close => None,
+        }
+    }
+
+    /// Handle keyboard input for the watcher user picker.
+    fn handle_watcher_picker_input(&mut self, key: KeyEvent) -> Option<DetailAction> {
+        match self.watcher_picker.handle_input(key)? {
+            AssigneeAction::Select(account_id, display_name) => self.issue.as_ref().map(|issue| {
+                DetailAction::ChangeWatch(
+                    issue.key.clone(),
+                    WatchChange::AddWatcher(account_id, display_name),
+                )
+            }),
+            AssigneeAction::Unassign | AssigneeAction::Cancel => None,
+        }
+    }
+
     /// Handle keyboard input for the comments panel.
     fn handle_comments_panel_input(&mut self, key: KeyEvent) -> Option<DetailAction> {
         if let Some(action) = self.comments_panel.handle_input(key) {
@@ -1154,6 +1525,10 @@ impl DetailView {
                 CommentAction::LoadComments(issue_key) => {
                     Some(DetailAction::FetchComments(issue_key))
                 }
+                CommentAction::OpenExternalEditor(draft) => self
+                    .issue
+                    .as_ref()
+                    .map(|issue| DetailAction::OpenExternalCommentEditor(issue.key.clone(), draft)),
             }
         } else {
             None
@@ -1165,9 +1540,21 @@ impl DetailView {
         // Check if we're actively editing text
         let is_editing = self.edit_state.as_ref().map(|s| s.editing).unwrap_or(false);
 
+        let editor_wants_escape = self.edit_state.as_ref().is_some_and(|s| {
+            s.field == EditField::Description && s.description_editor.wants_escape()
+        });
+        let keymap = keymap();
+
         match (key.code, key.modifiers) {
-            // Escape - either exit text editing mode, or cancel edit (may show confirmation)
-            (KeyCode::Esc, KeyModifiers::NONE) => {
+            // Leave insert/visual mode of the modal description editor
+            (KeyCode::Esc, KeyModifiers::NONE) if is_editing && editor_wants_escape => {
+                if let Some(edit_state) = &mut self.edit_state {
+                    edit_state.description_editor.handle_input(key);
+                }
+                None
+            }
+            // Cancel - either exit text editing mode, or cancel edit (may show confirmation)
+            _ if keymap.matches(KeyContext::Editor, Action::Cancel, key) => {
                 if is_editing {
                     // Exit text editing mode, but stay in edit view
                     if let Some(edit_state) = &mut self.edit_state {
@@ -1181,8 +1568,8 @@ impl DetailView {
                     None
                 }
             }
-            // Ctrl+S - save changes (works in both modes)
-            (KeyCode::Char('s'), KeyModifiers::CONTROL) => {
+            // Save changes (works in both modes)
+            _ if keymap.matches(KeyContext::Editor, Action::Submit, key) => {
                 if let Some(issue) = &self.issue {
                     if let Some(update_request) = self.create_update_request() {
                         Some(DetailAction::SaveEdit(issue.key.clone(), update_request))
@@ -1286,8 +1673,12 @@ impl DetailView {
             .iter()
             .map(|c| c.name.clone())
             .collect();
-        let description = issue.description_text();
+        let fix_versions = version_names(issue, VersionField::Fix);
+        let affects_versions = version_names(issue, VersionField::Affects);
+        let description = issue.description_doc();
         let project_key = issue.project_key().map(|s| s.to_string());
+        let watches = issue.watches();
+        let votes = issue.votes();
 
         // Calculate linked issues height
         let linked_issues_height = self.linked_issues.height();
@@ -1299,7 +1690,7 @@ impl DetailView {
                 .constraints([
                     Constraint::Length(3),                    // Header (type + key)
                     Constraint::Length(2),                    // Summary
-                    Constraint::Length(7),                    // Metadata
+                    Constraint::Length(8),                    // Metadata
                     Constraint::Length(linked_issues_height), // Linked issues
                     Constraint::Min(5),                       // Description (scrollable)
                 ])
@@ -1310,7 +1701,7 @@ impl DetailView {
                 .constraints([
                     Constraint::Length(3), // Header (type + key)
                     Constraint::Length(2), // Summary
-                    Constraint::Length(7), // Metadata
+                    Constraint::Length(8), // Metadata
                     Constraint::Min(5),    // Description (scrollable)
                 ])
                 .split(area)
@@ -1334,17 +1725,21 @@ impl DetailView {
             updated.as_deref(),
             &labels,
             &components,
+            &fix_versions,
+            &affects_versions,
             project_key.as_deref(),
+            watches.as_ref(),
+            votes.as_ref(),
         );
 
         // Render linked issues (if any)
         if linked_issues_height > 0 {
             self.linked_issues.render(frame, chunks[3]);
             // Render description (scrollable)
-            self.render_description(frame, chunks[4], &description);
+            self.render_description(frame, chunks[4], description.as_ref());
         } else {
             // Render description (scrollable)
-            self.render_description(frame, chunks[3], &description);
+            self.render_description(frame, chunks[3], description.as_ref());
         }
 
         // Render pickers and panels (overlays)
@@ -1353,8 +1748,11 @@ impl DetailView {
         self.priority_picker.render(frame, area);
         self.label_editor.render(frame, area);
         self.component_editor.render(frame, area);
+        self.version_editor.render(frame, area);
         self.link_manager.render(frame, area);
         self.issue_search_picker.render(frame, area);
+        self.watcher_manager.render(frame, area);
+        self.watcher_picker.render(frame, area);
         self.comments_panel.render(frame, area);
         self.history_view.render(frame, area);
     }
@@ -1459,15 +1857,21 @@ impl DetailView {
             Some(description_title),
             description_border,
         );
+        let description_wants_escape = edit_state.description_editor.wants_escape();
 
         // Put edit_state back
         self.edit_state = Some(edit_state);
 
         // Render edit mode hints (context-sensitive based on editing state)
         let hints = if is_editing {
+            let esc_hint = if description_focused && description_wants_escape {
+                ": normal mode  "
+            } else {
+                ": stop editing  "
+            };
             Line::from(vec![
                 Span::styled("Esc", Style::default().fg(t.warning)),
-                Span::raw(": stop editing  "),
+                Span::raw(esc_hint),
                 Span::styled("Ctrl+S", Style::default().fg(t.success)),
                 Span::raw(": save  "),
             ])
@@ -1499,12 +1903,15 @@ impl DetailView {
     /// Render the header section with issue type and key.
     fn render_header(&self, frame: &mut Frame, area: Rect, issue_type: &str, key: &str) {
         let t = theme();
-        let header_text = format!("{} - {}", issue_type, key);
+        let accent = Style::default().fg(t.accent).add_modifier(Modifier::BOLD);
 
-        let header = Paragraph::new(Line::from(vec![Span::styled(
-            header_text,
-            Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
-        )]))
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled(
+                issue_type.to_string(),
+                accent.patch(t.issue_type_style(issue_type)),
+            ),
+            Span::styled(format!(" - {}", key), accent),
+        ]))
         .block(
             Block::default()
                 .borders(Borders::BOTTOM)
@@ -1539,7 +1946,11 @@ impl DetailView {
         updated: Option<&str>,
         labels: &[String],
         components: &[String],
+        fix_versions: &[String],
+        affects_versions: &[String],
         project: Option<&str>,
+        watches: Option<&Watches>,
+        votes: Option<&Votes>,
     ) {
         let t = theme();
         let status_sty = status_style(status);
@@ -1565,12 +1976,34 @@ impl DetailView {
             ]),
         ];
 
-        // Project (if available)
+        // Project, watchers and votes (if available)
+        let mut project_spans = Vec::new();
         if let Some(proj) = project {
-            lines.push(Line::from(vec![
-                Span::styled("Project: ", Style::default().fg(t.dim)),
-                Span::raw(proj),
-            ]));
+            project_spans.push(Span::styled("Project: ", Style::default().fg(t.dim)));
+            project_spans.push(Span::raw(proj));
+        }
+        if let Some(watches) = watches {
+            if !project_spans.is_empty() {
+                project_spans.push(Span::raw("    "));
+            }
+            project_spans.push(Span::styled("Watchers: ", Style::default().fg(t.dim)));
+            project_spans.push(Span::raw(watches.watch_count.to_string()));
+            if watches.is_watching {
+                project_spans.push(Span::styled(" (watching)", Style::default().fg(t.success)));
+            }
+        }
+        if let Some(votes) = votes {
+            if !project_spans.is_empty() {
+                project_spans.push(Span::raw("    "));
+            }
+            project_spans.push(Span::styled("Votes: ", Style::default().fg(t.dim)));
+            project_spans.push(Span::raw(votes.votes.to_string()));
+            if votes.has_voted {
+                project_spans.push(Span::styled(" (voted)", Style::default().fg(t.success)));
+            }
+        }
+        if !project_spans.is_empty() {
+            lines.push(Line::from(project_spans));
         }
 
         // Dates
@@ -1619,6 +2052,23 @@ impl DetailView {
             lines.push(Line::from(comp_spans));
         }
 
+        // Fix and affects versions
+        let mut version_spans = Vec::new();
+        if !fix_versions.is_empty() {
+            version_spans.push(Span::styled("Fix versions: ", Style::default().fg(t.dim)));
+            version_spans.push(Span::raw(fix_versions.join(", ")));
+        }
+        if !affects_versions.is_empty() {
+            if !version_spans.is_empty() {
+                version_spans.push(Span::raw("    "));
+            }
+            version_spans.push(Span::styled("Affects: ", Style::default().fg(t.dim)));
+            version_spans.push(Span::raw(affects_versions.join(", ")));
+        }
+        if !version_spans.is_empty() {
+            lines.push(Line::from(version_spans));
+        }
+
         let metadata = Paragraph::new(lines).block(
             Block::default()
                 .borders(Borders::BOTTOM)
@@ -1629,19 +2079,21 @@ impl DetailView {
     }
 
     /// Render the description section with scrolling.
-    fn render_description(&mut self, frame: &mut Frame, area: Rect, description: &str) {
-        let description_text = if description.is_empty() {
-            "No description provided.".to_string()
-        } else {
-            description.to_string()
-        };
-
-        // Calculate content height for scrolling
-        // Estimate: count lines + wrapped lines based on area width
+    fn render_description(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        description: Option<&AtlassianDoc>,
+    ) {
+        let t = theme();
         let inner_width = area.width.saturating_sub(2) as usize; // Account for borders
-        let line_count = estimate_line_count(&description_text, inner_width);
+        let lines = match description.map(|doc| render_adf(doc, t, inner_width)) {
+            Some(rendered) if !rendered.lines.is_empty() => rendered.lines,
+            _ => vec![Line::from("No description provided.")],
+        };
 
-        self.content_height = line_count as u16;
+        // Lines are already wrapped to the inner width
+        self.content_height = lines.len() as u16;
         self.visible_height = area.height.saturating_sub(2); // Account for borders
 
         // Calculate max scroll
@@ -1652,15 +2104,13 @@ impl DetailView {
             self.scroll = self.max_scroll;
         }
 
-        let t = theme();
-        let description_paragraph = Paragraph::new(description_text)
+        let description_paragraph = Paragraph::new(lines)
             .block(
                 Block::default()
                     .title("Description")
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(t.border)),
             )
-            .wrap(Wrap { trim: true })
             .scroll((self.scroll, 0));
 
         frame.render_widget(description_paragraph, area);
@@ -1718,7 +2168,7 @@ impl DetailView {
             Span::styled(scroll_info, Style::default().fg(t.dim)),
             Span::raw(" | "),
             Span::styled(
-                "j/k:scroll  q:back  e:edit  c:comment  s:status  a:assignee  h:history  l:labels  L:link  p:priority  D:delete",
+                "j/k:scroll  q:back  e:edit  c:comment  s:status  a:assignee  h:history  l:labels  V:versions  L:link  p:priority  w:watch  v:vote  D:delete",
                 Style::default().fg(t.dim),
             ),
         ]);
@@ -1734,6 +2184,15 @@ impl Default for DetailView {
     }
 }
 
+/// Get the names of the versions in a version field of an issue.
+fn version_names(issue: &Issue, field: VersionField) -> Vec<String> {
+    issue
+        .versions(field)
+        .into_iter()
+        .map(|version| version.name)
+        .collect()
+}
+
 /// Format a JIRA date string for display.
 ///
 /// JIRA dates are typically in ISO 8601 format: "2024-01-15T10:00:00.000+0000"
@@ -1747,24 +2206,6 @@ fn format_date(date_str: &str) -> String {
     }
 }
 
-/// Estimate the number of lines needed to display text with word wrapping.
-fn estimate_line_count(text: &str, width: usize) -> usize {
-    if width == 0 {
-        return text.lines().count().max(1);
-    }
-
-    let mut count = 0;
-    for line in text.lines() {
-        if line.is_empty() {
-            count += 1;
-        } else {
-            // Rough estimate: divide line length by width, round up
-            count += (line.len() + width - 1) / width;
-        }
-    }
-    count.max(1)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1803,6 +2244,7 @@ mod tests {
                 issue_links: vec![],
                 subtasks: vec![],
                 parent: None,
+                extra_fields: Default::default(),
             },
         }
     }
@@ -1883,6 +2325,7 @@ mod tests {
                 issue_links: vec![],
                 subtasks: vec![],
                 parent: None,
+                extra_fields: Default::default(),
             },
         }
     }
@@ -2088,25 +2531,6 @@ mod tests {
         assert_eq!(format_date("short"), "short");
     }
 
-    #[test]
-    fn test_estimate_line_count() {
-        // Single line, fits in width
-        assert_eq!(estimate_line_count("hello", 80), 1);
-
-        // Multiple lines
-        assert_eq!(estimate_line_count("line1\nline2\nline3", 80), 3);
-
-        // Empty line
-        assert_eq!(estimate_line_count("line1\n\nline3", 80), 3);
-
-        // Long line that needs wrapping
-        let long_line = "a".repeat(100);
-        assert_eq!(estimate_line_count(&long_line, 50), 2);
-
-        // Zero width
-        assert_eq!(estimate_line_count("hello\nworld", 0), 2);
-    }
-
     #[test]
     fn test_full_issue_display() {
         let mut view = DetailView::new();
@@ -2243,7 +2667,7 @@ mod tests {
         let action = view.handle_input(key);
 
         // Cancel action is returned when pressing q/Esc in transition picker
-        assert!(action.is_none() || matches!(action, Some(DetailAction::GoBack)) == false);
+        assert!(action.is_none() || !matches!(action, Some(DetailAction::GoBack)));
     }
 
     #[test]
@@ -2322,6 +2746,193 @@ mod tests {
         assert!(!view.is_transition_picker_visible());
     }
 
+    // ========================================================================
+    // Watcher and vote tests
+    // ========================================================================
+
+    fn create_test_user(account_id: &str, display_name: &str) -> User {
+        User {
+            account_id: account_id.to_string(),
+            display_name: display_name.to_string(),
+            email_address: None,
+            active: true,
+            avatar_urls: None,
+        }
+    }
+
+    #[test]
+    fn test_w_key_toggles_watch() {
+        let mut view = DetailView::new();
+        let mut issue = create_test_issue("TEST-1", "Test issue");
+        view.set_issue(issue.clone());
+
+        let key = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE);
+        assert_eq!(
+            view.handle_input(key),
+            Some(DetailAction::ChangeWatch(
+                "TEST-1".to_string(),
+                WatchChange::Watch
+            ))
+        );
+
+        issue.fields.extra_fields.insert(
+            "watches".to_string(),
+            serde_json::json!({"watchCount": 1, "isWatching": true}),
+        );
+        view.set_issue(issue);
+        assert_eq!(
+            view.handle_input(key),
+            Some(DetailAction::ChangeWatch(
+                "TEST-1".to_string(),
+                WatchChange::Unwatch
+            ))
+        );
+    }
+
+    #[test]
+    fn test_v_key_toggles_vote() {
+        let mut view = DetailView::new();
+        let mut issue = create_test_issue("TEST-1", "Test issue");
+        issue.fields.extra_fields.insert(
+            "votes".to_string(),
+            serde_json::json!({"votes": 3, "hasVoted": true}),
+        );
+        view.set_issue(issue);
+
+        let key = KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE);
+        assert_eq!(
+            view.handle_input(key),
+            Some(DetailAction::ChangeWatch(
+                "TEST-1".to_string(),
+                WatchChange::Unvote
+            ))
+        );
+    }
+
+    #[test]
+    fn test_shift_v_opens_fix_version_editor() {
+        let mut view = DetailView::new();
+        view.set_issue(create_test_issue("TEST-1", "Test issue"));
+
+        let key = KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT);
+        let action = view.handle_input(key);
+
+        assert_eq!(
+            action,
+            Some(DetailAction::FetchVersions(
+                "TEST-1".to_string(),
+                String::new(),
+                VersionField::Fix
+            ))
+        );
+        assert!(view.is_version_editor_visible());
+        assert_eq!(view.version_field(), VersionField::Fix);
+    }
+
+    #[test]
+    fn test_version_editor_changes_affects_versions() {
+        let mut view = DetailView::new();
+        let mut issue = create_test_issue("TEST-1", "Test issue");
+        issue.fields.extra_fields.insert(
+            "versions".to_string(),
+            serde_json::json!([{"id": "1", "name": "1.0"}]),
+        );
+        view.set_issue(issue);
+
+        let key = KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT);
+        view.handle_input(key);
+        assert_eq!(view.version_field(), VersionField::Affects);
+        view.set_versions(vec!["1.1".to_string()]);
+
+        // Add the first available version
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(
+            view.handle_input(enter),
+            Some(DetailAction::ChangeVersion(
+                "TEST-1".to_string(),
+                VersionField::Affects,
+                VersionOperation::Add {
+                    name: "1.1".to_string()
+                }
+            ))
+        );
+
+        // Remove the current version
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        view.handle_input(tab);
+        assert_eq!(
+            view.handle_input(enter),
+            Some(DetailAction::ChangeVersion(
+                "TEST-1".to_string(),
+                VersionField::Affects,
+                VersionOperation::Remove {
+                    name: "1.0".to_string()
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_shift_w_opens_watcher_manager() {
+        let mut view = DetailView::new();
+        view.set_issue(create_test_issue("TEST-1", "Test issue"));
+
+        let key = KeyEvent::new(KeyCode::Char('W'), KeyModifiers::SHIFT);
+        let action = view.handle_input(key);
+
+        assert_eq!(
+            action,
+            Some(DetailAction::FetchWatchers("TEST-1".to_string()))
+        );
+        assert!(view.is_watcher_manager_visible());
+    }
+
+    #[test]
+    fn test_watcher_manager_add_and_remove() {
+        let mut view = DetailView::new();
+        view.set_issue(create_test_issue("TEST-1", "Test issue"));
+        view.show_watcher_manager_loading();
+        view.set_watchers(Watchers {
+            watch_count: 1,
+            is_watching: false,
+            watchers: vec![create_test_user("user1", "Alice")],
+        });
+
+        // 'd' removes the selected watcher
+        let key = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        assert_eq!(
+            view.handle_input(key),
+            Some(DetailAction::ChangeWatch(
+                "TEST-1".to_string(),
+                WatchChange::RemoveWatcher("user1".to_string(), "Alice".to_string())
+            ))
+        );
+
+        // 'a' opens the user picker
+        let key = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+        assert!(matches!(
+            view.handle_input(key),
+            Some(DetailAction::FetchWatcherCandidates(_, _))
+        ));
+        assert!(view.is_watcher_picker_visible());
+
+        // Existing watchers are not offered again
+        view.set_watcher_candidates(vec![
+            create_test_user("user1", "Alice"),
+            create_test_user("user2", "Bob"),
+        ]);
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(
+            view.handle_input(key),
+            Some(DetailAction::ChangeWatch(
+                "TEST-1".to_string(),
+                WatchChange::AddWatcher("user2".to_string(), "Bob".to_string())
+            ))
+        );
+        assert!(!view.is_watcher_picker_visible());
+        assert!(view.is_watcher_manager_visible());
+    }
+
     // ========================================================================
     // External editor tests
     // ========================================================================
@@ -1,29 +1,36 @@
 //! Filter panel view for filtering issues.
 //!
-//! Provides a multi-column filter panel with sections for status, assignee,
-//! project, labels, and sprint filters. Supports keyboard navigation and
-//! generates JQL queries from selected filters.
+//! Provides a multi-column filter panel with sections for project, epic,
+//! status, assignee, labels, sprint, issue type, priority, reporter and
+//! resolution filters, plus inputs for full text search and created/updated
+//! date ranges. Supports keyboard navigation, negating a section to exclude
+//! its selections, and generates JQL queries from selected filters. Filters
+//! the panel has no section for, such as components read from a JQL query,
+//! are kept and listed below the sections.
 
 use std::collections::HashSet;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style},
+    layout::{Constraint, Direction, Layout, Position, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
-use crate::api::types::{FilterOptions, FilterState, SprintFilter};
-use crate::ui::components::{MultiSelect, SelectItem};
+use crate::api::types::{
+    DateRange, FilterField, FilterOptions, FilterState, ResolutionFilter, SprintFilter,
+};
+use crate::events::{Action, KeyContext, PendingKeys};
+use crate::ui::components::{MultiSelect, SelectItem, TextInput};
 use crate::ui::theme::theme;
 
 /// Actions that can be returned from the filter panel.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FilterPanelAction {
     /// Apply the current filters and close the panel.
-    Apply(FilterState),
+    Apply(Box<FilterState>),
     /// Cancel and close the panel without applying.
     Cancel,
 }
@@ -37,6 +44,229 @@ pub enum FilterSectionType {
     Labels,
     Sprint,
     Epic,
+    IssueType,
+    Priority,
+    Reporter,
+    Resolution,
+    Text,
+}
+
+impl FilterSectionType {
+    /// Get the section title.
+    fn title(&self) -> &'static str {
+        match self {
+            FilterSectionType::Status => "Status",
+            FilterSectionType::Assignee => "Assignee",
+            FilterSectionType::Project => "Project",
+            FilterSectionType::Labels => "Labels",
+            FilterSectionType::Sprint => "Sprint",
+            FilterSectionType::Epic => "Epic",
+            FilterSectionType::IssueType => "Type",
+            FilterSectionType::Priority => "Priority",
+            FilterSectionType::Reporter => "Reporter",
+            FilterSectionType::Resolution => "Resolution",
+            FilterSectionType::Text => "Text & Dates",
+        }
+    }
+
+    /// Get the filter field the section edits, if it can be negated.
+    fn field(&self) -> Option<FilterField> {
+        match self {
+            FilterSectionType::Status => Some(FilterField::Status),
+            FilterSectionType::Assignee => Some(FilterField::Assignee),
+            FilterSectionType::Project => Some(FilterField::Project),
+            FilterSectionType::Labels => Some(FilterField::Labels),
+            FilterSectionType::Sprint => Some(FilterField::Sprint),
+            FilterSectionType::Epic => Some(FilterField::Epic),
+            FilterSectionType::IssueType => Some(FilterField::IssueType),
+            FilterSectionType::Priority => Some(FilterField::Priority),
+            FilterSectionType::Reporter => Some(FilterField::Reporter),
+            FilterSectionType::Resolution | FilterSectionType::Text => None,
+        }
+    }
+}
+
+/// Labels of the inputs in the text and dates section, in order.
+const TEXT_FIELDS: [&str; 5] = [
+    "Text",
+    "Created from",
+    "Created to",
+    "Updated from",
+    "Updated to",
+];
+
+/// The full text search and date range inputs of the filter panel.
+struct TextFields {
+    /// One input per entry of [`TEXT_FIELDS`].
+    inputs: Vec<TextInput>,
+    /// The index of the focused input.
+    cursor: usize,
+    /// The value of the input being edited from before editing started.
+    original: Option<String>,
+}
+
+impl TextFields {
+    /// Create empty inputs.
+    fn new() -> Self {
+        Self {
+            inputs: TEXT_FIELDS.iter().map(|_| TextInput::new()).collect(),
+            cursor: 0,
+            original: None,
+        }
+    }
+
+    /// Fill the inputs from a filter state.
+    fn set_state(&mut self, state: &FilterState) {
+        let created = state.created.clone().unwrap_or_default();
+        let updated = state.updated.clone().unwrap_or_default();
+        let values = [
+            state.text.clone(),
+            created.from,
+            created.to,
+            updated.from,
+            updated.to,
+        ];
+        for (input, value) in self.inputs.iter_mut().zip(values) {
+            input.set_value(value.unwrap_or_default());
+        }
+        self.original = None;
+    }
+
+    /// Get the trimmed value of an input, if it is set.
+    fn value(&self, index: usize) -> Option<String> {
+        let value = self.inputs[index].value().trim();
+        (!value.is_empty()).then(|| value.to_string())
+    }
+
+    /// Get the date range whose lower bound is the input at `from`.
+    fn range(&self, from: usize) -> Option<DateRange> {
+        let range = DateRange {
+            from: self.value(from),
+            to: self.value(from + 1),
+        };
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// Write the inputs to a filter state.
+    fn apply(&self, state: &mut FilterState) {
+        state.text = self.value(0);
+        state.created = self.range(1);
+        state.updated = self.range(3);
+    }
+
+    /// Clear all inputs.
+    fn clear(&mut self) {
+        for input in &mut self.inputs {
+            input.clear();
+        }
+        self.original = None;
+    }
+
+    /// Check if an input is being edited.
+    fn is_editing(&self) -> bool {
+        self.original.is_some()
+    }
+
+    /// Start editing the focused input.
+    fn start_editing(&mut self) {
+        self.original = Some(self.inputs[self.cursor].value().to_string());
+    }
+
+    /// Handle a key while editing.
+    ///
+    /// Enter keeps the typed value and Esc restores the previous one.
+    fn handle_edit_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.original = None,
+            KeyCode::Esc => {
+                if let Some(original) = self.original.take() {
+                    self.inputs[self.cursor].set_value(original);
+                }
+            }
+            _ => {
+                self.inputs[self.cursor].handle_input(key);
+            }
+        }
+    }
+
+    /// Handle a panel action while the section is focused.
+    fn handle_action(&mut self, action: Action) {
+        match action {
+            Action::MoveDown => self.cursor = (self.cursor + 1).min(self.inputs.len() - 1),
+            Action::MoveUp => self.cursor = self.cursor.saturating_sub(1),
+            Action::GoTop => self.cursor = 0,
+            Action::GoBottom => self.cursor = self.inputs.len() - 1,
+            Action::Toggle => self.start_editing(),
+            _ => {}
+        }
+    }
+
+    /// Render the inputs as labelled rows.
+    fn render(&self, frame: &mut Frame, area: Rect, title: &str, focused: bool) {
+        let t = theme();
+        let border_style = if focused {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let block = Block::default()
+            .title(title.to_string())
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let label_width = TEXT_FIELDS.iter().map(|l| l.len()).max().unwrap_or(0) + 2;
+        let lines: Vec<Line> = TEXT_FIELDS
+            .iter()
+            .zip(&self.inputs)
+            .enumerate()
+            .map(|(i, (label, input))| {
+                let current = focused && i == self.cursor;
+                let label_style = if current {
+                    Style::default()
+                        .fg(Color::White)
+                        .bg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(t.muted)
+                };
+                let value = if input.is_empty() && !(current && self.is_editing()) {
+                    Span::styled(
+                        if i == 0 { "-" } else { "e.g. -7d, 2024-01-31" },
+                        Style::default().fg(Color::DarkGray),
+                    )
+                } else {
+                    Span::raw(input.value())
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:<label_width$}", label), label_style),
+                    value,
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+
+        if focused && self.is_editing() {
+            let input = &self.inputs[self.cursor];
+            let typed = input.value().get(..input.cursor()).unwrap_or_default();
+            let x = inner.x + (label_width + typed.chars().count()) as u16;
+            let y = inner.y + self.cursor as u16;
+            if x < inner.x + inner.width && y < inner.y + inner.height {
+                frame.set_cursor_position(Position::new(x, y));
+            }
+        }
+    }
+}
+
+/// Create the resolution select with its fixed options.
+fn resolution_select() -> MultiSelect {
+    let mut select = MultiSelect::new("Resolution");
+    select.set_items(vec![
+        SelectItem::new("unresolved", "Unresolved"),
+        SelectItem::new("resolved", "Resolved"),
+    ]);
+    select
 }
 
 /// The filter panel view.
@@ -57,14 +287,29 @@ pub struct FilterPanelView {
     sprint_select: MultiSelect,
     /// Epic multi-select.
     epic_select: MultiSelect,
+    /// Issue type multi-select.
+    type_select: MultiSelect,
+    /// Priority multi-select.
+    priority_select: MultiSelect,
+    /// Reporter multi-select.
+    reporter_select: MultiSelect,
+    /// Resolution select (resolved or unresolved).
+    resolution_select: MultiSelect,
+    /// Full text search and date range inputs.
+    text_fields: TextFields,
     /// Whether "Assigned to me" is selected.
     assigned_to_me: bool,
     /// Whether "Current sprint" is selected.
     current_sprint: bool,
+    /// The state the panel was shown with, keeping the negated fields and
+    /// the filters without a section.
+    base: FilterState,
     /// The filter options available.
     options: FilterOptions,
     /// Section types in order.
     sections: Vec<FilterSectionType>,
+    /// Partially typed key sequence of a multi-key binding.
+    keys: PendingKeys,
 }
 
 impl FilterPanelView {
@@ -79,8 +324,14 @@ impl FilterPanelView {
             labels_select: MultiSelect::new("Labels"),
             sprint_select: MultiSelect::new("Sprint"),
             epic_select: MultiSelect::new("Epic"),
+            type_select: MultiSelect::new("Type"),
+            priority_select: MultiSelect::new("Priority"),
+            reporter_select: MultiSelect::new("Reporter"),
+            resolution_select: resolution_select(),
+            text_fields: TextFields::new(),
             assigned_to_me: false,
             current_sprint: false,
+            base: FilterState::default(),
             options: FilterOptions::default(),
             sections: vec![
                 FilterSectionType::Project,
@@ -89,7 +340,13 @@ impl FilterPanelView {
                 FilterSectionType::Assignee,
                 FilterSectionType::Labels,
                 FilterSectionType::Sprint,
+                FilterSectionType::IssueType,
+                FilterSectionType::Priority,
+                FilterSectionType::Reporter,
+                FilterSectionType::Resolution,
+                FilterSectionType::Text,
             ],
+            keys: PendingKeys::default(),
         }
     }
 
@@ -160,6 +417,89 @@ impl FilterPanelView {
         // Restore epic selections
         self.epic_select
             .set_selected(state.epics.iter().cloned().collect());
+
+        self.type_select
+            .set_selected(state.issue_types.iter().cloned().collect());
+        self.priority_select
+            .set_selected(state.priorities.iter().cloned().collect());
+
+        // Restore reporter selections - include __me__ if reporter_is_me is set
+        let mut reporter_ids: HashSet<String> = state.reporters.iter().cloned().collect();
+        if state.reporter_is_me {
+            reporter_ids.insert("__me__".to_string());
+        }
+        self.reporter_select.set_selected(reporter_ids);
+
+        let mut resolution = HashSet::new();
+        match state.resolution {
+            Some(ResolutionFilter::Unresolved) => resolution.insert("unresolved".to_string()),
+            Some(ResolutionFilter::Resolved) => resolution.insert("resolved".to_string()),
+            None => false,
+        };
+        self.resolution_select.set_selected(resolution);
+
+        self.text_fields.set_state(state);
+
+        self.base = state.clone();
+        self.update_titles();
+    }
+
+    /// Get the multi-select for a section, if it has one.
+    fn multiselect_mut(&mut self, section: FilterSectionType) -> Option<&mut MultiSelect> {
+        match section {
+            FilterSectionType::Status => Some(&mut self.status_select),
+            FilterSectionType::Assignee => Some(&mut self.assignee_select),
+            FilterSectionType::Project => Some(&mut self.project_select),
+            FilterSectionType::Labels => Some(&mut self.labels_select),
+            FilterSectionType::Sprint => Some(&mut self.sprint_select),
+            FilterSectionType::Epic => Some(&mut self.epic_select),
+            FilterSectionType::IssueType => Some(&mut self.type_select),
+            FilterSectionType::Priority => Some(&mut self.priority_select),
+            FilterSectionType::Reporter => Some(&mut self.reporter_select),
+            FilterSectionType::Resolution => Some(&mut self.resolution_select),
+            FilterSectionType::Text => None,
+        }
+    }
+
+    /// Mark negated sections in their titles.
+    fn update_titles(&mut self) {
+        for section in self.sections.clone() {
+            let negated = section.field().is_some_and(|f| self.base.is_negated(f));
+            let title = if negated {
+                format!("NOT {}", section.title())
+            } else {
+                section.title().to_string()
+            };
+            if let Some(select) = self.multiselect_mut(section) {
+                select.set_title(title);
+            }
+        }
+    }
+
+    /// Toggle whether the focused section excludes its selections.
+    fn toggle_negated(&mut self) {
+        if let Some(field) = self.focused_section_type().field() {
+            self.base.toggle_negated(field);
+            self.update_titles();
+        }
+    }
+
+    /// Describe the active filters that have no section in the panel.
+    fn other_filters(&self) -> Vec<String> {
+        FilterState {
+            components: self.base.components.clone(),
+            negated: self.base.negated.clone(),
+            ..FilterState::default()
+        }
+        .summary()
+    }
+
+    /// Check if a text or date input is being edited.
+    ///
+    /// While editing, keys are typed into the input instead of running
+    /// the panel's or the app's bindings.
+    pub fn is_editing(&self) -> bool {
+        self.text_fields.is_editing()
     }
 
     /// Hide the filter panel.
@@ -224,6 +564,30 @@ impl FilterPanelView {
             .collect();
         self.epic_select.set_items(epic_items);
 
+        let type_items: Vec<SelectItem> = options
+            .issue_types
+            .iter()
+            .map(|o| SelectItem::new(&o.id, &o.label))
+            .collect();
+        self.type_select.set_items(type_items);
+
+        let priority_items: Vec<SelectItem> = options
+            .priorities
+            .iter()
+            .map(|o| SelectItem::new(&o.id, &o.label))
+            .collect();
+        self.priority_select.set_items(priority_items);
+
+        // Add "Reported by me" as a special option in users
+        let mut reporter_items: Vec<SelectItem> = vec![SelectItem::new("__me__", "Reported by me")];
+        reporter_items.extend(
+            options
+                .users
+                .iter()
+                .map(|o| SelectItem::new(&o.id, &o.label)),
+        );
+        self.reporter_select.set_items(reporter_items);
+
         self.options = options;
     }
 
@@ -233,15 +597,8 @@ impl FilterPanelView {
     }
 
     /// Get a mutable reference to the currently focused multi-select.
-    fn focused_multiselect(&mut self) -> &mut MultiSelect {
-        match self.focused_section_type() {
-            FilterSectionType::Status => &mut self.status_select,
-            FilterSectionType::Assignee => &mut self.assignee_select,
-            FilterSectionType::Project => &mut self.project_select,
-            FilterSectionType::Labels => &mut self.labels_select,
-            FilterSectionType::Sprint => &mut self.sprint_select,
-            FilterSectionType::Epic => &mut self.epic_select,
-        }
+    fn focused_multiselect(&mut self) -> Option<&mut MultiSelect> {
+        self.multiselect_mut(self.focused_section_type())
     }
 
     /// Move to the next section.
@@ -260,7 +617,29 @@ impl FilterPanelView {
 
     /// Build the filter state from current selections.
     fn build_filter_state(&self) -> FilterState {
-        let mut state = FilterState::new();
+        // Start from the shown state so filters without a section survive
+        let mut state = FilterState {
+            // Statuses without an option can't be shown, so keep them as is
+            statuses: self
+                .base
+                .statuses
+                .iter()
+                .filter(|label| !self.options.statuses.iter().any(|o| o.label == **label))
+                .cloned()
+                .collect(),
+            assignees: Vec::new(),
+            assignee_is_me: false,
+            project: None,
+            labels: Vec::new(),
+            sprint: None,
+            epics: Vec::new(),
+            issue_types: Vec::new(),
+            priorities: Vec::new(),
+            reporters: Vec::new(),
+            reporter_is_me: false,
+            resolution: None,
+            ..self.base.clone()
+        };
 
         // Get selected statuses (using labels, not IDs for JQL)
         for id in self.status_select.selected() {
@@ -308,6 +687,33 @@ impl FilterPanelView {
             state.epics.push(epic.clone());
         }
 
+        state.issue_types = self.type_select.selected_ids();
+        state.priorities = self.priority_select.selected_ids();
+
+        // Handle reporter
+        if self.reporter_select.is_selected("__me__") {
+            state.reporter_is_me = true;
+        } else {
+            state.reporters = self
+                .reporter_select
+                .selected_ids()
+                .into_iter()
+                .filter(|id| id != "__me__")
+                .collect();
+        }
+
+        // Both or neither resolution selected doesn't filter
+        state.resolution = match (
+            self.resolution_select.is_selected("unresolved"),
+            self.resolution_select.is_selected("resolved"),
+        ) {
+            (true, false) => Some(ResolutionFilter::Unresolved),
+            (false, true) => Some(ResolutionFilter::Resolved),
+            _ => None,
+        };
+
+        self.text_fields.apply(&mut state);
+
         state
     }
 
@@ -319,45 +725,61 @@ impl FilterPanelView {
         self.labels_select.clear_selection();
         self.sprint_select.clear_selection();
         self.epic_select.clear_selection();
+        self.type_select.clear_selection();
+        self.priority_select.clear_selection();
+        self.reporter_select.clear_selection();
+        self.resolution_select.clear_selection();
+        self.text_fields.clear();
         self.assigned_to_me = false;
         self.current_sprint = false;
+        self.base.clear();
+        self.update_titles();
     }
 
     /// Handle keyboard input.
     ///
     /// Returns an action if one should be performed.
     pub fn handle_input(&mut self, key: KeyEvent) -> Option<FilterPanelAction> {
-        match (key.code, key.modifiers) {
+        if self.is_editing() {
+            self.text_fields.handle_edit_input(key);
+            return None;
+        }
+
+        match self.keys.resolve(KeyContext::FilterPanel, key)? {
             // Apply filters
-            (KeyCode::Enter, KeyModifiers::NONE) => {
+            Action::Submit => {
                 let state = self.build_filter_state();
                 self.hide();
-                return Some(FilterPanelAction::Apply(state));
+                return Some(FilterPanelAction::Apply(Box::new(state)));
             }
-            // Cancel with Esc or q
-            (KeyCode::Esc, KeyModifiers::NONE) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+            Action::Cancel => {
                 self.hide();
                 return Some(FilterPanelAction::Cancel);
             }
             // Clear all filters
-            (KeyCode::Char('c'), KeyModifiers::NONE) => {
+            Action::Clear => {
                 self.clear_all();
             }
-            // Switch sections with Tab, h/l, or arrow keys
-            (KeyCode::Tab, KeyModifiers::NONE)
-            | (KeyCode::Char('l'), KeyModifiers::NONE)
-            | (KeyCode::Right, _) => {
+            // Switch sections
+            Action::NextSection => {
                 self.next_section();
             }
-            (KeyCode::BackTab, KeyModifiers::SHIFT)
-            | (KeyCode::Char('h'), KeyModifiers::NONE)
-            | (KeyCode::Left, _) => {
+            Action::PrevSection => {
                 self.prev_section();
             }
-            // Delegate to focused multi-select
-            _ => {
-                self.focused_multiselect().handle_input(key);
+            // Navigate and edit the text and date inputs
+            action if self.focused_section_type() == FilterSectionType::Text => {
+                self.text_fields.handle_action(action)
             }
+            // Navigate and select in the focused multi-select
+            Action::MoveDown => self.focused_multiselect()?.move_down(),
+            Action::MoveUp => self.focused_multiselect()?.move_up(),
+            Action::GoTop => self.focused_multiselect()?.move_to_start(),
+            Action::GoBottom => self.focused_multiselect()?.move_to_end(),
+            Action::Toggle => self.focused_multiselect()?.toggle_current(),
+            Action::SelectAll => self.focused_multiselect()?.select_all(),
+            Action::Negate => self.toggle_negated(),
+            _ => {}
         }
         None
     }
@@ -397,7 +819,12 @@ impl FilterPanelView {
             .constraints([Constraint::Min(1), Constraint::Length(2)])
             .split(inner_area);
 
-        // Split content into 6 columns for filter sections
+        // Split content into two rows of filter sections
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+            .split(content_footer[0]);
+
         let columns = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
@@ -408,7 +835,18 @@ impl FilterPanelView {
                 Constraint::Ratio(1, 6), // Labels
                 Constraint::Ratio(1, 6), // Sprint
             ])
-            .split(content_footer[0]);
+            .split(rows[0]);
+
+        let lower_columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Ratio(1, 6), // Type
+                Constraint::Ratio(1, 6), // Priority
+                Constraint::Ratio(1, 6), // Reporter
+                Constraint::Ratio(1, 6), // Resolution
+                Constraint::Ratio(1, 3), // Text & Dates
+            ])
+            .split(rows[1]);
 
         // Render each section
         self.project_select
@@ -423,24 +861,56 @@ impl FilterPanelView {
             .render(frame, columns[4], self.focused_section == 4);
         self.sprint_select
             .render(frame, columns[5], self.focused_section == 5);
+        self.type_select
+            .render(frame, lower_columns[0], self.focused_section == 6);
+        self.priority_select
+            .render(frame, lower_columns[1], self.focused_section == 7);
+        self.reporter_select
+            .render(frame, lower_columns[2], self.focused_section == 8);
+        self.resolution_select
+            .render(frame, lower_columns[3], self.focused_section == 9);
+        self.text_fields.render(
+            frame,
+            lower_columns[4],
+            FilterSectionType::Text.title(),
+            self.focused_section == 10,
+        );
 
         // Render footer with help
-        let help_text = Line::from(vec![
-            Span::styled("h/l", Style::default().fg(t.warning)),
-            Span::raw(": switch section  "),
-            Span::styled("j/k", Style::default().fg(t.warning)),
-            Span::raw(": navigate  "),
-            Span::styled("Space", Style::default().fg(t.warning)),
-            Span::raw(": toggle  "),
-            Span::styled("Enter", Style::default().fg(t.warning)),
-            Span::raw(": apply  "),
-            Span::styled("c", Style::default().fg(t.warning)),
-            Span::raw(": clear  "),
-            Span::styled("q/Esc", Style::default().fg(t.warning)),
-            Span::raw(": cancel"),
-        ]);
-
-        let footer = Paragraph::new(help_text).style(Style::default().fg(t.muted));
+        let help_text = if self.is_editing() {
+            Line::from(vec![
+                Span::styled("Enter", Style::default().fg(t.warning)),
+                Span::raw(": done  "),
+                Span::styled("Esc", Style::default().fg(t.warning)),
+                Span::raw(": undo"),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled("h/l", Style::default().fg(t.warning)),
+                Span::raw(": switch section  "),
+                Span::styled("j/k", Style::default().fg(t.warning)),
+                Span::raw(": navigate  "),
+                Span::styled("Space", Style::default().fg(t.warning)),
+                Span::raw(": toggle/edit  "),
+                Span::styled("!", Style::default().fg(t.warning)),
+                Span::raw(": negate  "),
+                Span::styled("Enter", Style::default().fg(t.warning)),
+                Span::raw(": apply  "),
+                Span::styled("c", Style::default().fg(t.warning)),
+                Span::raw(": clear  "),
+                Span::styled("q/Esc", Style::default().fg(t.warning)),
+                Span::raw(": cancel"),
+            ])
+        };
+
+        let mut footer_lines = Vec::new();
+        let other = self.other_filters();
+        if !other.is_empty() {
+            footer_lines.push(Line::from(format!("Also: {}", other.join(" · "))));
+        }
+        footer_lines.push(help_text);
+
+        let footer = Paragraph::new(footer_lines).style(Style::default().fg(t.muted));
         frame.render_widget(footer, content_footer[1]);
     }
 }
@@ -455,6 +925,7 @@ impl Default for FilterPanelView {
 mod tests {
     use super::*;
     use crate::api::types::FilterOption;
+    use crossterm::event::{KeyCode, KeyModifiers};
 
     fn create_test_options() -> FilterOptions {
         let mut options = FilterOptions::new();
@@ -471,9 +942,41 @@ mod tests {
             FilterOption::new("bug", "bug"),
             FilterOption::new("feature", "feature"),
         ];
+        options.issue_types = vec![
+            FilterOption::new("Bug", "Bug"),
+            FilterOption::new("Task", "Task"),
+        ];
+        options.priorities = vec![
+            FilterOption::new("High", "High"),
+            FilterOption::new("Low", "Low"),
+        ];
         options
     }
 
+    fn press(view: &mut FilterPanelView, code: KeyCode) -> Option<FilterPanelAction> {
+        view.handle_input(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn type_text(view: &mut FilterPanelView, text: &str) {
+        for c in text.chars() {
+            press(view, KeyCode::Char(c));
+        }
+    }
+
+    /// Focus a section by tabbing to it.
+    fn focus(view: &mut FilterPanelView, section: FilterSectionType) {
+        while view.focused_section_type() != section {
+            press(view, KeyCode::Tab);
+        }
+    }
+
+    fn apply(view: &mut FilterPanelView) -> FilterState {
+        match press(view, KeyCode::Enter) {
+            Some(FilterPanelAction::Apply(state)) => *state,
+            other => panic!("expected the filter to be applied, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_new() {
         let view = FilterPanelView::new();
@@ -507,13 +1010,14 @@ mod tests {
         view.prev_section();
         assert_eq!(view.focused_section, 1);
 
-        // Wrap around (now 6 sections: Project, Epic, Status, Assignee, Labels, Sprint)
-        view.focused_section = 5;
+        // Wrap around (11 sections: Project, Epic, Status, Assignee, Labels,
+        // Sprint, Type, Priority, Reporter, Resolution, Text & Dates)
+        view.focused_section = 10;
         view.next_section();
         assert_eq!(view.focused_section, 0);
 
         view.prev_section();
-        assert_eq!(view.focused_section, 5);
+        assert_eq!(view.focused_section, 10);
     }
 
     #[test]
@@ -592,4 +1096,189 @@ mod tests {
         // The state should be restored - status "Open" corresponds to ID "1"
         assert!(view.status_select.is_selected("1"));
     }
+
+    #[test]
+    fn test_negate_section() {
+        let mut view = FilterPanelView::new();
+        view.set_options(create_test_options());
+        view.show_with_state(&FilterState::new());
+        view.focused_section = 2; // Status
+        view.status_select.toggle_current();
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('!'), KeyModifiers::NONE));
+        let state = view.build_filter_state();
+        assert_eq!(state.to_jql(), r#"status NOT IN ("Open")"#);
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('!'), KeyModifiers::NONE));
+        assert!(!view.build_filter_state().is_negated(FilterField::Status));
+    }
+
+    #[test]
+    fn test_keeps_filters_without_section() {
+        let mut view = FilterPanelView::new();
+        view.set_options(create_test_options());
+
+        let state = FilterState::from_jql(
+            r#"status IN ("Open", "Review") AND labels = bug AND component = api"#,
+        )
+        .unwrap();
+        view.show_with_state(&state);
+        assert_eq!(view.other_filters(), vec!["Components: api"]);
+
+        // Deselect the label; the component and unknown status stay
+        view.labels_select.set_selected(HashSet::new());
+        let built = view.build_filter_state();
+        assert!(built.labels.is_empty());
+        assert_eq!(built.statuses.len(), 2);
+        assert!(built.statuses.contains(&"Review".to_string()));
+        assert_eq!(built.components, vec!["api"]);
+
+        view.clear_all();
+        assert!(view.build_filter_state().is_empty());
+    }
+
+    #[test]
+    fn test_select_type_priority_reporter_and_resolution() {
+        let mut view = FilterPanelView::new();
+        view.set_options(create_test_options());
+        view.show_with_state(&FilterState::new());
+
+        // Second type, first priority
+        focus(&mut view, FilterSectionType::IssueType);
+        press(&mut view, KeyCode::Char('j'));
+        press(&mut view, KeyCode::Char(' '));
+        focus(&mut view, FilterSectionType::Priority);
+        press(&mut view, KeyCode::Char(' '));
+        press(&mut view, KeyCode::Char('!'));
+        // "Reported by me" is the first reporter
+        focus(&mut view, FilterSectionType::Reporter);
+        press(&mut view, KeyCode::Char(' '));
+        focus(&mut view, FilterSectionType::Resolution);
+        press(&mut view, KeyCode::Char(' '));
+
+        let state = apply(&mut view);
+        assert_eq!(state.issue_types, vec!["Task"]);
+        assert_eq!(state.priorities, vec!["High"]);
+        assert!(state.is_negated(FilterField::Priority));
+        assert!(state.reporter_is_me);
+        assert!(state.reporters.is_empty());
+        assert_eq!(state.resolution, Some(ResolutionFilter::Unresolved));
+        assert_eq!(
+            state.to_jql(),
+            r#"issuetype IN ("Task") AND priority NOT IN ("High") AND reporter = currentUser() AND resolution IS EMPTY"#
+        );
+    }
+
+    #[test]
+    fn test_both_resolutions_do_not_filter() {
+        let mut view = FilterPanelView::new();
+        view.show_with_state(&FilterState::new());
+        focus(&mut view, FilterSectionType::Resolution);
+        view.handle_input(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+        assert_eq!(view.resolution_select.selected_count(), 2);
+        assert_eq!(apply(&mut view).resolution, None);
+    }
+
+    #[test]
+    fn test_edit_text_and_dates() {
+        let mut view = FilterPanelView::new();
+        view.set_options(create_test_options());
+        view.show_with_state(&FilterState::new());
+        focus(&mut view, FilterSectionType::Text);
+
+        // Space starts editing, so j, q and ? are typed instead of bound
+        press(&mut view, KeyCode::Char(' '));
+        assert!(view.is_editing());
+        type_text(&mut view, "jq? crash ");
+        press(&mut view, KeyCode::Enter);
+        assert!(!view.is_editing());
+
+        // Created from
+        press(&mut view, KeyCode::Char('j'));
+        press(&mut view, KeyCode::Char(' '));
+        type_text(&mut view, "-7d");
+        press(&mut view, KeyCode::Enter);
+
+        // Updated to
+        press(&mut view, KeyCode::End);
+        press(&mut view, KeyCode::Char(' '));
+        type_text(&mut view, "2024-01-31");
+        press(&mut view, KeyCode::Enter);
+
+        assert!(view.is_visible());
+        let state = apply(&mut view);
+        assert_eq!(state.text.as_deref(), Some("jq? crash"));
+        assert_eq!(
+            state.created,
+            Some(DateRange {
+                from: Some("-7d".to_string()),
+                to: None,
+            })
+        );
+        assert_eq!(
+            state.updated,
+            Some(DateRange {
+                from: None,
+                to: Some("2024-01-31".to_string()),
+            })
+        );
+        assert_eq!(
+            state.to_jql(),
+            r#"created >= "-7d" AND updated <= "2024-01-31" AND text ~ "jq? crash""#
+        );
+    }
+
+    #[test]
+    fn test_escape_while_editing_restores_value() {
+        let mut view = FilterPanelView::new();
+        let state = FilterState {
+            text: Some("crash".to_string()),
+            ..FilterState::default()
+        };
+        view.show_with_state(&state);
+        focus(&mut view, FilterSectionType::Text);
+
+        press(&mut view, KeyCode::Char(' '));
+        type_text(&mut view, " log");
+        // Esc leaves the input but keeps the panel open
+        assert_eq!(press(&mut view, KeyCode::Esc), None);
+        assert!(view.is_visible());
+        assert!(!view.is_editing());
+        assert_eq!(apply(&mut view).text.as_deref(), Some("crash"));
+    }
+
+    #[test]
+    fn test_new_sections_round_trip() {
+        let mut view = FilterPanelView::new();
+        view.set_options(create_test_options());
+
+        let state = FilterState::from_jql(
+            r#"issuetype = Bug AND priority IN (High, Low) AND reporter = currentUser() AND resolution IS NOT EMPTY AND created >= -7d AND created <= -1d AND text ~ "crash""#,
+        )
+        .unwrap();
+        view.show_with_state(&state);
+        assert!(view.type_select.is_selected("Bug"));
+        assert!(view.priority_select.is_selected("Low"));
+        assert!(view.reporter_select.is_selected("__me__"));
+        assert!(view.resolution_select.is_selected("resolved"));
+        assert!(view.other_filters().is_empty());
+
+        let mut built = view.build_filter_state();
+        built.priorities.sort();
+        assert_eq!(built, state);
+
+        view.clear_all();
+        assert!(view.build_filter_state().is_empty());
+    }
+
+    #[test]
+    fn test_negate_ignored_for_text_and_resolution() {
+        let mut view = FilterPanelView::new();
+        view.show_with_state(&FilterState::new());
+        focus(&mut view, FilterSectionType::Resolution);
+        press(&mut view, KeyCode::Char('!'));
+        focus(&mut view, FilterSectionType::Text);
+        press(&mut view, KeyCode::Char('!'));
+        assert!(view.build_filter_state().negated.is_empty());
+    }
 }
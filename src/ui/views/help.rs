@@ -13,7 +13,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::events::{get_keybindings_grouped, KeyContext, Keybinding};
+use crate::events::{get_keybindings_grouped, keymap, Action, KeyContext, Keybinding};
 use crate::ui::theme::theme;
 
 /// Actions that can be returned from the help view.
@@ -100,7 +100,10 @@ impl HelpView {
     /// Returns `Some(HelpAction)` if an action should be taken, `None` otherwise.
     pub fn handle_input(&mut self, key_event: KeyEvent) -> Option<HelpAction> {
         match (key_event.code, key_event.modifiers) {
-            // Close help panel
+            // Close help panel (also with a remapped help key)
+            _ if keymap().matches(KeyContext::Global, Action::Help, key_event) => {
+                Some(HelpAction::Close)
+            }
             (KeyCode::Char('?'), KeyModifiers::NONE)
             | (KeyCode::Char('q'), KeyModifiers::NONE)
             | (KeyCode::Esc, KeyModifiers::NONE) => Some(HelpAction::Close),
@@ -208,22 +211,36 @@ impl HelpView {
 
         for (context, bindings) in &self.grouped_bindings {
             // Context header
-            lines.push(Line::from(vec![Span::styled(
-                format!("── {} ──", context.display()),
-                Style::default().fg(t.warning).add_modifier(Modifier::BOLD),
-            )]));
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("── {} ──", context.display()),
+                    Style::default().fg(t.warning).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  [keys.{}]", context.id()),
+                    Style::default().fg(t.dim),
+                ),
+            ]));
             lines.push(Line::from(""));
 
             // Keybindings in this context
             for binding in bindings {
-                lines.push(Line::from(vec![
+                let mut spans = vec![
                     Span::styled(
                         format!("{:>14}", binding.key),
                         Style::default().fg(t.success).add_modifier(Modifier::BOLD),
                     ),
                     Span::raw("  "),
                     Span::raw(binding.description.clone()),
-                ]));
+                ];
+                // Show the action name for bindings that can be remapped
+                if Action::from_id(&binding.action).is_some() {
+                    spans.push(Span::styled(
+                        format!("  {}", binding.action),
+                        Style::default().fg(t.dim),
+                    ));
+                }
+                lines.push(Line::from(spans));
             }
 
             lines.push(Line::from(""));
@@ -1,8 +1,11 @@
 //! Issue list view.
 //!
-//! Displays a table of JIRA issues with columns for Key, Summary, Status,
-//! Assignee, and Priority. Supports keyboard navigation, column sorting,
-//! pagination, and visual indicators for issue priority and type.
+//! Displays a table of JIRA issues. The columns default to Key, Summary, Status,
+//! Assignee, and Priority and can be configured per profile or saved filter.
+//! Supports keyboard navigation, column sorting, pagination, and visual
+//! indicators for issue priority and type.
+
+use std::collections::HashSet;
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
@@ -12,74 +15,214 @@ use ratatui::{
     widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
     Frame,
 };
-use tracing::debug;
+use tracing::{debug, warn};
 
-use crate::api::types::Issue;
+use crate::api::columns::{ColumnConfig, ColumnField, FieldIds};
+use crate::api::types::{field_value_text, Issue};
 use crate::cache::CacheStatus;
+use crate::events::{Action, KeyContext, PendingKeys};
 use crate::ui::components::{highlight_text, render_search_bar, QuickSearch};
 use crate::ui::theme::{issue_type_prefix, priority_style, status_style, theme, truncate};
 
 // ============================================================================
-// Sorting Types
+// Column Types
 // ============================================================================
 
-/// Column that can be sorted.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SortColumn {
-    Key,
-    Summary,
-    Status,
-    Assignee,
-    Priority,
+/// A column displayed in the issue list, resolved from configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListColumn {
+    /// The field shown in this column.
+    pub field: ColumnField,
+    /// Configured width, if any.
+    pub width: Option<u16>,
+    /// Configured header title, if any.
+    pub title: Option<String>,
 }
 
-impl SortColumn {
-    /// Convert to JQL field name.
-    pub fn as_jql_field(self) -> &'static str {
-        match self {
-            SortColumn::Key => "key",
-            SortColumn::Summary => "summary",
-            SortColumn::Status => "status",
-            SortColumn::Assignee => "assignee",
-            SortColumn::Priority => "priority",
+impl ListColumn {
+    /// Create a column with default width and title.
+    pub fn new(field: ColumnField) -> Self {
+        Self {
+            field,
+            width: None,
+            title: None,
         }
     }
 
-    /// Get display name for the column.
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            SortColumn::Key => "Key",
-            SortColumn::Summary => "Summary",
-            SortColumn::Status => "Status",
-            SortColumn::Assignee => "Assignee",
-            SortColumn::Priority => "Priority",
+    /// Resolve a column from its configuration.
+    ///
+    /// Returns `None` if the configured field is unknown.
+    pub fn from_config(config: &ColumnConfig) -> Option<Self> {
+        Some(Self {
+            field: ColumnField::from_field_id(&config.field)?,
+            width: config.width,
+            title: config.title.clone(),
+        })
+    }
+
+    /// Resolve a list of configured columns, falling back to the defaults
+    /// when none are configured or none are valid.
+    pub fn from_configs(configs: &[ColumnConfig]) -> Vec<Self> {
+        let columns: Vec<Self> = configs
+            .iter()
+            .filter_map(|c| {
+                let column = Self::from_config(c);
+                if column.is_none() {
+                    warn!(field = %c.field, "Ignoring unknown list column");
+                }
+                column
+            })
+            .collect();
+
+        if columns.is_empty() {
+            Self::defaults()
+        } else {
+            columns
         }
     }
 
-    /// Get column index (0-based).
-    pub fn index(&self) -> usize {
-        match self {
-            SortColumn::Key => 0,
-            SortColumn::Summary => 1,
-            SortColumn::Status => 2,
-            SortColumn::Assignee => 3,
-            SortColumn::Priority => 4,
-        }
+    /// The columns shown when none are configured.
+    pub fn defaults() -> Vec<Self> {
+        ColumnField::defaults().into_iter().map(Self::new).collect()
     }
 
-    /// Get column from index.
-    pub fn from_index(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(SortColumn::Key),
-            1 => Some(SortColumn::Summary),
-            2 => Some(SortColumn::Status),
-            3 => Some(SortColumn::Assignee),
-            4 => Some(SortColumn::Priority),
-            _ => None,
+    /// Header title for this column.
+    pub fn title(&self) -> String {
+        self.title
+            .clone()
+            .unwrap_or_else(|| self.field.display_name())
+    }
+
+    /// Width of this column in characters.
+    pub fn width(&self) -> u16 {
+        self.width.unwrap_or_else(|| self.field.default_width())
+    }
+
+    /// Whether this column expands to fill the remaining space.
+    fn is_flexible(&self) -> bool {
+        self.field == ColumnField::Summary && self.width.is_none()
+    }
+
+    /// The plain text shown in this column for an issue.
+    pub fn cell_text(&self, issue: &Issue, field_ids: &FieldIds) -> String {
+        let fields = &issue.fields;
+        match &self.field {
+            ColumnField::Key => {
+                format!(
+                    "{} {}",
+                    issue_type_prefix(&fields.issuetype.name),
+                    issue.key
+                )
+            }
+            ColumnField::Summary => fields.summary.clone(),
+            ColumnField::Status => fields.status.name.clone(),
+            ColumnField::Assignee => issue.assignee_name().to_string(),
+            ColumnField::Priority => issue.priority_name().to_string(),
+            ColumnField::IssueType => fields.issuetype.name.clone(),
+            ColumnField::Reporter => issue.reporter().unwrap_or("").to_string(),
+            ColumnField::Created => fields
+                .created
+                .as_deref()
+                .map(format_relative_time)
+                .unwrap_or_default(),
+            ColumnField::Updated => fields
+                .updated
+                .as_deref()
+                .map(format_relative_time)
+                .unwrap_or_default(),
+            ColumnField::DueDate => fields.duedate.clone().unwrap_or_default(),
+            ColumnField::StoryPoints => issue
+                .story_points(&field_ids.story_points)
+                .map(|p| p.to_string())
+                .unwrap_or_default(),
+            ColumnField::Sprint => issue
+                .sprint_name(&field_ids.sprint)
+                .unwrap_or("")
+                .to_string(),
+            ColumnField::Labels => fields.labels.join(", "),
+            ColumnField::Components => fields
+                .components
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            ColumnField::CustomField(id) => issue
+                .field_value(id)
+                .map(field_value_text)
+                .unwrap_or_default(),
         }
     }
 }
 
+/// Format an ISO 8601 JIRA timestamp relative to now (e.g. "5m ago", "3d ago").
+///
+/// Falls back to the date portion if the timestamp cannot be parsed.
+fn format_relative_time(timestamp: &str) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    format_relative_time_at(timestamp, now)
+}
+
+/// Format a timestamp relative to the given Unix time in seconds.
+fn format_relative_time_at(timestamp: &str, now: i64) -> String {
+    let Some(then) = parse_jira_timestamp(timestamp) else {
+        return timestamp.get(..10).unwrap_or(timestamp).to_string();
+    };
+
+    let elapsed = (now - then).max(0);
+    match elapsed {
+        0..=59 => "just now".to_string(),
+        60..=3_599 => format!("{}m ago", elapsed / 60),
+        3_600..=86_399 => format!("{}h ago", elapsed / 3_600),
+        86_400..=2_591_999 => format!("{}d ago", elapsed / 86_400),
+        2_592_000..=31_535_999 => format!("{}mo ago", elapsed / 2_592_000),
+        _ => format!("{}y ago", elapsed / 31_536_000),
+    }
+}
+
+/// Parse a JIRA timestamp ("2024-01-15T10:30:00.000+0000") into Unix seconds.
+fn parse_jira_timestamp(timestamp: &str) -> Option<i64> {
+    let num = |range: std::ops::Range<usize>| -> Option<i64> { timestamp.get(range)?.parse().ok() };
+
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+
+    // Timezone offset follows the optional fractional seconds: "Z", "+0000" or "+00:00"
+    let rest = timestamp.get(19..)?;
+    let tz = rest.trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset = match tz.chars().next() {
+        Some(sign @ ('+' | '-')) => {
+            let digits: String = tz[1..].chars().filter(|c| c.is_ascii_digit()).collect();
+            let hours: i64 = digits.get(0..2)?.parse().ok()?;
+            let minutes: i64 = digits.get(2..4).and_then(|m| m.parse().ok()).unwrap_or(0);
+            let offset = hours * 3_600 + minutes * 60;
+            if sign == '-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => 0,
+    };
+
+    // Days since the Unix epoch for a civil date (Howard Hinnant's algorithm)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second - offset)
+}
+
+// ============================================================================
+// Sorting Types
+// ============================================================================
+
 /// Sort direction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SortDirection {
@@ -118,22 +261,22 @@ impl SortDirection {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SortState {
     /// Currently sorted column.
-    pub column: SortColumn,
+    pub column: ColumnField,
     /// Sort direction.
     pub direction: SortDirection,
 }
 
 impl SortState {
     /// Create a new sort state.
-    pub fn new(column: SortColumn, direction: SortDirection) -> Self {
+    pub fn new(column: ColumnField, direction: SortDirection) -> Self {
         Self { column, direction }
     }
 
     /// Generate JQL ORDER BY clause.
-    pub fn to_jql(&self) -> String {
+    pub fn to_jql(&self, fields: &FieldIds) -> String {
         format!(
             "ORDER BY {} {}",
-            self.column.as_jql_field(),
+            self.column.as_jql_field(fields),
             self.direction.as_jql()
         )
     }
@@ -141,7 +284,7 @@ impl SortState {
     /// Toggle sort for a column.
     /// If clicking same column, toggle direction.
     /// If clicking different column, switch to that column with descending.
-    pub fn toggle_column(&mut self, column: SortColumn) {
+    pub fn toggle_column(&mut self, column: ColumnField) {
         if self.column == column {
             self.direction = self.direction.toggle();
         } else {
@@ -154,12 +297,102 @@ impl SortState {
 impl Default for SortState {
     fn default() -> Self {
         Self {
-            column: SortColumn::Key,
+            column: ColumnField::Key,
             direction: SortDirection::Descending,
         }
     }
 }
 
+// ============================================================================
+// Grouping Types
+// ============================================================================
+
+/// Field used to group issues in the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Status,
+    Assignee,
+    Parent,
+    Priority,
+    Sprint,
+    Component,
+}
+
+impl GroupBy {
+    /// All groupings, in cycling order.
+    const ALL: [GroupBy; 6] = [
+        GroupBy::Status,
+        GroupBy::Assignee,
+        GroupBy::Parent,
+        GroupBy::Priority,
+        GroupBy::Sprint,
+        GroupBy::Component,
+    ];
+
+    /// Get display name for the grouping.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            GroupBy::Status => "Status",
+            GroupBy::Assignee => "Assignee",
+            GroupBy::Parent => "Epic/Parent",
+            GroupBy::Priority => "Priority",
+            GroupBy::Sprint => "Sprint",
+            GroupBy::Component => "Component",
+        }
+    }
+
+    /// Cycle to the next grouping, or `None` after the last one.
+    pub fn cycle(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::ALL[0]),
+            Some(group_by) => {
+                let index = Self::ALL.iter().position(|g| *g == group_by).unwrap_or(0);
+                Self::ALL.get(index + 1).copied()
+            }
+        }
+    }
+
+    /// Get the label of the group an issue belongs to.
+    ///
+    /// Issues with several components are grouped under the first one.
+    pub fn group_label(self, issue: &Issue, field_ids: &FieldIds) -> String {
+        let fields = &issue.fields;
+        match self {
+            GroupBy::Status => fields.status.name.clone(),
+            GroupBy::Assignee => issue.assignee_name().to_string(),
+            GroupBy::Parent => fields
+                .parent
+                .as_ref()
+                .map(|p| format!("{} {}", p.key, p.fields.summary))
+                .unwrap_or_else(|| "No parent".to_string()),
+            GroupBy::Priority => issue.priority_name().to_string(),
+            GroupBy::Sprint => issue
+                .sprint_name(&field_ids.sprint)
+                .unwrap_or("No sprint")
+                .to_string(),
+            GroupBy::Component => fields
+                .components
+                .first()
+                .map(|c| c.name.clone())
+                .unwrap_or_else(|| "No component".to_string()),
+        }
+    }
+}
+
+/// A row in the issue table.
+#[derive(Debug, Clone, PartialEq)]
+enum ListRow {
+    /// A group header.
+    Group {
+        label: String,
+        count: usize,
+        story_points: Option<f64>,
+        collapsed: bool,
+    },
+    /// An issue, by index into the issue list.
+    Issue(usize),
+}
+
 // ============================================================================
 // Pagination Types
 // ============================================================================
@@ -269,6 +502,13 @@ impl PaginationState {
     pub fn start_loading(&mut self) {
         self.loading = true;
     }
+
+    /// Cancel an in-flight load more request.
+    ///
+    /// Its result is ignored once it arrives.
+    pub fn cancel_loading(&mut self) {
+        self.loading = false;
+    }
 }
 
 impl Default for PaginationState {
@@ -298,6 +538,20 @@ pub enum ListAction {
     OpenInBrowser(String),
     /// Open the create issue form.
     OpenCreateIssue,
+    /// Switch to the next tab.
+    NextTab,
+    /// Switch to the previous tab.
+    PrevTab,
+    /// Open a new tab.
+    NewTab,
+    /// Close the current tab.
+    CloseTab,
+    /// Open the go to issue finder.
+    GoToIssue,
+    /// Open the release view for the selected issue's project.
+    OpenReleases,
+    /// Quit the application.
+    Quit,
 }
 
 /// The issue list view state.
@@ -314,8 +568,8 @@ pub struct ListView {
     loading: bool,
     /// Current profile name (for status bar).
     profile_name: Option<String>,
-    /// Pending 'g' key for gg navigation.
-    pending_g: bool,
+    /// Partially typed key sequence, e.g. the first 'g' of `gg`.
+    keys: PendingKeys,
     /// Filter summary to display in the status bar.
     filter_summary: Option<String>,
     /// Current sort state.
@@ -326,12 +580,24 @@ pub struct ListView {
     header_focused: bool,
     /// Currently focused column index (when header is focused).
     focused_column: usize,
+    /// Columns shown in the table.
+    columns: Vec<ListColumn>,
+    /// IDs of the custom fields shown by name (sprint, story points).
+    field_ids: FieldIds,
     /// Quick search state.
     search: QuickSearch,
     /// Cache status for data freshness indicator.
     cache_status: Option<CacheStatus>,
     /// Error message to display (e.g., authentication failure).
     error: Option<String>,
+    /// Current grouping, if any.
+    group_by: Option<GroupBy>,
+    /// Labels of collapsed groups.
+    collapsed_groups: HashSet<String>,
+    /// Table rows (group headers and issues); `selected` indexes into this.
+    rows: Vec<ListRow>,
+    /// Selected issue key and scroll offset to restore when issues are set.
+    pending_position: Option<(Option<String>, usize)>,
 }
 
 impl ListView {
@@ -353,25 +619,55 @@ impl ListView {
             table_state,
             loading: false,
             profile_name: None,
-            pending_g: false,
+            keys: PendingKeys::default(),
             filter_summary: None,
             sort: SortState::default(),
             pagination: PaginationState::with_page_size(page_size),
             header_focused: false,
             focused_column: 0,
+            columns: ListColumn::defaults(),
+            field_ids: FieldIds::default(),
             search: QuickSearch::new(),
             cache_status: None,
             error: None,
+            group_by: None,
+            collapsed_groups: HashSet::new(),
+            rows: Vec::new(),
+            pending_position: None,
         }
     }
 
     /// Set the list of issues to display.
+    ///
+    /// Selects the first row, or the position passed to `restore_position`.
     pub fn set_issues(&mut self, issues: Vec<Issue>) {
         self.issues = issues;
+        self.rebuild_rows();
         self.selected = 0;
         self.scroll_offset = 0;
         self.table_state.select(Some(0));
         self.loading = false;
+
+        if let Some((key, offset)) = self.pending_position.take() {
+            if let Some(index) = key.and_then(|key| self.issues.iter().position(|i| i.key == key)) {
+                self.select_issue_index(index);
+            }
+            *self.table_state.offset_mut() = offset.min(self.rows.len().saturating_sub(1));
+        }
+    }
+
+    /// Get the selected issue's key and the index of the first visible row.
+    pub fn position(&self) -> (Option<String>, usize) {
+        (
+            self.selected_issue().map(|issue| issue.key.clone()),
+            self.table_state.offset(),
+        )
+    }
+
+    /// Select an issue and scroll to an offset the next time issues are set,
+    /// e.g. after a restored tab is fetched.
+    pub fn restore_position(&mut self, key: Option<String>, offset: usize) {
+        self.pending_position = Some((key, offset));
     }
 
     /// Update a single issue in the list.
@@ -381,6 +677,8 @@ impl ListView {
     pub fn update_issue(&mut self, updated_issue: &Issue) {
         if let Some(pos) = self.issues.iter().position(|i| i.key == updated_issue.key) {
             self.issues[pos] = updated_issue.clone();
+            // The update may have moved the issue to another group
+            self.rebuild_rows();
         }
     }
 
@@ -410,8 +708,23 @@ impl ListView {
     }
 
     /// Get the currently selected issue.
+    ///
+    /// Returns `None` when a group header is selected.
     pub fn selected_issue(&self) -> Option<&Issue> {
-        self.issues.get(self.selected)
+        match self.rows.get(self.selected) {
+            Some(ListRow::Issue(index)) => self.issues.get(*index),
+            _ => None,
+        }
+    }
+
+    /// Get the loaded issues.
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Find a loaded issue by key.
+    pub fn issue_by_key(&self, key: &str) -> Option<&Issue> {
+        self.issues.iter().find(|issue| issue.key == key)
     }
 
     /// Get the selected index.
@@ -463,6 +776,11 @@ impl ListView {
         self.sort = sort;
     }
 
+    /// Get the JQL ORDER BY clause of the sort state.
+    pub fn sort_jql(&self) -> String {
+        self.sort.to_jql(&self.field_ids)
+    }
+
     /// Check if header is currently focused for sorting.
     pub fn is_header_focused(&self) -> bool {
         self.header_focused
@@ -471,7 +789,11 @@ impl ListView {
     /// Enter header focus mode for sorting.
     pub fn enter_header_mode(&mut self) {
         self.header_focused = true;
-        self.focused_column = self.sort.column.index();
+        self.focused_column = self
+            .columns
+            .iter()
+            .position(|c| c.field == self.sort.column)
+            .unwrap_or(0);
     }
 
     /// Exit header focus mode.
@@ -479,6 +801,179 @@ impl ListView {
         self.header_focused = false;
     }
 
+    // ========================================================================
+    // Grouping Methods
+    // ========================================================================
+
+    /// Get the current grouping.
+    pub fn group_by(&self) -> Option<GroupBy> {
+        self.group_by
+    }
+
+    /// Set the grouping. Collapsed groups are reset.
+    pub fn set_group_by(&mut self, group_by: Option<GroupBy>) {
+        self.group_by = group_by;
+        self.collapsed_groups.clear();
+        self.rebuild_rows();
+    }
+
+    /// Rebuild the table rows from the issues and grouping state.
+    ///
+    /// Keeps the selection on the same issue (or group header) when possible.
+    fn rebuild_rows(&mut self) {
+        let anchor = self.rows.get(self.selected).map(|row| match row {
+            ListRow::Group { label, .. } => (true, label.clone()),
+            ListRow::Issue(index) => (
+                false,
+                self.issues
+                    .get(*index)
+                    .map(|i| i.key.clone())
+                    .unwrap_or_default(),
+            ),
+        });
+
+        self.rows = match self.group_by {
+            None => (0..self.issues.len()).map(ListRow::Issue).collect(),
+            Some(group_by) => {
+                // Groups appear in the order of their first issue (i.e. server sort order)
+                let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+                for (index, issue) in self.issues.iter().enumerate() {
+                    let label = group_by.group_label(issue, &self.field_ids);
+                    match groups.iter_mut().find(|(l, _)| *l == label) {
+                        Some((_, members)) => members.push(index),
+                        None => groups.push((label, vec![index])),
+                    }
+                }
+
+                let mut rows = Vec::with_capacity(self.issues.len() + groups.len());
+                for (label, members) in groups {
+                    let points: Vec<f64> = members
+                        .iter()
+                        .filter_map(|i| self.issues[*i].story_points(&self.field_ids.story_points))
+                        .collect();
+                    let collapsed = self.collapsed_groups.contains(&label);
+                    rows.push(ListRow::Group {
+                        label,
+                        count: members.len(),
+                        story_points: (!points.is_empty()).then(|| points.iter().sum()),
+                        collapsed,
+                    });
+                    if !collapsed {
+                        rows.extend(members.into_iter().map(ListRow::Issue));
+                    }
+                }
+                rows
+            }
+        };
+
+        let restored = anchor.and_then(|(is_group, id)| {
+            self.rows.iter().position(|row| match row {
+                ListRow::Group { label, .. } => is_group && *label == id,
+                ListRow::Issue(index) => !is_group && self.issues[*index].key == id,
+            })
+        });
+        self.selected =
+            restored.unwrap_or_else(|| self.selected.min(self.rows.len().saturating_sub(1)));
+        self.table_state.select(Some(self.selected));
+    }
+
+    /// Get the label of the group containing the selected row.
+    fn selected_group_label(&self) -> Option<String> {
+        let group_by = self.group_by?;
+        match self.rows.get(self.selected)? {
+            ListRow::Group { label, .. } => Some(label.clone()),
+            ListRow::Issue(index) => {
+                Some(group_by.group_label(&self.issues[*index], &self.field_ids))
+            }
+        }
+    }
+
+    /// Collapse or expand the group containing the selected row.
+    ///
+    /// Collapsing moves the selection to the group header.
+    fn toggle_selected_group(&mut self) {
+        let Some(label) = self.selected_group_label() else {
+            return;
+        };
+        if !self.collapsed_groups.remove(&label) {
+            self.collapsed_groups.insert(label.clone());
+            // Select the header so the selection doesn't jump to another group
+            if let Some(pos) = self
+                .rows
+                .iter()
+                .position(|row| matches!(row, ListRow::Group { label: l, .. } if *l == label))
+            {
+                self.selected = pos;
+            }
+        }
+        self.rebuild_rows();
+    }
+
+    /// Collapse all groups, or expand all if every group is already collapsed.
+    fn toggle_all_groups(&mut self) {
+        let Some(group_by) = self.group_by else {
+            return;
+        };
+        let labels: HashSet<String> = self
+            .issues
+            .iter()
+            .map(|i| group_by.group_label(i, &self.field_ids))
+            .collect();
+        if labels.is_subset(&self.collapsed_groups) {
+            self.collapsed_groups.clear();
+        } else {
+            self.collapsed_groups = labels;
+        }
+        self.rebuild_rows();
+    }
+
+    /// Select the row of an issue, expanding its group if it is collapsed.
+    fn select_issue_index(&mut self, index: usize) {
+        if let (Some(group_by), Some(issue)) = (self.group_by, self.issues.get(index)) {
+            if self
+                .collapsed_groups
+                .remove(&group_by.group_label(issue, &self.field_ids))
+            {
+                self.rebuild_rows();
+            }
+        }
+        if let Some(pos) = self
+            .rows
+            .iter()
+            .position(|row| *row == ListRow::Issue(index))
+        {
+            self.selected = pos;
+            self.table_state.select(Some(self.selected));
+        }
+    }
+
+    // ========================================================================
+    // Column Methods
+    // ========================================================================
+
+    /// Get the columns shown in the table.
+    pub fn columns(&self) -> &[ListColumn] {
+        &self.columns
+    }
+
+    /// Set the columns shown in the table.
+    ///
+    /// An empty list restores the default columns.
+    pub fn set_columns(&mut self, columns: Vec<ListColumn>) {
+        self.columns = if columns.is_empty() {
+            ListColumn::defaults()
+        } else {
+            columns
+        };
+        self.focused_column = self.focused_column.min(self.columns.len() - 1);
+    }
+
+    /// Set the IDs of the custom fields shown by name.
+    pub fn set_field_ids(&mut self, field_ids: FieldIds) {
+        self.field_ids = field_ids;
+        self.rebuild_rows();
+    }
+
     // ========================================================================
     // Pagination Methods
     // ========================================================================
@@ -496,6 +991,7 @@ impl ListView {
     /// Append issues to the existing list (for pagination).
     pub fn append_issues(&mut self, new_issues: Vec<Issue>) {
         self.issues.extend(new_issues);
+        self.rebuild_rows();
         self.pagination.loading = false;
     }
 
@@ -515,6 +1011,7 @@ impl ListView {
     /// Reset for a new query (clears issues and pagination).
     pub fn reset_for_new_query(&mut self) {
         self.issues.clear();
+        self.rows.clear();
         self.selected = 0;
         self.scroll_offset = 0;
         self.table_state.select(Some(0));
@@ -525,7 +1022,7 @@ impl ListView {
     fn check_load_more(&self) -> Option<ListAction> {
         // Load more if within 5 items of end
         let threshold = 5;
-        let near_end = self.selected + threshold >= self.issues.len();
+        let near_end = self.selected + threshold >= self.rows.len();
         let has_more = self.pagination.has_more;
         let not_loading = !self.pagination.loading;
 
@@ -561,110 +1058,106 @@ impl ListView {
             return self.handle_header_input(key);
         }
 
-        // Handle pending 'g' for gg navigation
-        if self.pending_g {
-            self.pending_g = false;
-            if key.code == KeyCode::Char('g') && key.modifiers == KeyModifiers::NONE {
-                self.move_to_start();
-                return None;
-            }
-            // If not 'g', fall through to normal handling
-        }
-
         match (key.code, key.modifiers) {
-            // Quick search activation
-            (KeyCode::Char('/'), KeyModifiers::NONE) => {
-                self.search.activate();
-            }
             // Next/previous match when search has results
-            (KeyCode::Char('n'), KeyModifiers::NONE) if !self.search.is_empty() => {
+            (KeyCode::Char('n'), KeyModifiers::NONE)
+                if !self.search.is_empty() && !self.keys.is_pending() =>
+            {
                 if let Some(idx) = self.search.next_match() {
-                    self.selected = idx;
-                    self.table_state.select(Some(self.selected));
+                    self.select_issue_index(idx);
                 }
+                return None;
             }
-            (KeyCode::Char('N'), KeyModifiers::SHIFT) if !self.search.is_empty() => {
+            (KeyCode::Char('N'), KeyModifiers::SHIFT)
+                if !self.search.is_empty() && !self.keys.is_pending() =>
+            {
                 if let Some(idx) = self.search.prev_match() {
-                    self.selected = idx;
-                    self.table_state.select(Some(self.selected));
+                    self.select_issue_index(idx);
                 }
+                return None;
+            }
+            // Clear search with Escape when not in search mode but search has results
+            (KeyCode::Esc, _) if !self.search.is_empty() => {
+                self.keys.clear();
+                self.search.deactivate();
+                return None;
+            }
+            _ => {}
+        }
+
+        match self.keys.resolve(KeyContext::IssueList, key)? {
+            // Quick search activation
+            Action::Search => {
+                self.search.activate();
             }
             // Navigation
-            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => {
+            Action::MoveDown => {
                 self.move_down();
                 return self.check_load_more();
             }
-            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => {
+            Action::MoveUp => {
                 self.move_up();
             }
-            (KeyCode::Char('g'), KeyModifiers::NONE) => {
-                // Wait for second 'g'
-                self.pending_g = true;
-            }
-            (KeyCode::Char('G'), KeyModifiers::SHIFT)
-            | (KeyCode::Char('G'), KeyModifiers::NONE) => {
-                self.move_to_end();
-                return self.check_load_more();
-            }
-            (KeyCode::Home, _) => {
+            Action::GoTop => {
                 self.move_to_start();
             }
-            (KeyCode::End, _) => {
+            Action::GoBottom => {
                 self.move_to_end();
                 return self.check_load_more();
             }
-            (KeyCode::PageDown, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
+            Action::PageDown => {
                 self.page_down();
                 return self.check_load_more();
             }
-            (KeyCode::PageUp, _) | (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+            Action::PageUp => {
                 self.page_up();
             }
             // Enter sort/header mode
-            (KeyCode::Char('s'), KeyModifiers::NONE) => {
+            Action::Sort => {
                 self.enter_header_mode();
             }
+            // Grouping
+            Action::CycleGroupBy => {
+                self.set_group_by(GroupBy::cycle(self.group_by));
+            }
+            Action::ToggleGroup => {
+                self.toggle_selected_group();
+            }
+            Action::ToggleAllGroups => {
+                self.toggle_all_groups();
+            }
             // Actions
-            (KeyCode::Enter, KeyModifiers::NONE) => {
+            Action::Open => {
                 if let Some(issue) = self.selected_issue() {
                     return Some(ListAction::OpenIssue(issue.key.clone()));
                 }
+                // Enter on a group header collapses/expands it
+                self.toggle_selected_group();
             }
-            (KeyCode::Char('r'), KeyModifiers::NONE) => {
-                return Some(ListAction::Refresh);
-            }
-            (KeyCode::Char('f'), KeyModifiers::NONE) => {
-                return Some(ListAction::OpenFilter);
-            }
-            // Saved filters dialog
-            (KeyCode::Char('F'), KeyModifiers::SHIFT) => {
-                return Some(ListAction::OpenSavedFilters);
+            Action::Quit => return Some(ListAction::Quit),
+            Action::Refresh => return Some(ListAction::Refresh),
+            Action::OpenFilter => return Some(ListAction::OpenFilter),
+            Action::SavedFilters => return Some(ListAction::OpenSavedFilters),
+            Action::OpenReleases if self.selected_issue().is_some() => {
+                return Some(ListAction::OpenReleases);
             }
-            // JQL input
-            (KeyCode::Char(':'), KeyModifiers::NONE)
-            | (KeyCode::Char(':'), KeyModifiers::SHIFT) => {
-                return Some(ListAction::OpenJqlInput);
-            }
-            // Open in browser
-            (KeyCode::Char('o'), KeyModifiers::NONE) => {
+            Action::JqlQuery => return Some(ListAction::OpenJqlInput),
+            Action::OpenInBrowser => {
                 if let Some(issue) = self.selected_issue() {
                     return Some(ListAction::OpenInBrowser(issue.key.clone()));
                 }
             }
-            // Create new issue (only when search is empty, since 'n' is used for next match)
-            (KeyCode::Char('n'), KeyModifiers::NONE) if self.search.is_empty() => {
-                return Some(ListAction::OpenCreateIssue);
-            }
+            Action::NewIssue => return Some(ListAction::OpenCreateIssue),
             // Manual load more
-            (KeyCode::Char('l'), KeyModifiers::CONTROL) => {
-                if self.pagination.has_more && !self.pagination.loading {
-                    return Some(ListAction::LoadMore);
-                }
-            }
-            // Clear search with Escape when not in search mode but search has results
-            (KeyCode::Esc, _) if !self.search.is_empty() => {
-                self.search.deactivate();
+            Action::LoadMore if self.pagination.has_more && !self.pagination.loading => {
+                return Some(ListAction::LoadMore);
             }
+            // Tabs
+            Action::NextTab => return Some(ListAction::NextTab),
+            Action::PrevTab => return Some(ListAction::PrevTab),
+            Action::NewTab => return Some(ListAction::NewTab),
+            Action::CloseTab => return Some(ListAction::CloseTab),
+            Action::GoToIssue => return Some(ListAction::GoToIssue),
             _ => {}
         }
         None
@@ -678,8 +1171,7 @@ impl ListView {
                 self.search.update_matches(&self.issues);
                 // Jump to first match
                 if let Some(idx) = self.search.current_match_index() {
-                    self.selected = idx;
-                    self.table_state.select(Some(self.selected));
+                    self.select_issue_index(idx);
                 }
                 None
             }
@@ -688,8 +1180,7 @@ impl ListView {
                 self.search.update_matches(&self.issues);
                 // Update selection if we still have matches
                 if let Some(idx) = self.search.current_match_index() {
-                    self.selected = idx;
-                    self.table_state.select(Some(self.selected));
+                    self.select_issue_index(idx);
                 }
                 None
             }
@@ -719,15 +1210,16 @@ impl ListView {
             }
             // Navigate right in header
             (KeyCode::Right, _) | (KeyCode::Char('l'), KeyModifiers::NONE) => {
-                if self.focused_column < 4 {
+                if self.focused_column + 1 < self.columns.len() {
                     self.focused_column += 1;
                 }
                 None
             }
             // Select column to sort
             (KeyCode::Enter, KeyModifiers::NONE) | (KeyCode::Char(' '), KeyModifiers::NONE) => {
-                if let Some(column) = SortColumn::from_index(self.focused_column) {
-                    self.sort.toggle_column(column);
+                if let Some(column) = self.columns.get(self.focused_column) {
+                    let field = column.field.clone();
+                    self.sort.toggle_column(field);
                     self.header_focused = false;
                     Some(ListAction::SortChanged)
                 } else {
@@ -745,10 +1237,10 @@ impl ListView {
 
     /// Move selection down by one.
     fn move_down(&mut self) {
-        if self.issues.is_empty() {
+        if self.rows.is_empty() {
             return;
         }
-        if self.selected < self.issues.len() - 1 {
+        if self.selected < self.rows.len() - 1 {
             self.selected += 1;
             self.table_state.select(Some(self.selected));
         }
@@ -770,19 +1262,19 @@ impl ListView {
 
     /// Move selection to the last item.
     fn move_to_end(&mut self) {
-        if !self.issues.is_empty() {
-            self.selected = self.issues.len() - 1;
+        if !self.rows.is_empty() {
+            self.selected = self.rows.len() - 1;
             self.table_state.select(Some(self.selected));
         }
     }
 
     /// Move selection down by a page (10 items).
     fn page_down(&mut self) {
-        if self.issues.is_empty() {
+        if self.rows.is_empty() {
             return;
         }
         let page_size = 10;
-        self.selected = (self.selected + page_size).min(self.issues.len() - 1);
+        self.selected = (self.selected + page_size).min(self.rows.len() - 1);
         self.table_state.select(Some(self.selected));
     }
 
@@ -969,15 +1461,15 @@ impl ListView {
     fn render_table(&mut self, frame: &mut Frame, area: Rect) {
         let t = theme();
 
-        // Calculate column widths based on available space
-        let key_width = 14; // PROJ-12345
-        let status_width = 15;
-        let assignee_width = 20;
-        let priority_width = 10;
-        let min_summary_width = 30;
-
-        // Summary gets remaining space
-        let fixed_width = key_width + status_width + assignee_width + priority_width + 8; // 8 for borders/spacing
+        // The summary column (unless given a fixed width) gets the remaining space
+        let min_summary_width = ColumnField::Summary.default_width() as usize;
+        let fixed_width: usize = self
+            .columns
+            .iter()
+            .filter(|c| !c.is_flexible())
+            .map(|c| c.width() as usize + 1)
+            .sum::<usize>()
+            + 4; // highlight symbol and spacing
         let summary_width = if area.width as usize > fixed_width + min_summary_width {
             area.width as usize - fixed_width
         } else {
@@ -985,25 +1477,18 @@ impl ListView {
         };
 
         // Create header with sort indicators
-        let columns = [
-            (SortColumn::Key, "Key"),
-            (SortColumn::Summary, "Summary"),
-            (SortColumn::Status, "Status"),
-            (SortColumn::Assignee, "Assignee"),
-            (SortColumn::Priority, "Priority"),
-        ];
-
-        let header_cells: Vec<Cell> = columns
+        let header_cells: Vec<Cell> = self
+            .columns
             .iter()
             .enumerate()
-            .map(|(i, (col, name))| {
+            .map(|(i, column)| {
                 // Add sort indicator if this is the sorted column
-                let indicator = if self.sort.column == *col {
+                let indicator = if self.sort.column == column.field {
                     self.sort.direction.indicator()
                 } else {
                     ""
                 };
-                let text = format!("{}{}", name, indicator);
+                let text = format!("{}{}", column.title(), indicator);
 
                 // Style: highlight focused column when in header mode
                 let style = if self.header_focused && i == self.focused_column {
@@ -1025,56 +1510,92 @@ impl ListView {
         let search_query = self.search.query();
         let has_search = !search_query.is_empty();
 
+        // Group headers put their label in the summary column when shown
+        let label_column = self
+            .columns
+            .iter()
+            .position(|c| c.field == ColumnField::Summary)
+            .filter(|pos| *pos > 0);
+
         // Create rows
         let rows: Vec<Row> = self
-            .issues
+            .rows
             .iter()
-            .enumerate()
-            .map(|(idx, issue)| {
-                // Key with type prefix
-                let type_prefix = issue_type_prefix(&issue.fields.issuetype.name);
-                let key_text = format!("{} {}", type_prefix, issue.key);
-
-                // Truncate summary to fit
-                let summary = truncate(&issue.fields.summary, summary_width);
-
-                // Status with color
-                let status_style_value = status_style(&issue.fields.status);
-
-                // Priority with color
-                let priority_style_value = priority_style(issue.fields.priority.as_ref());
+            .map(|row| {
+                let idx = match row {
+                    ListRow::Issue(idx) => *idx,
+                    ListRow::Group {
+                        label,
+                        count,
+                        story_points,
+                        collapsed,
+                    } => {
+                        let marker = if *collapsed { "▶" } else { "▼" };
+                        let points = story_points
+                            .map(|p| format!(" · {} pts", p))
+                            .unwrap_or_default();
+                        let title = format!("{}{}", label, points);
+                        let cells: Vec<Cell> = (0..self.columns.len())
+                            .map(|i| match (i, label_column) {
+                                (0, Some(_)) => Cell::from(format!("{} {}", marker, count)),
+                                (0, None) => {
+                                    Cell::from(format!("{} {} ({})", marker, title, count))
+                                }
+                                (i, Some(pos)) if i == pos => Cell::from(title.clone()),
+                                _ => Cell::from(""),
+                            })
+                            .collect();
+                        return Row::new(cells)
+                            .style(Style::default().fg(t.accent).add_modifier(Modifier::BOLD));
+                    }
+                };
+                let issue = &self.issues[idx];
 
                 // Check if this row matches the search
                 let is_match = has_search && self.search.is_match(idx);
                 let is_current = has_search && self.search.is_current_match(idx);
 
-                // Apply highlighting if searching
-                let key_cell = if has_search && is_match {
-                    Cell::from(highlight_text(&key_text, search_query))
-                } else {
-                    Cell::from(key_text)
-                };
-
-                let summary_cell = if has_search && is_match {
-                    Cell::from(highlight_text(&summary, search_query))
-                } else {
-                    Cell::from(summary)
-                };
-
-                let status_cell = if has_search && is_match {
-                    Cell::from(highlight_text(&issue.fields.status.name, search_query))
-                        .style(status_style_value)
-                } else {
-                    Cell::from(issue.fields.status.name.clone()).style(status_style_value)
-                };
-
-                let mut row = Row::new(vec![
-                    key_cell,
-                    summary_cell,
-                    status_cell,
-                    Cell::from(issue.assignee_name().to_string()),
-                    Cell::from(issue.priority_name().to_string()).style(priority_style_value),
-                ]);
+                let cells: Vec<Cell> = self
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        let width = if column.is_flexible() {
+                            summary_width
+                        } else {
+                            column.width() as usize
+                        };
+                        let text = truncate(&column.cell_text(issue, &self.field_ids), width);
+
+                        // Key, summary and status are the searchable columns
+                        let searchable = matches!(
+                            column.field,
+                            ColumnField::Key | ColumnField::Summary | ColumnField::Status
+                        );
+                        let cell = if is_match && searchable {
+                            Cell::from(highlight_text(&text, search_query))
+                        } else {
+                            Cell::from(text)
+                        };
+
+                        match column.field {
+                            ColumnField::Status => cell.style(status_style(&issue.fields.status)),
+                            ColumnField::IssueType => {
+                                cell.style(t.issue_type_style(&issue.fields.issuetype.name))
+                            }
+                            ColumnField::Priority => {
+                                cell.style(priority_style(issue.fields.priority.as_ref()))
+                            }
+                            ColumnField::Labels => cell.style(t.style_tag()),
+                            ColumnField::Components => cell.style(t.style_component()),
+                            ColumnField::Created | ColumnField::Updated => {
+                                cell.style(t.style_muted())
+                            }
+                            _ => cell,
+                        }
+                    })
+                    .collect();
+
+                let mut row = Row::new(cells);
 
                 // Add visual indicator for current match
                 if is_current {
@@ -1086,13 +1607,17 @@ impl ListView {
             .collect();
 
         // Define column constraints
-        let widths = [
-            Constraint::Length(key_width as u16),
-            Constraint::Min(min_summary_width as u16),
-            Constraint::Length(status_width as u16),
-            Constraint::Length(assignee_width as u16),
-            Constraint::Length(priority_width as u16),
-        ];
+        let widths: Vec<Constraint> = self
+            .columns
+            .iter()
+            .map(|c| {
+                if c.is_flexible() {
+                    Constraint::Min(min_summary_width as u16)
+                } else {
+                    Constraint::Length(c.width())
+                }
+            })
+            .collect();
 
         // Create table
         let table = Table::new(rows, widths)
@@ -1118,10 +1643,9 @@ impl ListView {
             format!("{} issues", self.issues.len())
         };
 
-        let selected_text = if !self.issues.is_empty() {
-            format!(" [{}/{}]", self.selected + 1, self.issues.len())
-        } else {
-            String::new()
+        let selected_text = match self.rows.get(self.selected) {
+            Some(ListRow::Issue(index)) => format!(" [{}/{}]", index + 1, self.issues.len()),
+            _ => String::new(),
         };
 
         let mut spans = vec![
@@ -1145,6 +1669,15 @@ impl ListView {
             Style::default().fg(t.accent),
         ));
 
+        // Add grouping info
+        if let Some(group_by) = self.group_by {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(
+                format!("[Group: {}]", group_by.display_name()),
+                Style::default().fg(t.accent),
+            ));
+        }
+
         // Add filter summary if active
         if let Some(summary) = &self.filter_summary {
             spans.push(Span::raw(" "));
@@ -1190,8 +1723,10 @@ impl ListView {
             "h/l:select column  Enter:sort  Esc:cancel"
         } else if !self.search.is_empty() {
             "n/N:next/prev match  /:new search  Esc:clear"
+        } else if self.group_by.is_some() {
+            "j/k:nav  z:fold  Z:fold all  v:group  /:search  o:open  ?:help"
         } else {
-            "j/k:nav  /:search  s:sort  f:filter  ^L:more  o:open  n:new  ?:help"
+            "j/k:nav  /:search  s:sort  v:group  f:filter  ^L:more  o:open  n:new  ?:help"
         };
         spans.push(Span::styled(help_text, Style::default().fg(t.dim)));
 
@@ -1245,6 +1780,7 @@ mod tests {
                 issue_links: vec![],
                 subtasks: vec![],
                 parent: None,
+                extra_fields: Default::default(),
             },
         }
     }
@@ -1396,14 +1932,14 @@ mod tests {
         let key = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
         let action = view.handle_input(key);
         assert!(action.is_none());
-        assert!(view.pending_g);
+        assert!(view.keys.is_pending());
 
         // Second 'g' should move to start
         let key = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
         let action = view.handle_input(key);
         assert!(action.is_none());
         assert_eq!(view.selected, 0);
-        assert!(!view.pending_g);
+        assert!(!view.keys.is_pending());
     }
 
     #[test]
@@ -1529,40 +2065,181 @@ mod tests {
     // ========================================================================
 
     #[test]
-    fn test_sort_column_as_jql_field() {
-        assert_eq!(SortColumn::Key.as_jql_field(), "key");
-        assert_eq!(SortColumn::Summary.as_jql_field(), "summary");
-        assert_eq!(SortColumn::Status.as_jql_field(), "status");
-        assert_eq!(SortColumn::Assignee.as_jql_field(), "assignee");
-        assert_eq!(SortColumn::Priority.as_jql_field(), "priority");
+    fn test_sort_state_to_jql_custom_field() {
+        let state = SortState::new(
+            ColumnField::CustomField("customfield_10050".to_string()),
+            SortDirection::Ascending,
+        );
+        assert_eq!(state.to_jql(&FieldIds::default()), "ORDER BY cf[10050] ASC");
+    }
+
+    // ========================================================================
+    // Column Tests
+    // ========================================================================
+
+    #[test]
+    fn test_list_column_from_configs() {
+        let configs = vec![
+            ColumnConfig::new("key"),
+            ColumnConfig {
+                field: "customfield_10050".to_string(),
+                width: Some(12),
+                title: Some("Team".to_string()),
+            },
+        ];
+        let columns = ListColumn::from_configs(&configs);
+
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0], ListColumn::new(ColumnField::Key));
+        assert_eq!(columns[1].title(), "Team");
+        assert_eq!(columns[1].width(), 12);
+    }
+
+    #[test]
+    fn test_list_column_from_configs_skips_unknown() {
+        let configs = vec![ColumnConfig::new("bogus"), ColumnConfig::new("created")];
+        let columns = ListColumn::from_configs(&configs);
+        assert_eq!(columns, vec![ListColumn::new(ColumnField::Created)]);
+    }
+
+    #[test]
+    fn test_list_column_from_configs_empty_uses_defaults() {
+        assert_eq!(ListColumn::from_configs(&[]), ListColumn::defaults());
+        assert_eq!(ListColumn::defaults().len(), 5);
+    }
+
+    #[test]
+    fn test_list_column_cell_text() {
+        let mut issue = create_test_issue("TEST-1", "First");
+        issue.fields.labels = vec!["backend".to_string(), "urgent".to_string()];
+        issue.fields.story_points = Some(3.0);
+        issue.fields.duedate = Some("2024-02-01".to_string());
+        issue.fields.extra_fields.insert(
+            "customfield_10050".to_string(),
+            serde_json::json!({ "value": "Platform" }),
+        );
+        issue.fields.extra_fields.insert(
+            "customfield_10020".to_string(),
+            serde_json::json!([
+                { "name": "Sprint 1", "state": "closed" },
+                { "name": "Sprint 2", "state": "active" }
+            ]),
+        );
+
+        let text =
+            |field: ColumnField| ListColumn::new(field).cell_text(&issue, &FieldIds::default());
+        assert_eq!(text(ColumnField::Labels), "backend, urgent");
+        assert_eq!(text(ColumnField::StoryPoints), "3");
+        assert_eq!(text(ColumnField::DueDate), "2024-02-01");
+        assert_eq!(text(ColumnField::Sprint), "Sprint 2");
+        assert_eq!(text(ColumnField::Reporter), "");
+        assert_eq!(
+            text(ColumnField::CustomField("customfield_10050".to_string())),
+            "Platform"
+        );
+        assert_eq!(
+            text(ColumnField::CustomField("customfield_99999".to_string())),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_list_column_cell_text_with_resolved_field_ids() {
+        let mut issue = create_test_issue("TEST-1", "First");
+        issue.fields.story_points = Some(3.0);
+        issue
+            .fields
+            .extra_fields
+            .insert("customfield_10106".to_string(), serde_json::json!(5.0));
+        issue.fields.extra_fields.insert(
+            "customfield_10300".to_string(),
+            serde_json::json!([{ "name": "Sprint 7", "state": "active" }]),
+        );
+        let fields = FieldIds {
+            story_points: "customfield_10106".to_string(),
+            sprint: "customfield_10300".to_string(),
+        };
+
+        let text = |field: ColumnField| ListColumn::new(field).cell_text(&issue, &fields);
+        assert_eq!(text(ColumnField::StoryPoints), "5");
+        assert_eq!(text(ColumnField::Sprint), "Sprint 7");
+        assert_eq!(GroupBy::Sprint.group_label(&issue, &fields), "Sprint 7");
+
+        let mut view = ListView::new();
+        view.set_field_ids(fields);
+        view.set_sort(SortState::new(
+            ColumnField::StoryPoints,
+            SortDirection::Ascending,
+        ));
+        assert_eq!(view.sort_jql(), "ORDER BY cf[10106] ASC");
+    }
+
+    #[test]
+    fn test_format_relative_time_at() {
+        let then = parse_jira_timestamp("2024-01-15T10:30:00.000+0000").unwrap();
+        assert_eq!(then, 1_705_314_600);
+
+        let at = |secs| format_relative_time_at("2024-01-15T10:30:00.000+0000", then + secs);
+        assert_eq!(at(30), "just now");
+        assert_eq!(at(5 * 60), "5m ago");
+        assert_eq!(at(3 * 3_600), "3h ago");
+        assert_eq!(at(2 * 86_400), "2d ago");
+        assert_eq!(at(65 * 86_400), "2mo ago");
+        assert_eq!(at(800 * 86_400), "2y ago");
     }
 
     #[test]
-    fn test_sort_column_display_name() {
-        assert_eq!(SortColumn::Key.display_name(), "Key");
-        assert_eq!(SortColumn::Summary.display_name(), "Summary");
-        assert_eq!(SortColumn::Status.display_name(), "Status");
-        assert_eq!(SortColumn::Assignee.display_name(), "Assignee");
-        assert_eq!(SortColumn::Priority.display_name(), "Priority");
+    fn test_parse_jira_timestamp_offsets() {
+        let utc = parse_jira_timestamp("2024-01-15T10:30:00Z").unwrap();
+        assert_eq!(
+            parse_jira_timestamp("2024-01-15T12:30:00.000+0200"),
+            Some(utc)
+        );
+        assert_eq!(parse_jira_timestamp("2024-01-15T05:30:00-05:00"), Some(utc));
+        assert_eq!(parse_jira_timestamp("not a date"), None);
+        assert_eq!(format_relative_time_at("2024-01-15", 0), "2024-01-15");
     }
 
     #[test]
-    fn test_sort_column_index() {
-        assert_eq!(SortColumn::Key.index(), 0);
-        assert_eq!(SortColumn::Summary.index(), 1);
-        assert_eq!(SortColumn::Status.index(), 2);
-        assert_eq!(SortColumn::Assignee.index(), 3);
-        assert_eq!(SortColumn::Priority.index(), 4);
+    fn test_set_columns() {
+        let mut view = ListView::new();
+        view.set_columns(vec![
+            ListColumn::new(ColumnField::Key),
+            ListColumn::new(ColumnField::Updated),
+        ]);
+        assert_eq!(view.columns().len(), 2);
+
+        // Empty restores defaults
+        view.set_columns(Vec::new());
+        assert_eq!(view.columns(), ListColumn::defaults().as_slice());
     }
 
     #[test]
-    fn test_sort_column_from_index() {
-        assert_eq!(SortColumn::from_index(0), Some(SortColumn::Key));
-        assert_eq!(SortColumn::from_index(1), Some(SortColumn::Summary));
-        assert_eq!(SortColumn::from_index(2), Some(SortColumn::Status));
-        assert_eq!(SortColumn::from_index(3), Some(SortColumn::Assignee));
-        assert_eq!(SortColumn::from_index(4), Some(SortColumn::Priority));
-        assert_eq!(SortColumn::from_index(5), None);
+    fn test_header_mode_with_configured_columns() {
+        let mut view = ListView::new();
+        view.set_issues(vec![create_test_issue("TEST-1", "First")]);
+        view.set_columns(vec![
+            ListColumn::new(ColumnField::Key),
+            ListColumn::new(ColumnField::CustomField("customfield_10050".to_string())),
+        ]);
+        view.enter_header_mode();
+
+        // Can't move past the last configured column
+        let key = KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE);
+        view.handle_input(key);
+        view.handle_input(key);
+        assert_eq!(view.focused_column, 1);
+
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(view.handle_input(key), Some(ListAction::SortChanged));
+        assert_eq!(
+            view.sort().to_jql(&FieldIds::default()),
+            "ORDER BY cf[10050] DESC"
+        );
+
+        // Re-entering header mode focuses the sorted column
+        view.enter_header_mode();
+        assert_eq!(view.focused_column, 1);
     }
 
     #[test]
@@ -1589,34 +2266,34 @@ mod tests {
     #[test]
     fn test_sort_state_default() {
         let state = SortState::default();
-        assert_eq!(state.column, SortColumn::Key);
+        assert_eq!(state.column, ColumnField::Key);
         assert_eq!(state.direction, SortDirection::Descending);
     }
 
     #[test]
     fn test_sort_state_to_jql() {
-        let state = SortState::new(SortColumn::Key, SortDirection::Descending);
-        assert_eq!(state.to_jql(), "ORDER BY key DESC");
+        let state = SortState::new(ColumnField::Key, SortDirection::Descending);
+        assert_eq!(state.to_jql(&FieldIds::default()), "ORDER BY key DESC");
 
-        let state = SortState::new(SortColumn::Status, SortDirection::Ascending);
-        assert_eq!(state.to_jql(), "ORDER BY status ASC");
+        let state = SortState::new(ColumnField::Status, SortDirection::Ascending);
+        assert_eq!(state.to_jql(&FieldIds::default()), "ORDER BY status ASC");
     }
 
     #[test]
     fn test_sort_state_toggle_column_same() {
-        let mut state = SortState::new(SortColumn::Key, SortDirection::Descending);
-        state.toggle_column(SortColumn::Key);
+        let mut state = SortState::new(ColumnField::Key, SortDirection::Descending);
+        state.toggle_column(ColumnField::Key);
 
-        assert_eq!(state.column, SortColumn::Key);
+        assert_eq!(state.column, ColumnField::Key);
         assert_eq!(state.direction, SortDirection::Ascending);
     }
 
     #[test]
     fn test_sort_state_toggle_column_different() {
-        let mut state = SortState::new(SortColumn::Key, SortDirection::Ascending);
-        state.toggle_column(SortColumn::Status);
+        let mut state = SortState::new(ColumnField::Key, SortDirection::Ascending);
+        state.toggle_column(ColumnField::Status);
 
-        assert_eq!(state.column, SortColumn::Status);
+        assert_eq!(state.column, ColumnField::Status);
         assert_eq!(state.direction, SortDirection::Descending);
     }
 
@@ -1671,7 +2348,7 @@ mod tests {
         let action = view.handle_input(key);
 
         assert_eq!(action, Some(ListAction::SortChanged));
-        assert_eq!(view.sort.column, SortColumn::Status);
+        assert_eq!(view.sort.column, ColumnField::Status);
         assert!(!view.is_header_focused());
     }
 
@@ -2221,4 +2898,236 @@ mod tests {
         // Selection should have moved to next match
         assert_eq!(view.selected, 1);
     }
+
+    // ========================================================================
+    // Grouping Tests
+    // ========================================================================
+
+    fn create_status_issue(key: &str, status: &str, points: Option<f64>) -> Issue {
+        let mut issue = create_test_issue(key, "Summary");
+        issue.fields.status.name = status.to_string();
+        issue.fields.story_points = points;
+        issue
+    }
+
+    fn create_grouped_view() -> ListView {
+        let mut view = ListView::new();
+        view.pagination.has_more = false;
+        view.set_issues(vec![
+            create_status_issue("TEST-1", "Open", Some(3.0)),
+            create_status_issue("TEST-2", "Done", None),
+            create_status_issue("TEST-3", "Open", Some(2.0)),
+        ]);
+        view.set_group_by(Some(GroupBy::Status));
+        view
+    }
+
+    #[test]
+    fn test_group_by_cycle() {
+        assert_eq!(GroupBy::cycle(None), Some(GroupBy::Status));
+        assert_eq!(
+            GroupBy::cycle(Some(GroupBy::Status)),
+            Some(GroupBy::Assignee)
+        );
+        assert_eq!(GroupBy::cycle(Some(GroupBy::Component)), None);
+    }
+
+    #[test]
+    fn test_group_labels() {
+        let issue = create_test_issue("TEST-1", "Summary");
+        let fields = FieldIds::default();
+        assert_eq!(GroupBy::Status.group_label(&issue, &fields), "Open");
+        assert_eq!(GroupBy::Assignee.group_label(&issue, &fields), "Unassigned");
+        assert_eq!(GroupBy::Parent.group_label(&issue, &fields), "No parent");
+        assert_eq!(GroupBy::Sprint.group_label(&issue, &fields), "No sprint");
+        assert_eq!(
+            GroupBy::Component.group_label(&issue, &fields),
+            "No component"
+        );
+    }
+
+    #[test]
+    fn test_grouped_rows_with_counts_and_points() {
+        let view = create_grouped_view();
+
+        assert_eq!(
+            view.rows,
+            vec![
+                ListRow::Group {
+                    label: "Open".to_string(),
+                    count: 2,
+                    story_points: Some(5.0),
+                    collapsed: false,
+                },
+                ListRow::Issue(0),
+                ListRow::Issue(2),
+                ListRow::Group {
+                    label: "Done".to_string(),
+                    count: 1,
+                    story_points: None,
+                    collapsed: false,
+                },
+                ListRow::Issue(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grouping_keeps_selected_issue() {
+        let mut view = ListView::new();
+        view.set_issues(vec![
+            create_status_issue("TEST-1", "Open", None),
+            create_status_issue("TEST-2", "Done", None),
+        ]);
+        view.selected = 1;
+
+        view.set_group_by(Some(GroupBy::Status));
+        assert_eq!(view.selected_issue().unwrap().key, "TEST-2");
+
+        view.set_group_by(None);
+        assert_eq!(view.selected_issue().unwrap().key, "TEST-2");
+    }
+
+    #[test]
+    fn test_header_row_has_no_selected_issue() {
+        let mut view = create_grouped_view();
+        view.selected = 0;
+        assert!(view.selected_issue().is_none());
+
+        // Enter on a header collapses instead of opening an issue
+        let action = view.handle_input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(action.is_none());
+        assert!(matches!(
+            view.rows[0],
+            ListRow::Group {
+                collapsed: true,
+                ..
+            }
+        ));
+        assert_eq!(view.rows.len(), 3);
+    }
+
+    #[test]
+    fn test_z_collapses_group_of_selected_issue() {
+        let mut view = create_grouped_view();
+        view.selected = 2; // TEST-3 in "Open"
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE));
+        assert_eq!(view.selected, 0);
+        assert_eq!(view.rows.len(), 3);
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE));
+        assert_eq!(view.rows.len(), 5);
+    }
+
+    #[test]
+    fn test_toggle_all_groups() {
+        let mut view = create_grouped_view();
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('Z'), KeyModifiers::SHIFT));
+        assert_eq!(view.rows.len(), 2);
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('Z'), KeyModifiers::SHIFT));
+        assert_eq!(view.rows.len(), 5);
+    }
+
+    #[test]
+    fn test_navigation_covers_group_rows() {
+        let mut view = create_grouped_view();
+        view.move_to_end();
+        assert_eq!(view.selected, 4);
+        assert_eq!(view.selected_issue().unwrap().key, "TEST-2");
+    }
+
+    #[test]
+    fn test_search_expands_collapsed_group() {
+        let mut view = create_grouped_view();
+        view.toggle_all_groups();
+
+        view.search.activate();
+        for c in "TEST-2".chars() {
+            view.handle_input(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+
+        assert_eq!(view.selected_issue().unwrap().key, "TEST-2");
+    }
+
+    #[test]
+    fn test_update_issue_moves_between_groups() {
+        let mut view = create_grouped_view();
+        // TEST-1 now comes first, so its new group leads
+        let updated = create_status_issue("TEST-1", "Done", Some(3.0));
+        view.update_issue(&updated);
+
+        assert!(matches!(
+            &view.rows[0],
+            ListRow::Group { label, count: 2, .. } if label == "Done"
+        ));
+        assert!(matches!(
+            &view.rows[3],
+            ListRow::Group { label, count: 1, .. } if label == "Open"
+        ));
+    }
+
+    #[test]
+    fn test_v_cycles_group_by() {
+        let mut view = create_grouped_view();
+        view.set_group_by(None);
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE));
+        assert_eq!(view.group_by(), Some(GroupBy::Status));
+        view.handle_input(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE));
+        assert_eq!(view.group_by(), Some(GroupBy::Assignee));
+    }
+
+    // ========================================================================
+    // Tab Key Tests
+    // ========================================================================
+
+    #[test]
+    fn test_gt_and_g_shift_t_switch_tabs() {
+        let mut view = ListView::new();
+        view.set_issues(vec![create_test_issue("TEST-1", "First")]);
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        let action = view.handle_input(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE));
+        assert_eq!(action, Some(ListAction::NextTab));
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        let action = view.handle_input(KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT));
+        assert_eq!(action, Some(ListAction::PrevTab));
+    }
+
+    #[test]
+    fn test_gi_opens_go_to_issue() {
+        let mut view = ListView::new();
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        let action = view.handle_input(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE));
+        assert_eq!(action, Some(ListAction::GoToIssue));
+    }
+
+    #[test]
+    fn test_ctrl_t_and_ctrl_w_open_and_close_tabs() {
+        let mut view = ListView::new();
+
+        let action = view.handle_input(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL));
+        assert_eq!(action, Some(ListAction::NewTab));
+
+        let action = view.handle_input(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        assert_eq!(action, Some(ListAction::CloseTab));
+    }
+
+    #[test]
+    fn test_sort_column_field_id_roundtrip() {
+        for column in [
+            ColumnField::IssueType,
+            ColumnField::DueDate,
+            ColumnField::StoryPoints,
+            ColumnField::Components,
+            ColumnField::CustomField("customfield_10050".to_string()),
+        ] {
+            assert_eq!(ColumnField::from_field_id(&column.field_id()), Some(column));
+        }
+    }
 }
@@ -10,13 +10,15 @@ mod help;
 mod history;
 mod list;
 mod profile;
+mod release;
 
 pub use create_issue::{CreateIssueAction, CreateIssueRenderData, CreateIssueView};
 pub use detail::{DetailAction, DetailView};
 pub use filter::{FilterPanelAction, FilterPanelView};
 pub use help::{HelpAction, HelpView};
-pub use list::{ListAction, ListView};
+pub use list::{ListAction, ListColumn, ListView, SortDirection, SortState};
 pub use profile::{
-    DeleteProfileDialog, FormField, ProfileFormAction, ProfileFormData, ProfileFormView,
-    ProfileListAction, ProfileListView, ProfileSummary,
+    DeleteProfileDialog, FormField, ImportBundleAction, ImportBundleDialog, ProfileFormAction,
+    ProfileFormData, ProfileFormView, ProfileListAction, ProfileListView, ProfileSummary,
 };
+pub use release::{ReleaseAction, ReleaseView};
@@ -3,6 +3,9 @@
 //! This module provides TUI views for managing JIRA profiles:
 //! - `ProfileListView`: Displays all configured profiles with CRUD operations
 //! - `ProfileFormView`: Form for adding or editing a profile
+//! - `ImportBundleDialog`: Conflict resolution for importing a profile bundle
+
+use std::path::PathBuf;
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
@@ -13,7 +16,8 @@ use ratatui::{
     Frame,
 };
 
-use crate::config::Profile;
+use crate::config::{ConflictResolution, Profile};
+use crate::events::{Action, KeyContext, PendingKeys};
 use crate::ui::components::TextInput;
 use crate::ui::theme::theme;
 
@@ -34,10 +38,33 @@ pub enum ProfileListAction {
     SetDefault(usize),
     /// Switch to the selected profile.
     SwitchToProfile(usize),
+    /// Export the profile to a bundle file.
+    ExportProfile(usize, PathBuf),
+    /// Import a profile bundle file.
+    ImportBundle(PathBuf),
     /// Go back to the previous view.
     GoBack,
 }
 
+/// File name suggested when importing a bundle.
+const DEFAULT_BUNDLE_FILE: &str = "lazyjira-team.toml";
+
+/// What a file path is being entered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PathPurpose {
+    /// Export the profile at this index.
+    Export(usize),
+    /// Import a bundle.
+    Import,
+}
+
+/// Prompt for the path of a bundle file.
+#[derive(Debug)]
+struct PathPrompt {
+    purpose: PathPurpose,
+    input: TextInput,
+}
+
 /// A summary of a profile for display in the list.
 #[derive(Debug, Clone)]
 pub struct ProfileSummary {
@@ -75,6 +102,10 @@ pub struct ProfileListView {
     selected: usize,
     /// List state for ratatui.
     list_state: ListState,
+    /// Partially typed key sequence of a multi-key binding.
+    keys: PendingKeys,
+    /// Bundle file path being entered, if any.
+    path_prompt: Option<PathPrompt>,
 }
 
 impl Default for ProfileListView {
@@ -90,6 +121,8 @@ impl ProfileListView {
             profiles: Vec::new(),
             selected: 0,
             list_state: ListState::default(),
+            keys: PendingKeys::default(),
+            path_prompt: None,
         }
     }
 
@@ -117,6 +150,46 @@ impl ProfileListView {
         self.profiles.get(self.selected).map(|p| p.name.as_str())
     }
 
+    /// Check whether a bundle file path is being entered.
+    pub fn is_prompting(&self) -> bool {
+        self.path_prompt.is_some()
+    }
+
+    /// Start entering a bundle file path.
+    fn start_prompt(&mut self, purpose: PathPurpose, default: String) {
+        self.path_prompt = Some(PathPrompt {
+            purpose,
+            input: TextInput::with_value(default),
+        });
+    }
+
+    /// Handle keyboard input while entering a bundle file path.
+    fn handle_prompt_input(&mut self, key: KeyEvent) -> Option<ProfileListAction> {
+        let prompt = self.path_prompt.as_mut()?;
+        match key.code {
+            KeyCode::Enter => {
+                let path = PathBuf::from(prompt.input.value().trim());
+                if path.as_os_str().is_empty() {
+                    return None;
+                }
+                let purpose = prompt.purpose;
+                self.path_prompt = None;
+                Some(match purpose {
+                    PathPurpose::Export(index) => ProfileListAction::ExportProfile(index, path),
+                    PathPurpose::Import => ProfileListAction::ImportBundle(path),
+                })
+            }
+            KeyCode::Esc => {
+                self.path_prompt = None;
+                None
+            }
+            _ => {
+                prompt.input.handle_input(key);
+                None
+            }
+        }
+    }
+
     /// Move selection down.
     fn move_down(&mut self) {
         if self.profiles.is_empty() {
@@ -138,54 +211,49 @@ impl ProfileListView {
 
     /// Handle keyboard input.
     pub fn handle_input(&mut self, key: KeyEvent) -> Option<ProfileListAction> {
-        match (key.code, key.modifiers) {
+        if self.path_prompt.is_some() {
+            return self.handle_prompt_input(key);
+        }
+        match self.keys.resolve(KeyContext::ProfileManagement, key)? {
             // Navigation
-            (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => {
+            Action::MoveDown => {
                 self.move_down();
                 None
             }
-            (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => {
+            Action::MoveUp => {
                 self.move_up();
                 None
             }
             // Add new profile
-            (KeyCode::Char('a'), KeyModifiers::NONE) => Some(ProfileListAction::AddProfile),
+            Action::Add => Some(ProfileListAction::AddProfile),
             // Edit selected profile
-            (KeyCode::Char('e'), KeyModifiers::NONE) | (KeyCode::Enter, _) => {
-                if !self.profiles.is_empty() {
-                    Some(ProfileListAction::EditProfile(self.selected))
-                } else {
-                    None
-                }
+            Action::Edit if !self.profiles.is_empty() => {
+                Some(ProfileListAction::EditProfile(self.selected))
             }
             // Delete selected profile
-            (KeyCode::Char('d'), KeyModifiers::NONE) => {
-                if !self.profiles.is_empty() {
-                    Some(ProfileListAction::DeleteProfile(self.selected))
-                } else {
-                    None
-                }
+            Action::Delete if !self.profiles.is_empty() => {
+                Some(ProfileListAction::DeleteProfile(self.selected))
             }
             // Set as default
-            (KeyCode::Char('s'), KeyModifiers::NONE) => {
-                if !self.profiles.is_empty() {
-                    Some(ProfileListAction::SetDefault(self.selected))
-                } else {
-                    None
-                }
+            Action::SetDefault if !self.profiles.is_empty() => {
+                Some(ProfileListAction::SetDefault(self.selected))
             }
             // Switch to profile
-            (KeyCode::Char(' '), KeyModifiers::NONE) => {
-                if !self.profiles.is_empty() {
-                    Some(ProfileListAction::SwitchToProfile(self.selected))
-                } else {
-                    None
-                }
+            Action::UseProfile if !self.profiles.is_empty() => {
+                Some(ProfileListAction::SwitchToProfile(self.selected))
             }
-            // Go back
-            (KeyCode::Char('q'), KeyModifiers::NONE) | (KeyCode::Esc, _) => {
-                Some(ProfileListAction::GoBack)
+            // Export to or import from a bundle file
+            Action::Export if !self.profiles.is_empty() => {
+                let default = format!("lazyjira-{}.toml", self.profiles[self.selected].name);
+                self.start_prompt(PathPurpose::Export(self.selected), default);
+                None
+            }
+            Action::Import => {
+                self.start_prompt(PathPurpose::Import, DEFAULT_BUNDLE_FILE.to_string());
+                None
             }
+            // Go back
+            Action::Back => Some(ProfileListAction::GoBack),
             _ => None,
         }
     }
@@ -210,12 +278,13 @@ impl ProfileListView {
                 Line::styled("No profiles configured", Style::default().fg(t.dim)),
                 Line::raw(""),
                 Line::styled(
-                    "Press 'a' to add a new profile",
+                    "Press 'a' to add a new profile or 'i' to import one",
                     Style::default().fg(t.warning),
                 ),
             ])
             .alignment(Alignment::Center);
             frame.render_widget(message, inner);
+            self.render_prompt(frame, area);
             return;
         }
 
@@ -277,11 +346,33 @@ impl ProfileListView {
             Span::raw("et default "),
             Span::styled("[Space]", Style::default().fg(t.warning)),
             Span::raw("switch "),
+            Span::styled("[x]", Style::default().fg(t.warning)),
+            Span::raw("export "),
+            Span::styled("[i]", Style::default().fg(t.warning)),
+            Span::raw("mport "),
             Span::styled("[q]", Style::default().fg(t.warning)),
             Span::raw("back"),
         ]);
         let help_para = Paragraph::new(help).alignment(Alignment::Center);
         frame.render_widget(help_para, chunks[1]);
+
+        self.render_prompt(frame, area);
+    }
+
+    /// Render the bundle file path prompt, if open.
+    fn render_prompt(&self, frame: &mut Frame, area: Rect) {
+        let Some(prompt) = &self.path_prompt else {
+            return;
+        };
+        let label = match prompt.purpose {
+            PathPurpose::Export(_) => "Export to file (Enter: export, Esc: cancel)",
+            PathPurpose::Import => "Import from file (Enter: import, Esc: cancel)",
+        };
+        let prompt_area = centered_rect(area, 60u16.min(area.width.saturating_sub(4)), 3);
+        frame.render_widget(Clear, prompt_area);
+        prompt
+            .input
+            .render_with_label(frame, prompt_area, label, true);
     }
 }
 
@@ -893,6 +984,175 @@ impl DeleteProfileDialog {
     }
 }
 
+// ============================================================================
+// Import Bundle Dialog
+// ============================================================================
+
+/// Actions returned from the import bundle dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportBundleAction {
+    /// Import, resolving conflicts as chosen.
+    Import(ConflictResolution),
+    /// Cancel the import.
+    Cancel,
+}
+
+/// Conflict resolutions offered by the import dialog, with their shortcut.
+const RESOLUTIONS: [(ConflictResolution, char, &str); 3] = [
+    (ConflictResolution::Replace, 'r', "Replace"),
+    (ConflictResolution::KeepExisting, 'k', "Keep mine"),
+    (ConflictResolution::Rename, 'c', "Import copies"),
+];
+
+/// Maximum number of conflicts listed in the import dialog.
+const MAX_LISTED_CONFLICTS: usize = 6;
+
+/// A dialog asking how to import a bundle whose entries clash with existing
+/// ones.
+#[derive(Debug, Default)]
+pub struct ImportBundleDialog {
+    /// Name of the bundle's profile.
+    profile_name: String,
+    /// Descriptions of the conflicting entries.
+    conflicts: Vec<String>,
+    /// Whether the dialog is visible.
+    visible: bool,
+    /// Index of the selected resolution.
+    selected: usize,
+}
+
+impl ImportBundleDialog {
+    /// Create a new import bundle dialog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the dialog for a bundle's profile and its conflicts.
+    pub fn show(&mut self, profile_name: impl Into<String>, conflicts: Vec<String>) {
+        self.profile_name = profile_name.into();
+        self.conflicts = conflicts;
+        self.visible = true;
+        // Default to keeping existing entries for safety
+        self.selected = 1;
+    }
+
+    /// Hide the dialog.
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Check if the dialog is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Get the selected resolution.
+    pub fn selected(&self) -> ConflictResolution {
+        RESOLUTIONS[self.selected].0
+    }
+
+    /// Handle keyboard input.
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<ImportBundleAction> {
+        match key.code {
+            KeyCode::Char(c) => {
+                let resolution = RESOLUTIONS
+                    .iter()
+                    .find(|(_, shortcut, _)| c.eq_ignore_ascii_case(shortcut))?
+                    .0;
+                self.hide();
+                Some(ImportBundleAction::Import(resolution))
+            }
+            KeyCode::Enter => {
+                self.hide();
+                Some(ImportBundleAction::Import(self.selected()))
+            }
+            KeyCode::Esc => {
+                self.hide();
+                Some(ImportBundleAction::Cancel)
+            }
+            KeyCode::Tab | KeyCode::Right => {
+                self.selected = (self.selected + 1) % RESOLUTIONS.len();
+                None
+            }
+            KeyCode::BackTab | KeyCode::Left => {
+                self.selected = (self.selected + RESOLUTIONS.len() - 1) % RESOLUTIONS.len();
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Render the dialog.
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let t = theme();
+        let listed = self.conflicts.len().min(MAX_LISTED_CONFLICTS);
+        let more = self.conflicts.len() - listed;
+        let mut lines = vec![
+            Line::raw(format!("Importing profile '{}'.", self.profile_name)),
+            Line::raw("These already exist with different settings:"),
+        ];
+        lines.extend(self.conflicts[..listed].iter().map(|conflict| {
+            Line::styled(format!("  - {}", conflict), Style::default().fg(t.warning))
+        }));
+        if more > 0 {
+            lines.push(Line::styled(
+                format!("  ... and {} more", more),
+                Style::default().fg(t.dim),
+            ));
+        }
+
+        let dialog_width = 60u16.min(area.width.saturating_sub(4));
+        let dialog_height = lines.len() as u16 + 6;
+        let dialog_area = centered_rect(area, dialog_width, dialog_height);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .title(Span::styled(
+                " Import Profile ",
+                Style::default().fg(t.accent).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent));
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let message = Paragraph::new(lines)
+            .style(Style::default().fg(t.fg))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(message, chunks[0]);
+
+        let mut buttons = Vec::new();
+        for (i, (_, shortcut, label)) in RESOLUTIONS.iter().enumerate() {
+            let style = if i == self.selected {
+                Style::default()
+                    .fg(t.selection_fg)
+                    .bg(t.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(t.accent)
+            };
+            buttons.push(Span::styled(
+                format!(" [{}] {} ", shortcut.to_ascii_uppercase(), label),
+                style,
+            ));
+            buttons.push(Span::raw(" "));
+        }
+        buttons.push(Span::styled("[Esc] Cancel", Style::default().fg(t.dim)));
+        let buttons_para = Paragraph::new(Line::from(buttons)).alignment(Alignment::Center);
+        frame.render_widget(buttons_para, chunks[1]);
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -1021,6 +1281,48 @@ mod tests {
         assert_eq!(view.handle_input(key), Some(ProfileListAction::GoBack));
     }
 
+    #[test]
+    fn test_profile_list_export_import_prompts() {
+        let mut view = ProfileListView::new();
+        view.set_profiles(vec![ProfileSummary {
+            name: "test".to_string(),
+            url: "https://test.com".to_string(),
+            email: "test@test.com".to_string(),
+            is_default: false,
+            has_token: true,
+        }]);
+        let press = |view: &mut ProfileListView, code| {
+            view.handle_input(KeyEvent::new(code, KeyModifiers::NONE))
+        };
+
+        // Export suggests a file named after the profile
+        assert_eq!(press(&mut view, KeyCode::Char('x')), None);
+        assert!(view.is_prompting());
+        // Keys are typed into the path rather than acting on the list
+        assert_eq!(press(&mut view, KeyCode::Char('q')), None);
+        press(&mut view, KeyCode::Backspace);
+        assert_eq!(
+            press(&mut view, KeyCode::Enter),
+            Some(ProfileListAction::ExportProfile(
+                0,
+                PathBuf::from("lazyjira-test.toml")
+            ))
+        );
+        assert!(!view.is_prompting());
+
+        // Import suggests the team bundle; Esc cancels
+        press(&mut view, KeyCode::Char('i'));
+        assert_eq!(press(&mut view, KeyCode::Esc), None);
+        assert!(!view.is_prompting());
+        press(&mut view, KeyCode::Char('i'));
+        assert_eq!(
+            press(&mut view, KeyCode::Enter),
+            Some(ProfileListAction::ImportBundle(PathBuf::from(
+                DEFAULT_BUNDLE_FILE
+            )))
+        );
+    }
+
     #[test]
     fn test_profile_list_empty_no_actions() {
         let mut view = ProfileListView::new();
@@ -1244,6 +1546,45 @@ mod tests {
         assert!(dialog.selected_confirm);
     }
 
+    // Import Bundle Dialog Tests
+
+    #[test]
+    fn test_import_dialog_shortcuts() {
+        let mut dialog = ImportBundleDialog::new();
+        dialog.show("team", vec!["profile 'team'".to_string()]);
+        assert!(dialog.is_visible());
+
+        let key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+        assert_eq!(
+            dialog.handle_input(key),
+            Some(ImportBundleAction::Import(ConflictResolution::Rename))
+        );
+        assert!(!dialog.is_visible());
+    }
+
+    #[test]
+    fn test_import_dialog_selection() {
+        let mut dialog = ImportBundleDialog::new();
+        dialog.show("team", Vec::new());
+        // Defaults to keeping existing entries
+        assert_eq!(dialog.selected(), ConflictResolution::KeepExisting);
+
+        dialog.handle_input(KeyEvent::new(KeyCode::Left, KeyModifiers::NONE));
+        assert_eq!(dialog.selected(), ConflictResolution::Replace);
+        dialog.handle_input(KeyEvent::new(KeyCode::Left, KeyModifiers::NONE));
+        assert_eq!(dialog.selected(), ConflictResolution::Rename);
+
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(
+            dialog.handle_input(key),
+            Some(ImportBundleAction::Import(ConflictResolution::Rename))
+        );
+
+        dialog.show("team", Vec::new());
+        let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(dialog.handle_input(key), Some(ImportBundleAction::Cancel));
+    }
+
     // Helper Tests
 
     #[test]
@@ -0,0 +1,1030 @@
+//! Release view for project versions.
+//!
+//! Lists the versions of a project and, for the selected version, its issues
+//! grouped by status category together with the completion percentage.
+//! Versions can be created and released from here; releasing a version can
+//! move its unresolved issues to another version.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::api::types::{Issue, Project, Version};
+use crate::events::{Action, KeyContext, PendingKeys};
+use crate::ui::components::TextInput;
+use crate::ui::theme::{status_style, theme, Theme};
+
+/// Actions that can be returned from the release view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReleaseAction {
+    /// Go back to the issue list.
+    GoBack,
+    /// Reload the versions of the project (project key).
+    Refresh(String),
+    /// Load the issues of a version (version ID).
+    FetchIssues(String),
+    /// Open an issue (issue key).
+    OpenIssue(String),
+    /// Create a version (project ID, version name).
+    CreateVersion(String, String),
+    /// Release a version.
+    Release {
+        /// The ID of the version to release.
+        version_id: String,
+        /// The release date (YYYY-MM-DD).
+        release_date: String,
+        /// The ID of the version that unresolved issues are moved to, if any.
+        move_to: Option<String>,
+    },
+}
+
+/// Status category group used to bucket the issues of a version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum StatusGroup {
+    ToDo,
+    InProgress,
+    Done,
+}
+
+impl StatusGroup {
+    /// All groups in display order.
+    const ALL: [StatusGroup; 3] = [Self::ToDo, Self::InProgress, Self::Done];
+
+    /// Get the group of an issue from its status category.
+    fn of(issue: &Issue) -> Self {
+        match issue
+            .fields
+            .status
+            .status_category
+            .as_ref()
+            .map(|c| c.key.as_str())
+        {
+            Some("done") => Self::Done,
+            Some("indeterminate") => Self::InProgress,
+            _ => Self::ToDo,
+        }
+    }
+
+    /// Get the display title for this group.
+    fn title(&self) -> &'static str {
+        match self {
+            Self::ToDo => "To Do",
+            Self::InProgress => "In Progress",
+            Self::Done => "Done",
+        }
+    }
+
+    /// Get the theme color for this group.
+    fn color(&self, t: &Theme) -> Color {
+        match self {
+            Self::ToDo => t.status_new,
+            Self::InProgress => t.status_in_progress,
+            Self::Done => t.status_done,
+        }
+    }
+}
+
+/// Which pane of the release view has focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Focus {
+    #[default]
+    Versions,
+    Issues,
+}
+
+/// A dialog shown on top of the release view.
+#[derive(Debug)]
+enum ReleaseDialog {
+    /// Entering the name of a new version.
+    CreateVersion(TextInput),
+    /// Choosing where unresolved issues go when releasing a version.
+    Release {
+        /// The version being released.
+        version: Version,
+        /// Unreleased versions that unresolved issues can be moved to.
+        targets: Vec<Version>,
+        /// Selected option: 0 keeps the issues, `n` moves them to `targets[n - 1]`.
+        selected: usize,
+    },
+}
+
+/// The release view state.
+#[derive(Debug, Default)]
+pub struct ReleaseView {
+    /// The project whose versions are shown.
+    project: Option<Project>,
+    /// Versions of the project, excluding archived ones.
+    versions: Vec<Version>,
+    /// Whether the versions are loading.
+    loading: bool,
+    /// Currently selected version index.
+    selected: usize,
+    /// Issues of the selected version, ordered by status group.
+    issues: Vec<Issue>,
+    /// ID of the version the issues belong to.
+    issues_version_id: Option<String>,
+    /// Whether the issues are loading.
+    issues_loading: bool,
+    /// Currently selected issue index.
+    issue_selected: usize,
+    /// Which pane has focus.
+    focus: Focus,
+    /// The open dialog, if any.
+    dialog: Option<ReleaseDialog>,
+    /// Partially typed key sequence of a multi-key binding.
+    keys: PendingKeys,
+}
+
+impl ReleaseView {
+    /// Create a new release view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the view for a project in loading state.
+    pub fn show(&mut self, project: Project) {
+        *self = Self {
+            project: Some(project),
+            loading: true,
+            ..Self::default()
+        };
+    }
+
+    /// Get the key of the project shown.
+    pub fn project_key(&self) -> Option<&str> {
+        self.project.as_ref().map(|p| p.key.as_str())
+    }
+
+    /// Check if the versions are loading.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Mark the versions as loading (e.g. on refresh).
+    pub fn set_loading(&mut self, loading: bool) {
+        self.loading = loading;
+    }
+
+    /// Set the versions of the project.
+    ///
+    /// Keeps the selected version if it still exists, otherwise selects the
+    /// first unreleased version.
+    pub fn set_versions(&mut self, versions: Vec<Version>) {
+        let selected_id = self.selected_version().map(|v| v.id.clone());
+        self.versions = versions.into_iter().filter(|v| !v.archived).collect();
+        self.selected = selected_id
+            .and_then(|id| self.versions.iter().position(|v| v.id == id))
+            .or_else(|| self.versions.iter().position(|v| !v.released))
+            .unwrap_or(0);
+        self.loading = false;
+    }
+
+    /// Add a newly created version and select it.
+    pub fn add_version(&mut self, version: Version) {
+        self.versions.push(version);
+        self.selected = self.versions.len() - 1;
+        self.focus = Focus::Versions;
+    }
+
+    /// Get the versions shown.
+    pub fn versions(&self) -> &[Version] {
+        &self.versions
+    }
+
+    /// Get the selected version.
+    pub fn selected_version(&self) -> Option<&Version> {
+        self.versions.get(self.selected)
+    }
+
+    /// Start loading the issues of the selected version.
+    ///
+    /// Returns the ID of the version to load issues for.
+    pub fn start_loading_issues(&mut self) -> Option<String> {
+        let id = self.selected_version()?.id.clone();
+        self.issues.clear();
+        self.issue_selected = 0;
+        self.issues_version_id = Some(id.clone());
+        self.issues_loading = true;
+        Some(id)
+    }
+
+    /// Set the issues of a version.
+    ///
+    /// Issues for a version other than the one last requested are ignored.
+    pub fn set_issues(&mut self, version_id: &str, mut issues: Vec<Issue>) {
+        if self.issues_version_id.as_deref() != Some(version_id) {
+            return;
+        }
+        // Stable sort keeps the server order within each group
+        issues.sort_by_key(StatusGroup::of);
+        self.issues = issues;
+        self.issue_selected = 0;
+        self.issues_loading = false;
+    }
+
+    /// Stop all loading indicators after a failed request.
+    pub fn fail_loading(&mut self) {
+        self.loading = false;
+        self.issues_loading = false;
+    }
+
+    /// Check if the issues of the selected version are loading.
+    pub fn is_issues_loading(&self) -> bool {
+        self.issues_loading
+    }
+
+    /// Get the issues of the selected version.
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Get the number of done issues and the total number of issues.
+    pub fn completion(&self) -> (usize, usize) {
+        let done = self
+            .issues
+            .iter()
+            .filter(|i| StatusGroup::of(i) == StatusGroup::Done)
+            .count();
+        (done, self.issues.len())
+    }
+
+    /// Count the issues of each status group.
+    fn group_counts(&self) -> [(StatusGroup, usize); 3] {
+        StatusGroup::ALL.map(|group| {
+            let count = self
+                .issues
+                .iter()
+                .filter(|i| StatusGroup::of(i) == group)
+                .count();
+            (group, count)
+        })
+    }
+
+    /// Check if a dialog is open.
+    pub fn has_dialog(&self) -> bool {
+        self.dialog.is_some()
+    }
+
+    /// Select a version and load its issues.
+    fn select_version(&mut self, index: usize) -> Option<ReleaseAction> {
+        if index == self.selected || index >= self.versions.len() {
+            return None;
+        }
+        self.selected = index;
+        self.start_loading_issues().map(ReleaseAction::FetchIssues)
+    }
+
+    /// Open the release dialog for the selected version.
+    fn open_release_dialog(&mut self) {
+        let Some(version) = self.selected_version().filter(|v| !v.released).cloned() else {
+            return;
+        };
+        let targets = self
+            .versions
+            .iter()
+            .filter(|v| !v.released && v.id != version.id)
+            .cloned()
+            .collect();
+        self.dialog = Some(ReleaseDialog::Release {
+            version,
+            targets,
+            selected: 0,
+        });
+    }
+
+    /// Handle keyboard input.
+    ///
+    /// Returns an optional action to be handled by the app.
+    pub fn handle_input(&mut self, key: KeyEvent) -> Option<ReleaseAction> {
+        if self.dialog.is_some() {
+            return self.handle_dialog_input(key);
+        }
+
+        match self.keys.resolve(KeyContext::Releases, key)? {
+            Action::Back => Some(ReleaseAction::GoBack),
+            Action::MoveDown => match self.focus {
+                Focus::Versions => self.select_version(self.selected + 1),
+                Focus::Issues => {
+                    if self.issue_selected + 1 < self.issues.len() {
+                        self.issue_selected += 1;
+                    }
+                    None
+                }
+            },
+            Action::MoveUp => match self.focus {
+                Focus::Versions => self.select_version(self.selected.saturating_sub(1)),
+                Focus::Issues => {
+                    self.issue_selected = self.issue_selected.saturating_sub(1);
+                    None
+                }
+            },
+            Action::SwitchPane => {
+                self.focus = match self.focus {
+                    Focus::Versions => Focus::Issues,
+                    Focus::Issues => Focus::Versions,
+                };
+                None
+            }
+            Action::FocusLeft => {
+                self.focus = Focus::Versions;
+                None
+            }
+            Action::FocusRight => {
+                self.focus = Focus::Issues;
+                None
+            }
+            Action::Open => match self.focus {
+                Focus::Versions => {
+                    self.focus = Focus::Issues;
+                    None
+                }
+                Focus::Issues => self
+                    .issues
+                    .get(self.issue_selected)
+                    .map(|issue| ReleaseAction::OpenIssue(issue.key.clone())),
+            },
+            Action::Refresh => {
+                let key = self.project_key()?.to_string();
+                self.loading = true;
+                Some(ReleaseAction::Refresh(key))
+            }
+            Action::NewVersion if self.project.is_some() => {
+                let mut input = TextInput::new();
+                input.set_placeholder("Version name, e.g. 1.2.0");
+                self.dialog = Some(ReleaseDialog::CreateVersion(input));
+                None
+            }
+            Action::ReleaseVersion => {
+                self.open_release_dialog();
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Handle keyboard input while a dialog is open.
+    fn handle_dialog_input(&mut self, key: KeyEvent) -> Option<ReleaseAction> {
+        match self.dialog.as_mut()? {
+            ReleaseDialog::CreateVersion(input) => match key.code {
+                KeyCode::Esc => {
+                    self.dialog = None;
+                    None
+                }
+                KeyCode::Enter => {
+                    let name = input.value().trim().to_string();
+                    if name.is_empty() {
+                        return None;
+                    }
+                    let project_id = self.project.as_ref()?.id.clone();
+                    self.dialog = None;
+                    Some(ReleaseAction::CreateVersion(project_id, name))
+                }
+                _ => {
+                    input.handle_input(key);
+                    None
+                }
+            },
+            ReleaseDialog::Release {
+                version,
+                targets,
+                selected,
+            } => match (key.code, key.modifiers) {
+                (KeyCode::Esc, _) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+                    self.dialog = None;
+                    None
+                }
+                (KeyCode::Char('j'), KeyModifiers::NONE) | (KeyCode::Down, _) => {
+                    if *selected < targets.len() {
+                        *selected += 1;
+                    }
+                    None
+                }
+                (KeyCode::Char('k'), KeyModifiers::NONE) | (KeyCode::Up, _) => {
+                    *selected = selected.saturating_sub(1);
+                    None
+                }
+                (KeyCode::Enter, KeyModifiers::NONE) => {
+                    let action = ReleaseAction::Release {
+                        version_id: version.id.clone(),
+                        release_date: today(),
+                        move_to: selected
+                            .checked_sub(1)
+                            .and_then(|i| targets.get(i))
+                            .map(|v| v.id.clone()),
+                    };
+                    self.dialog = None;
+                    Some(action)
+                }
+                _ => None,
+            },
+        }
+    }
+
+    /// Render the release view.
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+            .split(area);
+
+        self.render_versions(frame, chunks[0]);
+        self.render_version_detail(frame, chunks[1]);
+
+        match &self.dialog {
+            Some(ReleaseDialog::CreateVersion(input)) => {
+                self.render_create_dialog(frame, area, input)
+            }
+            Some(ReleaseDialog::Release {
+                version,
+                targets,
+                selected,
+            }) => self.render_release_dialog(frame, area, version, targets, *selected),
+            None => {}
+        }
+    }
+
+    /// Get the border style for a pane.
+    fn pane_border(&self, focus: Focus) -> Style {
+        let t = theme();
+        if self.focus == focus && self.dialog.is_none() {
+            Style::default().fg(t.border_focused)
+        } else {
+            Style::default().fg(t.border)
+        }
+    }
+
+    /// Render the versions list.
+    fn render_versions(&self, frame: &mut Frame, area: Rect) {
+        let t = theme();
+        let title = match self.project_key() {
+            Some(key) => format!(" Versions - {} ", key),
+            None => " Versions ".to_string(),
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(self.pane_border(Focus::Versions));
+
+        if self.loading || self.versions.is_empty() {
+            let text = if self.loading {
+                "Loading versions..."
+            } else {
+                "No versions. Press n to create one."
+            };
+            let paragraph = Paragraph::new(text)
+                .style(Style::default().fg(t.dim))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .versions
+            .iter()
+            .map(|version| {
+                let (marker, style) = if version.released {
+                    ("✓ ", Style::default().fg(t.dim))
+                } else {
+                    ("○ ", Style::default().fg(t.fg))
+                };
+                let mut spans = vec![
+                    Span::styled(marker, style),
+                    Span::styled(version.name.clone(), style),
+                ];
+                if let Some(date) = &version.release_date {
+                    spans.push(Span::styled(
+                        format!("  {}", date),
+                        Style::default().fg(t.dim),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .fg(t.selection_fg)
+                    .bg(t.selection_bg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    /// Render the selected version with its progress and grouped issues.
+    fn render_version_detail(&self, frame: &mut Frame, area: Rect) {
+        let t = theme();
+        let Some(version) = self.selected_version() else {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(self.pane_border(Focus::Issues));
+            frame.render_widget(block, area);
+            return;
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", version.name))
+            .borders(Borders::ALL)
+            .border_style(self.pane_border(Focus::Issues));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Release state
+                Constraint::Length(1), // Completion gauge
+                Constraint::Length(1), // Spacer
+                Constraint::Min(1),    // Grouped issues
+            ])
+            .split(inner);
+
+        let mut state_spans = if version.released {
+            vec![Span::styled("Released", Style::default().fg(t.success))]
+        } else {
+            vec![Span::styled("Unreleased", Style::default().fg(t.warning))]
+        };
+        if let Some(date) = &version.release_date {
+            state_spans.push(Span::styled("  Release date: ", Style::default().fg(t.dim)));
+            state_spans.push(Span::raw(date.clone()));
+        }
+        if let Some(description) = version.description.as_deref().filter(|d| !d.is_empty()) {
+            state_spans.push(Span::styled(
+                format!("  {}", description),
+                Style::default().fg(t.dim),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(state_spans)), chunks[0]);
+
+        if self.issues_loading {
+            let loading = Paragraph::new("Loading issues...")
+                .style(Style::default().fg(t.dim))
+                .alignment(Alignment::Center);
+            frame.render_widget(loading, chunks[3]);
+            return;
+        }
+
+        let (done, total) = self.completion();
+        let ratio = if total == 0 {
+            0.0
+        } else {
+            done as f64 / total as f64
+        };
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(t.success))
+            .ratio(ratio)
+            .label(format!(
+                "{} of {} issues done ({}%)",
+                done,
+                total,
+                (ratio * 100.0).round() as u32
+            ));
+        frame.render_widget(gauge, chunks[1]);
+
+        if self.issues.is_empty() {
+            let empty = Paragraph::new("No issues in this version")
+                .style(Style::default().fg(t.dim))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[3]);
+            return;
+        }
+
+        // Group headers are interleaved with the issue rows, so track the row
+        // of the selected issue for highlighting
+        let mut items = Vec::new();
+        let mut selected_row = None;
+        let mut issue_index = 0;
+        for (group, count) in self.group_counts() {
+            items.push(ListItem::new(Line::from(Span::styled(
+                format!("{} ({})", group.title(), count),
+                Style::default()
+                    .fg(group.color(t))
+                    .add_modifier(Modifier::BOLD),
+            ))));
+            for issue in &self.issues[issue_index..issue_index + count] {
+                if issue_index == self.issue_selected {
+                    selected_row = Some(items.len());
+                }
+                items.push(ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("  {:<12}", issue.key),
+                        Style::default().fg(t.accent),
+                    ),
+                    Span::raw(issue.fields.summary.clone()),
+                    Span::raw("  "),
+                    Span::styled(
+                        issue.fields.status.name.clone(),
+                        status_style(&issue.fields.status),
+                    ),
+                ])));
+                issue_index += 1;
+            }
+        }
+
+        let highlight = if self.focus == Focus::Issues {
+            Style::default()
+                .fg(t.selection_fg)
+                .bg(t.selection_bg)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let list = List::new(items).highlight_style(highlight);
+        let mut state = ListState::default();
+        state.select(selected_row);
+        frame.render_stateful_widget(list, chunks[3], &mut state);
+    }
+
+    /// Render the create version dialog.
+    fn render_create_dialog(&self, frame: &mut Frame, area: Rect, input: &TextInput) {
+        let t = theme();
+        let dialog_area = centered_rect(area, 50u16.min(area.width.saturating_sub(4)), 6);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .title(" New Version ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.border_focused));
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Length(1)])
+            .split(inner);
+        input.render(frame, chunks[0], true);
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "Enter:create  Esc:cancel",
+                Style::default().fg(t.dim),
+            ))
+            .alignment(Alignment::Center),
+            chunks[1],
+        );
+    }
+
+    /// Render the release dialog.
+    fn render_release_dialog(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        version: &Version,
+        targets: &[Version],
+        selected: usize,
+    ) {
+        let t = theme();
+        // Border (2) + unresolved count and blank line (2) + options + blank line and hint (2)
+        let dialog_height = (targets.len() as u16 + 7).min(area.height.saturating_sub(4));
+        let dialog_area =
+            centered_rect(area, 56u16.min(area.width.saturating_sub(4)), dialog_height);
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .title(format!(" Release {} ", version.name))
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.border_focused));
+
+        let unresolved = if self.issues_version_id.as_deref() == Some(version.id.as_str())
+            && !self.issues_loading
+        {
+            let (done, total) = self.completion();
+            format!("{} unresolved issue(s)", total - done)
+        } else {
+            "Unresolved issues".to_string()
+        };
+
+        let mut lines = vec![
+            Line::from(Span::styled(unresolved, Style::default().fg(t.dim))),
+            Line::default(),
+        ];
+        let options = std::iter::once(format!("Leave in {}", version.name))
+            .chain(targets.iter().map(|v| format!("Move to {}", v.name)));
+        for (i, label) in options.enumerate() {
+            let style = if i == selected {
+                Style::default()
+                    .fg(t.selection_fg)
+                    .bg(t.selection_bg)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(Span::styled(label, style)));
+        }
+        lines.push(Line::default());
+        lines.push(
+            Line::from(Span::styled(
+                "Enter:release  Esc:cancel",
+                Style::default().fg(t.dim),
+            ))
+            .alignment(Alignment::Center),
+        );
+
+        frame.render_widget(Paragraph::new(lines).block(block), dialog_area);
+    }
+}
+
+/// Get today's date (UTC) as YYYY-MM-DD.
+fn today() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    civil_date(secs.div_euclid(86_400))
+}
+
+/// Format days since the Unix epoch as a YYYY-MM-DD date (Howard Hinnant's algorithm).
+fn civil_date(days: i64) -> String {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Calculate a centered rectangle within the given area.
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width.min(area.width), height.min(area.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::{IssueFields, IssueType, Status, StatusCategory};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn create_test_project() -> Project {
+        Project {
+            id: "10000".to_string(),
+            key: "PROJ".to_string(),
+            name: "Project".to_string(),
+            avatar_urls: None,
+        }
+    }
+
+    fn create_test_version(id: &str, name: &str, released: bool) -> Version {
+        Version {
+            id: id.to_string(),
+            name: name.to_string(),
+            released,
+            ..Default::default()
+        }
+    }
+
+    fn create_test_issue(key: &str, category: &str) -> Issue {
+        Issue {
+            id: "1".to_string(),
+            key: key.to_string(),
+            self_url: "https://example.com".to_string(),
+            fields: IssueFields {
+                summary: format!("Issue {}", key),
+                description: None,
+                status: Status {
+                    id: "1".to_string(),
+                    name: category.to_string(),
+                    status_category: Some(StatusCategory {
+                        id: 1,
+                        key: category.to_string(),
+                        name: category.to_string(),
+                        color_name: None,
+                    }),
+                },
+                issuetype: IssueType {
+                    id: "1".to_string(),
+                    name: "Task".to_string(),
+                    subtask: false,
+                    description: None,
+                    icon_url: None,
+                },
+                priority: None,
+                assignee: None,
+                reporter: None,
+                project: None,
+                labels: vec![],
+                components: vec![],
+                created: None,
+                updated: None,
+                duedate: None,
+                story_points: None,
+                issue_links: vec![],
+                subtasks: vec![],
+                parent: None,
+                extra_fields: Default::default(),
+            },
+        }
+    }
+
+    fn create_loaded_view() -> ReleaseView {
+        let mut view = ReleaseView::new();
+        view.show(create_test_project());
+        view.set_versions(vec![
+            create_test_version("1", "1.0", true),
+            create_test_version("2", "1.1", false),
+            create_test_version("3", "1.2", false),
+        ]);
+        view
+    }
+
+    #[test]
+    fn test_set_versions_selects_first_unreleased() {
+        let mut view = ReleaseView::new();
+        view.show(create_test_project());
+        assert!(view.is_loading());
+
+        let mut archived = create_test_version("0", "0.9", true);
+        archived.archived = true;
+        view.set_versions(vec![
+            archived,
+            create_test_version("1", "1.0", true),
+            create_test_version("2", "1.1", false),
+        ]);
+
+        assert!(!view.is_loading());
+        assert_eq!(view.versions().len(), 2);
+        assert_eq!(view.selected_version().unwrap().name, "1.1");
+    }
+
+    #[test]
+    fn test_set_versions_keeps_selection() {
+        let mut view = create_loaded_view();
+        view.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(view.selected_version().unwrap().id, "3");
+
+        view.set_versions(vec![
+            create_test_version("3", "1.2", false),
+            create_test_version("2", "1.1", true),
+        ]);
+        assert_eq!(view.selected_version().unwrap().id, "3");
+    }
+
+    #[test]
+    fn test_moving_selection_fetches_issues() {
+        let mut view = create_loaded_view();
+
+        let action = view.handle_input(key(KeyCode::Char('k')));
+        assert_eq!(action, Some(ReleaseAction::FetchIssues("1".to_string())));
+        assert!(view.is_issues_loading());
+
+        // Already at the top
+        assert!(view.handle_input(key(KeyCode::Char('k'))).is_none());
+    }
+
+    #[test]
+    fn test_issues_grouped_and_completion() {
+        let mut view = create_loaded_view();
+        let version_id = view.start_loading_issues().unwrap();
+        view.set_issues(
+            &version_id,
+            vec![
+                create_test_issue("PROJ-1", "done"),
+                create_test_issue("PROJ-2", "new"),
+                create_test_issue("PROJ-3", "indeterminate"),
+                create_test_issue("PROJ-4", "done"),
+            ],
+        );
+
+        assert!(!view.is_issues_loading());
+        let keys: Vec<&str> = view.issues().iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["PROJ-2", "PROJ-3", "PROJ-1", "PROJ-4"]);
+        assert_eq!(view.completion(), (2, 4));
+    }
+
+    #[test]
+    fn test_stale_issues_ignored() {
+        let mut view = create_loaded_view();
+        view.start_loading_issues();
+        view.set_issues("1", vec![create_test_issue("PROJ-1", "done")]);
+
+        assert!(view.issues().is_empty());
+        assert!(view.is_issues_loading());
+    }
+
+    #[test]
+    fn test_open_issue_from_issue_pane() {
+        let mut view = create_loaded_view();
+        let version_id = view.start_loading_issues().unwrap();
+        view.set_issues(
+            &version_id,
+            vec![
+                create_test_issue("PROJ-1", "new"),
+                create_test_issue("PROJ-2", "new"),
+            ],
+        );
+
+        view.handle_input(key(KeyCode::Enter));
+        view.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(
+            view.handle_input(key(KeyCode::Enter)),
+            Some(ReleaseAction::OpenIssue("PROJ-2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_create_version() {
+        let mut view = create_loaded_view();
+        view.handle_input(key(KeyCode::Char('n')));
+        assert!(view.has_dialog());
+
+        // Empty names are not submitted
+        assert!(view.handle_input(key(KeyCode::Enter)).is_none());
+
+        for c in "2.0".chars() {
+            view.handle_input(key(KeyCode::Char(c)));
+        }
+        assert_eq!(
+            view.handle_input(key(KeyCode::Enter)),
+            Some(ReleaseAction::CreateVersion(
+                "10000".to_string(),
+                "2.0".to_string()
+            ))
+        );
+        assert!(!view.has_dialog());
+
+        view.add_version(create_test_version("4", "2.0", false));
+        assert_eq!(view.selected_version().unwrap().name, "2.0");
+    }
+
+    #[test]
+    fn test_release_moves_unresolved_issues() {
+        let mut view = create_loaded_view();
+        view.handle_input(KeyEvent::new(KeyCode::Char('R'), KeyModifiers::SHIFT));
+        assert!(view.has_dialog());
+
+        view.handle_input(key(KeyCode::Char('j')));
+        match view.handle_input(key(KeyCode::Enter)) {
+            Some(ReleaseAction::Release {
+                version_id,
+                release_date,
+                move_to,
+            }) => {
+                assert_eq!(version_id, "2");
+                assert_eq!(release_date.len(), 10);
+                assert_eq!(move_to.as_deref(), Some("3"));
+            }
+            other => panic!("Expected Release action, got {:?}", other),
+        }
+        assert!(!view.has_dialog());
+    }
+
+    #[test]
+    fn test_release_keeping_unresolved_issues() {
+        let mut view = create_loaded_view();
+        view.handle_input(KeyEvent::new(KeyCode::Char('R'), KeyModifiers::SHIFT));
+
+        match view.handle_input(key(KeyCode::Enter)) {
+            Some(ReleaseAction::Release { move_to, .. }) => assert!(move_to.is_none()),
+            other => panic!("Expected Release action, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_released_version_cannot_be_released() {
+        let mut view = create_loaded_view();
+        view.handle_input(key(KeyCode::Char('k')));
+        assert!(view.selected_version().unwrap().released);
+
+        view.handle_input(KeyEvent::new(KeyCode::Char('R'), KeyModifiers::SHIFT));
+        assert!(!view.has_dialog());
+    }
+
+    #[test]
+    fn test_go_back() {
+        let mut view = create_loaded_view();
+        assert_eq!(
+            view.handle_input(key(KeyCode::Esc)),
+            Some(ReleaseAction::GoBack)
+        );
+    }
+
+    #[test]
+    fn test_civil_date() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        assert_eq!(civil_date(19_723), "2024-01-01");
+        assert_eq!(civil_date(19_782), "2024-02-29");
+        assert_eq!(civil_date(19_783), "2024-03-01");
+    }
+}